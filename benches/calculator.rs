@@ -0,0 +1,22 @@
+//! Times every input of `Calculator::benchmark_corpus()`.
+//!
+//! Run with `cargo bench`, or `cargo bench -- dates` for one category. The
+//! parse cache is off, so each iteration lexes, parses and evaluates.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use link_calculator::Calculator;
+
+fn corpus(c: &mut Criterion) {
+    let mut calculator = Calculator::new();
+    calculator.parser_mut().set_parse_cache_capacity(0);
+    for case in Calculator::benchmark_corpus() {
+        let result = calculator.calculate_internal(case.input);
+        assert!(result.success, "{}: {:?}", case.name, result.error);
+        c.bench_function(&format!("{}/{}", case.category, case.name), |b| {
+            b.iter(|| calculator.calculate_internal(black_box(case.input)));
+        });
+    }
+}
+
+criterion_group!(benches, corpus);
+criterion_main!(benches);
@@ -29,7 +29,7 @@ fn main() {
 /// returns every artefact a downstream consumer needs.
 fn high_level_round_trip() {
     println!("--- high-level round trip (calculate_with_value) ---");
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let input = "(2 + 3) * 4";
 
     let (expression, value, steps, lino) = calculator
@@ -116,7 +116,7 @@ fn manual_reconstruction() {
 /// can render the same GitHub issue link the calculator uses internally.
 fn failure_round_trip() {
     println!("--- failure round trip ---");
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let bad = "???not-an-expression???";
     let err = calculator
         .calculate_with_value(bad)
@@ -4,7 +4,7 @@
 use link_calculator::Calculator;
 
 fn main() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     println!("=== Issue #121: Function call alternatives ===\n");
 
@@ -1,6 +1,6 @@
 use link_calculator::Calculator;
 fn main() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let cases = [
         // Using parentheses to force date parsing
@@ -5,7 +5,7 @@
 use link_calculator::Calculator;
 
 fn main() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let inputs = [
         "until 11:59pm EST on Monday, January 26th",
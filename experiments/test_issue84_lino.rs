@@ -1,7 +1,7 @@
 use link_calculator::Calculator;
 
 fn main() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // Test cases from issue #84
     let test_cases = vec![
@@ -0,0 +1,72 @@
+//! A corpus of representative inputs on the [`Calculator`], for timing the
+//! calculator before and after performance work. The `calculator` bench in
+//! `benches/` runs it with criterion.
+
+use serde::Serialize;
+
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// An input of the benchmark corpus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BenchmarkCase {
+    /// A short name for the case, unique in the corpus.
+    pub name: &'static str,
+    /// The part of the calculator it exercises: `arithmetic`, `units`,
+    /// `dates`, `currencies`, `integrals`, `plots`, `finance` or `matrices`.
+    pub category: &'static str,
+    /// The input to calculate.
+    pub input: &'static str,
+}
+
+const fn case(name: &'static str, category: &'static str, input: &'static str) -> BenchmarkCase {
+    BenchmarkCase {
+        name,
+        category,
+        input,
+    }
+}
+
+/// The corpus, by category.
+const CORPUS: &[BenchmarkCase] = &[
+    case("fractions", "arithmetic", "1/3 + 1/6"),
+    case("big_power", "arithmetic", "2^100"),
+    case("statistics", "arithmetic", "mean(1, 2, 3, 4)"),
+    case("length_sum", "units", "5 km + 300 m in miles"),
+    case("date_offset", "dates", "2026-03-15 + 90 days"),
+    case(
+        "date_difference",
+        "dates",
+        "(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC)",
+    ),
+    case("conversion", "currencies", "10 USD in EUR"),
+    case("mixed_sum", "currencies", "100 EUR + 20 GBP in USD"),
+    case("definite", "integrals", "integrate(sin(x), x, 0, pi)"),
+    case("indefinite", "integrals", "integrate x^2 dx"),
+    case("sine", "plots", "plot sin(x) from 0 to 10"),
+    case("mortgage", "finance", "amortize(200000, 5%, 30 years)"),
+    case("product", "matrices", "[[1,2],[3,4]] * [[5,6],[7,8]]"),
+];
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Returns the benchmark corpus as a JSON array of [`BenchmarkCase`],
+    /// so a host can time it in its own environment.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    #[must_use]
+    pub fn get_benchmark_corpus() -> String {
+        serde_json::to_string(Self::benchmark_corpus()).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+impl Calculator {
+    /// Returns representative inputs covering dates, currencies, integrals,
+    /// plots and the rest of the grammar. Every case calculates without
+    /// fetching rates. Calculate them at the `debug` steps level to get the
+    /// `phase_timings` of each.
+    #[must_use]
+    pub fn benchmark_corpus() -> &'static [BenchmarkCase] {
+        CORPUS
+    }
+}
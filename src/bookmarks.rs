@@ -0,0 +1,64 @@
+//! Named saved expressions on the [`Calculator`], recalled by name and
+//! calculated again with the current rates.
+
+use crate::types::Bookmarks;
+use crate::{CalculationResult, Calculator};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Saves `input` under `name` so that [`Self::recall`] can calculate it
+    /// again later. Returns `false` (saving nothing) if the name is not a
+    /// letter followed by letters, digits, `_` or `-`, or if the input does
+    /// not parse.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn save_expression(&mut self, name: &str, input: &str) -> bool {
+        self.parse(input).is_ok() && self.bookmarks.insert(name, input.trim()).is_ok()
+    }
+
+    /// Forgets the expression saved under `name`. Returns `false` if there
+    /// was none.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn remove_expression(&mut self, name: &str) -> bool {
+        self.bookmarks.remove(name).is_some()
+    }
+
+    /// Returns the saved expressions as a links notation document, one
+    /// `(name: "input")` line each.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn saved_expressions_lino(&self) -> String {
+        self.bookmarks.to_lino()
+    }
+
+    /// Replaces the saved expressions with a document previously returned by
+    /// [`Self::saved_expressions_lino`]. Returns `false` (leaving the current
+    /// ones untouched) if the document cannot be read.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn load_saved_expressions_lino(&mut self, lino: &str) -> bool {
+        match Bookmarks::from_lino(lino) {
+            Ok(bookmarks) => {
+                self.bookmarks = bookmarks;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl Calculator {
+    /// Calculates the expression saved under `name` with the current rates
+    /// and clock, as [`Self::calculate_internal`] would.
+    pub fn recall(&self, name: &str) -> CalculationResult {
+        match self.bookmarks.get(name) {
+            Some(input) => self.calculate_internal(input),
+            None => CalculationResult::failure(format!("No expression saved as '{name}'"), name),
+        }
+    }
+
+    /// Returns the saved expressions.
+    #[must_use]
+    pub const fn saved_expressions(&self) -> &Bookmarks {
+        &self.bookmarks
+    }
+}
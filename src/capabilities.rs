@@ -0,0 +1,331 @@
+//! What the grammar accepts, for help pages and autocomplete: the
+//! functions, operators, units, currencies and date formats, with names in
+//! the language set by [`Calculator::set_language`]. Functions come from the
+//! function registry and infix operators from the parser's precedence
+//! table, so the list follows the engine.
+
+mod names;
+
+use serde::Serialize;
+
+use crate::grammar::{infix_operators, physics_units, InputLocale};
+use crate::locale::{supported_locales, LocaleInfo};
+use crate::types::{AngleUnit, DataSizeUnit, DurationUnit, LengthUnit, MassUnit, Unit, VolumeUnit};
+use crate::{Calculator, FunctionInfo, FUNCTIONS};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Everything [`Calculator::capabilities`] lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    /// The named functions, by category.
+    pub functions: Vec<FunctionInfo>,
+    /// The operators, infix ones loosest first.
+    pub operators: Vec<OperatorInfo>,
+    /// The units amounts can carry, by category.
+    pub units: Vec<UnitInfo>,
+    /// The currencies with known rates, by code.
+    pub currencies: Vec<CurrencyInfo>,
+    /// The ways dates and times can be written.
+    pub date_formats: Vec<DateFormatInfo>,
+    /// The locales [`Calculator::set_locale`] accepts.
+    pub locales: Vec<LocaleInfo>,
+}
+
+/// Where an operator goes relative to its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Fixity {
+    /// Between two operands: `2 + 3`.
+    Infix,
+    /// Before its operand: `-5`.
+    Prefix,
+    /// After its operand: `5!`.
+    Postfix,
+}
+
+/// An operator of the grammar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OperatorInfo {
+    /// How it is written: `+`, `!`, `as`.
+    pub symbol: &'static str,
+    /// Where it goes relative to its operands.
+    pub fixity: Fixity,
+    /// How tightly an arithmetic operator binds, higher binding tighter.
+    pub precedence: Option<u8>,
+    /// Whether a chain groups from the right, as `2 ^ 3 ^ 2` does.
+    pub right_associative: bool,
+    /// What it does, in the calculator's language.
+    pub display_name: String,
+}
+
+/// The kind of quantity a unit measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitCategory {
+    /// Milliseconds to years.
+    Duration,
+    /// Bits and bytes, decimal and binary.
+    DataSize,
+    /// Milligrams to tons, pounds and ounces.
+    Mass,
+    /// Millimeters to miles.
+    Length,
+    /// Milliliters, liters and gallons.
+    Volume,
+    /// Radians, degrees and gradians.
+    Angle,
+    /// Amperes, volts, ohms, watts, newtons and joules, with SI prefixes.
+    Physics,
+    /// Speeds, fuel economy and acceleration.
+    Rate,
+}
+
+/// A unit amounts can carry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnitInfo {
+    /// How results are written with it: `kg`, `MiB`, `days`.
+    pub symbol: String,
+    /// The kind of quantity it measures.
+    pub category: UnitCategory,
+    /// Its name in the calculator's language.
+    pub display_name: String,
+}
+
+/// A currency with known rates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CurrencyInfo {
+    /// The ISO 4217 code: `USD`.
+    pub code: String,
+    /// Its sign: `$`.
+    pub symbol: String,
+    /// Its name in the calculator's language.
+    pub display_name: String,
+}
+
+/// A way to write a date or time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DateFormatInfo {
+    /// The shape of the input: `YYYY-MM-DD`.
+    pub pattern: &'static str,
+    /// An input of that shape, as the calculator's language and locale
+    /// write it.
+    pub example: String,
+}
+
+/// Operators outside the precedence table, with the key of their name.
+const OTHER_OPERATORS: &[(&str, Fixity, &str)] = &[
+    ("-", Fixity::Prefix, "negate"),
+    ("%", Fixity::Postfix, "percent"),
+    ("!", Fixity::Postfix, "factorial"),
+    ("==", Fixity::Infix, "equal"),
+    ("!=", Fixity::Infix, "not_equal"),
+    ("<", Fixity::Infix, "less"),
+    ("<=", Fixity::Infix, "less_or_equal"),
+    (">", Fixity::Infix, "greater"),
+    (">=", Fixity::Infix, "greater_or_equal"),
+    ("as", Fixity::Infix, "convert"),
+    ("in", Fixity::Infix, "convert"),
+    ("to", Fixity::Infix, "convert"),
+    ("at", Fixity::Infix, "at"),
+];
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Returns [`Self::capabilities`] as JSON.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    #[must_use]
+    pub fn get_capabilities(&self) -> String {
+        serde_json::to_string(&self.capabilities()).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+impl Calculator {
+    /// Describes the functions, operators, units, currencies and date
+    /// formats the calculator accepts. Names are in the language set with
+    /// [`Self::set_language`], and numeric dates are ordered as the locale
+    /// set with [`Self::set_locale`] reads them.
+    #[must_use]
+    pub fn capabilities(&self) -> Capabilities {
+        let language = self.language.as_deref().unwrap_or("en");
+        let day_first = self
+            .parser
+            .input_locale()
+            .is_some_and(InputLocale::day_first_dates);
+        Capabilities {
+            functions: FUNCTIONS.to_vec(),
+            operators: operators(language),
+            units: units(language),
+            currencies: self.currencies(language),
+            date_formats: date_formats(language, day_first),
+            locales: supported_locales(),
+        }
+    }
+
+    fn currencies(&self, language: &str) -> Vec<CurrencyInfo> {
+        let db = self.parser.currency_db();
+        let mut codes = db.supported_currencies();
+        codes.sort();
+        codes
+            .into_iter()
+            .filter_map(|code| db.get_currency(&code))
+            .map(|currency| CurrencyInfo {
+                display_name: names::lookup(names::CURRENCIES, &currency.code, language)
+                    .map_or(currency.name, str::to_string),
+                code: currency.code,
+                symbol: currency.symbol,
+            })
+            .collect()
+    }
+}
+
+fn operators(language: &str) -> Vec<OperatorInfo> {
+    let infix = infix_operators().map(|(symbol, precedence, right_associative)| {
+        let key = match symbol {
+            "+" => "add",
+            "-" => "subtract",
+            "*" => "multiply",
+            "/" => "divide",
+            "%" => "modulo",
+            "^" => "power",
+            _ => symbol,
+        };
+        (
+            symbol,
+            Fixity::Infix,
+            Some(precedence),
+            right_associative,
+            key,
+        )
+    });
+    let others = OTHER_OPERATORS
+        .iter()
+        .map(|&(symbol, fixity, key)| (symbol, fixity, None, false, key));
+    infix
+        .chain(others)
+        .map(
+            |(symbol, fixity, precedence, right_associative, key)| OperatorInfo {
+                symbol,
+                fixity,
+                precedence,
+                right_associative,
+                display_name: names::lookup(names::OPERATORS, key, language)
+                    .unwrap_or(symbol)
+                    .to_string(),
+            },
+        )
+        .collect()
+}
+
+/// Every unit, by category.
+fn all_units() -> impl Iterator<Item = (UnitCategory, Unit)> {
+    let durations = DurationUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::Duration, Unit::Duration(unit)));
+    let data_sizes = DataSizeUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::DataSize, Unit::DataSize(unit)));
+    let masses = MassUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::Mass, Unit::Mass(unit)));
+    let lengths = LengthUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::Length, Unit::Length(unit)));
+    let volumes = VolumeUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::Volume, Unit::Volume(unit)));
+    let angles = AngleUnit::ALL
+        .iter()
+        .map(|&unit| (UnitCategory::Angle, Unit::Angle(unit)));
+    let physics = physics_units().map(|unit| (UnitCategory::Physics, unit));
+    let per = |numerator, denominator| (UnitCategory::Rate, Unit::per(numerator, denominator));
+    let meter = Unit::Length(LengthUnit::Meter);
+    let rates = [
+        per(meter.clone(), Unit::Duration(DurationUnit::Seconds)),
+        per(
+            Unit::Length(LengthUnit::Kilometer),
+            Unit::Duration(DurationUnit::Hours),
+        ),
+        per(
+            Unit::Length(LengthUnit::Mile),
+            Unit::Duration(DurationUnit::Hours),
+        ),
+        per(
+            Unit::Length(LengthUnit::Mile),
+            Unit::Volume(VolumeUnit::Gallon),
+        ),
+        per(meter, Unit::power(Unit::Duration(DurationUnit::Seconds), 2)),
+    ];
+    durations
+        .chain(data_sizes)
+        .chain(masses)
+        .chain(lengths)
+        .chain(volumes)
+        .chain(angles)
+        .chain(physics)
+        .chain(rates)
+}
+
+fn units(language: &str) -> Vec<UnitInfo> {
+    all_units()
+        .map(|(category, unit)| {
+            let symbol = unit.display_name();
+            UnitInfo {
+                display_name: names::lookup(names::UNITS, &symbol, language)
+                    .unwrap_or(&symbol)
+                    .to_string(),
+                symbol,
+                category,
+            }
+        })
+        .collect()
+}
+
+fn date_formats(language: &str, day_first: bool) -> Vec<DateFormatInfo> {
+    let spelled = names::lookup(names::DATES, "day_month_year", language).unwrap_or_default();
+    let slashed = if day_first {
+        ("DD/MM/YYYY", "22/01/2026")
+    } else {
+        ("MM/DD/YYYY", "01/22/2026")
+    };
+    [
+        ("YYYY-MM-DD", "2026-01-22"),
+        ("YYYY-MM-DD hh:mm", "2026-01-22 14:30"),
+        ("Mon D, YYYY", "Jan 22, 2026"),
+        ("D Month YYYY", spelled),
+        slashed,
+        ("DD.MM.YYYY", "22.01.2026"),
+        ("h:mmam TZ", "8:59am UTC"),
+        ("hh:mm TZ", "14:30 MSK"),
+        ("now", "now"),
+        ("today", "today"),
+    ]
+    .into_iter()
+    .map(|(pattern, example)| DateFormatInfo {
+        pattern,
+        example: example.to_string(),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_unit_has_a_name_in_every_language() {
+        for (_, unit) in all_units() {
+            for language in ["en", "ru", "zh", "hi", "ar", "de", "fr"] {
+                let name = names::lookup(names::UNITS, &unit.display_name(), language);
+                assert!(name.is_some(), "{unit:?} has no name in {language}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_infix_operator_has_a_name() {
+        for operator in operators("en") {
+            assert_ne!(operator.display_name, operator.symbol);
+        }
+    }
+}
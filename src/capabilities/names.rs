@@ -0,0 +1,937 @@
+//! Names of units, currencies and operators, and date examples, in the
+//! UI languages of [`crate::supported_locales`].
+
+/// The languages of the name columns, in order.
+const LANGUAGES: [&str; 7] = ["en", "ru", "zh", "hi", "ar", "de", "fr"];
+
+/// A key and its name in each of [`LANGUAGES`].
+type Names = (&'static str, [&'static str; 7]);
+
+/// Units by the symbol results are written with.
+pub(super) const UNITS: &[Names] = &[
+    (
+        "milliseconds",
+        [
+            "millisecond",
+            "миллисекунда",
+            "毫秒",
+            "मिलीसेकंड",
+            "ميلي ثانية",
+            "Millisekunde",
+            "milliseconde",
+        ],
+    ),
+    (
+        "seconds",
+        [
+            "second",
+            "секунда",
+            "秒",
+            "सेकंड",
+            "ثانية",
+            "Sekunde",
+            "seconde",
+        ],
+    ),
+    (
+        "minutes",
+        [
+            "minute",
+            "минута",
+            "分钟",
+            "मिनट",
+            "دقيقة",
+            "Minute",
+            "minute",
+        ],
+    ),
+    (
+        "hours",
+        ["hour", "час", "小时", "घंटा", "ساعة", "Stunde", "heure"],
+    ),
+    ("days", ["day", "день", "天", "दिन", "يوم", "Tag", "jour"]),
+    (
+        "weeks",
+        ["week", "неделя", "周", "सप्ताह", "أسبوع", "Woche", "semaine"],
+    ),
+    (
+        "months",
+        ["month", "месяц", "月", "महीना", "شهر", "Monat", "mois"],
+    ),
+    (
+        "years",
+        ["year", "год", "年", "वर्ष", "سنة", "Jahr", "année"],
+    ),
+    ("b", ["bit", "бит", "比特", "बिट", "بت", "Bit", "bit"]),
+    (
+        "Kb",
+        [
+            "kilobit",
+            "килобит",
+            "千比特",
+            "किलोबिट",
+            "كيلوبت",
+            "Kilobit",
+            "kilobit",
+        ],
+    ),
+    (
+        "Mb",
+        [
+            "megabit",
+            "мегабит",
+            "兆比特",
+            "मेगाबिट",
+            "ميغابت",
+            "Megabit",
+            "mégabit",
+        ],
+    ),
+    (
+        "Gb",
+        [
+            "gigabit",
+            "гигабит",
+            "吉比特",
+            "गीगाबिट",
+            "جيجابت",
+            "Gigabit",
+            "gigabit",
+        ],
+    ),
+    (
+        "Tb",
+        [
+            "terabit",
+            "терабит",
+            "太比特",
+            "टेराबिट",
+            "تيرابت",
+            "Terabit",
+            "térabit",
+        ],
+    ),
+    (
+        "Pb",
+        [
+            "petabit",
+            "петабит",
+            "拍比特",
+            "पेटाबिट",
+            "بيتابت",
+            "Petabit",
+            "pétabit",
+        ],
+    ),
+    (
+        "Kib",
+        [
+            "kibibit",
+            "кибибит",
+            "基比比特",
+            "किबिबिट",
+            "كيبيبت",
+            "Kibibit",
+            "kibibit",
+        ],
+    ),
+    (
+        "Mib",
+        [
+            "mebibit",
+            "мебибит",
+            "梅比比特",
+            "मेबिबिट",
+            "ميبيبت",
+            "Mebibit",
+            "mébibit",
+        ],
+    ),
+    (
+        "Gib",
+        [
+            "gibibit",
+            "гибибит",
+            "吉比比特",
+            "गिबिबिट",
+            "جيبيبت",
+            "Gibibit",
+            "gibibit",
+        ],
+    ),
+    (
+        "Tib",
+        [
+            "tebibit",
+            "тебибит",
+            "太比比特",
+            "टेबिबिट",
+            "تيبيبت",
+            "Tebibit",
+            "tébibit",
+        ],
+    ),
+    (
+        "Pib",
+        [
+            "pebibit",
+            "пебибит",
+            "拍比比特",
+            "पेबिबिट",
+            "بيبيبت",
+            "Pebibit",
+            "pébibit",
+        ],
+    ),
+    (
+        "B",
+        ["byte", "байт", "字节", "बाइट", "بايت", "Byte", "octet"],
+    ),
+    (
+        "KB",
+        [
+            "kilobyte",
+            "килобайт",
+            "千字节",
+            "किलोबाइट",
+            "كيلوبايت",
+            "Kilobyte",
+            "kilooctet",
+        ],
+    ),
+    (
+        "MB",
+        [
+            "megabyte",
+            "мегабайт",
+            "兆字节",
+            "मेगाबाइट",
+            "ميغابايت",
+            "Megabyte",
+            "mégaoctet",
+        ],
+    ),
+    (
+        "GB",
+        [
+            "gigabyte",
+            "гигабайт",
+            "吉字节",
+            "गीगाबाइट",
+            "جيجابايت",
+            "Gigabyte",
+            "gigaoctet",
+        ],
+    ),
+    (
+        "TB",
+        [
+            "terabyte",
+            "терабайт",
+            "太字节",
+            "टेराबाइट",
+            "تيرابايت",
+            "Terabyte",
+            "téraoctet",
+        ],
+    ),
+    (
+        "PB",
+        [
+            "petabyte",
+            "петабайт",
+            "拍字节",
+            "पेटाबाइट",
+            "بيتابايت",
+            "Petabyte",
+            "pétaoctet",
+        ],
+    ),
+    (
+        "KiB",
+        [
+            "kibibyte",
+            "кибибайт",
+            "基比字节",
+            "किबिबाइट",
+            "كيبيبايت",
+            "Kibibyte",
+            "kibioctet",
+        ],
+    ),
+    (
+        "MiB",
+        [
+            "mebibyte",
+            "мебибайт",
+            "梅比字节",
+            "मेबिबाइट",
+            "ميبيبايت",
+            "Mebibyte",
+            "mébioctet",
+        ],
+    ),
+    (
+        "GiB",
+        [
+            "gibibyte",
+            "гибибайт",
+            "吉比字节",
+            "गिबिबाइट",
+            "جيبيبايت",
+            "Gibibyte",
+            "gibioctet",
+        ],
+    ),
+    (
+        "TiB",
+        [
+            "tebibyte",
+            "тебибайт",
+            "太比字节",
+            "टेबिबाइट",
+            "تيبيبايت",
+            "Tebibyte",
+            "tébioctet",
+        ],
+    ),
+    (
+        "PiB",
+        [
+            "pebibyte",
+            "пебибайт",
+            "拍比字节",
+            "पेबिबाइट",
+            "بيبيبايت",
+            "Pebibyte",
+            "pébioctet",
+        ],
+    ),
+    (
+        "mg",
+        [
+            "milligram",
+            "миллиграмм",
+            "毫克",
+            "मिलीग्राम",
+            "ملليغرام",
+            "Milligramm",
+            "milligramme",
+        ],
+    ),
+    (
+        "g",
+        ["gram", "грамм", "克", "ग्राम", "غرام", "Gramm", "gramme"],
+    ),
+    (
+        "kg",
+        [
+            "kilogram",
+            "килограмм",
+            "千克",
+            "किलोग्राम",
+            "كيلوغرام",
+            "Kilogramm",
+            "kilogramme",
+        ],
+    ),
+    (
+        "t",
+        ["metric ton", "тонна", "吨", "टन", "طن", "Tonne", "tonne"],
+    ),
+    (
+        "lb",
+        ["pound", "фунт", "磅", "पाउंड", "رطل", "Pfund", "livre"],
+    ),
+    (
+        "oz",
+        ["ounce", "унция", "盎司", "औंस", "أونصة", "Unze", "once"],
+    ),
+    (
+        "mm",
+        [
+            "millimeter",
+            "миллиметр",
+            "毫米",
+            "मिलीमीटर",
+            "مليمتر",
+            "Millimeter",
+            "millimètre",
+        ],
+    ),
+    (
+        "cm",
+        [
+            "centimeter",
+            "сантиметр",
+            "厘米",
+            "सेंटीमीटर",
+            "سنتيمتر",
+            "Zentimeter",
+            "centimètre",
+        ],
+    ),
+    (
+        "m",
+        ["meter", "метр", "米", "मीटर", "متر", "Meter", "mètre"],
+    ),
+    (
+        "km",
+        [
+            "kilometer",
+            "километр",
+            "千米",
+            "किलोमीटर",
+            "كيلومتر",
+            "Kilometer",
+            "kilomètre",
+        ],
+    ),
+    (
+        "inch",
+        ["inch", "дюйм", "英寸", "इंच", "بوصة", "Zoll", "pouce"],
+    ),
+    ("ft", ["foot", "фут", "英尺", "फुट", "قدم", "Fuß", "pied"]),
+    ("yd", ["yard", "ярд", "码", "गज", "ياردة", "Yard", "yard"]),
+    (
+        "mi",
+        ["mile", "миля", "英里", "मील", "ميل", "Meile", "mile"],
+    ),
+    (
+        "ml",
+        [
+            "milliliter",
+            "миллилитр",
+            "毫升",
+            "मिलीलीटर",
+            "مليلتر",
+            "Milliliter",
+            "millilitre",
+        ],
+    ),
+    (
+        "L",
+        ["liter", "литр", "升", "लीटर", "لتر", "Liter", "litre"],
+    ),
+    (
+        "gal",
+        [
+            "US gallon",
+            "галлон США",
+            "美制加仑",
+            "अमेरिकी गैलन",
+            "غالون أمريكي",
+            "US-Gallone",
+            "gallon américain",
+        ],
+    ),
+    (
+        "rad",
+        [
+            "radian",
+            "радиан",
+            "弧度",
+            "रेडियन",
+            "راديان",
+            "Radiant",
+            "radian",
+        ],
+    ),
+    (
+        "°",
+        ["degree", "градус", "度", "डिग्री", "درجة", "Grad", "degré"],
+    ),
+    (
+        "grad",
+        [
+            "gradian",
+            "град",
+            "百分度",
+            "ग्रेडियन",
+            "غراد",
+            "Gon",
+            "grade",
+        ],
+    ),
+    (
+        "'",
+        [
+            "arcminute",
+            "угловая минута",
+            "角分",
+            "चाप मिनट",
+            "دقيقة قوسية",
+            "Winkelminute",
+            "minute d'arc",
+        ],
+    ),
+    (
+        "\"",
+        [
+            "arcsecond",
+            "угловая секунда",
+            "角秒",
+            "चाप सेकंड",
+            "ثانية قوسية",
+            "Winkelsekunde",
+            "seconde d'arc",
+        ],
+    ),
+    (
+        "dms",
+        [
+            "degrees, minutes and seconds",
+            "градусы, минуты и секунды",
+            "度分秒",
+            "डिग्री, मिनट और सेकंड",
+            "درجات ودقائق وثوانٍ",
+            "Grad, Minuten und Sekunden",
+            "degrés, minutes et secondes",
+        ],
+    ),
+    (
+        "A",
+        [
+            "ampere",
+            "ампер",
+            "安培",
+            "एम्पियर",
+            "أمبير",
+            "Ampere",
+            "ampère",
+        ],
+    ),
+    (
+        "V",
+        ["volt", "вольт", "伏特", "वोल्ट", "فولت", "Volt", "volt"],
+    ),
+    ("Ω", ["ohm", "ом", "欧姆", "ओम", "أوم", "Ohm", "ohm"]),
+    ("W", ["watt", "ватт", "瓦特", "वाट", "واط", "Watt", "watt"]),
+    (
+        "N",
+        [
+            "newton",
+            "ньютон",
+            "牛顿",
+            "न्यूटन",
+            "نيوتن",
+            "Newton",
+            "newton",
+        ],
+    ),
+    (
+        "J",
+        ["joule", "джоуль", "焦耳", "जूल", "جول", "Joule", "joule"],
+    ),
+    (
+        "m/s",
+        [
+            "meter per second",
+            "метр в секунду",
+            "米每秒",
+            "मीटर प्रति सेकंड",
+            "متر في الثانية",
+            "Meter pro Sekunde",
+            "mètre par seconde",
+        ],
+    ),
+    (
+        "km/h",
+        [
+            "kilometer per hour",
+            "километр в час",
+            "千米每小时",
+            "किलोमीटर प्रति घंटा",
+            "كيلومتر في الساعة",
+            "Kilometer pro Stunde",
+            "kilomètre par heure",
+        ],
+    ),
+    (
+        "mi/h",
+        [
+            "mile per hour",
+            "миля в час",
+            "英里每小时",
+            "मील प्रति घंटा",
+            "ميل في الساعة",
+            "Meile pro Stunde",
+            "mille par heure",
+        ],
+    ),
+    (
+        "mi/gal",
+        [
+            "mile per gallon",
+            "миля на галлон",
+            "英里每加仑",
+            "मील प्रति गैलन",
+            "ميل لكل غالون",
+            "Meile pro Gallone",
+            "mille par gallon",
+        ],
+    ),
+    (
+        "m/s²",
+        [
+            "meter per second squared",
+            "метр на секунду в квадрате",
+            "米每二次方秒",
+            "मीटर प्रति सेकंड वर्ग",
+            "متر لكل ثانية مربعة",
+            "Meter pro Quadratsekunde",
+            "mètre par seconde carrée",
+        ],
+    ),
+];
+
+/// Currencies by ISO 4217 code.
+pub(super) const CURRENCIES: &[Names] = &[
+    (
+        "USD",
+        [
+            "US dollar",
+            "доллар США",
+            "美元",
+            "अमेरिकी डॉलर",
+            "دولار أمريكي",
+            "US-Dollar",
+            "dollar américain",
+        ],
+    ),
+    (
+        "EUR",
+        ["euro", "евро", "欧元", "यूरो", "يورو", "Euro", "euro"],
+    ),
+    (
+        "GBP",
+        [
+            "pound sterling",
+            "фунт стерлингов",
+            "英镑",
+            "पाउंड स्टर्लिंग",
+            "جنيه إسترليني",
+            "Pfund Sterling",
+            "livre sterling",
+        ],
+    ),
+    (
+        "JPY",
+        [
+            "Japanese yen",
+            "японская иена",
+            "日元",
+            "जापानी येन",
+            "ين ياباني",
+            "Japanischer Yen",
+            "yen japonais",
+        ],
+    ),
+    (
+        "CHF",
+        [
+            "Swiss franc",
+            "швейцарский франк",
+            "瑞士法郎",
+            "स्विस फ़्रैंक",
+            "فرنك سويسري",
+            "Schweizer Franken",
+            "franc suisse",
+        ],
+    ),
+    (
+        "CNY",
+        [
+            "Chinese yuan",
+            "китайский юань",
+            "人民币",
+            "चीनी युआन",
+            "يوان صيني",
+            "Chinesischer Yuan",
+            "yuan chinois",
+        ],
+    ),
+    (
+        "RUB",
+        [
+            "Russian ruble",
+            "российский рубль",
+            "俄罗斯卢布",
+            "रूसी रूबल",
+            "روبل روسي",
+            "Russischer Rubel",
+            "rouble russe",
+        ],
+    ),
+    (
+        "INR",
+        [
+            "Indian rupee",
+            "индийская рупия",
+            "印度卢比",
+            "भारतीय रुपया",
+            "روبية هندية",
+            "Indische Rupie",
+            "roupie indienne",
+        ],
+    ),
+    (
+        "CLF",
+        [
+            "Chilean Unidad de Fomento",
+            "чилийская расчётная единица",
+            "智利发展单位",
+            "चिली यूनिदाद दे फ़ोमेंतो",
+            "وحدة الحساب التشيلية",
+            "Chilenische Unidad de Fomento",
+            "unité de compte chilienne",
+        ],
+    ),
+    (
+        "KZT",
+        [
+            "Kazakhstani tenge",
+            "казахстанский тенге",
+            "哈萨克斯坦坚戈",
+            "कज़ाख़स्तानी तेंगे",
+            "تينغ كازاخستاني",
+            "Kasachischer Tenge",
+            "tenge kazakh",
+        ],
+    ),
+];
+
+/// Operators by what they do.
+pub(super) const OPERATORS: &[Names] = &[
+    (
+        "add",
+        [
+            "addition",
+            "сложение",
+            "加法",
+            "जोड़",
+            "جمع",
+            "Addition",
+            "addition",
+        ],
+    ),
+    (
+        "subtract",
+        [
+            "subtraction",
+            "вычитание",
+            "减法",
+            "घटाव",
+            "طرح",
+            "Subtraktion",
+            "soustraction",
+        ],
+    ),
+    (
+        "multiply",
+        [
+            "multiplication",
+            "умножение",
+            "乘法",
+            "गुणा",
+            "ضرب",
+            "Multiplikation",
+            "multiplication",
+        ],
+    ),
+    (
+        "divide",
+        [
+            "division",
+            "деление",
+            "除法",
+            "भाग",
+            "قسمة",
+            "Division",
+            "division",
+        ],
+    ),
+    (
+        "modulo",
+        [
+            "remainder",
+            "остаток от деления",
+            "取余",
+            "शेषफल",
+            "باقي القسمة",
+            "Rest",
+            "reste",
+        ],
+    ),
+    (
+        "power",
+        [
+            "power",
+            "возведение в степень",
+            "乘方",
+            "घात",
+            "أس",
+            "Potenz",
+            "puissance",
+        ],
+    ),
+    (
+        "negate",
+        [
+            "negation",
+            "смена знака",
+            "取负",
+            "ऋणात्मक",
+            "سالب",
+            "Negation",
+            "opposé",
+        ],
+    ),
+    (
+        "percent",
+        [
+            "percent",
+            "процент",
+            "百分比",
+            "प्रतिशत",
+            "نسبة مئوية",
+            "Prozent",
+            "pourcentage",
+        ],
+    ),
+    (
+        "factorial",
+        [
+            "factorial",
+            "факториал",
+            "阶乘",
+            "क्रमगुणित",
+            "مضروب",
+            "Fakultät",
+            "factorielle",
+        ],
+    ),
+    (
+        "equal",
+        [
+            "equal to",
+            "равно",
+            "等于",
+            "बराबर",
+            "يساوي",
+            "gleich",
+            "égal à",
+        ],
+    ),
+    (
+        "not_equal",
+        [
+            "not equal to",
+            "не равно",
+            "不等于",
+            "बराबर नहीं",
+            "لا يساوي",
+            "ungleich",
+            "différent de",
+        ],
+    ),
+    (
+        "less",
+        [
+            "less than",
+            "меньше",
+            "小于",
+            "से कम",
+            "أصغر من",
+            "kleiner als",
+            "inférieur à",
+        ],
+    ),
+    (
+        "less_or_equal",
+        [
+            "at most",
+            "меньше или равно",
+            "小于等于",
+            "से कम या बराबर",
+            "أصغر من أو يساوي",
+            "kleiner oder gleich",
+            "inférieur ou égal à",
+        ],
+    ),
+    (
+        "greater",
+        [
+            "greater than",
+            "больше",
+            "大于",
+            "से अधिक",
+            "أكبر من",
+            "größer als",
+            "supérieur à",
+        ],
+    ),
+    (
+        "greater_or_equal",
+        [
+            "at least",
+            "больше или равно",
+            "大于等于",
+            "से अधिक या बराबर",
+            "أكبر من أو يساوي",
+            "größer oder gleich",
+            "supérieur ou égal à",
+        ],
+    ),
+    (
+        "convert",
+        [
+            "conversion",
+            "перевод",
+            "换算",
+            "रूपांतरण",
+            "تحويل",
+            "Umrechnung",
+            "conversion",
+        ],
+    ),
+    (
+        "at",
+        [
+            "at a date",
+            "на дату",
+            "在某日期",
+            "तिथि पर",
+            "في تاريخ",
+            "zum Datum",
+            "à la date",
+        ],
+    ),
+];
+
+/// Date examples written with the month names of each language.
+pub(super) const DATES: &[Names] = &[(
+    "day_month_year",
+    [
+        "22 January 2026",
+        "22 января 2026",
+        "22 一月 2026",
+        "22 जनवरी 2026",
+        "22 يناير 2026",
+        "22 Januar 2026",
+        "22 janvier 2026",
+    ],
+)];
+
+/// Returns the name of `key` in `table` in the language of `language`,
+/// falling back to English for other languages.
+pub(super) fn lookup(table: &[Names], key: &str, language: &str) -> Option<&'static str> {
+    let column = LANGUAGES
+        .iter()
+        .position(|code| *code == language)
+        .unwrap_or(0);
+    table
+        .iter()
+        .find(|(known, _)| *known == key)
+        .map(|(_, names)| names[column])
+}
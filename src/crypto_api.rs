@@ -19,11 +19,16 @@
 // Allow futures that are not Send, as these are WASM-only functions running in a single-threaded context
 #![allow(clippy::future_not_send)]
 
+#[cfg(feature = "wasm")]
 use std::collections::HashMap;
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
+#[cfg(feature = "wasm")]
 use wasm_bindgen_futures::JsFuture;
+#[cfg(feature = "wasm")]
 use web_sys::{Request, RequestInit, RequestMode, Response};
 
+#[cfg(feature = "wasm")]
 use crate::types::ExchangeRateInfo;
 
 /// The API source identifier for rates fetched from CoinGecko.
@@ -86,6 +91,7 @@ impl std::fmt::Display for CryptoApiError {
 ///
 /// # Returns
 /// An `ExchangeRateInfo` with the current price (1 [ticker] = price [vs_currency])
+#[cfg(feature = "wasm")]
 pub async fn fetch_crypto_price(
     ticker: &str,
     vs_currency: &str,
@@ -114,6 +120,7 @@ pub async fn fetch_crypto_price(
 ///
 /// # Returns
 /// A map from ticker → `ExchangeRateInfo`
+#[cfg(feature = "wasm")]
 pub async fn fetch_crypto_prices(
     tickers: &[&str],
     vs_currency: &str,
@@ -165,6 +172,7 @@ pub async fn fetch_crypto_prices(
 }
 
 /// Fetches a single coin price from CoinGecko.
+#[cfg(feature = "wasm")]
 async fn fetch_coingecko_price(
     url: &str,
     coin_id: &str,
@@ -188,6 +196,7 @@ async fn fetch_coingecko_price(
 }
 
 /// Performs an HTTP GET request and parses the response as JSON.
+#[cfg(feature = "wasm")]
 async fn fetch_json_value(url: &str) -> Result<serde_json::Value, CryptoApiError> {
     let opts = RequestInit::new();
     opts.set_method("GET");
@@ -17,8 +17,11 @@
 #![allow(clippy::future_not_send)]
 
 use std::collections::HashMap;
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
+#[cfg(feature = "wasm")]
 use wasm_bindgen_futures::JsFuture;
+#[cfg(feature = "wasm")]
 use web_sys::{Request, RequestInit, RequestMode, Response};
 
 use crate::types::ExchangeRateInfo;
@@ -30,10 +33,12 @@ pub const API_SOURCE: &str = "frankfurter.dev (ECB)";
 pub const CBR_API_SOURCE: &str = "cbr.ru (Central Bank of Russia)";
 
 /// Primary URL for the Frankfurter API (European Central Bank data).
+#[cfg(feature = "wasm")]
 const FRANKFURTER_URL: &str = "https://api.frankfurter.app";
 
 /// URL for the Central Bank of Russia daily exchange rates (XML API).
 /// Returns all currency rates relative to RUB for the current business day.
+#[cfg(feature = "wasm")]
 const CBR_DAILY_URL: &str = "https://www.cbr.ru/scripts/XML_daily.asp";
 
 /// Response from the Frankfurter API.
@@ -84,6 +89,7 @@ impl std::fmt::Display for CurrencyApiError {
 /// # Note
 /// The ECB publishes rates daily at around 16:00 CET.
 /// RUB is not available through ECB - use historical .lino files for RUB rates.
+#[cfg(feature = "wasm")]
 pub async fn fetch_current_rates(
     base_currency: &str,
 ) -> Result<(String, HashMap<String, f64>), CurrencyApiError> {
@@ -104,6 +110,7 @@ pub async fn fetch_current_rates(
 ///
 /// # Note
 /// RUB is not available through ECB - use historical .lino files for RUB rates.
+#[cfg(feature = "wasm")]
 pub async fn fetch_historical_rates(
     base_currency: &str,
     date: &str,
@@ -115,6 +122,7 @@ pub async fn fetch_historical_rates(
 }
 
 /// Fetches rates from the Frankfurter API URL.
+#[cfg(feature = "wasm")]
 async fn fetch_rates_from_url(
     url: &str,
 ) -> Result<(String, HashMap<String, f64>), CurrencyApiError> {
@@ -123,6 +131,7 @@ async fn fetch_rates_from_url(
 
 /// Performs the actual fetch and JSON parsing for the Frankfurter API.
 /// Works in both Window and Web Worker contexts.
+#[cfg(feature = "wasm")]
 async fn fetch_json(url: &str) -> Result<(String, HashMap<String, f64>), CurrencyApiError> {
     let opts = RequestInit::new();
     opts.set_method("GET");
@@ -207,6 +216,7 @@ pub fn rates_to_exchange_info(
 }
 
 /// Fetches a single exchange rate.
+#[cfg(feature = "wasm")]
 pub async fn fetch_rate(from: &str, to: &str) -> Result<ExchangeRateInfo, CurrencyApiError> {
     let (date, rates) = fetch_current_rates(from).await?;
     let to_lower = to.to_lowercase();
@@ -233,6 +243,7 @@ pub async fn fetch_rate(from: &str, to: &str) -> Result<ExchangeRateInfo, Curren
 /// A tuple of (date_string, rates_map) where:
 /// - `date_string` is in YYYY-MM-DD format
 /// - `rates_map` maps lowercase currency code → rate (1 unit of that currency in RUB)
+#[cfg(feature = "wasm")]
 pub async fn fetch_cbr_rates() -> Result<(String, HashMap<String, f64>), CurrencyApiError> {
     let opts = RequestInit::new();
     opts.set_method("GET");
@@ -1,6 +1,6 @@
 //! Error types for the Link Calculator.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 /// Main error type for calculator operations.
@@ -10,6 +10,10 @@ pub enum CalculatorError {
     #[error("Parse error: {0}")]
     ParseError(String),
 
+    /// Error parsing the input expression at a known location.
+    #[error("Parse error: {message}")]
+    ParseErrorAt { message: String, span: ErrorSpan },
+
     /// Error with an unexpected token during parsing.
     #[error("Unexpected token '{found}' at position {position}, expected {expected}")]
     UnexpectedToken {
@@ -74,10 +78,18 @@ pub enum CalculatorError {
     #[error("Invalid arguments for function '{function}': {reason}")]
     InvalidFunctionArgs { function: String, reason: String },
 
+    /// A configured evaluation limit was exceeded (see [`crate::types::EvalConfig`]).
+    #[error("Evaluation limit exceeded: {0}")]
+    LimitExceeded(String),
+
     /// Domain error (e.g., sqrt of negative number, log of non-positive).
     #[error("Domain error: {0}")]
     DomainError(String),
 
+    /// The host cancelled the calculation before it finished.
+    #[error("Calculation cancelled")]
+    Cancelled,
+
     /// Symbolic result (for indefinite integrals and symbolic computation).
     /// This is not really an error but a different type of result that needs special handling.
     #[error("{result}")]
@@ -93,6 +105,26 @@ pub enum CalculatorError {
     },
 }
 
+/// A range of the input, in characters, that an error refers to.
+///
+/// `end` is exclusive; an empty range (`start == end`) points between two
+/// characters, e.g. at the end of the input when more was expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ErrorSpan {
+    /// Index of the first offending character.
+    pub start: usize,
+    /// Index one past the last offending character.
+    pub end: usize,
+}
+
+impl ErrorSpan {
+    /// Creates a span covering `start..end`.
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
 /// Error information for i18n support.
 ///
 /// This struct contains all the information needed to translate an error
@@ -103,7 +135,7 @@ pub struct ErrorInfo {
     pub key: String,
     /// Parameters for interpolation in the translated message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub params: Option<HashMap<String, String>>,
+    pub params: Option<BTreeMap<String, String>>,
 }
 
 impl ErrorInfo {
@@ -118,7 +150,7 @@ impl ErrorInfo {
 
     /// Creates a new error info with key and parameters.
     #[must_use]
-    pub fn with_params(key: impl Into<String>, params: HashMap<String, String>) -> Self {
+    pub fn with_params(key: impl Into<String>, params: BTreeMap<String, String>) -> Self {
         Self {
             key: key.into(),
             params: Some(params),
@@ -134,8 +166,8 @@ impl CalculatorError {
     #[must_use]
     pub fn to_error_info(&self) -> ErrorInfo {
         match self {
-            Self::ParseError(msg) => {
-                let mut params = HashMap::new();
+            Self::ParseError(msg) | Self::ParseErrorAt { message: msg, .. } => {
+                let mut params = BTreeMap::new();
                 params.insert("message".to_string(), msg.clone());
                 ErrorInfo::with_params("errors.parseError", params)
             }
@@ -144,7 +176,7 @@ impl CalculatorError {
                 expected,
                 position,
             } => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("found".to_string(), found.clone());
                 params.insert("expected".to_string(), expected.clone());
                 params.insert("position".to_string(), position.to_string());
@@ -155,67 +187,73 @@ impl CalculatorError {
                 left_unit,
                 right_unit,
             } => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("operation".to_string(), operation.clone());
                 params.insert("leftUnit".to_string(), left_unit.clone());
                 params.insert("rightUnit".to_string(), right_unit.clone());
                 ErrorInfo::with_params("errors.unitMismatch", params)
             }
             Self::EvaluationError(msg) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("message".to_string(), msg.clone());
                 ErrorInfo::with_params("errors.evaluationError", params)
             }
             Self::DivisionByZero => ErrorInfo::new("errors.divisionByZero"),
             Self::InvalidDateTime(format) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("format".to_string(), format.clone());
                 ErrorInfo::with_params("errors.invalidDateTime", params)
             }
             Self::UnknownCurrency(currency) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("currency".to_string(), currency.clone());
                 ErrorInfo::with_params("errors.unknownCurrency", params)
             }
             Self::CurrencyConversion { from, to, reason } => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("from".to_string(), from.clone());
                 params.insert("to".to_string(), to.clone());
                 params.insert("reason".to_string(), reason.clone());
                 ErrorInfo::with_params("errors.currencyConversion", params)
             }
             Self::NoHistoricalRate { currency, date } => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("currency".to_string(), currency.clone());
                 params.insert("date".to_string(), date.clone());
                 ErrorInfo::with_params("errors.noHistoricalRate", params)
             }
             Self::Overflow => ErrorInfo::new("errors.overflow"),
             Self::InvalidOperation(msg) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("message".to_string(), msg.clone());
                 ErrorInfo::with_params("errors.invalidOperation", params)
             }
             Self::EmptyInput => ErrorInfo::new("errors.emptyInput"),
             Self::UnknownFunction(name) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("name".to_string(), name.clone());
                 ErrorInfo::with_params("errors.unknownFunction", params)
             }
             Self::InvalidFunctionArgs { function, reason } => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("function".to_string(), function.clone());
                 params.insert("reason".to_string(), reason.clone());
                 ErrorInfo::with_params("errors.invalidFunctionArgs", params)
             }
+            Self::LimitExceeded(msg) => {
+                let mut params = BTreeMap::new();
+                params.insert("message".to_string(), msg.clone());
+                ErrorInfo::with_params("errors.limitExceeded", params)
+            }
             Self::DomainError(msg) => {
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("message".to_string(), msg.clone());
                 ErrorInfo::with_params("errors.domainError", params)
             }
+            Self::Cancelled => ErrorInfo::new("errors.cancelled"),
             Self::SymbolicResult { result, .. } => {
                 // SymbolicResult is not really an error, but we provide info for consistency
-                let mut params = HashMap::new();
+                let mut params = BTreeMap::new();
                 params.insert("result".to_string(), result.clone());
                 ErrorInfo::with_params("result.symbolic", params)
             }
@@ -229,6 +267,38 @@ impl CalculatorError {
         Self::ParseError(msg.into())
     }
 
+    /// Creates a parse error pointing at the characters `start..end` of the input.
+    pub fn parse_at(msg: impl Into<String>, start: usize, end: usize) -> Self {
+        Self::ParseErrorAt {
+            message: msg.into(),
+            span: ErrorSpan::new(start, end),
+        }
+    }
+
+    /// Returns the part of the input this error points at, if known.
+    #[must_use]
+    pub fn span(&self) -> Option<ErrorSpan> {
+        match self {
+            Self::ParseErrorAt { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+
+    /// Moves the span of this error `offset` characters to the right.
+    ///
+    /// Used when the parsed text starts part-way into the original input,
+    /// e.g. after leading whitespace has been trimmed.
+    #[must_use]
+    pub fn offset_span(self, offset: usize) -> Self {
+        match self {
+            Self::ParseErrorAt { message, span } => Self::ParseErrorAt {
+                message,
+                span: ErrorSpan::new(span.start + offset, span.end + offset),
+            },
+            other => other,
+        }
+    }
+
     /// Creates an unexpected token error.
     #[must_use]
     pub fn unexpected_token(found: &str, expected: &str, position: usize) -> Self {
@@ -283,6 +353,15 @@ mod tests {
         assert_eq!(err.to_string(), "Parse error: invalid syntax");
     }
 
+    #[test]
+    fn test_parse_error_span() {
+        let err = CalculatorError::parse_at("bad token", 4, 7);
+        assert_eq!(err.to_string(), "Parse error: bad token");
+        assert_eq!(err.span(), Some(ErrorSpan::new(4, 7)));
+        assert_eq!(err.offset_span(2).span(), Some(ErrorSpan::new(6, 9)));
+        assert_eq!(CalculatorError::parse("no span").span(), None);
+    }
+
     #[test]
     fn test_unexpected_token() {
         let err = CalculatorError::unexpected_token("+", "number", 5);
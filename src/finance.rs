@@ -0,0 +1,106 @@
+//! Financial helpers on the [`Calculator`]: the default VAT rate, result
+//! breakdowns and amortization schedules.
+
+use crate::grammar::Amortization;
+use crate::types::{Decimal, Expression, FormatOptions, Rational};
+use crate::{BreakdownItem, CalculationResult, Calculator, ResultTable};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Sets the VAT rate, in percent, used when `+ VAT` or `gross ... at VAT`
+    /// is written without one.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_default_vat_rate(&mut self, percent: f64) {
+        let rate = Rational::from_f64(percent) / Rational::from_integer(100);
+        self.parser.set_default_vat_rate(Some(rate));
+    }
+
+    /// Clears the default VAT rate, so VAT must be given with a rate.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_default_vat_rate(&mut self) {
+        self.parser.set_default_vat_rate(None);
+    }
+}
+
+impl Calculator {
+    /// Formats the labelled parts of the last result, if it has any.
+    pub(super) fn breakdown_items(&self, options: &FormatOptions) -> Option<Vec<BreakdownItem>> {
+        let breakdown = self.parser.breakdown();
+        if breakdown.is_empty() {
+            return None;
+        }
+        Some(
+            breakdown
+                .iter()
+                .map(|(label, value)| BreakdownItem {
+                    label: label.clone(),
+                    value: self.display_value(value, options),
+                })
+                .collect(),
+        )
+    }
+
+    /// Adds the period-by-period schedule of a top-level `amortize(...)` call
+    /// to `result` as a table, and replaces the result with a summary of the
+    /// payment and totals.
+    pub(super) fn attach_amortization_schedule(
+        &mut self,
+        expr: &Expression,
+        result: &mut CalculationResult,
+    ) {
+        let mut call = expr;
+        while let Expression::Group(inner) | Expression::WithPrecision { value: inner, .. } = call {
+            call = inner;
+        }
+        let Expression::FunctionCall { name, args } = call else {
+            return;
+        };
+        if !name.eq_ignore_ascii_case("amortize") {
+            return;
+        }
+        let Ok(values) = args
+            .iter()
+            .map(|arg| self.parser.evaluate_expr(arg))
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return;
+        };
+        let Ok(schedule) = Amortization::schedule(&values) else {
+            return;
+        };
+
+        let options = self.display_options(Some(expr));
+        let show = |amount: Decimal| schedule.amount(amount).to_display_string_with(&options);
+        result.result = format!(
+            "{} per month; total paid {}; total interest {}",
+            show(schedule.payment),
+            show(schedule.total_paid),
+            show(schedule.total_interest)
+        );
+        result.table = Some(schedule_table(&schedule, &options));
+    }
+}
+
+fn schedule_table(schedule: &Amortization, options: &FormatOptions) -> ResultTable {
+    let show = |amount: Decimal| schedule.amount(amount).to_display_string_with(options);
+    ResultTable {
+        columns: ["Period", "Payment", "Interest", "Principal", "Balance"]
+            .map(String::from)
+            .to_vec(),
+        rows: schedule
+            .periods
+            .iter()
+            .map(|period| {
+                vec![
+                    period.period.to_string(),
+                    show(period.payment),
+                    show(period.interest),
+                    show(period.principal),
+                    show(period.balance),
+                ]
+            })
+            .collect(),
+    }
+}
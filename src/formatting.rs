@@ -0,0 +1,126 @@
+//! Number formatting preferences on the [`Calculator`].
+
+use crate::grammar::si_prefixed;
+use crate::types::{Expression, FormatOptions, RoundingMode, Unit, Value};
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Sets number formatting preferences from a JSON object, e.g.
+    /// `{"group_separator": ",", "decimal_separator": ".", "max_fraction_digits": 4}`
+    /// or `{"datetime_format": "long"}`.
+    ///
+    /// Missing fields keep their defaults. Returns `false` (leaving the current
+    /// preferences untouched) if the JSON cannot be parsed.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_format_options(&mut self, options_json: &str) -> bool {
+        match serde_json::from_str::<FormatOptions>(options_json) {
+            Ok(options) => {
+                self.format_options = options;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Uses the digit grouping and decimal separator of a UI locale such as
+    /// `en`, `de` or `ru-RU`. Rounding preferences are reset.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_format_locale(&mut self, locale: &str) {
+        self.format_options = FormatOptions::for_locale(locale);
+    }
+
+    /// Sets how halves are rounded: `half_up` (the default) or `half_even`
+    /// (banker's rounding). Returns false, leaving the mode unchanged, for
+    /// an unknown name.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_rounding_mode(&mut self, mode: &str) -> bool {
+        let Some(mode) = RoundingMode::parse(mode) else {
+            return false;
+        };
+        self.format_options.rounding_mode = mode;
+        true
+    }
+
+    /// Rounds amounts of money in results to the decimals of their currency,
+    /// such as whole yen or cents, as is done by default. The unrounded
+    /// result stays available as `raw_result`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_round_money(&mut self, round_money: bool) {
+        self.format_options.round_money = round_money;
+    }
+
+    /// Writes amounts of volts, amps, ohms, watts, newtons and joules, and
+    /// durations under a tenth of a second, with an SI prefix, such as
+    /// `4.7 kΩ` for `4700 ohm` or `12 µs` for `0.000012 s`, as is done by
+    /// default.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_si_prefixes(&mut self, si_prefixes: bool) {
+        self.format_options.si_prefixes = si_prefixes;
+    }
+
+    /// Shows results of `10^above` or more and below `10^below` in
+    /// scientific notation, such as `1.551121004333099e25` for `25!`; the
+    /// digits stay available among the alternatives. `None` turns a
+    /// threshold off.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_scientific_thresholds(&mut self, above: Option<i32>, below: Option<i32>) {
+        self.format_options.scientific_above = above;
+        self.format_options.scientific_below = below;
+    }
+
+    /// Restores canonical number output (no grouping, `.` decimal point).
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_format_options(&mut self) {
+        self.format_options = FormatOptions::default();
+    }
+}
+
+impl Calculator {
+    /// Returns the number formatting preferences applied to results.
+    #[must_use]
+    pub fn format_options(&self) -> &FormatOptions {
+        &self.format_options
+    }
+
+    /// Returns a mutable borrow of the number formatting preferences.
+    pub fn format_options_mut(&mut self) -> &mut FormatOptions {
+        &mut self.format_options
+    }
+
+    /// Returns the formatting for the result of `expr`: the preferences,
+    /// overridden by a precision directive such as `to 2 dp`. A precision
+    /// or a conversion such as `in mA` keeps the unit the result is in.
+    pub(crate) fn display_options(&self, expr: Option<&Expression>) -> FormatOptions {
+        let mut options = match expr.and_then(Expression::display_precision) {
+            Some(precision) => self.format_options.with_precision(precision),
+            None => self.format_options.clone(),
+        };
+        if matches!(
+            expr,
+            Some(Expression::UnitConversion { .. } | Expression::WithPrecision { .. })
+        ) {
+            options.si_prefixes = false;
+        }
+        options
+    }
+
+    /// Formats `value` for display, rounding an amount of money to the
+    /// decimals of its currency when money is rounded, and writing amounts
+    /// of physics units with an SI prefix when prefixes are on. Amounts in a
+    /// currency of unknown precision are not rounded.
+    pub(crate) fn display_value(&self, value: &Value, options: &FormatOptions) -> String {
+        if let Some(prefixed) = si_prefixed(value).filter(|_| options.si_prefixes) {
+            return prefixed.to_display_string_with(options);
+        }
+        let Unit::Currency(code) = &value.unit else {
+            return value.to_display_string_with(options);
+        };
+        match self.parser.currency_db().decimals(code) {
+            Some(decimals) => value.to_display_string_with(&options.for_money(u32::from(decimals))),
+            None => value.to_display_string_with(options),
+        }
+    }
+}
@@ -0,0 +1,601 @@
+//! The named functions a user can call, with a signature and a short
+//! description of each, so that frontends can offer them as the user types.
+//!
+//! Functions that take bare words as arguments, such as the `male` in
+//! `bmr(male, 30 years, 180 cm, 75 kg)`, are only read that way when they are
+//! registered here.
+
+use crate::types::{Expression, Value};
+#[cfg(feature = "text-tools")]
+use FunctionCategory::Hash;
+use FunctionCategory::{
+    Calculus, Calendar, Color, Encoding, Finance, Health, Math, Matrix, Measurement, Physics,
+    Proportion, Text,
+};
+
+/// The group a [`FunctionInfo`] is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionCategory {
+    /// Trigonometric, logarithmic and rounding functions of numbers.
+    Math,
+    /// Integrals, derivatives, roots, plots and simplification of
+    /// expressions in a variable.
+    Calculus,
+    /// Matrices and their determinants, inverses and transposes.
+    Matrix,
+    /// Percent changes, shares and ratios.
+    Proportion,
+    /// Prices, taxes, tips, discounts, loans and exchange rates.
+    Finance,
+    /// Dates, ages, weekdays and countdowns.
+    Calendar,
+    /// Notation and geometry: SI prefixes, distances between coordinates.
+    Measurement,
+    /// Body measurements: body mass index and basal metabolic rate.
+    Health,
+    /// Physics formulas solved for an unknown quantity.
+    Physics,
+    /// Hex colors: mixing, lightening and building from channels.
+    Color,
+    /// Lengths of quoted text in characters, bytes and words.
+    Text,
+    /// Base64 and URL encodings of quoted text.
+    Encoding,
+    /// Hex digests of quoted text, with the `text-tools` feature.
+    Hash,
+}
+
+/// A function the calculator knows, as offered by
+/// [`Calculator::suggest`](crate::Calculator::suggest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct FunctionInfo {
+    /// The name it is called by.
+    pub name: &'static str,
+    /// How it is called: `bmi(weight, height)`.
+    pub signature: &'static str,
+    /// What it returns.
+    pub description: &'static str,
+    /// The group it is listed under.
+    pub category: FunctionCategory,
+}
+
+const fn function(
+    name: &'static str,
+    signature: &'static str,
+    description: &'static str,
+    category: FunctionCategory,
+) -> FunctionInfo {
+    FunctionInfo {
+        name,
+        signature,
+        description,
+        category,
+    }
+}
+
+/// Every registered function, by category and then by name.
+pub const FUNCTIONS: &[FunctionInfo] = &[
+    function("abs", "abs(x)", "Absolute value", Math),
+    function("acos", "acos(x)", "Inverse cosine", Math),
+    function("asin", "asin(x)", "Inverse sine", Math),
+    function("atan", "atan(x)", "Inverse tangent", Math),
+    function(
+        "atan2",
+        "atan2(y, x)",
+        "Angle of the point (x, y) from the x axis",
+        Math,
+    ),
+    function(
+        "average",
+        "average(a, b, ...)",
+        "Mean of the arguments, or of results: average(#2:#4)",
+        Math,
+    ),
+    function(
+        "beta",
+        "beta(a, b)",
+        "Beta function, gamma(a) gamma(b) / gamma(a + b)",
+        Math,
+    ),
+    function("cbrt", "cbrt(x)", "Cube root", Math),
+    function("ceil", "ceil(x)", "Round up to an integer", Math),
+    function("ci", "Ci(x)", "Cosine integral", Math),
+    function("cos", "cos(x)", "Cosine of an angle in radians", Math),
+    function("cosh", "cosh(x)", "Hyperbolic cosine", Math),
+    function("count", "count(a, b, ...)", "Number of the arguments", Math),
+    function(
+        "deg",
+        "deg(x)",
+        "Angle in radians converted to degrees",
+        Math,
+    ),
+    function("ei", "Ei(x)", "Exponential integral", Math),
+    function("erf", "erf(x)", "Error function", Math),
+    function(
+        "erfc",
+        "erfc(x)",
+        "Complementary error function, 1 - erf(x)",
+        Math,
+    ),
+    function("exp", "exp(x)", "e raised to the power x", Math),
+    function(
+        "factorial",
+        "factorial(n)",
+        "Product of 1 to n, exactly",
+        Math,
+    ),
+    function(
+        "factors",
+        "n with factors",
+        "Prime factors and divisors of an integer result",
+        Math,
+    ),
+    function("floor", "floor(x)", "Round down to an integer", Math),
+    function(
+        "frombase",
+        "frombase(\"digits\", base)",
+        "Integer from its digits in base 2 to 36",
+        Math,
+    ),
+    function(
+        "gamma",
+        "gamma(x)",
+        "Gamma function, (x - 1)! for whole x",
+        Math,
+    ),
+    function(
+        "index",
+        "index(list, position)",
+        "Element of a list, counting from 0",
+        Math,
+    ),
+    function("ln", "ln(x)", "Natural logarithm", Math),
+    function("log", "log(x)", "Logarithm to base 10", Math),
+    function("log10", "log10(x)", "Logarithm to base 10", Math),
+    function("log2", "log2(x)", "Logarithm to base 2", Math),
+    function("max", "max(a, b, ...)", "Largest argument", Math),
+    function("mean", "mean(a, b, ...)", "Mean of the arguments", Math),
+    function(
+        "median",
+        "median(a, b, ...)",
+        "Middle value of the arguments",
+        Math,
+    ),
+    function("min", "min(a, b, ...)", "Smallest argument", Math),
+    function("mode", "mode(a, b, ...)", "Most frequent argument", Math),
+    function("ncr", "ncr(n, k)", "Ways to choose k of n, exactly", Math),
+    function(
+        "pow",
+        "pow(base, exponent)",
+        "base raised to the power exponent, exactly for integers",
+        Math,
+    ),
+    function(
+        "rad",
+        "rad(x)",
+        "Angle in degrees converted to radians",
+        Math,
+    ),
+    function(
+        "round",
+        "round(x, places)",
+        "Round to the nearest integer or to places decimals",
+        Math,
+    ),
+    function(
+        "si",
+        "Si(x)",
+        "Sine integral, the integral of sin(t)/t from 0",
+        Math,
+    ),
+    function("sign", "sign(x)", "-1, 0 or 1 by the sign of x", Math),
+    function("sin", "sin(x)", "Sine of an angle in radians", Math),
+    function("sinh", "sinh(x)", "Hyperbolic sine", Math),
+    function("sqrt", "sqrt(x)", "Square root", Math),
+    function(
+        "stddev",
+        "stddev(a, b, ...)",
+        "Sample standard deviation of the arguments",
+        Math,
+    ),
+    function(
+        "sum",
+        "sum(a, b, ...)",
+        "Total of the arguments, or of results: sum(#1:#5)",
+        Math,
+    ),
+    function("tan", "tan(x)", "Tangent of an angle in radians", Math),
+    function("tanh", "tanh(x)", "Hyperbolic tangent", Math),
+    function(
+        "tobase",
+        "tobase(n, base)",
+        "Digits of an integer in base 2 to 36",
+        Math,
+    ),
+    function("trunc", "trunc(x)", "Drop the fractional part", Math),
+    function(
+        "variance",
+        "variance(a, b, ...)",
+        "Sample variance of the arguments",
+        Math,
+    ),
+    function(
+        "equivalent",
+        "is a equivalent to b",
+        "Whether two expressions in x agree wherever both are defined",
+        Calculus,
+    ),
+    function(
+        "integrate",
+        "integrate(f, x, a, b)",
+        "Definite integral of f from a to b",
+        Calculus,
+    ),
+    function(
+        "nderiv",
+        "nderiv(f, x, at)",
+        "Derivative of f at a point",
+        Calculus,
+    ),
+    function(
+        "plot",
+        "plot f from a to b",
+        "Graph of f over a range of x",
+        Calculus,
+    ),
+    function(
+        "root",
+        "root(f, x, lower, upper)",
+        "Value of x between the bounds where f is 0",
+        Calculus,
+    ),
+    function(
+        "simplify",
+        "simplify f",
+        "Expression collected and reduced",
+        Calculus,
+    ),
+    function(
+        "det",
+        "det(matrix)",
+        "Determinant of a square matrix",
+        Matrix,
+    ),
+    function(
+        "inverse",
+        "inverse(matrix)",
+        "Inverse of a square matrix",
+        Matrix,
+    ),
+    function(
+        "matrix",
+        "matrix(row, row, ...)",
+        "Matrix from its rows: matrix([1, 2], [3, 4])",
+        Matrix,
+    ),
+    function(
+        "transpose",
+        "transpose(matrix)",
+        "Matrix with rows and columns swapped",
+        Matrix,
+    ),
+    function(
+        "percent_change",
+        "percent_change(old, new)",
+        "Change from old to new as a percentage",
+        Proportion,
+    ),
+    function(
+        "percent_of_total",
+        "percent_of_total(part, whole)",
+        "Part as a percentage of the whole",
+        Proportion,
+    ),
+    function(
+        "ratio_split",
+        "ratio_split(total, shares...)",
+        "Total divided in the ratio of the shares",
+        Proportion,
+    ),
+    function(
+        "add_tip",
+        "add_tip(amount, rate)",
+        "Amount with a tip added",
+        Finance,
+    ),
+    function(
+        "add_vat",
+        "add_vat(net, rate)",
+        "Gross price with VAT added, the default rate if none is given",
+        Finance,
+    ),
+    function(
+        "amortize",
+        "amortize(principal, rate, term)",
+        "Monthly payment of a loan at a yearly rate over a term",
+        Finance,
+    ),
+    function(
+        "apply_discounts",
+        "apply_discounts(price, discount, ...)",
+        "Price after discounts taken one after another",
+        Finance,
+    ),
+    function(
+        "apply_percentages",
+        "apply_percentages(price, change, ...)",
+        "Price after percentages added or taken off one after another",
+        Finance,
+    ),
+    function(
+        "better_deal",
+        "better_deal(price1 for amount1, price2 for amount2)",
+        "Which of two offers costs less per unit",
+        Finance,
+    ),
+    function(
+        "original_price",
+        "original_price(price, discount, ...)",
+        "Price before discounts taken one after another",
+        Finance,
+    ),
+    function(
+        "rate_history",
+        "FROM/TO from start to end",
+        "Known exchange rates over a range of dates",
+        Finance,
+    ),
+    function(
+        "remove_vat",
+        "remove_vat(gross, rate)",
+        "Net price with VAT taken out, the default rate if none is given",
+        Finance,
+    ),
+    function(
+        "split_bill",
+        "split_bill(total, people)",
+        "Share of a bill for each person",
+        Finance,
+    ),
+    function(
+        "age",
+        "age(birth date)",
+        "Time since a date in years, months and days",
+        Calendar,
+    ),
+    function(
+        "day_of_week",
+        "day_of_week(date)",
+        "Weekday a date falls on",
+        Calendar,
+    ),
+    function(
+        "days_in_month",
+        "days_in_month(date)",
+        "Number of days in the month of a date",
+        Calendar,
+    ),
+    function(
+        "days_in_year",
+        "days_in_year(year)",
+        "Number of days in a year",
+        Calendar,
+    ),
+    function(
+        "is_leap_year",
+        "is_leap_year(year)",
+        "Whether a year has 366 days",
+        Calendar,
+    ),
+    function(
+        "next_weekday",
+        "next_weekday(\"day\", from)",
+        "Next such day of the week after a date",
+        Calendar,
+    ),
+    function(
+        "time_span",
+        "start to end",
+        "Time between two clock times",
+        Calendar,
+    ),
+    function(
+        "time_until",
+        "time_until(date)",
+        "Time left until a date in years, months and days",
+        Calendar,
+    ),
+    function(
+        "week_number",
+        "week_number(date)",
+        "ISO week number of a date",
+        Calendar,
+    ),
+    function(
+        "weekends_until",
+        "weekends until date",
+        "Number of weekends before a date",
+        Calendar,
+    ),
+    function(
+        "working_hours_until",
+        "working hours until date",
+        "Working hours on weekdays before a date",
+        Calendar,
+    ),
+    function(
+        "eng",
+        "eng(amount)",
+        "Amount written with an SI prefix: 4.7 kΩ",
+        Measurement,
+    ),
+    function(
+        "haversine",
+        "haversine(lat1, lon1, lat2, lon2)",
+        "Great-circle distance between two coordinates in km",
+        Measurement,
+    ),
+    function(
+        "bmi",
+        "bmi(weight, height)",
+        "Body mass index, with its WHO classification",
+        Health,
+    ),
+    function(
+        "bmr",
+        "bmr(male|female, age, height, weight)",
+        "Basal metabolic rate in kcal/day (Mifflin-St Jeor)",
+        Health,
+    ),
+    function(
+        "physics",
+        "physics(unknown, quantity, value, ...)",
+        "Solves V = I * R, P = V * I, F = m * a or E = m * c^2 for the unknown",
+        Physics,
+    ),
+    function(
+        "darken",
+        "darken(color, amount)",
+        "Color with its HSL lightness lowered by a percentage",
+        Color,
+    ),
+    function(
+        "hsl",
+        "hsl(hue, saturation, lightness)",
+        "Color from hue in degrees and percentages",
+        Color,
+    ),
+    function(
+        "lighten",
+        "lighten(color, amount)",
+        "Color with its HSL lightness raised by a percentage",
+        Color,
+    ),
+    function(
+        "mix",
+        "mix(color1, color2, weight)",
+        "Blend taking a percentage of the first color, 50% by default",
+        Color,
+    ),
+    function(
+        "rgb",
+        "rgb(red, green, blue)",
+        "Color from channels from 0 to 255",
+        Color,
+    ),
+    function(
+        "length",
+        "length(\"text\")",
+        "Number of characters in quoted text",
+        Text,
+    ),
+    function(
+        "utf8_bytes",
+        "utf8_bytes(\"text\")",
+        "Size of quoted text in UTF-8 bytes",
+        Text,
+    ),
+    function(
+        "words",
+        "words(\"text\")",
+        "Number of words in quoted text",
+        Text,
+    ),
+    function(
+        "base64",
+        "base64(\"text\")",
+        "Quoted text encoded in base64",
+        Encoding,
+    ),
+    function(
+        "base64decode",
+        "base64decode(\"base64\")",
+        "Text decoded from base64",
+        Encoding,
+    ),
+    function(
+        "urlencode",
+        "urlencode(\"text\")",
+        "Quoted text percent-encoded for a URL",
+        Encoding,
+    ),
+    #[cfg(feature = "text-tools")]
+    function(
+        "crc32",
+        "crc32(\"text\")",
+        "CRC-32 checksum of quoted text in hex",
+        Hash,
+    ),
+    #[cfg(feature = "text-tools")]
+    function(
+        "md5",
+        "md5(\"text\")",
+        "MD5 digest of quoted text in hex",
+        Hash,
+    ),
+    #[cfg(feature = "text-tools")]
+    function(
+        "sha256",
+        "sha256(\"text\")",
+        "SHA-256 digest of quoted text in hex",
+        Hash,
+    ),
+];
+
+/// Other names registered functions are called by, with the name each
+/// stands for.
+pub const FUNCTION_ALIASES: &[(&str, &str)] = &[
+    ("choose", "ncr"),
+    ("degrees", "deg"),
+    ("determinant", "det"),
+    ("inv", "inverse"),
+    ("radians", "rad"),
+    ("signum", "sign"),
+];
+
+/// Returns the registered function called `name`, or by the alias `name`,
+/// ignoring case.
+#[must_use]
+pub fn lookup(name: &str) -> Option<&'static FunctionInfo> {
+    let name = FUNCTION_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, function)| function);
+    FUNCTIONS
+        .iter()
+        .find(|function| function.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the registered functions whose name starts with `prefix`,
+/// ignoring case, in name order.
+#[must_use]
+pub fn functions_starting_with(prefix: &str) -> Vec<&'static FunctionInfo> {
+    let prefix = prefix.to_lowercase();
+    let mut matches: Vec<_> = FUNCTIONS
+        .iter()
+        .filter(|function| function.name.starts_with(&prefix))
+        .collect();
+    matches.sort_by_key(|function| function.name);
+    matches
+}
+
+/// Returns true if `name` is a registered function that takes bare words as
+/// arguments.
+#[must_use]
+pub fn takes_word_arguments(name: &str) -> bool {
+    lookup(name).is_some_and(|function| matches!(function.category, Health | Physics))
+}
+
+/// Reads a bare word argument of a function that takes them, such as the
+/// `male` in `bmr(male, 30 years, 180 cm, 75 kg)`, as text rather than a
+/// variable.
+#[must_use]
+pub fn word_argument(function: &str, arg: &Expression) -> Option<Value> {
+    match arg {
+        Expression::Variable(word) if takes_word_arguments(function) => {
+            Some(Value::text(word.clone()))
+        }
+        _ => None,
+    }
+}
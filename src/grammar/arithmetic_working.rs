@@ -0,0 +1,183 @@
+//! Digit-level working for teaching steps: long multiplication and long
+//! division of whole numbers, and adding fractions over a common
+//! denominator.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+
+use crate::types::{BinaryOp, Rational, Unit, Value};
+
+/// Longest operand, in digits, whose working is written out.
+const MAX_DIGITS: usize = 12;
+
+/// Returns the working of `left op right` as steps, or nothing if the
+/// operation has no written method or the operands are too long to show.
+pub(super) fn working(left: &Value, op: BinaryOp, right: &Value) -> Vec<String> {
+    if left.unit != Unit::None || right.unit != Unit::None {
+        return Vec::new();
+    }
+    let (Some(a), Some(b)) = (left.to_rational(), right.to_rational()) else {
+        return Vec::new();
+    };
+    match op {
+        BinaryOp::Multiply => whole_numbers(&a, &b)
+            .map(|(a, b)| long_multiplication(a, b))
+            .unwrap_or_default(),
+        BinaryOp::Divide => whole_numbers(&a, &b)
+            .filter(|(_, b)| *b != 0)
+            .map(|(a, b)| long_division(a, b))
+            .unwrap_or_default(),
+        BinaryOp::Add | BinaryOp::Subtract if !a.is_integer() || !b.is_integer() => {
+            fraction_sum(&a, op, &b)
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns both numbers as whole numbers of at most [`MAX_DIGITS`] digits.
+fn whole_numbers(a: &Rational, b: &Rational) -> Option<(u128, u128)> {
+    let whole = |n: &Rational| {
+        n.is_integer()
+            .then(|| u128::try_from(n.numer_bigint()).ok())
+            .flatten()
+            .filter(|n| n.to_string().len() <= MAX_DIGITS)
+    };
+    Some((whole(a)?, whole(b)?))
+}
+
+/// Multiplies `a` by each digit of `b` and adds the shifted partial
+/// products.
+fn long_multiplication(a: u128, b: u128) -> Vec<String> {
+    let mut steps = vec![format!("Long multiplication: {a} × {b}")];
+    let digits: Vec<u128> = b
+        .to_string()
+        .bytes()
+        .rev()
+        .map(|digit| u128::from(digit - b'0'))
+        .collect();
+    let mut partials = Vec::new();
+    for (place, digit) in digits.iter().enumerate() {
+        let partial = a * digit;
+        let shifted = partial * 10_u128.pow(place as u32);
+        if place == 0 {
+            steps.push(format!("{a} × {digit} = {partial}"));
+        } else {
+            steps.push(format!(
+                "{a} × {digit} = {partial}, shifted {place} place{}: {shifted}",
+                if place == 1 { "" } else { "s" }
+            ));
+        }
+        partials.push(shifted);
+    }
+    if partials.len() > 1 {
+        let sum: u128 = partials.iter().sum();
+        let terms: Vec<_> = partials.iter().map(u128::to_string).collect();
+        steps.push(format!(
+            "Add the partial products: {} = {sum}",
+            terms.join(" + ")
+        ));
+    }
+    steps
+}
+
+/// Divides `a` by `b` digit by digit, bringing down one digit of the
+/// dividend at a time.
+fn long_division(a: u128, b: u128) -> Vec<String> {
+    let mut steps = vec![format!("Long division: {a} ÷ {b}")];
+    let digits: Vec<u128> = a
+        .to_string()
+        .bytes()
+        .map(|digit| u128::from(digit - b'0'))
+        .collect();
+    let (mut quotient, mut remainder) = (0_u128, 0_u128);
+    let mut started = false;
+    for (index, digit) in digits.iter().enumerate() {
+        let current = remainder * 10 + digit;
+        let q = current / b;
+        remainder = current % b;
+        quotient = quotient * 10 + q;
+        if !started && q == 0 && index + 1 < digits.len() {
+            // Not enough to divide yet; take the next digit too
+            continue;
+        }
+        if started {
+            steps.push(format!(
+                "Bring down {digit}: {current} ÷ {b} = {q}, remainder {remainder}"
+            ));
+        } else {
+            steps.push(format!("{current} ÷ {b} = {q}, remainder {remainder}"));
+            started = true;
+        }
+    }
+    steps.push(format!("Quotient {quotient}, remainder {remainder}"));
+    steps
+}
+
+/// Rewrites two fractions over their least common denominator and adds or
+/// subtracts the numerators.
+fn fraction_sum(a: &Rational, op: BinaryOp, b: &Rational) -> Vec<String> {
+    let (da, db) = (a.denom_bigint(), b.denom_bigint());
+    let common = da.lcm(db);
+    if common.to_string().len() > MAX_DIGITS {
+        return Vec::new();
+    }
+    let scaled = |n: &Rational| n.numer_bigint() * (&common / n.denom_bigint());
+    let (na, nb) = (scaled(a), scaled(b));
+    let numerator: BigInt = if op == BinaryOp::Add {
+        &na + &nb
+    } else {
+        &na - &nb
+    };
+    let mut steps = vec![
+        format!("Common denominator: lcm({da}, {db}) = {common}"),
+        format!(
+            "{} = {na}/{common}, {} = {nb}/{common}",
+            a.to_fraction_string(),
+            b.to_fraction_string()
+        ),
+        format!("{na}/{common} {op} {nb}/{common} = {numerator}/{common}"),
+    ];
+    let result = Rational::new_bigint(numerator.clone(), common.clone());
+    if result.numer_bigint() != &numerator {
+        steps.push(format!(
+            "Simplify: {numerator}/{common} = {}",
+            result.to_fraction_string()
+        ));
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_multiplication() {
+        let steps = long_multiplication(123, 45);
+        assert_eq!(steps[1], "123 × 5 = 615");
+        assert_eq!(steps[2], "123 × 4 = 492, shifted 1 place: 4920");
+        assert_eq!(steps[3], "Add the partial products: 615 + 4920 = 5535");
+    }
+
+    #[test]
+    fn test_long_division() {
+        let steps = long_division(1234, 7);
+        assert_eq!(
+            steps[1..],
+            [
+                "12 ÷ 7 = 1, remainder 5",
+                "Bring down 3: 53 ÷ 7 = 7, remainder 4",
+                "Bring down 4: 44 ÷ 7 = 6, remainder 2",
+                "Quotient 176, remainder 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fraction_sum() {
+        let steps = fraction_sum(&Rational::new(1, 6), BinaryOp::Add, &Rational::new(1, 3));
+        assert_eq!(steps[0], "Common denominator: lcm(6, 3) = 6");
+        assert_eq!(steps[2], "1/6 + 2/6 = 3/6");
+        assert_eq!(steps[3], "Simplify: 3/6 = 1/2");
+    }
+}
@@ -0,0 +1,77 @@
+//! Integers in any base from 2 to 36, with digits past 9 written as the
+//! letters A to Z:
+//! - `tobase(255, 16)`: the digits of an integer, `"FF"`
+//! - `frombase("zz", 36)`: the integer the digits stand for, `1295`
+//!
+//! These complement `0xFF` literals and `255 in hex`, which only cover
+//! bases 2, 8 and 16.
+
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use crate::error::CalculatorError;
+use crate::types::{Rational, Unit, Value, ValueKind};
+
+/// The base conversion functions.
+pub const BASE_FUNCTIONS: &[&str] = &["tobase", "frombase"];
+
+/// Returns true if `name` is `tobase` or `frombase`.
+#[must_use]
+pub fn is_base_function(name: &str) -> bool {
+    BASE_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Evaluates `tobase(n, base)` or `frombase(digits, base)`.
+pub fn evaluate_base_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let [value, base] = args else {
+        let first = if name == "tobase" { "n" } else { "\"digits\"" };
+        return Err(CalculatorError::invalid_args(
+            &name,
+            format!("expected 2 arguments: {name}({first}, base)"),
+        ));
+    };
+    let base = integer(base)
+        .and_then(|base| u32::try_from(base).ok())
+        .filter(|base| (2..=36).contains(base))
+        .ok_or_else(|| {
+            CalculatorError::invalid_args(&name, "the base must be an integer from 2 to 36")
+        })?;
+    if name == "tobase" {
+        let n = integer(value).ok_or_else(|| {
+            CalculatorError::invalid_args(&name, "only integers can be written in another base")
+        })?;
+        let digits = n.abs().to_str_radix(base).to_uppercase();
+        let sign = if n.is_negative() { "-" } else { "" };
+        return Ok(Value::text(format!("{sign}{digits}")));
+    }
+    // Digits that are all decimal, as in `frombase(1010, 2)`, may be given
+    // as a number
+    let digits = match &value.kind {
+        ValueKind::Text(text) => text.trim().to_string(),
+        _ => integer(value).map(|n| n.to_string()).unwrap_or_default(),
+    };
+    let (negative, unsigned) = digits
+        .strip_prefix('-')
+        .map_or((false, digits.as_str()), |rest| (true, rest));
+    let n = BigInt::parse_bytes(unsigned.as_bytes(), base)
+        .filter(|_| !unsigned.starts_with('+'))
+        .ok_or_else(|| {
+            CalculatorError::invalid_args(
+                &name,
+                format!("\"{digits}\" is not a number in base {base}"),
+            )
+        })?;
+    let n = if negative { -n } else { n };
+    Ok(Value::rational(Rational::from_bigint(n)))
+}
+
+/// Returns the value as an integer if it is a plain whole number.
+fn integer(value: &Value) -> Option<BigInt> {
+    value
+        .to_rational()
+        .filter(|n| value.unit == Unit::None && n.is_integer())
+        .map(|n| n.numer_bigint().clone())
+}
@@ -0,0 +1,183 @@
+//! Calendar functions over dates: `age(date)` and `time until <date>`, and
+//! queries such as `week number of <date>` or `is 2028 a leap year`, and
+//! the span of a shift between two clock times, `9:00 to 17:30`.
+//!
+//! `age` and `time until` measure a [`Value::calendar_span_between`] two
+//! dates, counting whole calendar months rather than fixed-length seconds.
+//! The parser supplies the reference date (`today` for `age`, `now` for
+//! `time until`) when it is omitted, so `age(15 June 1990)` is
+//! `age(15 June 1990, today)`.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use super::countdown::{evaluate_countdown_function, COUNTDOWN_FUNCTIONS};
+use crate::error::CalculatorError;
+use crate::types::{bare_year_datetime, Expression, Value, ValueKind};
+
+/// The calendar functions other than the countdowns.
+pub const CALENDAR_FUNCTIONS: &[&str] = &[
+    "age",
+    "time_until",
+    "week_number",
+    "day_of_week",
+    "days_in_month",
+    "days_in_year",
+    "is_leap_year",
+    "time_span",
+];
+
+/// Returns true if `name` is a calendar function.
+#[must_use]
+pub fn is_calendar_function(name: &str) -> bool {
+    let name = name.to_lowercase();
+    COUNTDOWN_FUNCTIONS.contains(&name.as_str()) || CALENDAR_FUNCTIONS.contains(&name.as_str())
+}
+
+/// Returns the reference date used when a calendar function is called with
+/// a single argument.
+#[must_use]
+pub fn default_reference_date(name: &str) -> Option<Expression> {
+    match name.to_lowercase().as_str() {
+        "age" => Some(Expression::Today),
+        "time_until" | "working_hours_until" | "weekends_until" => Some(Expression::Now),
+        _ => None,
+    }
+}
+
+/// Evaluates a calendar function:
+/// - `age(date, on)`: the span from `date` to `on`
+/// - `time_until(target, from)`: the span from `from` to `target`
+/// - `week_number(date)`: the ISO 8601 week of `date`
+/// - `day_of_week(date)`: the name of the weekday of `date`
+/// - `days_in_month(date)`: the number of days in the month of `date`
+/// - `days_in_year(year)` and `is_leap_year(year)`: for a year or a date
+/// - `time_span(start, end)`: the time from one clock time to the next
+///   `end`, overnight if it is earlier
+/// - the countdowns to a deadline, such as `working_hours_until(deadline)`
+pub fn evaluate_calendar_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name_lower = name.to_lowercase();
+    if COUNTDOWN_FUNCTIONS.contains(&name_lower.as_str()) {
+        return evaluate_countdown_function(&name_lower, args);
+    }
+    if name_lower == "time_span" {
+        let [start, end] = args else {
+            return Err(CalculatorError::invalid_args(
+                &name_lower,
+                "expected 2 clock times",
+            ));
+        };
+        return Value::time_span(start, end);
+    }
+    if !matches!(name_lower.as_str(), "age" | "time_until") {
+        return evaluate_calendar_query(&name_lower, args);
+    }
+    let dates: Vec<_> = args
+        .iter()
+        .map(|arg| match &arg.kind {
+            ValueKind::DateTime(dt) => Ok(dt),
+            _ => Err(CalculatorError::invalid_args(
+                &name_lower,
+                format!("expected a date, got {}", arg.type_name()),
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+    let [date, reference] = dates.as_slice() else {
+        return Err(CalculatorError::invalid_args(
+            &name_lower,
+            "expected 1 or 2 date arguments",
+        ));
+    };
+
+    Ok(if name_lower == "age" {
+        Value::calendar_span_between(date, reference)
+    } else {
+        Value::calendar_span_between(reference, date)
+    })
+}
+
+/// Evaluates a calendar function of a single date or year.
+fn evaluate_calendar_query(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let [arg] = args else {
+        return Err(CalculatorError::invalid_args(name, "expected 1 argument"));
+    };
+    match name {
+        "week_number" => Ok(Value::from_integer(i64::from(
+            date_arg(name, arg)?.iso_week().week(),
+        ))),
+        "day_of_week" => Ok(Value::text(weekday_name(date_arg(name, arg)?.weekday()))),
+        "days_in_month" => {
+            let date = date_arg(name, arg)?;
+            Ok(Value::from_integer(days_in_month(
+                date.year(),
+                date.month(),
+            )))
+        }
+        "days_in_year" => {
+            let year = year_arg(name, arg)?;
+            Ok(Value::from_integer(if is_leap_year(year) {
+                366
+            } else {
+                365
+            }))
+        }
+        _ => Ok(Value::boolean(is_leap_year(year_arg(name, arg)?))),
+    }
+}
+
+/// Returns the calendar date of a datetime argument.
+fn date_arg(name: &str, arg: &Value) -> Result<NaiveDate, CalculatorError> {
+    match &arg.kind {
+        ValueKind::DateTime(dt) => Ok(dt.local_date()),
+        _ => Err(CalculatorError::invalid_args(
+            name,
+            format!("expected a date, got {}", arg.type_name()),
+        )),
+    }
+}
+
+/// Returns the year of a date argument, or of a four-digit whole number
+/// read as a year.
+fn year_arg(name: &str, arg: &Value) -> Result<i32, CalculatorError> {
+    match &arg.kind {
+        ValueKind::DateTime(dt) => Ok(dt.local_date().year()),
+        _ => bare_year_datetime(arg)
+            .map(|dt| dt.local_date().year())
+            .ok_or_else(|| {
+                CalculatorError::invalid_args(
+                    name,
+                    format!("expected a year or a date, got {}", arg.to_display_string()),
+                )
+            }),
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    match (
+        NaiveDate::from_ymd_opt(year, month, 1),
+        NaiveDate::from_ymd_opt(next_year, next_month, 1),
+    ) {
+        (Some(first), Some(next)) => (next - first).num_days(),
+        _ => 31,
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
@@ -0,0 +1,101 @@
+//! Color functions over hex colors such as `#336699`:
+//! - `mix(color1, color2, weight)`: `weight` of the first color and the rest
+//!   of the second, half of each by default: `mix(#ff0000, #0000ff, 50%)`
+//! - `lighten(color, amount)` and `darken(color, amount)`: HSL lightness
+//!   raised or lowered by `amount`: `lighten(#336699, 10%)`
+//! - `rgb(red, green, blue)` and `hsl(hue, saturation, lightness)`: a color
+//!   from its channels, `rgb(255, 136, 0)` or `hsl(32, 100%, 50%)`
+//!
+//! Amounts and weights are percentages, which arrive as fractions. Results
+//! are colors written the way the first color argument was, so that
+//! `lighten(#336699, 10%)` stays in hex.
+
+use crate::error::CalculatorError;
+use crate::function_registry::{lookup, FunctionCategory};
+use crate::types::{Color, ColorModel, Unit, Value};
+
+/// Returns true if `name` is a registered color function.
+#[must_use]
+pub fn is_color_function(name: &str) -> bool {
+    lookup(name).is_some_and(|function| function.category == FunctionCategory::Color)
+}
+
+/// Evaluates a color function.
+pub fn evaluate_color_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let invalid = |reason: &str| CalculatorError::invalid_args(&name, reason);
+    match (name.as_str(), args) {
+        ("mix", [first, second]) => mix(&name, first, second, 0.5),
+        ("mix", [first, second, weight]) => {
+            mix(&name, first, second, fraction(&name, weight, "weight")?)
+        }
+        ("mix", _) => Err(invalid(
+            "expected 2 or 3 arguments: mix(color1, color2, weight)",
+        )),
+        ("lighten" | "darken", [color, amount]) => {
+            let (color, model) = color_argument(&name, color)?;
+            let amount = fraction(&name, amount, "amount")?;
+            let amount = if name == "darken" { -amount } else { amount };
+            Ok(Value::color(color.lighten(amount), model))
+        }
+        ("lighten" | "darken", _) => Err(invalid(&format!(
+            "expected 2 arguments: {name}(color, amount)"
+        ))),
+        ("rgb", [red, green, blue]) => {
+            let channel = |value: &Value| {
+                value
+                    .to_rational()
+                    .filter(|n| value.unit == Unit::None && n.is_integer())
+                    .and_then(|n| u8::try_from(n.numer_bigint()).ok())
+                    .ok_or_else(|| invalid("each channel must be an integer from 0 to 255"))
+            };
+            let color = Color::new(channel(red)?, channel(green)?, channel(blue)?);
+            Ok(Value::color(color, ColorModel::Rgb))
+        }
+        ("hsl", [hue, saturation, lightness]) => {
+            let hue = plain_number(hue).ok_or_else(|| invalid("the hue must be in degrees"))?;
+            let saturation = fraction(&name, saturation, "saturation")?;
+            let lightness = fraction(&name, lightness, "lightness")?;
+            Ok(Value::color(
+                Color::from_hsl(hue, saturation, lightness),
+                ColorModel::Hsl,
+            ))
+        }
+        _ => Err(invalid("expected 3 arguments")),
+    }
+}
+
+/// Mixes two colors, written the way the first one is.
+fn mix(name: &str, first: &Value, second: &Value, weight: f64) -> Result<Value, CalculatorError> {
+    let (first, model) = color_argument(name, first)?;
+    let (second, _) = color_argument(name, second)?;
+    Ok(Value::color(first.mix(second, weight), model))
+}
+
+fn color_argument(name: &str, value: &Value) -> Result<(Color, ColorModel), CalculatorError> {
+    value.as_color().ok_or_else(|| {
+        CalculatorError::invalid_args(
+            name,
+            format!(
+                "{} is not a color such as #336699",
+                value.to_display_string()
+            ),
+        )
+    })
+}
+
+/// Reads a percentage from 0% to 100% as a fraction from 0 to 1.
+fn fraction(name: &str, value: &Value, what: &str) -> Result<f64, CalculatorError> {
+    plain_number(value)
+        .filter(|n| (0.0..=1.0).contains(n))
+        .ok_or_else(|| {
+            CalculatorError::invalid_args(name, format!("the {what} must be from 0% to 100%"))
+        })
+}
+
+fn plain_number(value: &Value) -> Option<f64> {
+    value
+        .to_rational()
+        .filter(|_| value.unit == Unit::None)
+        .map(|n| n.to_f64())
+}
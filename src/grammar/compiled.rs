@@ -0,0 +1,182 @@
+//! Expressions of one variable compiled for repeated numeric evaluation.
+//!
+//! Plotting and numerical integration evaluate the same expression at
+//! hundreds or thousands of points. Going through the full evaluator each
+//! time means substituting the variable, building [`crate::types::Value`]s
+//! and dispatching on units and dates at every node. A
+//! [`CompiledExpression`] resolves all of that once, into a tree of closures
+//! over `f64`.
+
+use super::math_functions::{evaluate_function, is_math_function};
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Decimal, Expression, Unit};
+
+type Eval = Box<dyn Fn(f64) -> Result<f64, CalculatorError>>;
+
+/// Functions that need more than their numeric arguments, and so are left
+/// to the full evaluator.
+const UNCOMPILED_FUNCTIONS: &[&str] = &["integrate", "nderiv", "root", "factorial"];
+
+/// An expression compiled into a closure of its variable.
+pub struct CompiledExpression {
+    eval: Eval,
+    nodes: u64,
+}
+
+impl std::fmt::Debug for CompiledExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledExpression")
+            .field("nodes", &self.nodes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CompiledExpression {
+    /// Compiles `expr` as a function of `variable`.
+    ///
+    /// Returns `None` if the expression uses anything beyond unitless
+    /// numbers, arithmetic, powers and math functions, such as units, dates
+    /// or other variables; those need the full evaluator.
+    #[must_use]
+    pub fn compile(expr: &Expression, variable: &str) -> Option<Self> {
+        let mut nodes = 0;
+        let eval = compile_node(expr, variable, &mut nodes)?;
+        Some(Self { eval, nodes })
+    }
+
+    /// Evaluates the expression with its variable set to `x`, failing where
+    /// the full evaluator would (division by zero, domain errors, overflow).
+    pub fn evaluate(&self, x: f64) -> Result<f64, CalculatorError> {
+        (self.eval)(x)
+    }
+
+    /// Number of expression nodes evaluated per call, the operations the
+    /// full evaluator would have counted.
+    #[must_use]
+    pub const fn nodes(&self) -> u64 {
+        self.nodes
+    }
+}
+
+fn compile_node(expr: &Expression, variable: &str, nodes: &mut u64) -> Option<Eval> {
+    *nodes += 1;
+    Some(match expr {
+        Expression::Number { value, unit, .. } if *unit == Unit::None => {
+            let value = value.to_f64();
+            Box::new(move |_| Ok(value))
+        }
+        Expression::Variable(name) if name == variable => Box::new(Ok),
+        Expression::Group(inner) => compile_node(inner, variable, nodes)?,
+        Expression::Negate(inner) => {
+            let inner = compile_node(inner, variable, nodes)?;
+            Box::new(move |x| Ok(-inner(x)?))
+        }
+        Expression::Binary { left, op, right } => {
+            let left = compile_node(left, variable, nodes)?;
+            let right = compile_node(right, variable, nodes)?;
+            match op {
+                BinaryOp::Add => Box::new(move |x| finite(left(x)? + right(x)?)),
+                BinaryOp::Subtract => Box::new(move |x| finite(left(x)? - right(x)?)),
+                BinaryOp::Multiply => Box::new(move |x| finite(left(x)? * right(x)?)),
+                BinaryOp::Divide => Box::new(move |x| {
+                    let (numerator, denominator) = (left(x)?, right(x)?);
+                    if denominator == 0.0 {
+                        return Err(CalculatorError::DivisionByZero);
+                    }
+                    finite(numerator / denominator)
+                }),
+                BinaryOp::Modulo => return None,
+            }
+        }
+        Expression::Power { base, exponent } => {
+            let base = compile_node(base, variable, nodes)?;
+            let exponent = compile_node(exponent, variable, nodes)?;
+            Box::new(move |x| power(base(x)?, exponent(x)?))
+        }
+        Expression::FunctionCall { name, args } => {
+            let name = name.to_lowercase();
+            if !is_math_function(&name) || UNCOMPILED_FUNCTIONS.contains(&name.as_str()) {
+                return None;
+            }
+            let args = args
+                .iter()
+                .map(|arg| compile_node(arg, variable, nodes))
+                .collect::<Option<Vec<_>>>()?;
+            if args.is_empty() {
+                // A constant such as pi or e
+                let value = evaluate_function(&name, &[]).ok()?.to_f64();
+                return Some(Box::new(move |_| Ok(value)));
+            }
+            Box::new(move |x| {
+                let values = args
+                    .iter()
+                    .map(|arg| arg(x).and_then(to_decimal))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(evaluate_function(&name, &values)?.to_f64())
+            })
+        }
+        _ => return None,
+    })
+}
+
+/// Raises `base` to `exponent` the way [`super::evaluate_power`] does for
+/// numbers.
+fn power(base: f64, exponent: f64) -> Result<f64, CalculatorError> {
+    if exponent.fract() == 0.0 && exponent.abs() <= 1_000_000.0 {
+        if exponent < 0.0 && base == 0.0 {
+            return Err(CalculatorError::domain(
+                "division by zero (negative exponent with zero base)",
+            ));
+        }
+        // The exponent is a whole number within i32, so the cast is exact
+        #[allow(clippy::cast_possible_truncation)]
+        return finite(base.powi(exponent as i32));
+    }
+    let result = base.powf(exponent);
+    if result.is_nan() {
+        return Err(CalculatorError::domain("power result is undefined"));
+    }
+    finite(result)
+}
+
+fn finite(value: f64) -> Result<f64, CalculatorError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(CalculatorError::Overflow)
+    }
+}
+
+fn to_decimal(value: f64) -> Result<Decimal, CalculatorError> {
+    Decimal::try_from_f64(value).ok_or(CalculatorError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::ExpressionParser;
+
+    fn compile(input: &str) -> Option<CompiledExpression> {
+        let expr = ExpressionParser::new().parse(input).unwrap();
+        CompiledExpression::compile(&expr, "x")
+    }
+
+    #[test]
+    fn test_evaluates_arithmetic_powers_and_functions() {
+        let compiled = compile("3*x^2 - 2/x + sin(pi()/2)").unwrap();
+        assert!((compiled.evaluate(2.0).unwrap() - 12.0).abs() < 1e-12);
+        assert!(compile("(-x)^0.5").unwrap().evaluate(2.0).is_err());
+        assert!(matches!(
+            compile("1/x").unwrap().evaluate(0.0),
+            Err(CalculatorError::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_leaves_units_and_other_variables_to_the_evaluator() {
+        assert!(compile("x * 5 km").is_none());
+        assert!(compile("x + y").is_none());
+        assert!(compile("x % 2").is_none());
+        assert!(compile("factorial(x)").is_none());
+    }
+}
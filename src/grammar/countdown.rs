@@ -0,0 +1,168 @@
+//! Countdowns to a deadline on the business-day calendar: `working hours
+//! until Friday 5pm` and `how many weekends until 1 Jun 2026`.
+//!
+//! Working days are Monday to Friday, and working hours 9:00 to 17:00 on
+//! them, on the wall clock of the time counted from. The parser supplies
+//! `now` as that time, so countdowns follow the calculator's clock.
+
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::error::CalculatorError;
+use crate::types::{DateTime, DurationUnit, Rational, Unit, Value, ValueKind};
+
+/// The countdown functions.
+pub(super) const COUNTDOWN_FUNCTIONS: &[&str] =
+    &["working_hours_until", "weekends_until", "next_weekday"];
+
+/// The hour working hours start at.
+const WORKDAY_START: u32 = 9;
+
+/// The hour working hours end at.
+const WORKDAY_END: u32 = 17;
+
+/// The most days a countdown counts through, about a hundred years.
+const MAX_COUNTDOWN_DAYS: i64 = 36_525;
+
+/// Evaluates a countdown function:
+/// - `working_hours_until(deadline, from)`: the working hours left from
+///   `from` to `deadline`, in hours
+/// - `weekends_until(deadline, from)`: the weekends that start after `from`
+///   and before `deadline`
+/// - `next_weekday(day, [time,] from)`: the next such day after `from`, at
+///   `time` (today too, if that time has not passed yet)
+pub(super) fn evaluate_countdown_function(
+    name: &str,
+    args: &[Value],
+) -> Result<Value, CalculatorError> {
+    if name == "next_weekday" {
+        return next_weekday(args);
+    }
+    let [deadline, from] = args else {
+        return Err(CalculatorError::invalid_args(name, "expected 1 or 2 dates"));
+    };
+    let (deadline, from) = (datetime_arg(name, deadline)?, datetime_arg(name, from)?);
+    let start = wall_clock(from, from);
+    let end = wall_clock(deadline, from);
+    if end < start {
+        return Err(CalculatorError::invalid_args(
+            name,
+            "the deadline has already passed",
+        ));
+    }
+    if (end.date() - start.date()).num_days() > MAX_COUNTDOWN_DAYS {
+        return Err(CalculatorError::invalid_args(
+            name,
+            "the deadline is too far away",
+        ));
+    }
+    if name == "working_hours_until" {
+        return Ok(Value::rational_with_unit(
+            Rational::new(i128::from(working_seconds(start, end)), 3600),
+            Unit::Duration(DurationUnit::Hours),
+        ));
+    }
+    let weekends = start
+        .date()
+        .iter_days()
+        .skip(1)
+        .take_while(|day| day.and_time(NaiveTime::MIN) < end)
+        .filter(|day| day.weekday() == Weekday::Sat)
+        .count();
+    Ok(Value::from_integer(
+        i64::try_from(weekends).unwrap_or(i64::MAX),
+    ))
+}
+
+/// Returns the seconds of working hours from `start` to `end`.
+fn working_seconds(start: NaiveDateTime, end: NaiveDateTime) -> i64 {
+    start
+        .date()
+        .iter_days()
+        .take_while(|day| *day <= end.date())
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+        .map(|day| {
+            let open = day.and_hms_opt(WORKDAY_START, 0, 0).unwrap_or_default();
+            let close = day.and_hms_opt(WORKDAY_END, 0, 0).unwrap_or_default();
+            (close.min(end) - open.max(start)).num_seconds().max(0)
+        })
+        .sum()
+}
+
+/// Returns the next `day` after `from` at `time`, or at midnight without
+/// one.
+fn next_weekday(args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = "next_weekday";
+    let (day, time, from) = match args {
+        [day, from] => (day, None, from),
+        [day, time, from] => (day, Some(time), from),
+        _ => {
+            return Err(CalculatorError::invalid_args(
+                name,
+                "expected a day of the week and a date",
+            ))
+        }
+    };
+    let weekday = match &day.kind {
+        ValueKind::Text(text) => text.parse::<Weekday>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| CalculatorError::invalid_args(name, "expected a day of the week"))?;
+    let from = datetime_arg(name, from)?;
+    let time = match time {
+        Some(time) => wall_clock(datetime_arg(name, time)?, from).time(),
+        None => NaiveTime::MIN,
+    };
+    let now = wall_clock(from, from);
+    let next = now
+        .date()
+        .iter_days()
+        .map(|day| day.and_time(time))
+        .find(|moment| moment.weekday() == weekday && *moment > now)
+        .unwrap_or(now);
+    let next = DateTime::from_utc(next.and_utc(), true, true);
+    Ok(Value::datetime(match from.offset_seconds() {
+        Some(offset) => next.reinterpret_naive_as_local(offset),
+        None => next,
+    }))
+}
+
+/// Returns the reading of `dt` on the wall clock of `reference`. A date or
+/// time written without a timezone is already that reading.
+fn wall_clock(dt: &DateTime, reference: &DateTime) -> NaiveDateTime {
+    let offset = match dt.offset_seconds() {
+        Some(_) => reference.offset_seconds().unwrap_or(0),
+        None => 0,
+    };
+    dt.inner_utc().naive_utc() + Duration::seconds(i64::from(offset))
+}
+
+fn datetime_arg<'a>(name: &str, arg: &'a Value) -> Result<&'a DateTime, CalculatorError> {
+    match &arg.kind {
+        ValueKind::DateTime(dt) => Ok(dt),
+        _ => Err(CalculatorError::invalid_args(
+            name,
+            format!("expected a date, got {}", arg.type_name()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_working_seconds() {
+        let at = |day, hour| {
+            NaiveDate::from_ymd_opt(2026, 3, day)
+                .and_then(|date| date.and_hms_opt(hour, 0, 0))
+                .unwrap()
+        };
+        // Monday 10:00 to Monday 12:00
+        assert_eq!(working_seconds(at(16, 10), at(16, 12)), 2 * 3600);
+        // Friday 16:00 to Monday 10:00
+        assert_eq!(working_seconds(at(13, 16), at(16, 10)), 2 * 3600);
+        // Saturday to Sunday
+        assert_eq!(working_seconds(at(14, 8), at(15, 20)), 0);
+    }
+}
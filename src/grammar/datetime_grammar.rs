@@ -1,7 +1,7 @@
 //! Grammar for parsing date and time expressions.
 
 use crate::error::CalculatorError;
-use crate::types::{DateTime, Value};
+use crate::types::DateTime;
 
 /// Grammar for parsing datetime expressions.
 #[derive(Debug, Default)]
@@ -276,140 +276,6 @@ impl DateTimeGrammar {
 
         None
     }
-
-    /// Localizes a parsed datetime to the user's local timezone, when one is known.
-    ///
-    /// A bare `now` becomes the current local time; bare (timezone-less) times are
-    /// re-anchored to the local offset. Inputs with an explicit timezone, and the
-    /// explicit `now utc` / `now <tz>` forms, are returned unchanged.
-    fn localize(dt: DateTime, raw: &str, offset: Option<i32>) -> DateTime {
-        let Some(offset) = offset else {
-            return dt;
-        };
-        if raw.trim().eq_ignore_ascii_case("now") {
-            return DateTime::now_local(offset);
-        }
-        dt.reinterpret_naive_as_local(offset)
-    }
-
-    /// Tries to parse a datetime subtraction expression like "(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC)".
-    ///
-    /// `local_offset` is the user's timezone offset in seconds east of UTC, when
-    /// known; it makes bare `now` and timezone-less times resolve to local time.
-    #[must_use]
-    pub fn try_parse_datetime_subtraction(
-        &self,
-        input: &str,
-        local_offset: Option<i32>,
-    ) -> Option<(Value, Vec<String>, String)> {
-        // Look for pattern: (datetime) - (datetime)
-        let input = input.trim();
-
-        if !input.contains('-') {
-            return None;
-        }
-
-        if let Some((left, right)) = input.split_once(" - ") {
-            if let (Ok(dt1), Ok(dt2)) = (self.parse(left.trim()), self.parse(right.trim())) {
-                let dt1 = Self::localize(dt1, left.trim(), local_offset);
-                let dt2 = Self::localize(dt2, right.trim(), local_offset);
-                return Some(Self::datetime_difference_result(
-                    &dt1,
-                    &dt2,
-                    left.trim(),
-                    right.trim(),
-                ));
-            }
-        }
-
-        // Check if it starts with '(' for the explicit "(datetime) - (datetime)" form.
-        if !input.starts_with('(') {
-            return None;
-        }
-
-        // Try to find the matching closing paren for the first datetime
-        let mut paren_depth = 0;
-        let mut first_end = None;
-
-        for (i, ch) in input.char_indices() {
-            match ch {
-                '(' => paren_depth += 1,
-                ')' => {
-                    paren_depth -= 1;
-                    if paren_depth == 0 {
-                        first_end = Some(i);
-                        break;
-                    }
-                }
-                _ => {}
-            }
-        }
-
-        let first_end = first_end?;
-
-        // Extract first datetime (without parens)
-        let first_dt_str = &input[1..first_end];
-
-        // Find the minus sign
-        let rest = input[first_end + 1..].trim();
-        if !rest.starts_with('-') {
-            return None;
-        }
-
-        let second_part = rest[1..].trim();
-        if !second_part.starts_with('(') || !second_part.ends_with(')') {
-            return None;
-        }
-
-        // Extract second datetime (without parens)
-        let second_dt_str = &second_part[1..second_part.len() - 1];
-
-        // Try to parse both as datetimes
-        let Ok(dt1) = self.parse(first_dt_str) else {
-            return None;
-        };
-
-        let Ok(dt2) = self.parse(second_dt_str) else {
-            return None;
-        };
-
-        let dt1 = Self::localize(dt1, first_dt_str.trim(), local_offset);
-        let dt2 = Self::localize(dt2, second_dt_str.trim(), local_offset);
-
-        Some(Self::datetime_difference_result(
-            &dt1,
-            &dt2,
-            first_dt_str.trim(),
-            second_dt_str.trim(),
-        ))
-    }
-
-    fn datetime_difference_result(
-        dt1: &DateTime,
-        dt2: &DateTime,
-        first_dt_str: &str,
-        second_dt_str: &str,
-    ) -> (Value, Vec<String>, String) {
-        // Calculate the signed difference (dt1 - dt2). Using signed seconds keeps
-        // the result correct when dt1 is earlier than dt2 (a negative duration),
-        // instead of collapsing to zero.
-        let seconds = dt1.signed_subtract_seconds(dt2);
-
-        let value = Value::duration(seconds);
-
-        let steps = vec![
-            format!("Parse first datetime: {dt1}"),
-            format!("Parse second datetime: {dt2}"),
-            format!("Calculate difference: {dt1} - {dt2}"),
-            format!("Result: {}", value.to_display_string()),
-        ];
-
-        // Issue #30 fix: Use exactly 2 outer parentheses for Links notation
-        // Format: ((datetime1) - (datetime2))
-        let lino = format!("(({first_dt_str}) - ({second_dt_str}))");
-
-        (value, steps, lino)
-    }
 }
 
 #[cfg(test)]
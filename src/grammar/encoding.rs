@@ -0,0 +1,42 @@
+//! Encodings of quoted text:
+//! - `base64("hello")`: standard base64 of the UTF-8 bytes, `aGVsbG8=`
+//! - `base64decode("aGVsbG8=")`: the text back from base64
+//! - `urlencode("a b&c")`: percent-encoded for a URL, `a%20b%26c`
+
+use crate::error::CalculatorError;
+use crate::function_registry::{lookup, FunctionCategory};
+use crate::types::{Value, ValueKind};
+use crate::utils::{base64_decode, base64_encode, url_encode};
+
+/// Returns true if `name` is a registered encoding function.
+#[must_use]
+pub fn is_encoding_function(name: &str) -> bool {
+    lookup(name).is_some_and(|function| function.category == FunctionCategory::Encoding)
+}
+
+/// Evaluates an encoding function.
+pub fn evaluate_encoding_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let [Value {
+        kind: ValueKind::Text(text),
+        ..
+    }] = args
+    else {
+        return Err(CalculatorError::invalid_args(
+            &name,
+            format!("expected quoted text: {name}(\"text\")"),
+        ));
+    };
+    let encoded = match name.as_str() {
+        "base64" => base64_encode(text.as_bytes()),
+        "base64decode" => {
+            let bytes = base64_decode(text)
+                .ok_or_else(|| CalculatorError::invalid_args(&name, "the text is not base64"))?;
+            String::from_utf8(bytes).map_err(|_| {
+                CalculatorError::invalid_args(&name, "the decoded bytes are not UTF-8 text")
+            })?
+        }
+        _ => url_encode(text),
+    };
+    Ok(Value::text(encoded))
+}
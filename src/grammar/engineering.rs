@@ -0,0 +1,207 @@
+//! Engineering notation with SI prefixes: `eng(4700 ohm)` gives `4.7 kΩ` and
+//! `eng(0.000012 s)` gives `12 µs`.
+//!
+//! Lengths, volumes and durations are written in meters, liters and seconds
+//! and masses in grams before the prefix is chosen, so `eng(0.0047 km)`
+//! gives `4.7 m`.
+//!
+//! Results in the units of physics quantities, and durations under a tenth
+//! of a second, are written with a prefix on their own unless that is turned
+//! off with [`FormatOptions::si_prefixes`](crate::types::FormatOptions).
+//! Both write a prefixed amount by its unit symbol, as `10 Ω` or `12 µs`.
+
+use super::physics::si_unit_amount;
+use crate::error::CalculatorError;
+use crate::types::{DurationUnit, MassUnit, Rational, Unit, Value, ValueKind};
+
+/// SI prefixes by the power of ten they stand for.
+const SI_PREFIXES: [(i32, &str); 21] = [
+    (-30, "q"),
+    (-27, "r"),
+    (-24, "y"),
+    (-21, "z"),
+    (-18, "a"),
+    (-15, "f"),
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "µ"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+    (15, "P"),
+    (18, "E"),
+    (21, "Z"),
+    (24, "Y"),
+    (27, "R"),
+    (30, "Q"),
+];
+
+/// Decimal places kept in the number before the prefix.
+const MANTISSA_DECIMALS: i32 = 12;
+
+/// Returns true if `name` is `eng`.
+#[must_use]
+pub fn is_engineering_function(name: &str) -> bool {
+    name.eq_ignore_ascii_case("eng")
+}
+
+/// Evaluates `eng(x)`: the amount written with the SI prefix that leaves
+/// between 1 and 1000 before it.
+pub fn evaluate_engineering_function(args: &[Value]) -> Result<Value, CalculatorError> {
+    let invalid = |reason: &str| CalculatorError::invalid_args("eng", reason);
+    let [value] = args else {
+        return Err(invalid("expected 1 argument: eng(amount)"));
+    };
+    let (amount, symbol) =
+        si_amount(value).ok_or_else(|| invalid("expected a number or an SI quantity"))?;
+    let (mantissa, unit) = with_si_prefix(&amount, &symbol);
+    let mantissa = mantissa.round_to_places(MANTISSA_DECIMALS);
+    Ok(Value::text(if symbol.is_empty() {
+        // A bare number takes the prefix alone: `4.7k`
+        format!("{}{}", mantissa.to_display_string(), unit.display_name())
+    } else {
+        Value::rational_with_unit(mantissa, unit).to_display_string()
+    }))
+}
+
+/// Returns `value` in the SI-prefixed unit that leaves between 1 and 1000
+/// before it: `4700 ohm` is `4.7 kΩ` and `0.000012 s` is `12 µs`.
+///
+/// Only amounts of the units of physics quantities and durations in seconds
+/// are prefixed; returns `None` for other values, and for those that need
+/// no prefix. Amounts under one but from a tenth of the SI unit are written
+/// in that unit, as `0.5 A`, and durations from a tenth of a second up are
+/// left as they are.
+#[must_use]
+pub fn si_prefixed(value: &Value) -> Option<Value> {
+    if !matches!(value.kind, ValueKind::Rational(_) | ValueKind::Number(_)) {
+        return None;
+    }
+    let (amount, symbol) = match &value.unit {
+        Unit::Duration(DurationUnit::Seconds) => (value.to_rational()?, "s"),
+        _ => si_unit_amount(value)?,
+    };
+    let magnitude = amount.abs();
+    let tenth = Rational::new(1, 10);
+    if symbol == "s" && magnitude >= tenth {
+        return None;
+    }
+    let (mantissa, unit) = if magnitude >= tenth && magnitude < Rational::one() {
+        (amount, Unit::Custom(symbol.to_string()))
+    } else {
+        with_si_prefix(&amount, symbol)
+    };
+    if mantissa == value.to_rational()? && unit.display_name() == value.unit.display_name() {
+        return None;
+    }
+    let prefixed = Value::rational_with_unit(mantissa, unit);
+    Some(match value.kind {
+        ValueKind::Number(_) => prefixed.inexact(),
+        _ => prefixed,
+    })
+}
+
+/// Returns the power of ten an SI prefix stands for: 3 for `k`. `u` is
+/// read as `µ`.
+pub(super) fn prefix_power(prefix: &str) -> Option<i32> {
+    let prefix = if prefix == "u" { "µ" } else { prefix };
+    SI_PREFIXES
+        .iter()
+        .find(|(power, symbol)| *power != 0 && *symbol == prefix)
+        .map(|(power, ..)| *power)
+}
+
+/// Returns the amount in the unprefixed SI unit and that unit's symbol.
+fn si_amount(value: &Value) -> Option<(Rational, String)> {
+    let amount = value.to_rational()?;
+    match &value.unit {
+        Unit::None => Some((amount, String::new())),
+        Unit::Mass(unit) => {
+            let grams = unit.convert(amount.to_f64(), MassUnit::Gram);
+            Some((Rational::from_f64(grams), "g".to_string()))
+        }
+        Unit::Length(_) | Unit::Volume(_) | Unit::Duration(_) => {
+            let base = value.to_base_unit().unwrap_or_else(|| value.clone());
+            let symbol = match &base.unit {
+                Unit::Duration(duration) => duration.abbreviation().to_string(),
+                unit => unit.display_name(),
+            };
+            Some((base.to_rational()?, symbol))
+        }
+        Unit::Custom(name) if matches!(name.to_lowercase().as_str(), "ohm" | "ohms") => {
+            Some((amount, "Ω".to_string()))
+        }
+        Unit::Custom(name) => Some((amount, name.clone())),
+        _ => None,
+    }
+}
+
+/// Returns `amount` of the unit written `symbol` with the SI prefix that
+/// leaves between 1 and 1000 before it, or the largest or smallest prefix
+/// beyond that range, as the number before the prefixed unit: `4.7` and
+/// `kΩ`. A bare number takes the prefix alone as its unit. The prefix is chosen after rounding,
+/// so that `999.9999999999999k` is `1M`.
+fn with_si_prefix(amount: &Rational, symbol: &str) -> (Rational, Unit) {
+    let scale = |exponent: i32| Rational::from_integer(10).pow_i32(exponent);
+    let rounded = si_prefix(amount).map_or_else(
+        || amount.clone(),
+        |(exponent, ..)| {
+            (amount.clone() / scale(exponent)).round_to_places(MANTISSA_DECIMALS) * scale(exponent)
+        },
+    );
+    let (exponent, prefix) = si_prefix(&rounded).unwrap_or((0, ""));
+    let mantissa = amount.clone() / scale(exponent);
+    let unit = match (symbol, exponent) {
+        ("", 0) => Unit::None,
+        ("s", 0) => Unit::Duration(DurationUnit::Seconds),
+        _ => Unit::Custom(format!("{prefix}{symbol}")),
+    };
+    (mantissa, unit)
+}
+
+/// Returns the power of ten of the SI prefix that leaves between 1 and 1000
+/// before `amount`, or of the largest or smallest prefix beyond that range,
+/// with the prefix. Returns `None` for 0.
+fn si_prefix(amount: &Rational) -> Option<(i32, &'static str)> {
+    let exponent = amount.decimal_exponent()?;
+    let exponent = (exponent - exponent.rem_euclid(3)).clamp(-30, 30);
+    SI_PREFIXES
+        .iter()
+        .find(|(power, ..)| *power == exponent)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Decimal;
+
+    fn eng(value: Value) -> String {
+        evaluate_engineering_function(&[value])
+            .unwrap()
+            .to_display_string()
+    }
+
+    #[test]
+    fn test_prefixes() {
+        let ohms = Value::number_with_unit(Decimal::new(4700), Unit::Custom("ohm".to_string()));
+        assert_eq!(eng(ohms), "4.7 kΩ");
+        let seconds = Value::rational_with_unit(
+            Rational::new(12, 1_000_000),
+            Unit::Duration(DurationUnit::Seconds),
+        );
+        assert_eq!(eng(seconds), "12 µs");
+        assert_eq!(eng(Value::from_integer(4700)), "4.7k");
+        assert_eq!(eng(Value::from_integer(0)), "0");
+        assert_eq!(eng(Value::from_integer(-250)), "-250");
+    }
+
+    #[test]
+    fn test_currency_is_rejected() {
+        let money = Value::from_integer_with_unit(5, Unit::currency("USD"));
+        assert!(evaluate_engineering_function(&[money]).is_err());
+    }
+}
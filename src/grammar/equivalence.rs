@@ -0,0 +1,208 @@
+//! Equivalence of two expressions: `is x^2 - 1 equivalent to (x-1)(x+1)`.
+//!
+//! The difference of the two sides is simplified first; if it becomes 0
+//! they are equivalent. Otherwise both sides are evaluated at pseudo-random
+//! points, which settles identities that simplification cannot prove, such
+//! as `sin(x)^2 + cos(x)^2` and `1`. Points where either side is undefined
+//! are skipped, so `x/x` counts as equivalent to `1`.
+
+use std::collections::BTreeSet;
+
+use super::simplify::simplify;
+use super::symbolic::{as_number, eval_at, strip_groups, substitute};
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Decimal, Expression, Value};
+
+/// Points the two sides are compared at.
+const PROBES: usize = 16;
+/// Points at which both sides must be defined for a numeric verdict.
+const MIN_DEFINED_PROBES: usize = 4;
+/// Largest magnitude of a probed value.
+const PROBE_RANGE: f64 = 10.0;
+/// Relative difference below which two results are taken to be equal.
+const TOLERANCE: f64 = 1e-9;
+
+/// How [`check_equivalence`] reached its verdict.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Equivalence {
+    /// The difference of the sides simplified to 0.
+    Simplified,
+    /// The sides agreed at every probed point where both are defined.
+    Probed {
+        /// How many points both sides were defined at.
+        points: usize,
+    },
+    /// The sides differ at a point.
+    Differs {
+        /// The value of each variable at the point.
+        at: Vec<(String, f64)>,
+        /// The left side there.
+        left: f64,
+        /// The right side there.
+        right: f64,
+    },
+}
+
+impl Equivalence {
+    /// Returns true unless the sides were found to differ.
+    #[must_use]
+    pub fn is_equivalent(&self) -> bool {
+        !matches!(self, Self::Differs { .. })
+    }
+}
+
+/// Decides whether two expressions are equal for every value of their
+/// variables.
+pub fn check_equivalence(
+    left: &Expression,
+    right: &Expression,
+) -> Result<Equivalence, CalculatorError> {
+    if is_zero(&simplify(&difference(left, right))) {
+        return Ok(Equivalence::Simplified);
+    }
+
+    let mut variables = BTreeSet::new();
+    collect_variables(left, &mut variables);
+    collect_variables(right, &mut variables);
+    let mut points = ProbePoints::default();
+    let mut defined = 0;
+    let probes = if variables.is_empty() { 1 } else { PROBES };
+    for _ in 0..probes {
+        let at: Vec<_> = variables
+            .iter()
+            .map(|name| (name.clone(), points.next_value()))
+            .collect();
+        let (Some(l), Some(r)) = (evaluate_at(left, &at), evaluate_at(right, &at)) else {
+            continue;
+        };
+        if (l - r).abs() > TOLERANCE * l.abs().max(r.abs()).max(1.0) {
+            return Ok(Equivalence::Differs {
+                at,
+                left: l,
+                right: r,
+            });
+        }
+        defined += 1;
+    }
+    if defined < MIN_DEFINED_PROBES.min(probes) {
+        return Err(CalculatorError::invalid_args(
+            "equivalent",
+            "the expressions are undefined at too many points to compare",
+        ));
+    }
+    Ok(Equivalence::Probed { points: defined })
+}
+
+/// Evaluates `equivalent(left, right)` to a Boolean, explaining how it was
+/// decided in `steps`.
+pub fn evaluate_equivalence(
+    args: &[Expression],
+    steps: &mut Vec<String>,
+) -> Result<Value, CalculatorError> {
+    let [left, right] = args else {
+        return Err(CalculatorError::invalid_args(
+            "equivalent",
+            "expected two expressions, e.g. is x^2 - 1 equivalent to (x-1)(x+1)",
+        ));
+    };
+    let difference = difference(left, right);
+    steps.push(format!("Simplify the difference: {difference}"));
+    steps.push(format!("= {}", simplify(&difference)));
+    let equivalence = check_equivalence(left, right)?;
+    match &equivalence {
+        Equivalence::Simplified => {
+            steps.push("The difference is 0, so the expressions are equivalent".to_string());
+        }
+        Equivalence::Probed { points } => steps.push(format!(
+            "Both sides agree at {points} sample points, so the expressions are equivalent"
+        )),
+        Equivalence::Differs { at, left, right } => {
+            let at: Vec<_> = at
+                .iter()
+                .map(|(name, value)| format!("{name} = {}", show(*value)))
+                .collect();
+            let at = if at.is_empty() {
+                String::new()
+            } else {
+                format!(" at {}", at.join(", "))
+            };
+            steps.push(format!(
+                "The sides differ{at}: {} vs {}",
+                show(*left),
+                show(*right)
+            ));
+        }
+    }
+    Ok(Value::boolean(equivalence.is_equivalent()))
+}
+
+fn difference(left: &Expression, right: &Expression) -> Expression {
+    Expression::binary(
+        Expression::group(left.clone()),
+        BinaryOp::Subtract,
+        Expression::group(right.clone()),
+    )
+}
+
+fn is_zero(expr: &Expression) -> bool {
+    as_number(&strip_groups(expr)).is_some_and(|value| value.is_zero())
+}
+
+fn collect_variables(expr: &Expression, variables: &mut BTreeSet<String>) {
+    match expr {
+        Expression::Variable(name) => {
+            variables.insert(name.clone());
+        }
+        Expression::Binary { left, right, .. } => {
+            collect_variables(left, variables);
+            collect_variables(right, variables);
+        }
+        Expression::Negate(inner) | Expression::Group(inner) => collect_variables(inner, variables),
+        Expression::Power { base, exponent } => {
+            collect_variables(base, variables);
+            collect_variables(exponent, variables);
+        }
+        Expression::FunctionCall { args, .. } => {
+            for arg in args {
+                collect_variables(arg, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Evaluates `expr` with each variable bound to its value in `at`.
+fn evaluate_at(expr: &Expression, at: &[(String, f64)]) -> Option<f64> {
+    let bound = at.iter().try_fold(expr.clone(), |expr, (name, value)| {
+        let value = Expression::number(Decimal::try_from_f64(*value)?);
+        Some(substitute(&expr, name, &value))
+    })?;
+    eval_at(&bound, "", 0.0)
+}
+
+/// A fixed xorshift sequence of points, so that a verdict is the same every
+/// time it is asked for.
+struct ProbePoints(u64);
+
+impl Default for ProbePoints {
+    fn default() -> Self {
+        Self(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+impl ProbePoints {
+    /// Returns the next value in `-PROBE_RANGE..PROBE_RANGE`.
+    fn next_value(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        #[allow(clippy::cast_precision_loss)] // only the top 53 bits are kept
+        let unit = (self.0 >> 11) as f64 / (1_u64 << 53) as f64;
+        (unit * 2.0 - 1.0) * PROBE_RANGE
+    }
+}
+
+/// Writes a sampled value, to a Decimal's digits where it fits one.
+fn show(value: f64) -> String {
+    Decimal::try_from_f64(value).map_or_else(|| value.to_string(), |d| d.normalize().to_string())
+}
@@ -0,0 +1,94 @@
+//! Exact values of math functions of multiples of π and powers of e, so
+//! that `sin(pi)` is `0` rather than `1.2e-16` and `ln(e^2)` is `2`, and of
+//! the gamma and beta functions of whole numbers, so that `gamma(30)` keeps
+//! every digit and `beta(1, 3)` is `1/3`.
+
+use super::integer_functions::{exact_beta, exact_gamma};
+use crate::error::CalculatorError;
+use crate::types::{Rational, SymbolicConstant, Value};
+
+/// Returns the exact value of the function `name` of `args` when it has a
+/// rational one: the sine, cosine and tangent of a multiple of π (taken as
+/// radians), the natural logarithm of a power of e and the gamma and beta
+/// functions of positive integers. Returns `None` otherwise, leaving the
+/// function to be calculated with decimals.
+pub fn evaluate_exact_function(
+    name: &str,
+    args: &[Value],
+) -> Option<Result<Value, CalculatorError>> {
+    if let ("beta", [a, b]) = (name, args) {
+        return exact_beta(a, b).map(Ok);
+    }
+    if let ("gamma", [n]) = (name, args) {
+        if let Some(gamma) = exact_gamma(n) {
+            return Some(Ok(gamma));
+        }
+    }
+    let [arg] = args else {
+        return None;
+    };
+    let (coefficient, constant, power) = arg.as_symbolic()?;
+    match (name, constant, power) {
+        ("sin", SymbolicConstant::Pi, 1) => sine_of_pi_multiple(coefficient).map(Ok),
+        // cos x = sin(x + π/2)
+        ("cos", SymbolicConstant::Pi, 1) => {
+            sine_of_pi_multiple(&(coefficient.clone() + Rational::new(1, 2))).map(Ok)
+        }
+        ("tan", SymbolicConstant::Pi, 1) => tangent_of_pi_multiple(coefficient),
+        ("ln", SymbolicConstant::E, _) if *coefficient == Rational::from_integer(1) => {
+            Some(Ok(Value::from_integer(i64::from(power))))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `sin(kπ)` when it is rational, which it is when `k` is a
+/// multiple of 1/6 with an odd or zero remainder of sixths.
+fn sine_of_pi_multiple(k: &Rational) -> Option<Value> {
+    let sixths = turn_fraction(k, 6)?;
+    let (numer, denom) = match sixths {
+        0 | 6 => (0, 1),
+        1 | 5 => (1, 2),
+        3 => (1, 1),
+        7 | 11 => (-1, 2),
+        9 => (-1, 1),
+        // √3/2
+        _ => return None,
+    };
+    Some(Value::rational(Rational::new(numer, denom)))
+}
+
+/// Returns `tan(kπ)` when `k` is a multiple of 1/4, and a domain error at
+/// the poles `π/2 + nπ`.
+fn tangent_of_pi_multiple(k: &Rational) -> Option<Result<Value, CalculatorError>> {
+    Some(match turn_fraction(k, 4)? % 4 {
+        0 => Ok(Value::from_integer(0)),
+        1 => Ok(Value::from_integer(1)),
+        2 => Err(CalculatorError::domain("tan is undefined at this value")),
+        _ => Ok(Value::from_integer(-1)),
+    })
+}
+
+/// Returns the angle `kπ` reduced to one turn and counted in `π/parts`,
+/// from `0` to `2 * parts - 1`, or `None` when that is not a whole number.
+fn turn_fraction(k: &Rational, parts: i128) -> Option<i128> {
+    let two = Rational::from_integer(2);
+    let within_turn = k.remainder(&two)?;
+    let n = within_turn * Rational::from_integer(parts);
+    n.is_integer().then(|| n.numer().rem_euclid(2 * parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sine_of_pi_multiples() {
+        let sin = |numer, denom| sine_of_pi_multiple(&Rational::new(numer, denom));
+        assert_eq!(sin(1, 1), Some(Value::from_integer(0)));
+        assert_eq!(sin(-1, 2), Some(Value::from_integer(-1)));
+        assert_eq!(sin(13, 6), Some(Value::rational(Rational::new(1, 2))));
+        assert_eq!(sin(1, 3), None);
+        assert_eq!(sin(1, 5), None);
+    }
+}
@@ -1,17 +1,24 @@
 //! Expression parser that combines all grammars.
 
 use crate::error::CalculatorError;
+use crate::function_registry::word_argument;
 use crate::grammar::linear_equation;
+use crate::grammar::locale_input::{self, InputLocale};
+use crate::grammar::locale_numbers::NumberLocale;
 use crate::grammar::polynomial_equation;
 use crate::grammar::token_parser::TokenParser;
 use crate::grammar::{
-    evaluate_function, evaluate_indefinite_integral, DateTimeGrammar, Lexer, NumberGrammar,
+    evaluate_equivalence, evaluate_indefinite_integral, evaluate_integer_facts, evaluate_power,
+    evaluate_simplify, is_health_function, is_physics_function, is_proportion_function,
+    is_value_function, HealthFormula, Lexer, NumberGrammar, PhysicsSolution, Proportion, Token,
 };
 use crate::types::{
-    BinaryOp, ComparisonOp, CurrencyDatabase, DateTime, Decimal, Expression, Rational, Unit, Value,
-    ValueKind,
+    AngleUnit, AngleWrap, CancellationToken, Clock, ComparisonOp, ConversionReceipt,
+    CurrencyDatabase, DateTimeFormat, Decimal, EvalConfig, Expression, History, PhaseTimings,
+    RateUsage, Rational, StepsLevel, Unit, Value, ValueKind,
 };
-use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
 
 // Local-timezone handling for `now` and bare times lives in a child module so it
 // can access `ExpressionParser`'s private fields while keeping this file small.
@@ -21,71 +28,135 @@ mod timezone;
 #[path = "expression_parser_locale.rs"]
 mod locale;
 
-/// Evaluates a power expression, using exact rational arithmetic when possible.
-///
-/// When both base and exponent are rational and the exponent is an integer
-/// that fits in i32, the computation is exact (arbitrary precision).
-/// Otherwise, falls back to f64 computation.
-///
-/// This function is exposed so downstream consumers can reproduce the
-/// exact-versus-floating-point fallback used inside the evaluator.
-pub fn evaluate_power(base_val: &Value, exp_val: &Value) -> Result<Value, CalculatorError> {
-    // Try exact rational exponentiation first
-    if let (Some(base_rat), Some(exp_rat)) = (base_val.to_rational(), exp_val.to_rational()) {
-        if exp_rat.is_integer() {
-            // Check exponent fits in i32 (reasonable range for exact computation)
-            let exp_i128 = exp_rat.numer();
-            if let Ok(exp_i32) = i32::try_from(exp_i128) {
-                // Guard against absurdly large exponents that would consume too much memory
-                if exp_i32.abs() <= 1_000_000 {
-                    if exp_i32 < 0 && base_rat.is_zero() {
-                        return Err(CalculatorError::domain(
-                            "division by zero (negative exponent with zero base)",
-                        ));
-                    }
-                    let result = base_rat.pow_i32(exp_i32);
-                    return Ok(Value::rational(result));
-                }
-            }
-        }
-    }
-
-    // Fallback to f64 for non-integer exponents or very large exponents
-    let base_dec = base_val
-        .as_decimal()
-        .ok_or_else(|| CalculatorError::InvalidOperation("power base must be numeric".into()))?;
-    let exp_dec = exp_val.as_decimal().ok_or_else(|| {
-        CalculatorError::InvalidOperation("power exponent must be numeric".into())
-    })?;
-
-    let base_f64 = base_dec.to_f64();
-    let exp_f64 = exp_dec.to_f64();
-    let result = base_f64.powf(exp_f64);
-
-    if result.is_nan() {
-        return Err(CalculatorError::domain("power result is undefined"));
-    }
-    if result.is_infinite() {
-        return Err(CalculatorError::Overflow);
-    }
-
-    Ok(Value::number(Decimal::from_f64(result)))
-}
+#[path = "expression_parser_integrate.rs"]
+mod numeric_integration;
+
+#[path = "expression_parser_calculus.rs"]
+mod numeric_calculus;
+
+#[path = "expression_parser_lino.rs"]
+mod lino;
+
+#[path = "expression_parser_limits.rs"]
+mod limits;
+
+#[path = "expression_parser_rate_history.rs"]
+mod rate_history;
+#[path = "expression_parser_rates.rs"]
+mod rates;
+pub use rate_history::RateHistory;
+
+#[path = "expression_parser_context.rs"]
+mod context;
+use context::EvalContext;
+
+#[path = "expression_parser_finance.rs"]
+mod finance;
+
+#[path = "expression_parser_partial.rs"]
+mod partial;
+
+#[path = "expression_parser_shopping.rs"]
+mod shopping;
+
+#[path = "expression_parser_steps.rs"]
+mod steps;
+
+#[path = "expression_parser_comparison.rs"]
+mod comparison;
+
+#[path = "expression_parser_equation.rs"]
+mod equation;
+
+#[path = "expression_parser_graph.rs"]
+mod graph;
+use graph::StepGraph;
+#[path = "expression_parser_angles.rs"]
+mod angles;
+#[path = "expression_parser_binary.rs"]
+mod binary;
+#[path = "expression_parser_bindings.rs"]
+mod bindings;
+#[path = "expression_parser_cache.rs"]
+mod cache;
+#[path = "expression_parser_calculation.rs"]
+mod calculation;
+#[path = "expression_parser_compile.rs"]
+mod compile;
+#[path = "expression_parser_progress.rs"]
+mod progress;
+#[path = "expression_parser_shared.rs"]
+mod shared;
+use cache::ParseCache;
+pub use cache::ParseCacheStats;
+pub use partial::PartialEvaluation;
+pub use progress::ProgressCallback;
+use progress::ProgressHook;
 
 /// Parser for calculator expressions.
 #[derive(Debug, Default)]
 pub struct ExpressionParser {
     number_grammar: NumberGrammar,
-    datetime_grammar: DateTimeGrammar,
     currency_db: CurrencyDatabase,
-    /// Current date context for historical currency conversions (set by AtTime expressions).
-    current_date_context: Option<DateTime>,
+    /// The context set by enclosing `at` clauses, such as the date for
+    /// historical currency conversions.
+    context: EvalContext,
     /// The user's local timezone offset in seconds east of UTC, when known.
     ///
     /// When set, `now` and bare (timezone-less) times such as `12:30` are
     /// interpreted in this local timezone instead of UTC. Explicit timezones
     /// (e.g. `12:30 UTC`) are always honored regardless of this setting.
     local_offset_seconds: Option<i32>,
+    /// Earlier results, referenced as `ans` and `#n`, shared by the
+    /// evaluations of this parser.
+    history: Arc<RwLock<History>>,
+    /// Limits that keep pathological inputs from hanging evaluation.
+    eval_config: EvalConfig,
+    /// Evaluation steps taken so far in the current calculation.
+    operations: u64,
+    /// The rates used by the last currency conversion, reported in its steps.
+    rate_receipt: ConversionReceipt,
+    /// Warnings raised during the last evaluation with steps.
+    warnings: Vec<String>,
+    /// VAT rate used when `+ VAT` is written without one.
+    default_vat_rate: Option<Rational>,
+    /// Labelled parts of the last evaluated result, such as net, VAT and gross.
+    breakdown: Vec<(String, Value)>,
+    /// Whether steps show operands normalized to base units.
+    base_unit_steps: bool,
+    /// Whether steps show the written working of basic arithmetic.
+    teaching_steps: bool,
+    /// The detail level of calculation steps.
+    steps_level: StepsLevel,
+    /// The format of dates and times in steps.
+    datetime_format: DateTimeFormat,
+    /// The computation graph of the last evaluation with steps.
+    step_graph: StepGraph,
+    /// How long the phases of the last calculation took, at the debug
+    /// steps level.
+    phase_timings: Option<PhaseTimings>,
+    /// The number convention input is read in first, when known.
+    number_locale: Option<NumberLocale>,
+    /// How slash dates and the `¥` sign are read, when a locale is set.
+    input_locale: Option<InputLocale>,
+    /// Receives the progress of long-running evaluations.
+    progress: ProgressHook,
+    /// Where the current time comes from, when not the system clock.
+    clock: Option<Arc<dyn Clock>>,
+    /// Set by the host to stop the running calculation.
+    cancellation: Option<CancellationToken>,
+    /// Recently parsed inputs, shared by the evaluations of this parser.
+    parse_cache: Arc<Mutex<ParseCache>>,
+    /// The currency money results are given in, when set.
+    output_currency: Option<Unit>,
+    /// Every exchange rate used by the current calculation.
+    rates_used: Vec<RateUsage>,
+    /// The values of variables, set by [`Self::evaluate_with_bindings`].
+    bindings: HashMap<String, Value>,
+    /// The unit plain numbers are angles in, for trigonometric functions.
+    angle_mode: AngleUnit,
+    /// How the results of arithmetic on angles are brought within a turn.
+    angle_wrap: AngleWrap,
 }
 
 impl ExpressionParser {
@@ -94,10 +165,33 @@ impl ExpressionParser {
     pub fn new() -> Self {
         Self {
             number_grammar: NumberGrammar::new(),
-            datetime_grammar: DateTimeGrammar::new(),
             currency_db: CurrencyDatabase::new(),
-            current_date_context: None,
+            context: EvalContext::default(),
             local_offset_seconds: None,
+            history: Arc::default(),
+            eval_config: EvalConfig::default(),
+            operations: 0,
+            rate_receipt: ConversionReceipt::default(),
+            warnings: Vec::new(),
+            default_vat_rate: None,
+            breakdown: Vec::new(),
+            base_unit_steps: false,
+            teaching_steps: false,
+            steps_level: StepsLevel::Normal,
+            datetime_format: DateTimeFormat::Iso,
+            step_graph: StepGraph::default(),
+            phase_timings: None,
+            number_locale: None,
+            input_locale: None,
+            progress: ProgressHook::default(),
+            clock: None,
+            cancellation: None,
+            parse_cache: Arc::default(),
+            output_currency: None,
+            rates_used: Vec::new(),
+            bindings: HashMap::new(),
+            angle_mode: AngleUnit::Radian,
+            angle_wrap: AngleWrap::None,
         }
     }
 
@@ -111,91 +205,32 @@ impl ExpressionParser {
         &mut self.currency_db
     }
 
-    /// Parses and evaluates an expression, returning the result, steps, and lino representation.
-    pub fn parse_and_evaluate(
-        &mut self,
-        input: &str,
-    ) -> Result<(Value, Vec<String>, String), CalculatorError> {
-        let input = input.trim();
-        if input.is_empty() {
-            return Err(CalculatorError::EmptyInput);
-        }
-
-        self.currency_db.clear_last_used_rate();
-        if let Some(result) = self
-            .datetime_grammar
-            .try_parse_datetime_subtraction(input, self.local_offset_seconds)
-        {
-            return Ok(result);
-        }
-
-        let expr = self.parse(input)?;
-        let lino = expr.to_lino();
-        let (value, steps) = self.evaluate_with_steps(&expr)?;
-
-        Ok((value, steps, lino))
+    pub(super) fn parse_tokenized(&self, input: &str) -> Result<Expression, CalculatorError> {
+        let tokens = Lexer::new(input).tokenize()?;
+        self.parse_tokens(input, tokens)
     }
 
-    pub(super) fn parse_tokenized(&self, input: &str) -> Result<Expression, CalculatorError> {
-        let mut lexer = Lexer::new(input);
-        let tokens = lexer.tokenize()?;
-        let mut parser = TokenParser::new(&tokens, &self.number_grammar, input);
+    /// Builds the expression of `input` from its tokens.
+    fn parse_tokens(
+        &self,
+        input: &str,
+        mut tokens: Vec<Token>,
+    ) -> Result<Expression, CalculatorError> {
+        if let Some(locale) = self.input_locale {
+            locale_input::rewrite_tokens(&mut tokens, locale);
+        }
+        self.check_token_nesting(&tokens)?;
+        self.check_number_lengths(&tokens)?;
+        let mut parser = TokenParser::new(&tokens, &self.number_grammar, input)
+            .with_max_depth(self.eval_config.max_depth);
         let mut expr = parser.parse_complete_expression()?;
+        self.check_expression_depth(&expr)?;
         if let Some(offset) = self.local_offset_seconds {
             expr.apply_local_offset(offset);
         }
         Ok(expr)
     }
 
-    /// Evaluates an expression.
-    pub fn evaluate(&mut self, expr: &Expression) -> Result<Value, CalculatorError> {
-        self.evaluate_expr(expr)
-    }
-
-    fn expression_contains_variable(expr: &Expression) -> bool {
-        match expr {
-            Expression::Variable(_) => true,
-            Expression::Until(inner) | Expression::Negate(inner) | Expression::Group(inner) => {
-                Self::expression_contains_variable(inner)
-            }
-            Expression::Binary { left, right, .. }
-            | Expression::Power {
-                base: left,
-                exponent: right,
-            } => {
-                Self::expression_contains_variable(left)
-                    || Self::expression_contains_variable(right)
-            }
-            Expression::Equality { left, right } | Expression::Comparison { left, right, .. } => {
-                Self::expression_contains_variable(left)
-                    || Self::expression_contains_variable(right)
-            }
-            Expression::AtTime { value, time } => {
-                Self::expression_contains_variable(value)
-                    || Self::expression_contains_variable(time)
-            }
-            Expression::FunctionCall { args, .. } => {
-                args.iter().any(Self::expression_contains_variable)
-            }
-            Expression::IndefiniteIntegral { integrand, .. } => {
-                Self::expression_contains_variable(integrand)
-            }
-            Expression::UnitConversion { value, .. } => Self::expression_contains_variable(value),
-            Expression::Number { .. }
-            | Expression::DateTime(_)
-            | Expression::Now
-            | Expression::Today => false,
-        }
-    }
-
-    fn solve_equation(left: &Expression, right: &Expression) -> Result<Value, CalculatorError> {
-        if let Ok(solution) = linear_equation::solve(left, right) {
-            return Ok(solution.to_value());
-        }
-
-        Ok(polynomial_equation::solve(left, right)?.to_value())
-    }
-
     /// Evaluates an expression with step-by-step tracking.
     ///
     /// Returns the final [`Value`] alongside the human-readable list of steps
@@ -207,12 +242,28 @@ impl ExpressionParser {
         expr: &Expression,
     ) -> Result<(Value, Vec<String>), CalculatorError> {
         let mut steps = Vec::new();
+        self.warnings.clear();
+        self.rates_used.clear();
+        self.breakdown.clear();
+        self.step_graph = StepGraph::default();
 
         steps.push(format!("Input expression: {expr}"));
 
-        let result = self.evaluate_expr_with_steps(expr, &mut steps)?;
+        let mut detail = Vec::new();
+        let result = match self.output_currency_for(expr) {
+            Some(currency) => {
+                let value = self.with_conversion_target(&currency, |parser| {
+                    parser.evaluate_expr_with_steps(expr, &mut detail)
+                })?;
+                self.convert_to_output_currency(value, &currency, &mut detail)?
+            }
+            None => self.evaluate_expr_with_steps(expr, &mut detail)?,
+        };
+        if self.steps_level > StepsLevel::Minimal {
+            steps.append(&mut detail);
+        }
 
-        steps.push(format!("Final result: {}", result.to_display_string()));
+        steps.push(format!("Final result: {}", self.show(&result)));
 
         Ok((result, steps))
     }
@@ -223,22 +274,23 @@ impl ExpressionParser {
     /// exposed so downstream consumers can reuse the calculator's evaluator
     /// when reconstructing computations from a pre-parsed AST.
     pub fn evaluate_expr(&mut self, expr: &Expression) -> Result<Value, CalculatorError> {
+        self.count_operation()?;
         match expr {
             Expression::Number { value, unit, .. } => {
                 // Convert to Rational for exact arithmetic
-                let rational = Rational::from_decimal(*value);
+                let rational = value.clone();
                 Ok(Value::rational_with_unit(rational, unit.clone()))
             }
-            Expression::DateTime(dt) => Ok(Value::datetime(dt.clone())),
+            Expression::Text(text) => Ok(Value::text(text.clone())),
+            Expression::DateTime(dt) => Ok(Value::datetime(self.resolve_datetime(dt))),
             Expression::Now | Expression::Today => Ok(Value::datetime(self.current_date(expr))),
+            Expression::HistoryRef(reference) => self.history().resolve(*reference),
+            Expression::HistoryRange(first, last) => self.history().resolve_range(*first, *last),
             Expression::Until(target) => {
                 let target_val = self.evaluate_expr(target)?;
                 let now = self.current_now();
                 match &target_val.kind {
-                    ValueKind::DateTime(target_dt) => {
-                        let seconds = target_dt.signed_subtract_seconds(&now);
-                        Ok(Value::duration(seconds))
-                    }
+                    ValueKind::DateTime(target_dt) => Ok(Value::date_difference(target_dt, &now)),
                     _ => Err(CalculatorError::InvalidOperation(
                         "until requires a datetime expression".into(),
                     )),
@@ -255,55 +307,56 @@ impl ExpressionParser {
             }
             Expression::Group(inner) => self.evaluate_expr(inner),
             Expression::AtTime { value, time } => {
-                // Evaluate the time expression to get a DateTime
                 let time_val = self.evaluate_expr(time)?;
-
-                // Extract the DateTime for use in currency conversions
-                let date_context = match &time_val.kind {
-                    ValueKind::DateTime(dt) => Some(dt.clone()),
-                    _ => None,
-                };
-
-                // Set the date context for this evaluation
-                let old_context = self.current_date_context.take();
-                self.current_date_context = date_context;
-
-                // Evaluate the value expression with the date context
-                let result = self.evaluate_expr(value);
-
-                // Restore the old context
-                self.current_date_context = old_context;
-
-                result
+                self.with_at_context(&time_val, |parser| parser.evaluate_expr(value))
             }
             Expression::FunctionCall { name, args } => {
                 let name_lower = name.to_lowercase();
 
-                // Special handling for integrate(expr, var, lower, upper)
-                if name_lower == "integrate" {
-                    return self.evaluate_integrate(args);
+                // Special handling for integrate(expr, var, lower, upper) and
+                // the other functions of a variable
+                match name_lower.as_str() {
+                    "integrate" => return self.evaluate_integrate(args),
+                    "nderiv" => return self.evaluate_nderiv(args),
+                    "root" => return self.evaluate_root(args),
+                    "rate_history" => return self.evaluate_rate_history(args, &mut Vec::new()),
+                    "better_deal" => return self.evaluate_better_deal(args, &mut Vec::new()),
+                    _ => {}
                 }
 
-                // Evaluate all arguments
-                let mut arg_values = Vec::new();
-                for arg in args {
+                if name_lower == "simplify" {
+                    return evaluate_simplify(args);
+                }
+                if name_lower == "equivalent" {
+                    return evaluate_equivalence(args, &mut Vec::new());
+                }
+                if let ("factors", [arg]) = (name_lower.as_str(), args.as_slice()) {
                     let val = self.evaluate_expr(arg)?;
-                    // Extract the decimal value
-                    let decimal = val.as_decimal().ok_or_else(|| {
-                        CalculatorError::invalid_args(name, "expected numeric argument")
-                    })?;
-                    arg_values.push(decimal);
+                    return evaluate_integer_facts(&val, &mut Vec::new());
                 }
 
+                // Statistics and list functions keep exact rationals, units and lists
+                if is_value_function(&name_lower) {
+                    let values: Result<Vec<_>, _> = args
+                        .iter()
+                        .map(|arg| match word_argument(&name_lower, arg) {
+                            Some(word) => Ok(word),
+                            None => self.evaluate_expr(arg),
+                        })
+                        .collect();
+                    return self.evaluate_value_call(name, &values?);
+                }
+
+                // Evaluate all arguments
+                let values = args
+                    .iter()
+                    .map(|arg| self.evaluate_expr(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+
                 // Call the function
-                let result = evaluate_function(name, &arg_values)?;
-                Ok(Value::number(result))
-            }
-            Expression::Variable(name) => {
-                // Variables should not appear in direct evaluation
-                // They are only used in integration contexts
-                Err(CalculatorError::eval(format!("undefined variable: {name}")))
+                self.call_math_function(name, &values, &mut Vec::new())
             }
+            Expression::Variable(name) => self.bound_value(name),
             Expression::Power { base, exponent } => {
                 let base_val = self.evaluate_expr(base)?;
                 let exp_val = self.evaluate_expr(exponent)?;
@@ -318,13 +371,12 @@ impl ExpressionParser {
                 // or display the symbolic representation
                 evaluate_indefinite_integral(integrand, variable)
             }
+            Expression::WithPrecision { value, .. } => self.evaluate_expr(value),
+            Expression::RateLookup { from, to } => self.lookup_rate(from, to),
             Expression::UnitConversion { value, target_unit } => {
-                let val = self.evaluate_expr(value)?;
-                val.convert_to_unit_at_date(
-                    target_unit,
-                    &mut self.currency_db,
-                    self.current_date_context.as_ref(),
-                )
+                let val =
+                    self.with_conversion_target(target_unit, |parser| parser.evaluate_expr(value))?;
+                self.convert_value_of(value, &val, target_unit)
             }
             Expression::Equality { left, right } => {
                 if Self::expression_contains_variable(left)
@@ -342,29 +394,39 @@ impl ExpressionParser {
                 let right_val = self.evaluate_expr(right)?;
                 self.evaluate_comparison_values(&left_val, *op, &right_val)
             }
+            Expression::List(items) => {
+                let values: Result<Vec<_>, _> =
+                    items.iter().map(|item| self.evaluate_expr(item)).collect();
+                Ok(Value::list(values?))
+            }
         }
     }
 
-    /// Evaluates an expression, pushing human-readable steps into `steps`.
-    ///
-    /// The same evaluator [`Self::evaluate_with_steps`] uses internally, but
-    /// the caller owns the step buffer. Useful when interleaving step output
-    /// from several sub-evaluations.
-    pub fn evaluate_expr_with_steps(
+    /// Evaluates one node for [`Self::evaluate_expr_with_steps`].
+    fn evaluate_node_with_steps(
         &mut self,
         expr: &Expression,
         steps: &mut Vec<String>,
     ) -> Result<Value, CalculatorError> {
+        self.count_operation()?;
         match expr {
             Expression::Number { value, unit, .. } => {
                 // Convert to Rational for exact arithmetic
-                let rational = Rational::from_decimal(*value);
+                let rational = value.clone();
                 let val = Value::rational_with_unit(rational, unit.clone());
-                steps.push(format!("Literal value: {}", val.to_display_string()));
+                steps.push(format!("Literal value: {}", self.show(&val)));
                 Ok(val)
             }
+            Expression::Text(text) => {
+                steps.push(format!("Text value: \"{text}\""));
+                Ok(Value::text(text.clone()))
+            }
             Expression::DateTime(dt) => {
-                steps.push(format!("DateTime value: {dt}"));
+                let dt = &self.resolve_datetime(dt);
+                steps.push(format!(
+                    "DateTime value: {}",
+                    dt.format_as(&self.datetime_format)
+                ));
                 if let Some(utc_equivalent) = dt.utc_equivalent_display() {
                     steps.push(format!("UTC equivalent: {utc_equivalent}"));
                 }
@@ -392,26 +454,39 @@ impl ExpressionParser {
                 } else {
                     "Today's date"
                 };
-                steps.push(format!("{description}: {date}"));
+                steps.push(format!(
+                    "{description}: {}",
+                    date.format_as(&self.datetime_format)
+                ));
                 Ok(Value::datetime(date))
             }
+            Expression::HistoryRef(reference) => {
+                let value = self.history().resolve(*reference)?;
+                steps.push(format!("Previous result {expr}: {}", self.show(&value)));
+                Ok(value)
+            }
+            Expression::HistoryRange(first, last) => {
+                let value = self.history().resolve_range(*first, *last)?;
+                steps.push(format!("Previous results {expr}: {}", self.show(&value)));
+                Ok(value)
+            }
             Expression::Until(target) => {
                 let target_val = self.evaluate_expr_with_steps(target, steps)?;
                 let now = self.current_now();
                 match &target_val.kind {
                     ValueKind::DateTime(target_dt) => {
                         let seconds = target_dt.signed_subtract_seconds(&now);
-                        let duration = Value::duration(seconds);
+                        let duration = Value::date_difference(target_dt, &now);
                         if seconds >= 0 {
                             steps.push(format!(
                                 "Time until {}: {}",
-                                target_val.to_display_string(),
-                                duration.to_display_string()
+                                self.show(&target_val),
+                                self.show(&duration)
                             ));
                         } else {
                             steps.push(format!(
                                 "Time since {}: {} ago",
-                                target_val.to_display_string(),
+                                self.show(&target_val),
                                 Value::duration(-seconds).to_display_string()
                             ));
                         }
@@ -428,26 +503,23 @@ impl ExpressionParser {
 
                 steps.push(format!(
                     "Compute: {} {} {}",
-                    left_val.to_display_string(),
+                    self.show(&left_val),
                     op,
-                    right_val.to_display_string()
+                    self.show(&right_val)
                 ));
+                self.push_base_unit_steps(&[&left_val, &right_val], steps);
+                self.push_teaching_steps(&left_val, *op, &right_val, steps);
 
                 // Clear any previous rate tracking before the operation
-                self.currency_db.clear_last_used_rate();
+                self.rate_receipt = ConversionReceipt::default();
 
                 let result = self.apply_binary_op(&left_val, *op, &right_val)?;
 
                 // If a currency conversion was used, add rate info to steps.
-                // For cross-rate (triangulated) conversions there may be multiple entries.
-                for (from, to, rate_info) in self.currency_db.get_last_used_rates() {
-                    steps.push(format!(
-                        "Exchange rate: {}",
-                        rate_info.format_for_display(from, to)
-                    ));
-                }
+                self.push_rate_steps(steps);
 
-                steps.push(format!("= {}", result.to_display_string()));
+                steps.push(format!("= {}", self.show(&result)));
+                self.push_base_unit_steps(&[&result], steps);
 
                 Ok(result)
             }
@@ -463,25 +535,10 @@ impl ExpressionParser {
             }
             Expression::AtTime { value, time } => {
                 let time_val = self.evaluate_expr_with_steps(time, steps)?;
-                steps.push(format!("At time: {}", time_val.to_display_string()));
-
-                // Extract the DateTime for use in currency conversions
-                let date_context = match &time_val.kind {
-                    ValueKind::DateTime(dt) => Some(dt.clone()),
-                    _ => None,
-                };
-
-                // Set the date context for this evaluation
-                let old_context = self.current_date_context.take();
-                self.current_date_context = date_context;
-
-                // Evaluate the value expression with the date context
-                let result = self.evaluate_expr_with_steps(value, steps);
-
-                // Restore the old context
-                self.current_date_context = old_context;
-
-                result
+                steps.push(format!("At time: {}", self.show(&time_val)));
+                self.with_at_context(&time_val, |parser| {
+                    parser.evaluate_expr_with_steps(value, steps)
+                })
             }
             Expression::FunctionCall { name, args } => {
                 let name_lower = name.to_lowercase();
@@ -489,20 +546,51 @@ impl ExpressionParser {
                 // Special handling for integrate(expr, var, lower, upper)
                 if name_lower == "integrate" {
                     steps.push(format!("Numerical integration: {}(...)", name));
-                    let result = self.evaluate_integrate(args)?;
-                    steps.push(format!("= {}", result.to_display_string()));
+                    let (result, estimate) = self.integrate_with_estimate(args)?;
+                    steps.push(format!("= {}", self.show(&result)));
+                    steps.push(format!(
+                        "Error estimate: ±{:.1e} (subintervals: {})",
+                        estimate.error, estimate.intervals
+                    ));
                     return Ok(result);
                 }
 
-                let mut arg_values = Vec::new();
+                if name_lower == "simplify" {
+                    return evaluate_simplify(args);
+                }
+
+                if name_lower == "equivalent" {
+                    let val = evaluate_equivalence(args, steps)?;
+                    steps.push(format!("= {}", self.show(&val)));
+                    return Ok(val);
+                }
+
+                if let ("factors", [arg]) = (name_lower.as_str(), args.as_slice()) {
+                    let val = self.evaluate_expr_with_steps(arg, steps)?;
+                    return evaluate_integer_facts(&val, steps);
+                }
+
+                if name_lower == "rate_history" {
+                    return self.evaluate_rate_history(args, steps);
+                }
+
+                if name_lower == "better_deal" {
+                    return self.evaluate_better_deal(args, steps);
+                }
+
+                if let Some(result) = self.calculus_with_steps(&name_lower, args, steps) {
+                    return result;
+                }
+
+                let mut values = Vec::new();
                 let mut arg_display = Vec::new();
                 for arg in args {
-                    let val = self.evaluate_expr_with_steps(arg, steps)?;
-                    arg_display.push(val.to_display_string());
-                    let decimal = val.as_decimal().ok_or_else(|| {
-                        CalculatorError::invalid_args(name, "expected numeric argument")
-                    })?;
-                    arg_values.push(decimal);
+                    let val = match word_argument(&name_lower, arg) {
+                        Some(word) => word,
+                        None => self.evaluate_expr_with_steps(arg, steps)?,
+                    };
+                    arg_display.push(self.show(&val));
+                    values.push(val);
                 }
 
                 steps.push(format!(
@@ -510,13 +598,32 @@ impl ExpressionParser {
                     name,
                     arg_display.join(", ")
                 ));
-                let result = evaluate_function(name, &arg_values)?;
-                let val = Value::number(result);
-                steps.push(format!("= {}", val.to_display_string()));
+                let breakdown_len = self.breakdown.len();
+                let val = if is_proportion_function(&name_lower) {
+                    let proportion = Proportion::compute(name, &values)?;
+                    steps.extend(proportion.formula);
+                    proportion.result
+                } else if is_health_function(&name_lower) {
+                    let health = HealthFormula::compute(name, &values)?;
+                    steps.extend(health.formula);
+                    health.result
+                } else if is_physics_function(&name_lower) {
+                    let solution = PhysicsSolution::solve(&values)?;
+                    steps.extend(solution.formula);
+                    solution.result
+                } else if is_value_function(&name_lower) {
+                    self.evaluate_value_call(name, &values)?
+                } else {
+                    self.call_math_function(name, &values, steps)?
+                };
+                self.push_breakdown_steps(breakdown_len, steps);
+                steps.push(format!("= {}", self.show(&val)));
                 Ok(val)
             }
             Expression::Variable(name) => {
-                Err(CalculatorError::eval(format!("undefined variable: {name}")))
+                let value = self.bound_value(name)?;
+                steps.push(format!("Variable {name}: {}", self.show(&value)));
+                Ok(value)
             }
             Expression::Power { base, exponent } => {
                 let base_val = self.evaluate_expr_with_steps(base, steps)?;
@@ -524,12 +631,12 @@ impl ExpressionParser {
 
                 steps.push(format!(
                     "Compute: {} ^ {}",
-                    base_val.to_display_string(),
-                    exp_val.to_display_string()
+                    self.show(&base_val),
+                    self.show(&exp_val)
                 ));
 
                 let val = evaluate_power(&base_val, &exp_val)?;
-                steps.push(format!("= {}", val.to_display_string()));
+                steps.push(format!("= {}", self.show(&val)));
                 Ok(val)
             }
             Expression::IndefiniteIntegral {
@@ -541,36 +648,32 @@ impl ExpressionParser {
                     integrand, variable
                 ));
                 let result = evaluate_indefinite_integral(integrand, variable)?;
-                steps.push(format!("= {}", result.to_display_string()));
+                steps.push(format!("= {}", self.show(&result)));
                 Ok(result)
             }
+            Expression::WithPrecision { value, .. } => self.evaluate_expr_with_steps(value, steps),
+            Expression::RateLookup { from, to } => {
+                let rate = self.lookup_rate(from, to)?;
+                self.push_rate_steps(steps);
+                steps.push(format!("= {}", self.show(&rate)));
+                Ok(rate)
+            }
             Expression::UnitConversion { value, target_unit } => {
-                let val = self.evaluate_expr_with_steps(value, steps)?;
+                let val = self.with_conversion_target(target_unit, |parser| {
+                    parser.evaluate_expr_with_steps(value, steps)
+                })?;
                 steps.push(format!(
                     "Convert: {} to {}",
-                    val.to_display_string(),
+                    self.show(&val),
                     target_unit.conversion_target_name()
                 ));
 
-                // Clear any previous rate tracking before the conversion
-                self.currency_db.clear_last_used_rate();
-
-                let result = val.convert_to_unit_at_date(
-                    target_unit,
-                    &mut self.currency_db,
-                    self.current_date_context.as_ref(),
-                )?;
+                let result = self.convert_value_of(value, &val, target_unit)?;
 
                 // If a currency conversion was used, add rate info to steps.
-                // For cross-rate (triangulated) conversions there may be multiple entries.
-                for (from, to, rate_info) in self.currency_db.get_last_used_rates() {
-                    steps.push(format!(
-                        "Exchange rate: {}",
-                        rate_info.format_for_display(from, to)
-                    ));
-                }
+                self.push_rate_steps(steps);
 
-                steps.push(format!("= {}", result.to_display_string()));
+                steps.push(format!("= {}", self.show(&result)));
                 Ok(result)
             }
             Expression::Equality { left, right } => {
@@ -587,7 +690,7 @@ impl ExpressionParser {
                         steps.extend(solution.derivation_steps());
                         solution.to_value()
                     };
-                    steps.push(format!("Solution: {}", result.to_display_string()));
+                    steps.push(format!("Solution: {}", self.show(&result)));
                     return Ok(result);
                 }
 
@@ -596,11 +699,11 @@ impl ExpressionParser {
                 let right_val = self.evaluate_expr_with_steps(right, steps)?;
                 steps.push(format!(
                     "Compare: {} = {}",
-                    left_val.to_display_string(),
-                    right_val.to_display_string()
+                    self.show(&left_val),
+                    self.show(&right_val)
                 ));
                 let result = Value::boolean(left_val == right_val);
-                steps.push(format!("= {}", result.to_display_string()));
+                steps.push(format!("= {}", self.show(&result)));
                 Ok(result)
             }
             Expression::Comparison { left, op, right } => {
@@ -613,279 +716,24 @@ impl ExpressionParser {
                 };
                 steps.push(format!(
                     "Compare: {} {} {}",
-                    left_val.to_display_string(),
+                    self.show(&left_val),
                     operator,
-                    right_val.to_display_string()
+                    self.show(&right_val)
                 ));
-                self.currency_db.clear_last_used_rate();
+                self.rate_receipt = ConversionReceipt::default();
                 let result = self.evaluate_comparison_values(&left_val, *op, &right_val)?;
-                for (from, to, rate_info) in self.currency_db.get_last_used_rates() {
-                    steps.push(format!(
-                        "Exchange rate: {}",
-                        rate_info.format_for_display(from, to)
-                    ));
-                }
-                steps.push(format!("= {}", result.to_display_string()));
+                self.push_rate_steps(steps);
+                steps.push(format!("= {}", self.show(&result)));
                 Ok(result)
             }
-        }
-    }
-
-    fn evaluate_comparison_values(
-        &mut self,
-        left: &Value,
-        op: ComparisonOp,
-        right: &Value,
-    ) -> Result<Value, CalculatorError> {
-        if op == ComparisonOp::Equal {
-            return Ok(Value::boolean(
-                self.compare_values(left, right)
-                    .map_or_else(|_| left == right, |ordering| ordering == Ordering::Equal),
-            ));
-        }
-
-        if op == ComparisonOp::NotEqual {
-            return Ok(Value::boolean(
-                self.compare_values(left, right)
-                    .map_or_else(|_| left != right, |ordering| ordering != Ordering::Equal),
-            ));
-        }
-
-        let ordering = self.compare_values(left, right)?;
-        let result = match op {
-            ComparisonOp::Less => Value::boolean(ordering == Ordering::Less),
-            ComparisonOp::LessOrEqual => {
-                Value::boolean(matches!(ordering, Ordering::Less | Ordering::Equal))
-            }
-            ComparisonOp::Greater => Value::boolean(ordering == Ordering::Greater),
-            ComparisonOp::GreaterOrEqual => {
-                Value::boolean(matches!(ordering, Ordering::Greater | Ordering::Equal))
-            }
-            ComparisonOp::Compare => Value::comparison_result(
-                left.to_display_string(),
-                Self::ordering_symbol(ordering),
-                right.to_display_string(),
-            ),
-            ComparisonOp::Equal => unreachable!("handled before ordering comparison"),
-            ComparisonOp::NotEqual => unreachable!("handled before ordering comparison"),
-        };
-
-        Ok(result)
-    }
-
-    fn compare_values(&mut self, left: &Value, right: &Value) -> Result<Ordering, CalculatorError> {
-        if let (Some(left_seconds), Some(right_seconds)) = (
-            Self::duration_seconds_for_comparison(left),
-            Self::duration_seconds_for_comparison(right),
-        ) {
-            return left_seconds.partial_cmp(&right_seconds).ok_or_else(|| {
-                CalculatorError::InvalidOperation(format!(
-                    "cannot order {} and {}",
-                    left.type_name(),
-                    right.type_name()
-                ))
-            });
-        }
-
-        let (left, right) = self.normalize_comparison_values(left, right)?;
-
-        if let (Some(left_rational), Some(right_rational)) =
-            (left.to_rational(), right.to_rational())
-        {
-            return Ok(left_rational.cmp(&right_rational));
-        }
-
-        match (&left.kind, &right.kind) {
-            (ValueKind::DateTime(left_dt), ValueKind::DateTime(right_dt)) => {
-                Ok(left_dt.cmp(right_dt))
-            }
-            (
-                ValueKind::Duration {
-                    seconds: left_seconds,
-                },
-                ValueKind::Duration {
-                    seconds: right_seconds,
-                },
-            ) => Ok(left_seconds.cmp(right_seconds)),
-            _ => Err(CalculatorError::InvalidOperation(format!(
-                "cannot order {} and {}",
-                left.type_name(),
-                right.type_name()
-            ))),
-        }
-    }
-
-    fn normalize_comparison_values(
-        &mut self,
-        left: &Value,
-        right: &Value,
-    ) -> Result<(Value, Value), CalculatorError> {
-        if left.unit == right.unit {
-            return Ok((left.clone(), right.clone()));
-        }
-
-        if left.unit == Unit::None || right.unit == Unit::None {
-            return Err(CalculatorError::InvalidOperation(format!(
-                "cannot compare {} with {}",
-                left.to_display_string(),
-                right.to_display_string()
-            )));
-        }
-
-        let date_context = self.current_date_context.clone();
-        let right_converted = right
-            .convert_to_unit_at_date(&left.unit, &mut self.currency_db, date_context.as_ref())
-            .map_err(|_| {
-                CalculatorError::InvalidOperation(format!(
-                    "cannot compare {} with {}",
-                    left.to_display_string(),
-                    right.to_display_string()
-                ))
-            })?;
-
-        Ok((left.clone(), right_converted))
-    }
-
-    fn duration_seconds_for_comparison(value: &Value) -> Option<f64> {
-        match (&value.kind, &value.unit) {
-            (ValueKind::Duration { seconds }, Unit::None) => Some(*seconds as f64),
-            (ValueKind::Number(decimal), Unit::Duration(unit)) => {
-                Some(unit.to_secs(decimal.to_f64()))
-            }
-            (ValueKind::Rational(rational), Unit::Duration(unit)) => {
-                Some(unit.to_secs(rational.to_f64()))
-            }
-            _ => None,
-        }
-    }
-
-    const fn ordering_symbol(ordering: Ordering) -> &'static str {
-        match ordering {
-            Ordering::Less => "<",
-            Ordering::Equal => "=",
-            Ordering::Greater => ">",
-        }
-    }
-
-    /// Applies a binary operator to two already-evaluated values.
-    ///
-    /// Exposes the same routing the evaluator uses internally so callers can
-    /// reuse currency-aware add/subtract or unit-aware multiply/divide
-    /// outside of a full expression.
-    pub fn apply_binary_op(
-        &mut self,
-        left: &Value,
-        op: BinaryOp,
-        right: &Value,
-    ) -> Result<Value, CalculatorError> {
-        match op {
-            BinaryOp::Add => left.add_at_date(
-                right,
-                &mut self.currency_db,
-                self.current_date_context.as_ref(),
-            ),
-            BinaryOp::Subtract => left.subtract_at_date(
-                right,
-                &mut self.currency_db,
-                self.current_date_context.as_ref(),
-            ),
-            BinaryOp::Multiply => left.multiply(right),
-            BinaryOp::Divide => left.divide(right),
-            BinaryOp::Modulo => left.modulo(right),
-        }
-    }
-
-    /// Evaluates an integrate function call: integrate(expr, var, lower, upper).
-    ///
-    /// Uses numerical integration (Simpson's rule) to compute the definite integral.
-    /// Exposed so downstream consumers can reuse the same integrator when
-    /// reconstructing or composing their own evaluators.
-    #[allow(clippy::many_single_char_names)]
-    pub fn evaluate_integrate(&mut self, args: &[Expression]) -> Result<Value, CalculatorError> {
-        if args.len() != 4 {
-            return Err(CalculatorError::invalid_args(
-                "integrate",
-                "expected 4 arguments: integrate(expr, var, lower, upper)",
-            ));
-        }
-
-        // Second argument must be a variable name
-        let var_name = match &args[1] {
-            Expression::Variable(name) => name.clone(),
-            _ => {
-                return Err(CalculatorError::invalid_args(
-                    "integrate",
-                    "second argument must be a variable name (e.g., x)",
-                ))
+            Expression::List(items) => {
+                let values: Result<Vec<_>, _> = items
+                    .iter()
+                    .map(|item| self.evaluate_expr_with_steps(item, steps))
+                    .collect();
+                Ok(Value::list(values?))
             }
-        };
-
-        // Evaluate lower and upper bounds
-        let lower_val = self.evaluate_expr(&args[2])?;
-        let lower = lower_val.as_decimal().ok_or_else(|| {
-            CalculatorError::invalid_args("integrate", "lower bound must be numeric")
-        })?;
-
-        let upper_val = self.evaluate_expr(&args[3])?;
-        let upper = upper_val.as_decimal().ok_or_else(|| {
-            CalculatorError::invalid_args("integrate", "upper bound must be numeric")
-        })?;
-
-        let a = lower.to_f64();
-        let b = upper.to_f64();
-
-        // The expression to integrate
-        let integrand = &args[0];
-
-        // Numerical integration using Simpson's rule
-        let n = 1000_usize; // Number of subdivisions
-        let h = (b - a) / (n as f64);
-
-        let mut sum = 0.0;
-
-        // f(a) + f(b)
-        sum += self.evaluate_at(integrand, &var_name, a)?.to_f64();
-        sum += self.evaluate_at(integrand, &var_name, b)?.to_f64();
-
-        // 4 * sum of odd terms
-        for i in (1..n).step_by(2) {
-            let x = (i as f64).mul_add(h, a);
-            sum = 4.0_f64.mul_add(self.evaluate_at(integrand, &var_name, x)?.to_f64(), sum);
-        }
-
-        // 2 * sum of even terms
-        for i in (2..n).step_by(2) {
-            let x = (i as f64).mul_add(h, a);
-            sum = 2.0_f64.mul_add(self.evaluate_at(integrand, &var_name, x)?.to_f64(), sum);
         }
-
-        let result = sum * h / 3.0;
-
-        if result.is_nan() {
-            return Err(CalculatorError::domain("integration result is undefined"));
-        }
-        if result.is_infinite() {
-            return Err(CalculatorError::Overflow);
-        }
-
-        Ok(Value::number(Decimal::from_f64(result)))
-    }
-
-    /// Evaluates an expression at a specific numeric value of `var_name`.
-    ///
-    /// Convenience wrapper around [`Self::evaluate_expr_with_var`] that
-    /// coerces the result to a [`Decimal`]. Exposed for downstream consumers
-    /// (e.g. custom integrators or plotters).
-    pub fn evaluate_at(
-        &mut self,
-        expr: &Expression,
-        var_name: &str,
-        value: f64,
-    ) -> Result<Decimal, CalculatorError> {
-        let val = self.evaluate_expr_with_var(expr, var_name, Decimal::from_f64(value))?;
-        val.as_decimal().ok_or_else(|| {
-            CalculatorError::InvalidOperation("expected numeric result in integration".into())
-        })
     }
 
     /// Evaluates an expression with a variable substitution.
@@ -899,21 +747,22 @@ impl ExpressionParser {
         var_name: &str,
         var_value: Decimal,
     ) -> Result<Value, CalculatorError> {
+        self.count_operation()?;
         match expr {
             Expression::Number { value, unit, .. } => {
-                let rational = Rational::from_decimal(*value);
+                let rational = value.clone();
                 Ok(Value::rational_with_unit(rational, unit.clone()))
             }
-            Expression::DateTime(dt) => Ok(Value::datetime(dt.clone())),
+            Expression::Text(text) => Ok(Value::text(text.clone())),
+            Expression::DateTime(dt) => Ok(Value::datetime(self.resolve_datetime(dt))),
             Expression::Now | Expression::Today => Ok(Value::datetime(self.current_date(expr))),
+            Expression::HistoryRef(reference) => self.history().resolve(*reference),
+            Expression::HistoryRange(first, last) => self.history().resolve_range(*first, *last),
             Expression::Until(target) => {
                 let target_val = self.evaluate_expr_with_var(target, var_name, var_value)?;
                 let now = self.current_now();
                 match &target_val.kind {
-                    ValueKind::DateTime(target_dt) => {
-                        let seconds = target_dt.signed_subtract_seconds(&now);
-                        Ok(Value::duration(seconds))
-                    }
+                    ValueKind::DateTime(target_dt) => Ok(Value::date_difference(target_dt, &now)),
                     _ => Err(CalculatorError::InvalidOperation(
                         "until requires a datetime expression".into(),
                     )),
@@ -930,8 +779,10 @@ impl ExpressionParser {
             }
             Expression::Group(inner) => self.evaluate_expr_with_var(inner, var_name, var_value),
             Expression::AtTime { value, time } => {
-                let _time_val = self.evaluate_expr_with_var(time, var_name, var_value)?;
-                self.evaluate_expr_with_var(value, var_name, var_value)
+                let time_val = self.evaluate_expr_with_var(time, var_name, var_value)?;
+                self.with_at_context(&time_val, |parser| {
+                    parser.evaluate_expr_with_var(value, var_name, var_value)
+                })
             }
             Expression::FunctionCall { name, args } => {
                 let name_lower = name.to_lowercase();
@@ -943,26 +794,40 @@ impl ExpressionParser {
                         "nested integration is not supported",
                     ));
                 }
+                if name_lower == "nderiv" || name_lower == "root" {
+                    return Err(CalculatorError::invalid_args(
+                        name,
+                        "cannot be used inside integrate, nderiv or root",
+                    ));
+                }
 
-                // Evaluate all arguments with variable substitution
-                let mut arg_values = Vec::new();
-                for arg in args {
-                    let val = self.evaluate_expr_with_var(arg, var_name, var_value)?;
-                    let decimal = val.as_decimal().ok_or_else(|| {
-                        CalculatorError::invalid_args(name, "expected numeric argument")
-                    })?;
-                    arg_values.push(decimal);
+                if name_lower == "simplify" {
+                    return Err(CalculatorError::invalid_args(
+                        "simplify",
+                        "simplify cannot be used inside another expression",
+                    ));
+                }
+                if name_lower == "equivalent" {
+                    return Err(CalculatorError::invalid_args(
+                        "equivalent",
+                        "cannot be used inside integrate, nderiv or root",
+                    ));
                 }
 
-                let result = evaluate_function(name, &arg_values)?;
-                Ok(Value::number(result))
+                // Evaluate all arguments with variable substitution
+                let values = args
+                    .iter()
+                    .map(|arg| self.evaluate_expr_with_var(arg, var_name, var_value))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.call_math_function(name, &values, &mut Vec::new())
             }
             Expression::Variable(name) => {
                 if name == var_name {
                     // Keep as Decimal for integration (numerical computation)
                     Ok(Value::number(var_value))
                 } else {
-                    Err(CalculatorError::eval(format!("undefined variable: {name}")))
+                    self.bound_value(name)
                 }
             }
             Expression::Power { base, exponent } => {
@@ -974,13 +839,15 @@ impl ExpressionParser {
                 "nested integration",
                 "nested indefinite integrals are not supported",
             )),
+            Expression::WithPrecision { value, .. } => {
+                self.evaluate_expr_with_var(value, var_name, var_value)
+            }
+            Expression::RateLookup { from, to } => self.lookup_rate(from, to),
             Expression::UnitConversion { value, target_unit } => {
-                let val = self.evaluate_expr_with_var(value, var_name, var_value)?;
-                val.convert_to_unit_at_date(
-                    target_unit,
-                    &mut self.currency_db,
-                    self.current_date_context.as_ref(),
-                )
+                let val = self.with_conversion_target(target_unit, |parser| {
+                    parser.evaluate_expr_with_var(value, var_name, var_value)
+                })?;
+                self.convert_value_of(value, &val, target_unit)
             }
             Expression::Equality { left, right } => {
                 let left_val = self.evaluate_expr_with_var(left, var_name, var_value)?;
@@ -992,6 +859,13 @@ impl ExpressionParser {
                 let right_val = self.evaluate_expr_with_var(right, var_name, var_value)?;
                 self.evaluate_comparison_values(&left_val, *op, &right_val)
             }
+            Expression::List(items) => {
+                let values: Result<Vec<_>, _> = items
+                    .iter()
+                    .map(|item| self.evaluate_expr_with_var(item, var_name, var_value))
+                    .collect();
+                Ok(Value::list(values?))
+            }
         }
     }
 }
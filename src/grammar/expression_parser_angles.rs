@@ -0,0 +1,210 @@
+//! The angle mode of [`ExpressionParser`]: the unit the trigonometric
+//! functions read plain numbers in, and the inverse ones answer in. An angle
+//! written with its unit, as in `sin(30°)`, is read in that unit whatever
+//! the mode. Arithmetic on angles can also be wrapped into one turn.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::evaluate_power;
+use crate::grammar::exact_constants::evaluate_exact_function;
+use crate::grammar::math_functions::root;
+use crate::grammar::number_grammar::MAX_EXPONENT;
+use crate::grammar::special_functions::gamma_scientific;
+use crate::types::{AngleUnit, AngleWrap, Decimal, Rational, Unit, Value};
+
+/// Functions of an angle.
+const ANGLE_FUNCTIONS: &[&str] = &["sin", "cos", "tan"];
+
+/// Functions whose result is an angle.
+const INVERSE_ANGLE_FUNCTIONS: &[&str] = &["asin", "acos", "atan", "atan2"];
+
+/// Decimal places the trigonometric functions of an angle in degrees or
+/// gradians are rounded to, so that `cos(90°)` is `0` rather than `6e-17`.
+const TRIG_PLACES: u32 = 14;
+
+/// Decimal places angles answered in degrees or gradians are rounded to, so
+/// that `asin(0.5)` is `30` rather than `30.000000000000004`.
+const ANGLE_PLACES: u32 = 12;
+
+impl ExpressionParser {
+    /// Returns the unit plain numbers are angles in.
+    pub fn angle_mode(&self) -> AngleUnit {
+        self.angle_mode
+    }
+
+    /// Sets the unit plain numbers are angles in: `sin(30)` is ½ in
+    /// degrees, and `asin(0.5)` is `30`.
+    pub fn set_angle_mode(&mut self, mode: AngleUnit) {
+        self.angle_mode = mode;
+    }
+
+    /// Returns how the results of arithmetic on angles are brought within
+    /// a turn.
+    pub fn angle_wrap(&self) -> AngleWrap {
+        self.angle_wrap
+    }
+
+    /// Sets how the results of arithmetic on angles are brought within a
+    /// turn: with [`AngleWrap::Full`], `350° + 20°` is `10°`.
+    pub fn set_angle_wrap(&mut self, wrap: AngleWrap) {
+        self.angle_wrap = wrap;
+    }
+
+    /// Returns a plain number converted to the angle unit `target` as an
+    /// angle in the angle mode, as the trigonometric functions read it:
+    /// `pi in degrees` is `180°` in radians. Other values are returned as
+    /// they are.
+    pub(super) fn as_angle(&self, value: &Value, target: &Unit) -> Value {
+        let mut value = value.clone();
+        if value.unit == Unit::None && matches!(target, Unit::Angle(_)) {
+            value.unit = Unit::Angle(self.angle_mode);
+        }
+        value
+    }
+
+    /// Brings an angle calculated by arithmetic within the range of the
+    /// angle wrapping, in its own unit. Other values are returned as they
+    /// are.
+    pub(super) fn wrap_angle(&self, value: Value) -> Value {
+        let Unit::Angle(unit) = value.unit else {
+            return value;
+        };
+        match value.to_rational() {
+            Some(angle) if self.angle_wrap != AngleWrap::None => {
+                Value::rational_with_unit(self.angle_wrap.apply(&angle, unit), value.unit)
+            }
+            _ => value,
+        }
+    }
+
+    /// Calls the math function `name` with the numbers of `values`. The
+    /// trigonometric functions take an angle in its unit, or in the angle
+    /// mode if it has none, and the inverse ones answer in the angle mode;
+    /// the steps say which unit was used. `pi` and `e` are kept symbolic,
+    /// and functions of them that have exact values, such as `sin(pi)`,
+    /// answer exactly.
+    pub(super) fn call_math_function(
+        &self,
+        name: &str,
+        values: &[Value],
+        steps: &mut Vec<String>,
+    ) -> Result<Value, CalculatorError> {
+        let name_lower = name.to_lowercase();
+        if values.is_empty() {
+            if let Some(constant) = Value::constant(&name_lower) {
+                return Ok(constant);
+            }
+        }
+        if self.angle_mode == AngleUnit::Radian || !ANGLE_FUNCTIONS.contains(&name_lower.as_str()) {
+            if let Some(result) = evaluate_exact_function(&name_lower, values) {
+                return result;
+            }
+        }
+        // Roots are taken on the exact digits, which may be past a Decimal,
+        // and of an amount in a unit as the power that takes the unit's root
+        if let ("sqrt" | "cbrt", [value]) = (name_lower.as_str(), values) {
+            if value.unit.has_dimension() {
+                let degree = if name_lower == "sqrt" { 2 } else { 3 };
+                return evaluate_power(value, &Value::rational(Rational::new(1, degree)));
+            }
+            if let (Some(x), Unit::None) = (value.as_rational(), &value.unit) {
+                if name_lower == "sqrt" && x.is_negative() {
+                    return Err(CalculatorError::domain(
+                        "sqrt argument must be non-negative",
+                    ));
+                }
+                let degree = if name_lower == "sqrt" { 2 } else { 3 };
+                return Ok(Value::number(root(x, degree)?));
+            }
+        }
+        let mut args = values
+            .iter()
+            .map(|value| {
+                value.as_decimal().ok_or_else(|| {
+                    if value.to_rational().is_some() {
+                        CalculatorError::invalid_args(
+                            name,
+                            "argument is too large for a 28-digit decimal",
+                        )
+                    } else {
+                        CalculatorError::invalid_args(name, "expected numeric argument")
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let ([value], [angle]) = (values, args.as_mut_slice()) {
+            let unit = match value.unit {
+                Unit::Angle(unit) => unit,
+                _ => self.angle_mode,
+            };
+            if ANGLE_FUNCTIONS.contains(&name_lower.as_str()) && unit != AngleUnit::Radian {
+                if name_lower == "tan" && is_odd_quarter_turn(*angle, unit) {
+                    return Err(CalculatorError::domain("tan is undefined at this value"));
+                }
+                let written = Value::number_with_unit(*angle, Unit::Angle(unit));
+                let radians = Decimal::from_f64(angle.to_f64() * unit.radians())?;
+                let shown = Value::number(radians).to_display_string();
+                steps.push(if matches!(value.unit, Unit::Angle(_)) {
+                    format!(
+                        "Convert {} to radians: {shown} rad",
+                        written.to_display_string()
+                    )
+                } else {
+                    format!(
+                        "Angle mode {}: {angle} is {} = {shown} rad",
+                        unit.name(),
+                        written.to_display_string()
+                    )
+                });
+                *angle = radians;
+                return Ok(Value::number(
+                    self.evaluate_limited_function(name, &args)?
+                        .round(TRIG_PLACES),
+                ));
+            }
+        }
+
+        let result = match self.evaluate_limited_function(name, &args) {
+            Err(CalculatorError::Overflow) if name_lower == "gamma" && !args[0].is_negative() => {
+                return large_gamma(args[0]);
+            }
+            result => result?,
+        };
+        if self.angle_mode == AngleUnit::Radian
+            || !INVERSE_ANGLE_FUNCTIONS.contains(&name_lower.as_str())
+        {
+            return Ok(Value::number(result));
+        }
+        let angle =
+            Decimal::from_f64(result.to_f64() / self.angle_mode.radians())?.round(ANGLE_PLACES);
+        steps.push(format!(
+            "Angle mode {}: {result} rad is {}",
+            self.angle_mode.name(),
+            Value::number_with_unit(angle, Unit::Angle(self.angle_mode)).to_display_string()
+        ));
+        Ok(Value::number(angle))
+    }
+}
+
+/// Γ(x) of a positive x past the range of a Decimal, to the digits of an
+/// `f64`: `gamma(30.5)` is `4.82269693349089e31`.
+fn large_gamma(x: Decimal) -> Result<Value, CalculatorError> {
+    let (mantissa, exponent) = gamma_scientific(x.to_f64())?;
+    if exponent.unsigned_abs() > MAX_EXPONENT {
+        return Err(CalculatorError::Overflow);
+    }
+    let mantissa =
+        Rational::parse_decimal_digits(&mantissa.to_string()).ok_or(CalculatorError::Overflow)?;
+    Ok(Value::rational(mantissa * Rational::from_integer(10).pow_i32(exponent)).inexact())
+}
+
+/// Checks if `angle` is an odd number of quarter turns, where tan has a
+/// pole.
+fn is_odd_quarter_turn(angle: Decimal, unit: AngleUnit) -> bool {
+    if unit == AngleUnit::Radian {
+        return false;
+    }
+    let quarters = Rational::from_decimal(angle) * Rational::from_integer(4) / unit.turn();
+    quarters.is_integer() && quarters.numer() % 2 != 0
+}
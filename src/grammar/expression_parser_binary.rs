@@ -0,0 +1,86 @@
+//! Binary operators of [`ExpressionParser`] on evaluated values, routed to
+//! the matrix, color, time-of-day and physics quantity rules before plain
+//! arithmetic, with currency amounts converted at their rate dates.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::power::divide_by_unit;
+use crate::grammar::quantity_op;
+use crate::types::{BinaryOp, ConversionReceipt, Value};
+
+impl ExpressionParser {
+    /// Applies a binary operator to two already-evaluated values.
+    ///
+    /// Exposes the same routing the evaluator uses internally so callers can
+    /// reuse currency-aware add/subtract or unit-aware multiply/divide
+    /// outside of a full expression.
+    pub fn apply_binary_op(
+        &mut self,
+        left: &Value,
+        op: BinaryOp,
+        right: &Value,
+    ) -> Result<Value, CalculatorError> {
+        if let Some(result) = Value::matrix_op(left, op, right) {
+            return result;
+        }
+        if let Some(result) = Value::color_op(left, op, right) {
+            return result;
+        }
+        if let Some(result) = Value::time_of_day_op(left, op, right) {
+            return result;
+        }
+        if let Some(result) = quantity_op(left, op, right) {
+            return result;
+        }
+        if let Some(result) = divide_by_unit(left, op, right) {
+            return result;
+        }
+        if let Some(result) = Value::broadcast(left, right, |l, r| self.apply_binary_op(l, op, r)) {
+            return result;
+        }
+        if let Some(result) =
+            Value::symbolic_op(left, op, right, |l, r| self.apply_binary_op(l, op, r))
+        {
+            return result;
+        }
+        let mut result = match op {
+            BinaryOp::Add | BinaryOp::Subtract => {
+                let (result, receipt) = self.add_or_subtract(left, op, right)?;
+                self.rate_receipt = receipt;
+                result
+            }
+            BinaryOp::Multiply => left.multiply(right)?,
+            BinaryOp::Divide => left.divide(right)?,
+            BinaryOp::Modulo => left.modulo(right)?,
+        };
+        if result.unit.is_currency() {
+            result.rate_date = self.merged_rate_date(left, right);
+        }
+        Ok(self.wrap_angle(result))
+    }
+
+    /// Adds or subtracts two values, converting amounts of money at the
+    /// date each one is pinned to.
+    fn add_or_subtract(
+        &mut self,
+        left: &Value,
+        op: BinaryOp,
+        right: &Value,
+    ) -> Result<(Value, ConversionReceipt), CalculatorError> {
+        let pinned = self.amounts_in_target_currency(left, right)?;
+        let (left, right, date) = match &pinned {
+            Some((left, right, _)) => (left, right, None),
+            None => (left, right, self.effective_rate_date(right).cloned()),
+        };
+        let (result, operation_receipt) = if op == BinaryOp::Add {
+            left.add_at_date(right, &self.currency_db, date.as_ref())?
+        } else {
+            left.subtract_at_date(right, &self.currency_db, date.as_ref())?
+        };
+        self.rates_used
+            .extend(operation_receipt.usages(date.as_ref()));
+        let mut receipt = pinned.map(|(_, _, receipt)| receipt).unwrap_or_default();
+        receipt.extend(operation_receipt);
+        Ok((result, receipt))
+    }
+}
@@ -0,0 +1,106 @@
+//! Variable bindings for [`ExpressionParser`], so that a host can parse an
+//! expression once and evaluate it many times with different values of its
+//! variables, as plotting or optimization loops do.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Expression, Rational, Unit, Value};
+
+impl ExpressionParser {
+    /// Evaluates `expr` with each variable named in `bindings` standing for
+    /// its value: `x^2 + y` with `x = 3 m` and `y = 1 m²` is `10 m²`.
+    ///
+    /// Variables are single letters in parsed input; an [`Expression`]
+    /// built with [`Expression::variable`] may use any name. A number
+    /// written against a bound name, such as `3x` or `2y`, is a multiple of
+    /// the variable rather than a quantity in the unit of that name. The
+    /// bindings last for this evaluation only, and a variable left unbound
+    /// is an error.
+    pub fn evaluate_with_bindings(
+        &self,
+        expr: &Expression,
+        bindings: &[(&str, Value)],
+    ) -> Result<Value, CalculatorError> {
+        let expr = bindings.iter().fold(expr.clone(), |expr, (name, _)| {
+            let unit = self
+                .number_grammar
+                .parse_unit_with_alternatives(name)
+                .map_or_else(|_| Unit::Custom((*name).to_string()), |(unit, _)| unit);
+            multiples_of(&expr, name, &unit)
+        });
+        let mut parser = self.working_copy();
+        parser.bindings = bindings
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), value.clone()))
+            .collect();
+        parser.evaluate(&expr)
+    }
+
+    /// Returns the value bound to the variable `name`.
+    pub(super) fn bound_value(&self, name: &str) -> Result<Value, CalculatorError> {
+        self.bindings
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CalculatorError::eval(format!("undefined variable: {name}")))
+    }
+}
+
+/// Rewrites numbers in `unit`, the unit `name` reads as, as multiples of
+/// the variable `name`: the parser took `3x` for 3 in the unit `x` and
+/// `2y^2` for two square years, not knowing they would be bound.
+fn multiples_of(expr: &Expression, name: &str, unit: &Unit) -> Expression {
+    let variable = || Expression::variable(name);
+    let multiple = |value: &Rational, variable: Expression| {
+        Expression::binary(
+            Expression::number(value.clone()),
+            BinaryOp::Multiply,
+            variable,
+        )
+    };
+    match expr {
+        Expression::Number {
+            value,
+            unit: written,
+            ..
+        } => match written {
+            written if written == unit => multiple(value, variable()),
+            Unit::Power { base, exponent } if **base == *unit => {
+                let exponent = Expression::number(i64::from(*exponent));
+                multiple(value, Expression::power(variable(), exponent))
+            }
+            _ => expr.clone(),
+        },
+        // "3x^2" is 3 * x^2, not (3x)^2
+        Expression::Power { base, exponent } => match base.as_ref() {
+            Expression::Number {
+                value,
+                unit: written,
+                ..
+            } if written == unit => multiple(
+                value,
+                Expression::power(variable(), multiples_of(exponent, name, unit)),
+            ),
+            _ => Expression::power(
+                multiples_of(base, name, unit),
+                multiples_of(exponent, name, unit),
+            ),
+        },
+        Expression::Binary { left, op, right } => Expression::binary(
+            multiples_of(left, name, unit),
+            *op,
+            multiples_of(right, name, unit),
+        ),
+        Expression::Negate(inner) => Expression::negate(multiples_of(inner, name, unit)),
+        Expression::Group(inner) => Expression::group(multiples_of(inner, name, unit)),
+        Expression::FunctionCall {
+            name: function,
+            args,
+        } => Expression::function_call(
+            function.clone(),
+            args.iter()
+                .map(|arg| multiples_of(arg, name, unit))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
@@ -0,0 +1,226 @@
+//! A least-recently-used cache of parsed expressions, so that re-evaluating
+//! the same input on every keystroke, as the web app does, skips the parser.
+//!
+//! Parsing reads bare times as today and month-day dates as this year, so the
+//! cache empties when the UTC date changes. Inputs that mention the current
+//! time, such as `now`, are never cached.
+
+use std::collections::HashMap;
+use std::sync::MutexGuard;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::locale_input::InputLocale;
+use crate::grammar::locale_numbers::NumberLocale;
+use crate::sync::lock_recover;
+use crate::types::Expression;
+
+/// How many inputs the cache holds by default.
+const DEFAULT_CAPACITY: usize = 256;
+
+/// Counters describing how well the parse cache is working.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseCacheStats {
+    /// Parses answered from the cache.
+    pub hits: u64,
+    /// Parses that ran the parser.
+    pub misses: u64,
+    /// Entries dropped to make room for newer inputs.
+    pub evictions: u64,
+    /// Inputs currently cached.
+    pub entries: usize,
+    /// Inputs the cache holds before evicting the least recently used.
+    pub capacity: usize,
+}
+
+/// An input and the settings its parse depends on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ParseKey {
+    input: String,
+    number_locale: Option<NumberLocale>,
+    input_locale: Option<InputLocale>,
+    local_offset_seconds: Option<i32>,
+    max_depth: usize,
+}
+
+/// Every interpretation of an input, or the error parsing it raised.
+type Parsed = Result<Vec<Expression>, CalculatorError>;
+
+/// Parsed inputs with the tick each was last used at.
+#[derive(Debug)]
+pub(super) struct ParseCache {
+    entries: HashMap<ParseKey, (Parsed, u64)>,
+    /// The UTC date the entries were parsed on.
+    day: Option<NaiveDate>,
+    tick: u64,
+    stats: ParseCacheStats,
+}
+
+impl Default for ParseCache {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            day: None,
+            tick: 0,
+            stats: ParseCacheStats {
+                capacity: DEFAULT_CAPACITY,
+                ..ParseCacheStats::default()
+            },
+        }
+    }
+}
+
+impl ParseCache {
+    fn get(&mut self, key: &ParseKey, day: NaiveDate) -> Option<Parsed> {
+        if self.day != Some(day) {
+            self.entries.clear();
+            self.day = Some(day);
+        }
+        self.tick += 1;
+        if let Some((parsed, used)) = self.entries.get_mut(key) {
+            *used = self.tick;
+            self.stats.hits += 1;
+            return Some(parsed.clone());
+        }
+        self.stats.misses += 1;
+        None
+    }
+
+    fn insert(&mut self, key: ParseKey, parsed: Parsed) {
+        if self.stats.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) {
+            self.evict_to(self.stats.capacity - 1);
+        }
+        self.entries.insert(key, (parsed, self.tick));
+    }
+
+    /// Drops the least recently used entries until at most `len` remain.
+    fn evict_to(&mut self, len: usize) {
+        while self.entries.len() > len {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            let Some(oldest) = oldest else {
+                return;
+            };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+impl ExpressionParser {
+    /// Locks the parse cache, which the evaluations of one parser share.
+    fn parse_cache(&self) -> MutexGuard<'_, ParseCache> {
+        lock_recover(&self.parse_cache)
+    }
+
+    /// Returns the parse cache's hit, miss and eviction counts and size.
+    pub fn parse_cache_stats(&self) -> ParseCacheStats {
+        let cache = self.parse_cache();
+        ParseCacheStats {
+            entries: cache.entries.len(),
+            ..cache.stats
+        }
+    }
+
+    /// Sets how many inputs the parse cache holds; 0 turns caching off.
+    pub fn set_parse_cache_capacity(&mut self, capacity: usize) {
+        let mut cache = self.parse_cache();
+        cache.stats.capacity = capacity;
+        cache.evict_to(capacity);
+    }
+
+    /// Empties the parse cache and resets its counters.
+    pub fn clear_parse_cache(&mut self) {
+        let mut cache = self.parse_cache();
+        let capacity = cache.stats.capacity;
+        *cache = ParseCache::default();
+        cache.stats.capacity = capacity;
+    }
+
+    /// Returns the cached parse of `input` under the current settings, or
+    /// runs `parse` and caches its result.
+    pub(super) fn cached_parse(&self, input: &str, parse: impl FnOnce() -> Parsed) -> Parsed {
+        let key = ParseKey {
+            input: input.to_string(),
+            number_locale: self.number_locale,
+            input_locale: self.input_locale,
+            local_offset_seconds: self.local_offset_seconds,
+            max_depth: self.eval_config.max_depth,
+        };
+        let today = self.clock_now().date_naive();
+        let cached = self.parse_cache().get(&key, today);
+        if let Some(parsed) = cached {
+            return parsed;
+        }
+        let parsed = parse();
+        let live = parsed
+            .as_ref()
+            .is_ok_and(|exprs| exprs.iter().any(Expression::contains_live_time));
+        if !live {
+            self.parse_cache().insert(key, parsed.clone());
+        }
+        parsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_input_hits_cache() {
+        let parser = ExpressionParser::new();
+        parser.parse("2 + 3").unwrap();
+        parser.parse("2 + 3").unwrap();
+        let stats = parser.parse_cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut parser = ExpressionParser::new();
+        parser.set_parse_cache_capacity(2);
+        for input in ["1", "2", "1", "3", "1", "2"] {
+            parser.parse(input).unwrap();
+        }
+        let stats = parser.parse_cache_stats();
+        // "2" is evicted by "3", then "3" by "2"; "1" stays cached
+        assert_eq!((stats.hits, stats.misses), (2, 4));
+        assert_eq!((stats.evictions, stats.entries), (2, 2));
+    }
+
+    #[test]
+    fn test_live_time_is_not_cached() {
+        let parser = ExpressionParser::new();
+        parser.parse("now").unwrap();
+        parser.parse("now").unwrap();
+        assert_eq!(parser.parse_cache_stats().hits, 0);
+    }
+
+    #[test]
+    fn test_errors_are_cached() {
+        let parser = ExpressionParser::new();
+        assert!(parser.parse("2 +").is_err());
+        assert!(parser.parse("2 +").is_err());
+        assert_eq!(parser.parse_cache_stats().hits, 1);
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_cache() {
+        let mut parser = ExpressionParser::new();
+        parser.set_parse_cache_capacity(0);
+        parser.parse("2 + 3").unwrap();
+        parser.parse("2 + 3").unwrap();
+        let stats = parser.parse_cache_stats();
+        assert_eq!((stats.hits, stats.entries), (0, 0));
+    }
+}
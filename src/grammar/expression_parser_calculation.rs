@@ -0,0 +1,69 @@
+//! Calculations on [`ExpressionParser`]: parsing and evaluating one input,
+//! written as text or in links notation, and the result history the
+//! calculations share.
+
+use std::sync::{RwLockReadGuard, RwLockWriteGuard};
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::sync::{read_recover, write_recover};
+use crate::types::{ConversionReceipt, History, PhaseTimings, StepsLevel, Value};
+
+impl ExpressionParser {
+    /// Returns the results that `ans` and `#n` refer to.
+    pub fn history(&self) -> RwLockReadGuard<'_, History> {
+        read_recover(&self.history)
+    }
+
+    /// Returns the result history for changing.
+    pub fn history_mut(&self) -> RwLockWriteGuard<'_, History> {
+        write_recover(&self.history)
+    }
+
+    /// Parses and evaluates an expression, returning the result, steps, and lino representation.
+    pub fn parse_and_evaluate(
+        &mut self,
+        input: &str,
+    ) -> Result<(Value, Vec<String>, String), CalculatorError> {
+        let leading_whitespace = input.chars().take_while(|c| c.is_whitespace()).count();
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(CalculatorError::EmptyInput);
+        }
+
+        self.start_calculation();
+        let expr = self
+            .parse(input)
+            .map_err(|e| e.offset_span(leading_whitespace))?;
+        let lino = expr.to_lino();
+        let started = self.clock_now();
+        let (value, mut steps) = self.evaluate_with_steps(&expr)?;
+        if self.steps_level == StepsLevel::Debug {
+            let eval_ms = PhaseTimings::millis_between(started, self.clock_now());
+            steps.splice(0..0, self.debug_steps(input));
+            self.phase_timings = Some(self.time_parsing(input, eval_ms));
+        }
+
+        Ok((value, steps, lino))
+    }
+
+    /// Parses an expression given in links notation, such as the
+    /// `lino_interpretation` of an earlier result, and evaluates it as
+    /// [`Self::parse_and_evaluate`] does.
+    pub fn parse_lino_and_evaluate(
+        &mut self,
+        lino: &str,
+    ) -> Result<(Value, Vec<String>, String), CalculatorError> {
+        self.start_calculation();
+        let expr = self.parse_lino(lino)?;
+        let (value, steps) = self.evaluate_with_steps(&expr)?;
+        Ok((value, steps, expr.to_lino()))
+    }
+
+    /// Forgets the rates, timings and step count of the last calculation.
+    fn start_calculation(&mut self) {
+        self.rate_receipt = ConversionReceipt::default();
+        self.phase_timings = None;
+        self.reset_operation_count();
+    }
+}
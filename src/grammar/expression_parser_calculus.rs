@@ -0,0 +1,135 @@
+//! Numerical differentiation and root finding for [`ExpressionParser`].
+//!
+//! Evaluates `nderiv(expr, var, at)` and `root(expr, var, lower, upper)`
+//! with the algorithms in [`crate::grammar::derivative`] and
+//! [`crate::grammar::find_root`].
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::{derivative, find_root};
+use crate::types::{Decimal, Expression, Value};
+
+impl ExpressionParser {
+    /// Evaluates `nderiv(expr, var, at)`: the derivative of `expr` with
+    /// respect to `var` at the point `at`.
+    pub fn evaluate_nderiv(&mut self, args: &[Expression]) -> Result<Value, CalculatorError> {
+        let [expr, var, at] = args else {
+            return Err(CalculatorError::invalid_args(
+                "nderiv",
+                "expected 3 arguments: nderiv(expr, var, at)",
+            ));
+        };
+        let var_name = variable_name("nderiv", var)?;
+        let at = self.numeric_argument("nderiv", at, "point")?;
+        let compiled = self.compile(expr, &var_name);
+        let (value, error) = derivative(
+            |x| self.evaluate_point(compiled.as_ref(), expr, &var_name, x),
+            at,
+        )?;
+        finite_result(round_to_error(value, error))
+    }
+
+    /// Evaluates `root(expr, var, lower, upper)`: a value of `var` between
+    /// `lower` and `upper` where `expr` is zero.
+    pub fn evaluate_root(&mut self, args: &[Expression]) -> Result<Value, CalculatorError> {
+        let [expr, var, lower, upper] = args else {
+            return Err(CalculatorError::invalid_args(
+                "root",
+                "expected 4 arguments: root(expr, var, lower, upper)",
+            ));
+        };
+        let var_name = variable_name("root", var)?;
+        let lower = self.numeric_argument("root", lower, "lower bound")?;
+        let upper = self.numeric_argument("root", upper, "upper bound")?;
+        let tolerance = self.eval_config.root_tolerance;
+        let max_iterations = self.eval_config.max_root_iterations;
+        let compiled = self.compile(expr, &var_name);
+        let root = find_root(
+            |x| self.evaluate_point(compiled.as_ref(), expr, &var_name, x),
+            lower,
+            upper,
+            tolerance,
+            max_iterations,
+        )?;
+        finite_result(round_to_error(root, tolerance))
+    }
+
+    /// Evaluates `nderiv` or `root` as one step, or returns `None` for other
+    /// functions.
+    pub(super) fn calculus_with_steps(
+        &mut self,
+        name: &str,
+        args: &[Expression],
+        steps: &mut Vec<String>,
+    ) -> Option<Result<Value, CalculatorError>> {
+        let evaluate = match name {
+            "nderiv" => Self::evaluate_nderiv,
+            "root" => Self::evaluate_root,
+            _ => return None,
+        };
+        let display: Vec<_> = args.iter().map(Expression::to_canonical_string).collect();
+        steps.push(format!("Call function: {name}({})", display.join(", ")));
+        let result = evaluate(self, args);
+        if let Ok(value) = &result {
+            steps.push(format!("= {}", value.to_display_string()));
+        }
+        Some(result)
+    }
+
+    /// Evaluates `arg`, the `what` of `function`, to a number.
+    fn numeric_argument(
+        &mut self,
+        function: &str,
+        arg: &Expression,
+        what: &str,
+    ) -> Result<f64, CalculatorError> {
+        let value = self.evaluate_expr(arg)?;
+        numeric(function, &value, what)
+    }
+}
+
+/// Reads `value`, the `what` of `function`, as a float, failing if it is
+/// not a number or is too large for a Decimal.
+pub(super) fn numeric(function: &str, value: &Value, what: &str) -> Result<f64, CalculatorError> {
+    value.as_decimal().map(|d| d.to_f64()).ok_or_else(|| {
+        let reason = if value.to_rational().is_some() {
+            format!("{what} is too large for a 28-digit decimal")
+        } else {
+            format!("{what} must be numeric")
+        };
+        CalculatorError::invalid_args(function, reason)
+    })
+}
+
+fn variable_name(function: &str, arg: &Expression) -> Result<String, CalculatorError> {
+    match arg {
+        Expression::Variable(name) => Ok(name.clone()),
+        _ => Err(CalculatorError::invalid_args(
+            function,
+            "second argument must be a variable name (e.g., x)",
+        )),
+    }
+}
+
+/// Rounds away the digits of `value` that are below its estimated error, so
+/// that `nderiv(x^2, x, 3)` prints `6` rather than `6.000000000000001`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_to_error(value: f64, error: f64) -> f64 {
+    let error = error.max(100.0 * f64::EPSILON * value.abs().max(1.0));
+    if !error.is_finite() {
+        return value;
+    }
+    // Clamped to 0..=15, so the cast is exact
+    let decimals = (-error.log10()).floor().clamp(0.0, 15.0) as usize;
+    format!("{value:.decimals$}").parse().unwrap_or(value)
+}
+
+fn finite_result(value: f64) -> Result<Value, CalculatorError> {
+    if value.is_nan() {
+        return Err(CalculatorError::domain("result is undefined"));
+    }
+    if value.is_infinite() {
+        return Err(CalculatorError::Overflow);
+    }
+    Ok(Value::number(Decimal::from_f64(value)?))
+}
@@ -0,0 +1,18 @@
+//! Compiling expressions of [`ExpressionParser`] for the numeric calculus
+//! functions, which evaluate one expression many times.
+
+use super::ExpressionParser;
+use crate::grammar::CompiledExpression;
+use crate::types::{AngleUnit, Expression};
+
+impl ExpressionParser {
+    /// Compiles `expr` as a function of `variable` for repeated evaluation.
+    /// Compiled math functions take radians, so in another angle mode the
+    /// full evaluator is used instead.
+    pub(super) fn compile(&self, expr: &Expression, variable: &str) -> Option<CompiledExpression> {
+        if self.angle_mode != AngleUnit::Radian {
+            return None;
+        }
+        CompiledExpression::compile(expr, variable)
+    }
+}
@@ -0,0 +1,193 @@
+//! The evaluation context set by `at` clauses and conversions, such as the
+//! date used for historical exchange rates in
+//! `(100 USD + 50 EUR) at 22 Jan 2026`, and the currency money results are
+//! given in.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{
+    bare_year_datetime, ConversionReceipt, DateTime, Expression, Unit, Value, ValueKind,
+};
+
+/// What the expression being evaluated is evaluated against.
+///
+/// An `at` clause or conversion replaces the context for everything inside
+/// it, however deeply nested, and the outer context is restored when it
+/// ends.
+#[derive(Debug, Clone, Default)]
+pub(super) struct EvalContext {
+    /// The date for historical currency conversions and rate lookups.
+    pub date: Option<DateTime>,
+    /// The currency of an enclosing conversion such as `... in EUR`.
+    pub target_currency: Option<Unit>,
+}
+
+impl EvalContext {
+    /// Returns this context moved to the date `time` names: a date and time,
+    /// or a bare year such as `2021`. Anything else keeps the current date.
+    fn at(&self, time: &Value) -> Self {
+        let date = match &time.kind {
+            ValueKind::DateTime(dt) => Some(dt.clone()),
+            _ => bare_year_datetime(time).or_else(|| self.date.clone()),
+        };
+        Self {
+            date,
+            ..self.clone()
+        }
+    }
+}
+
+impl ExpressionParser {
+    /// Returns the date of the enclosing `at` clause, if any.
+    pub(super) fn context_date(&self) -> Option<&DateTime> {
+        self.context.date.as_ref()
+    }
+
+    /// Evaluates `body` in the context of an `at` clause whose time
+    /// evaluated to `time`, restoring the outer context afterwards even
+    /// when `body` fails.
+    ///
+    /// An amount of money that comes out of the clause keeps its date, so
+    /// `(100 USD at 1 Jan 2025) + (100 USD at 1 Jan 2026) in EUR` converts
+    /// each amount at its own date's rate.
+    pub(super) fn with_at_context(
+        &mut self,
+        time: &Value,
+        body: impl FnOnce(&mut Self) -> Result<Value, CalculatorError>,
+    ) -> Result<Value, CalculatorError> {
+        let inner = self.context.at(time);
+        let outer = std::mem::replace(&mut self.context, inner);
+        let result = body(self).map(|mut value| {
+            if value.unit.is_currency() && value.rate_date.is_none() {
+                value.rate_date.clone_from(&self.context.date);
+            }
+            value
+        });
+        self.context = outer;
+        result
+    }
+
+    /// Evaluates `body`, the value of a conversion to `target_unit`, with
+    /// that currency as the context's target, so mixed-currency sums inside
+    /// it are converted straight to the target.
+    pub(super) fn with_conversion_target(
+        &mut self,
+        target_unit: &Unit,
+        body: impl FnOnce(&mut Self) -> Result<Value, CalculatorError>,
+    ) -> Result<Value, CalculatorError> {
+        if !target_unit.is_currency() {
+            return body(self);
+        }
+        let outer = self.context.target_currency.replace(target_unit.clone());
+        let result = body(self);
+        self.context.target_currency = outer;
+        result
+    }
+
+    /// Converts two amounts of money to the target currency of the
+    /// context, each at its own date, so they can be added or subtracted:
+    /// `84 USD - 34 EUR in EUR` converts only the dollars. Returns `None`
+    /// when there is no target, or the amounts share a currency and a date.
+    pub(super) fn amounts_in_target_currency(
+        &mut self,
+        left: &Value,
+        right: &Value,
+    ) -> Result<Option<(Value, Value, ConversionReceipt)>, CalculatorError> {
+        let Some(target) = self.context.target_currency.clone() else {
+            return Ok(None);
+        };
+        if !left.unit.is_currency()
+            || !right.unit.is_currency()
+            || (left.unit == right.unit
+                && self.effective_rate_date(left) == self.effective_rate_date(right))
+        {
+            return Ok(None);
+        }
+
+        let mut receipt = ConversionReceipt::default();
+        let mut converted = [left.clone(), right.clone()];
+        for value in converted.iter_mut().filter(|value| value.unit != target) {
+            let date = self.effective_rate_date(value).cloned();
+            let (in_target, value_receipt) =
+                value.convert_to_unit_at_date(&target, &self.currency_db, date.as_ref())?;
+            self.rates_used.extend(value_receipt.usages(date.as_ref()));
+            receipt.extend(value_receipt);
+            *value = in_target;
+        }
+        let [left, right] = converted;
+        Ok(Some((left, right, receipt)))
+    }
+
+    /// Returns the rate date for the result of an operation on `left` and
+    /// `right`: the date their amounts of money share, if any.
+    pub(super) fn merged_rate_date(&self, left: &Value, right: &Value) -> Option<DateTime> {
+        if left.rate_date.is_none() && right.rate_date.is_none() {
+            return None;
+        }
+        let dates = [left, right]
+            .into_iter()
+            .filter(|value| value.unit.is_currency())
+            .map(|value| self.effective_rate_date(value))
+            .collect::<Vec<_>>();
+        match dates.as_slice() {
+            [Some(date)] => Some((*date).clone()),
+            [Some(a), Some(b)] if a == b => Some((*a).clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the date `value` is converted at: its own, or the context's.
+    pub(super) fn effective_rate_date<'a>(&'a self, value: &'a Value) -> Option<&'a DateTime> {
+        value.rate_date.as_ref().or_else(|| self.context_date())
+    }
+}
+
+impl ExpressionParser {
+    /// Returns the currency money results are given in, if set.
+    pub fn output_currency(&self) -> Option<&Unit> {
+        self.output_currency.as_ref()
+    }
+
+    /// Sets the currency money results are given in, unless the expression
+    /// converts its result itself, or clears it.
+    pub fn set_output_currency(&mut self, currency: Option<Unit>) {
+        self.output_currency = currency.filter(Unit::is_currency);
+    }
+
+    /// Returns the output currency `expr` is evaluated in: none when the
+    /// expression ends in a conversion such as `... in USD`.
+    pub(super) fn output_currency_for(&self, expr: &Expression) -> Option<Unit> {
+        let mut outer = expr;
+        while let Expression::Group(inner) | Expression::WithPrecision { value: inner, .. } = outer
+        {
+            outer = inner;
+        }
+        if matches!(outer, Expression::UnitConversion { .. }) {
+            return None;
+        }
+        self.output_currency.clone()
+    }
+
+    /// Converts an amount of money `value` to the output `currency`,
+    /// reporting the conversion in `steps`. Other values are returned as
+    /// they are.
+    pub(super) fn convert_to_output_currency(
+        &mut self,
+        value: Value,
+        currency: &Unit,
+        steps: &mut Vec<String>,
+    ) -> Result<Value, CalculatorError> {
+        if !value.unit.is_currency() || value.unit == *currency {
+            return Ok(value);
+        }
+        steps.push(format!(
+            "Convert: {} to {}",
+            self.show(&value),
+            currency.conversion_target_name()
+        ));
+        let result = self.convert_value(&value, currency)?;
+        self.push_rate_steps(steps);
+        steps.push(format!("= {}", self.show(&result)));
+        Ok(result)
+    }
+}
@@ -0,0 +1,61 @@
+//! Equations such as `2x + 3 = 7`, solved for their variable.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::{linear_equation, polynomial_equation};
+use crate::types::{Expression, Value};
+
+impl ExpressionParser {
+    pub(super) fn expression_contains_variable(expr: &Expression) -> bool {
+        match expr {
+            Expression::Variable(_) => true,
+            Expression::Until(inner) | Expression::Negate(inner) | Expression::Group(inner) => {
+                Self::expression_contains_variable(inner)
+            }
+            Expression::Binary { left, right, .. }
+            | Expression::Power {
+                base: left,
+                exponent: right,
+            } => {
+                Self::expression_contains_variable(left)
+                    || Self::expression_contains_variable(right)
+            }
+            Expression::Equality { left, right } | Expression::Comparison { left, right, .. } => {
+                Self::expression_contains_variable(left)
+                    || Self::expression_contains_variable(right)
+            }
+            Expression::AtTime { value, time } => {
+                Self::expression_contains_variable(value)
+                    || Self::expression_contains_variable(time)
+            }
+            Expression::FunctionCall { args, .. } | Expression::List(args) => {
+                args.iter().any(Self::expression_contains_variable)
+            }
+            Expression::IndefiniteIntegral { integrand, .. } => {
+                Self::expression_contains_variable(integrand)
+            }
+            Expression::UnitConversion { value, .. } | Expression::WithPrecision { value, .. } => {
+                Self::expression_contains_variable(value)
+            }
+            Expression::Number { .. }
+            | Expression::Text(_)
+            | Expression::DateTime(_)
+            | Expression::Now
+            | Expression::Today
+            | Expression::HistoryRef(_)
+            | Expression::HistoryRange(..)
+            | Expression::RateLookup { .. } => false,
+        }
+    }
+
+    pub(super) fn solve_equation(
+        left: &Expression,
+        right: &Expression,
+    ) -> Result<Value, CalculatorError> {
+        if let Ok(solution) = linear_equation::solve(left, right) {
+            return Ok(solution.to_value());
+        }
+
+        Ok(polynomial_equation::solve(left, right)?.to_value())
+    }
+}
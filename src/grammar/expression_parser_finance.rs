@@ -0,0 +1,93 @@
+//! Financial functions that depend on [`ExpressionParser`] settings, and the
+//! labelled breakdown of their results.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::{
+    evaluate_value_function, is_bill_function, is_discount_function, is_factorial_function,
+    is_statistics_function, is_tax_function, percentages_are_plain, BillSplit, DiscountChain, Tip,
+    VatBreakdown,
+};
+use crate::types::{BinaryOp, Rational, Value};
+
+impl ExpressionParser {
+    /// Returns the VAT rate used when `+ VAT` is written without one, as a
+    /// fraction (0.19 for 19%).
+    #[must_use]
+    pub fn default_vat_rate(&self) -> Option<&Rational> {
+        self.default_vat_rate.as_ref()
+    }
+
+    /// Sets the VAT rate used when `+ VAT` is written without one, as a
+    /// fraction (0.19 for 19%), or clears it.
+    pub fn set_default_vat_rate(&mut self, rate: Option<Rational>) {
+        self.default_vat_rate = rate;
+    }
+
+    /// Returns the labelled parts of the last result evaluated with steps,
+    /// such as the net, VAT and gross of `150 EUR + VAT 19%`, the total and
+    /// share per person of `86.40 USD + 18% tip split 4 ways`, or the price
+    /// after each discount of `200 USD - 20% - 10%`.
+    #[must_use]
+    pub fn breakdown(&self) -> &[(String, Value)] {
+        &self.breakdown
+    }
+
+    /// Evaluates a function over whole values, applying parser settings such
+    /// as the default VAT rate, and adds the parts of financial results to the
+    /// breakdown.
+    pub(super) fn evaluate_value_call(
+        &mut self,
+        name: &str,
+        args: &[Value],
+    ) -> Result<Value, CalculatorError> {
+        if is_factorial_function(name) {
+            if let Some(n) = args.first().and_then(Value::to_rational) {
+                self.check_factorial_input(name, n.to_f64())?;
+            }
+        }
+        if is_tax_function(name) {
+            let vat = VatBreakdown::compute(name, args, self.default_vat_rate.as_ref())?;
+            self.breakdown.extend(vat.parts());
+            return Ok(vat.result());
+        }
+        if is_bill_function(name) {
+            if name.eq_ignore_ascii_case("add_tip") {
+                let tip = Tip::compute(args)?;
+                self.breakdown.extend(tip.parts());
+                return Ok(tip.total);
+            }
+            let split = BillSplit::compute(args)?;
+            // A plain bill has no earlier parts, so show what was split
+            if self.breakdown.is_empty() {
+                self.breakdown
+                    .push(("Total".to_string(), split.total.clone()));
+            }
+            self.breakdown.push(split.part());
+            return Ok(split.per_person);
+        }
+        if percentages_are_plain(name, args) {
+            return args[1..].iter().try_fold(args[0].clone(), |total, change| {
+                self.apply_binary_op(&total, BinaryOp::Add, change)
+            });
+        }
+        if is_discount_function(name) {
+            let chain = DiscountChain::compute(name, args)?;
+            self.breakdown.extend(chain.parts());
+            return Ok(chain.result());
+        }
+        if is_statistics_function(name) {
+            let args = self.amounts_in_first_currency(args)?;
+            return evaluate_value_function(name, &args);
+        }
+        evaluate_value_function(name, args)
+    }
+
+    /// Reports the parts added to the breakdown since it had `from` parts as
+    /// steps.
+    pub(super) fn push_breakdown_steps(&self, from: usize, steps: &mut Vec<String>) {
+        for (label, value) in &self.breakdown[from..] {
+            steps.push(format!("{label}: {}", value.to_display_string()));
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! The computation graph recorded alongside the steps of [`ExpressionParser`].
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Expression, StepRate, StructuredStep, Unit, Value};
+
+/// Structured steps of an evaluation, and the ids of the values evaluated
+/// so far that no step has consumed yet.
+#[derive(Debug, Default)]
+pub(super) struct StepGraph {
+    steps: Vec<StructuredStep>,
+    pending: Vec<usize>,
+}
+
+impl ExpressionParser {
+    /// Evaluates an expression, pushing human-readable steps into `steps`.
+    ///
+    /// The same evaluator [`Self::evaluate_with_steps`] uses internally, but
+    /// the caller owns the step buffer. Useful when interleaving step output
+    /// from several sub-evaluations. Every operation is also recorded in
+    /// [`Self::structured_steps`].
+    pub fn evaluate_expr_with_steps(
+        &mut self,
+        expr: &Expression,
+        steps: &mut Vec<String>,
+    ) -> Result<Value, CalculatorError> {
+        let mark = self.step_graph.pending.len();
+        let value = self.evaluate_node_with_steps(expr, steps)?;
+        // A group passes the value of its inner expression through
+        if !matches!(expr, Expression::Group(_)) {
+            let inputs = self.step_graph.pending.split_off(mark);
+            self.record_step(expr, inputs, &value);
+        }
+        Ok(value)
+    }
+
+    /// Returns the computation graph of the last evaluation with steps:
+    /// one step per operation, referring to its operands by id.
+    pub fn structured_steps(&self) -> &[StructuredStep] {
+        &self.step_graph.steps
+    }
+
+    fn record_step(&mut self, expr: &Expression, inputs: Vec<usize>, value: &Value) {
+        let (operation, detail) = operation(expr);
+        let uses_rates = matches!(
+            expr,
+            Expression::Binary { .. }
+                | Expression::UnitConversion { .. }
+                | Expression::RateLookup { .. }
+        );
+        let rates = if uses_rates {
+            self.rate_receipt
+                .rates
+                .iter()
+                .map(|(from, to, info)| StepRate {
+                    from: from.clone(),
+                    to: to.clone(),
+                    rate: info.rate,
+                    source: info.source.clone(),
+                    date: info.date.clone(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let id = self.step_graph.steps.len();
+        self.step_graph.steps.push(StructuredStep {
+            id,
+            operation: operation.to_string(),
+            detail,
+            inputs,
+            output: value.to_display_string(),
+            unit: (value.unit != Unit::None).then(|| value.unit.to_string()),
+            rates,
+        });
+        self.step_graph.pending.push(id);
+    }
+}
+
+/// Names the operation of `expr`, with the function, unit or variable it
+/// refers to.
+fn operation(expr: &Expression) -> (&'static str, Option<String>) {
+    match expr {
+        Expression::Number { .. } => ("literal", None),
+        Expression::Text(_) => ("text", None),
+        Expression::DateTime(_) => ("datetime", None),
+        Expression::Now => ("now", None),
+        Expression::Today => ("today", None),
+        Expression::HistoryRef(_) | Expression::HistoryRange(..) => {
+            ("history", Some(expr.to_string()))
+        }
+        Expression::Until(_) => ("until", None),
+        Expression::Binary { op, .. } => match op {
+            BinaryOp::Add => ("add", None),
+            BinaryOp::Subtract => ("subtract", None),
+            BinaryOp::Multiply => ("multiply", None),
+            BinaryOp::Divide => ("divide", None),
+            BinaryOp::Modulo => ("modulo", None),
+        },
+        Expression::Negate(_) => ("negate", None),
+        Expression::Group(_) => ("group", None),
+        Expression::AtTime { .. } => ("at_time", None),
+        Expression::FunctionCall { name, .. } => ("function", Some(name.clone())),
+        Expression::Variable(name) => ("variable", Some(name.clone())),
+        Expression::Power { .. } => ("power", None),
+        Expression::IndefiniteIntegral { variable, .. } => ("integral", Some(variable.clone())),
+        Expression::UnitConversion { target_unit, .. } => {
+            ("conversion", Some(target_unit.to_string()))
+        }
+        Expression::Equality { .. } => ("equation", None),
+        Expression::Comparison { op, .. } => ("comparison", Some(op.to_string())),
+        Expression::List(_) => ("list", None),
+        Expression::WithPrecision { .. } => ("precision", None),
+        Expression::RateLookup { from, to } => ("rate_lookup", Some(format!("{from}/{to}"))),
+    }
+}
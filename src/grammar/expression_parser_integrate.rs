@@ -0,0 +1,195 @@
+//! Numerical definite integration for [`ExpressionParser`].
+//!
+//! Evaluates `integrate(expr, var, lower, upper)` with adaptive
+//! Gauss–Kronrod quadrature (see [`crate::grammar::quadrature`]). Either bound may be
+//! `inf` or `-inf`.
+
+use super::numeric_calculus::numeric;
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::quadrature::{integrate_adaptive, Quadrature, EVALUATIONS_PER_INTERVAL};
+use crate::grammar::CompiledExpression;
+use crate::types::{Decimal, Expression, Value};
+
+/// Offset used to approach a point where the integrand is undefined, such
+/// as `sin(x)/x` at 0.
+const NUDGE: f64 = 1e-7;
+
+/// Relative error above which an integral that used up all its
+/// subintervals is taken to diverge, as `1/x` from 0 does.
+const DIVERGENCE_THRESHOLD: f64 = 1e-3;
+
+impl ExpressionParser {
+    /// Evaluates an integrate function call: integrate(expr, var, lower, upper).
+    ///
+    /// Uses adaptive numerical integration to compute the definite integral.
+    /// Exposed so downstream consumers can reuse the same integrator when
+    /// reconstructing or composing their own evaluators.
+    pub fn evaluate_integrate(&mut self, args: &[Expression]) -> Result<Value, CalculatorError> {
+        self.integrate_with_estimate(args).map(|(value, _)| value)
+    }
+
+    /// Like [`Self::evaluate_integrate`], but also returns the error estimate
+    /// and how many subintervals the range was split into.
+    pub(crate) fn integrate_with_estimate(
+        &mut self,
+        args: &[Expression],
+    ) -> Result<(Value, Quadrature), CalculatorError> {
+        if args.len() != 4 {
+            return Err(CalculatorError::invalid_args(
+                "integrate",
+                "expected 4 arguments: integrate(expr, var, lower, upper)",
+            ));
+        }
+
+        // Second argument must be a variable name
+        let var_name = match &args[1] {
+            Expression::Variable(name) => name.clone(),
+            _ => {
+                return Err(CalculatorError::invalid_args(
+                    "integrate",
+                    "second argument must be a variable name (e.g., x)",
+                ))
+            }
+        };
+
+        let a = self.integration_bound(&args[2], "lower bound")?;
+        let b = self.integration_bound(&args[3], "upper bound")?;
+
+        // The expression to integrate
+        let integrand = &args[0];
+
+        let compiled = self.compile(integrand, &var_name);
+        let max_intervals = self.eval_config.max_integration_subdivisions.max(1);
+        let tolerance = self.eval_config.integration_tolerance;
+        let total = (max_intervals * EVALUATIONS_PER_INTERVAL) as u64;
+        let mut done = 0;
+        let result = integrate_adaptive(
+            |x| {
+                done += 1;
+                self.report_progress("integration", done, total)?;
+                self.integrand_at(compiled.as_ref(), integrand, &var_name, x)
+            },
+            a,
+            b,
+            tolerance,
+            max_intervals,
+        )?;
+        self.report_progress("integration", total, total)?;
+
+        if result.value.is_nan() {
+            return Err(CalculatorError::domain("integration result is undefined"));
+        }
+        if result.value.is_infinite() {
+            return Err(CalculatorError::Overflow);
+        }
+        if result.intervals >= max_intervals
+            && result.error > DIVERGENCE_THRESHOLD * result.value.abs()
+        {
+            return Err(CalculatorError::domain("integral does not converge"));
+        }
+
+        // Drop the last digits of floating-point noise, far below any
+        // achievable error, so that exact results print exactly
+        let value = format!("{:.14e}", result.value)
+            .parse()
+            .unwrap_or(result.value);
+        Ok((Value::number(Decimal::from_f64(value)?), result))
+    }
+
+    /// Evaluates an integration bound, accepting `inf` and `-inf`.
+    fn integration_bound(
+        &mut self,
+        bound: &Expression,
+        what: &str,
+    ) -> Result<f64, CalculatorError> {
+        if let Some(infinity) = infinite_bound(bound) {
+            return Ok(infinity);
+        }
+        let value = self.evaluate_expr(bound)?;
+        numeric("integrate", &value, what)
+    }
+
+    /// Evaluates the integrand, approaching points where it is undefined
+    /// from both sides.
+    fn integrand_at(
+        &mut self,
+        compiled: Option<&CompiledExpression>,
+        integrand: &Expression,
+        var_name: &str,
+        x: f64,
+    ) -> Result<f64, CalculatorError> {
+        match self.evaluate_point(compiled, integrand, var_name, x) {
+            Ok(y) => Ok(y),
+            Err(e @ (CalculatorError::Cancelled | CalculatorError::LimitExceeded(_))) => Err(e),
+            Err(e) => {
+                let nudge = NUDGE * x.abs().max(1.0);
+                let left = self.evaluate_point(compiled, integrand, var_name, x - nudge);
+                let right = self.evaluate_point(compiled, integrand, var_name, x + nudge);
+                match (left, right) {
+                    (Ok(left), Ok(right)) if (left - right).abs() < 1e-3 => {
+                        Ok(0.5 * (left + right))
+                    }
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+
+    /// Evaluates `expr` at a value of `var_name`, through `compiled` when
+    /// the expression could be compiled and the full evaluator otherwise.
+    pub(crate) fn evaluate_point(
+        &mut self,
+        compiled: Option<&CompiledExpression>,
+        expr: &Expression,
+        var_name: &str,
+        x: f64,
+    ) -> Result<f64, CalculatorError> {
+        match compiled {
+            Some(compiled) => self.evaluate_compiled(compiled, x),
+            None => self.evaluate_at(expr, var_name, x).map(|d| d.to_f64()),
+        }
+    }
+
+    /// Evaluates a compiled expression at `x`, counting its steps against
+    /// the operation budget like the full evaluator would.
+    pub fn evaluate_compiled(
+        &mut self,
+        compiled: &CompiledExpression,
+        x: f64,
+    ) -> Result<f64, CalculatorError> {
+        self.count_operations(compiled.nodes())?;
+        compiled.evaluate(x)
+    }
+
+    /// Evaluates an expression at a specific numeric value of `var_name`.
+    ///
+    /// Convenience wrapper around [`Self::evaluate_expr_with_var`] that
+    /// coerces the result to a [`Decimal`]. Exposed for downstream consumers
+    /// (e.g. custom integrators or plotters).
+    pub fn evaluate_at(
+        &mut self,
+        expr: &Expression,
+        var_name: &str,
+        value: f64,
+    ) -> Result<Decimal, CalculatorError> {
+        let val = self.evaluate_expr_with_var(expr, var_name, Decimal::from_f64(value)?)?;
+        val.as_decimal().ok_or_else(|| {
+            CalculatorError::InvalidOperation("expected numeric result in integration".into())
+        })
+    }
+}
+
+/// Recognizes `inf`, `infinity` and their negations as integration bounds.
+fn infinite_bound(bound: &Expression) -> Option<f64> {
+    match bound {
+        Expression::Variable(name)
+            if name.eq_ignore_ascii_case("inf") || name.eq_ignore_ascii_case("infinity") =>
+        {
+            Some(f64::INFINITY)
+        }
+        Expression::Negate(inner) => infinite_bound(inner).map(|x| -x),
+        Expression::Group(inner) => infinite_bound(inner),
+        _ => None,
+    }
+}
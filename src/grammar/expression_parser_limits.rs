@@ -0,0 +1,170 @@
+//! Enforcement of [`EvalConfig`] limits for [`ExpressionParser`].
+//!
+//! Depth is checked once, before evaluation starts: on the tokens, so that
+//! runaway parenthesis nesting never reaches the recursive parser, and on
+//! the parsed tree. The operation budget is counted on every evaluation step.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::{evaluate_function, Token, TokenKind};
+use crate::types::{Decimal, EvalConfig, Expression, Value};
+
+impl ExpressionParser {
+    /// Returns the limits enforced while parsing and evaluating.
+    #[must_use]
+    pub fn eval_config(&self) -> &EvalConfig {
+        &self.eval_config
+    }
+
+    /// Replaces the limits enforced while parsing and evaluating.
+    ///
+    /// A smaller history limit takes effect at the next recorded result.
+    pub fn set_eval_config(&mut self, config: EvalConfig) {
+        self.eval_config = config;
+    }
+
+    /// Returns a mutable borrow of the evaluation limits.
+    pub fn eval_config_mut(&mut self) -> &mut EvalConfig {
+        &mut self.eval_config
+    }
+
+    /// Records a result in the history, forgetting the oldest results beyond
+    /// [`EvalConfig::max_history_entries`]. Returns the result's number.
+    pub fn record_history(
+        &self,
+        input: impl Into<String>,
+        result: impl Into<String>,
+        value: Value,
+    ) -> usize {
+        let mut history = self.history_mut();
+        let number = history.push(input, result, value);
+        history.keep_latest(self.eval_config.max_history_entries);
+        number
+    }
+
+    /// Parses input from an untrusted source, such as a fuzzer or a network
+    /// request, without evaluating it.
+    ///
+    /// Never panics: invalid UTF-8 and input longer than
+    /// [`EvalConfig::max_input_length`] bytes are rejected before the lexer
+    /// runs, and nesting deeper than [`EvalConfig::max_depth`] before the
+    /// parser recurses that far, so parsing uses memory bounded by the
+    /// input's length. The default depth fits the main thread's stack even
+    /// in debug builds; fuzz on a thread with as large a stack, or lower the
+    /// depth.
+    pub fn parse_untrusted(&self, input: &[u8]) -> Result<Expression, CalculatorError> {
+        let max = self.eval_config.max_input_length;
+        if input.len() > max {
+            return Err(CalculatorError::LimitExceeded(format!(
+                "input is longer than {max} bytes"
+            )));
+        }
+        let input = std::str::from_utf8(input)
+            .map_err(|e| CalculatorError::parse(format!("input is not valid UTF-8: {e}")))?;
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(CalculatorError::EmptyInput);
+        }
+        self.parse(input)
+    }
+
+    /// Rejects tokens nested deeper than [`EvalConfig::max_depth`].
+    pub(super) fn check_token_nesting(&self, tokens: &[Token]) -> Result<(), CalculatorError> {
+        let mut depth = 0_usize;
+        for token in tokens {
+            match token.kind {
+                TokenKind::LeftParen | TokenKind::LeftBracket => {
+                    depth += 1;
+                    self.check_depth(depth)?;
+                }
+                TokenKind::RightParen | TokenKind::RightBracket => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects number literals longer than [`EvalConfig::max_input_length`]
+    /// before their digits are read, which takes time quadratic in their
+    /// length.
+    pub(super) fn check_number_lengths(&self, tokens: &[Token]) -> Result<(), CalculatorError> {
+        let max = self.eval_config.max_input_length;
+        if tokens
+            .iter()
+            .any(|token| matches!(&token.kind, TokenKind::Number(text) if text.len() > max))
+        {
+            return Err(CalculatorError::LimitExceeded(format!(
+                "number is longer than {max} digits"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Rejects expressions nested deeper than [`EvalConfig::max_depth`].
+    pub(super) fn check_expression_depth(&self, expr: &Expression) -> Result<(), CalculatorError> {
+        self.check_depth(expr.depth())
+    }
+
+    fn check_depth(&self, depth: usize) -> Result<(), CalculatorError> {
+        let max = self.eval_config.max_depth;
+        if depth > max {
+            return Err(CalculatorError::LimitExceeded(format!(
+                "expression is nested more than {max} levels deep"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Starts counting evaluation steps for a new calculation.
+    pub(super) fn reset_operation_count(&mut self) {
+        self.operations = 0;
+    }
+
+    /// Counts one evaluation step against [`EvalConfig::operation_budget`],
+    /// stopping if the host has cancelled the calculation.
+    pub(super) fn count_operation(&mut self) -> Result<(), CalculatorError> {
+        self.count_operations(1)
+    }
+
+    /// Counts `count` evaluation steps at once, for work done outside the
+    /// evaluator such as a [`crate::grammar::CompiledExpression`].
+    pub(super) fn count_operations(&mut self, count: u64) -> Result<(), CalculatorError> {
+        self.check_cancelled()?;
+        self.operations += count;
+        match self.eval_config.operation_budget {
+            Some(budget) if self.operations > budget => Err(CalculatorError::LimitExceeded(
+                format!("calculation needs more than {budget} steps"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Evaluates a math function after checking its arguments against the
+    /// configured limits.
+    pub(super) fn evaluate_limited_function(
+        &self,
+        name: &str,
+        args: &[Decimal],
+    ) -> Result<Decimal, CalculatorError> {
+        if name.eq_ignore_ascii_case("factorial") {
+            if let Some(n) = args.first() {
+                self.check_factorial_input(name, n.to_f64())?;
+            }
+        }
+        evaluate_function(name, args)
+    }
+
+    /// Checks the first argument of `factorial(n)` or `ncr(n, k)`, which
+    /// multiply up to `n`, against [`EvalConfig::max_factorial_input`].
+    pub(super) fn check_factorial_input(&self, name: &str, n: f64) -> Result<(), CalculatorError> {
+        let max = self.eval_config.max_factorial_input;
+        if n > max as f64 {
+            return Err(CalculatorError::LimitExceeded(format!(
+                "{name} input {n} is larger than {max}"
+            )));
+        }
+        Ok(())
+    }
+}
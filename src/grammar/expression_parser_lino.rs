@@ -0,0 +1,185 @@
+//! Converts links notation back into an [`Expression`], the inverse of
+//! [`Expression::to_lino`].
+//!
+//! Links are matched structurally: `(a + b)`, `(a at b)`, `(a as unit)`,
+//! `(name (args))`, `(list (items))` and so on. Leaves that carry no further
+//! structure, such as `(84 USD)` or `(2026-01-22)`, are read with the ordinary
+//! expression grammar.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::token_parser::TokenParser;
+use crate::grammar::Lexer;
+use crate::lino::{LinkRef, LinoParser};
+use crate::types::{BinaryOp, ComparisonOp, Expression, Unit};
+
+const BINARY_OPS: [BinaryOp; 5] = [
+    BinaryOp::Add,
+    BinaryOp::Subtract,
+    BinaryOp::Multiply,
+    BinaryOp::Divide,
+    BinaryOp::Modulo,
+];
+
+const COMPARISON_OPS: [ComparisonOp; 6] = [
+    ComparisonOp::Equal,
+    ComparisonOp::Less,
+    ComparisonOp::LessOrEqual,
+    ComparisonOp::Greater,
+    ComparisonOp::GreaterOrEqual,
+    ComparisonOp::NotEqual,
+];
+
+impl Expression {
+    /// Parses links notation, such as `((84 USD) - (34 EUR))`, into an expression.
+    pub fn from_lino(input: &str) -> Result<Self, CalculatorError> {
+        ExpressionParser::new().parse_lino(input)
+    }
+}
+
+impl ExpressionParser {
+    /// Parses links notation, such as `((84 USD) - (34 EUR))`, into an
+    /// expression that can be evaluated.
+    pub fn parse_lino(&self, input: &str) -> Result<Expression, CalculatorError> {
+        let links = LinoParser::new()
+            .parse(input)
+            .map_err(|e| CalculatorError::parse(format!("Invalid links notation: {e}")))?;
+        match links.as_slice() {
+            [link] => self.expression_from_refs(&link.refs),
+            [] => Err(CalculatorError::EmptyInput),
+            _ => Err(CalculatorError::parse(
+                "Links notation must contain a single expression",
+            )),
+        }
+    }
+
+    fn expression_from_refs(&self, refs: &[LinkRef]) -> Result<Expression, CalculatorError> {
+        let expr = match refs {
+            [] => return Err(CalculatorError::parse("Empty link in links notation")),
+            [single] => self.expression_from_ref(single)?,
+            [keyword, operand] if is_word(keyword, "-") => {
+                Expression::negate(self.expression_from_ref(operand)?)
+            }
+            [keyword, target] if is_word(keyword, "until") => {
+                Expression::Until(Box::new(self.expression_from_ref(target)?))
+            }
+            [keyword, LinkRef::Nested(items)] if is_word(keyword, "list") => {
+                Expression::list(self.expressions_from_refs(&items.refs)?)
+            }
+            [keyword, LinkRef::Nested(body)] if is_word(keyword, "integrate") => {
+                self.indefinite_integral(&body.refs)?
+            }
+            [keyword, left, right] if is_word(keyword, "compare") => Expression::comparison(
+                self.expression_from_ref(left)?,
+                ComparisonOp::Compare,
+                self.expression_from_ref(right)?,
+            ),
+            [LinkRef::Literal(name), LinkRef::Nested(args)] if is_identifier(name) => {
+                Expression::function_call(name, self.expressions_from_refs(&args.refs)?)
+            }
+            [left, LinkRef::Literal(op), right] if is_operator(op) => {
+                self.binary_from_refs(left, op, right)?
+            }
+            _ => self.parse_leaf(&join_refs(refs))?,
+        };
+        Ok(expr)
+    }
+
+    fn expression_from_ref(&self, link_ref: &LinkRef) -> Result<Expression, CalculatorError> {
+        match link_ref {
+            LinkRef::Nested(link) => self.expression_from_refs(&link.refs),
+            LinkRef::Literal(text) | LinkRef::Ref(text) => self.parse_leaf(text),
+        }
+    }
+
+    fn expressions_from_refs(&self, refs: &[LinkRef]) -> Result<Vec<Expression>, CalculatorError> {
+        refs.iter()
+            .map(|link_ref| self.expression_from_ref(link_ref))
+            .collect()
+    }
+
+    fn binary_from_refs(
+        &self,
+        left: &LinkRef,
+        op: &str,
+        right: &LinkRef,
+    ) -> Result<Expression, CalculatorError> {
+        if op == "as" {
+            let target = self.parse_unit_name(&right.to_lino())?;
+            return Ok(Expression::unit_conversion(
+                self.expression_from_ref(left)?,
+                target,
+            ));
+        }
+
+        let left = self.expression_from_ref(left)?;
+        let right = self.expression_from_ref(right)?;
+        Ok(match op {
+            "^" => Expression::power(left, right),
+            "at" => Expression::at_time(left, right),
+            "=" => Expression::equality(left, right),
+            _ => {
+                if let Some(op) = BINARY_OPS.into_iter().find(|o| o.symbol() == op) {
+                    Expression::binary(left, op, right)
+                } else if let Some(op) = COMPARISON_OPS.into_iter().find(|o| o.symbol() == op) {
+                    Expression::comparison(left, op, right)
+                } else {
+                    unreachable!("is_operator accepted {op}")
+                }
+            }
+        })
+    }
+
+    /// Reads `(integrand * (differential of (x)))`.
+    fn indefinite_integral(&self, refs: &[LinkRef]) -> Result<Expression, CalculatorError> {
+        let [integrand, times, LinkRef::Nested(differential)] = refs else {
+            return Err(CalculatorError::parse("Invalid integral in links notation"));
+        };
+        let variable = match differential.refs.as_slice() {
+            [d, of, LinkRef::Nested(variable)]
+                if is_word(d, "differential") && is_word(of, "of") =>
+            {
+                variable.to_lino()
+            }
+            _ => return Err(CalculatorError::parse("Invalid integral in links notation")),
+        };
+        if !is_word(times, "*") || !is_identifier(&variable) {
+            return Err(CalculatorError::parse("Invalid integral in links notation"));
+        }
+        Ok(Expression::IndefiniteIntegral {
+            integrand: Box::new(self.expression_from_ref(integrand)?),
+            variable,
+        })
+    }
+
+    fn parse_leaf(&self, text: &str) -> Result<Expression, CalculatorError> {
+        self.parse_tokenized(text)
+    }
+
+    fn parse_unit_name(&self, text: &str) -> Result<Unit, CalculatorError> {
+        let tokens = Lexer::new(text).tokenize()?;
+        TokenParser::new(&tokens, &self.number_grammar, text).parse_complete_unit()
+    }
+}
+
+fn is_word(link_ref: &LinkRef, word: &str) -> bool {
+    matches!(link_ref, LinkRef::Literal(text) if text.eq_ignore_ascii_case(word))
+}
+
+fn is_identifier(text: &str) -> bool {
+    text.chars().next().is_some_and(char::is_alphabetic)
+        && text.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn is_operator(text: &str) -> bool {
+    matches!(text, "^" | "at" | "as" | "=")
+        || BINARY_OPS.iter().any(|op| op.symbol() == text)
+        || COMPARISON_OPS.iter().any(|op| op.symbol() == text)
+}
+
+fn join_refs(refs: &[LinkRef]) -> String {
+    refs.iter()
+        .map(LinkRef::to_lino)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,7 +1,12 @@
-//! Locale-aware parser fallback for number input.
+//! Parsing entry points, with a locale-aware fallback for number input and
+//! the locale's reading of slash dates and the `¥` sign.
+
+use std::borrow::Cow;
 
 use crate::error::CalculatorError;
-use crate::grammar::{locale_numbers, ExpressionParser};
+use crate::grammar::locale_input::{self, InputLocale};
+use crate::grammar::locale_numbers::{self, NumberLocale};
+use crate::grammar::ExpressionParser;
 use crate::types::Expression;
 
 impl ExpressionParser {
@@ -14,13 +19,80 @@ impl ExpressionParser {
             .ok_or_else(|| CalculatorError::parse("No parseable interpretation"))
     }
 
+    /// Checks that `input` parses, without evaluating it.
+    ///
+    /// Accepts exactly the inputs [`Self::parse_and_evaluate`] gets past
+    /// parsing, and reports the same parse errors with the same spans.
+    pub fn validate(&self, input: &str) -> Result<(), CalculatorError> {
+        let leading_whitespace = input.chars().take_while(|c| c.is_whitespace()).count();
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(CalculatorError::EmptyInput);
+        }
+        self.parse_interpretations(input)
+            .map(|_| ())
+            .map_err(|e| e.offset_span(leading_whitespace))
+    }
+
+    /// Reads numbers in the convention of `language` (such as `ru`, `de` or
+    /// `en-US`) first, so that `1 234,56` is 1234.56 in Russian and `1.234`
+    /// is 1234 in German. `None` restores auto-detection. Returns false, and
+    /// keeps the current setting, for a language without a known convention.
+    pub fn set_number_locale(&mut self, language: Option<&str>) -> bool {
+        let Some(language) = language else {
+            self.number_locale = None;
+            return true;
+        };
+        let locale = locale_numbers::locale_for_language(language);
+        if locale.is_some() {
+            self.number_locale = locale;
+        }
+        locale.is_some()
+    }
+
+    /// Reads slash dates and the `¥` sign the way `language` writes them:
+    /// `01/02/2026` is the 1st of February in `ru` or `en-GB`, and `¥100` is
+    /// yuan in `zh`. `None` restores the default month-first dates and yen.
+    pub fn set_input_locale(&mut self, language: Option<&str>) {
+        self.input_locale = language.map(InputLocale::for_language);
+    }
+
+    /// Returns the conventions slash dates and the `¥` sign are read with,
+    /// when an input locale is set.
+    #[must_use]
+    pub const fn input_locale(&self) -> Option<InputLocale> {
+        self.input_locale
+    }
+
+    /// Returns `input` with its dates and currency signs rewritten for the
+    /// input locale, when one is set.
+    pub(super) fn localized_input<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        self.input_locale
+            .and_then(|locale| locale_input::rewrite_with_locale(input, locale))
+            .map_or(Cow::Borrowed(input), Cow::Owned)
+    }
+
     /// Parses an expression into every supported locale interpretation.
     ///
-    /// The ordinary grammar is tried first and wins when it succeeds. If the
-    /// ordinary grammar rejects the input, common locale number conventions are
+    /// With a number locale set, the input read in that locale comes first,
+    /// followed by the ordinary grammar's reading. Otherwise the ordinary
+    /// grammar is tried first and wins when it succeeds. If the ordinary
+    /// grammar rejects the input, common locale number conventions are
     /// normalized to the grammar's canonical decimal-dot format and tried in a
     /// stable order.
+    ///
+    /// Recently parsed inputs are answered from the parse cache (see
+    /// [`Self::parse_cache_stats`]).
     pub fn parse_interpretations(&self, input: &str) -> Result<Vec<Expression>, CalculatorError> {
+        self.cached_parse(input, || self.parse_uncached(input))
+    }
+
+    fn parse_uncached(&self, input: &str) -> Result<Vec<Expression>, CalculatorError> {
+        let localized = self.localized_input(input);
+        let input = localized.as_ref();
+        if let Some(locale) = self.number_locale {
+            return self.parse_in_locale(input, locale);
+        }
         match self.parse_tokenized(input) {
             Ok(expr) => Ok(vec![expr]),
             Err(first_error) => {
@@ -45,4 +117,32 @@ impl ExpressionParser {
             }
         }
     }
+
+    /// Parses `input` read in `locale`, then as written.
+    fn parse_in_locale(
+        &self,
+        input: &str,
+        locale: NumberLocale,
+    ) -> Result<Vec<Expression>, CalculatorError> {
+        let mut interpretations: Vec<Expression> = Vec::new();
+        let mut first_error = None;
+        let localized = locale_numbers::rewrite_with_locale(input, locale);
+        for candidate in localized.iter().map(String::as_str).chain([input]) {
+            match self.parse_tokenized(candidate) {
+                Ok(expr) => {
+                    let lino = expr.to_lino();
+                    if !interpretations.iter().any(|e| e.to_lino() == lino) {
+                        interpretations.push(expr);
+                    }
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        match first_error {
+            Some(e) if interpretations.is_empty() => Err(e),
+            _ => Ok(interpretations),
+        }
+    }
 }
@@ -0,0 +1,108 @@
+//! Best-effort evaluation of expressions that fail part-way.
+//!
+//! When `(84 USD + 10 ABC) * 2 + (3 + 4)` fails because ABC has no rate,
+//! the parts that do evaluate are still worth showing. Partial evaluation
+//! replaces every subexpression that evaluates with its value and collects
+//! the smallest subexpressions that fail, giving `(84 USD + 10 ABC) * 2 + 7`.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{Expression, Value};
+
+/// The outcome of evaluating as much of a failing expression as possible.
+#[derive(Debug, Clone)]
+pub struct PartialEvaluation {
+    /// The expression with every evaluable subexpression replaced by its value.
+    pub expression: Expression,
+    /// The smallest subexpressions that failed, with their errors, in input order.
+    pub unresolved: Vec<(Expression, CalculatorError)>,
+}
+
+impl ExpressionParser {
+    /// Evaluates the parts of `expr` that can be evaluated.
+    ///
+    /// Returns `None` when `expr` evaluates completely, or when nothing
+    /// smaller than the whole expression could be singled out as failing.
+    /// Function calls over a free variable, such as `integrate(x^2, x, 0, 1)`,
+    /// are never split, since their arguments have no value on their own.
+    pub fn evaluate_partially(&mut self, expr: &Expression) -> Option<PartialEvaluation> {
+        self.reset_operation_count();
+        let mut unresolved = Vec::new();
+        let expression = self.reduce(expr, &mut unresolved);
+        if unresolved.is_empty() || (unresolved.len() == 1 && unresolved[0].0 == *expr) {
+            return None;
+        }
+        Some(PartialEvaluation {
+            expression,
+            unresolved,
+        })
+    }
+
+    fn reduce(
+        &mut self,
+        expr: &Expression,
+        unresolved: &mut Vec<(Expression, CalculatorError)>,
+    ) -> Expression {
+        let error = match self.evaluate_expr(expr) {
+            Ok(value) => return resolved(&value),
+            Err(error) => error,
+        };
+
+        let failed_children = unresolved.len();
+        let reduced = match expr {
+            Expression::Binary { left, op, right } => {
+                let left = self.reduce(left, unresolved);
+                Expression::binary(left, *op, self.reduce(right, unresolved))
+            }
+            Expression::Comparison { left, op, right } => {
+                let left = self.reduce(left, unresolved);
+                Expression::comparison(left, *op, self.reduce(right, unresolved))
+            }
+            Expression::Power { base, exponent } => {
+                let base = self.reduce(base, unresolved);
+                Expression::power(base, self.reduce(exponent, unresolved))
+            }
+            Expression::Negate(inner) => Expression::negate(self.reduce(inner, unresolved)),
+            Expression::Group(inner) => Expression::group(self.reduce(inner, unresolved)),
+            Expression::UnitConversion { value, target_unit } => {
+                Expression::unit_conversion(self.reduce(value, unresolved), target_unit.clone())
+            }
+            Expression::WithPrecision { value, precision } => {
+                Expression::with_precision(self.reduce(value, unresolved), *precision)
+            }
+            // Integrands only make sense inside their call
+            Expression::FunctionCall { .. } if Self::expression_contains_variable(expr) => {
+                expr.clone()
+            }
+            Expression::FunctionCall { name, args } => {
+                let mut reduced_args = Vec::with_capacity(args.len());
+                for arg in args {
+                    reduced_args.push(self.reduce(arg, unresolved));
+                }
+                Expression::function_call(name.clone(), reduced_args)
+            }
+            Expression::List(items) => {
+                let mut reduced_items = Vec::with_capacity(items.len());
+                for item in items {
+                    reduced_items.push(self.reduce(item, unresolved));
+                }
+                Expression::list(reduced_items)
+            }
+            _ => expr.clone(),
+        };
+
+        // Every part evaluated, so this node itself is what fails.
+        if unresolved.len() == failed_children {
+            unresolved.push((expr.clone(), error));
+        }
+        reduced
+    }
+}
+
+/// Stands in for an evaluated subexpression.
+///
+/// Partial results are only ever displayed, so the value is carried as
+/// display text rather than converted back into a literal.
+fn resolved(value: &Value) -> Expression {
+    Expression::Variable(value.to_display_string())
+}
@@ -0,0 +1,97 @@
+//! Progress reporting and cancellation of long-running evaluations of
+//! [`ExpressionParser`].
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::sync::lock_recover;
+use crate::types::{CancellationToken, Progress};
+
+/// Called with the progress of long-running evaluations. Returning `false`
+/// cancels the calculation with [`CalculatorError::Cancelled`].
+pub type ProgressCallback = Box<dyn FnMut(&Progress) -> bool + Send>;
+
+/// How many times a stage reports its progress, at most.
+const REPORTS_PER_STAGE: u64 = 100;
+
+/// The progress callback of a parser, if any, shared by its evaluations.
+#[derive(Clone, Default)]
+pub(super) struct ProgressHook(Arc<Mutex<Option<ProgressCallback>>>);
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ProgressHook")
+            .field(&self.callback().as_ref().map(|_| "callback"))
+            .finish()
+    }
+}
+
+impl ProgressHook {
+    /// Locks the callback. A callback that panicked is still called again.
+    fn callback(&self) -> MutexGuard<'_, Option<ProgressCallback>> {
+        lock_recover(&self.0)
+    }
+}
+
+impl ExpressionParser {
+    /// Sets the callback that receives the progress of numerical integration
+    /// and plot sampling; `None` removes it.
+    pub fn set_progress_callback(&mut self, callback: Option<ProgressCallback>) {
+        *self.progress.callback() = callback;
+    }
+
+    /// Reports that `done` of `total` work items of `stage` are done.
+    ///
+    /// The callback hears about each stage about a hundred times, however
+    /// many items it has. Exposed so that custom integrators or plotters
+    /// report progress the same way.
+    pub fn report_progress(
+        &mut self,
+        stage: &str,
+        done: u64,
+        total: u64,
+    ) -> Result<(), CalculatorError> {
+        self.check_cancelled()?;
+        let every = (total / REPORTS_PER_STAGE).max(1);
+        if done % every != 0 && done != total {
+            return Ok(());
+        }
+        let progress = Progress {
+            stage: stage.to_string(),
+            done,
+            total,
+        };
+        let carry_on = self
+            .progress
+            .callback()
+            .as_mut()
+            .map_or(true, |callback| callback(&progress));
+        if carry_on {
+            Ok(())
+        } else {
+            Err(CalculatorError::Cancelled)
+        }
+    }
+
+    /// Sets the token the host cancels the running calculation with; `None`
+    /// removes it.
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation = token;
+    }
+
+    /// Fails with [`CalculatorError::Cancelled`] once the host has cancelled
+    /// the calculation.
+    pub fn check_cancelled(&self) -> Result<(), CalculatorError> {
+        if self
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            Err(CalculatorError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
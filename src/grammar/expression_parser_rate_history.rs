@@ -0,0 +1,124 @@
+//! Exchange rates over a range of dates, such as
+//! `USD/EUR from 1 Jan 2026 to 31 Jan 2026`.
+
+use chrono::NaiveDate;
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{Decimal, Expression, Rational, Value, ValueKind};
+
+/// The known rates of a currency pair between two dates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateHistory {
+    /// The currency being priced.
+    pub from: String,
+    /// The currency the rates are quoted in.
+    pub to: String,
+    /// Each known rate and its date, oldest first.
+    pub rates: Vec<(NaiveDate, f64)>,
+}
+
+impl RateHistory {
+    /// Returns the lowest rate and its date.
+    #[must_use]
+    pub fn min(&self) -> Option<(NaiveDate, f64)> {
+        self.rates
+            .iter()
+            .copied()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the highest rate and its date.
+    #[must_use]
+    pub fn max(&self) -> Option<(NaiveDate, f64)> {
+        self.rates
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Returns the exact mean of the rates as they are written (`0.92`
+    /// rather than the nearest binary float).
+    #[must_use]
+    pub fn average(&self) -> Option<Rational> {
+        (!self.rates.is_empty()).then(|| {
+            let sum = self
+                .rates
+                .iter()
+                .map(|&(_, rate)| {
+                    Decimal::try_from_f64(rate)
+                        .map_or_else(|| Rational::from_f64(rate), Rational::from_decimal)
+                })
+                .fold(Rational::zero(), |sum, rate| sum + rate);
+            sum / Rational::from_integer(self.rates.len() as i128)
+        })
+    }
+}
+
+impl ExpressionParser {
+    /// Looks up the known rates of `rate_history(FROM/TO rate, start, end)`.
+    pub fn rate_history(&mut self, args: &[Expression]) -> Result<RateHistory, CalculatorError> {
+        let invalid = |message: &str| CalculatorError::invalid_args("rate_history", message);
+        let [Expression::RateLookup { from, to }, start, end] = args else {
+            return Err(invalid("expected a currency pair and two dates"));
+        };
+        let start = self
+            .range_date(start)
+            .ok_or_else(|| invalid("start must be a date"))?;
+        let end = self
+            .range_date(end)
+            .ok_or_else(|| invalid("end must be a date"))?;
+        if start > end {
+            return Err(invalid("start must not be after end"));
+        }
+
+        let rates: Vec<_> = self
+            .currency_db
+            .historical_rates_between(from, to, start, end)
+            .into_iter()
+            .map(|(date, info)| (date, info.rate))
+            .collect();
+        if rates.is_empty() {
+            return Err(CalculatorError::NoHistoricalRate {
+                currency: format!("{from}/{to}"),
+                date: format!("{start} to {end}"),
+            });
+        }
+        Ok(RateHistory {
+            from: from.clone(),
+            to: to.clone(),
+            rates,
+        })
+    }
+
+    /// Evaluates a rate range to its average rate, reporting the range,
+    /// lowest, highest and average rates in `steps`.
+    pub(super) fn evaluate_rate_history(
+        &mut self,
+        args: &[Expression],
+        steps: &mut Vec<String>,
+    ) -> Result<Value, CalculatorError> {
+        let history = self.rate_history(args)?;
+        let (first, last) = (history.rates[0].0, history.rates[history.rates.len() - 1].0);
+        let pair = format!("{}/{}", history.from, history.to);
+        steps.push(format!(
+            "{pair} rates: {} from {first} to {last}",
+            history.rates.len()
+        ));
+        if let (Some(min), Some(max)) = (history.min(), history.max()) {
+            steps.push(format!("Minimum: {} ({})", min.1, min.0));
+            steps.push(format!("Maximum: {} ({})", max.1, max.0));
+        }
+        let average = Value::rational(history.average().unwrap_or_default());
+        steps.push(format!("Average: {}", average.to_display_string()));
+        Ok(average)
+    }
+
+    /// Evaluates one end of a rate range to its date.
+    fn range_date(&mut self, expr: &Expression) -> Option<NaiveDate> {
+        match self.evaluate_expr(expr).ok()?.kind {
+            ValueKind::DateTime(dt) => Some(dt.as_chrono().date_naive()),
+            _ => None,
+        }
+    }
+}
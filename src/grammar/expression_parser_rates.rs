@@ -0,0 +1,125 @@
+//! Exchange rate lookups such as `USD/EUR rate at 22 Jan 2026`, and the
+//! steps and warnings that report which rates a calculation used.
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::geo::{is_distance, round_distance};
+use crate::types::{Expression, RateUsage, Unit, Value};
+
+impl ExpressionParser {
+    /// Looks up how many units of `to` one unit of `from` buys.
+    ///
+    /// Uses the historical rate for the enclosing `at` date when there is
+    /// one, otherwise the current rate. The conversion's receipt is kept so
+    /// callers can report the rate's source and date.
+    pub(super) fn lookup_rate(&mut self, from: &str, to: &str) -> Result<Value, CalculatorError> {
+        let conversion = self
+            .currency_db
+            .convert_at(1.0, from, to, self.context_date())?;
+        let usages = conversion.receipt.usages(self.context_date());
+        self.rates_used.extend(usages);
+        let rate = conversion.receipt.rate();
+        self.rate_receipt = conversion.receipt;
+        Ok(Value::rational(rate))
+    }
+
+    /// Converts `value` to `target_unit` at the date it is pinned to, or the
+    /// enclosing `at` date, keeping the receipt for any exchange rates used.
+    /// A plain number converted to an angle is an angle in the angle mode.
+    pub(super) fn convert_value(
+        &mut self,
+        value: &Value,
+        target_unit: &Unit,
+    ) -> Result<Value, CalculatorError> {
+        let value = &self.as_angle(value, target_unit);
+        let (converted, receipt) = value.convert_to_unit_at_date(
+            target_unit,
+            &self.currency_db,
+            self.effective_rate_date(value),
+        )?;
+        let usages = receipt.usages(self.effective_rate_date(value));
+        self.rates_used.extend(usages);
+        self.rate_receipt = receipt;
+        Ok(converted)
+    }
+
+    /// Converts `value`, the value of `expr`, to `target_unit` like
+    /// [`Self::convert_value`], keeping a great-circle distance to the
+    /// decimal places it has in kilometers.
+    pub(super) fn convert_value_of(
+        &mut self,
+        expr: &Expression,
+        value: &Value,
+        target_unit: &Unit,
+    ) -> Result<Value, CalculatorError> {
+        let converted = self.convert_value(value, target_unit)?;
+        Ok(if is_distance(expr) {
+            round_distance(converted)
+        } else {
+            converted
+        })
+    }
+
+    /// Converts the amounts of money among `args`, and among the items of
+    /// any lists, to the currency of the first, at the rates `+` would use,
+    /// so `sum(10 USD, 5 EUR)` is in dollars.
+    pub(super) fn amounts_in_first_currency(
+        &mut self,
+        args: &[Value],
+    ) -> Result<Vec<Value>, CalculatorError> {
+        let items: Vec<Value> = args
+            .iter()
+            .flat_map(|arg| {
+                arg.as_list()
+                    .map_or_else(|| vec![arg.clone()], <[Value]>::to_vec)
+            })
+            .collect();
+        let Some(currency) = items
+            .first()
+            .map(|first| first.unit.clone())
+            .filter(Unit::is_currency)
+        else {
+            return Ok(items);
+        };
+        let mut converted = Vec::with_capacity(items.len());
+        for item in items {
+            converted.push(if item.unit.is_currency() && item.unit != currency {
+                self.convert_value(&item, &currency)?
+            } else {
+                item
+            });
+        }
+        Ok(converted)
+    }
+
+    /// Returns every exchange rate the last calculation used, in the order
+    /// the conversions were made.
+    #[must_use]
+    pub fn rates_used(&self) -> &[RateUsage] {
+        &self.rates_used
+    }
+
+    /// Returns the warnings raised during the last evaluation with steps,
+    /// such as a historical rate far from the requested date.
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Reports the rates used by the last currency conversion, and any
+    /// warnings about them, as steps.
+    ///
+    /// Cross-rate (triangulated) conversions report one step per rate.
+    pub(super) fn push_rate_steps(&mut self, steps: &mut Vec<String>) {
+        for (from, to, rate_info) in &self.rate_receipt.rates {
+            steps.push(format!(
+                "Exchange rate: {}",
+                rate_info.format_for_display(from, to)
+            ));
+        }
+        for warning in &self.rate_receipt.warnings {
+            steps.push(format!("Warning: {warning}"));
+            self.warnings.push(warning.clone());
+        }
+    }
+}
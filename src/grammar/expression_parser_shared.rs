@@ -0,0 +1,68 @@
+//! Evaluation through a shared [`ExpressionParser`], so that one parser can
+//! evaluate on several threads at once.
+//!
+//! The state of one evaluation, such as its operation count, warnings and
+//! steps, lives in a working copy made for it. The copy shares the
+//! parser's history, parse cache, exchange rates and hooks, and copies its
+//! settings.
+
+use super::graph::StepGraph;
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::grammar::NumberGrammar;
+use crate::types::{ConversionReceipt, Expression, Value};
+
+impl ExpressionParser {
+    /// Returns a parser for one evaluation, with the settings of this one
+    /// and the same history, parse cache, rates, clock and hooks.
+    pub(crate) fn working_copy(&self) -> Self {
+        let mut currency_db = self.currency_db.shared();
+        currency_db.set_historical_rate_mode(self.currency_db.historical_rate_mode());
+        currency_db.set_stale_rate_days(self.currency_db.stale_rate_days());
+        Self {
+            number_grammar: NumberGrammar::new(),
+            currency_db,
+            context: self.context.clone(),
+            local_offset_seconds: self.local_offset_seconds,
+            history: self.history.clone(),
+            eval_config: self.eval_config.clone(),
+            operations: 0,
+            rate_receipt: ConversionReceipt::default(),
+            warnings: Vec::new(),
+            default_vat_rate: self.default_vat_rate.clone(),
+            breakdown: Vec::new(),
+            base_unit_steps: self.base_unit_steps,
+            teaching_steps: self.teaching_steps,
+            steps_level: self.steps_level,
+            datetime_format: self.datetime_format.clone(),
+            step_graph: StepGraph::default(),
+            phase_timings: None,
+            number_locale: self.number_locale,
+            input_locale: self.input_locale,
+            progress: self.progress.clone(),
+            clock: self.clock.clone(),
+            cancellation: self.cancellation.clone(),
+            parse_cache: self.parse_cache.clone(),
+            output_currency: self.output_currency.clone(),
+            rates_used: Vec::new(),
+            bindings: self.bindings.clone(),
+            angle_mode: self.angle_mode,
+            angle_wrap: self.angle_wrap,
+        }
+    }
+
+    /// Evaluates an expression.
+    ///
+    /// Takes `&self`, so one parser can evaluate on several threads at
+    /// once; each evaluation counts its own operations against the limits.
+    pub fn evaluate(&self, expr: &Expression) -> Result<Value, CalculatorError> {
+        let mut parser = self.working_copy();
+        parser.reset_operation_count();
+        let Some(currency) = parser.output_currency_for(expr) else {
+            return parser.evaluate_expr(expr);
+        };
+        let value =
+            parser.with_conversion_target(&currency, |parser| parser.evaluate_expr(expr))?;
+        parser.convert_to_output_currency(value, &currency, &mut Vec::new())
+    }
+}
@@ -0,0 +1,98 @@
+//! Comparison shopping: `compare 5 USD for 300 g vs 8 USD for 550 g` finds
+//! the better deal by the price of each offer per kilogram.
+
+use std::cmp::Ordering;
+
+use super::ExpressionParser;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Expression, LengthUnit, MassUnit, Unit, Value, VolumeUnit};
+
+impl ExpressionParser {
+    /// Evaluates `better_deal(price, quantity, price, quantity)`: the offer
+    /// with the lower unit price, and the unit price of each offer in the
+    /// breakdown. Unit prices are per kilogram, liter or meter, and in the
+    /// currency of the first offer.
+    pub(super) fn evaluate_better_deal(
+        &mut self,
+        args: &[Expression],
+        steps: &mut Vec<String>,
+    ) -> Result<Value, CalculatorError> {
+        let [first_price, first_quantity, second_price, second_quantity] = args else {
+            return Err(CalculatorError::invalid_args(
+                "better_deal",
+                "expected two prices, each with a quantity",
+            ));
+        };
+        let mut offers: Vec<(String, Unit, Unit, Value)> = Vec::with_capacity(2);
+        for (price, quantity) in [
+            (first_price, first_quantity),
+            (second_price, second_quantity),
+        ] {
+            let mut price = self.evaluate_expr(price)?;
+            let quantity = self.evaluate_expr(quantity)?;
+            let description = format!("{} for {}", self.show(&price), self.show(&quantity));
+            if let Some((_, currency, _, _)) = offers.first() {
+                if price.unit.is_currency() && currency.is_currency() && price.unit != *currency {
+                    price = self.convert_value(&price, currency)?;
+                }
+            }
+            let unit_price = self.apply_binary_op(&price, BinaryOp::Divide, &quantity)?;
+            offers.push((description, price.unit, quantity.unit, unit_price));
+        }
+        let [(first, price_unit, quantity_unit, first_unit_price), (second, _, _, second_unit_price)] =
+            offers.as_slice()
+        else {
+            unreachable!("two offers are evaluated");
+        };
+
+        let target = match reference_unit(quantity_unit) {
+            Some(per) => Unit::per(price_unit.clone(), per),
+            None => first_unit_price.unit.clone(),
+        };
+        let incomparable =
+            || CalculatorError::InvalidOperation(format!("cannot compare {first} with {second}"));
+        let first_unit_price = self
+            .convert_value(first_unit_price, &target)
+            .map_err(|_| incomparable())?;
+        let second_unit_price = self
+            .convert_value(second_unit_price, &target)
+            .map_err(|_| incomparable())?;
+        let ordering = match (
+            first_unit_price.to_rational(),
+            second_unit_price.to_rational(),
+        ) {
+            (Some(first), Some(second)) => first.cmp(&second),
+            _ => return Err(incomparable()),
+        };
+
+        let breakdown_len = self.breakdown.len();
+        self.breakdown
+            .push((first.clone(), first_unit_price.clone()));
+        self.breakdown
+            .push((second.clone(), second_unit_price.clone()));
+        self.push_breakdown_steps(breakdown_len, steps);
+
+        let first = format!("{first} ({})", self.show(&first_unit_price));
+        let second = format!("{second} ({})", self.show(&second_unit_price));
+        let result = match ordering {
+            Ordering::Less => Value::comparison_result(first, "is a better deal than", second),
+            Ordering::Equal => Value::comparison_result(first, "costs the same as", second),
+            Ordering::Greater => Value::comparison_result(second, "is a better deal than", first),
+        };
+        steps.push(format!("= {}", self.show(&result)));
+        Ok(result)
+    }
+}
+
+/// Returns the unit a price for a quantity in `unit` is given per: a
+/// kilogram, a liter or a meter, or that unit itself for other quantities,
+/// and none for a count of items.
+fn reference_unit(unit: &Unit) -> Option<Unit> {
+    match unit {
+        Unit::None => None,
+        Unit::Mass(_) => Some(Unit::Mass(MassUnit::Kilogram)),
+        Unit::Volume(_) => Some(Unit::Volume(VolumeUnit::Liter)),
+        Unit::Length(_) => Some(Unit::Length(LengthUnit::Meter)),
+        other => Some(other.clone()),
+    }
+}
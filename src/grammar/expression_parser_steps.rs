@@ -0,0 +1,157 @@
+//! Optional detail in the steps of [`ExpressionParser`].
+
+use super::ExpressionParser;
+use crate::grammar::arithmetic_working::working;
+use crate::grammar::Lexer;
+use crate::types::{
+    BinaryOp, DateTimeFormat, Expression, FormatOptions, PhaseTimings, StepsLevel, Value,
+};
+
+impl ExpressionParser {
+    /// Returns true if steps show operands normalized to base units.
+    pub fn base_unit_steps(&self) -> bool {
+        self.base_unit_steps
+    }
+
+    /// Shows every unit-bearing operand normalized to base units (meters,
+    /// liters, seconds, kilograms, bytes) in the steps, so that unit
+    /// conversions can be audited.
+    pub fn set_base_unit_steps(&mut self, enabled: bool) {
+        self.base_unit_steps = enabled;
+    }
+
+    /// Returns true if steps show the written working of basic arithmetic.
+    pub fn teaching_steps(&self) -> bool {
+        self.teaching_steps
+    }
+
+    /// Shows the written working of basic arithmetic in the steps: long
+    /// multiplication, long division and adding fractions over a common
+    /// denominator.
+    pub fn set_teaching_steps(&mut self, enabled: bool) {
+        self.teaching_steps = enabled;
+    }
+
+    /// Returns the detail level of calculation steps.
+    pub fn steps_level(&self) -> StepsLevel {
+        self.steps_level
+    }
+
+    /// Sets the detail level of calculation steps.
+    pub fn set_steps_level(&mut self, level: StepsLevel) {
+        self.steps_level = level;
+    }
+
+    /// Returns the format of dates and times in steps.
+    pub fn datetime_format(&self) -> &DateTimeFormat {
+        &self.datetime_format
+    }
+
+    /// Sets the format of dates and times in steps, so that they match the
+    /// result.
+    pub fn set_datetime_format(&mut self, format: DateTimeFormat) {
+        self.datetime_format = format;
+    }
+
+    /// Displays a value in a step, with dates in the steps' format.
+    pub(super) fn show(&self, value: &Value) -> String {
+        if self.datetime_format == DateTimeFormat::Iso {
+            return value.to_display_string();
+        }
+        value.to_display_string_with(&FormatOptions {
+            datetime_format: self.datetime_format.clone(),
+            ..FormatOptions::default()
+        })
+    }
+
+    /// Returns how long lexing, parsing and evaluating the last calculation
+    /// took, when it ran at the [`StepsLevel::Debug`] level.
+    pub fn phase_timings(&self) -> Option<PhaseTimings> {
+        self.phase_timings
+    }
+
+    /// Times lexing and parsing `input` again, bypassing the parse cache,
+    /// alongside the `eval_ms` its evaluation took.
+    pub(super) fn time_parsing(&self, input: &str, eval_ms: f64) -> PhaseTimings {
+        let input = self.localized_input(input.trim());
+        let started = self.clock_now();
+        let tokens = Lexer::new(&input).tokenize();
+        let lexed = self.clock_now();
+        if let Ok(tokens) = tokens {
+            let _ = self.parse_tokens(&input, tokens);
+        }
+        PhaseTimings {
+            lex_ms: PhaseTimings::millis_between(started, lexed),
+            parse_ms: PhaseTimings::millis_between(lexed, self.clock_now()),
+            eval_ms,
+        }
+    }
+
+    /// Describes how the parser reads `input`: its tokens and the grammar
+    /// branch chosen, or why parsing failed. These are the first steps at
+    /// the [`StepsLevel::Debug`] level.
+    pub fn debug_steps(&self, input: &str) -> Vec<String> {
+        let input = input.trim();
+        let mut steps = Vec::new();
+        match Lexer::new(input).tokenize() {
+            Ok(tokens) => {
+                let tokens = tokens
+                    .iter()
+                    .map(|token| format!("{:?}", token.kind))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                steps.push(format!("Tokens: {tokens}"));
+            }
+            Err(e) => steps.push(format!("Tokenizer error: {e}")),
+        }
+        match self.parse(input) {
+            Ok(expr) => {
+                steps.push(format!("Grammar branch: {}", grammar_branch(&expr)));
+                steps.push(format!("Links notation: {}", expr.to_lino()));
+            }
+            Err(e) => steps.push(format!("Parse error: {e}")),
+        }
+        steps
+    }
+
+    /// Pushes a step for each value that is not already in its base unit,
+    /// when base-unit steps are enabled.
+    pub(super) fn push_base_unit_steps(&self, values: &[&Value], steps: &mut Vec<String>) {
+        if !self.base_unit_steps && self.steps_level < StepsLevel::Verbose {
+            return;
+        }
+        for value in values {
+            if let Some(base) = value.to_base_unit() {
+                steps.push(format!(
+                    "Normalize to base units: {} = {}",
+                    value.to_display_string(),
+                    base.to_display_string()
+                ));
+            }
+        }
+    }
+
+    /// Pushes the written working of `left op right`, when teaching steps
+    /// are enabled.
+    pub(super) fn push_teaching_steps(
+        &self,
+        left: &Value,
+        op: BinaryOp,
+        right: &Value,
+        steps: &mut Vec<String>,
+    ) {
+        if self.teaching_steps {
+            steps.extend(working(left, op, right));
+        }
+    }
+}
+
+/// Names the grammar rule that produced the top of `expr`.
+fn grammar_branch(expr: &Expression) -> String {
+    match expr {
+        Expression::Binary { op, .. } => format!("binary operation ({op})"),
+        Expression::Group(inner) => grammar_branch(inner),
+        Expression::FunctionCall { name, .. } => format!("function call ({name})"),
+        _ => expr.kind().to_string(),
+    }
+}
@@ -1,15 +1,23 @@
-//! Local-timezone handling for [`ExpressionParser`].
+//! Local-timezone and clock handling for [`ExpressionParser`].
 //!
 //! When the user's local timezone offset is configured, `now` and bare
 //! (timezone-less) times such as `12:30` are interpreted in that timezone
 //! instead of UTC. Inputs with an explicit timezone (e.g. `12:30 UTC`) are
 //! always honored regardless of this setting.
 //!
+//! The current time comes from the parser's [`Clock`], the system clock
+//! unless another one is set.
+//!
 //! This is a child module of `expression_parser`, so these `impl` blocks can
-//! access `ExpressionParser`'s private `local_offset_seconds` field.
+//! access `ExpressionParser`'s private `local_offset_seconds` and `clock`
+//! fields.
+
+use std::sync::Arc;
+
+use chrono::{DateTime as ChronoDateTime, Utc};
 
 use super::ExpressionParser;
-use crate::types::{DateTime, Expression};
+use crate::types::{Clock, DateTime, Expression, SystemClock};
 
 impl ExpressionParser {
     /// Sets the user's local timezone offset in seconds east of UTC.
@@ -25,21 +33,49 @@ impl ExpressionParser {
         self.local_offset_seconds
     }
 
+    /// Sets the clock `now`, `today` and live times such as
+    /// `current EST time` are read from; `None` goes back to the system
+    /// clock.
+    pub fn set_clock(&mut self, clock: Option<Box<dyn Clock>>) {
+        self.clock = clock.map(Arc::from);
+    }
+
+    /// Returns the current instant by the parser's clock.
+    #[must_use]
+    pub fn clock_now(&self) -> ChronoDateTime<Utc> {
+        self.clock
+            .as_ref()
+            .map_or_else(|| SystemClock.now(), |clock| clock.now())
+    }
+
     /// Returns a `DateTime` representing the current instant, honoring the
     /// configured local timezone offset when one is set.
     pub(super) fn current_now(&self) -> DateTime {
-        match self.local_offset_seconds {
+        let now = match self.local_offset_seconds {
             Some(offset) => DateTime::now_local(offset),
             None => DateTime::now_with_label("current UTC time", Some(0), Some("UTC".to_string())),
-        }
+        };
+        now.at_instant(self.clock_now())
     }
 
     /// Resolves a dynamic current-date expression in the configured timezone.
     pub(super) fn current_date(&self, expression: &Expression) -> DateTime {
         match expression {
             Expression::Now => self.current_now(),
-            Expression::Today => DateTime::today(self.local_offset_seconds.unwrap_or(0)),
+            Expression::Today => {
+                DateTime::today_at(self.clock_now(), self.local_offset_seconds.unwrap_or(0))
+            }
             _ => unreachable!("current_date only accepts dynamic date expressions"),
         }
     }
+
+    /// Reads a live time parsed from the input, such as `now EST`, by the
+    /// parser's clock; other datetimes are returned unchanged.
+    pub(super) fn resolve_datetime(&self, dt: &DateTime) -> DateTime {
+        if dt.is_live_time() {
+            dt.clone().at_instant(self.clock_now())
+        } else {
+            dt.clone()
+        }
+    }
 }
@@ -0,0 +1,193 @@
+//! Facts about integers for `360 with factors`: the prime factorization,
+//! the number of divisors and divisibility by the small numbers that have a
+//! rule of thumb.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::error::CalculatorError;
+use crate::types::{Rational, Value};
+
+/// Largest integer factorized; beyond it trial division takes too long.
+pub const MAX_FACTORIZED: u64 = 1_000_000_000_000;
+
+/// Returns the prime factors of `n` with their powers, smallest first, or
+/// `None` if `n` is below 2 or above [`MAX_FACTORIZED`].
+#[must_use]
+pub fn prime_factors(n: u64) -> Option<Vec<(u64, u32)>> {
+    if !(2..=MAX_FACTORIZED).contains(&n) {
+        return None;
+    }
+    let mut factors = Vec::new();
+    let mut rest = n;
+    let mut divisor = 2;
+    while divisor * divisor <= rest {
+        let mut power = 0;
+        while rest % divisor == 0 {
+            rest /= divisor;
+            power += 1;
+        }
+        if power > 0 {
+            factors.push((divisor, power));
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+    if rest > 1 {
+        factors.push((rest, 1));
+    }
+    Some(factors)
+}
+
+/// Writes prime factors as a product: `2^3 * 3^2 * 5`.
+#[must_use]
+pub fn format_prime_factors(factors: &[(u64, u32)]) -> String {
+    factors
+        .iter()
+        .map(|&(prime, power)| match power {
+            1 => prime.to_string(),
+            _ => format!("{prime}^{power}"),
+        })
+        .collect::<Vec<_>>()
+        .join(" * ")
+}
+
+/// Evaluates `factors(n)`: returns the integer `n` unchanged and explains
+/// its factors and divisibility in `steps`.
+pub fn evaluate_integer_facts(
+    value: &Value,
+    steps: &mut Vec<String>,
+) -> Result<Value, CalculatorError> {
+    let integer = value
+        .to_rational()
+        .filter(Rational::is_integer)
+        .ok_or_else(|| CalculatorError::invalid_args("factors", "the result must be an integer"))?;
+    steps.extend(integer_fact_steps(integer.numer_bigint()));
+    Ok(value.clone())
+}
+
+/// Explains the factors and divisibility of `n`, one step per fact.
+#[must_use]
+pub fn integer_fact_steps(n: &BigInt) -> Vec<String> {
+    let mut steps = Vec::new();
+    let magnitude = n.abs();
+    match magnitude.to_u64().and_then(prime_factors) {
+        Some(factors) => {
+            steps.push(format!(
+                "Prime factorization: {magnitude} = {}",
+                format_prime_factors(&factors)
+            ));
+            let count: u64 = factors
+                .iter()
+                .map(|&(_, power)| u64::from(power) + 1)
+                .product();
+            let product: Vec<_> = factors
+                .iter()
+                .map(|&(_, power)| format!("({power} + 1)"))
+                .collect();
+            steps.push(format!("Divisors: {} = {count}", product.join(" * ")));
+        }
+        None if magnitude <= BigInt::from(1) => {
+            steps.push(format!("{magnitude} has no prime factors"));
+        }
+        None => steps.push(format!(
+            "Prime factorization: {magnitude} is too large to factor (over {MAX_FACTORIZED})"
+        )),
+    }
+    if !magnitude.is_zero() {
+        steps.extend(divisibility_steps(&magnitude));
+    }
+    steps
+}
+
+/// States whether `n` is divisible by 2 to 11 (except 7), with the rule of
+/// thumb that shows it.
+fn divisibility_steps(n: &BigInt) -> Vec<String> {
+    let digits = n.to_string();
+    let last = |count: usize| digits[digits.len().saturating_sub(count)..].to_string();
+    let digit_sum: u32 = digits.chars().filter_map(|c| c.to_digit(10)).sum();
+    let alternating: i64 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                i64::from(d)
+            } else {
+                -i64::from(d)
+            }
+        })
+        .sum();
+
+    let rules: [(u32, String); 9] = [
+        (2, format!("the last digit {} is even", last(1))),
+        (3, format!("the digit sum {digit_sum} is divisible by 3")),
+        (
+            4,
+            format!("the last two digits {} are divisible by 4", last(2)),
+        ),
+        (5, format!("the last digit {} is 0 or 5", last(1))),
+        (6, "it is divisible by both 2 and 3".to_string()),
+        (
+            8,
+            format!("the last three digits {} are divisible by 8", last(3)),
+        ),
+        (9, format!("the digit sum {digit_sum} is divisible by 9")),
+        (10, format!("the last digit {} is 0", last(1))),
+        (
+            11,
+            format!("the alternating digit sum {alternating} is divisible by 11"),
+        ),
+    ];
+    rules
+        .into_iter()
+        .map(|(divisor, rule)| {
+            if n.is_multiple_of(&BigInt::from(divisor)) {
+                format!("Divisible by {divisor}: {rule}")
+            } else {
+                format!("Not divisible by {divisor}: {}", negate_rule(&rule))
+            }
+        })
+        .collect()
+}
+
+/// Turns a rule such as "the last digit 4 is even" into "the last digit 4
+/// is not even".
+fn negate_rule(rule: &str) -> String {
+    if let Some(subject) = rule.strip_prefix("it is ") {
+        return format!("it is not {subject}");
+    }
+    for verb in [" is ", " are "] {
+        if let Some((subject, rest)) = rule.rsplit_once(verb) {
+            return format!("{subject}{verb}not {rest}");
+        }
+    }
+    rule.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factor_steps() {
+        let steps = integer_fact_steps(&BigInt::from(360));
+        assert_eq!(steps[0], "Prime factorization: 360 = 2^3 * 3^2 * 5");
+        assert_eq!(steps[1], "Divisors: (3 + 1) * (2 + 1) * (1 + 1) = 24");
+        assert!(steps.contains(&"Divisible by 9: the digit sum 9 is divisible by 9".to_string()));
+        assert!(steps.contains(
+            &"Not divisible by 11: the alternating digit sum -3 is not divisible by 11".to_string()
+        ));
+    }
+
+    #[test]
+    fn test_primes_and_units() {
+        assert_eq!(prime_factors(97), Some(vec![(97, 1)]));
+        assert_eq!(prime_factors(1), None);
+        assert_eq!(
+            integer_fact_steps(&BigInt::from(1))[0],
+            "1 has no prime factors"
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Financial functions:
+//! - `amortize(principal, annual rate, term)`
+//! - `add_vat(net, rate)` and `remove_vat(gross, rate)`, written
+//!   `150 EUR + VAT 19%` and `gross 119 EUR at VAT 19%`
+//! - `add_tip(bill, rate)` and `split_bill(total, ways)`, written
+//!   `86.40 USD + 18% tip split 4 ways`
+//! - `apply_discounts(price, rates...)` and `original_price(price, rates...)`,
+//!   written `final price 144 USD after 20% and 10% discounts, original?`
+//! - `apply_percentages(price, changes...)`, written `200 USD - 20% - 10%`
+//!
+//! Amortization amounts are kept in cents (two decimal places) period by
+//! period, the way a lender's schedule is, so the rows add up exactly to the
+//! totals. VAT is computed exactly and only rounded for display.
+
+use crate::error::CalculatorError;
+use crate::types::{Decimal, DurationUnit, Rational, Unit, Value};
+
+/// The financial functions.
+pub const FINANCE_FUNCTIONS: &[&str] = &[
+    "amortize",
+    "add_vat",
+    "remove_vat",
+    "add_tip",
+    "split_bill",
+    "apply_discounts",
+    "apply_percentages",
+    "original_price",
+];
+
+/// Returns true if `name` is a financial function.
+#[must_use]
+pub fn is_finance_function(name: &str) -> bool {
+    FINANCE_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Returns true if `name` is a VAT function: `add_vat` or `remove_vat`.
+#[must_use]
+pub fn is_tax_function(name: &str) -> bool {
+    matches!(name.to_lowercase().as_str(), "add_vat" | "remove_vat")
+}
+
+/// Returns true if `name` is a bill function: `add_tip` or `split_bill`.
+#[must_use]
+pub fn is_bill_function(name: &str) -> bool {
+    matches!(name.to_lowercase().as_str(), "add_tip" | "split_bill")
+}
+
+/// Returns true if `name` is a discount function: `apply_discounts`,
+/// `apply_percentages` or `original_price`.
+#[must_use]
+pub fn is_discount_function(name: &str) -> bool {
+    matches!(
+        name.to_lowercase().as_str(),
+        "apply_discounts" | "apply_percentages" | "original_price"
+    )
+}
+
+/// Returns true if `apply_percentages(price, changes...)` adds its changes
+/// to the price as plain numbers.
+///
+/// Only an amount of money is marked down or up by a percentage of itself,
+/// so `200 USD - 20%` is `160 USD` but `100 - 25%` stays `99.75`.
+#[must_use]
+pub fn percentages_are_plain(name: &str, args: &[Value]) -> bool {
+    name.eq_ignore_ascii_case("apply_percentages")
+        && args.first().is_some_and(|price| !price.unit.is_currency())
+}
+
+/// Evaluates a financial function. `amortize` evaluates to the periodic
+/// payment; [`Amortization::schedule`] gives the full breakdown.
+pub fn evaluate_finance_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    if is_tax_function(name) {
+        return Ok(VatBreakdown::compute(name, args, None)?.result());
+    }
+    if name.eq_ignore_ascii_case("add_tip") {
+        return Ok(Tip::compute(args)?.total);
+    }
+    if name.eq_ignore_ascii_case("split_bill") {
+        return Ok(BillSplit::compute(args)?.per_person);
+    }
+    if percentages_are_plain(name, args) {
+        let mut total = args[0]
+            .to_rational()
+            .ok_or_else(|| CalculatorError::invalid_args(name, "expected a numeric price"))?;
+        for change in &args[1..] {
+            total = total
+                + change.to_rational().ok_or_else(|| {
+                    CalculatorError::invalid_args(name, "expected a numeric percentage")
+                })?;
+        }
+        return Ok(Value::rational_with_unit(total, args[0].unit.clone()));
+    }
+    if is_discount_function(name) {
+        return Ok(DiscountChain::compute(name, args)?.result());
+    }
+    let schedule = Amortization::schedule(args)?;
+    Ok(schedule.amount(schedule.payment))
+}
+
+/// The net, tax and gross parts of an amount subject to VAT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VatBreakdown {
+    /// The VAT rate as a fraction (0.19 for 19%).
+    pub rate: Rational,
+    /// The amount before tax.
+    pub net: Value,
+    /// The tax.
+    pub tax: Value,
+    /// The amount including tax.
+    pub gross: Value,
+    /// Whether the tax was extracted from a gross amount (`remove_vat`)
+    /// rather than added to a net one (`add_vat`).
+    pub extracted: bool,
+}
+
+impl VatBreakdown {
+    /// Computes `add_vat(net, rate)` or `remove_vat(gross, rate)`. When the
+    /// rate is omitted, `default_rate` is used.
+    pub fn compute(
+        name: &str,
+        args: &[Value],
+        default_rate: Option<&Rational>,
+    ) -> Result<Self, CalculatorError> {
+        let name = name.to_lowercase();
+        let invalid = |reason: &str| CalculatorError::invalid_args(&name, reason);
+        let (amount, rate) = match args {
+            [amount, rate] if rate.unit == Unit::None => (amount, rate.to_rational()),
+            [_, _] => return Err(invalid("VAT rate must be a percentage, e.g. 19%")),
+            [amount] => (amount, default_rate.cloned()),
+            _ => return Err(invalid("expected an amount and an optional VAT rate")),
+        };
+        let rate =
+            rate.ok_or_else(|| invalid("no VAT rate given and no default VAT rate is set"))?;
+        if rate.is_negative() {
+            return Err(invalid("VAT rate must not be negative"));
+        }
+        let value = amount
+            .to_rational()
+            .ok_or_else(|| invalid("expected a numeric amount"))?;
+        let unit = amount.unit.clone();
+
+        let extracted = name == "remove_vat";
+        let (net, gross) = if extracted {
+            (value.clone() / (Rational::one() + rate.clone()), value)
+        } else {
+            (value.clone(), value * (Rational::one() + rate.clone()))
+        };
+        let tax = gross.clone() - net.clone();
+        Ok(Self {
+            rate,
+            net: Value::rational_with_unit(net, unit.clone()),
+            tax: Value::rational_with_unit(tax, unit.clone()),
+            gross: Value::rational_with_unit(gross, unit),
+            extracted,
+        })
+    }
+
+    /// The value the function evaluates to: the gross amount for `add_vat`,
+    /// the net amount for `remove_vat`.
+    #[must_use]
+    pub fn result(&self) -> Value {
+        if self.extracted {
+            self.net.clone()
+        } else {
+            self.gross.clone()
+        }
+    }
+
+    /// The labelled parts, in order: net, VAT and gross.
+    #[must_use]
+    pub fn parts(&self) -> Vec<(String, Value)> {
+        let percent = self.rate.clone() * Rational::from_integer(100);
+        vec![
+            ("Net".to_string(), self.net.clone()),
+            (
+                format!("VAT {}%", percent.to_display_string()),
+                self.tax.clone(),
+            ),
+            ("Gross".to_string(), self.gross.clone()),
+        ]
+    }
+}
+
+/// A bill with a tip added: `86.40 USD + 18% tip`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tip {
+    /// The tip rate as a fraction (0.18 for 18%).
+    pub rate: Rational,
+    /// The bill before the tip.
+    pub subtotal: Value,
+    /// The tip.
+    pub tip: Value,
+    /// The bill including the tip.
+    pub total: Value,
+}
+
+impl Tip {
+    /// Computes `add_tip(bill, rate)`.
+    pub fn compute(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("add_tip", reason);
+        let [bill, rate] = args else {
+            return Err(invalid("expected a bill and a tip rate"));
+        };
+        let rate = rate
+            .to_rational()
+            .filter(|rate| !rate.is_negative())
+            .filter(|_| rate.unit == Unit::None)
+            .ok_or_else(|| invalid("tip must be a non-negative percentage, e.g. 18%"))?;
+        let subtotal = bill
+            .to_rational()
+            .ok_or_else(|| invalid("expected a numeric bill"))?;
+        let tip = subtotal.clone() * rate.clone();
+        let total = subtotal.clone() + tip.clone();
+        Ok(Self {
+            rate,
+            subtotal: Value::rational_with_unit(subtotal, bill.unit.clone()),
+            tip: Value::rational_with_unit(tip, bill.unit.clone()),
+            total: Value::rational_with_unit(total, bill.unit.clone()),
+        })
+    }
+
+    /// The labelled parts, in order: subtotal, tip and total.
+    #[must_use]
+    pub fn parts(&self) -> Vec<(String, Value)> {
+        let percent = self.rate.clone() * Rational::from_integer(100);
+        vec![
+            ("Subtotal".to_string(), self.subtotal.clone()),
+            (
+                format!("Tip {}%", percent.to_display_string()),
+                self.tip.clone(),
+            ),
+            ("Total".to_string(), self.total.clone()),
+        ]
+    }
+}
+
+/// A bill shared evenly: `101.95 USD split 4 ways`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BillSplit {
+    /// How many people share the bill.
+    pub ways: Rational,
+    /// The whole bill.
+    pub total: Value,
+    /// Each person's share.
+    pub per_person: Value,
+}
+
+impl BillSplit {
+    /// Computes `split_bill(total, ways)`.
+    pub fn compute(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("split_bill", reason);
+        let [total, ways] = args else {
+            return Err(invalid("expected a total and a number of ways"));
+        };
+        let ways = ways
+            .to_rational()
+            .filter(|ways| ways.is_integer() && !ways.is_negative() && !ways.is_zero())
+            .filter(|_| ways.unit == Unit::None)
+            .ok_or_else(|| invalid("the number of ways must be a positive whole number"))?;
+        let amount = total
+            .to_rational()
+            .ok_or_else(|| invalid("expected a numeric total"))?;
+        Ok(Self {
+            per_person: Value::rational_with_unit(amount / ways.clone(), total.unit.clone()),
+            ways,
+            total: total.clone(),
+        })
+    }
+
+    /// The labelled share of each person.
+    #[must_use]
+    pub fn part(&self) -> (String, Value) {
+        (
+            format!("Per person ({} ways)", self.ways.to_display_string()),
+            self.per_person.clone(),
+        )
+    }
+}
+
+/// A price with discounts taken one after another, each from the price the
+/// one before left: `200 USD - 20% - 10%` is `144 USD`, not `140 USD`.
+///
+/// Markups are taken the same way, as negative discounts: `200 USD - 20% +
+/// 5%` is `168 USD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscountChain {
+    /// The discount rates as fractions (0.2 for 20% off, -0.05 for a 5%
+    /// markup), in the order taken.
+    pub rates: Vec<Rational>,
+    /// The price before the discounts.
+    pub original: Value,
+    /// The price after each discount in turn; the last is the final price.
+    pub stages: Vec<Value>,
+    /// Whether the original price was found from the final one
+    /// (`original_price`) rather than discounted (`apply_discounts`).
+    pub reversed: bool,
+}
+
+impl DiscountChain {
+    /// Computes `apply_discounts(price, rates...)` from the original price,
+    /// or `original_price(price, rates...)` from the final one. For
+    /// `apply_percentages(price, changes...)`, each change is added to the
+    /// price, so a negative one is a discount and a positive one a markup.
+    pub fn compute(name: &str, args: &[Value]) -> Result<Self, CalculatorError> {
+        let name = name.to_lowercase();
+        let invalid = |reason: &str| CalculatorError::invalid_args(&name, reason);
+        let [price, rates @ ..] = args else {
+            return Err(invalid("expected a price and its discounts"));
+        };
+        if rates.is_empty() {
+            return Err(invalid("expected at least one discount"));
+        }
+        let markups = name == "apply_percentages";
+        let rates = rates
+            .iter()
+            .map(|rate| {
+                rate.to_rational()
+                    .filter(|_| rate.unit == Unit::None)
+                    .map(|rate| if markups { -rate } else { rate })
+                    .filter(|rate| (markups || !rate.is_negative()) && *rate <= Rational::one())
+                    .ok_or_else(|| invalid("discounts must be percentages from 0% to 100%"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let amount = price
+            .to_rational()
+            .ok_or_else(|| invalid("expected a numeric price"))?;
+
+        let reversed = name == "original_price";
+        let original = if reversed {
+            let kept = rates.iter().fold(Rational::one(), |kept, rate| {
+                kept * (Rational::one() - rate.clone())
+            });
+            if kept.is_zero() {
+                return Err(invalid("a 100% discount leaves no price to go back from"));
+            }
+            amount / kept
+        } else {
+            amount
+        };
+        let stages = rates
+            .iter()
+            .scan(original.clone(), |current, rate| {
+                *current = current.clone() * (Rational::one() - rate.clone());
+                Some(Value::rational_with_unit(
+                    current.clone(),
+                    price.unit.clone(),
+                ))
+            })
+            .collect();
+        Ok(Self {
+            rates,
+            original: Value::rational_with_unit(original, price.unit.clone()),
+            stages,
+            reversed,
+        })
+    }
+
+    /// The value the function evaluates to: the final price for
+    /// `apply_discounts`, the original price for `original_price`.
+    #[must_use]
+    pub fn result(&self) -> Value {
+        if self.reversed {
+            self.original.clone()
+        } else {
+            self.stages.last().unwrap_or(&self.original).clone()
+        }
+    }
+
+    /// The labelled parts, in order: the original price, the price after
+    /// each discount or markup, and the total discount or markup.
+    #[must_use]
+    pub fn parts(&self) -> Vec<(String, Value)> {
+        let percent =
+            |rate: &Rational| (rate.abs() * Rational::from_integer(100)).to_display_string();
+        let mut parts = vec![("Original price".to_string(), self.original.clone())];
+        parts.extend(self.rates.iter().zip(&self.stages).map(|(rate, stage)| {
+            let label = if rate.is_negative() {
+                format!("After {}% markup", percent(rate))
+            } else {
+                format!("After {}% off", percent(rate))
+            };
+            (label, stage.clone())
+        }));
+        if let (Some(original), Some(last)) = (
+            self.original.to_rational(),
+            self.stages.last().and_then(Value::to_rational),
+        ) {
+            let total = original.clone() - last;
+            if !original.is_zero() {
+                let kind = if total.is_negative() {
+                    "markup"
+                } else {
+                    "discount"
+                };
+                parts.push((
+                    format!("Total {kind} {}%", percent(&(total.clone() / original))),
+                    Value::rational_with_unit(total.abs(), self.original.unit.clone()),
+                ));
+            }
+        }
+        parts
+    }
+}
+
+/// One period of an [`Amortization`] schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmortizationPeriod {
+    /// The period number, from 1.
+    pub period: usize,
+    /// The amount paid this period.
+    pub payment: Decimal,
+    /// The part of the payment that is interest.
+    pub interest: Decimal,
+    /// The part of the payment that repays the principal.
+    pub principal: Decimal,
+    /// The principal still owed after this period.
+    pub balance: Decimal,
+}
+
+/// A loan repaid in equal monthly payments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Amortization {
+    /// The unit of every amount, usually the principal's currency.
+    pub unit: Unit,
+    /// The regular monthly payment; the last payment may differ by a few cents.
+    pub payment: Decimal,
+    /// Every payment period, in order.
+    pub periods: Vec<AmortizationPeriod>,
+    /// The sum of all payments.
+    pub total_paid: Decimal,
+    /// The sum of all interest.
+    pub total_interest: Decimal,
+}
+
+impl Amortization {
+    /// Builds the schedule for `amortize(principal, annual rate, term)`,
+    /// where the term is a duration in years or months.
+    pub fn schedule(args: &[Value]) -> Result<Self, CalculatorError> {
+        let [principal, rate, term] = args else {
+            return Err(invalid_args(
+                "expected 3 arguments: amortize(principal, annual rate, term)",
+            ));
+        };
+        let amount = principal
+            .as_decimal()
+            .filter(|amount| !amount.is_negative() && !amount.is_zero())
+            .ok_or_else(|| invalid_args("principal must be a positive amount"))?;
+        let annual_rate = rate
+            .as_decimal()
+            .filter(|annual_rate| rate.unit == Unit::None && !annual_rate.is_negative())
+            .ok_or_else(|| invalid_args("rate must be a non-negative percentage, e.g. 3.5%"))?;
+        let months = term_in_months(term)?;
+
+        let monthly_rate = annual_rate.to_f64() / 12.0;
+        let payment = if monthly_rate == 0.0 {
+            amount.to_f64() / months as f64
+        } else {
+            amount.to_f64() * monthly_rate / (1.0 - (1.0 + monthly_rate).powi(-(months as i32)))
+        };
+        let payment = cents(Decimal::from_f64(payment)?);
+        let monthly_rate = Decimal::from_f64(monthly_rate)?;
+
+        let mut periods = Vec::with_capacity(months);
+        let mut balance = amount;
+        let (mut total_paid, mut total_interest) = (Decimal::zero(), Decimal::zero());
+        for period in 1..=months {
+            let interest = cents(balance * monthly_rate);
+            // The last payment clears whatever rounding left over
+            let principal_part = if period == months {
+                balance
+            } else {
+                (payment - interest).min(balance)
+            };
+            balance = balance - principal_part;
+            total_paid = total_paid + interest + principal_part;
+            total_interest = total_interest + interest;
+            periods.push(AmortizationPeriod {
+                period,
+                payment: interest + principal_part,
+                interest,
+                principal: principal_part,
+                balance,
+            });
+        }
+
+        Ok(Self {
+            unit: principal.unit.clone(),
+            payment,
+            periods,
+            total_paid,
+            total_interest,
+        })
+    }
+
+    /// Returns `amount` as a value in the schedule's unit.
+    #[must_use]
+    pub fn amount(&self, amount: Decimal) -> Value {
+        Value::number_with_unit(amount, self.unit.clone())
+    }
+}
+
+fn term_in_months(term: &Value) -> Result<usize, CalculatorError> {
+    let count = term
+        .as_decimal()
+        .map(|count| count.to_f64())
+        .ok_or_else(|| invalid_args("term must be a duration, e.g. 30 years"))?;
+    let months = match term.unit {
+        Unit::Duration(DurationUnit::Years) => count * 12.0,
+        Unit::Duration(DurationUnit::Months) => count,
+        _ => {
+            return Err(invalid_args(
+                "term must be in years or months, e.g. 30 years",
+            ))
+        }
+    };
+    #[allow(clippy::float_cmp)]
+    if months < 1.0 || months != months.trunc() || months > 1200.0 {
+        return Err(invalid_args(
+            "term must be a whole number of months, up to 100 years",
+        ));
+    }
+    #[allow(clippy::cast_sign_loss)] // at least 1, checked above
+    Ok(months as usize)
+}
+
+fn cents(amount: Decimal) -> Decimal {
+    amount.round_half_away_from_zero(2)
+}
+
+fn invalid_args(reason: &str) -> CalculatorError {
+    CalculatorError::invalid_args("amortize", reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(principal: i64, rate_per_mille: i64, years: i64) -> Amortization {
+        Amortization::schedule(&[
+            Value::number_with_unit(Decimal::new(principal), Unit::currency("USD")),
+            Value::rational(Rational::new(rate_per_mille.into(), 1000)),
+            Value::number_with_unit(Decimal::new(years), Unit::Duration(DurationUnit::Years)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_thirty_year_mortgage() {
+        let schedule = schedule(200_000, 35, 30);
+        assert_eq!(schedule.payment.to_string(), "898.09");
+        assert_eq!(schedule.periods.len(), 360);
+        assert!(schedule.periods.last().unwrap().balance.is_zero());
+        let principal = schedule
+            .periods
+            .iter()
+            .fold(Decimal::zero(), |sum, p| sum + p.principal);
+        assert_eq!(principal, Decimal::new(200_000));
+        assert_eq!(
+            schedule.total_paid,
+            schedule.total_interest + Decimal::new(200_000)
+        );
+    }
+
+    #[test]
+    fn test_vat_both_ways() {
+        let rate = Value::rational(Rational::new(19, 100));
+        let net = Value::from_integer_with_unit(150, Unit::currency("EUR"));
+        let added = VatBreakdown::compute("add_vat", &[net, rate], None).unwrap();
+        assert_eq!(added.result().to_display_string(), "178.5 EUR");
+        assert_eq!(added.tax.to_display_string(), "28.5 EUR");
+
+        let gross = Value::from_integer_with_unit(119, Unit::currency("EUR"));
+        let removed =
+            VatBreakdown::compute("remove_vat", &[gross], Some(&Rational::new(19, 100))).unwrap();
+        assert_eq!(removed.result().to_display_string(), "100 EUR");
+        assert_eq!(removed.parts()[1].0, "VAT 19%");
+    }
+
+    #[test]
+    fn test_tip_and_split() {
+        let bill = Value::rational_with_unit(Rational::new(8640, 100), Unit::currency("USD"));
+        let tip = Tip::compute(&[bill, Value::rational(Rational::new(18, 100))]).unwrap();
+        assert_eq!(tip.parts()[1].0, "Tip 18%");
+        let split = BillSplit::compute(&[tip.total, Value::from_integer(4)]).unwrap();
+        assert_eq!(split.per_person.to_display_string(), "25.488 USD");
+        assert!(BillSplit::compute(&[split.total, Value::from_integer(0)]).is_err());
+    }
+
+    #[test]
+    fn test_discounts_compound() {
+        let price = Value::from_integer_with_unit(200, Unit::currency("USD"));
+        let rates = [
+            Value::rational(Rational::new(20, 100)),
+            Value::rational(Rational::new(10, 100)),
+        ];
+        let args = [&[price][..], &rates[..]].concat();
+        let chain = DiscountChain::compute("apply_discounts", &args).unwrap();
+        assert_eq!(chain.result().to_display_string(), "144 USD");
+        assert_eq!(chain.parts()[3].0, "Total discount 28%");
+
+        let args = [&[chain.result()][..], &rates[..]].concat();
+        let chain = DiscountChain::compute("original_price", &args).unwrap();
+        assert_eq!(chain.result().to_display_string(), "200 USD");
+    }
+
+    #[test]
+    fn test_zero_rate_splits_evenly() {
+        let schedule = schedule(1200, 0, 1);
+        assert_eq!(schedule.payment, Decimal::new(100));
+        assert!(schedule.total_interest.is_zero());
+    }
+}
@@ -0,0 +1,123 @@
+//! Great-circle distance between two coordinates with the haversine
+//! formula: `haversine(lat1, lon1, lat2, lon2)`, written
+//! `distance between (55.7558, 37.6173) and (48.8566, 2.3522)`. Coordinates
+//! can also be angles, as in `(55° 45' 21" N, 37° 37' 2" E)`.
+//!
+//! The Earth is taken to be a sphere of mean radius 6371 km, which is within
+//! about 0.5% of the distance on the ellipsoid.
+
+use crate::error::CalculatorError;
+use crate::types::{AngleUnit, Decimal, Expression, LengthUnit, Unit, Value};
+
+/// Mean radius of the Earth in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Decimal places kept in the distance, in kilometers: to the meter.
+const DISTANCE_DECIMALS: u32 = 3;
+
+/// Returns true if `name` is `haversine`.
+#[must_use]
+pub fn is_geo_function(name: &str) -> bool {
+    name.eq_ignore_ascii_case("haversine")
+}
+
+/// Returns true if `expr` is a great-circle distance, `haversine(...)`,
+/// which `distance between ... and ...` is read as.
+#[must_use]
+pub fn is_distance(expr: &Expression) -> bool {
+    match expr {
+        Expression::FunctionCall { name, .. } => is_geo_function(name),
+        Expression::Group(inner) => is_distance(inner),
+        _ => false,
+    }
+}
+
+/// Rounds a distance converted to another unit, as in `distance between
+/// ... in miles`, to the decimal places it has in kilometers.
+#[must_use]
+pub fn round_distance(distance: Value) -> Value {
+    match distance.as_decimal() {
+        Some(amount) => Value::number_with_unit(
+            amount
+                .round_half_away_from_zero(DISTANCE_DECIMALS)
+                .normalize(),
+            distance.unit,
+        ),
+        None => distance,
+    }
+}
+
+/// Evaluates `haversine(lat1, lon1, lat2, lon2)`: the great-circle distance
+/// between two points given in decimal degrees, in kilometers.
+pub fn evaluate_geo_function(args: &[Value]) -> Result<Value, CalculatorError> {
+    let invalid = |reason: String| CalculatorError::invalid_args("haversine", reason);
+    let [lat1, lon1, lat2, lon2] = args else {
+        return Err(invalid(
+            "expected 4 arguments: haversine(lat1, lon1, lat2, lon2)".to_string(),
+        ));
+    };
+    let degrees = |value: &Value, what: &str, limit: f64| {
+        let degrees = value
+            .to_rational()
+            .and_then(|r| match value.unit {
+                Unit::None => Some(r.to_f64()),
+                Unit::Angle(unit) => {
+                    Some(r.to_f64() * unit.radians() / AngleUnit::Degree.radians())
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                invalid(format!(
+                    "the {what} must be an angle or a number of degrees"
+                ))
+            })?;
+        if degrees.abs() > limit {
+            return Err(invalid(format!(
+                "the {what} {degrees} is outside -{limit} to {limit} degrees"
+            )));
+        }
+        Ok(degrees)
+    };
+    let km = haversine_km(
+        (
+            degrees(lat1, "latitude", 90.0)?,
+            degrees(lon1, "longitude", 180.0)?,
+        ),
+        (
+            degrees(lat2, "latitude", 90.0)?,
+            degrees(lon2, "longitude", 180.0)?,
+        ),
+    );
+    let km = Decimal::from_f64(km)?
+        .round_half_away_from_zero(DISTANCE_DECIMALS)
+        .normalize();
+    Ok(Value::number_with_unit(
+        km,
+        Unit::Length(LengthUnit::Kilometer),
+    ))
+}
+
+/// Returns the great-circle distance in kilometers between two
+/// `(latitude, longitude)` points in degrees.
+fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.1 - from.1).to_radians();
+    let a =
+        (lat1.cos() * lat2.cos()).mul_add((d_lon / 2.0).sin().powi(2), (d_lat / 2.0).sin().powi(2));
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haversine_km() {
+        let moscow_paris = haversine_km((55.7558, 37.6173), (48.8566, 2.3522));
+        assert!((moscow_paris - 2486.0).abs() < 2.0, "{moscow_paris}");
+        assert!(haversine_km((10.0, 20.0), (10.0, 20.0)).abs() < 1e-9);
+        let half_circumference = EARTH_RADIUS_KM * std::f64::consts::PI;
+        assert!((haversine_km((0.0, 0.0), (0.0, 180.0)) - half_circumference).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,51 @@
+//! Hex digests of quoted text, behind the `text-tools` feature:
+//! - `md5("text")`: 128-bit MD5
+//! - `sha256("text")`: 256-bit SHA-256
+//! - `crc32("text")`: 32-bit CRC-32 (IEEE), as used by zip and PNG
+//!
+//! The text is hashed as UTF-8. The digests are pure Rust, so they work the
+//! same in WebAssembly.
+
+use std::fmt::Write;
+
+use md5::{Digest, Md5};
+use sha2::Sha256;
+
+use crate::error::CalculatorError;
+use crate::function_registry::{lookup, FunctionCategory};
+use crate::types::{Value, ValueKind};
+
+/// Returns true if `name` is a registered hash function.
+#[must_use]
+pub fn is_hash_function(name: &str) -> bool {
+    lookup(name).is_some_and(|function| function.category == FunctionCategory::Hash)
+}
+
+/// Evaluates a hash function, giving its digest in lowercase hex.
+pub fn evaluate_hash_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let [Value {
+        kind: ValueKind::Text(text),
+        ..
+    }] = args
+    else {
+        return Err(CalculatorError::invalid_args(
+            &name,
+            format!("expected quoted text: {name}(\"text\")"),
+        ));
+    };
+    let bytes = text.as_bytes();
+    let digest = match name.as_str() {
+        "md5" => hex(&Md5::digest(bytes)),
+        "sha256" => hex(&Sha256::digest(bytes)),
+        _ => format!("{:08x}", crc32fast::hash(bytes)),
+    };
+    Ok(Value::text(digest))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02x}").unwrap();
+        hex
+    })
+}
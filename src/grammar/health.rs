@@ -0,0 +1,182 @@
+//! Health formulas over body measurements:
+//! - `bmi(weight, height)`: body mass index, `bmi(70 kg, 1.75 m)`
+//! - `bmr(sex, age, height, weight)`: basal metabolic rate by the
+//!   Mifflin-St Jeor equation, `bmr(male, 30 years, 180 cm, 75 kg)`
+//!
+//! Measurements may be in any unit of their kind; plain numbers are taken to
+//! be kilograms, meters for the height in `bmi`, centimeters for the height
+//! in `bmr` and years. Each result keeps the formula it was computed with
+//! and, for `bmi`, its classification, so that steps can show them.
+
+use crate::error::CalculatorError;
+use crate::function_registry::{lookup, FunctionCategory};
+use crate::types::{Decimal, DurationUnit, LengthUnit, MassUnit, Unit, Value, ValueKind};
+
+/// Decimal places a body mass index is given to.
+const BMI_DECIMALS: u32 = 1;
+
+/// Returns true if `name` is a registered health function.
+#[must_use]
+pub fn is_health_function(name: &str) -> bool {
+    lookup(name).is_some_and(|function| function.category == FunctionCategory::Health)
+}
+
+/// Evaluates a health function.
+pub fn evaluate_health_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    Ok(HealthFormula::compute(name, args)?.result)
+}
+
+/// The result of a health function and the formula behind it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthFormula {
+    /// The formula in words, then with the measurements substituted, then
+    /// the classification of the result if there is one.
+    pub formula: Vec<String>,
+    /// The body mass index, or the basal metabolic rate in kcal/day.
+    pub result: Value,
+}
+
+impl HealthFormula {
+    /// Computes `bmi(weight, height)` or `bmr(sex, age, height, weight)`.
+    pub fn compute(name: &str, args: &[Value]) -> Result<Self, CalculatorError> {
+        if name.eq_ignore_ascii_case("bmr") {
+            Self::bmr(args)
+        } else {
+            Self::bmi(args)
+        }
+    }
+
+    fn bmi(args: &[Value]) -> Result<Self, CalculatorError> {
+        let [weight, height] = args else {
+            return Err(CalculatorError::invalid_args(
+                "bmi",
+                "expected 2 arguments: bmi(weight, height)",
+            ));
+        };
+        let kg = measurement("bmi", weight, "weight", &Unit::Mass(MassUnit::Kilogram))?;
+        let m = measurement("bmi", height, "height", &Unit::Length(LengthUnit::Meter))?;
+        let bmi = Decimal::from_f64(kg / (m * m))?
+            .round_half_away_from_zero(BMI_DECIMALS)
+            .normalize();
+        Ok(Self {
+            formula: vec![
+                "Body mass index: weight in kg / (height in m)^2".to_string(),
+                format!("= {} / {}^2", show(kg), show(m)),
+                format!("Classification: {}", bmi_classification(bmi.to_f64())),
+            ],
+            result: Value::number(bmi),
+        })
+    }
+
+    fn bmr(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("bmr", reason);
+        let [sex, age, height, weight] = args else {
+            return Err(invalid(
+                "expected 4 arguments: bmr(male or female, age, height, weight)",
+            ));
+        };
+        let (offset, constant) = match &sex.kind {
+            ValueKind::Text(word) => match word.to_lowercase().as_str() {
+                "male" | "man" | "m" => (5.0, "+ 5"),
+                "female" | "woman" | "f" => (-161.0, "- 161"),
+                _ => return Err(invalid("the first argument must be male or female")),
+            },
+            _ => return Err(invalid("the first argument must be male or female")),
+        };
+        let years = measurement("bmr", age, "age", &Unit::Duration(DurationUnit::Years))?;
+        let cm = measurement(
+            "bmr",
+            height,
+            "height",
+            &Unit::Length(LengthUnit::Centimeter),
+        )?;
+        let kg = measurement("bmr", weight, "weight", &Unit::Mass(MassUnit::Kilogram))?;
+        let kcal = 5.0f64.mul_add(-years, 6.25f64.mul_add(cm, 10.0 * kg)) + offset;
+        let kcal = Decimal::from_f64(kcal)?.round_half_away_from_zero(0);
+        Ok(Self {
+            formula: vec![
+                format!(
+                    "Basal metabolic rate (Mifflin-St Jeor): \
+                     10 * weight in kg + 6.25 * height in cm - 5 * age in years {constant}"
+                ),
+                format!(
+                    "= 10 * {} + 6.25 * {} - 5 * {} {constant}",
+                    show(kg),
+                    show(cm),
+                    show(years)
+                ),
+            ],
+            result: Value::number_with_unit(kcal, Unit::Custom("kcal/day".to_string())),
+        })
+    }
+}
+
+/// Returns a positive measurement in `unit`, taking a plain number to be in
+/// that unit already.
+fn measurement(
+    function: &str,
+    value: &Value,
+    what: &str,
+    unit: &Unit,
+) -> Result<f64, CalculatorError> {
+    let amount = value.to_rational().map(|r| r.to_f64());
+    let converted = match (&value.unit, amount) {
+        (Unit::None, Some(amount)) => Some(amount),
+        (from, Some(amount)) => from.conversion_factor(unit).map(|factor| amount * factor),
+        (_, None) => None,
+    };
+    let converted = converted.ok_or_else(|| {
+        CalculatorError::invalid_args(function, format!("the {what} must be in {unit}"))
+    })?;
+    if converted <= 0.0 {
+        return Err(CalculatorError::invalid_args(
+            function,
+            format!("the {what} must be positive"),
+        ));
+    }
+    Ok(converted)
+}
+
+/// Writes a measurement to at most 2 decimal places.
+fn show(amount: f64) -> String {
+    Decimal::try_from_f64(amount).map_or_else(
+        || amount.to_string(),
+        |amount| amount.round_half_away_from_zero(2).normalize().to_string(),
+    )
+}
+
+/// The WHO classification of a body mass index for adults.
+fn bmi_classification(bmi: f64) -> &'static str {
+    match bmi {
+        b if b < 18.5 => "underweight (below 18.5)",
+        b if b < 25.0 => "normal weight (18.5 to 24.9)",
+        b if b < 30.0 => "overweight (25 to 29.9)",
+        _ => "obese (30 or more)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bmi_classification() {
+        assert_eq!(bmi_classification(17.0), "underweight (below 18.5)");
+        assert_eq!(bmi_classification(22.9), "normal weight (18.5 to 24.9)");
+        assert_eq!(bmi_classification(25.0), "overweight (25 to 29.9)");
+        assert_eq!(bmi_classification(31.2), "obese (30 or more)");
+    }
+
+    #[test]
+    fn test_bmr() {
+        let args = [
+            Value::text("female"),
+            Value::from_integer(25),
+            Value::from_integer(165),
+            Value::from_integer(60),
+        ];
+        let bmr = HealthFormula::compute("bmr", &args).unwrap();
+        // 600 + 1031.25 - 125 - 161
+        assert_eq!(bmr.result.to_display_string(), "1345 kcal/day");
+    }
+}
@@ -0,0 +1,168 @@
+//! Functions computed exactly over big integers, so that large results keep
+//! every digit instead of overflowing a float:
+//! - `factorial(n)` (also `n!`): `factorial(30)` is `265252859812191058636308480000000`
+//! - `ncr(n, k)` (also `choose`): the ways to choose `k` of `n`, `ncr(10, 3)` is `120`
+//! - `pow(base, exponent)`: the same as `base ^ exponent`, `pow(2, 100)`
+
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive};
+
+use super::power::evaluate_power;
+use crate::error::CalculatorError;
+use crate::types::{Rational, Unit, Value};
+
+/// The functions of a natural number that count: the factorial and the
+/// binomial coefficient.
+pub const FACTORIAL_FUNCTIONS: &[&str] = &["factorial", "ncr", "choose"];
+
+/// Returns true if `name` is `factorial`, `ncr` or `choose`, whose first
+/// argument is limited by [`crate::types::EvalConfig::max_factorial_input`].
+#[must_use]
+pub fn is_factorial_function(name: &str) -> bool {
+    FACTORIAL_FUNCTIONS
+        .iter()
+        .any(|f| name.eq_ignore_ascii_case(f))
+}
+
+/// Returns true if `name` is evaluated with [`evaluate_integer_function`].
+#[must_use]
+pub fn is_integer_function(name: &str) -> bool {
+    is_factorial_function(name) || name.eq_ignore_ascii_case("pow")
+}
+
+/// Evaluates `factorial(n)`, `ncr(n, k)` or `pow(base, exponent)`.
+pub fn evaluate_integer_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    match (name.as_str(), args) {
+        ("factorial", [n]) => {
+            let n = natural(&name, n)?;
+            Ok(integer_value(factorial(n)))
+        }
+        ("ncr" | "choose", [n, k]) => {
+            let (n, k) = (natural(&name, n)?, natural(&name, k)?);
+            Ok(integer_value(binomial(n, k)))
+        }
+        ("pow", [base, exponent]) => evaluate_power(base, exponent),
+        _ => {
+            let usage = match name.as_str() {
+                "factorial" => "expected 1 argument: factorial(n)".to_string(),
+                "pow" => "expected 2 arguments: pow(base, exponent)".to_string(),
+                _ => format!("expected 2 arguments: {name}(n, k)"),
+            };
+            Err(CalculatorError::invalid_args(&name, usage))
+        }
+    }
+}
+
+/// Returns the value as a non-negative integer, the only inputs factorials
+/// and binomial coefficients are defined for.
+fn natural(name: &str, value: &Value) -> Result<u64, CalculatorError> {
+    value
+        .to_rational()
+        .filter(|n| value.unit == Unit::None && n.is_integer() && !n.is_negative())
+        .and_then(|n| n.numer_bigint().to_u64())
+        .ok_or_else(|| {
+            CalculatorError::domain(format!("{name} argument must be a non-negative integer"))
+        })
+}
+
+fn integer_value(n: BigInt) -> Value {
+    Value::rational(Rational::from_bigint(n))
+}
+
+/// `n!`, exactly.
+fn factorial(n: u64) -> BigInt {
+    (2..=n).fold(BigInt::one(), |product, i| product * i)
+}
+
+/// Largest argument that [`exact_gamma`] works out as a whole number.
+const EXACT_GAMMA_MAX: u64 = 1000;
+
+/// Returns `gamma(n)` as the exact `(n - 1)!` when `n` is a positive
+/// integer, so `gamma(30)` keeps every digit. Returns `None` otherwise.
+#[must_use]
+pub fn exact_gamma(n: &Value) -> Option<Value> {
+    let n = natural("gamma", n).ok()?;
+    (n != 0 && n <= EXACT_GAMMA_MAX).then(|| integer_value(factorial(n - 1)))
+}
+
+/// Largest `a + b` that [`exact_beta`] works out as a fraction.
+const EXACT_BETA_MAX_SUM: u64 = 1000;
+
+/// Returns `beta(a, b)` as an exact fraction when `a` and `b` are positive
+/// integers: `(a - 1)! (b - 1)! / (a + b - 1)!`, which is
+/// `1 / ((a + b - 1) * ncr(a + b - 2, a - 1))`. Returns `None` otherwise.
+#[must_use]
+pub fn exact_beta(a: &Value, b: &Value) -> Option<Value> {
+    let (a, b) = (natural("beta", a).ok()?, natural("beta", b).ok()?);
+    if a == 0 || b == 0 || a + b > EXACT_BETA_MAX_SUM {
+        return None;
+    }
+    let denominator = binomial(a + b - 2, a - 1) * (a + b - 1);
+    Some(Value::rational(Rational::new_bigint(
+        BigInt::one(),
+        denominator,
+    )))
+}
+
+/// The binomial coefficient `n` choose `k`, which is `0` when `k > n`.
+fn binomial(n: u64, k: u64) -> BigInt {
+    if k > n {
+        return BigInt::ZERO;
+    }
+    // Each partial product is itself a binomial coefficient, so the
+    // division is exact
+    let k = k.min(n - k);
+    (0..k).fold(BigInt::one(), |product, i| product * (n - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial_is_exact() {
+        assert_eq!(factorial(0), BigInt::one());
+        assert_eq!(factorial(25).to_string(), "15511210043330985984000000");
+        assert_eq!(factorial(1000).to_string().len(), 2568);
+    }
+
+    #[test]
+    fn test_exact_gamma() {
+        let gamma = |n| exact_gamma(&Value::from_integer(n));
+        assert_eq!(gamma(1), Some(Value::from_integer(1)));
+        assert_eq!(gamma(5), Some(Value::from_integer(24)));
+        assert_eq!(
+            gamma(29).and_then(|value| value.to_rational()),
+            Some(Rational::from_bigint(factorial(28)))
+        );
+        assert_eq!(gamma(0), None);
+        assert_eq!(exact_gamma(&Value::rational(Rational::new(1, 2))), None);
+    }
+
+    #[test]
+    fn test_exact_beta() {
+        let beta = |a, b| exact_beta(&Value::from_integer(a), &Value::from_integer(b));
+        assert_eq!(beta(1, 3), Some(Value::rational(Rational::new(1, 3))));
+        assert_eq!(beta(2, 3), Some(Value::rational(Rational::new(1, 12))));
+        assert_eq!(beta(0, 3), None);
+        assert_eq!(
+            exact_beta(
+                &Value::rational(Rational::new(1, 2)),
+                &Value::from_integer(1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(10, 3), BigInt::from(120));
+        assert_eq!(binomial(10, 0), BigInt::one());
+        assert_eq!(binomial(3, 5), BigInt::ZERO);
+        assert_eq!(
+            binomial(100, 50).to_string(),
+            "100891344545564193334812497256"
+        );
+    }
+}
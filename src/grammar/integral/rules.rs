@@ -0,0 +1,658 @@
+//! Rule-based symbolic integration.
+//!
+//! Rules are tried in order: constants, linearity, standard table forms
+//! (with linear inner arguments `a*x + b`), special integrals (Si, Ci, Ei),
+//! logarithmic derivatives, u-substitution, squares of `sin`/`cos`, `exp`
+//! times `sin`/`cos`, and integration by parts for a polynomial times
+//! `exp`/`sin`/`cos`/`ln`. Each rule either returns an exact
+//! antiderivative or declines, so unsupported integrands produce no result
+//! rather than a wrong one.
+
+use crate::grammar::symbolic::{
+    add, as_number, call, depends_on, differentiate, div, eval_at, from_poly, int, is_euler,
+    is_number, mul, neg, num, poly_coeffs, poly_degree, pow, scale, sub, substitute, var,
+};
+use crate::types::{BinaryOp, Decimal, Expression, Rational};
+
+/// Placeholder variable used while integrating the outer function of a
+/// substitution. It contains a character the lexer never produces.
+const SUBSTITUTION_VAR: &str = "$u";
+
+/// Maximum rule nesting, guarding against pathological recursion.
+const MAX_DEPTH: usize = 12;
+
+/// Sample points used to check that a substitution ratio is constant.
+const SAMPLE_POINTS: [f64; 7] = [-0.9, -0.35, 0.3, 0.65, 0.85, 1.3, 2.2];
+
+/// Computes an antiderivative of `integrand` with respect to `x`, without
+/// the constant of integration.
+pub(super) fn antiderivative(integrand: &Expression, x: &str) -> Option<Expression> {
+    integrate(integrand, x, 0)
+}
+
+fn integrate(e: &Expression, x: &str, depth: usize) -> Option<Expression> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    if !depends_on(e, x) {
+        return Some(mul(e.clone(), var(x)));
+    }
+    let next = depth + 1;
+
+    match e {
+        Expression::Group(inner) => integrate(inner, x, depth),
+        Expression::Variable(_) => Some(div(pow(var(x), int(2)), int(2))),
+        Expression::Negate(inner) => Some(neg(integrate(inner, x, next)?)),
+        Expression::Binary { left, op, right } => match op {
+            BinaryOp::Add => Some(add(integrate(left, x, next)?, integrate(right, x, next)?)),
+            BinaryOp::Subtract => Some(sub(integrate(left, x, next)?, integrate(right, x, next)?)),
+            BinaryOp::Multiply => integrate_product(e, left, right, x, next),
+            BinaryOp::Divide => integrate_quotient(e, left, right, x, next),
+            BinaryOp::Modulo => None,
+        },
+        Expression::Power { base, exponent } => integrate_power(e, base, exponent, x, next),
+        Expression::FunctionCall { name, args } if args.len() == 1 => {
+            table_form(name, &args[0], x).or_else(|| substitution(e, x, next))
+        }
+        _ => None,
+    }
+}
+
+fn integrate_product(
+    e: &Expression,
+    left: &Expression,
+    right: &Expression,
+    x: &str,
+    depth: usize,
+) -> Option<Expression> {
+    if !depends_on(left, x) {
+        return Some(mul(left.clone(), integrate(right, x, depth)?));
+    }
+    if !depends_on(right, x) {
+        return Some(mul(right.clone(), integrate(left, x, depth)?));
+    }
+    // Expand polynomial products such as x * (x + 1) before anything else.
+    if let Some(coeffs) = poly_coeffs(e, x) {
+        return Some(integrate_poly(&coeffs, x));
+    }
+    substitution(e, x, depth)
+        .or_else(|| exp_times_trig(left, right, x))
+        .or_else(|| exp_times_trig(right, left, x))
+        .or_else(|| by_parts(left, right, x, depth))
+        .or_else(|| by_parts(right, left, x, depth))
+}
+
+fn integrate_quotient(
+    e: &Expression,
+    left: &Expression,
+    right: &Expression,
+    x: &str,
+    depth: usize,
+) -> Option<Expression> {
+    if !depends_on(right, x) {
+        return Some(div(integrate(left, x, depth)?, right.clone()));
+    }
+    if let Some(special) = special_integral(left, right, x) {
+        return Some(special);
+    }
+    if !depends_on(left, x) {
+        if let Some(reciprocal) = integrate_reciprocal(right, x) {
+            return Some(mul(left.clone(), reciprocal));
+        }
+    }
+    // Logarithmic derivative: c * h'(x) / h(x) -> c * ln|h(x)|
+    if let Some(c) = constant_ratio(left, &differentiate(right, x)?, x) {
+        return Some(scale(c, ln_abs(right.clone())));
+    }
+    // ... also with a cofactor in the denominator: 1 / (x * ln(x)) is
+    // (1/x) / ln(x) -> ln|ln(x)|
+    if let Expression::Binary {
+        left: p,
+        op: BinaryOp::Multiply,
+        right: q,
+    } = right
+    {
+        for (cofactor, h) in [(p, q), (q, p)] {
+            let expected = mul((**cofactor).clone(), differentiate(h, x)?);
+            if let Some(c) = constant_ratio(left, &expected, x) {
+                return Some(scale(c, ln_abs((**h).clone())));
+            }
+        }
+    }
+    // Reciprocal powers become negative powers for substitution:
+    // x / sqrt(x^2 + 1) -> x * (x^2 + 1)^(-1/2)
+    let reciprocal = match right {
+        Expression::FunctionCall { name, args } if args.len() == 1 && name == "sqrt" => {
+            Expression::power(args[0].clone(), num(Decimal::new(-1) / Decimal::new(2)))
+        }
+        Expression::Power { base, exponent } if as_number(exponent).is_some() => {
+            Expression::power((**base).clone(), num(-as_number(exponent)?))
+        }
+        _ => return substitution(e, x, depth),
+    };
+    integrate(
+        &Expression::binary(left.clone(), BinaryOp::Multiply, reciprocal),
+        x,
+        depth,
+    )
+}
+
+fn integrate_power(
+    e: &Expression,
+    base: &Expression,
+    exponent: &Expression,
+    x: &str,
+    depth: usize,
+) -> Option<Expression> {
+    if !depends_on(exponent, x) {
+        if let Some((a, _)) = linear(base, x) {
+            return Some(match as_number(exponent) {
+                Some(n) if n == -Decimal::one() => div(ln_abs(base.clone()), num(a)),
+                Some(n) => {
+                    let n1 = n + Decimal::one();
+                    let d = a * n1;
+                    if d == d.round(0) {
+                        div(pow(base.clone(), num(n1)), num(d))
+                    } else {
+                        scale(Decimal::one().checked_div(&d)?, pow(base.clone(), num(n1)))
+                    }
+                }
+                None => {
+                    let n1 = add(exponent.clone(), int(1));
+                    div(pow(base.clone(), n1.clone()), mul(num(a), n1))
+                }
+            });
+        }
+        if let Some(coeffs) = poly_coeffs(e, x) {
+            return Some(integrate_poly(&coeffs, x));
+        }
+        if let Some(square) = trig_square(base, exponent, x) {
+            return Some(square);
+        }
+    }
+    if !depends_on(base, x) {
+        if let Some((a, _)) = linear(exponent, x) {
+            if is_euler(base) {
+                return Some(div(e.clone(), num(a)));
+            }
+            return Some(div(e.clone(), mul(num(a), call("ln", base.clone()))));
+        }
+    }
+    substitution(e, x, depth)
+}
+
+/// Standard table forms `∫f(a*x + b) dx = F(a*x + b) / a`.
+fn table_form(name: &str, u: &Expression, x: &str) -> Option<Expression> {
+    let (a, _) = linear(u, x)?;
+    let u = u.clone();
+    let f = match name.to_lowercase().as_str() {
+        "sin" => neg(call("cos", u)),
+        "cos" => call("sin", u),
+        "tan" => neg(ln_abs(call("cos", u))),
+        "exp" => call("exp", u),
+        "sinh" => call("cosh", u),
+        "cosh" => call("sinh", u),
+        "tanh" => call("ln", call("cosh", u)),
+        "ln" => sub(mul(u.clone(), call("ln", u.clone())), u),
+        "sqrt" => div(
+            mul(int(2), pow(u, num(Decimal::new(3) / Decimal::new(2)))),
+            int(3),
+        ),
+        "atan" => sub(
+            mul(u.clone(), call("atan", u.clone())),
+            div(call("ln", add(int(1), pow(u, int(2)))), int(2)),
+        ),
+        _ => return None,
+    };
+    Some(div(f, num(a)))
+}
+
+/// `∫sin(u)^2 dx = x/2 − sin(2u)/(4a)` and `∫cos(u)^2 dx = x/2 + sin(2u)/(4a)`
+/// for `u = a*x + b`.
+fn trig_square(base: &Expression, exponent: &Expression, x: &str) -> Option<Expression> {
+    let Expression::FunctionCall { name, args } = base else {
+        return None;
+    };
+    if args.len() != 1 || !is_number(exponent, 2) {
+        return None;
+    }
+    let (a, _) = linear(&args[0], x)?;
+    let half = div(var(x), int(2));
+    let double = div(
+        call("sin", mul(int(2), args[0].clone())),
+        num(a * Decimal::new(4)),
+    );
+    match name.to_lowercase().as_str() {
+        "sin" => Some(sub(half, double)),
+        "cos" => Some(add(half, double)),
+        _ => None,
+    }
+}
+
+/// `∫exp(u)·sin(v) dx = exp(u)·(a·sin(v) − c·cos(v)) / (a² + c²)`, and
+/// likewise for `cos`, for `u = a*x + b` and `v = c*x + d`.
+fn exp_times_trig(exp: &Expression, trig: &Expression, x: &str) -> Option<Expression> {
+    let (
+        Expression::FunctionCall {
+            name: exp_name,
+            args: exp_args,
+        },
+        Expression::FunctionCall {
+            name,
+            args: trig_args,
+        },
+    ) = (exp, trig)
+    else {
+        return None;
+    };
+    if exp_name != "exp" || exp_args.len() != 1 || trig_args.len() != 1 {
+        return None;
+    }
+    let (exp_slope, _) = linear(&exp_args[0], x)?;
+    let (trig_slope, _) = linear(&trig_args[0], x)?;
+    let (sin, cos) = (
+        call("sin", trig_args[0].clone()),
+        call("cos", trig_args[0].clone()),
+    );
+    let combination = match name.as_str() {
+        "sin" => sub(scale(exp_slope, sin), scale(trig_slope, cos)),
+        "cos" => add(scale(exp_slope, cos), scale(trig_slope, sin)),
+        _ => return None,
+    };
+    Some(div(
+        mul(exp.clone(), combination),
+        num(exp_slope * exp_slope + trig_slope * trig_slope),
+    ))
+}
+
+/// Non-elementary integrals expressed through special functions.
+fn special_integral(left: &Expression, right: &Expression, x: &str) -> Option<Expression> {
+    if !matches!(right, Expression::Variable(v) if v == x) {
+        return None;
+    }
+    let Expression::FunctionCall { name, args } = left else {
+        return None;
+    };
+    if args.len() != 1 || !matches!(&args[0], Expression::Variable(v) if v == x) {
+        return None;
+    }
+    let special = match name.to_lowercase().as_str() {
+        "sin" => "Si",
+        "cos" => "Ci",
+        "exp" => "Ei",
+        _ => return None,
+    };
+    Some(call(special, var(x)))
+}
+
+/// Integrates `1 / r` for linear and simple quadratic denominators.
+fn integrate_reciprocal(r: &Expression, x: &str) -> Option<Expression> {
+    if let Some((a, _)) = linear(r, x) {
+        return Some(div(ln_abs(r.clone()), num(a)));
+    }
+
+    // 1 / sqrt(c - a*x^2) -> asin(x * sqrt(a / c)) / sqrt(a)
+    if let Expression::FunctionCall { name, args } = r {
+        if name == "sqrt" && args.len() == 1 {
+            let coeffs = quadratic_without_linear_term(&args[0], x)?;
+            let (c, a) = (coeffs[0], -coeffs[2]);
+            if a.is_negative() || a.is_zero() || c.is_negative() || c.is_zero() {
+                return None;
+            }
+            let inner = times_x(sqrt_number(a.checked_div(&c)?), x);
+            return Some(div(call("asin", inner), sqrt_number(a)));
+        }
+    }
+
+    // 1 / (a*x^2 + c) -> atan(x * sqrt(a / c)) / sqrt(a * c)
+    let coeffs = quadratic_without_linear_term(r, x)?;
+    let (c, a) = (coeffs[0], coeffs[2]);
+    if a.is_negative() || c.is_negative() || c.is_zero() {
+        return None;
+    }
+    let inner = times_x(sqrt_number(a.checked_div(&c)?), x);
+    Some(div(call("atan", inner), sqrt_number(a * c)))
+}
+
+/// Returns `[c, 0, a]` when `expr` is `a*x^2 + c` with `a != 0`.
+fn quadratic_without_linear_term(expr: &Expression, x: &str) -> Option<Vec<Decimal>> {
+    let coeffs = poly_coeffs(expr, x)?;
+    (poly_degree(&coeffs) == Some(2) && coeffs[1].is_zero() && !coeffs[2].is_zero())
+        .then_some(coeffs)
+}
+
+/// `k * x`, written as `x / n` when `k` is the unit fraction `1/n`.
+fn times_x(k: Expression, x: &str) -> Expression {
+    match as_number(&k) {
+        Some(k) => scale(k, var(x)),
+        None => mul(k, var(x)),
+    }
+}
+
+/// A square root as an exact number when possible, otherwise `sqrt(n)`.
+fn sqrt_number(n: Decimal) -> Expression {
+    match Rational::from_decimal(n)
+        .nth_root(2)
+        .and_then(|root| root.to_decimal())
+    {
+        Some(root) => num(root),
+        None => call("sqrt", num(n)),
+    }
+}
+
+/// u-substitution: finds an inner function `h(x)` such that the integrand is
+/// `c * g(h(x)) * h'(x)` for a constant `c`, then integrates `g` in `u`.
+fn substitution(e: &Expression, x: &str, depth: usize) -> Option<Expression> {
+    let mut candidates = Vec::new();
+    collect_candidates(e, x, &mut candidates);
+
+    for candidate in candidates {
+        let (outer, inner) = match &candidate {
+            Expression::FunctionCall { name, args } => (
+                Expression::function_call(name.clone(), vec![var(SUBSTITUTION_VAR)]),
+                args[0].clone(),
+            ),
+            Expression::Power { base, exponent } if !depends_on(exponent, x) => (
+                Expression::power(var(SUBSTITUTION_VAR), (**exponent).clone()),
+                (**base).clone(),
+            ),
+            Expression::Power { base, exponent } => (
+                Expression::power((**base).clone(), var(SUBSTITUTION_VAR)),
+                (**exponent).clone(),
+            ),
+            _ => continue,
+        };
+        if linear(&inner, x).is_some() {
+            continue;
+        }
+        let Some(inner_derivative) = differentiate(&inner, x) else {
+            continue;
+        };
+        let expected = Expression::binary(candidate.clone(), BinaryOp::Multiply, inner_derivative);
+        let Some(c) = constant_ratio(e, &expected, x) else {
+            continue;
+        };
+        if let Some(g) = integrate(&outer, SUBSTITUTION_VAR, depth) {
+            return Some(scale(c, substitute(&g, SUBSTITUTION_VAR, &inner)));
+        }
+    }
+    None
+}
+
+/// Collects function calls and powers whose argument depends on `x`.
+fn collect_candidates(e: &Expression, x: &str, out: &mut Vec<Expression>) {
+    match e {
+        Expression::FunctionCall { args, .. } => {
+            if args.len() == 1 && depends_on(&args[0], x) {
+                out.push(e.clone());
+            }
+            for arg in args {
+                collect_candidates(arg, x, out);
+            }
+        }
+        Expression::Power { base, exponent } => {
+            if depends_on(e, x) {
+                out.push(e.clone());
+            }
+            collect_candidates(base, x, out);
+            collect_candidates(exponent, x, out);
+        }
+        Expression::Binary { left, right, .. } => {
+            collect_candidates(left, x, out);
+            collect_candidates(right, x, out);
+        }
+        Expression::Negate(inner) | Expression::Group(inner) => collect_candidates(inner, x, out),
+        _ => {}
+    }
+}
+
+/// Integration by parts for `P(x) * f(x)` where `P` is a polynomial and
+/// `f` is `exp`, `sin`, `cos`, `sinh` or `cosh` of a linear argument, or
+/// `ln(x)`.
+fn by_parts(p: &Expression, f: &Expression, x: &str, depth: usize) -> Option<Expression> {
+    let coeffs = poly_coeffs(p, x)?;
+    poly_degree(&coeffs)?;
+
+    if let Expression::FunctionCall { name, args } = f {
+        if args.len() == 1 && name == "ln" && matches!(&args[0], Expression::Variable(v) if v == x)
+        {
+            return Some(polynomial_times_ln(&coeffs, x));
+        }
+    }
+
+    // Tabular integration: ∫P·f = P·F1 − P'·F2 + P''·F3 − ...
+    let Expression::FunctionCall { name, args } = f else {
+        return None;
+    };
+    if args.len() != 1
+        || !matches!(name.as_str(), "exp" | "sin" | "cos" | "sinh" | "cosh")
+        || linear(&args[0], x).is_none()
+    {
+        return None;
+    }
+    let mut result = int(0);
+    let mut derivative = p.clone();
+    let mut antiderivative = f.clone();
+    let mut sign_positive = true;
+    for _ in 0..=poly_degree(&coeffs)? {
+        antiderivative = integrate(&antiderivative, x, depth)?;
+        let term = mul(derivative.clone(), antiderivative.clone());
+        result = if sign_positive {
+            add(result, term)
+        } else {
+            sub(result, term)
+        };
+        sign_positive = !sign_positive;
+        derivative = differentiate(&derivative, x)?;
+    }
+    Some(result)
+}
+
+/// `∫P(x)·ln(x) dx = Q(x)·ln(x) − ∫Q(x)/x dx` where `Q = ∫P`.
+fn polynomial_times_ln(coeffs: &[Decimal], x: &str) -> Expression {
+    let q = integrate_poly_coeffs(coeffs);
+    // Q has no constant term, so Q(x)/x is a polynomial again.
+    let q_over_x: Vec<Decimal> = q.iter().skip(1).copied().collect();
+    let correction = integrate_poly_coeffs(&q_over_x);
+    sub(
+        mul(from_poly(&q, x), call("ln", var(x))),
+        from_poly(&correction, x),
+    )
+}
+
+fn integrate_poly(coeffs: &[Decimal], x: &str) -> Expression {
+    let mut result = int(0);
+    for (power, coeff) in coeffs.iter().enumerate().rev() {
+        if coeff.is_zero() {
+            continue;
+        }
+        let n1 = power as i64 + 1;
+        let term = div(mul(num(*coeff), pow(var(x), int(n1))), int(n1));
+        result = add(result, term);
+    }
+    result
+}
+
+fn integrate_poly_coeffs(coeffs: &[Decimal]) -> Vec<Decimal> {
+    let mut result = vec![Decimal::zero()];
+    for (power, coeff) in coeffs.iter().enumerate() {
+        let n1 = Decimal::new(power as i64 + 1);
+        result.push(coeff.checked_div(&n1).unwrap_or_default());
+    }
+    result
+}
+
+/// Returns `(a, b)` when `expr` is the linear function `a*x + b`, `a != 0`.
+fn linear(expr: &Expression, x: &str) -> Option<(Decimal, Decimal)> {
+    let coeffs = poly_coeffs(expr, x)?;
+    (poly_degree(&coeffs) == Some(1)).then(|| (coeffs[1], coeffs[0]))
+}
+
+fn ln_abs(expr: Expression) -> Expression {
+    call("ln", call("abs", expr))
+}
+
+/// Returns `c` if `numerator / denominator` equals the same constant `c`
+/// at every sample point where both sides are defined.
+fn constant_ratio(numerator: &Expression, denominator: &Expression, x: &str) -> Option<Decimal> {
+    let mut ratio: Option<f64> = None;
+    let mut samples = 0;
+    for point in SAMPLE_POINTS {
+        let (Some(n), Some(d)) = (eval_at(numerator, x, point), eval_at(denominator, x, point))
+        else {
+            continue;
+        };
+        if d.abs() < 1e-12 {
+            continue;
+        }
+        let r = n / d;
+        match ratio {
+            Some(first) if (r - first).abs() > 1e-9 * first.abs().max(1.0) => return None,
+            Some(_) => {}
+            None => ratio = Some(r),
+        }
+        samples += 1;
+    }
+    if samples < 3 {
+        return None;
+    }
+    let c = Decimal::try_from_f64(ratio?)?.round(9).normalize();
+    (!c.is_zero()).then_some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::symbolic::with_groups;
+
+    fn integral(e: &Expression) -> String {
+        with_groups(&antiderivative(e, "x").expect("should integrate")).to_string()
+    }
+
+    /// Checks F'(x) ≈ f(x) numerically at a few points.
+    fn assert_antiderivative(f: &Expression) {
+        let big_f = antiderivative(f, "x").expect("should integrate");
+        for point in [0.4, 0.7, 1.6] {
+            let h = 1e-5;
+            let (Some(hi), Some(lo), Some(expected)) = (
+                eval_at(&big_f, "x", point + h),
+                eval_at(&big_f, "x", point - h),
+                eval_at(f, "x", point),
+            ) else {
+                panic!("undefined at {point}");
+            };
+            let slope = (hi - lo) / (2.0 * h);
+            assert!(
+                (slope - expected).abs() < 1e-4,
+                "F'({point}) = {slope}, f({point}) = {expected}"
+            );
+        }
+    }
+
+    fn x() -> Expression {
+        var("x")
+    }
+
+    #[test]
+    fn test_x_times_exp_x_by_parts() {
+        let f = Expression::binary(x(), BinaryOp::Multiply, call("exp", x()));
+        assert_eq!(integral(&f), "x * exp(x) - exp(x)");
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_one_over_one_plus_x_squared() {
+        let f = Expression::binary(
+            int(1),
+            BinaryOp::Divide,
+            Expression::binary(int(1), BinaryOp::Add, Expression::power(x(), int(2))),
+        );
+        assert_eq!(integral(&f), "atan(x)");
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_linearity() {
+        // 3*x^2 + 2*sin(x) -> x^3 - 2*cos(x)
+        let f = Expression::binary(
+            Expression::binary(int(3), BinaryOp::Multiply, Expression::power(x(), int(2))),
+            BinaryOp::Add,
+            Expression::binary(int(2), BinaryOp::Multiply, call("sin", x())),
+        );
+        assert_eq!(integral(&f), "x^3 - 2 * cos(x)");
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_linear_inner_argument() {
+        let inner = Expression::binary(int(2), BinaryOp::Multiply, x());
+        let f = call("cos", inner);
+        assert_eq!(integral(&f), "sin(2 * x) / 2");
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_u_substitution() {
+        // x * exp(x^2) -> exp(x^2) / 2
+        let f = Expression::binary(
+            x(),
+            BinaryOp::Multiply,
+            call("exp", Expression::power(x(), int(2))),
+        );
+        assert_antiderivative(&f);
+
+        // cos(x) * sin(x)^2 -> sin(x)^3 / 3
+        let f = Expression::binary(
+            call("cos", x()),
+            BinaryOp::Multiply,
+            Expression::power(call("sin", x()), int(2)),
+        );
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_logarithmic_derivative() {
+        // 2x / (x^2 + 1) -> ln|x^2 + 1|
+        let f = Expression::binary(
+            Expression::binary(int(2), BinaryOp::Multiply, x()),
+            BinaryOp::Divide,
+            Expression::binary(Expression::power(x(), int(2)), BinaryOp::Add, int(1)),
+        );
+        assert_eq!(integral(&f), "ln(abs(x^2 + 1))");
+    }
+
+    #[test]
+    fn test_polynomial_times_trig_by_parts() {
+        let f = Expression::binary(
+            Expression::power(x(), int(2)),
+            BinaryOp::Multiply,
+            call("sin", x()),
+        );
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_x_ln_x() {
+        let f = Expression::binary(x(), BinaryOp::Multiply, call("ln", x()));
+        assert_antiderivative(&f);
+    }
+
+    #[test]
+    fn test_reciprocal_sqrt() {
+        let f = Expression::binary(
+            int(1),
+            BinaryOp::Divide,
+            call(
+                "sqrt",
+                Expression::binary(int(1), BinaryOp::Subtract, Expression::power(x(), int(2))),
+            ),
+        );
+        assert_eq!(integral(&f), "asin(x)");
+    }
+
+    #[test]
+    fn test_unsupported_returns_none() {
+        let f = call("exp", Expression::power(x(), int(2)));
+        assert!(antiderivative(&f, "x").is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! Lexer for tokenizing calculator input.
 
 use crate::error::CalculatorError;
+use crate::types::{Color, Radix};
 
 /// Checks if a character is a Unicode combining mark (General Category M).
 ///
@@ -10,6 +11,15 @@ use crate::error::CalculatorError;
 /// - Me (Mark, Enclosing) — rare, e.g., combining enclosing circle ⃝
 ///
 /// These characters are integral parts of words in scripts like Devanagari (Hindi),
+/// Returns true for the country prefixes written before `$`, such as `A`
+/// in `A$` (Australian dollar) or `R` in `R$` (Brazilian real).
+fn is_dollar_prefix(text: &str) -> bool {
+    matches!(
+        text,
+        "US" | "A" | "AU" | "C" | "CA" | "NZ" | "HK" | "S" | "SG" | "R" | "MX" | "NT"
+    )
+}
+
 /// Arabic, and Thai, but are not classified as `is_alphabetic()` in Rust.
 fn is_unicode_mark(ch: char) -> bool {
     // Unicode General Category "M" (Mark) covers Mn, Mc, and Me.
@@ -134,6 +144,16 @@ pub enum TokenKind {
     LeftParen,
     /// Right parenthesis.
     RightParen,
+    /// A reference to an earlier result by its history number (e.g., `#3`).
+    HistoryRef(usize),
+    /// A hex color as its `0xRRGGBB` number (e.g., `#ff8800` or `#f80`).
+    HexColor(u32),
+    /// Quoted text without its quotes (e.g., `"hello"`).
+    Text(String),
+    /// Left square bracket (list literals and indexing).
+    LeftBracket,
+    /// Right square bracket.
+    RightBracket,
     /// A colon (for time).
     Colon,
     /// A comma.
@@ -300,6 +320,44 @@ impl Lexer {
                 self.advance();
                 Token::new(TokenKind::RightParen, start, self.pos, ")".to_string())
             }
+            '#' if self.hex_color_ahead().is_some() => {
+                let color = self.hex_color_ahead().unwrap_or_default();
+                self.advance();
+                while !self.is_at_end() && self.current().is_ascii_hexdigit() {
+                    self.advance();
+                }
+                let text: String = self.input[start..self.pos].iter().collect();
+                Token::new(TokenKind::HexColor(color), start, self.pos, text)
+            }
+            '#' if self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                self.advance();
+                let digits_start = self.pos;
+                while !self.is_at_end() && self.current().is_ascii_digit() {
+                    self.advance();
+                }
+                let digits: String = self.input[digits_start..self.pos].iter().collect();
+                let index = digits.parse().map_err(|_| {
+                    CalculatorError::parse_at(
+                        format!("History reference #{digits} is too large"),
+                        start,
+                        self.pos,
+                    )
+                })?;
+                Token::new(
+                    TokenKind::HistoryRef(index),
+                    start,
+                    self.pos,
+                    format!("#{digits}"),
+                )
+            }
+            '[' => {
+                self.advance();
+                Token::new(TokenKind::LeftBracket, start, self.pos, "[".to_string())
+            }
+            ']' => {
+                self.advance();
+                Token::new(TokenKind::RightBracket, start, self.pos, "]".to_string())
+            }
             ':' => {
                 self.advance();
                 Token::new(TokenKind::Colon, start, self.pos, ":".to_string())
@@ -356,11 +414,50 @@ impl Lexer {
                 }
             }
             _ if ch == '.' => self.scan_number()?,
+            // Seconds of arc after a number (e.g., 15"), else text
+            '"' if self.follows_digit(start) => {
+                self.advance();
+                Token::new(
+                    TokenKind::Identifier("\"".to_string()),
+                    start,
+                    self.pos,
+                    "\"".to_string(),
+                )
+            }
+            '"' | '“' => self.scan_text()?,
             _ if ch.is_alphabetic() => self.scan_identifier(),
+            // The degree sign, as an angle unit (e.g., 30°)
+            '°' => {
+                self.advance();
+                Token::new(
+                    TokenKind::Identifier("°".to_string()),
+                    start,
+                    self.pos,
+                    "°".to_string(),
+                )
+            }
+            // Minutes of arc (e.g., 30' or 30′), or seconds written with
+            // two primes (15'' or 15″)
+            '\'' | '′' | '″' => {
+                self.advance();
+                let seconds =
+                    ch == '″' || (ch == '\'' && !self.is_at_end() && self.current() == '\'');
+                if seconds && ch == '\'' {
+                    self.advance();
+                }
+                let unit = if seconds { "\"" } else { "'" };
+                Token::new(
+                    TokenKind::Identifier(unit.to_string()),
+                    start,
+                    self.pos,
+                    self.input[start..self.pos].iter().collect(),
+                )
+            }
             // Currency symbols used as prefix notation (e.g., $10, €5, £3)
             // These are recognized as single-character identifiers and mapped to ISO codes
             // by CurrencyDatabase::parse_currency().
-            '$' | '€' | '£' | '¥' | '₽' | '₹' | '₩' | '₿' | '₫' | '₸' => {
+            '$' | '€' | '£' | '¥' | '₽' | '₹' | '₩' | '₿' | '₫' | '₸' | '₺' | '₴' | '₪' | '₱'
+            | '₦' => {
                 self.advance();
                 let symbol = ch.to_string();
                 Token::new(
@@ -371,24 +468,42 @@ impl Lexer {
                 )
             }
             _ => {
-                return Err(CalculatorError::parse(format!(
-                    "Unexpected character '{ch}' at position {start}"
-                )));
+                return Err(CalculatorError::parse_at(
+                    format!("Unexpected character '{ch}' at position {start}"),
+                    start,
+                    start + 1,
+                ));
             }
         };
 
         Ok(token)
     }
 
+    /// Checks if the last character before `pos`, past any spaces, is a
+    /// digit.
+    fn follows_digit(&self, pos: usize) -> bool {
+        self.input[..pos]
+            .iter()
+            .rev()
+            .find(|ch| !ch.is_whitespace())
+            .is_some_and(char::is_ascii_digit)
+    }
+
     fn scan_number(&mut self) -> Result<Token, CalculatorError> {
         let start = self.pos;
         let mut text = String::new();
         let mut has_dot = false;
 
         if self.current() == '.' && !self.peek().is_some_and(|c| c.is_ascii_digit()) {
-            return Err(CalculatorError::parse(format!(
-                "Unexpected character '.' at position {start}"
-            )));
+            return Err(CalculatorError::parse_at(
+                format!("Unexpected character '.' at position {start}"),
+                start,
+                start + 1,
+            ));
+        }
+
+        if let Some(token) = self.scan_radix_literal() {
+            return Ok(token);
         }
 
         while !self.is_at_end() {
@@ -410,6 +525,12 @@ impl Lexer {
             }
         }
 
+        // An exponent, as numbers are written in scientific notation: "2.5e-3"
+        if let Some(exponent) = self.exponent_at(self.pos) {
+            text.extend(exponent.iter());
+            self.pos += exponent.len();
+        }
+
         Ok(Token::new(
             TokenKind::Number(text.clone()),
             start,
@@ -418,6 +539,64 @@ impl Lexer {
         ))
     }
 
+    /// Returns the exponent of a number in scientific notation at `pos`,
+    /// such as the `e-3` of `2.5e-3`: an `e` or `E`, an optional sign and
+    /// digits. A letter straight after them makes them part of a word.
+    fn exponent_at(&self, pos: usize) -> Option<&[char]> {
+        if !matches!(self.input.get(pos), Some('e' | 'E')) {
+            return None;
+        }
+        let mut end = pos + 1;
+        if matches!(self.input.get(end), Some('+' | '-')) {
+            end += 1;
+        }
+        let digits = end;
+        while self.input.get(end).is_some_and(char::is_ascii_digit) {
+            end += 1;
+        }
+        if end == digits || self.input.get(end).is_some_and(|c| c.is_alphabetic()) {
+            return None;
+        }
+        Some(&self.input[pos..end])
+    }
+
+    /// Scans a prefixed integer literal such as `0xFF`, `0b1010` or `0o17`.
+    ///
+    /// The prefix is only recognized when a valid digit follows it, so inputs
+    /// like `0b` (zero bits) or `0 x` keep their previous meaning.
+    fn scan_radix_literal(&mut self) -> Option<Token> {
+        if self.current() != '0' {
+            return None;
+        }
+        let radix = Radix::from_prefix_char(*self.input.get(self.pos + 1)?)?;
+        if !self
+            .input
+            .get(self.pos + 2)
+            .is_some_and(|&c| radix.is_digit(c))
+        {
+            return None;
+        }
+
+        let start = self.pos;
+        let mut end = start + 2;
+        while end < self.input.len() && radix.is_digit(self.input[end]) {
+            end += 1;
+        }
+        // Reject literals glued to letters, e.g. `0b12` or `0xFFg`.
+        if end < self.input.len() && self.input[end].is_alphanumeric() {
+            return None;
+        }
+
+        let text: String = self.input[start..end].iter().collect();
+        self.pos = end;
+        Some(Token::new(
+            TokenKind::Number(text.clone()),
+            start,
+            end,
+            text,
+        ))
+    }
+
     /// Attempts to scan a full numeric date literal starting at the current
     /// position, returning the matched text and the end index on success.
     ///
@@ -513,6 +692,12 @@ impl Lexer {
             }
         }
 
+        // Country-prefixed dollar signs (e.g., A$10, C$5, R$20, HK$100)
+        if !self.is_at_end() && self.current() == '$' && is_dollar_prefix(&text) {
+            text.push('$');
+            self.advance();
+        }
+
         // Check for keywords (including multilingual equivalents)
         let kind = match text.to_lowercase().as_str() {
             "at" => TokenKind::At,
@@ -547,6 +732,51 @@ impl Lexer {
         Token::new(kind, start, self.pos, text)
     }
 
+    /// Scans text in straight or curly double quotes, where `\"` and `\\`
+    /// stand for a quote and a backslash.
+    fn scan_text(&mut self) -> Result<Token, CalculatorError> {
+        let start = self.pos;
+        let closing = if self.current() == '“' { '”' } else { '"' };
+        self.advance();
+        let mut text = String::new();
+        loop {
+            if self.is_at_end() {
+                return Err(CalculatorError::parse_at(
+                    format!("Unterminated text starting at position {start}"),
+                    start,
+                    self.pos,
+                ));
+            }
+            let ch = self.current();
+            self.advance();
+            match ch {
+                '\\' if matches!(self.input.get(self.pos), Some('"' | '\\')) => {
+                    text.push(self.current());
+                    self.advance();
+                }
+                _ if ch == closing => break,
+                _ => text.push(ch),
+            }
+        }
+        let raw: String = self.input[start..self.pos].iter().collect();
+        Ok(Token::new(TokenKind::Text(text), start, self.pos, raw))
+    }
+
+    /// Reads the hex color starting at the current `#`: six hex digits, or
+    /// three with at least one letter so that `#123` stays a history
+    /// reference.
+    fn hex_color_ahead(&self) -> Option<u32> {
+        let digits: String = self.input[self.pos + 1..]
+            .iter()
+            .take_while(|c| c.is_alphanumeric() || **c == '_')
+            .collect();
+        let short_form = digits.len() == 3 && digits.chars().any(|c| c.is_ascii_alphabetic());
+        if digits.len() != 6 && !short_form {
+            return None;
+        }
+        Color::parse_hex(&digits).map(Color::to_number)
+    }
+
     fn skip_whitespace(&mut self) {
         while !self.is_at_end() && self.current().is_whitespace() {
             self.advance();
@@ -589,6 +819,19 @@ mod tests {
         assert!(matches!(tokens[0].kind, TokenKind::Number(ref s) if s == "3.14"));
     }
 
+    #[test]
+    fn test_tokenize_radix_literals() {
+        let mut lexer = Lexer::new("0xFF + 0b1010");
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].kind, TokenKind::Number(ref s) if s == "0xFF"));
+        assert!(matches!(tokens[2].kind, TokenKind::Number(ref s) if s == "0b1010"));
+
+        // Without digits after the prefix, `0b` stays a number followed by a unit.
+        let mut lexer = Lexer::new("0b");
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].kind, TokenKind::Number(ref s) if s == "0"));
+    }
+
     #[test]
     fn test_tokenize_expression() {
         let mut lexer = Lexer::new("2 + 3");
@@ -605,6 +848,23 @@ mod tests {
         assert!(matches!(tokens[4].kind, TokenKind::RightParen));
     }
 
+    #[test]
+    fn test_tokenize_brackets() {
+        let mut lexer = Lexer::new("[1, 2][0]");
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].kind, TokenKind::LeftBracket));
+        assert!(matches!(tokens[4].kind, TokenKind::RightBracket));
+        assert!(matches!(tokens[5].kind, TokenKind::LeftBracket));
+    }
+
+    #[test]
+    fn test_tokenize_history_ref() {
+        let mut lexer = Lexer::new("#3 + #12");
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].kind, TokenKind::HistoryRef(3)));
+        assert!(matches!(tokens[2].kind, TokenKind::HistoryRef(12)));
+    }
+
     #[test]
     fn test_tokenize_identifier() {
         let mut lexer = Lexer::new("USD EUR");
@@ -58,7 +58,7 @@ impl LinearForm {
                 if *unit != Unit::None {
                     return Err(Self::unsupported_equation());
                 }
-                Ok(Self::constant(Rational::from_decimal(*value)))
+                Ok(Self::constant(value.clone()))
             }
             Expression::Variable(name) => Ok(Self::variable(name.clone())),
             Expression::Binary { left, op, right } => {
@@ -74,17 +74,23 @@ impl LinearForm {
             }
             Expression::Negate(inner) => Ok(Self::from_expression(inner)?.negate()),
             Expression::Group(inner) => Self::from_expression(inner),
-            Expression::DateTime(_)
+            Expression::Text(_)
+            | Expression::DateTime(_)
             | Expression::Now
             | Expression::Today
+            | Expression::HistoryRef(_)
+            | Expression::HistoryRange(..)
             | Expression::Until(_)
             | Expression::AtTime { .. }
             | Expression::FunctionCall { .. }
             | Expression::Power { .. }
             | Expression::IndefiniteIntegral { .. }
             | Expression::UnitConversion { .. }
+            | Expression::WithPrecision { .. }
+            | Expression::RateLookup { .. }
             | Expression::Equality { .. }
-            | Expression::Comparison { .. } => Err(Self::unsupported_equation()),
+            | Expression::Comparison { .. }
+            | Expression::List(_) => Err(Self::unsupported_equation()),
         }
     }
 
@@ -0,0 +1,184 @@
+//! Functions that take whole values rather than plain numbers: statistics
+//! over argument lists, list indexing (`index([10, 20, 30], 1)`),
+//! calendar functions over dates, financial functions over amounts,
+//! proportions, SI prefixes (`eng(4700 ohm)`), integers in any base,
+//! distances between coordinates, health and physics formulas, colors,
+//! lengths, encodings and digests of text, matrix functions, exact
+//! factorials and powers, and rounding.
+
+use super::bases::{evaluate_base_function, is_base_function};
+use super::calendar_functions::{evaluate_calendar_function, is_calendar_function};
+use super::color::{evaluate_color_function, is_color_function};
+use super::encoding::{evaluate_encoding_function, is_encoding_function};
+use super::engineering::{evaluate_engineering_function, is_engineering_function};
+use super::finance::{evaluate_finance_function, is_finance_function};
+use super::geo::{evaluate_geo_function, is_geo_function};
+#[cfg(feature = "text-tools")]
+use super::hash::{evaluate_hash_function, is_hash_function};
+use super::health::{evaluate_health_function, is_health_function};
+use super::integer_functions::{evaluate_integer_function, is_integer_function};
+use super::matrix_functions::{evaluate_matrix_function, is_matrix_function};
+use super::physics::{evaluate_physics_function, is_physics_function};
+use super::proportion::{evaluate_proportion_function, is_proportion_function};
+use super::rounding::{evaluate_rounding_function, is_rounding_function};
+use super::statistics::{evaluate_statistics, is_statistics_function};
+use super::text::{evaluate_text_function, is_text_function};
+use crate::error::CalculatorError;
+use crate::types::Value;
+
+/// The digests of text that the `text-tools` feature adds, named here so
+/// that a build without it says what is missing.
+#[cfg(not(feature = "text-tools"))]
+const HASH_FUNCTIONS: &[&str] = &["md5", "sha256", "crc32"];
+
+/// Returns true if `name` is evaluated with [`evaluate_value_function`].
+#[must_use]
+pub fn is_value_function(name: &str) -> bool {
+    #[cfg(feature = "text-tools")]
+    if is_hash_function(name) {
+        return true;
+    }
+    #[cfg(not(feature = "text-tools"))]
+    if HASH_FUNCTIONS.iter().any(|f| name.eq_ignore_ascii_case(f)) {
+        return true;
+    }
+    is_statistics_function(name)
+        || is_calendar_function(name)
+        || is_finance_function(name)
+        || is_proportion_function(name)
+        || is_engineering_function(name)
+        || is_base_function(name)
+        || is_geo_function(name)
+        || is_health_function(name)
+        || is_physics_function(name)
+        || is_color_function(name)
+        || is_text_function(name)
+        || is_encoding_function(name)
+        || is_matrix_function(name)
+        || is_integer_function(name)
+        || is_rounding_function(name)
+        || name.eq_ignore_ascii_case("index")
+}
+
+/// Evaluates a function whose arguments may be lists or carry units.
+pub fn evaluate_value_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    if name.eq_ignore_ascii_case("index") {
+        let [list, index] = args else {
+            return Err(CalculatorError::invalid_args(
+                "index",
+                "expected 2 arguments: index(list, position)",
+            ));
+        };
+        return list.index(index);
+    }
+    if is_calendar_function(name) {
+        return evaluate_calendar_function(name, args);
+    }
+    if is_finance_function(name) {
+        return evaluate_finance_function(name, args);
+    }
+    if is_engineering_function(name) {
+        return evaluate_engineering_function(args);
+    }
+    if is_base_function(name) {
+        return evaluate_base_function(name, args);
+    }
+    if is_proportion_function(name) {
+        return evaluate_proportion_function(name, args);
+    }
+    if is_geo_function(name) {
+        return evaluate_geo_function(args);
+    }
+    if is_health_function(name) {
+        return evaluate_health_function(name, args);
+    }
+    if is_physics_function(name) {
+        return evaluate_physics_function(args);
+    }
+    if is_color_function(name) {
+        return evaluate_color_function(name, args);
+    }
+    if is_text_function(name) {
+        return evaluate_text_function(name, args);
+    }
+    if is_encoding_function(name) {
+        return evaluate_encoding_function(name, args);
+    }
+    #[cfg(feature = "text-tools")]
+    if is_hash_function(name) {
+        return evaluate_hash_function(name, args);
+    }
+    #[cfg(not(feature = "text-tools"))]
+    if HASH_FUNCTIONS.iter().any(|f| name.eq_ignore_ascii_case(f)) {
+        return Err(CalculatorError::InvalidOperation(format!(
+            "{} requires the text-tools feature",
+            name.to_lowercase()
+        )));
+    }
+    if is_matrix_function(name) {
+        return evaluate_matrix_function(name, args);
+    }
+    if is_integer_function(name) {
+        return evaluate_integer_function(name, args);
+    }
+    if is_rounding_function(name) {
+        return evaluate_rounding_function(name, args);
+    }
+    evaluate_statistics(name, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bases::BASE_FUNCTIONS;
+    use super::super::calendar_functions::CALENDAR_FUNCTIONS;
+    use super::super::countdown::COUNTDOWN_FUNCTIONS;
+    use super::super::finance::FINANCE_FUNCTIONS;
+    use super::super::integer_functions::FACTORIAL_FUNCTIONS;
+    use super::super::math_functions::MATH_FUNCTIONS;
+    use super::super::matrix_functions::MATRIX_FUNCTIONS;
+    use super::super::proportion::PROPORTION_FUNCTIONS;
+    use super::super::rounding::ROUNDING_FUNCTIONS;
+    use super::super::statistics::STATISTICS_FUNCTIONS;
+    use crate::function_registry::lookup;
+    use crate::types::Value;
+
+    /// The functions the evaluator calls on their unevaluated arguments,
+    /// before dispatching the others by name.
+    const EXPRESSION_FUNCTIONS: &[&str] = &[
+        "integrate",
+        "nderiv",
+        "root",
+        "simplify",
+        "equivalent",
+        "factors",
+        "rate_history",
+        "better_deal",
+        "plot",
+    ];
+
+    /// The functions of one name each.
+    const SINGLE_FUNCTIONS: &[&str] = &["eng", "haversine", "physics", "index", "pow"];
+
+    #[test]
+    fn test_every_dispatched_function_is_registered() {
+        let dispatched = [
+            EXPRESSION_FUNCTIONS,
+            SINGLE_FUNCTIONS,
+            MATH_FUNCTIONS,
+            STATISTICS_FUNCTIONS,
+            CALENDAR_FUNCTIONS,
+            COUNTDOWN_FUNCTIONS,
+            FINANCE_FUNCTIONS,
+            PROPORTION_FUNCTIONS,
+            MATRIX_FUNCTIONS,
+            ROUNDING_FUNCTIONS,
+            FACTORIAL_FUNCTIONS,
+            BASE_FUNCTIONS,
+        ];
+        for name in dispatched.into_iter().flatten() {
+            if Value::constant(name).is_none() {
+                assert!(lookup(name).is_some(), "{name} is not registered");
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Dates and currency signs that locales read differently.
+//!
+//! `01/02/2026` is January 2 in the US and 1 February in most other
+//! countries, and `¥` is the yuan in China and the yen elsewhere. Input read
+//! in a locale is rewritten to the form the grammar reads unambiguously:
+//! dates to `01.02.2026`, which is always day-first, before lexing, and the
+//! `¥` token to `元` after. Rewrites keep every character in place, so error
+//! positions still point into what the user typed. Quoted text is left alone.
+
+use crate::grammar::{Token, TokenKind};
+
+/// How a locale writes slash dates and the `¥` sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputLocale {
+    day_first_dates: bool,
+    yuan_sign: bool,
+}
+
+impl InputLocale {
+    /// Returns the conventions of a language tag such as `ru`, `en-GB` or
+    /// `zh-CN`. English is month-first in the US and day-first in other
+    /// regions; Chinese, Japanese and Korean write the year first and keep
+    /// the month-first reading of slash dates.
+    #[must_use]
+    pub fn for_language(tag: &str) -> Self {
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().unwrap_or_default().trim().to_lowercase();
+        let region = parts.next().map(str::to_uppercase);
+        let day_first_dates = match language.as_str() {
+            "en" => region.is_some_and(|region| region != "US"),
+            "zh" | "ja" | "ko" => false,
+            _ => true,
+        };
+        Self {
+            day_first_dates,
+            yuan_sign: language == "zh",
+        }
+    }
+
+    /// Returns true if `01/02/2026` is the 1st of February.
+    #[must_use]
+    pub const fn day_first_dates(self) -> bool {
+        self.day_first_dates
+    }
+
+    /// Returns true if `¥` is the Chinese yuan rather than the Japanese yen.
+    #[must_use]
+    pub const fn yuan_sign(self) -> bool {
+        self.yuan_sign
+    }
+}
+
+/// Rewrites the slash dates of `input` written in `locale` to the grammar's
+/// reading, or returns `None` if there are none to rewrite.
+pub(super) fn rewrite_with_locale(input: &str, locale: InputLocale) -> Option<String> {
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut changed = false;
+    let mut in_text = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '"' | '“' | '”' => in_text = !in_text,
+            c if !in_text && locale.day_first_dates && c.is_ascii_digit() => {
+                let end = digits_end(&chars, i);
+                if let Some(date_end) = day_first_slash_date(&chars, i) {
+                    for c in &mut chars[i..date_end] {
+                        if *c == '/' {
+                            *c = '.';
+                        }
+                    }
+                    changed = true;
+                    i = date_end;
+                } else {
+                    i = end;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    changed.then(|| chars.into_iter().collect())
+}
+
+/// Reads the `¥` tokens of input written in `locale` as the yuan sign `元`
+/// when the locale writes the yuan that way.
+pub(super) fn rewrite_tokens(tokens: &mut [Token], locale: InputLocale) {
+    if !locale.yuan_sign {
+        return;
+    }
+    for token in tokens {
+        if matches!(&token.kind, TokenKind::Identifier(id) if id == "¥") {
+            token.kind = TokenKind::Identifier("元".to_string());
+        }
+    }
+}
+
+/// Returns the end of a `d/m/yyyy` date starting at `start`, which must not
+/// follow a digit, a separator or a letter.
+fn day_first_slash_date(chars: &[char], start: usize) -> Option<usize> {
+    if start > 0 && (chars[start - 1].is_alphanumeric() || matches!(chars[start - 1], '/' | '.')) {
+        return None;
+    }
+    let day_end = digits_end(chars, start);
+    if chars.get(day_end) != Some(&'/') {
+        return None;
+    }
+    let month_end = digits_end(chars, day_end + 1);
+    if chars.get(month_end) != Some(&'/') {
+        return None;
+    }
+    let year_end = digits_end(chars, month_end + 1);
+    let lengths = (
+        day_end - start,
+        month_end - day_end - 1,
+        year_end - month_end - 1,
+    );
+    if !matches!(lengths, (1 | 2, 1 | 2, 4)) {
+        return None;
+    }
+    if chars
+        .get(year_end)
+        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '/' | '.'))
+    {
+        return None;
+    }
+    let number = |from: usize, to: usize| {
+        chars[from..to]
+            .iter()
+            .fold(0, |n, c| n * 10 + c.to_digit(10).unwrap_or(0))
+    };
+    let day = number(start, day_end);
+    let month = number(day_end + 1, month_end);
+    ((1..=31).contains(&day) && (1..=12).contains(&month)).then_some(year_end)
+}
+
+/// Returns the end of the run of ASCII digits starting at `start`.
+fn digits_end(chars: &[char], start: usize) -> usize {
+    chars[start.min(chars.len())..]
+        .iter()
+        .position(|c| !c.is_ascii_digit())
+        .map_or(chars.len(), |len| start + len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_slash_dates_day_first() {
+        let ru = InputLocale::for_language("ru");
+        assert_eq!(
+            rewrite_with_locale("01/02/2026 + 1 day", ru).as_deref(),
+            Some("01.02.2026 + 1 day")
+        );
+        assert_eq!(rewrite_with_locale("12/31/2026", ru), None);
+        assert_eq!(rewrite_with_locale("1/2", ru), None);
+        assert_eq!(
+            rewrite_with_locale("01/02/2026", InputLocale::for_language("en")),
+            None
+        );
+        assert!(rewrite_with_locale("1/2/2026", InputLocale::for_language("en-GB")).is_some());
+    }
+
+    #[test]
+    fn test_leaves_quoted_text_alone() {
+        let ru = InputLocale::for_language("ru");
+        assert_eq!(rewrite_with_locale("length of \"01/02/2026\"", ru), None);
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! The grammar itself uses `.` as the decimal separator. These helpers build
 //! normalized expression variants for user input that uses decimal/grouping
-//! conventions from supported UI locales, such as `82,6172` or `1.234,56`.
+//! conventions from supported UI locales, such as `82,6172`, `1.234,56` or
+//! `1 234,56`.
+//!
+//! A comma directly between digits is part of a number; a comma followed by
+//! a space separates function arguments, so `max(1,5; 2)` is not needed:
+//! `max(1,5, 2)` reads as `max(1.5, 2)`.
 
-#[derive(Debug, Clone, Copy)]
-struct NumberLocale {
+/// Decimal and grouping separators of a number convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberLocale {
     decimal_separator: char,
     grouping_separator: Option<char>,
 }
@@ -26,8 +32,39 @@ const LOCALES: &[NumberLocale] = &[
         decimal_separator: '.',
         grouping_separator: Some(','),
     },
+    // French/Russian style: 1 234,56 -> 1234.56.
+    NumberLocale {
+        decimal_separator: ',',
+        grouping_separator: Some(' '),
+    },
+    // SI style: 1 234.56 -> 1234.56.
+    NumberLocale {
+        decimal_separator: '.',
+        grouping_separator: Some(' '),
+    },
 ];
 
+/// Returns the number convention of a language, such as `ru` or `de-AT`.
+pub(super) fn locale_for_language(language: &str) -> Option<NumberLocale> {
+    let language = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let (decimal_separator, grouping_separator) = match language.as_str() {
+        "en" | "zh" | "hi" | "ar" | "ja" | "ko" | "he" | "th" => ('.', ','),
+        "ru" | "uk" | "be" | "kk" | "fr" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no"
+        | "bg" | "hu" | "lt" | "lv" | "et" => (',', ' '),
+        "de" | "es" | "it" | "pt" | "nl" | "tr" | "id" | "da" | "el" | "ro" | "hr" | "sr"
+        | "sl" | "vi" => (',', '.'),
+        _ => return None,
+    };
+    Some(NumberLocale {
+        decimal_separator,
+        grouping_separator: Some(grouping_separator),
+    })
+}
+
 /// Returns normalized variants of `input` using supported locale number
 /// conventions. Variants are ordered by locale preference and de-duplicated.
 pub(super) fn variants(input: &str) -> Vec<String> {
@@ -42,7 +79,9 @@ pub(super) fn variants(input: &str) -> Vec<String> {
     variants
 }
 
-fn rewrite_with_locale(input: &str, locale: NumberLocale) -> Option<String> {
+/// Rewrites the numbers of `input` written in `locale` to the grammar's
+/// format, or returns `None` if there are none to rewrite.
+pub(super) fn rewrite_with_locale(input: &str, locale: NumberLocale) -> Option<String> {
     let mut output = String::with_capacity(input.len());
     let mut chars = input.char_indices().peekable();
     let mut changed = false;
@@ -55,7 +94,10 @@ fn rewrite_with_locale(input: &str, locale: NumberLocale) -> Option<String> {
 
         let mut end = start + ch.len_utf8();
         while let Some(&(idx, next)) = chars.peek() {
-            if next.is_ascii_digit() || next == '.' || next == ',' {
+            let grouping_space = locale.grouping_separator == Some(' ')
+                && is_space_separator(next)
+                && starts_digit_group(&input[idx + next.len_utf8()..]);
+            if next.is_ascii_digit() || next == '.' || next == ',' || grouping_space {
                 chars.next();
                 end = idx + next.len_utf8();
             } else {
@@ -63,15 +105,20 @@ fn rewrite_with_locale(input: &str, locale: NumberLocale) -> Option<String> {
             }
         }
 
-        let candidate = &input[start..end];
-        if let Some(normalized) = normalize_number(candidate, locale) {
-            if normalized != candidate {
+        // A separator ending the run, as in "max(1,5, 2)", is not part of it
+        let run = &input[start..end];
+        let number = run.trim_end_matches(['.', ',']);
+        // "1\u{a0}234" groups with a no-break space like "1 234"
+        let candidate = number.replace(is_space_separator, " ");
+        if let Some(normalized) = normalize_number(&candidate, locale) {
+            if normalized != number {
                 changed = true;
             }
             output.push_str(&normalized);
         } else {
-            output.push_str(candidate);
+            output.push_str(number);
         }
+        output.push_str(&run[number.len()..]);
     }
 
     changed.then_some(output)
@@ -155,6 +202,19 @@ fn normalize_integer_part(integer: &str, locale: NumberLocale) -> Option<String>
     })
 }
 
+/// Returns true for the spaces used to group digits: a regular, no-break or
+/// narrow no-break space.
+fn is_space_separator(ch: char) -> bool {
+    matches!(ch, ' ' | '\u{a0}' | '\u{202f}')
+}
+
+/// Returns true if `rest` starts with exactly three digits.
+fn starts_digit_group(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    chars.by_ref().take(3).filter(char::is_ascii_digit).count() == 3
+        && !chars.next().is_some_and(|ch| ch.is_ascii_digit())
+}
+
 fn push_unique(values: &mut Vec<String>, value: String) {
     if !values.contains(&value) {
         values.push(value);
@@ -181,6 +241,13 @@ mod tests {
         assert_eq!(variants("1,234.56"), vec!["1234.56"]);
     }
 
+    #[test]
+    fn normalizes_space_grouping() {
+        assert!(variants("1 234,56 + 2,5").contains(&"1234.56 + 2.5".to_string()));
+        assert_eq!(variants("1\u{a0}234\u{a0}567 / 7"), vec!["1234567 / 7"]);
+        assert!(variants("10 20").is_empty());
+    }
+
     #[test]
     fn ignores_argument_separator_with_spaces() {
         assert!(variants("integrate(x^2, x, 0, 3)").is_empty());
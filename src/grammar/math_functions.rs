@@ -2,13 +2,43 @@
 //!
 //! This module provides implementations for various mathematical functions
 //! including trigonometry, logarithms, and numerical integration.
+//!
+//! Only transcendental functions, such as `sin`, `ln` or a power with a
+//! fractional exponent, go through `f64`; rounding, `abs`, `min`, `max`,
+//! integer powers and factorials are exact on [`Decimal`].
 
+use super::quadrature::integrate_adaptive;
+use super::special_functions as special;
+use super::statistics::evaluate_statistics_decimal;
 use crate::error::CalculatorError;
-use crate::types::Decimal;
+use crate::types::{Decimal, Rational};
+use std::convert::Infallible;
+
+/// Significant digits a root is worked out to before it is rounded to a
+/// Decimal's 28.
+const ROOT_DIGITS: u32 = 30;
 
-/// The number of subdivisions for numerical integration (Simpson's rule).
+/// The maximum number of subintervals for numerical integration.
 const INTEGRATION_SUBDIVISIONS: usize = 1000;
 
+/// The error numerical integration aims for.
+const INTEGRATION_TOLERANCE: f64 = 1e-10;
+
+/// The number of step sizes numerical differentiation extrapolates from.
+const DERIVATIVE_STEPS: usize = 10;
+
+/// The factor the step shrinks by between central differences.
+const DERIVATIVE_STEP_RATIO: f64 = 1.4;
+
+/// The largest error estimate, relative to the derivative, that numerical
+/// differentiation accepts; more means `f` is not differentiable there.
+const DERIVATIVE_MAX_RELATIVE_ERROR: f64 = 1e-6;
+
+/// The largest `|f(root)|` root finding accepts, relative to the larger of
+/// `|f|` at the bounds, unless a looser tolerance allows more; more means
+/// the sign change is a pole or a jump.
+const ROOT_MAX_RELATIVE_RESIDUAL: f64 = 1e-6;
+
 /// Evaluates a mathematical function with the given arguments.
 ///
 /// # Supported Functions
@@ -37,8 +67,20 @@ const INTEGRATION_SUBDIVISIONS: usize = 1000;
 /// - `floor(x)` - Floor
 /// - `ceil(x)` - Ceiling
 /// - `round(x)` - Round to nearest
+/// - `round(x, places)` - Round to `places` decimal places (negative places round to tens, hundreds, ...)
 /// - `factorial(n)` - Factorial (n must be non-negative integer)
 ///
+/// ## Special functions
+/// - `gamma(x)` - Γ(x), exactly (x - 1)! for whole x
+/// - `beta(a, b)` - B(a, b) = Γ(a) Γ(b) / Γ(a + b)
+/// - `erf(x)`, `erfc(x)` - Error function and its complement
+/// - `Si(x)`, `Ci(x)`, `Ei(x)` - Sine, cosine and exponential integrals
+///
+/// ## Statistics (any number of arguments)
+/// - `sum(...)`, `count(...)` - Sum and number of arguments
+/// - `mean(...)`, `median(...)`, `mode(...)` - Averages
+/// - `variance(...)`, `stddev(...)` - Sample variance and standard deviation
+///
 /// ## Constants
 /// - `pi()` - π ≈ 3.14159...
 /// - `e()` - Euler's number ≈ 2.71828...
@@ -50,23 +92,23 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
         // Constants
         "pi" => {
             check_arg_count(&name_lower, args, 0)?;
-            Ok(Decimal::from_f64(std::f64::consts::PI))
+            Decimal::from_f64(std::f64::consts::PI)
         }
         "e" => {
             check_arg_count(&name_lower, args, 0)?;
-            Ok(Decimal::from_f64(std::f64::consts::E))
+            Decimal::from_f64(std::f64::consts::E)
         }
 
         // Trigonometric functions
         "sin" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.sin()))
+            Decimal::from_f64(x.sin())
         }
         "cos" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.cos()))
+            Decimal::from_f64(x.cos())
         }
         "tan" => {
             check_arg_count(&name_lower, args, 1)?;
@@ -75,7 +117,7 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if result.is_infinite() || result.is_nan() {
                 return Err(CalculatorError::domain("tan is undefined at this value"));
             }
-            Ok(Decimal::from_f64(result))
+            Decimal::from_f64(result)
         }
         "asin" => {
             check_arg_count(&name_lower, args, 1)?;
@@ -83,7 +125,7 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if !(-1.0..=1.0).contains(&x) {
                 return Err(CalculatorError::domain("asin argument must be in [-1, 1]"));
             }
-            Ok(Decimal::from_f64(x.asin()))
+            Decimal::from_f64(x.asin())
         }
         "acos" => {
             check_arg_count(&name_lower, args, 1)?;
@@ -91,46 +133,43 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if !(-1.0..=1.0).contains(&x) {
                 return Err(CalculatorError::domain("acos argument must be in [-1, 1]"));
             }
-            Ok(Decimal::from_f64(x.acos()))
+            Decimal::from_f64(x.acos())
         }
         "atan" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.atan()))
+            Decimal::from_f64(x.atan())
         }
         "atan2" => {
             check_arg_count(&name_lower, args, 2)?;
             let y = args[0].to_f64();
             let x = args[1].to_f64();
-            Ok(Decimal::from_f64(y.atan2(x)))
+            Decimal::from_f64(y.atan2(x))
         }
 
         // Hyperbolic functions
         "sinh" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.sinh()))
+            Decimal::from_f64(x.sinh())
         }
         "cosh" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.cosh()))
+            Decimal::from_f64(x.cosh())
         }
         "tanh" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.tanh()))
+            Decimal::from_f64(x.tanh())
         }
 
         // Exponential and logarithmic
         "exp" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            let result = x.exp();
-            if result.is_infinite() {
-                return Err(CalculatorError::Overflow);
-            }
-            Ok(Decimal::from_f64(result))
+            // Beyond about e^66 the result no longer fits a Decimal
+            Decimal::from_f64(x.exp())
         }
         "ln" => {
             check_arg_count(&name_lower, args, 1)?;
@@ -138,7 +177,7 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if x <= 0.0 {
                 return Err(CalculatorError::domain("ln argument must be positive"));
             }
-            Ok(Decimal::from_f64(x.ln()))
+            Decimal::from_f64(x.ln())
         }
         "log" => {
             // log(x) is log base 10, log(x, base) is log base `base`
@@ -160,9 +199,9 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
                         "log base must be positive and not 1",
                     ));
                 }
-                Ok(Decimal::from_f64(x.log(base)))
+                Decimal::from_f64(x.log(base))
             } else {
-                Ok(Decimal::from_f64(x.log10()))
+                Decimal::from_f64(x.log10())
             }
         }
         "log2" => {
@@ -171,7 +210,7 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if x <= 0.0 {
                 return Err(CalculatorError::domain("log2 argument must be positive"));
             }
-            Ok(Decimal::from_f64(x.log2()))
+            Decimal::from_f64(x.log2())
         }
         "log10" => {
             check_arg_count(&name_lower, args, 1)?;
@@ -179,10 +218,13 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
             if x <= 0.0 {
                 return Err(CalculatorError::domain("log10 argument must be positive"));
             }
-            Ok(Decimal::from_f64(x.log10()))
+            Decimal::from_f64(x.log10())
         }
         "pow" => {
             check_arg_count(&name_lower, args, 2)?;
+            if let Some(result) = integer_power(args[0], args[1]) {
+                return result;
+            }
             let base = args[0].to_f64();
             let exp = args[1].to_f64();
             let result = base.powf(exp);
@@ -191,10 +233,7 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
                     "pow result is undefined (e.g., negative base with fractional exponent)",
                 ));
             }
-            if result.is_infinite() {
-                return Err(CalculatorError::Overflow);
-            }
-            Ok(Decimal::from_f64(result))
+            Decimal::from_f64(result)
         }
 
         // Other mathematical functions
@@ -206,147 +245,201 @@ pub fn evaluate_function(name: &str, args: &[Decimal]) -> Result<Decimal, Calcul
                     "sqrt argument must be non-negative",
                 ));
             }
-            Ok(Decimal::from_f64(x.sqrt()))
+            Decimal::from_f64(x.sqrt())
         }
         "cbrt" => {
             check_arg_count(&name_lower, args, 1)?;
             let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.cbrt()))
+            Decimal::from_f64(x.cbrt())
         }
         "abs" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.abs()))
+            Ok(args[0].abs())
         }
         "floor" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.floor()))
+            Ok(args[0].floor())
         }
         "ceil" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.ceil()))
+            Ok(args[0].ceil())
         }
+        "round" if args.len() == 2 => round_to_places(args[0], args[1]),
         "round" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.round()))
+            Ok(args[0].round_half_away_from_zero(0))
         }
         "trunc" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.trunc()))
+            Ok(args[0].trunc())
         }
         "sign" | "signum" => {
             check_arg_count(&name_lower, args, 1)?;
-            let x = args[0].to_f64();
-            Ok(Decimal::from_f64(x.signum()))
-        }
-        "min" => {
-            if args.len() < 2 {
-                return Err(CalculatorError::invalid_args(
-                    &name_lower,
-                    "expected at least 2 arguments",
-                ));
-            }
-            let min = args
-                .iter()
-                .map(Decimal::to_f64)
-                .fold(f64::INFINITY, f64::min);
-            Ok(Decimal::from_f64(min))
-        }
-        "max" => {
-            if args.len() < 2 {
-                return Err(CalculatorError::invalid_args(
-                    &name_lower,
-                    "expected at least 2 arguments",
-                ));
-            }
-            let max = args
-                .iter()
-                .map(Decimal::to_f64)
-                .fold(f64::NEG_INFINITY, f64::max);
-            Ok(Decimal::from_f64(max))
+            Ok(args[0].signum())
         }
         "factorial" => {
             check_arg_count(&name_lower, args, 1)?;
-            let n = args[0].to_f64();
-            #[allow(clippy::float_cmp)]
-            if n < 0.0 || n != n.floor() {
+            let n = args[0];
+            if n.is_negative() || n != n.trunc() {
                 return Err(CalculatorError::domain(
                     "factorial argument must be a non-negative integer",
                 ));
             }
-            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-            let n_int = n as u64;
-            if n_int > 170 {
-                return Err(CalculatorError::Overflow);
+            factorial(n)
+        }
+
+        // Special functions
+        "gamma" => {
+            check_arg_count(&name_lower, args, 1)?;
+            let x = args[0];
+            if !x.is_negative() && !x.is_zero() && x == x.trunc() {
+                return factorial(x - Decimal::one());
+            }
+            Decimal::from_f64(special::gamma(x.to_f64())?)
+        }
+        "beta" => {
+            check_arg_count(&name_lower, args, 2)?;
+            let (a, b) = (args[0], args[1]);
+            if [a, b]
+                .iter()
+                .all(|x| !x.is_negative() && !x.is_zero() && *x == x.trunc())
+            {
+                // B(a, b) = (a - 1)! (b - 1)! / (a + b - 1)!
+                let exact = factorial(a - Decimal::one())
+                    .and_then(|x| Ok((x, factorial(b - Decimal::one())?)))
+                    .and_then(|(x, y)| Ok((x, y, factorial(a + b - Decimal::one())?)));
+                if let Ok((x, y, z)) = exact {
+                    if let Some(result) = x.checked_mul(&y).and_then(|xy| xy.checked_div(&z)) {
+                        return Ok(result);
+                    }
+                }
             }
-            let result = factorial(n_int);
-            Ok(Decimal::from_f64(result))
+            Decimal::from_f64(special::beta(a.to_f64(), b.to_f64())?)
+        }
+        "erf" => {
+            check_arg_count(&name_lower, args, 1)?;
+            Decimal::from_f64(special::erf(args[0].to_f64()))
+        }
+        "erfc" => {
+            check_arg_count(&name_lower, args, 1)?;
+            Decimal::from_f64(special::erfc(args[0].to_f64()))
         }
+        "si" => {
+            check_arg_count(&name_lower, args, 1)?;
+            Decimal::from_f64(special::si(args[0].to_f64()))
+        }
+        "ci" => {
+            check_arg_count(&name_lower, args, 1)?;
+            Decimal::from_f64(special::ci(args[0].to_f64())?)
+        }
+        "ei" => {
+            check_arg_count(&name_lower, args, 1)?;
+            Decimal::from_f64(special::ei(args[0].to_f64())?)
+        }
+
+        "mean" | "average" | "median" | "mode" | "stddev" | "variance" | "sum" | "count"
+        | "min" | "max" => evaluate_statistics_decimal(&name_lower, args),
 
         // Conversion functions
         "deg" | "degrees" => {
             check_arg_count(&name_lower, args, 1)?;
             let radians = args[0].to_f64();
-            Ok(Decimal::from_f64(radians.to_degrees()))
+            Decimal::from_f64(radians.to_degrees())
         }
         "rad" | "radians" => {
             check_arg_count(&name_lower, args, 1)?;
             let degrees = args[0].to_f64();
-            Ok(Decimal::from_f64(degrees.to_radians()))
+            Decimal::from_f64(degrees.to_radians())
         }
 
         _ => Err(CalculatorError::unknown_function(name)),
     }
 }
 
+/// The math functions, including the constants `pi` and `e`, which are
+/// also called without arguments.
+pub const MATH_FUNCTIONS: &[&str] = &[
+    "sin",
+    "cos",
+    "tan",
+    "asin",
+    "acos",
+    "atan",
+    "atan2",
+    "sinh",
+    "cosh",
+    "tanh",
+    "exp",
+    "ln",
+    "log",
+    "log2",
+    "log10",
+    "pow",
+    "sqrt",
+    "cbrt",
+    "abs",
+    "floor",
+    "ceil",
+    "round",
+    "trunc",
+    "sign",
+    "signum",
+    "min",
+    "max",
+    "mean",
+    "average",
+    "median",
+    "mode",
+    "stddev",
+    "variance",
+    "sum",
+    "count",
+    "integrate",
+    "nderiv",
+    "root",
+    "factorial",
+    "gamma",
+    "beta",
+    "erf",
+    "erfc",
+    "si",
+    "ci",
+    "ei",
+    "pi",
+    "e",
+    "deg",
+    "degrees",
+    "rad",
+    "radians",
+];
+
 /// Returns true if the given name is a known math function.
 #[must_use]
 pub fn is_math_function(name: &str) -> bool {
-    let name_lower = name.to_lowercase();
+    MATH_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Returns true if `name` takes an expression and the variable it is in as
+/// its first two arguments, as `integrate(x^2, x, 0, 1)` does.
+#[must_use]
+pub fn binds_variable(name: &str) -> bool {
     matches!(
-        name_lower.as_str(),
-        "sin"
-            | "cos"
-            | "tan"
-            | "asin"
-            | "acos"
-            | "atan"
-            | "atan2"
-            | "sinh"
-            | "cosh"
-            | "tanh"
-            | "exp"
-            | "ln"
-            | "log"
-            | "log2"
-            | "log10"
-            | "pow"
-            | "sqrt"
-            | "cbrt"
-            | "abs"
-            | "floor"
-            | "ceil"
-            | "round"
-            | "trunc"
-            | "sign"
-            | "signum"
-            | "min"
-            | "max"
-            | "integrate"
-            | "factorial"
-            | "pi"
-            | "e"
-            | "deg"
-            | "degrees"
-            | "rad"
-            | "radians"
+        name.to_lowercase().as_str(),
+        "integrate" | "nderiv" | "root"
     )
 }
 
+/// Takes the `degree`-th root of the exact number `x` on its digits rather
+/// than through `f64`, failing if it is too large for a Decimal. A Decimal
+/// argument may itself be a rounded `f64`, so it is not worth more digits.
+pub(super) fn root(x: &Rational, degree: u32) -> Result<Decimal, CalculatorError> {
+    x.root_to_digits(degree, ROOT_DIGITS)
+        .and_then(|root| root.to_decimal())
+        .ok_or(CalculatorError::Overflow)
+}
+
 /// Checks that the function received the expected number of arguments.
 fn check_arg_count(
     func_name: &str,
@@ -362,39 +455,292 @@ fn check_arg_count(
     Ok(())
 }
 
-/// Computes factorial of n.
-fn factorial(n: u64) -> f64 {
-    if n <= 1 {
-        1.0
-    } else {
-        (2..=n).fold(1.0, |acc, x| acc * (x as f64))
+/// Rounds `x` to `places` decimal places, halves away from zero.
+fn round_to_places(x: Decimal, places: Decimal) -> Result<Decimal, CalculatorError> {
+    const MAX_PLACES: i64 = 28;
+    let places_f64 = places.to_f64();
+    #[allow(clippy::float_cmp)]
+    if places_f64 != places_f64.trunc() || places_f64.abs() > MAX_PLACES as f64 {
+        return Err(CalculatorError::invalid_args(
+            "round",
+            format!("places must be an integer between -{MAX_PLACES} and {MAX_PLACES}"),
+        ));
+    }
+    let places = places_f64 as i64;
+    if places >= 0 {
+        return Ok(x.round_half_away_from_zero(places.unsigned_abs() as u32));
+    }
+    // round(1234, -2) = round(12.34) * 100
+    let scale = (0..-places).fold(Decimal::one(), |acc, _| acc * Decimal::new(10));
+    let scaled = x
+        .checked_div(&scale)
+        .ok_or_else(|| CalculatorError::eval("round: scale is zero"))?;
+    Ok(scaled.round_half_away_from_zero(0) * scale)
+}
+
+/// Computes `n!` for a non-negative integer `n`; past `27!` it no longer
+/// fits a Decimal.
+fn factorial(n: Decimal) -> Result<Decimal, CalculatorError> {
+    let mut result = Decimal::one();
+    let mut i = Decimal::new(2);
+    while i <= n {
+        result = result.checked_mul(&i).ok_or(CalculatorError::Overflow)?;
+        i = i + Decimal::one();
+    }
+    Ok(result)
+}
+
+/// Raises `base` to a whole `exponent` by repeated squaring, or returns
+/// `None` for other exponents, which need `f64`.
+fn integer_power(base: Decimal, exponent: Decimal) -> Option<Result<Decimal, CalculatorError>> {
+    if exponent != exponent.trunc() {
+        return None;
+    }
+    let exponent = exponent.to_i64()?;
+    if exponent < 0 && base.is_zero() {
+        return Some(Err(CalculatorError::domain(
+            "division by zero (negative exponent with zero base)",
+        )));
+    }
+    let mut result = Decimal::one();
+    let mut square = base;
+    let mut remaining = exponent.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = match result.checked_mul(&square) {
+                Some(result) => result,
+                None => return Some(Err(CalculatorError::Overflow)),
+            };
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = match square.checked_mul(&square) {
+                Some(square) => square,
+                None => return Some(Err(CalculatorError::Overflow)),
+            };
+        }
+    }
+    if exponent < 0 {
+        return Some(
+            Decimal::one()
+                .checked_div(&result)
+                .ok_or(CalculatorError::Overflow),
+        );
     }
+    Some(Ok(result))
 }
 
-/// Performs numerical integration using Simpson's rule.
+/// Performs adaptive numerical integration.
 ///
-/// Integrates the function `f` from `a` to `b` using Simpson's rule
-/// with `INTEGRATION_SUBDIVISIONS` intervals.
-#[allow(clippy::many_single_char_names)]
+/// Integrates the function `f` from `a` to `b`, either of which may be
+/// infinite, with adaptive Gauss–Kronrod quadrature using at most
+/// `INTEGRATION_SUBDIVISIONS` subintervals.
 pub fn integrate<F>(f: F, a: f64, b: f64) -> f64
 where
     F: Fn(f64) -> f64,
 {
-    let n = INTEGRATION_SUBDIVISIONS;
-    let h = (b - a) / (n as f64);
+    let Ok(result) = integrate_adaptive(
+        |x| Ok::<_, Infallible>(f(x)),
+        a,
+        b,
+        INTEGRATION_TOLERANCE,
+        INTEGRATION_SUBDIVISIONS,
+    );
+    result.value
+}
 
-    let mut sum = f(a) + f(b);
+/// Estimates the derivative of `f` at `x` with Ridders' method: central
+/// differences over shrinking steps, extrapolated to a zero step.
+///
+/// Returns the derivative and an estimate of its error. Near the edge of the
+/// domain of `f`, the first step is shrunk until `f` is defined on both
+/// sides of `x`. Fails if `f` is not defined at `x`, if the estimates do
+/// not settle, as at a jump, or if the slopes from the left and the right
+/// differ, as at the corner of `abs(x)`.
+pub fn derivative(
+    mut f: impl FnMut(f64) -> Result<f64, CalculatorError>,
+    x: f64,
+) -> Result<(f64, f64), CalculatorError> {
+    let value = match f(x) {
+        Ok(value) if value.is_finite() => value,
+        Err(error @ (CalculatorError::Cancelled | CalculatorError::LimitExceeded(_))) => {
+            return Err(error)
+        }
+        _ => {
+            return Err(CalculatorError::domain(format!(
+                "the function is not defined at {x}"
+            )))
+        }
+    };
+    let not_differentiable =
+        || CalculatorError::domain(format!("the function is not differentiable at {x}"));
+    let mut step = 0.1 * x.abs().max(1.0);
+    let difference = |f: &mut dyn FnMut(f64) -> Result<f64, CalculatorError>, h: f64| {
+        Ok::<_, CalculatorError>((f(x + h)? - f(x - h)?) / (2.0 * h))
+    };
+    let mut first = difference(&mut f, step);
+    let mut shrunk = false;
+    for _ in 0..DERIVATIVE_STEPS {
+        match first {
+            Err(CalculatorError::Cancelled | CalculatorError::LimitExceeded(_)) => break,
+            // Keep a margin from the point where `f` stops being defined
+            Ok(d) if d.is_finite() && !shrunk => break,
+            Ok(d) if d.is_finite() => shrunk = false,
+            _ => shrunk = true,
+        }
+        step /= 10.0;
+        first = difference(&mut f, step);
+    }
 
-    for i in 1..n {
-        let x = (i as f64).mul_add(h, a);
-        if i % 2 == 0 {
-            sum = 2.0_f64.mul_add(f(x), sum);
-        } else {
-            sum = 4.0_f64.mul_add(f(x), sum);
+    // The gap between the slopes from either side shrinks with the step
+    // where `f` is smooth, but not at a corner
+    let mut one_sided_gap = |h: f64| {
+        Ok::<_, CalculatorError>(((f(x + h)? - value) / h - (value - f(x - h)?) / h).abs())
+    };
+    let (wide, narrow) = (one_sided_gap(step / 100.0)?, one_sided_gap(step / 1000.0)?);
+    if narrow > 0.5 * wide && narrow > DERIVATIVE_MAX_RELATIVE_ERROR * value.abs().max(1.0) {
+        return Err(not_differentiable());
+    }
+
+    // table[j] holds the estimate extrapolated j times from the current step
+    let mut table = vec![first?];
+    let mut best = (table[0], f64::INFINITY);
+    for _ in 1..DERIVATIVE_STEPS {
+        step /= DERIVATIVE_STEP_RATIO;
+        let previous = std::mem::replace(&mut table, vec![difference(&mut f, step)?]);
+        let mut factor = DERIVATIVE_STEP_RATIO * DERIVATIVE_STEP_RATIO;
+        for j in 1..=previous.len() {
+            let estimate = table[j - 1].mul_add(factor, -previous[j - 1]) / (factor - 1.0);
+            factor *= DERIVATIVE_STEP_RATIO * DERIVATIVE_STEP_RATIO;
+            let error = (estimate - table[j - 1])
+                .abs()
+                .max((estimate - previous[j - 1]).abs());
+            if error <= best.1 {
+                best = (estimate, error);
+            }
+            table.push(estimate);
+        }
+        // Stop once a smaller step makes the estimate worse
+        let last = table.len() - 1;
+        if (table[last] - previous[last - 1]).abs() >= 2.0 * best.1 {
+            break;
         }
     }
+    if best.1 > DERIVATIVE_MAX_RELATIVE_ERROR * best.0.abs().max(1.0) {
+        return Err(not_differentiable());
+    }
+    Ok(best)
+}
 
-    sum * h / 3.0
+/// Finds a root of `f` between `lower` and `upper` with Brent's method.
+///
+/// `f` must change sign between the bounds. The search stops once the root
+/// is known to within `tolerance`, and fails if that takes more than
+/// `max_iterations` steps, or if `f` is not close to zero there or not
+/// defined on the way, as where it changes sign through a pole.
+#[allow(clippy::many_single_char_names)]
+pub fn find_root(
+    mut f: impl FnMut(f64) -> Result<f64, CalculatorError>,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64, CalculatorError> {
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a)?, f(b)?);
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() || fa.is_nan() || fb.is_nan() {
+        return Err(CalculatorError::invalid_args(
+            "root",
+            format!(
+                "no root is bracketed between {lower} and {upper}: \
+                 the function has the same sign at both ({fa} and {fb})"
+            ),
+        ));
+    }
+
+    let max_residual = fa.abs().max(fb.abs()) * ROOT_MAX_RELATIVE_RESIDUAL.max(tolerance.sqrt());
+
+    // The root lies between b (the best estimate) and c
+    let (mut c, mut fc) = (a, fa);
+    let mut step = b - a;
+    let mut previous_step = step;
+    for _ in 0..max_iterations {
+        if fb.signum() == fc.signum() {
+            (c, fc) = (a, fa);
+            step = b - a;
+            previous_step = step;
+        }
+        if fc.abs() < fb.abs() {
+            (a, fa) = (b, fb);
+            (b, fb) = (c, fc);
+            (c, fc) = (a, fa);
+        }
+        let accuracy = (2.0 * f64::EPSILON).mul_add(b.abs(), 0.5 * tolerance);
+        let midpoint = 0.5 * (c - b);
+        if midpoint.abs() <= accuracy || fb == 0.0 {
+            if fb.abs() > max_residual {
+                return Err(CalculatorError::domain(format!(
+                    "no root between {lower} and {upper}: the function changes sign \
+                     without crossing zero near {b}"
+                )));
+            }
+            return Ok(b);
+        }
+        if previous_step.abs() >= accuracy && fa.abs() > fb.abs() {
+            // Interpolate: secant when only two points differ, otherwise
+            // inverse quadratic
+            let s = fb / fa;
+            let (mut p, mut q) = if a.to_bits() == c.to_bits() {
+                (2.0 * midpoint * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * midpoint * q).mul_add(q - r, -(b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let limit = (3.0 * midpoint)
+                .mul_add(q, -(accuracy * q).abs())
+                .min((previous_step * q).abs());
+            if 2.0 * p < limit {
+                previous_step = step;
+                step = p / q;
+            } else {
+                step = midpoint;
+                previous_step = step;
+            }
+        } else {
+            step = midpoint;
+            previous_step = step;
+        }
+        (a, fa) = (b, fb);
+        b += if step.abs() > accuracy {
+            step
+        } else {
+            accuracy.copysign(midpoint)
+        };
+        fb = f(b).map_err(|error| match error {
+            CalculatorError::Cancelled | CalculatorError::LimitExceeded(_) => error,
+            _ => CalculatorError::domain(format!(
+                "no root between {lower} and {upper}: the function changes sign \
+                 through a pole or discontinuity at {b}"
+            )),
+        })?;
+    }
+    Err(CalculatorError::LimitExceeded(format!(
+        "root finding did not converge within {max_iterations} iterations"
+    )))
 }
 
 #[cfg(test)]
@@ -410,8 +756,11 @@ mod tests {
         let result = evaluate_function("sin", &[Decimal::new(0)]).unwrap();
         assert!(approx_eq(result.to_f64(), 0.0, 1e-10));
 
-        let result =
-            evaluate_function("sin", &[Decimal::from_f64(std::f64::consts::PI / 2.0)]).unwrap();
+        let result = evaluate_function(
+            "sin",
+            &[Decimal::from_f64(std::f64::consts::PI / 2.0).unwrap()],
+        )
+        .unwrap();
         assert!(approx_eq(result.to_f64(), 1.0, 1e-10));
     }
 
@@ -420,7 +769,8 @@ mod tests {
         let result = evaluate_function("cos", &[Decimal::new(0)]).unwrap();
         assert!(approx_eq(result.to_f64(), 1.0, 1e-10));
 
-        let result = evaluate_function("cos", &[Decimal::from_f64(std::f64::consts::PI)]).unwrap();
+        let result =
+            evaluate_function("cos", &[Decimal::from_f64(std::f64::consts::PI).unwrap()]).unwrap();
         assert!(approx_eq(result.to_f64(), -1.0, 1e-10));
     }
 
@@ -435,13 +785,14 @@ mod tests {
 
     #[test]
     fn test_sqrt_negative() {
-        let result = evaluate_function("sqrt", &[Decimal::from_f64(-1.0)]);
+        let result = evaluate_function("sqrt", &[Decimal::from_f64(-1.0).unwrap()]);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_ln() {
-        let result = evaluate_function("ln", &[Decimal::from_f64(std::f64::consts::E)]).unwrap();
+        let result =
+            evaluate_function("ln", &[Decimal::from_f64(std::f64::consts::E).unwrap()]).unwrap();
         assert!(approx_eq(result.to_f64(), 1.0, 1e-10));
     }
 
@@ -469,6 +820,22 @@ mod tests {
         assert!(approx_eq(result.to_f64(), 1.0, 1e-10));
     }
 
+    #[test]
+    fn test_round_to_places() {
+        let round = |x: &str, places: i64| {
+            evaluate_function("round", &[x.parse().unwrap(), Decimal::new(places)])
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(round("3.14159", 2), "3.14");
+        assert_eq!(round("2.675", 2), "2.68");
+        assert_eq!(round("-2.5", 0), "-3");
+        assert_eq!(round("1234.5", -2), "1200");
+        assert!(
+            evaluate_function("round", &[Decimal::one(), Decimal::from_f64(0.5).unwrap()]).is_err()
+        );
+    }
+
     #[test]
     fn test_pi() {
         let result = evaluate_function("pi", &[]).unwrap();
@@ -483,7 +850,7 @@ mod tests {
 
     #[test]
     fn test_abs() {
-        let result = evaluate_function("abs", &[Decimal::from_f64(-5.0)]).unwrap();
+        let result = evaluate_function("abs", &[Decimal::from_f64(-5.0).unwrap()]).unwrap();
         assert!(approx_eq(result.to_f64(), 5.0, 1e-10));
     }
 
@@ -535,4 +902,32 @@ mod tests {
         assert!(!is_math_function("foo"));
         assert!(!is_math_function("USD"));
     }
+
+    #[test]
+    fn test_derivative() {
+        let (value, error) = derivative(|x| Ok(x.exp()), 1.0).unwrap();
+        assert!(approx_eq(value, std::f64::consts::E, 1e-10));
+        assert!(error < 1e-8);
+        // The first step shrinks until sqrt is defined on both sides
+        let (value, _) = derivative(
+            |x| evaluate_function("sqrt", &[Decimal::from_f64(x).unwrap()]).map(|d| d.to_f64()),
+            0.01,
+        )
+        .unwrap();
+        assert!(approx_eq(value, 5.0, 1e-6), "{value}");
+        assert!(derivative(|x| Ok(1.0 / x), 0.0).is_err());
+        assert!(derivative(|x| Ok(x.signum()), 0.5e-3).is_err());
+    }
+
+    #[test]
+    fn test_find_root() {
+        let root = find_root(|x| Ok(x.mul_add(x, -2.0)), 0.0, 2.0, 1e-12, 100).unwrap();
+        assert!(approx_eq(root, std::f64::consts::SQRT_2, 1e-12));
+        assert!(find_root(|x| Ok(x.mul_add(x, 1.0)), -1.0, 1.0, 1e-12, 100).is_err());
+        assert!(find_root(|x| Ok(x.tan()), 1.0, 2.0, 1e-12, 100).is_err());
+        assert!(matches!(
+            find_root(|x| Ok(x.cos() - x), 0.0, 1.0, 0.0, 2),
+            Err(CalculatorError::LimitExceeded(_))
+        ));
+    }
 }
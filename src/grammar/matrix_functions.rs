@@ -0,0 +1,44 @@
+//! Matrix functions: `transpose`, `det` and `inverse`, and `matrix`, which
+//! builds the matrix for a literal such as `[[1, 2], [3, 4]]`.
+
+use crate::error::CalculatorError;
+use crate::types::Value;
+
+/// The matrix functions, with `determinant` for `det` and `inv` for
+/// `inverse`.
+pub const MATRIX_FUNCTIONS: &[&str] = &[
+    "matrix",
+    "transpose",
+    "det",
+    "determinant",
+    "inverse",
+    "inv",
+];
+
+/// Returns true if `name` is a matrix function.
+#[must_use]
+pub fn is_matrix_function(name: &str) -> bool {
+    MATRIX_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Evaluates a matrix function.
+pub fn evaluate_matrix_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    if name == "matrix" {
+        // Rows that are not all plain numbers stay a list of lists
+        return Ok(Value::matrix_from_rows(args).unwrap_or_else(|| Value::list(args.to_vec())));
+    }
+    let [matrix] = args else {
+        return Err(CalculatorError::invalid_args(
+            &name,
+            format!("expected 1 argument: {name}(matrix)"),
+        ));
+    };
+    match name.as_str() {
+        "transpose" => matrix.transpose(),
+        "det" | "determinant" => matrix.determinant(),
+        _ => matrix.inverse(),
+    }
+}
@@ -1,19 +1,96 @@
 //! Grammar modules for parsing expressions.
 
+mod arithmetic_working;
+mod bases;
+mod calendar_functions;
+mod color;
+mod compiled;
+mod countdown;
 mod datetime_grammar;
+mod encoding;
+mod engineering;
+mod equivalence;
+mod exact_constants;
 mod expression_parser;
+mod factors;
+mod finance;
+mod geo;
+#[cfg(feature = "text-tools")]
+mod hash;
+mod health;
+mod integer_functions;
 mod integral;
 mod lexer;
 mod linear_equation;
+mod list_functions;
+mod locale_input;
 mod locale_numbers;
 mod math_functions;
+mod matrix_functions;
 mod number_grammar;
+mod number_words;
+mod physics;
 mod polynomial_equation;
+mod power;
+mod proportion;
+mod quadrature;
+mod rounding;
+mod simplify;
+mod special_functions;
+mod statistics;
+mod symbolic;
+mod text;
 mod token_parser;
 
+pub use bases::{evaluate_base_function, is_base_function};
+pub use calendar_functions::{
+    default_reference_date, evaluate_calendar_function, is_calendar_function,
+};
+pub use color::{evaluate_color_function, is_color_function};
+pub use compiled::CompiledExpression;
 pub use datetime_grammar::DateTimeGrammar;
-pub use expression_parser::{evaluate_power, ExpressionParser};
-pub use integral::{evaluate_indefinite_integral, symbolic_result_to_latex, try_symbolic_integral};
+pub use encoding::{evaluate_encoding_function, is_encoding_function};
+pub use engineering::{evaluate_engineering_function, is_engineering_function, si_prefixed};
+pub use equivalence::{check_equivalence, evaluate_equivalence, Equivalence};
+pub use expression_parser::{
+    ExpressionParser, ParseCacheStats, PartialEvaluation, ProgressCallback, RateHistory,
+};
+pub use factors::{
+    evaluate_integer_facts, format_prime_factors, integer_fact_steps, prime_factors, MAX_FACTORIZED,
+};
+pub use finance::{
+    evaluate_finance_function, is_bill_function, is_discount_function, is_finance_function,
+    is_tax_function, percentages_are_plain, Amortization, AmortizationPeriod, BillSplit,
+    DiscountChain, Tip, VatBreakdown,
+};
+pub use geo::{evaluate_geo_function, is_geo_function};
+#[cfg(feature = "text-tools")]
+pub use hash::{evaluate_hash_function, is_hash_function};
+pub use health::{evaluate_health_function, is_health_function, HealthFormula};
+pub use integer_functions::{
+    evaluate_integer_function, is_factorial_function, is_integer_function,
+};
+pub use integral::{
+    evaluate_indefinite_integral, symbolic_antiderivative, symbolic_result_to_latex,
+    try_symbolic_integral,
+};
 pub use lexer::{Lexer, Token, TokenKind};
-pub use math_functions::{evaluate_function, integrate, is_math_function};
+pub use list_functions::{evaluate_value_function, is_value_function};
+pub use locale_input::InputLocale;
+pub(crate) use math_functions::binds_variable;
+pub use math_functions::{derivative, evaluate_function, find_root, integrate, is_math_function};
 pub use number_grammar::NumberGrammar;
+pub use number_words::NumberWord;
+pub(crate) use physics::physics_units;
+pub use physics::{
+    evaluate_physics_function, is_physics_function, is_physics_quantity, quantity_op,
+    PhysicsSolution,
+};
+pub use power::evaluate_power;
+pub use proportion::{evaluate_proportion_function, is_proportion_function, Proportion};
+pub use rounding::{evaluate_rounding_function, is_rounding_function};
+pub use simplify::{evaluate_simplify, simplify};
+pub use statistics::{evaluate_statistics, is_statistics_function};
+pub use text::{evaluate_text_function, is_text_function};
+pub(crate) use token_parser::infix_operators;
+pub(crate) use token_parser::AUTO_PLOT_BOUND;
@@ -1,8 +1,19 @@
 //! Grammar for parsing numbers with optional units.
 
+use super::physics::is_physics_unit;
+use num_bigint::BigInt;
+
 use crate::crypto_api;
 use crate::error::CalculatorError;
-use crate::types::{CurrencyDatabase, DataSizeUnit, Decimal, DurationUnit, MassUnit, Unit};
+use crate::types::{
+    AngleUnit, CurrencyDatabase, DataSizeUnit, DurationUnit, LengthUnit, MassUnit, Radix, Rational,
+    Unit, VolumeUnit,
+};
+
+/// The largest power of ten a number literal may be written with, as in
+/// `1e400`: the same bound as exact powers such as `10^400`, so any result
+/// shown in scientific notation reads back as it is shown.
+pub(super) const MAX_EXPONENT: u32 = 1_000_000;
 
 /// Grammar for parsing numbers with optional units.
 #[derive(Debug, Default)]
@@ -15,8 +26,9 @@ impl NumberGrammar {
         Self
     }
 
-    /// Parses a number string into a Decimal.
-    pub fn parse_number(&self, s: &str) -> Result<Decimal, CalculatorError> {
+    /// Parses a number string into an exact rational, keeping every digit
+    /// however many are written.
+    pub fn parse_number(&self, s: &str) -> Result<Rational, CalculatorError> {
         let s = s.trim();
 
         // Handle negative numbers
@@ -24,11 +36,36 @@ impl NumberGrammar {
             .strip_prefix('-')
             .map_or((false, s), |stripped| (true, stripped.trim()));
 
-        let decimal: Decimal = s
-            .parse()
-            .map_err(|_| CalculatorError::parse(format!("Invalid number: {s}")))?;
+        let number = match Radix::split_literal(s) {
+            Some((radix, digits)) => Self::parse_radix_digits(radix, digits)?,
+            None => Self::parse_decimal(s)?,
+        };
 
-        Ok(if is_negative { -decimal } else { decimal })
+        Ok(if is_negative { -number } else { number })
+    }
+
+    /// Parses decimal digits with an optional exponent, such as `2.5e-3`.
+    fn parse_decimal(s: &str) -> Result<Rational, CalculatorError> {
+        let invalid = || CalculatorError::parse(format!("Invalid number: {s}"));
+        let (mantissa, exponent) = s
+            .split_once(['e', 'E'])
+            .map_or((s, None), |(mantissa, exponent)| (mantissa, Some(exponent)));
+        let mantissa = Rational::parse_decimal_digits(mantissa).ok_or_else(invalid)?;
+        let Some(exponent) = exponent else {
+            return Ok(mantissa);
+        };
+        let exponent: i32 = exponent.parse().map_err(|_| invalid())?;
+        if exponent.unsigned_abs() > MAX_EXPONENT {
+            return Err(CalculatorError::Overflow);
+        }
+        Ok(mantissa * Rational::from_integer(10).pow_i32(exponent))
+    }
+
+    /// Parses the digits of a prefixed integer literal (`FF` in `0xFF`).
+    fn parse_radix_digits(radix: Radix, digits: &str) -> Result<Rational, CalculatorError> {
+        BigInt::parse_bytes(digits.as_bytes(), radix.base())
+            .map(Rational::from_bigint)
+            .ok_or_else(|| CalculatorError::parse(format!("Invalid number: {digits}")))
     }
 
     /// Returns the decimal multiplier for an SI-style numeric suffix.
@@ -39,7 +76,7 @@ impl NumberGrammar {
     /// distinguishes multipliers, so `m` is milli and `M` is mega. `K`/`К`
     /// are accepted as common user spellings of kilo.
     #[must_use]
-    pub(crate) fn si_suffix_multiplier(s: &str) -> Option<Decimal> {
+    pub(crate) fn si_suffix_multiplier(s: &str) -> Option<Rational> {
         let multiplier = match s {
             // Submultiples
             "r" => "0.000000000000000000000000001",
@@ -68,7 +105,7 @@ impl NumberGrammar {
             _ => return None,
         };
 
-        multiplier.parse().ok()
+        Rational::parse_decimal_digits(multiplier)
     }
 
     /// Parses a number with an optional unit.
@@ -76,7 +113,7 @@ impl NumberGrammar {
         &self,
         number_str: &str,
         unit_str: Option<&str>,
-    ) -> Result<(Decimal, Unit), CalculatorError> {
+    ) -> Result<(Rational, Unit), CalculatorError> {
         let number = self.parse_number(number_str)?;
 
         let unit = match unit_str {
@@ -105,6 +142,11 @@ impl NumberGrammar {
         let s = s.trim();
         let mut alternatives = Vec::new();
 
+        // Lengths, masses and durations to a power in superscript: "m²", "cm³"
+        if let Some(power) = Unit::parse_superscript_power(s, |base| self.parse_unit(base).ok()) {
+            return Ok((power, alternatives));
+        }
+
         // Try to parse as data size unit first (before currency, to avoid conflicts)
         if let Some(data_size) = DataSizeUnit::parse(s) {
             return Ok((Unit::DataSize(data_size), alternatives));
@@ -129,11 +171,33 @@ impl NumberGrammar {
             return Ok((primary, alternatives));
         }
 
-        // Try to parse as duration unit (before currency, to avoid e.g. "h" being treated as a currency)
-        if let Some(dur) = DurationUnit::parse(s) {
+        // Try to parse as duration unit (before currency, to avoid e.g. "h" being treated as a currency).
+        // A capital "W" is the watt; weeks are "w" or "wk"
+        if let Some(dur) = DurationUnit::parse(s).filter(|_| s != "W") {
             return Ok((Unit::Duration(dur), alternatives));
         }
 
+        // Length, volume and rates such as "mph", before currency so that "km"
+        // is not read as a currency code
+        if let Some(length) = LengthUnit::parse(s) {
+            return Ok((Unit::Length(length), alternatives));
+        }
+        if let Some(volume) = VolumeUnit::parse(s) {
+            return Ok((Unit::Volume(volume), alternatives));
+        }
+        if let Some(angle) = AngleUnit::parse(s) {
+            return Ok((Unit::Angle(angle), alternatives));
+        }
+        if let Some(compound) = Unit::parse_compound_alias(s) {
+            return Ok((compound, alternatives));
+        }
+
+        // Electrical and mechanical units such as "ohm", "mA" and "kV", before
+        // currency so that their case survives and "mA" is not mega-amperes
+        if is_physics_unit(s) {
+            return Ok((Unit::Custom(s.to_string()), alternatives));
+        }
+
         // Try to parse as cryptocurrency or fiat currency alias
         if let Some(currency_code) = CurrencyDatabase::parse_currency(s) {
             let primary = Unit::currency(&currency_code);
@@ -252,6 +316,21 @@ mod tests {
         assert_eq!(n.to_string(), "-5");
     }
 
+    #[test]
+    fn test_parse_radix_literals() {
+        let grammar = NumberGrammar::new();
+        assert_eq!(grammar.parse_number("0xFF").unwrap().to_string(), "255");
+        assert_eq!(grammar.parse_number("0b1010").unwrap().to_string(), "10");
+        assert_eq!(grammar.parse_number("0o17").unwrap().to_string(), "15");
+        assert_eq!(
+            grammar
+                .parse_number("0x1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")
+                .unwrap()
+                .to_string(),
+            "680564733841876926926749214863536422911"
+        );
+    }
+
     #[test]
     fn test_parse_number_with_currency() {
         let grammar = NumberGrammar::new();
@@ -0,0 +1,166 @@
+//! English and Russian number words (`two hundred thirty five`,
+//! `двести тридцать пять`, `a dozen`, `half`) and operator words (`plus`,
+//! `divided by`).
+
+use super::NumberGrammar;
+use crate::types::BinaryOp;
+
+/// The meaning of a single number word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberWord {
+    /// A value added to the number being built: `thirty`, `five`.
+    Value(u64),
+    /// A multiplier of the number built so far: `hundred`, `dozen`.
+    Multiplier(u64),
+    /// A scale that closes the group before it: `thousand`, `million`.
+    Scale(u64),
+    /// A fraction of the number built so far: `half`, `thirds`.
+    Fraction(u64),
+}
+
+impl NumberGrammar {
+    /// Looks up an English or Russian number word.
+    #[must_use]
+    pub fn number_word(word: &str) -> Option<NumberWord> {
+        let lower = word.to_lowercase();
+        english_number_word(&lower).or_else(|| russian_number_word(&lower))
+    }
+
+    /// Returns the operator spelled by `word`, and whether it needs a
+    /// following `by` (`divided by`), in English or Russian.
+    #[must_use]
+    pub fn operator_word(word: &str) -> Option<(BinaryOp, bool)> {
+        match word.to_lowercase().as_str() {
+            "plus" | "плюс" => Some((BinaryOp::Add, false)),
+            "minus" | "минус" => Some((BinaryOp::Subtract, false)),
+            "times" => Some((BinaryOp::Multiply, false)),
+            "multiplied" | "умножить" | "умноженное" => {
+                Some((BinaryOp::Multiply, true))
+            }
+            "divided" | "разделить" | "делить" | "деленное" | "делённое" => {
+                Some((BinaryOp::Divide, true))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn english_number_word(word: &str) -> Option<NumberWord> {
+    let value = match word {
+        "zero" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        "hundred" | "hundreds" => return Some(NumberWord::Multiplier(100)),
+        "dozen" | "dozens" => return Some(NumberWord::Multiplier(12)),
+        "thousand" | "thousands" => return Some(NumberWord::Scale(1_000)),
+        "million" | "millions" => return Some(NumberWord::Scale(1_000_000)),
+        "billion" | "billions" => return Some(NumberWord::Scale(1_000_000_000)),
+        "half" | "halves" => return Some(NumberWord::Fraction(2)),
+        "third" | "thirds" => return Some(NumberWord::Fraction(3)),
+        "quarter" | "quarters" => return Some(NumberWord::Fraction(4)),
+        _ => return None,
+    };
+    Some(NumberWord::Value(value))
+}
+
+fn russian_number_word(word: &str) -> Option<NumberWord> {
+    let value = match word {
+        "ноль" | "нуль" => 0,
+        "один" | "одна" | "одно" => 1,
+        "два" | "две" => 2,
+        "три" => 3,
+        "четыре" => 4,
+        "пять" => 5,
+        "шесть" => 6,
+        "семь" => 7,
+        "восемь" => 8,
+        "девять" => 9,
+        "десять" => 10,
+        "одиннадцать" => 11,
+        "двенадцать" => 12,
+        "тринадцать" => 13,
+        "четырнадцать" => 14,
+        "пятнадцать" => 15,
+        "шестнадцать" => 16,
+        "семнадцать" => 17,
+        "восемнадцать" => 18,
+        "девятнадцать" => 19,
+        "двадцать" => 20,
+        "тридцать" => 30,
+        "сорок" => 40,
+        "пятьдесят" => 50,
+        "шестьдесят" => 60,
+        "семьдесят" => 70,
+        "восемьдесят" => 80,
+        "девяносто" => 90,
+        "сто" => 100,
+        "двести" => 200,
+        "триста" => 300,
+        "четыреста" => 400,
+        "пятьсот" => 500,
+        "шестьсот" => 600,
+        "семьсот" => 700,
+        "восемьсот" => 800,
+        "девятьсот" => 900,
+        "дюжина" | "дюжины" | "дюжин" => return Some(NumberWord::Multiplier(12)),
+        "тысяча" | "тысячи" | "тысяч" => return Some(NumberWord::Scale(1_000)),
+        "миллион" | "миллиона" | "миллионов" => {
+            return Some(NumberWord::Scale(1_000_000))
+        }
+        "миллиард" | "миллиарда" | "миллиардов" => {
+            return Some(NumberWord::Scale(1_000_000_000))
+        }
+        "половина" | "половину" | "пол" => return Some(NumberWord::Fraction(2)),
+        "треть" | "трети" => return Some(NumberWord::Fraction(3)),
+        "четверть" | "четверти" => return Some(NumberWord::Fraction(4)),
+        _ => return None,
+    };
+    Some(NumberWord::Value(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_words() {
+        assert_eq!(
+            NumberGrammar::number_word("Twenty"),
+            Some(NumberWord::Value(20))
+        );
+        assert_eq!(
+            NumberGrammar::number_word("тысяч"),
+            Some(NumberWord::Scale(1_000))
+        );
+        assert_eq!(NumberGrammar::number_word("second"), None);
+        assert_eq!(
+            NumberGrammar::operator_word("divided"),
+            Some((BinaryOp::Divide, true))
+        );
+    }
+}
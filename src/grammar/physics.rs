@@ -0,0 +1,619 @@
+//! Physics formulas solved for whichever quantity is unknown:
+//! `voltage with current 2 A and resistance 10 ohm` is
+//! `physics(voltage, current, 2 A, resistance, 10 ohm)` and gives `20 V`.
+//!
+//! Every formula is a product of powers of its quantities, so any one of
+//! them can be solved for exactly. Each given quantity must be a plain
+//! number, taken to be in its SI unit, or carry a unit of its own dimension:
+//! `4.7 kohm` is a resistance and `500 g` a mass, but `2 kg` is not a
+//! current.
+
+use super::engineering::prefix_power;
+use crate::error::CalculatorError;
+use crate::types::{
+    BinaryOp, DurationUnit, LengthUnit, MassUnit, Rational, Unit, Value, ValueKind,
+};
+
+/// The powers of the kilogram, meter, second and ampere a unit is made of:
+/// a volt is `kg m² s⁻³ A⁻¹`, `[1, 2, -3, -1]`.
+type Dimension = [i32; 4];
+
+/// A physical quantity a formula relates.
+#[derive(Debug, PartialEq, Eq)]
+struct Quantity {
+    /// What the user calls it: `voltage`.
+    name: &'static str,
+    /// Its symbol in formulas: `V`.
+    symbol: &'static str,
+    /// Its SI unit: `V`.
+    unit: &'static str,
+    /// What its SI unit is made of.
+    dimension: Dimension,
+    /// Its value if it is a physical constant, in its SI unit.
+    constant: Option<i128>,
+}
+
+const fn quantity(
+    name: &'static str,
+    symbol: &'static str,
+    unit: &'static str,
+    dimension: Dimension,
+) -> Quantity {
+    Quantity {
+        name,
+        symbol,
+        unit,
+        dimension,
+        constant: None,
+    }
+}
+
+const VOLTAGE: Quantity = quantity("voltage", "V", "V", [1, 2, -3, -1]);
+const CURRENT: Quantity = quantity("current", "I", "A", [0, 0, 0, 1]);
+const RESISTANCE: Quantity = quantity("resistance", "R", "Ω", [1, 2, -3, -2]);
+const POWER: Quantity = quantity("power", "P", "W", [1, 2, -3, 0]);
+const FORCE: Quantity = quantity("force", "F", "N", [1, 1, -2, 0]);
+const MASS: Quantity = quantity("mass", "m", "kg", [1, 0, 0, 0]);
+const ACCELERATION: Quantity = quantity("acceleration", "a", "m/s²", [0, 1, -2, 0]);
+const ENERGY: Quantity = quantity("energy", "E", "J", [1, 2, -2, 0]);
+const SPEED_OF_LIGHT: Quantity = Quantity {
+    name: "speed of light",
+    symbol: "c",
+    unit: "m/s",
+    dimension: [0, 1, -1, 0],
+    constant: Some(299_792_458),
+};
+
+/// The quantities that can be given or solved for.
+const QUANTITIES: [&Quantity; 8] = [
+    &VOLTAGE,
+    &CURRENT,
+    &RESISTANCE,
+    &POWER,
+    &FORCE,
+    &MASS,
+    &ACCELERATION,
+    &ENERGY,
+];
+
+/// A formula `∏ quantity^power = 1`: `V = I * R` is `V * I^-1 * R^-1 = 1`.
+struct Formula {
+    name: &'static str,
+    equation: &'static str,
+    terms: &'static [(&'static Quantity, i32)],
+}
+
+const FORMULAS: [Formula; 4] = [
+    Formula {
+        name: "Ohm's law",
+        equation: "V = I * R",
+        terms: &[(&VOLTAGE, 1), (&CURRENT, -1), (&RESISTANCE, -1)],
+    },
+    Formula {
+        name: "Electric power",
+        equation: "P = V * I",
+        terms: &[(&POWER, 1), (&VOLTAGE, -1), (&CURRENT, -1)],
+    },
+    Formula {
+        name: "Newton's second law",
+        equation: "F = m * a",
+        terms: &[(&FORCE, 1), (&MASS, -1), (&ACCELERATION, -1)],
+    },
+    Formula {
+        name: "Mass-energy equivalence",
+        equation: "E = m * c^2",
+        terms: &[(&ENERGY, 1), (&MASS, -1), (&SPEED_OF_LIGHT, -2)],
+    },
+];
+
+/// Returns true if `name` is `physics`.
+#[must_use]
+pub fn is_physics_function(name: &str) -> bool {
+    name.eq_ignore_ascii_case("physics")
+}
+
+/// Returns true if `word` names a quantity the physics formulas relate.
+#[must_use]
+pub fn is_physics_quantity(word: &str) -> bool {
+    find_quantity(word).is_some()
+}
+
+/// Returns true if `symbol` is the unit of a physics quantity, possibly with
+/// an SI prefix: `V`, `mA`, `kohm`.
+pub(super) fn is_physics_unit(symbol: &str) -> bool {
+    QUANTITIES
+        .iter()
+        .any(|quantity| unit_power(quantity, symbol).is_some())
+}
+
+/// Returns the SI units of the physics quantities that have no unit type of
+/// their own: `V`, `A`, `Ω`, `W`, `N` and `J`.
+pub fn physics_units() -> impl Iterator<Item = Unit> {
+    QUANTITIES
+        .iter()
+        .filter(|quantity| ***quantity != MASS && ***quantity != ACCELERATION)
+        .map(|quantity| si_unit(quantity))
+}
+
+/// Evaluates `physics(unknown, quantity, value, ...)`.
+pub fn evaluate_physics_function(args: &[Value]) -> Result<Value, CalculatorError> {
+    Ok(PhysicsSolution::solve(args)?.result)
+}
+
+/// Multiplies or divides amounts when at least one is in the unit of a
+/// physics quantity, working out the unit of the result from what each unit
+/// is made of.
+///
+/// `2 A * 10 ohm` is `20 V`, `10 J / 2 s` is `5 W`, `2 N * 3 m` is `6 J`
+/// and `10 J / 2 W` is `5 seconds`; a result that no quantity measures is a
+/// power of the operands' unit, as `3 V * 2 V` is `6 V²`, or an error.
+/// `2 kg * 3 m/s²` is `6 N` too. Returns `None` unless one operand is in a
+/// physics unit, or a mass or an acceleration whose product or quotient is
+/// a quantity.
+pub fn quantity_op(
+    left: &Value,
+    op: BinaryOp,
+    right: &Value,
+) -> Option<Result<Value, CalculatorError>> {
+    let sign = match op {
+        BinaryOp::Multiply => 1,
+        BinaryOp::Divide => -1,
+        _ => return None,
+    };
+    let (left_dimension, left_amount) = unit_dimension(left)?;
+    let (right_dimension, right_amount) = unit_dimension(right)?;
+    let dimension: Dimension =
+        std::array::from_fn(|i| left_dimension[i] + sign * right_dimension[i]);
+    let physics_operand = [left, right]
+        .iter()
+        .any(|value| physics_unit_of(&value.unit).is_some());
+    let Some(unit) = dimension_unit(dimension, &left.unit, &right.unit) else {
+        return physics_operand.then(|| {
+            let name = if sign == 1 { "multiply" } else { "divide" };
+            Err(CalculatorError::unit_mismatch(
+                name,
+                &left.unit.display_name(),
+                &right.unit.display_name(),
+            ))
+        });
+    };
+    if !physics_operand
+        && !QUANTITIES
+            .iter()
+            .any(|quantity| si_unit(quantity) == unit && **quantity != MASS)
+    {
+        return None;
+    }
+    let amount = if sign == 1 {
+        left_amount * right_amount
+    } else if right_amount.is_zero() {
+        return Some(Err(CalculatorError::DivisionByZero));
+    } else {
+        left_amount / right_amount
+    };
+    let result = Value::rational_with_unit(amount, unit);
+    let inexact = [left, right]
+        .iter()
+        .any(|value| matches!(value.kind, ValueKind::Number(_)));
+    Some(Ok(if inexact { result.inexact() } else { result }))
+}
+
+/// Returns what the unit of `value` is made of, and the amount in the SI
+/// unit: `4.7 kohm` is `4700` of `[1, 2, -3, -2]`. Plain numbers, and units
+/// that are not made of the kilogram, meter, second and ampere, have none.
+fn unit_dimension(value: &Value) -> Option<(Dimension, Rational)> {
+    let amount = value.to_rational()?;
+    let (dimension, scale) = if let Some((quantity, power, exponent)) = physics_unit_of(&value.unit)
+    {
+        (
+            quantity.dimension.map(|d| d * exponent),
+            Rational::from_integer(10).pow_i32(power * exponent),
+        )
+    } else {
+        let base = value.unit.base_unit()?;
+        (
+            measure_dimension(&value.unit)?,
+            value.unit.exact_conversion_factor(&base)?,
+        )
+    };
+    Some((dimension, amount * scale))
+}
+
+/// Returns the physics quantity measured by `unit` or the power of which it
+/// is, with the power of ten of its prefix and the power of the unit: `kV²`
+/// is the voltage, 3 and 2.
+fn physics_unit_of(unit: &Unit) -> Option<(&'static Quantity, i32, i32)> {
+    let (base, exponent) = unit.base_and_exponent();
+    // Three letters read as a currency code, so `ohm` may arrive as `OHM`
+    let (Unit::Custom(name) | Unit::Currency(name)) = base else {
+        return None;
+    };
+    QUANTITIES
+        .iter()
+        .find_map(|quantity| unit_power(quantity, name).map(|power| (*quantity, power, exponent)))
+}
+
+/// Returns what a length, duration, mass, or a power or quotient of them,
+/// is made of: `km/h` is `[0, 1, -1, 0]`.
+fn measure_dimension(unit: &Unit) -> Option<Dimension> {
+    match unit {
+        Unit::Mass(_) => Some([1, 0, 0, 0]),
+        Unit::Length(_) => Some([0, 1, 0, 0]),
+        Unit::Duration(DurationUnit::Months | DurationUnit::Years) => None,
+        Unit::Duration(_) => Some([0, 0, 1, 0]),
+        Unit::Compound {
+            numerator,
+            denominator,
+        } => {
+            let (numerator, denominator) = (
+                measure_dimension(numerator)?,
+                measure_dimension(denominator)?,
+            );
+            Some(std::array::from_fn(|i| numerator[i] - denominator[i]))
+        }
+        Unit::Power { base, exponent } => Some(measure_dimension(base)?.map(|d| d * exponent)),
+        _ => None,
+    }
+}
+
+/// Returns the SI unit of what `dimension` measures: the unit of a physics
+/// quantity, a plain number, a length, duration or mass, or their quotient,
+/// or a power of the unit of `left` or `right`, as `V²`.
+fn dimension_unit(dimension: Dimension, left: &Unit, right: &Unit) -> Option<Unit> {
+    if dimension == [0; 4] {
+        return Some(Unit::None);
+    }
+    if let Some(quantity) = QUANTITIES
+        .iter()
+        .find(|quantity| quantity.dimension == dimension)
+    {
+        return Some(si_unit(quantity));
+    }
+    let power_of = |unit: &Unit| {
+        let (quantity, ..) = physics_unit_of(unit)?;
+        let (index, step) = quantity
+            .dimension
+            .iter()
+            .enumerate()
+            .find(|(_, d)| **d != 0)?;
+        let exponent = dimension[index] / step;
+        (quantity.dimension.map(|d| d * exponent) == dimension)
+            .then(|| Unit::power(si_unit(quantity), exponent))
+    };
+    if let Some(unit) = power_of(left).or_else(|| power_of(right)) {
+        return Some(unit);
+    }
+    if dimension[3] != 0 {
+        return None;
+    }
+    let bases = [
+        Unit::Mass(MassUnit::Kilogram),
+        Unit::Length(LengthUnit::Meter),
+        Unit::Duration(DurationUnit::Seconds),
+    ];
+    let factors = |sign: i32| {
+        let mut factors = bases
+            .iter()
+            .zip(dimension)
+            .filter(|(_, power)| power * sign > 0)
+            .map(|(base, power)| Unit::power(base.clone(), power * sign));
+        let unit = factors.next();
+        factors.next().is_none().then_some(unit)
+    };
+    match (factors(1)?, factors(-1)?) {
+        (Some(numerator), None) => Some(numerator),
+        (Some(numerator), Some(denominator)) => Some(Unit::per(numerator, denominator)),
+        _ => None,
+    }
+}
+
+/// Returns an amount of the unit of a physics quantity, such as `4700 ohm`
+/// or `2 kW`, in the quantity's SI unit, with that unit's symbol.
+pub(super) fn si_unit_amount(value: &Value) -> Option<(Rational, &'static str)> {
+    let (Unit::Custom(name) | Unit::Currency(name)) = &value.unit else {
+        return None;
+    };
+    let amount = value.to_rational()?;
+    QUANTITIES.iter().find_map(|quantity| {
+        unit_power(quantity, name).map(|power| {
+            (
+                amount.clone() * Rational::from_integer(10).pow_i32(power),
+                quantity.unit,
+            )
+        })
+    })
+}
+
+/// A physics formula solved for its unknown, and how it was solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhysicsSolution {
+    /// The formula, rearranged for the unknown, then with the given
+    /// quantities substituted.
+    pub formula: Vec<String>,
+    /// The unknown quantity in its SI unit.
+    pub result: Value,
+}
+
+impl PhysicsSolution {
+    /// Solves `physics(unknown, quantity, value, ...)`.
+    pub fn solve(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: String| CalculatorError::invalid_args("physics", reason);
+        let Some((unknown, givens)) = args
+            .split_first()
+            .filter(|(_, rest)| !rest.is_empty() && rest.len() % 2 == 0)
+        else {
+            return Err(invalid(
+                "expected the unknown and pairs of quantity and value, \
+                 e.g. voltage with current 2 A and resistance 10 ohm"
+                    .to_string(),
+            ));
+        };
+        let unknown = quantity_argument(unknown).map_err(invalid)?;
+        let mut known: Vec<(&Quantity, Rational)> = Vec::new();
+        for pair in givens.chunks(2) {
+            let quantity = quantity_argument(&pair[0]).map_err(invalid)?;
+            if quantity == unknown || known.iter().any(|(q, _)| *q == quantity) {
+                return Err(invalid(format!("the {} is given twice", quantity.name)));
+            }
+            known.push((quantity, si_amount(quantity, &pair[1]).map_err(invalid)?));
+        }
+
+        let formula = FORMULAS
+            .iter()
+            .find(|formula| {
+                let variables: Vec<_> = formula
+                    .terms
+                    .iter()
+                    .filter(|(q, _)| q.constant.is_none())
+                    .collect();
+                variables.len() == known.len() + 1
+                    && variables
+                        .iter()
+                        .all(|(q, _)| *q == unknown || known.iter().any(|(given, _)| given == q))
+            })
+            .ok_or_else(|| {
+                let equations: Vec<_> = FORMULAS.iter().map(|f| f.equation).collect();
+                let given: Vec<_> = known.iter().map(|(q, _)| q.name).collect();
+                invalid(format!(
+                    "no formula gives the {} from the {}; known formulas: {}",
+                    unknown.name,
+                    given.join(" and "),
+                    equations.join(", ")
+                ))
+            })?;
+        formula.solve_for(unknown, &known).map_err(invalid)
+    }
+}
+
+impl Formula {
+    /// Solves for `unknown`, whose power is ±1, from the other quantities.
+    fn solve_for(
+        &self,
+        unknown: &Quantity,
+        known: &[(&Quantity, Rational)],
+    ) -> Result<PhysicsSolution, String> {
+        let sign = self
+            .terms
+            .iter()
+            .find(|(q, _)| *q == unknown)
+            .map_or(1, |(_, power)| power.signum());
+        // unknown^sign = ∏ other^-power, so unknown = ∏ other^(-power * sign)
+        let others: Vec<_> = self
+            .terms
+            .iter()
+            .filter(|(q, _)| *q != unknown)
+            .map(|(q, power)| {
+                let amount = q.constant.map_or_else(
+                    || {
+                        known
+                            .iter()
+                            .find(|(given, _)| given == q)
+                            .map(|(_, amount)| amount.clone())
+                            .unwrap_or_default()
+                    },
+                    Rational::from_integer,
+                );
+                (*q, -power * sign, amount)
+            })
+            .collect();
+
+        let mut result = Rational::from_integer(1);
+        for (q, power, amount) in &others {
+            if *power < 0 && amount.is_zero() {
+                return Err(format!("the {} must not be zero", q.name));
+            }
+            result = result * amount.pow_i32(*power);
+        }
+
+        let symbolic = |q: &Quantity, power: i32| power_text(q.symbol, power.abs());
+        let substituted = |amount: &Rational, q: &Quantity, power: i32| {
+            let text = format!("{} {}", amount.to_display_string(), q.unit);
+            if power.abs() == 1 {
+                text
+            } else {
+                power_text(&format!("({text})"), power.abs())
+            }
+        };
+        let rearranged = quotient(
+            others
+                .iter()
+                .map(|(q, power, _)| (*power, symbolic(q, *power))),
+        );
+        let values = quotient(
+            others
+                .iter()
+                .map(|(q, power, amount)| (*power, substituted(amount, q, *power))),
+        );
+        Ok(PhysicsSolution {
+            formula: vec![
+                format!("{}: {}", self.name, self.equation),
+                format!(
+                    "Solve for {}: {} = {rearranged}",
+                    unknown.symbol, unknown.symbol
+                ),
+                format!("= {values}"),
+            ],
+            result: Value::rational_with_unit(result, si_unit(unknown)),
+        })
+    }
+}
+
+/// Returns the quantity named by a word argument.
+fn quantity_argument(value: &Value) -> Result<&'static Quantity, String> {
+    match &value.kind {
+        ValueKind::Text(word) => {
+            find_quantity(word).ok_or_else(|| format!("unknown quantity '{word}'"))
+        }
+        _ => Err(format!(
+            "expected a quantity name such as voltage, found {}",
+            value.to_display_string()
+        )),
+    }
+}
+
+fn find_quantity(word: &str) -> Option<&'static Quantity> {
+    QUANTITIES
+        .iter()
+        .copied()
+        .find(|quantity| quantity.name.eq_ignore_ascii_case(word))
+}
+
+/// Returns `value` in the SI unit of `quantity`, rejecting units of another
+/// dimension.
+fn si_amount(quantity: &Quantity, value: &Value) -> Result<Rational, String> {
+    let mismatch = || {
+        format!(
+            "the {} must be in {}, not {}",
+            quantity.name,
+            quantity.unit,
+            value.to_display_string()
+        )
+    };
+    let amount = value.to_rational().ok_or_else(mismatch)?;
+    let scale = match &value.unit {
+        Unit::None => Some(Rational::from_integer(1)),
+        unit => si_scale(quantity, unit),
+    };
+    Ok(amount * scale.ok_or_else(mismatch)?)
+}
+
+/// Returns the factor that turns an amount in `unit` into the SI unit of
+/// `quantity`, or `None` if the unit measures something else.
+fn si_scale(quantity: &Quantity, unit: &Unit) -> Option<Rational> {
+    match unit {
+        Unit::Mass(unit) if quantity == &MASS => {
+            Some(Rational::from_f64(unit.convert(1.0, MassUnit::Kilogram)))
+        }
+        Unit::Compound { .. } if quantity == &ACCELERATION => {
+            let si = Unit::per(
+                Unit::Length(LengthUnit::Meter),
+                Unit::power(Unit::Duration(DurationUnit::Seconds), 2),
+            );
+            unit.conversion_factor(&si).map(Rational::from_f64)
+        }
+        // Three letters read as a currency code, so `ohm` may arrive as `OHM`
+        Unit::Custom(name) | Unit::Currency(name) => {
+            unit_power(quantity, name).map(|power| Rational::from_integer(10).pow_i32(power))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the power of ten of a unit such as `mA` or `kohm` relative to the
+/// SI unit of `quantity`, or `None` if it measures something else.
+fn unit_power(quantity: &Quantity, name: &str) -> Option<i32> {
+    let is_unit = |name: &str| {
+        if quantity == &RESISTANCE {
+            matches!(name.to_lowercase().as_str(), "ω" | "ohm" | "ohms")
+        } else {
+            quantity != &MASS && quantity != &ACCELERATION && name == quantity.unit
+        }
+    };
+    if is_unit(name) {
+        return Some(0);
+    }
+    let prefix_len = name.chars().next()?.len_utf8();
+    let (prefix, rest) = name.split_at(prefix_len);
+    if is_unit(rest) {
+        prefix_power(prefix)
+    } else {
+        None
+    }
+}
+
+fn si_unit(quantity: &Quantity) -> Unit {
+    if quantity == &MASS {
+        Unit::Mass(MassUnit::Kilogram)
+    } else if quantity == &ACCELERATION {
+        Unit::per(
+            Unit::Length(LengthUnit::Meter),
+            Unit::power(Unit::Duration(DurationUnit::Seconds), 2),
+        )
+    } else {
+        Unit::Custom(quantity.unit.to_string())
+    }
+}
+
+fn power_text(base: &str, power: i32) -> String {
+    if power == 1 {
+        base.to_string()
+    } else {
+        format!("{base}^{power}")
+    }
+}
+
+/// Writes factors with positive powers over those with negative powers:
+/// `V / I`.
+fn quotient(factors: impl Iterator<Item = (i32, String)>) -> String {
+    let (numerator, denominator): (Vec<_>, Vec<_>) = factors.partition(|(power, _)| *power > 0);
+    let join = |factors: Vec<(i32, String)>| {
+        factors
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect::<Vec<_>>()
+            .join(" * ")
+    };
+    let count = denominator.len();
+    let (numerator, denominator) = (join(numerator), join(denominator));
+    let numerator = if numerator.is_empty() {
+        "1".to_string()
+    } else {
+        numerator
+    };
+    match count {
+        0 => numerator,
+        1 => format!("{numerator} / {denominator}"),
+        _ => format!("{numerator} / ({denominator})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ohms_law_solved_for_resistance() {
+        let word = Value::text;
+        let volts = |n| Value::from_integer_with_unit(n, Unit::Custom("V".to_string()));
+        let amps = |n| Value::from_integer_with_unit(n, Unit::Custom("A".to_string()));
+        let solution = PhysicsSolution::solve(&[
+            word("resistance"),
+            word("voltage"),
+            volts(20),
+            word("current"),
+            amps(2),
+        ])
+        .unwrap();
+        assert_eq!(solution.result.to_display_string(), "10 Ω");
+        assert_eq!(solution.formula[1], "Solve for R: R = V / I");
+        assert_eq!(solution.formula[2], "= 20 V / 2 A");
+    }
+
+    #[test]
+    fn test_unit_power() {
+        assert_eq!(unit_power(&RESISTANCE, "kohm"), Some(3));
+        assert_eq!(unit_power(&CURRENT, "mA"), Some(-3));
+        assert_eq!(unit_power(&CURRENT, "V"), None);
+        assert_eq!(unit_power(&MASS, "kg"), None);
+    }
+}
@@ -52,7 +52,7 @@ impl PolynomialForm {
                 if *unit != Unit::None {
                     return Err(Self::unsupported_equation());
                 }
-                Ok(Self::constant(Rational::from_decimal(*value)))
+                Ok(Self::constant(value.clone()))
             }
             Expression::Variable(name) => Ok(Self::variable(name.clone())),
             Expression::Binary { left, op, right } => {
@@ -74,16 +74,22 @@ impl PolynomialForm {
                     Self::from_expression(exponent)?.as_non_negative_integer_exponent()?;
                 base.pow(exponent)
             }
-            Expression::DateTime(_)
+            Expression::Text(_)
+            | Expression::DateTime(_)
             | Expression::Now
             | Expression::Today
+            | Expression::HistoryRef(_)
+            | Expression::HistoryRange(..)
             | Expression::Until(_)
             | Expression::AtTime { .. }
             | Expression::FunctionCall { .. }
             | Expression::IndefiniteIntegral { .. }
             | Expression::UnitConversion { .. }
+            | Expression::WithPrecision { .. }
+            | Expression::RateLookup { .. }
             | Expression::Equality { .. }
-            | Expression::Comparison { .. } => Err(Self::unsupported_equation()),
+            | Expression::Comparison { .. }
+            | Expression::List(_) => Err(Self::unsupported_equation()),
         }
     }
 
@@ -420,7 +426,7 @@ fn binomial_power_roots(polynomial: &PolynomialForm) -> Option<Vec<Rational>> {
         return Some(Vec::new());
     }
 
-    let root = rational_nth_root(&target, degree)?;
+    let root = target.nth_root(degree)?;
     if degree % 2 == 0 {
         let negative_root = -root.clone();
         Some(vec![negative_root, root])
@@ -456,58 +462,7 @@ fn quadratic_roots(polynomial: &PolynomialForm) -> Vec<Rational> {
 }
 
 fn rational_square_root(value: &Rational) -> Option<Rational> {
-    rational_nth_root(value, 2)
-}
-
-fn rational_nth_root(value: &Rational, degree: u32) -> Option<Rational> {
-    if value.is_negative() {
-        if degree % 2 == 0 {
-            return None;
-        }
-
-        return rational_nth_root(&value.abs(), degree).map(std::ops::Neg::neg);
-    }
-
-    let numerator = u128::try_from(value.numer()).ok()?;
-    let denominator = u128::try_from(value.denom()).ok()?;
-    let numerator_root = integer_nth_root(numerator, degree)?;
-    let denominator_root = integer_nth_root(denominator, degree)?;
-
-    Some(Rational::new(
-        i128::try_from(numerator_root).ok()?,
-        i128::try_from(denominator_root).ok()?,
-    ))
-}
-
-fn integer_nth_root(value: u128, degree: u32) -> Option<u128> {
-    let mut low = 0;
-    let mut high = value;
-
-    while low <= high {
-        let midpoint = low + (high - low) / 2;
-        match checked_pow_u128(midpoint, degree) {
-            Some(power) if power == value => return Some(midpoint),
-            Some(power) if power < value => low = midpoint + 1,
-            _ => {
-                if midpoint == 0 {
-                    break;
-                }
-                high = midpoint - 1;
-            }
-        }
-    }
-
-    None
-}
-
-fn checked_pow_u128(base: u128, exponent: u32) -> Option<u128> {
-    let mut result = 1_u128;
-
-    for _ in 0..exponent {
-        result = result.checked_mul(base)?;
-    }
-
-    Some(result)
+    value.nth_root(2)
 }
 
 fn rational_root_candidates(polynomial: &PolynomialForm) -> BTreeSet<Rational> {
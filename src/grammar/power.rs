@@ -0,0 +1,298 @@
+//! Exponentiation, shared by every evaluation path.
+
+use super::number_grammar::MAX_EXPONENT;
+use super::physics::is_physics_unit;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Decimal, Rational, Unit, Value, ValueKind};
+
+/// The highest root taken exactly; a longer decimal exponent such as
+/// `2^0.123456789` has no rational result anyway.
+const MAX_ROOT_DEGREE: u32 = 1000;
+
+/// Significant digits an irrational root is taken to, a few more than a
+/// Decimal keeps so that raising it to a power leaves 28 correct.
+const ROOT_DIGITS: u32 = 32;
+
+/// The most bits the numerator and denominator of a base may have for its
+/// root to be taken on its digits; the integer roots slow down past it, so
+/// larger bases are taken on a log scale.
+const MAX_ROOT_BITS: u64 = 100_000;
+
+/// The largest power an irrational root is raised to; each power adds to
+/// the error of the rounded root.
+const MAX_ROOT_POWER: i32 = 100;
+
+/// Evaluates a power expression, using exact rational arithmetic when possible.
+///
+/// When both base and exponent are rational and the exponent is an integer
+/// that fits in i32, the computation is exact (arbitrary precision). A
+/// fractional exponent is exact too when the root is rational, so `8^(1/3)`
+/// is 2 and `4^(3/2)` is 8, and an irrational root is taken to 28 digits.
+/// Otherwise, falls back to f64 computation.
+///
+/// This function is exposed so downstream consumers can reproduce the
+/// exact-versus-floating-point fallback used inside the evaluator.
+pub fn evaluate_power(base_val: &Value, exp_val: &Value) -> Result<Value, CalculatorError> {
+    if let Some(result) = base_val.matrix_power(exp_val) {
+        return result;
+    }
+    if let Some(result) = Value::broadcast(base_val, exp_val, evaluate_power) {
+        return result;
+    }
+    if let Some(result) = base_val.symbolic_power(exp_val) {
+        return Ok(result);
+    }
+
+    // Try exact rational exponentiation first
+    if let (Some(base_rat), Some(exp_rat)) = (base_val.to_rational(), exp_val.to_rational()) {
+        if exp_rat.is_integer() {
+            // Check exponent fits in i32 (reasonable range for exact computation)
+            let exp_i128 = exp_rat.numer();
+            if let Ok(exp_i32) = i32::try_from(exp_i128) {
+                // Guard against absurdly large exponents that would consume too much memory
+                if exp_i32.abs() <= 1_000_000 {
+                    if exp_i32 < 0 && base_rat.is_zero() {
+                        return Err(CalculatorError::domain(
+                            "division by zero (negative exponent with zero base)",
+                        ));
+                    }
+                    let result = Value::rational_with_unit(
+                        base_rat.pow_i32(exp_i32),
+                        power_unit(&base_val.unit, exp_i32)?,
+                    );
+                    return Ok(match base_val.kind {
+                        ValueKind::Number(_) => result.inexact(),
+                        _ => result,
+                    });
+                }
+            }
+        } else if base_val.unit.has_dimension() {
+            return root_of_unit(base_val, &base_rat, exp_val, &exp_rat);
+        } else if base_val.unit == Unit::None && base_val.as_symbolic().is_none() {
+            if let Some(result) = exact_root_power(&base_rat, &exp_rat) {
+                return Ok(Value::rational(result));
+            }
+            if let Some(result) = rounded_root_power(&base_rat, &exp_rat) {
+                return Ok(Value::number(result));
+            }
+        }
+    }
+
+    // Fallback to f64 for non-integer exponents or very large exponents,
+    // and to a log scale for bases too large or small for a Decimal
+    let exact_zero = base_val.to_rational().map_or(true, |r| r.is_zero());
+    let Some(base_dec) = base_val.as_decimal().filter(|d| !d.is_zero() || exact_zero) else {
+        let base = base_val.to_rational().ok_or_else(|| {
+            CalculatorError::InvalidOperation("power base must be numeric".into())
+        })?;
+        let exponent = exp_val.to_rational().ok_or_else(|| {
+            CalculatorError::InvalidOperation("power exponent must be numeric".into())
+        })?;
+        return scaled_power(&base, exponent.to_f64());
+    };
+    let exp_dec = exp_val.as_decimal().ok_or_else(|| {
+        CalculatorError::InvalidOperation("power exponent must be numeric".into())
+    })?;
+
+    let base_f64 = base_dec.to_f64();
+    let exp_f64 = exp_dec.to_f64();
+    let result = base_f64.powf(exp_f64);
+
+    if result.is_nan() {
+        return Err(CalculatorError::domain("power result is undefined"));
+    }
+    match Decimal::try_from_f64(result) {
+        Some(result) => Ok(Value::number(result)),
+        None => scaled_power(&Rational::from_decimal(base_dec), exp_f64),
+    }
+}
+
+/// Raises `base`, past the range of a Decimal, to `exponent` on a log
+/// scale: `(2^1024)^(1/1024)` is 2. The result keeps the digits the
+/// logarithm is accurate to.
+fn scaled_power(base: &Rational, exponent: f64) -> Result<Value, CalculatorError> {
+    if base.is_negative() && exponent.fract() != 0.0 {
+        return Err(CalculatorError::domain("power result is undefined"));
+    }
+    let magnitude = base.abs();
+    let leading = magnitude
+        .decimal_exponent()
+        .ok_or(CalculatorError::Overflow)?;
+    let mantissa = magnitude
+        .mantissa(leading, Rational::SCIENTIFIC_DECIMALS)
+        .ok_or(CalculatorError::Overflow)?;
+    let log10 = exponent * (f64::from(leading) + mantissa.to_f64().log10());
+    if !log10.is_finite() || log10.abs() > f64::from(MAX_EXPONENT) {
+        return Err(CalculatorError::Overflow);
+    }
+    let power = log10.floor();
+    // The logarithm is a digit short of a float's, and each digit of the
+    // power takes another from the mantissa
+    let int_digits = power.abs().max(1.0).log10().floor() + 1.0;
+    // Both are within 0..=16 and MAX_EXPONENT, so the casts are exact
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (decimals, power) = (
+        (f64::from(Rational::SCIENTIFIC_DECIMALS) - int_digits).max(0.0) as usize,
+        power as i32,
+    );
+    let digits = format!("{:.decimals$}", 10f64.powf(log10 - f64::from(power)));
+    let result = Rational::parse_decimal_digits(&digits).ok_or(CalculatorError::Overflow)?
+        * Rational::from_integer(10).pow_i32(power);
+    let odd = base.is_negative() && exponent % 2.0 != 0.0;
+    Ok(Value::rational(if odd { -result } else { result }).inexact())
+}
+
+/// Divides a plain number by an amount in a unit as the number times the
+/// amount to the power -1, so `1 / (2 m)` is `0.5 m⁻¹` like `(2 m)^-1`.
+/// Returns `None` for other operations, and for units without powers.
+pub(super) fn divide_by_unit(
+    left: &Value,
+    op: BinaryOp,
+    right: &Value,
+) -> Option<Result<Value, CalculatorError>> {
+    if op != BinaryOp::Divide
+        || left.unit != Unit::None
+        || right.unit == Unit::None
+        || power_unit(&right.unit, -1).is_err()
+    {
+        return None;
+    }
+    left.to_rational()?;
+    if right.to_rational()?.is_zero() {
+        return Some(Err(CalculatorError::DivisionByZero));
+    }
+    Some(
+        evaluate_power(right, &Value::from_integer(-1))
+            .and_then(|reciprocal| left.multiply(&reciprocal)),
+    )
+}
+
+/// Raises an amount in a unit to a fraction, when the unit has that root:
+/// `(9 m²)^0.5` is `3 m`, but `(2 m)^0.5` has no unit to be in.
+fn root_of_unit(
+    base_val: &Value,
+    base: &Rational,
+    exp_val: &Value,
+    exponent: &Rational,
+) -> Result<Value, CalculatorError> {
+    let unit = fractional_power_unit(&base_val.unit, exponent)?.ok_or_else(|| {
+        CalculatorError::InvalidOperation(format!(
+            "cannot raise {} to the power {}",
+            base_val.unit.display_name(),
+            exp_val.to_display_string()
+        ))
+    })?;
+    let amount = evaluate_power(&Value::rational(base.clone()), exp_val)?;
+    Ok(Value {
+        unit,
+        ..match base_val.kind {
+            ValueKind::Number(_) => amount.inexact(),
+            _ => amount,
+        }
+    })
+}
+
+/// The unit of an amount raised to the fraction `exponent`, taking the root
+/// of each part of a compound unit: `(16 m²/s²)^0.5` is in `m/s`. Returns
+/// `None` when a power of the unit does not divide evenly.
+fn fractional_power_unit(
+    unit: &Unit,
+    exponent: &Rational,
+) -> Result<Option<Unit>, CalculatorError> {
+    if let Unit::Compound {
+        numerator,
+        denominator,
+    } = unit
+    {
+        let numerator = fractional_power_unit(numerator, exponent)?;
+        let denominator = fractional_power_unit(denominator, exponent)?;
+        return Ok(numerator.zip(denominator).map(|(n, d)| Unit::per(n, d)));
+    }
+    let (base, power) = unit.base_and_exponent();
+    let power = Rational::from_integer(i128::from(power)) * exponent.clone();
+    match i32::try_from(power.numer()) {
+        Ok(whole) if power.is_integer() => power_unit(base, whole).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Raises `base` to the fraction `exponent` when its root is rational,
+/// taking the root first: `4^(3/2)` is `(√4)³`. Returns `None` when the
+/// result is irrational.
+fn exact_root_power(base: &Rational, exponent: &Rational) -> Option<Rational> {
+    if !root_in_budget(base) {
+        return None;
+    }
+    let degree = u32::try_from(exponent.denom())
+        .ok()
+        .filter(|degree| *degree <= MAX_ROOT_DEGREE)?;
+    let power = i32::try_from(exponent.numer())
+        .ok()
+        .filter(|power| power.abs() <= 1_000_000)?;
+    if power < 0 && base.is_zero() {
+        return None;
+    }
+    Some(base.nth_root(degree)?.pow_i32(power))
+}
+
+/// Raises `base` to the fraction `exponent` when its root is irrational,
+/// rounding the root to [`ROOT_DIGITS`] digits rather than through an f64,
+/// which would make `99999999999999999999^(1/2)` a whole number. Returns
+/// `None` when the exponent is too long a fraction to take this way, or
+/// the result is past the range of a Decimal.
+fn rounded_root_power(base: &Rational, exponent: &Rational) -> Option<Decimal> {
+    if !root_in_budget(base) {
+        return None;
+    }
+    let degree = u32::try_from(exponent.denom())
+        .ok()
+        .filter(|degree| *degree <= MAX_ROOT_DEGREE)?;
+    let power = i32::try_from(exponent.numer())
+        .ok()
+        .filter(|power| power.abs() <= MAX_ROOT_POWER)?;
+    if base.is_zero() {
+        return None;
+    }
+    base.root_to_digits(degree, ROOT_DIGITS)?
+        .pow_i32(power)
+        .to_decimal()
+}
+
+/// Returns true if `base` is small enough for its root to be taken on its
+/// digits, within [`MAX_ROOT_BITS`].
+fn root_in_budget(base: &Rational) -> bool {
+    base.numer_bigint().bits() + base.denom_bigint().bits() <= MAX_ROOT_BITS
+}
+
+/// The unit of an amount raised to `exponent`: `(3 m)^2` is in `m²`,
+/// `(3 V)^2` in `V²` and `(3 m/s)^2` in `m²/s²`. Units that have no powers,
+/// such as currencies, can only be raised to the first power.
+fn power_unit(unit: &Unit, exponent: i32) -> Result<Unit, CalculatorError> {
+    let (base, power) = unit.base_and_exponent();
+    match base {
+        Unit::None => Ok(Unit::None),
+        _ if exponent == 1 => Ok(unit.clone()),
+        Unit::Compound {
+            numerator,
+            denominator,
+        } if power == 1 && exponent < 0 => Ok(Unit::per(
+            power_unit(denominator, -exponent)?,
+            power_unit(numerator, -exponent)?,
+        )),
+        Unit::Compound {
+            numerator,
+            denominator,
+        } if power == 1 => Ok(Unit::per(
+            power_unit(numerator, exponent)?,
+            power_unit(denominator, exponent)?,
+        )),
+        Unit::Custom(name) | Unit::Currency(name) if is_physics_unit(name) => {
+            Ok(Unit::power(base.clone(), power.saturating_mul(exponent)))
+        }
+        _ if base.is_powerable() => Ok(Unit::power(base.clone(), power.saturating_mul(exponent))),
+        _ => Err(CalculatorError::InvalidOperation(format!(
+            "cannot raise {} to a power",
+            unit.display_name()
+        ))),
+    }
+}
@@ -0,0 +1,207 @@
+//! Proportions written in words:
+//! - `percent_change(old, new)`, written `percent change from 80 to 100`
+//! - `percent_of_total(part, whole)`, written `100 is what percent of 250`
+//! - `ratio_split(total, shares...)`, written `ratio 3:4 of 140`
+//!
+//! Each result keeps the formula it was computed with, so that steps can
+//! show how it was reached.
+
+use crate::error::CalculatorError;
+use crate::types::{Rational, Unit, Value};
+
+/// The proportion functions.
+pub const PROPORTION_FUNCTIONS: &[&str] = &["percent_change", "percent_of_total", "ratio_split"];
+
+/// Returns true if `name` is a proportion function.
+#[must_use]
+pub fn is_proportion_function(name: &str) -> bool {
+    PROPORTION_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Evaluates a proportion function.
+pub fn evaluate_proportion_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    Ok(Proportion::compute(name, args)?.result)
+}
+
+/// The result of a proportion function and the formula behind it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proportion {
+    /// The formula in words, then with the arguments substituted.
+    pub formula: Vec<String>,
+    /// A percentage such as `25%`, or the list of shares of a ratio split.
+    pub result: Value,
+}
+
+impl Proportion {
+    /// Computes `percent_change(old, new)`, `percent_of_total(part, whole)`
+    /// or `ratio_split(total, shares...)`.
+    pub fn compute(name: &str, args: &[Value]) -> Result<Self, CalculatorError> {
+        let name = name.to_lowercase();
+        match name.as_str() {
+            "percent_change" => Self::percent_change(args),
+            "percent_of_total" => Self::percent_of_total(args),
+            _ => Self::ratio_split(args),
+        }
+    }
+
+    fn percent_change(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("percent_change", reason);
+        let [old, new] = args else {
+            return Err(invalid("expected an old and a new value"));
+        };
+        let (from, to) = same_unit_amounts(old, new)
+            .ok_or_else(|| invalid("expected two numbers in the same unit"))?;
+        if from.is_zero() {
+            return Err(invalid("the change from zero is not a percentage"));
+        }
+        let change = (to - from.clone()) / from * Rational::from_integer(100);
+        Ok(Self {
+            formula: vec![
+                "Percent change: (new - old) / old * 100".to_string(),
+                format!(
+                    "= ({} - {}) / {} * 100",
+                    new.to_display_string(),
+                    old.to_display_string(),
+                    old.to_display_string()
+                ),
+            ],
+            result: percentage(&change),
+        })
+    }
+
+    fn percent_of_total(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("percent_of_total", reason);
+        let [part, whole] = args else {
+            return Err(invalid("expected a part and a whole"));
+        };
+        let (amount, total) = same_unit_amounts(part, whole)
+            .ok_or_else(|| invalid("expected two numbers in the same unit"))?;
+        if total.is_zero() {
+            return Err(invalid("the whole must not be zero"));
+        }
+        let share = amount / total * Rational::from_integer(100);
+        Ok(Self {
+            formula: vec![
+                "Percent of total: part / whole * 100".to_string(),
+                format!(
+                    "= {} / {} * 100",
+                    part.to_display_string(),
+                    whole.to_display_string()
+                ),
+            ],
+            result: percentage(&share),
+        })
+    }
+
+    fn ratio_split(args: &[Value]) -> Result<Self, CalculatorError> {
+        let invalid = |reason: &str| CalculatorError::invalid_args("ratio_split", reason);
+        let [total, shares @ ..] = args else {
+            return Err(invalid("expected a total and the shares of the ratio"));
+        };
+        if shares.len() < 2 {
+            return Err(invalid("a ratio needs at least two shares, e.g. 3:4"));
+        }
+        let amount = total
+            .to_rational()
+            .ok_or_else(|| invalid("expected a numeric total"))?;
+        let shares = shares
+            .iter()
+            .map(|share| {
+                share
+                    .to_rational()
+                    .filter(|share| !share.is_negative())
+                    .filter(|_| share.unit == Unit::None)
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid("the shares must be non-negative numbers"))?;
+        let sum = shares
+            .iter()
+            .fold(Rational::zero(), |sum, share| sum + share.clone());
+        if sum.is_zero() {
+            return Err(invalid("the shares must not all be zero"));
+        }
+
+        let substituted: Vec<_> = shares
+            .iter()
+            .map(|share| {
+                format!(
+                    "{} * {} / {}",
+                    total.to_display_string(),
+                    share.to_display_string(),
+                    sum.to_display_string()
+                )
+            })
+            .collect();
+        let parts = shares
+            .into_iter()
+            .map(|share| {
+                Value::rational_with_unit(amount.clone() * share / sum.clone(), total.unit.clone())
+            })
+            .collect();
+        Ok(Self {
+            formula: vec![
+                "Ratio split: total * share / sum of shares".to_string(),
+                format!("= [{}]", substituted.join(", ")),
+            ],
+            result: Value::list(parts),
+        })
+    }
+}
+
+/// Returns both amounts as rationals if they are numbers in the same unit.
+fn same_unit_amounts(a: &Value, b: &Value) -> Option<(Rational, Rational)> {
+    if a.unit != b.unit {
+        return None;
+    }
+    Some((a.to_rational()?, b.to_rational()?))
+}
+
+/// Shows a percentage such as `25%`.
+fn percentage(percent: &Rational) -> Value {
+    Value::text(format!("{}%", percent.to_display_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_change_and_share() {
+        let change = Proportion::compute(
+            "percent_change",
+            &[Value::from_integer(80), Value::from_integer(100)],
+        )
+        .unwrap();
+        assert_eq!(change.result.to_display_string(), "25%");
+        assert_eq!(change.formula[1], "= (100 - 80) / 80 * 100");
+
+        let share = evaluate_proportion_function(
+            "percent_of_total",
+            &[Value::from_integer(100), Value::from_integer(250)],
+        )
+        .unwrap();
+        assert_eq!(share.to_display_string(), "40%");
+    }
+
+    #[test]
+    fn test_ratio_split() {
+        let split = Proportion::compute(
+            "ratio_split",
+            &[
+                Value::from_integer(140),
+                Value::from_integer(3),
+                Value::from_integer(4),
+            ],
+        )
+        .unwrap();
+        assert_eq!(split.result.to_display_string(), "[60, 80]");
+        assert_eq!(split.formula[1], "= [140 * 3 / 7, 140 * 4 / 7]");
+        assert!(Proportion::compute(
+            "ratio_split",
+            &[Value::from_integer(140), Value::from_integer(3)]
+        )
+        .is_err());
+    }
+}
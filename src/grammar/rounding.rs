@@ -0,0 +1,82 @@
+//! Rounding and sign functions, computed exactly on rationals and keeping
+//! the unit of their argument:
+//! - `abs(x)`, `floor(x)`, `ceil(x)`, `trunc(x)`: `floor(2.7 kg)` is `2 kg`
+//! - `round(x)` and `round(x, places)`, halves away from zero:
+//!   `round(2.675, 2)` is `2.68`, `round(1250, -2)` is `1300`
+//! - `sign(x)` (also `signum`): `-1`, `0` or `1`
+
+use crate::error::CalculatorError;
+use crate::types::{Rational, Value};
+
+/// The most decimal places `round(x, places)` accepts either way.
+const MAX_PLACES: i32 = 28;
+
+/// The rounding and sign functions.
+pub const ROUNDING_FUNCTIONS: &[&str] =
+    &["abs", "floor", "ceil", "round", "trunc", "sign", "signum"];
+
+/// Returns true if `name` is a rounding or sign function.
+#[must_use]
+pub fn is_rounding_function(name: &str) -> bool {
+    ROUNDING_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Evaluates `abs`, `floor`, `ceil`, `round`, `trunc` or `sign`.
+pub fn evaluate_rounding_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let (x, places) = match (name.as_str(), args) {
+        (_, [x]) => (x, None),
+        ("round", [x, places]) => (x, Some(places)),
+        ("round", _) => {
+            return Err(CalculatorError::invalid_args(
+                &name,
+                "expected 1 or 2 arguments: round(x) or round(x, places)",
+            ))
+        }
+        _ => {
+            return Err(CalculatorError::invalid_args(
+                &name,
+                format!("expected 1 argument: {name}(x)"),
+            ))
+        }
+    };
+    let number = x
+        .to_rational()
+        .ok_or_else(|| CalculatorError::invalid_args(&name, "expected numeric argument"))?;
+
+    let result = match name.as_str() {
+        "abs" => number.abs(),
+        "floor" => number.floor(),
+        "ceil" => number.ceil(),
+        "trunc" => number.trunc(),
+        "round" => number.round_to_places(places.map_or(Ok(0), decimal_places)?),
+        _ => {
+            let sign = if number.is_zero() {
+                0
+            } else if number.is_negative() {
+                -1
+            } else {
+                1
+            };
+            return Ok(Value::rational(Rational::from_integer(sign)));
+        }
+    };
+    Ok(Value::rational_with_unit(result, x.unit.clone()))
+}
+
+fn decimal_places(places: &Value) -> Result<i32, CalculatorError> {
+    places
+        .to_rational()
+        .filter(|places| {
+            places.is_integer() && places.abs() <= Rational::from_integer(MAX_PLACES.into())
+        })
+        .and_then(|places| i32::try_from(places.numer()).ok())
+        .ok_or_else(|| {
+            CalculatorError::invalid_args(
+                "round",
+                format!("places must be an integer between -{MAX_PLACES} and {MAX_PLACES}"),
+            )
+        })
+}
@@ -0,0 +1,526 @@
+//! Expression simplification: `simplify (x^2 + 2x + 1)/(x+1)` gives `x + 1`.
+//!
+//! A small rewrite-rule engine applies [`RULES`] in turn until none of them
+//! changes the expression. Like terms are combined by reading sums and
+//! products as polynomials with exact coefficients, in which anything that is
+//! not polynomial (`sin(x)`, `1/x`) is kept as an opaque factor.
+
+use std::collections::BTreeMap;
+
+use super::symbolic::{
+    add, div, divides_by_zero, int, mul, neg, num, pow, strip_groups, sub, var, with_groups,
+};
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Expression, Rational, Unit, Value};
+
+/// Rounds of rule applications before giving up on reaching a fixed point.
+const MAX_ROUNDS: usize = 8;
+/// Highest degree and term count of the polynomials built while simplifying.
+const MAX_DEGREE: u32 = 20;
+const MAX_TERMS: usize = 200;
+
+/// A rewrite rule, returning the expression unchanged if it does not apply.
+type Rule = fn(&Expression) -> Expression;
+
+/// Constant folding, then cancelling common factors of quotients, then
+/// combining like terms.
+const RULES: &[Rule] = &[fold_constants, cancel_common_factors, combine_like_terms];
+
+/// Simplifies an expression symbolically.
+#[must_use]
+pub fn simplify(expr: &Expression) -> Expression {
+    with_groups(&simplify_ungrouped(expr))
+}
+
+/// Evaluates `simplify(expr)`, reporting the simplified form as a symbolic
+/// result.
+pub fn evaluate_simplify(args: &[Expression]) -> Result<Value, CalculatorError> {
+    let [expr] = args else {
+        return Err(CalculatorError::invalid_args(
+            "simplify",
+            "expected one expression, e.g. simplify (x^2 - 1)/(x - 1)",
+        ));
+    };
+    let result = simplify(expr);
+    if divides_by_zero(&result) {
+        return Err(CalculatorError::DivisionByZero);
+    }
+    Err(CalculatorError::SymbolicResult {
+        expression: Expression::function_call("simplify", vec![expr.clone()]).to_lino(),
+        result: result.to_string(),
+        latex_input: format!(
+            "\\operatorname{{simplify}}\\left({}\\right)",
+            expr.to_latex()
+        ),
+        latex_result: result.to_latex(),
+    })
+}
+
+fn simplify_ungrouped(expr: &Expression) -> Expression {
+    let mut current = strip_groups(expr);
+    for _ in 0..MAX_ROUNDS {
+        let mut changed = false;
+        for rule in RULES {
+            let rewritten = rule(&current);
+            if rewritten != current {
+                current = rewritten;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    current
+}
+
+/// Rebuilds the expression bottom-up with the folding builders, so
+/// `2 * 3 * x + 0` becomes `6 * x`.
+fn fold_constants(expr: &Expression) -> Expression {
+    rewrite_bottom_up(expr, &|e| e)
+}
+
+/// Cancels the common polynomial factors of every quotient, so
+/// `(x^2 - 1)/(x - 1)` becomes `x + 1`.
+fn cancel_common_factors(expr: &Expression) -> Expression {
+    rewrite_bottom_up(expr, &|e| match &e {
+        Expression::Binary {
+            left,
+            op: BinaryOp::Divide,
+            right,
+        } => cancel(left, right).unwrap_or(e),
+        _ => e,
+    })
+}
+
+/// Collects the terms of sums and products, so `x + 2x - 1 + 1` becomes
+/// `3 * x`.
+fn combine_like_terms(expr: &Expression) -> Expression {
+    let mut atoms = Atoms::default();
+    Polynomial::from_expression(expr, &mut atoms)
+        .and_then(|polynomial| polynomial.to_expression(&atoms))
+        .unwrap_or_else(|| expr.clone())
+}
+
+/// Rebuilds `expr` from its leaves with the folding builders, applying
+/// `rewrite` to every rebuilt node.
+fn rewrite_bottom_up(expr: &Expression, rewrite: &dyn Fn(Expression) -> Expression) -> Expression {
+    let rebuilt = match expr {
+        Expression::Binary { left, op, right } => {
+            let left = rewrite_bottom_up(left, rewrite);
+            let right = rewrite_bottom_up(right, rewrite);
+            match op {
+                BinaryOp::Add => add(left, right),
+                BinaryOp::Subtract => sub(left, right),
+                BinaryOp::Multiply => mul(left, right),
+                BinaryOp::Divide => div(left, right),
+                BinaryOp::Modulo => Expression::binary(left, *op, right),
+            }
+        }
+        Expression::Power { base, exponent } => pow(
+            rewrite_bottom_up(base, rewrite),
+            rewrite_bottom_up(exponent, rewrite),
+        ),
+        Expression::Negate(inner) => neg(rewrite_bottom_up(inner, rewrite)),
+        Expression::Group(inner) => rewrite_bottom_up(inner, rewrite),
+        Expression::FunctionCall { name, args } => Expression::function_call(
+            name.clone(),
+            args.iter()
+                .map(|arg| rewrite_bottom_up(arg, rewrite))
+                .collect(),
+        ),
+        other => other.clone(),
+    };
+    rewrite(rebuilt)
+}
+
+/// Cancels the common factors of `numerator / denominator`, if they have any.
+fn cancel(numerator: &Expression, denominator: &Expression) -> Option<Expression> {
+    let mut atoms = Atoms::default();
+    let numerator = Polynomial::from_expression(numerator, &mut atoms)?;
+    let denominator = Polynomial::from_expression(denominator, &mut atoms)?;
+    if denominator.constant().is_some() {
+        return None;
+    }
+    let (numerator, denominator) = numerator.cancel(&denominator)?;
+    match denominator.constant() {
+        Some(constant) => numerator
+            .scale(&(Rational::one() / constant))
+            .to_expression(&atoms),
+        None => Some(div(
+            numerator.to_expression(&atoms)?,
+            denominator.to_expression(&atoms)?,
+        )),
+    }
+}
+
+/// A variable, or an opaque subexpression such as `sin(x)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Factor {
+    Variable(String),
+    Atom(usize),
+}
+
+/// The opaque subexpressions of a polynomial, indexed by [`Factor::Atom`].
+#[derive(Default)]
+struct Atoms(Vec<Expression>);
+
+impl Atoms {
+    fn intern(&mut self, expr: Expression) -> Factor {
+        let index = self
+            .0
+            .iter()
+            .position(|atom| *atom == expr)
+            .unwrap_or_else(|| {
+                self.0.push(expr);
+                self.0.len() - 1
+            });
+        Factor::Atom(index)
+    }
+}
+
+/// A product of factors with positive exponents, sorted by factor.
+type Monomial = Vec<(Factor, u32)>;
+
+/// A polynomial in several factors with exact coefficients.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Polynomial {
+    terms: BTreeMap<Monomial, Rational>,
+}
+
+impl Polynomial {
+    fn constant_term(value: Rational) -> Self {
+        Self::monomial(Vec::new(), value)
+    }
+
+    fn monomial(monomial: Monomial, coefficient: Rational) -> Self {
+        let mut terms = BTreeMap::new();
+        if !coefficient.is_zero() {
+            terms.insert(monomial, coefficient);
+        }
+        Self { terms }
+    }
+
+    fn factor(factor: Factor) -> Self {
+        Self::monomial(vec![(factor, 1)], Rational::one())
+    }
+
+    /// Reads `expr` as a polynomial, or `None` if it would grow too large.
+    fn from_expression(expr: &Expression, atoms: &mut Atoms) -> Option<Self> {
+        let polynomial = match expr {
+            Expression::Number {
+                value,
+                unit: Unit::None,
+                ..
+            } => Self::constant_term(value.clone()),
+            Expression::Variable(name) => Self::factor(Factor::Variable(name.clone())),
+            Expression::Group(inner) => Self::from_expression(inner, atoms)?,
+            Expression::Negate(inner) => Self::from_expression(inner, atoms)?.negate(),
+            Expression::Binary { left, op, right } => {
+                let left_polynomial = Self::from_expression(left, atoms)?;
+                let right_polynomial = Self::from_expression(right, atoms)?;
+                match op {
+                    BinaryOp::Add => left_polynomial.add(&right_polynomial),
+                    BinaryOp::Subtract => left_polynomial.add(&right_polynomial.negate()),
+                    BinaryOp::Multiply => left_polynomial.multiply(&right_polynomial)?,
+                    BinaryOp::Divide => match right_polynomial.constant() {
+                        Some(divisor) if !divisor.is_zero() => {
+                            left_polynomial.scale(&(Rational::one() / divisor))
+                        }
+                        _ => {
+                            let quotient = div(simplify_ungrouped(left), simplify_ungrouped(right));
+                            Self::factor(atoms.intern(quotient))
+                        }
+                    },
+                    BinaryOp::Modulo => Self::factor(atoms.intern(expr.clone())),
+                }
+            }
+            Expression::Power { base, exponent } => {
+                let exponent = Self::from_expression(exponent, atoms)?.constant();
+                match exponent.filter(|n| n.is_integer() && !n.is_negative()) {
+                    Some(n) if n.numer() <= i128::from(MAX_DEGREE) => {
+                        #[allow(clippy::cast_sign_loss)] // non-negative, checked above
+                        let n = n.numer() as u32;
+                        Self::from_expression(base, atoms)?.pow(n)?
+                    }
+                    _ => Self::factor(atoms.intern(expr.clone())),
+                }
+            }
+            Expression::FunctionCall { name, args } => {
+                let args = args.iter().map(simplify_ungrouped).collect();
+                Self::factor(atoms.intern(Expression::function_call(name.clone(), args)))
+            }
+            other => Self::factor(atoms.intern(other.clone())),
+        };
+        (polynomial.terms.len() <= MAX_TERMS).then_some(polynomial)
+    }
+
+    /// Returns the value if this is a constant.
+    fn constant(&self) -> Option<Rational> {
+        match self.terms.iter().next() {
+            None => Some(Rational::zero()),
+            Some((monomial, coefficient)) if monomial.is_empty() && self.terms.len() == 1 => {
+                Some(coefficient.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn add(mut self, other: &Self) -> Self {
+        for (monomial, coefficient) in &other.terms {
+            let sum = self.terms.get(monomial).cloned().unwrap_or_default() + coefficient.clone();
+            if sum.is_zero() {
+                self.terms.remove(monomial);
+            } else {
+                self.terms.insert(monomial.clone(), sum);
+            }
+        }
+        self
+    }
+
+    fn negate(self) -> Self {
+        self.scale(&-Rational::one())
+    }
+
+    fn scale(mut self, factor: &Rational) -> Self {
+        if factor.is_zero() {
+            return Self::default();
+        }
+        for coefficient in self.terms.values_mut() {
+            *coefficient = coefficient.clone() * factor.clone();
+        }
+        self
+    }
+
+    fn multiply(&self, other: &Self) -> Option<Self> {
+        let mut product = Self::default();
+        for (left, a) in &self.terms {
+            for (right, b) in &other.terms {
+                let term = Self::monomial(multiply_monomials(left, right)?, a.clone() * b.clone());
+                product = product.add(&term);
+            }
+        }
+        (product.terms.len() <= MAX_TERMS).then_some(product)
+    }
+
+    fn pow(&self, exponent: u32) -> Option<Self> {
+        let mut result = Self::constant_term(Rational::one());
+        for _ in 0..exponent {
+            result = result.multiply(self)?;
+        }
+        Some(result)
+    }
+
+    /// Divides out the common factors of `self / denominator`, returning
+    /// the reduced numerator and denominator, or `None` if there are none.
+    fn cancel(&self, denominator: &Self) -> Option<(Self, Self)> {
+        // A constant multiple: (2x + 2)/(x + 1)
+        let (monomial, leading) = denominator.terms.iter().next_back()?;
+        let ratio = self
+            .terms
+            .get(monomial)
+            .map(|coefficient| coefficient.clone() / leading.clone());
+        if let Some(ratio) = ratio.filter(|ratio| *self == denominator.clone().scale(ratio)) {
+            return Some((
+                Self::constant_term(ratio),
+                Self::constant_term(Rational::one()),
+            ));
+        }
+
+        // Otherwise only polynomials in one common factor are reduced
+        let factor = self.single_factor(denominator)?;
+        let numerator = self.dense(&factor);
+        let denominator = denominator.dense(&factor);
+        let gcd = dense_gcd(numerator.clone(), denominator.clone());
+        if gcd.len() <= 1 {
+            return None;
+        }
+        let (numerator, _) = dense_div_rem(&numerator, &gcd);
+        let (denominator, _) = dense_div_rem(&denominator, &gcd);
+        Some((
+            Self::from_dense(&numerator, &factor),
+            Self::from_dense(&denominator, &factor),
+        ))
+    }
+
+    /// Returns the only factor used by `self` and `other`, if there is one.
+    fn single_factor(&self, other: &Self) -> Option<Factor> {
+        let mut factors = self
+            .terms
+            .keys()
+            .chain(other.terms.keys())
+            .flatten()
+            .map(|(factor, _)| factor);
+        let first = factors.next()?;
+        factors.all(|factor| factor == first).then(|| first.clone())
+    }
+
+    /// Coefficients by degree in `factor`, constant first.
+    fn dense(&self, factor: &Factor) -> Vec<Rational> {
+        let mut coefficients = Vec::new();
+        for (monomial, coefficient) in &self.terms {
+            let degree = monomial
+                .iter()
+                .find(|(f, _)| f == factor)
+                .map_or(0, |(_, exponent)| *exponent as usize);
+            if coefficients.len() <= degree {
+                coefficients.resize(degree + 1, Rational::zero());
+            }
+            coefficients[degree] = coefficient.clone();
+        }
+        coefficients
+    }
+
+    fn from_dense(coefficients: &[Rational], factor: &Factor) -> Self {
+        let mut polynomial = Self::default();
+        for (degree, coefficient) in coefficients.iter().enumerate() {
+            let monomial = if degree == 0 {
+                Vec::new()
+            } else {
+                vec![(factor.clone(), degree as u32)]
+            };
+            polynomial = polynomial.add(&Self::monomial(monomial, coefficient.clone()));
+        }
+        polynomial
+    }
+
+    /// Writes the polynomial with its highest-degree terms first, or `None`
+    /// if a coefficient is too large for a Decimal.
+    fn to_expression(&self, atoms: &Atoms) -> Option<Expression> {
+        let mut terms: Vec<_> = self.terms.iter().collect();
+        terms.sort_by_key(|(monomial, _)| {
+            std::cmp::Reverse(monomial.iter().map(|(_, exponent)| exponent).sum::<u32>())
+        });
+        let mut result: Option<Expression> = None;
+        for (monomial, coefficient) in terms {
+            let term = term_expression(monomial, &coefficient.abs(), atoms)?;
+            result = Some(match (result, coefficient.is_negative()) {
+                (None, true) => neg(term),
+                (None, false) => term,
+                (Some(sum), true) => sub(sum, term),
+                (Some(sum), false) => add(sum, term),
+            });
+        }
+        Some(result.unwrap_or_else(|| int(0)))
+    }
+}
+
+fn multiply_monomials(left: &Monomial, right: &Monomial) -> Option<Monomial> {
+    let mut exponents: BTreeMap<Factor, u32> = left.iter().cloned().collect();
+    for (factor, exponent) in right {
+        *exponents.entry(factor.clone()).or_default() += exponent;
+    }
+    exponents
+        .values()
+        .all(|exponent| *exponent <= MAX_DEGREE)
+        .then(|| exponents.into_iter().collect())
+}
+
+/// Writes `coefficient * monomial`, with fractional coefficients as
+/// divisions (`x / 2`), or `None` if the coefficient is too large for a
+/// Decimal.
+fn term_expression(
+    monomial: &Monomial,
+    coefficient: &Rational,
+    atoms: &Atoms,
+) -> Option<Expression> {
+    let numerator = num(Rational::from_bigint(coefficient.numer_bigint().clone()).to_decimal()?);
+    let denominator = num(Rational::from_bigint(coefficient.denom_bigint().clone()).to_decimal()?);
+    let body = monomial
+        .iter()
+        .map(|(factor, exponent)| {
+            let base = match factor {
+                Factor::Variable(name) => var(name),
+                Factor::Atom(index) => atoms.0[*index].clone(),
+            };
+            pow(base, int(i64::from(*exponent)))
+        })
+        .reduce(mul);
+    match body {
+        // "2 / x" rather than "2 * 1 / x"
+        Some(Expression::Binary {
+            left,
+            op: BinaryOp::Divide,
+            right,
+        }) => Some(div(mul(numerator, *left), mul(denominator, *right))),
+        Some(body) => Some(div(mul(numerator, body), denominator)),
+        None => Some(div(numerator, denominator)),
+    }
+}
+
+fn trim(mut coefficients: Vec<Rational>) -> Vec<Rational> {
+    while coefficients.last().is_some_and(Rational::is_zero) {
+        coefficients.pop();
+    }
+    coefficients
+}
+
+/// Divides dense polynomials, returning the quotient and remainder.
+fn dense_div_rem(numerator: &[Rational], divisor: &[Rational]) -> (Vec<Rational>, Vec<Rational>) {
+    let divisor = trim(divisor.to_vec());
+    let mut remainder = trim(numerator.to_vec());
+    let Some(leading) = divisor.last().cloned() else {
+        return (Vec::new(), remainder);
+    };
+    if remainder.len() < divisor.len() {
+        return (Vec::new(), remainder);
+    }
+    let mut quotient = vec![Rational::zero(); remainder.len() - divisor.len() + 1];
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let factor = remainder[remainder.len() - 1].clone() / leading.clone();
+        for (i, coefficient) in divisor.iter().enumerate() {
+            remainder[shift + i] =
+                remainder[shift + i].clone() - factor.clone() * coefficient.clone();
+        }
+        quotient[shift] = factor;
+        remainder.pop();
+        remainder = trim(remainder);
+    }
+    (quotient, remainder)
+}
+
+/// The monic greatest common divisor of two dense polynomials.
+fn dense_gcd(mut a: Vec<Rational>, mut b: Vec<Rational>) -> Vec<Rational> {
+    b = trim(b);
+    while !b.is_empty() {
+        let (_, remainder) = dense_div_rem(&a, &b);
+        a = b;
+        b = remainder;
+    }
+    let a = trim(a);
+    match a.last().cloned() {
+        Some(leading) => a.into_iter().map(|c| c / leading.clone()).collect(),
+        None => a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::ExpressionParser;
+
+    fn simplified(input: &str) -> String {
+        let command = ExpressionParser::new()
+            .parse(&format!("simplify {input}"))
+            .unwrap();
+        let Expression::FunctionCall { args, .. } = command else {
+            panic!("simplify {input} is a call of simplify");
+        };
+        simplify(&args[0]).to_string()
+    }
+
+    #[test]
+    fn test_cancel_common_factors() {
+        assert_eq!(simplified("(x^2 + 2x + 1)/(x+1)"), "x + 1");
+        assert_eq!(simplified("(x^2 - 1)/(x - 1)"), "x + 1");
+        assert_eq!(simplified("(2*x + 2)/(x + 1)"), "2");
+    }
+
+    #[test]
+    fn test_combine_like_terms() {
+        assert_eq!(simplified("x + 2*x - 1 + 1"), "3 * x");
+        assert_eq!(simplified("(x + 1)^2"), "x^2 + 2 * x + 1");
+        assert_eq!(simplified("sin(x) + sin(x)"), "2 * sin(x)");
+    }
+}
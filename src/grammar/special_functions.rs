@@ -0,0 +1,379 @@
+//! Special functions on `f64`: the gamma and beta functions, the error
+//! function, and the sine, cosine and exponential integrals `Si`, `Ci` and
+//! `Ei` that symbolic integrals such as `integrate sin(x)/x dx` give.
+//!
+//! Each is accurate to about 1e-14 relative error over the range where its
+//! value fits an `f64`.
+
+use std::f64::consts::{FRAC_2_SQRT_PI, FRAC_PI_2, LN_10, PI};
+
+use crate::error::CalculatorError;
+
+/// The Euler–Mascheroni constant γ.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Relative error at which series and continued fractions stop.
+const EPSILON: f64 = 1e-16;
+
+/// Most terms a series or continued fraction may take.
+const MAX_TERMS: usize = 500;
+
+/// Lanczos approximation coefficients for g = 7, n = 9.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Γ(x), which is (x - 1)! for whole x. Undefined at 0 and the negative
+/// integers.
+#[allow(clippy::float_cmp)]
+pub fn gamma(x: f64) -> Result<f64, CalculatorError> {
+    if x <= 0.0 && x == x.trunc() {
+        return Err(CalculatorError::domain(
+            "gamma is undefined at 0 and the negative integers",
+        ));
+    }
+    if x < 0.5 {
+        // Reflection: Γ(x) Γ(1 - x) = π / sin(πx)
+        return Ok(PI / ((PI * x).sin() * gamma(1.0 - x)?));
+    }
+    let (t, sum) = lanczos(x);
+    let result = (2.0 * PI).sqrt() * t.powf(x - 0.5) * (-t).exp() * sum;
+    if result.is_finite() {
+        Ok(result)
+    } else {
+        Err(CalculatorError::Overflow)
+    }
+}
+
+/// Γ(x) for positive x as a mantissa in [1, 10) and a power of ten, which
+/// goes on through log-gamma long after Γ(x) overflows an `f64`.
+pub fn gamma_scientific(x: f64) -> Result<(f64, i32), CalculatorError> {
+    if let Ok(value) = gamma(x) {
+        return Ok(scientific_parts(value));
+    }
+    let log10 = ln_gamma(x) / LN_10;
+    let exponent = log10.floor();
+    Ok((10f64.powf(log10 - exponent), float_exponent(exponent)?))
+}
+
+/// Splits a positive `value` into a mantissa in [1, 10) and a power of ten.
+#[allow(clippy::cast_possible_truncation)]
+fn scientific_parts(value: f64) -> (f64, i32) {
+    let exponent = value.log10().floor();
+    (value / 10f64.powf(exponent), exponent as i32)
+}
+
+/// A whole `exponent` as an `i32`, if it fits.
+#[allow(clippy::cast_possible_truncation)]
+fn float_exponent(exponent: f64) -> Result<i32, CalculatorError> {
+    if exponent.abs() < f64::from(i32::MAX) {
+        Ok(exponent as i32)
+    } else {
+        Err(CalculatorError::Overflow)
+    }
+}
+
+/// ln Γ(x) for positive x, which stays finite long after Γ(x) overflows.
+fn ln_gamma(x: f64) -> f64 {
+    let (t, sum) = lanczos(x);
+    0.5f64.mul_add((2.0 * PI).ln(), (x - 0.5) * t.ln()) - t + sum.ln()
+}
+
+/// The Lanczos base point and series for Γ(x), x ≥ 0.5.
+fn lanczos(x: f64) -> (f64, f64) {
+    let x = x - 1.0;
+    let sum = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    (x + LANCZOS_G + 0.5, sum)
+}
+
+/// B(a, b) = Γ(a) Γ(b) / Γ(a + b).
+#[allow(clippy::float_cmp)]
+pub fn beta(a: f64, b: f64) -> Result<f64, CalculatorError> {
+    let result = if a > 0.0 && b > 0.0 {
+        (ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)).exp()
+    } else if (a + b) <= 0.0 && (a + b) == (a + b).trunc() {
+        // Γ(a + b) has a pole, so the quotient is 0 unless Γ(a) or Γ(b) has one too
+        gamma(a)?;
+        gamma(b)?;
+        0.0
+    } else {
+        gamma(a)? * gamma(b)? / gamma(a + b)?
+    };
+    if result.is_finite() {
+        Ok(result)
+    } else {
+        Err(CalculatorError::Overflow)
+    }
+}
+
+/// The error function erf(x) = 2/√π ∫₀ˣ e^(-t²) dt.
+pub fn erf(x: f64) -> f64 {
+    if x.abs() < 2.5 {
+        erf_series(x)
+    } else {
+        x.signum() * (1.0 - erfc_continued_fraction(x.abs()))
+    }
+}
+
+/// The complementary error function erfc(x) = 1 - erf(x), computed
+/// directly where erf(x) is close to 1.
+pub fn erfc(x: f64) -> f64 {
+    if x.abs() < 2.5 {
+        1.0 - erf_series(x)
+    } else if x > 0.0 {
+        erfc_continued_fraction(x)
+    } else {
+        2.0 - erfc_continued_fraction(-x)
+    }
+}
+
+/// erf(x) = 2/√π Σ (-1)ⁿ x²ⁿ⁺¹ / (n! (2n + 1)).
+fn erf_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut power = x;
+    let mut sum = x;
+    for n in 1..MAX_TERMS {
+        power *= -x2 / n as f64;
+        let term = power / (2 * n + 1) as f64;
+        sum += term;
+        if term.abs() < EPSILON * sum.abs() {
+            break;
+        }
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
+/// erfc(x) = e^(-x²)/√π · 1/(x + ½/(x + 1/(x + 3⁄2/(x + ...)))) for x ≥ 2.5,
+/// evaluated from the tail.
+fn erfc_continued_fraction(x: f64) -> f64 {
+    let tail = (1..=60)
+        .rev()
+        .fold(x, |tail, n| x + f64::from(n) / 2.0 / tail);
+    (-x * x).exp() / (PI.sqrt() * tail)
+}
+
+/// The sine integral Si(x) = ∫₀ˣ sin(t)/t dt.
+pub fn si(x: f64) -> f64 {
+    let (si, _) = sine_cosine_integrals(x.abs());
+    si.copysign(x)
+}
+
+/// The cosine integral Ci(x) = γ + ln x + ∫₀ˣ (cos(t) - 1)/t dt, for
+/// positive x.
+pub fn ci(x: f64) -> Result<f64, CalculatorError> {
+    if x <= 0.0 {
+        return Err(CalculatorError::domain("Ci argument must be positive"));
+    }
+    Ok(sine_cosine_integrals(x).1)
+}
+
+/// Si(t) and Ci(t) for t ≥ 0: by their power series up to 2, and beyond
+/// by the continued fraction of the exponential integral E₁(it).
+#[allow(clippy::many_single_char_names)]
+fn sine_cosine_integrals(t: f64) -> (f64, f64) {
+    if t == 0.0 {
+        return (0.0, f64::NEG_INFINITY);
+    }
+    if t > 2.0 {
+        // Modified Lentz's method on complex numbers as (re, im) pairs
+        let mut b = (1.0, t);
+        let mut c = (1.0 / f64::MIN_POSITIVE, 0.0);
+        let mut d = complex_inverse(b);
+        let mut h = d;
+        for i in 2..MAX_TERMS {
+            let a = -((i - 1) as f64).powi(2);
+            b.0 += 2.0;
+            d = complex_inverse(complex_add(complex_scale(d, a), b));
+            let a_over_c = complex_scale(complex_inverse(c), a);
+            c = complex_add(b, a_over_c);
+            let delta = complex_multiply(c, d);
+            h = complex_multiply(h, delta);
+            if (delta.0 - 1.0).abs() + delta.1.abs() < EPSILON {
+                break;
+            }
+        }
+        let h = complex_multiply((t.cos(), -t.sin()), h);
+        return (FRAC_PI_2 + h.1, -h.0);
+    }
+
+    // Si sums the odd terms of Σ (-1)^⌊k/2⌋ tᵏ / (k · k!), Ci the even ones
+    let (mut sine_sum, mut cosine_sum) = (0.0, 0.0);
+    let mut factorial_power = 1.0;
+    for k in 1..MAX_TERMS {
+        factorial_power *= t / k as f64;
+        let term = factorial_power / k as f64;
+        let sign = if (k / 2) % 2 == 0 { 1.0 } else { -1.0 };
+        if k % 2 == 1 {
+            sine_sum += sign * term;
+        } else {
+            cosine_sum += sign * term;
+        }
+        if term < EPSILON * (sine_sum.abs() + cosine_sum.abs()) {
+            break;
+        }
+    }
+    (sine_sum, cosine_sum + t.ln() + EULER_GAMMA)
+}
+
+/// The exponential integral Ei(x) = -∫₋ₓ^∞ e^(-t)/t dt, for x ≠ 0.
+pub fn ei(x: f64) -> Result<f64, CalculatorError> {
+    if x == 0.0 {
+        return Err(CalculatorError::domain("Ei is undefined at 0"));
+    }
+    if x < 0.0 {
+        return Ok(-exponential_integral_e1(-x));
+    }
+    let result = if x < 40.0 {
+        // Ei(x) = γ + ln x + Σ xᵏ / (k · k!)
+        let mut power = 1.0;
+        let mut sum = 0.0;
+        for k in 1..MAX_TERMS {
+            power *= x / k as f64;
+            let term = power / k as f64;
+            sum += term;
+            if term < EPSILON * sum {
+                break;
+            }
+        }
+        sum + x.ln() + EULER_GAMMA
+    } else {
+        // Ei(x) ~ eˣ/x Σ k!/xᵏ, stopped before the terms grow
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for k in 1..MAX_TERMS {
+            let next = term * k as f64 / x;
+            if next < EPSILON * sum || next > term {
+                break;
+            }
+            term = next;
+            sum += term;
+        }
+        x.exp() / x * sum
+    };
+    if result.is_finite() {
+        Ok(result)
+    } else {
+        Err(CalculatorError::Overflow)
+    }
+}
+
+/// E₁(x) = ∫ₓ^∞ e^(-t)/t dt for positive x: by its series up to 1, and
+/// beyond by its continued fraction.
+#[allow(clippy::many_single_char_names)]
+fn exponential_integral_e1(x: f64) -> f64 {
+    if x <= 1.0 {
+        // E₁(x) = -γ - ln x - Σ (-x)ᵏ / (k · k!)
+        let mut power = 1.0;
+        let mut sum = 0.0;
+        for k in 1..MAX_TERMS {
+            power *= -x / k as f64;
+            let term = power / k as f64;
+            sum += term;
+            if term.abs() < EPSILON * sum.abs() {
+                break;
+            }
+        }
+        return -EULER_GAMMA - x.ln() - sum;
+    }
+    // Modified Lentz's method
+    let mut b = x + 1.0;
+    let mut c = 1.0 / f64::MIN_POSITIVE;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_TERMS {
+        let a = -((i * i) as f64);
+        b += 2.0;
+        d = 1.0 / a.mul_add(d, b);
+        c = b + a / c;
+        let delta = c * d;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h * (-x).exp()
+}
+
+fn complex_add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn complex_scale(a: (f64, f64), factor: f64) -> (f64, f64) {
+    (a.0 * factor, a.1 * factor)
+}
+
+fn complex_multiply(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0.mul_add(b.0, -a.1 * b.1), a.0.mul_add(b.1, a.1 * b.0))
+}
+
+fn complex_inverse(a: (f64, f64)) -> (f64, f64) {
+    let norm = a.0.mul_add(a.0, a.1 * a.1);
+    (a.0 / norm, -a.1 / norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        let error = (actual - expected).abs() / expected.abs().max(1.0);
+        assert!(error < 1e-12, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_gamma_and_beta() {
+        assert_close(gamma(5.0).unwrap(), 24.0);
+        assert_close(gamma(0.5).unwrap(), PI.sqrt());
+        assert_close(gamma(-1.5).unwrap(), 4.0 * PI.sqrt() / 3.0);
+        assert!(gamma(-2.0).is_err());
+        assert!(gamma(200.0).is_err());
+        let (mantissa, exponent) = gamma_scientific(30.5).unwrap();
+        assert_close(mantissa, 4.822_696_933_490_909);
+        assert_eq!(exponent, 31);
+        let (mantissa, exponent) = gamma_scientific(200.0).unwrap();
+        assert_close(mantissa, 3.943_289_336_823_953);
+        assert_eq!(exponent, 372);
+        assert_close(beta(2.0, 3.0).unwrap(), 1.0 / 12.0);
+        assert_close(beta(0.5, 0.5).unwrap(), PI);
+    }
+
+    #[test]
+    fn test_error_functions() {
+        assert_close(erf(0.5), 0.520_499_877_813_046_5);
+        assert_close(erf(-1.0), -0.842_700_792_949_714_9);
+        assert!((erfc(3.0) - 2.209_049_699_858_544e-5).abs() < 1e-18);
+        assert_close(erfc(-2.5), 1.999_593_047_982_555);
+        assert_close(erf(2.5), 0.999_593_047_982_555);
+    }
+
+    #[test]
+    fn test_trigonometric_integrals() {
+        assert_close(si(1.0), 0.946_083_070_367_183);
+        assert_close(si(-5.0), -1.549_931_244_944_674);
+        assert_close(si(50.0), 1.551_617_072_485_936);
+        assert_close(ci(1.0).unwrap(), 0.337_403_922_900_968_1);
+        assert_close(ci(10.0).unwrap(), -0.045_456_433_004_455_37);
+        assert!(ci(0.0).is_err());
+    }
+
+    #[test]
+    fn test_exponential_integral() {
+        assert_close(ei(1.0).unwrap(), 1.895_117_816_355_937);
+        assert_close(ei(50.0).unwrap(), 1.058_563_689_713_169e20);
+        assert_close(ei(-1.0).unwrap(), -0.219_383_934_395_520_3);
+        assert_close(ei(-3.0).unwrap(), -0.013_048_381_094_197_04);
+        assert!(ei(0.0).is_err());
+    }
+}
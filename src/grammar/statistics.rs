@@ -0,0 +1,270 @@
+//! Statistics functions over argument lists, e.g. `mean(1, 2, 3, 4)` or
+//! `max(3, 7, 5)`.
+//!
+//! Arguments are combined as exact rationals, so `mean(1, 2, 2)` is `5/3`
+//! rather than a rounded decimal. Only `stddev` can leave the rationals,
+//! when the variance is not a perfect square, and then its root is taken to
+//! 30 digits however large it is.
+
+use crate::error::CalculatorError;
+use crate::types::{Decimal, DurationUnit, Rational, Unit, Value, ValueKind};
+
+/// Significant digits a standard deviation is worked out to.
+const ROOT_DIGITS: u32 = 30;
+
+/// The statistics functions.
+pub const STATISTICS_FUNCTIONS: &[&str] = &[
+    "mean", "average", "median", "mode", "stddev", "variance", "sum", "count", "min", "max",
+];
+
+/// Returns true if `name` is a statistics function.
+#[must_use]
+pub fn is_statistics_function(name: &str) -> bool {
+    STATISTICS_FUNCTIONS
+        .iter()
+        .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+/// Evaluates a statistics function over already-evaluated arguments.
+///
+/// List arguments contribute each of their items, so `sum(#1:#5)` totals a
+/// range of earlier results. Items in units of the same kind are converted
+/// to the first item's unit, which every function but `count` and
+/// `variance` keeps (`mean(10 kg, 20 kg)` is `15 kg`, `sum(1 kg, 500 g)` is
+/// `1.5 kg`). Durations in mixed units, or that no longer come out whole,
+/// give a duration as `+` does: `sum(1 hour, 30 minutes)` is
+/// `1 hour, 30 minutes` and `average(1 hour, 30 minutes)` is `45 minutes`.
+pub fn evaluate_statistics(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name_lower = name.to_lowercase();
+    // Lists are aggregated item by item: sum([1, 2], 3) is sum(1, 2, 3)
+    let args: Vec<Value> = args
+        .iter()
+        .flat_map(|arg| {
+            arg.as_list()
+                .map_or_else(|| vec![arg.clone()], <[Value]>::to_vec)
+        })
+        .collect();
+    let raw_duration = args
+        .iter()
+        .any(|arg| matches!(arg.kind, ValueKind::Duration { .. }));
+    // Raw durations, such as a difference of two dates, count in seconds
+    let args: Vec<Value> = args
+        .into_iter()
+        .map(|arg| match arg.kind {
+            ValueKind::Duration { seconds, .. } => Value::rational_with_unit(
+                Rational::from_integer(i128::from(seconds)),
+                Unit::Duration(DurationUnit::Seconds),
+            ),
+            _ => arg,
+        })
+        .collect();
+    let unit = args.first().map_or(Unit::None, |first| first.unit.clone());
+    let mixed_units = raw_duration || args.iter().any(|arg| arg.unit != unit);
+    let mut numbers = Vec::with_capacity(args.len());
+    for arg in &args {
+        let number = arg.to_rational().ok_or_else(|| {
+            CalculatorError::invalid_args(&name_lower, "expected numeric argument")
+        })?;
+        let factor = if arg.unit == unit {
+            Rational::one()
+        } else {
+            arg.unit.exact_conversion_factor(&unit).ok_or_else(|| {
+                CalculatorError::invalid_args(&name_lower, "all arguments must have the same unit")
+            })?
+        };
+        numbers.push(number * factor);
+    }
+    let unit = match name_lower.as_str() {
+        "count" | "variance" => Unit::None,
+        _ => unit,
+    };
+
+    Ok(match statistic(&name_lower, numbers)? {
+        Statistic::Exact(r) if mixed_units || !r.is_integer() => whole_seconds(&r, &unit)
+            .map_or_else(|| Value::rational_with_unit(r, unit), Value::duration),
+        Statistic::Exact(r) => Value::rational_with_unit(r, unit),
+        Statistic::Approximate(r) => Value::rational_with_unit(r, unit).inexact(),
+    })
+}
+
+/// Returns an amount in a fixed duration unit (weeks and smaller) as whole
+/// seconds, or `None` if it is not one.
+fn whole_seconds(amount: &Rational, unit: &Unit) -> Option<i64> {
+    let Unit::Duration(duration) = unit else {
+        return None;
+    };
+    if matches!(duration, DurationUnit::Months | DurationUnit::Years) {
+        return None;
+    }
+    let seconds =
+        amount.clone() * unit.exact_conversion_factor(&Unit::Duration(DurationUnit::Seconds))?;
+    if !seconds.is_integer() {
+        return None;
+    }
+    i64::try_from(seconds.numer_bigint()).ok()
+}
+
+/// Evaluates a statistics function over plain decimal arguments.
+pub fn evaluate_statistics_decimal(
+    name: &str,
+    args: &[Decimal],
+) -> Result<Decimal, CalculatorError> {
+    let numbers = args.iter().copied().map(Rational::from_decimal).collect();
+    Ok(match statistic(&name.to_lowercase(), numbers)? {
+        Statistic::Exact(r) => r.to_decimal().ok_or(CalculatorError::Overflow)?,
+        Statistic::Approximate(r) => r.to_decimal().ok_or(CalculatorError::Overflow)?,
+    })
+}
+
+enum Statistic {
+    Exact(Rational),
+    Approximate(Rational),
+}
+
+fn statistic(name: &str, mut numbers: Vec<Rational>) -> Result<Statistic, CalculatorError> {
+    if name == "count" {
+        return Ok(Statistic::Exact(Rational::from_integer(
+            numbers.len() as i128
+        )));
+    }
+    if name == "sum" {
+        return Ok(Statistic::Exact(sum(&numbers)));
+    }
+
+    let min_args = if matches!(name, "variance" | "stddev") {
+        2
+    } else {
+        1
+    };
+    if numbers.len() < min_args {
+        return Err(CalculatorError::invalid_args(
+            name,
+            format!("expected at least {min_args} argument(s)"),
+        ));
+    }
+
+    Ok(match name {
+        "mean" | "average" => Statistic::Exact(mean(&numbers)),
+        "median" => {
+            numbers.sort();
+            let mid = numbers.len() / 2;
+            if numbers.len() % 2 == 1 {
+                Statistic::Exact(numbers[mid].clone())
+            } else {
+                Statistic::Exact(mean(&numbers[mid - 1..=mid]))
+            }
+        }
+        "mode" => Statistic::Exact(mode(numbers)),
+        "min" => Statistic::Exact(numbers.into_iter().min().unwrap_or_default()),
+        "max" => Statistic::Exact(numbers.into_iter().max().unwrap_or_default()),
+        "variance" => Statistic::Exact(variance(&numbers)),
+        "stddev" => square_root(&variance(&numbers))?,
+        _ => return Err(CalculatorError::unknown_function(name)),
+    })
+}
+
+fn sum(numbers: &[Rational]) -> Rational {
+    numbers
+        .iter()
+        .cloned()
+        .fold(Rational::zero(), |acc, n| acc + n)
+}
+
+fn mean(numbers: &[Rational]) -> Rational {
+    sum(numbers) / Rational::from_integer(numbers.len() as i128)
+}
+
+/// The most frequent value; ties go to the smallest value.
+fn mode(mut numbers: Vec<Rational>) -> Rational {
+    numbers.sort();
+    let mut best = (0, 0);
+    let mut start = 0;
+    for end in 1..=numbers.len() {
+        if end == numbers.len() || numbers[end] != numbers[start] {
+            if end - start > best.1 {
+                best = (start, end - start);
+            }
+            start = end;
+        }
+    }
+    numbers.swap_remove(best.0)
+}
+
+/// Sample variance (divides by `n - 1`).
+fn variance(numbers: &[Rational]) -> Rational {
+    let mean = mean(numbers);
+    let squares = numbers
+        .iter()
+        .map(|n| {
+            let d = n.clone() - mean.clone();
+            d.clone() * d
+        })
+        .fold(Rational::zero(), |acc, n| acc + n);
+    squares / Rational::from_integer(numbers.len() as i128 - 1)
+}
+
+/// Square root, exact when `value` is the square of a rational.
+fn square_root(value: &Rational) -> Result<Statistic, CalculatorError> {
+    if let Some(exact) = value.nth_root(2) {
+        return Ok(Statistic::Exact(exact));
+    }
+    value
+        .root_to_digits(2, ROOT_DIGITS)
+        .map(Statistic::Approximate)
+        .ok_or(CalculatorError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(numbers: &[i64]) -> Vec<Value> {
+        numbers.iter().map(|&n| Value::from_integer(n)).collect()
+    }
+
+    fn eval(name: &str, numbers: &[i64]) -> String {
+        evaluate_statistics(name, &values(numbers))
+            .unwrap()
+            .to_display_string()
+    }
+
+    #[test]
+    fn test_mean_is_exact() {
+        assert_eq!(eval("mean", &[1, 2, 3, 4]), "2.5");
+        let mean = evaluate_statistics("mean", &values(&[1, 2, 2])).unwrap();
+        assert_eq!(mean.as_rational(), Some(&Rational::new(5, 3)));
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(eval("median", &[3, 1, 2]), "2");
+        assert_eq!(eval("median", &[4, 1, 3, 2]), "2.5");
+    }
+
+    #[test]
+    fn test_mode_prefers_smallest_on_ties() {
+        assert_eq!(eval("mode", &[1, 2, 2, 3, 3]), "2");
+        assert_eq!(eval("mode", &[5, 4]), "4");
+    }
+
+    #[test]
+    fn test_variance_and_stddev() {
+        let variance = evaluate_statistics("variance", &values(&[2, 4, 4, 4, 5, 5, 7, 9])).unwrap();
+        assert_eq!(variance.as_rational(), Some(&Rational::new(32, 7)));
+        assert_eq!(eval("stddev", &[1, 3]), "1.414213562373095");
+        assert_eq!(eval("stddev", &[1, 3, 5]), "2");
+    }
+
+    #[test]
+    fn test_sum_and_count() {
+        assert_eq!(eval("sum", &[1, 2, 3]), "6");
+        assert_eq!(eval("count", &[7, 8, 9]), "3");
+        assert_eq!(eval("count", &[]), "0");
+    }
+
+    #[test]
+    fn test_argument_errors() {
+        assert!(evaluate_statistics("mean", &[]).is_err());
+        assert!(evaluate_statistics("variance", &values(&[1])).is_err());
+    }
+}
@@ -0,0 +1,578 @@
+//! Symbolic expression helpers shared by the integrator and differentiator.
+//!
+//! The builders here fold numeric constants and drop neutral elements
+//! (`0 + x`, `1 * x`, `x ^ 1`), so rule-based transformations produce
+//! readable results without a separate simplification pass.
+
+use super::math_functions::evaluate_function;
+use super::simplify::simplify;
+use crate::types::{BinaryOp, Decimal, Expression, Unit};
+
+/// Highest polynomial degree handled by [`poly_coeffs`].
+const MAX_POLY_DEGREE: usize = 20;
+
+pub(super) fn num(value: Decimal) -> Expression {
+    Expression::number(value)
+}
+
+pub(super) fn int(value: i64) -> Expression {
+    num(Decimal::new(value))
+}
+
+pub(super) fn var(name: &str) -> Expression {
+    Expression::variable(name)
+}
+
+pub(super) fn call(name: &str, arg: Expression) -> Expression {
+    Expression::function_call(name, vec![arg])
+}
+
+/// Returns the plain (unitless) numeric value of an expression, if any.
+pub(super) fn as_number(expr: &Expression) -> Option<Decimal> {
+    match expr {
+        Expression::Number {
+            value,
+            unit: Unit::None,
+            ..
+        } => value.to_decimal(),
+        Expression::Group(inner) => as_number(inner),
+        Expression::Negate(inner) => as_number(inner).map(|value| -value),
+        _ => None,
+    }
+}
+
+pub(super) fn is_number(expr: &Expression, n: i64) -> bool {
+    as_number(expr).is_some_and(|v| v == Decimal::new(n))
+}
+
+pub(super) fn add(a: Expression, b: Expression) -> Expression {
+    match (as_number(&a), as_number(&b)) {
+        (Some(x), Some(y)) => return num(x + y),
+        (Some(x), _) if x.is_zero() => return b,
+        (_, Some(y)) if y.is_zero() => return a,
+        (_, Some(y)) if y.is_negative() => return sub(a, num(-y)),
+        _ => {}
+    }
+    match b {
+        Expression::Negate(inner) => sub(a, *inner),
+        b => Expression::binary(a, BinaryOp::Add, b),
+    }
+}
+
+pub(super) fn sub(a: Expression, b: Expression) -> Expression {
+    match (as_number(&a), as_number(&b)) {
+        (Some(x), Some(y)) => return num(x - y),
+        (_, Some(y)) if y.is_zero() => return a,
+        (Some(x), _) if x.is_zero() => return neg(b),
+        (_, Some(y)) if y.is_negative() => return add(a, num(-y)),
+        _ => {}
+    }
+    match b {
+        Expression::Negate(inner) => add(a, *inner),
+        b => Expression::binary(a, BinaryOp::Subtract, b),
+    }
+}
+
+pub(super) fn neg(a: Expression) -> Expression {
+    if let Some(x) = as_number(&a) {
+        return num(-x);
+    }
+    match a {
+        Expression::Negate(inner) => *inner,
+        a => Expression::negate(a),
+    }
+}
+
+pub(super) fn mul(a: Expression, b: Expression) -> Expression {
+    match (as_number(&a), as_number(&b)) {
+        (Some(x), Some(y)) => return num(x * y),
+        (Some(x), _) | (_, Some(x)) if x.is_zero() => return int(0),
+        (Some(x), _) if x == Decimal::one() => return b,
+        (_, Some(y)) if y == Decimal::one() => return a,
+        (Some(x), _) if x.is_negative() => return neg(mul(num(-x), b)),
+        (_, Some(_)) => return mul(b, a),
+        _ => {}
+    }
+    match (a, b) {
+        (Expression::Negate(x), y) => neg(mul(*x, y)),
+        (y, Expression::Negate(x)) => neg(mul(y, *x)),
+        // Fold numeric coefficients: 2 * (3 * x) -> 6 * x
+        (
+            a,
+            Expression::Binary {
+                left,
+                op: BinaryOp::Multiply,
+                right,
+            },
+        ) if as_number(&a).is_some() && as_number(&left).is_some() => mul(mul(a, *left), *right),
+        // Move coefficients into quotients: 3 * (x^3 / 3) -> x^3
+        (
+            a,
+            Expression::Binary {
+                left,
+                op: BinaryOp::Divide,
+                right,
+            },
+        ) if as_number(&a).is_some() && as_number(&right).is_some() => div(mul(a, *left), *right),
+        (a, b) => Expression::binary(a, BinaryOp::Multiply, b),
+    }
+}
+
+pub(super) fn div(a: Expression, b: Expression) -> Expression {
+    match (as_number(&a), as_number(&b)) {
+        (_, Some(y)) if y == Decimal::one() => return a,
+        // Kept for the caller to report, rather than folded to 0 by 0/0
+        (_, Some(y)) if y.is_zero() => return Expression::binary(a, BinaryOp::Divide, b),
+        // 0/b is 0 unless b is zero too, as `x - x` is once simplified
+        (Some(x), _) if x.is_zero() => {
+            return if is_number(&simplify(&b), 0) {
+                Expression::binary(a, BinaryOp::Divide, int(0))
+            } else {
+                int(0)
+            };
+        }
+        (_, Some(y)) if y.is_negative() => return neg(div(a, num(-y))),
+        (Some(x), Some(y)) if !y.is_zero() => {
+            // Only fold terminating quotients so 1/3 stays exact.
+            if let Some(q) = x.checked_div(&y) {
+                if q * y == x {
+                    return num(q);
+                }
+            }
+        }
+        _ => {}
+    }
+    match a {
+        Expression::Negate(inner) => neg(div(*inner, b)),
+        // Combine numeric divisors: (x / 2) / 3 -> x / 6
+        Expression::Binary {
+            left,
+            op: BinaryOp::Divide,
+            right,
+        } if as_number(&right).is_some() && as_number(&b).is_some() => {
+            let divisor = as_number(&right).unwrap_or_default() * as_number(&b).unwrap_or_default();
+            div(*left, num(divisor))
+        }
+        // Cancel numeric coefficients: (6 * x) / 3 -> 2 * x
+        Expression::Binary {
+            left,
+            op: BinaryOp::Multiply,
+            right,
+        } if as_number(&left).is_some() && as_number(&b).is_some() => {
+            let coefficient = as_number(&left).unwrap_or_default();
+            let divisor = as_number(&b).unwrap_or_default();
+            match coefficient
+                .checked_div(&divisor)
+                .filter(|q| *q * divisor == coefficient)
+            {
+                Some(quotient) => mul(num(quotient), *right),
+                None => Expression::binary(mul(*left, *right), BinaryOp::Divide, b),
+            }
+        }
+        a => Expression::binary(a, BinaryOp::Divide, b),
+    }
+}
+
+pub(super) fn pow(base: Expression, exponent: Expression) -> Expression {
+    if is_number(&exponent, 0) {
+        return int(1);
+    }
+    if is_number(&exponent, 1) {
+        return base;
+    }
+    if as_number(&exponent).is_some_and(|n| n * Decimal::new(2) == Decimal::one()) {
+        return call("sqrt", base);
+    }
+    Expression::power(base, exponent)
+}
+
+/// Multiplies by a constant, writing unit fractions as divisions
+/// (`0.5 * f` becomes `f / 2`).
+pub(super) fn scale(c: Decimal, e: Expression) -> Expression {
+    if c != c.round(0) {
+        if let Some(reciprocal) = Decimal::one().checked_div(&c) {
+            let rounded = reciprocal.round(6);
+            if rounded == rounded.round(0)
+                && (rounded * c - Decimal::one()).abs()
+                    < Decimal::one() / Decimal::new(1_000_000_000)
+            {
+                return div(e, num(rounded.normalize()));
+            }
+        }
+    }
+    mul(num(c), e)
+}
+
+/// Removes explicit grouping so rules only see the expression structure.
+pub(super) fn strip_groups(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Group(inner) => strip_groups(inner),
+        Expression::Binary { left, op, right } => {
+            Expression::binary(strip_groups(left), *op, strip_groups(right))
+        }
+        Expression::Negate(inner) => Expression::negate(strip_groups(inner)),
+        Expression::Power { base, exponent } => {
+            Expression::power(strip_groups(base), strip_groups(exponent))
+        }
+        Expression::FunctionCall { name, args } => {
+            Expression::function_call(name.clone(), args.iter().map(strip_groups).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Re-inserts the groups needed to print an expression unambiguously.
+pub(super) fn with_groups(expr: &Expression) -> Expression {
+    match expr {
+        Expression::Binary { left, op, right } => {
+            let left = with_groups(left);
+            let right = with_groups(right);
+            let left = if binding(&left) < op.precedence() {
+                Expression::group(left)
+            } else {
+                left
+            };
+            let right_binding = binding(&right);
+            let non_associative = matches!(op, BinaryOp::Subtract | BinaryOp::Divide);
+            let right = if right_binding < op.precedence()
+                || (right_binding == op.precedence() && non_associative)
+            {
+                Expression::group(right)
+            } else {
+                right
+            };
+            Expression::binary(left, *op, right)
+        }
+        Expression::Negate(inner) => {
+            let inner = with_groups(inner);
+            if matches!(inner, Expression::Binary { op, .. } if op.precedence() <= BinaryOp::Add.precedence())
+            {
+                Expression::negate(Expression::group(inner))
+            } else {
+                Expression::negate(inner)
+            }
+        }
+        Expression::Power { base, exponent } => {
+            let wrap = |e: Expression| {
+                let compound = matches!(
+                    e,
+                    Expression::Binary { .. } | Expression::Negate(_) | Expression::Power { .. }
+                ) || as_number(&e).is_some_and(|n| n.is_negative());
+                if compound {
+                    Expression::group(e)
+                } else {
+                    e
+                }
+            };
+            Expression::power(wrap(with_groups(base)), wrap(with_groups(exponent)))
+        }
+        Expression::FunctionCall { name, args } => {
+            Expression::function_call(name.clone(), args.iter().map(with_groups).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Binding strength of an expression when used as a binary operand.
+fn binding(expr: &Expression) -> u8 {
+    match expr {
+        Expression::Binary { op, .. } => op.precedence(),
+        _ => u8::MAX,
+    }
+}
+
+/// Returns true if the expression references the variable `x`.
+pub(super) fn depends_on(expr: &Expression, x: &str) -> bool {
+    match expr {
+        Expression::Variable(name) => name == x,
+        Expression::Binary { left, right, .. } => depends_on(left, x) || depends_on(right, x),
+        Expression::Negate(inner) | Expression::Group(inner) => depends_on(inner, x),
+        Expression::Power { base, exponent } => depends_on(base, x) || depends_on(exponent, x),
+        Expression::FunctionCall { args, .. } => args.iter().any(|a| depends_on(a, x)),
+        _ => false,
+    }
+}
+
+/// Returns true if the expression divides by zero somewhere, as `0/(x - x)`
+/// does once folded.
+pub(super) fn divides_by_zero(expr: &Expression) -> bool {
+    match expr {
+        Expression::Binary {
+            op: BinaryOp::Divide,
+            right,
+            ..
+        } if as_number(right).is_some_and(|y| y.is_zero()) => true,
+        Expression::Binary { left, right, .. } => divides_by_zero(left) || divides_by_zero(right),
+        Expression::Negate(inner) | Expression::Group(inner) => divides_by_zero(inner),
+        Expression::Power { base, exponent } => divides_by_zero(base) || divides_by_zero(exponent),
+        Expression::FunctionCall { args, .. } => args.iter().any(divides_by_zero),
+        _ => false,
+    }
+}
+
+/// Replaces every occurrence of the variable `x` with `replacement`.
+pub(super) fn substitute(expr: &Expression, x: &str, replacement: &Expression) -> Expression {
+    match expr {
+        Expression::Variable(name) if name == x => replacement.clone(),
+        Expression::Binary { left, op, right } => Expression::binary(
+            substitute(left, x, replacement),
+            *op,
+            substitute(right, x, replacement),
+        ),
+        Expression::Negate(inner) => Expression::negate(substitute(inner, x, replacement)),
+        Expression::Group(inner) => Expression::group(substitute(inner, x, replacement)),
+        Expression::Power { base, exponent } => Expression::power(
+            substitute(base, x, replacement),
+            substitute(exponent, x, replacement),
+        ),
+        Expression::FunctionCall { name, args } => Expression::function_call(
+            name.clone(),
+            args.iter().map(|a| substitute(a, x, replacement)).collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Evaluates a purely mathematical expression with `x` bound to `value`.
+pub(super) fn eval_at(expr: &Expression, x: &str, value: f64) -> Option<f64> {
+    let result = match expr {
+        Expression::Number { .. } => as_number(expr)?.to_f64(),
+        Expression::Variable(name) if name == x => value,
+        Expression::Binary { left, op, right } => {
+            let l = eval_at(left, x, value)?;
+            let r = eval_at(right, x, value)?;
+            match op {
+                BinaryOp::Add => l + r,
+                BinaryOp::Subtract => l - r,
+                BinaryOp::Multiply => l * r,
+                BinaryOp::Divide => l / r,
+                BinaryOp::Modulo => l % r,
+            }
+        }
+        Expression::Negate(inner) => -eval_at(inner, x, value)?,
+        Expression::Group(inner) => eval_at(inner, x, value)?,
+        Expression::Power { base, exponent } => {
+            eval_at(base, x, value)?.powf(eval_at(exponent, x, value)?)
+        }
+        Expression::FunctionCall { name, args } => {
+            let args = args
+                .iter()
+                .map(|a| eval_at(a, x, value).and_then(Decimal::try_from_f64))
+                .collect::<Option<Vec<_>>>()?;
+            evaluate_function(name, &args).ok()?.to_f64()
+        }
+        _ => return None,
+    };
+    result.is_finite().then_some(result)
+}
+
+/// Returns polynomial coefficients (constant term first) if `expr` is a
+/// polynomial in `x` with numeric coefficients.
+pub(super) fn poly_coeffs(expr: &Expression, x: &str) -> Option<Vec<Decimal>> {
+    let coeffs = match expr {
+        Expression::Number { .. } => vec![as_number(expr)?],
+        Expression::Variable(name) if name == x => vec![Decimal::zero(), Decimal::one()],
+        Expression::Group(inner) => poly_coeffs(inner, x)?,
+        Expression::Negate(inner) => poly_coeffs(inner, x)?.into_iter().map(|c| -c).collect(),
+        Expression::Binary { left, op, right } => {
+            let l = poly_coeffs(left, x)?;
+            match op {
+                BinaryOp::Add | BinaryOp::Subtract => {
+                    let r = poly_coeffs(right, x)?;
+                    let sign = if *op == BinaryOp::Add {
+                        Decimal::one()
+                    } else {
+                        -Decimal::one()
+                    };
+                    (0..l.len().max(r.len()))
+                        .map(|i| {
+                            l.get(i).copied().unwrap_or_default()
+                                + sign * r.get(i).copied().unwrap_or_default()
+                        })
+                        .collect()
+                }
+                BinaryOp::Multiply => poly_mul(&l, &poly_coeffs(right, x)?)?,
+                BinaryOp::Divide => {
+                    let d = as_number(right)?;
+                    l.iter()
+                        .map(|c| c.checked_div(&d).filter(|q| *q * d == *c))
+                        .collect::<Option<Vec<_>>>()?
+                }
+                BinaryOp::Modulo => return None,
+            }
+        }
+        Expression::Power { base, exponent } => {
+            let n = as_number(exponent)?;
+            if n != n.round(0) || n.is_negative() || n.to_f64() > MAX_POLY_DEGREE as f64 {
+                return None;
+            }
+            let n = usize::try_from(n.to_f64() as i64).ok()?;
+            let b = poly_coeffs(base, x)?;
+            let mut result = vec![Decimal::one()];
+            for _ in 0..n {
+                result = poly_mul(&result, &b)?;
+            }
+            result
+        }
+        _ => return None,
+    };
+    (coeffs.len() <= MAX_POLY_DEGREE + 1).then_some(coeffs)
+}
+
+fn poly_mul(a: &[Decimal], b: &[Decimal]) -> Option<Vec<Decimal>> {
+    if a.len() + b.len() > MAX_POLY_DEGREE + 2 {
+        return None;
+    }
+    let mut result = vec![Decimal::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] = result[i + j] + *x * *y;
+        }
+    }
+    Some(result)
+}
+
+/// Degree of a coefficient list, ignoring trailing zeros.
+pub(super) fn poly_degree(coeffs: &[Decimal]) -> Option<usize> {
+    coeffs.iter().rposition(|c| !c.is_zero())
+}
+
+/// Builds `c_n * x^n + ... + c_1 * x + c_0` from coefficients.
+pub(super) fn from_poly(coeffs: &[Decimal], x: &str) -> Expression {
+    let mut result = int(0);
+    for (power, coeff) in coeffs.iter().enumerate().rev() {
+        if coeff.is_zero() {
+            continue;
+        }
+        let term = scale(*coeff, pow(var(x), int(power as i64)));
+        result = add(result, term);
+    }
+    result
+}
+
+/// Symbolically differentiates `expr` with respect to `x`.
+pub(super) fn differentiate(expr: &Expression, x: &str) -> Option<Expression> {
+    if !depends_on(expr, x) {
+        return Some(int(0));
+    }
+    Some(match expr {
+        Expression::Variable(_) => int(1),
+        Expression::Group(inner) => differentiate(inner, x)?,
+        Expression::Negate(inner) => neg(differentiate(inner, x)?),
+        Expression::Binary { left, op, right } => {
+            let dl = differentiate(left, x)?;
+            let dr = differentiate(right, x)?;
+            let (l, r) = ((**left).clone(), (**right).clone());
+            match op {
+                BinaryOp::Add => add(dl, dr),
+                BinaryOp::Subtract => sub(dl, dr),
+                BinaryOp::Multiply => add(mul(dl, r), mul(l, dr)),
+                BinaryOp::Divide if !depends_on(right, x) => div(dl, r),
+                BinaryOp::Divide => div(sub(mul(dl, r.clone()), mul(l, dr)), pow(r, int(2))),
+                BinaryOp::Modulo => return None,
+            }
+        }
+        Expression::Power { base, exponent } if !depends_on(exponent, x) => {
+            let n = (**exponent).clone();
+            let reduced = match as_number(&n) {
+                Some(v) => num(v - Decimal::one()),
+                None => sub(n.clone(), int(1)),
+            };
+            mul(
+                mul(n, pow((**base).clone(), reduced)),
+                differentiate(base, x)?,
+            )
+        }
+        Expression::Power { base, exponent } if !depends_on(base, x) => {
+            let ln_base = if is_euler(base) {
+                int(1)
+            } else {
+                call("ln", (**base).clone())
+            };
+            mul(mul(expr.clone(), ln_base), differentiate(exponent, x)?)
+        }
+        Expression::FunctionCall { name, args } if args.len() == 1 => {
+            let u = args[0].clone();
+            let du = differentiate(&u, x)?;
+            let outer = match name.to_lowercase().as_str() {
+                "sin" => call("cos", u),
+                "cos" => neg(call("sin", u)),
+                "tan" => div(int(1), pow(call("cos", u), int(2))),
+                "exp" => call("exp", u),
+                "ln" => div(int(1), u),
+                "log" | "log10" => div(int(1), mul(u, call("ln", int(10)))),
+                "log2" => div(int(1), mul(u, call("ln", int(2)))),
+                "sqrt" => div(int(1), mul(int(2), call("sqrt", u))),
+                "sinh" => call("cosh", u),
+                "cosh" => call("sinh", u),
+                "tanh" => div(int(1), pow(call("cosh", u), int(2))),
+                "atan" => div(int(1), add(int(1), pow(u, int(2)))),
+                "asin" => div(int(1), call("sqrt", sub(int(1), pow(u, int(2))))),
+                "acos" => neg(div(int(1), call("sqrt", sub(int(1), pow(u, int(2)))))),
+                _ => return None,
+            };
+            mul(outer, du)
+        }
+        _ => return None,
+    })
+}
+
+/// Returns true for Euler's number written as the constant `e`.
+pub(super) fn is_euler(expr: &Expression) -> bool {
+    matches!(expr, Expression::FunctionCall { name, args } if args.is_empty() && name.eq_ignore_ascii_case("e"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn show(expr: &Expression) -> String {
+        with_groups(expr).to_string()
+    }
+
+    #[test]
+    fn test_builders_fold_constants() {
+        assert_eq!(show(&add(int(2), int(3))), "5");
+        assert_eq!(show(&mul(int(1), var("x"))), "x");
+        assert_eq!(show(&mul(var("x"), int(3))), "3 * x");
+        assert_eq!(show(&sub(var("x"), neg(var("y")))), "x + y");
+        assert_eq!(show(&div(int(1), int(3))), "1 / 3");
+        assert_eq!(show(&div(int(6), int(4))), "1.5");
+    }
+
+    #[test]
+    fn test_with_groups_adds_needed_parentheses() {
+        let expr = mul(int(2), add(var("x"), int(1)));
+        assert_eq!(show(&expr), "2 * (x + 1)");
+        let expr = pow(add(var("x"), int(1)), int(2));
+        assert_eq!(show(&expr), "(x + 1)^2");
+        let expr = sub(var("x"), sub(var("y"), int(1)));
+        assert_eq!(show(&expr), "x - (y - 1)");
+    }
+
+    #[test]
+    fn test_differentiate_polynomial() {
+        let expr = add(pow(var("x"), int(3)), mul(int(2), var("x")));
+        let derivative = differentiate(&expr, "x").unwrap();
+        assert_eq!(show(&derivative), "3 * x^2 + 2");
+    }
+
+    #[test]
+    fn test_differentiate_chain_rule() {
+        let expr = call("sin", pow(var("x"), int(2)));
+        let derivative = differentiate(&expr, "x").unwrap();
+        let expected = 2.0 * 1.0_f64.cos();
+        assert!((eval_at(&derivative, "x", 1.0).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_poly_coeffs() {
+        let expr = mul(add(var("x"), int(1)), sub(var("x"), int(1)));
+        let coeffs = poly_coeffs(&expr, "x").unwrap();
+        assert_eq!(
+            coeffs,
+            vec![Decimal::new(-1), Decimal::zero(), Decimal::one()]
+        );
+        assert!(poly_coeffs(&call("sin", var("x")), "x").is_none());
+    }
+}
@@ -0,0 +1,49 @@
+//! Lengths of quoted text:
+//! - `length("hello")`: characters, written `length of "hello"`
+//! - `utf8_bytes("привет")`: bytes in UTF-8, written `utf8 bytes of "привет"`
+//! - `words("the quick brown fox")`: words separated by whitespace, written
+//!   `words in "the quick brown fox"`
+//!
+//! Characters are Unicode scalar values, so a letter with a combining accent
+//! counts as two.
+
+use crate::error::CalculatorError;
+use crate::function_registry::{lookup, FunctionCategory};
+use crate::types::{DataSizeUnit, Unit, Value, ValueKind};
+
+/// Returns true if `name` is a registered text function.
+#[must_use]
+pub fn is_text_function(name: &str) -> bool {
+    lookup(name).is_some_and(|function| function.category == FunctionCategory::Text)
+}
+
+/// Evaluates a text function.
+pub fn evaluate_text_function(name: &str, args: &[Value]) -> Result<Value, CalculatorError> {
+    let name = name.to_lowercase();
+    let text = match args {
+        [Value {
+            kind: ValueKind::Text(text),
+            ..
+        }] => text,
+        [_] => {
+            return Err(CalculatorError::invalid_args(
+                &name,
+                "the argument must be quoted text such as \"hello\"",
+            ))
+        }
+        _ => {
+            return Err(CalculatorError::invalid_args(
+                &name,
+                format!("expected 1 argument: {name}(\"text\")"),
+            ))
+        }
+    };
+    let count = |n: usize| i64::try_from(n).unwrap_or(i64::MAX);
+    Ok(match name.as_str() {
+        "utf8_bytes" => {
+            Value::from_integer_with_unit(count(text.len()), Unit::DataSize(DataSizeUnit::Byte))
+        }
+        "words" => Value::from_integer(count(text.split_whitespace().count())),
+        _ => Value::from_integer(count(text.chars().count())),
+    })
+}
@@ -1,10 +1,37 @@
 //! Token-based expression parser.
+mod angle;
+mod calendar;
 mod comparison;
+mod countdown;
+mod datetime;
+mod duration;
+mod equivalence;
+mod factors;
+mod finance;
+mod geo;
+mod integral;
+mod list;
+mod operators;
+mod physics;
+mod plot;
+mod precision;
+mod proportion;
+mod rate;
+mod text;
 mod units;
+mod variables;
+mod words;
+mod world_clock;
 
 use crate::error::CalculatorError;
-use crate::grammar::{is_math_function, DateTimeGrammar, NumberGrammar, Token, TokenKind};
-use crate::types::{BinaryOp, Decimal, Expression, Unit};
+use crate::function_registry::takes_word_arguments;
+use crate::grammar::{
+    default_reference_date, is_math_function, DateTimeGrammar, NumberGrammar, Token, TokenKind,
+};
+use crate::types::{AngleUnit, ColorModel, Decimal, Expression, Rational, Unit};
+pub use operators::infix_operators;
+use operators::{Operands, Precedence};
+pub use plot::AUTO_PLOT_BOUND;
 
 /// Internal token-based parser.
 pub struct TokenParser<'a> {
@@ -13,6 +40,13 @@ pub struct TokenParser<'a> {
     number_grammar: &'a NumberGrammar,
     #[allow(dead_code)]
     original_input: &'a str,
+    /// Prefix and right-associative operators currently being parsed.
+    depth: usize,
+    /// How many such operators may nest.
+    max_depth: usize,
+    /// Names read as variables rather than units, such as the `x` of
+    /// `nderiv(3x^2, x, 1)`.
+    variables: Vec<String>,
 }
 
 impl<'a> TokenParser<'a> {
@@ -26,29 +60,55 @@ impl<'a> TokenParser<'a> {
             pos: 0,
             number_grammar,
             original_input,
+            depth: 0,
+            max_depth: usize::MAX,
+            variables: Vec::new(),
         }
     }
 
-    pub fn parse_complete_expression(&mut self) -> Result<Expression, CalculatorError> {
-        let expr = self.parse_expression()?;
+    /// Limits how deeply prefix operators such as `-` and `until`, and
+    /// right-associative powers, may nest.
+    ///
+    /// These recurse without brackets, so the token nesting check cannot see
+    /// them; without a limit a long run of them overflows the stack.
+    #[must_use]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
 
-        if self.is_at_end() {
-            return Ok(expr);
+    pub fn parse_complete_expression(&mut self) -> Result<Expression, CalculatorError> {
+        let mut expr = self.parse_expression()?;
+        expr = self.parse_factors_directive(expr);
+        if let Some(precision) = self.parse_precision_directive()? {
+            expr = Expression::with_precision(expr, precision);
         }
 
-        let token = self.current().expect("non-EOF token should exist");
-        Err(CalculatorError::parse(format!(
-            "Unexpected trailing input '{}' at position {}",
-            token.text, token.start
-        )))
+        let Some(token) = self.current().filter(|_| !self.is_at_end()) else {
+            return Ok(expr);
+        };
+        Err(self.error_at(
+            self.pos,
+            format!(
+                "Unexpected trailing input '{}' at position {}",
+                token.text, token.start
+            ),
+        ))
     }
 
+    /// Parses arithmetic with the conversions, rates and times that may
+    /// follow it, such as `5 km + 300 m in miles`.
     fn parse_additive(&mut self) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_multiplicative()?;
+        let mut left = self.parse_operators(Precedence::Sum, Operands::Full)?;
 
-        while let Some(op) = self.match_additive_op() {
-            let right = self.parse_multiplicative()?;
-            left = Expression::binary(left, op, right);
+        // Sharing a bill: "86.40 USD + 18% tip split 4 ways"
+        if self.bill_split_ahead() {
+            left = self.parse_bill_split(left)?;
+        }
+
+        // Pace: "5 km in 25 minutes"
+        if self.pace_ahead() {
+            left = self.parse_pace(left)?;
         }
 
         // Check for "at" keyword
@@ -58,8 +118,15 @@ impl<'a> TokenParser<'a> {
             left = Expression::at_time(left, time);
         }
 
+        // Timesheets: "9:00 to 17:30 minus 45 minutes break"
+        if self.time_span_ahead(&left) {
+            left = self.parse_time_span(left)?;
+        }
+
         // Check for "as", "in", or "to" keyword (unit conversion, e.g. "741 KB as MB", "19 TON in USD")
-        if self.check_as() || self.check_in() || self.check_to() {
+        if (self.check_as() || self.check_in() || self.check_to())
+            && !self.precision_directive_ahead()
+        {
             self.advance(); // consume "as"/"in"/"to"
             let target_unit = self.parse_unit_for_conversion()?;
 
@@ -82,75 +149,88 @@ impl<'a> TokenParser<'a> {
         Ok(left)
     }
 
-    fn parse_multiplicative(&mut self) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_power()?;
-
-        while let Some(op) = self.match_multiplicative_op() {
-            let right = self.parse_power()?;
-            left = Expression::binary(left, op, right);
+    /// Runs `parse` one level deeper, failing once the depth limit is reached.
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Expression, CalculatorError>,
+    ) -> Result<Expression, CalculatorError> {
+        if self.depth >= self.max_depth {
+            return Err(CalculatorError::LimitExceeded(format!(
+                "expression is nested more than {} levels deep",
+                self.max_depth
+            )));
         }
-
-        Ok(left)
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
     }
 
-    fn parse_power(&mut self) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_unary()?;
-
-        // Power is right-associative: 2^3^4 = 2^(3^4)
-        if self.check(&TokenKind::Caret) {
+    fn parse_primary(&mut self) -> Result<Expression, CalculatorError> {
+        if self.variable_ahead() {
+            let Some(TokenKind::Identifier(id)) = self.current_kind() else {
+                unreachable!("a variable is an identifier");
+            };
+            let variable = Expression::variable(id.clone());
             self.advance();
-            let right = self.parse_power()?; // Right-associative recursion
-            left = Expression::power(left, right);
+            return Ok(variable);
         }
 
-        Ok(left)
-    }
+        if self.check(&TokenKind::LeftBracket) {
+            return self.parse_list();
+        }
 
-    fn parse_unary(&mut self) -> Result<Expression, CalculatorError> {
-        if self.check(&TokenKind::Minus) {
+        // Earlier results: "#3", or a range of them: "#2:#5"
+        if let Some(TokenKind::HistoryRef(number)) = self.current_kind() {
+            let number = *number;
             self.advance();
-            let expr = self.parse_unary()?;
-            return Ok(Expression::negate(expr));
+            if let (Some(TokenKind::Colon), Some(TokenKind::HistoryRef(last))) =
+                (self.current_kind(), self.peek_kind())
+            {
+                let last = *last;
+                self.pos += 2;
+                return Ok(Expression::HistoryRange(number, last));
+            }
+            return Ok(Expression::HistoryRef(Some(number)));
         }
 
-        let expr = self.parse_primary()?;
-
-        // Handle postfix percent operator: expr% → expr / 100
-        // With optional "of <rhs>": expr% of rhs → (expr / 100) * rhs
-        if matches!(self.current_kind(), Some(TokenKind::Percent))
-            && !self.percent_starts_binary_expression()
-        {
+        // Quoted text: "hello"
+        if let Some(TokenKind::Text(text)) = self.current_kind() {
+            let text = text.clone();
             self.advance();
-            let percent_expr = Expression::binary(
-                expr,
-                BinaryOp::Divide,
-                Expression::number(Decimal::new(100)),
-            );
-            if matches!(self.current_kind(), Some(TokenKind::Of)) {
-                self.advance(); // consume "of"
-                let rhs = self.parse_primary()?;
-                return Ok(Expression::binary(percent_expr, BinaryOp::Multiply, rhs));
-            }
-            return Ok(percent_expr);
+            return Ok(Expression::Text(text));
         }
 
-        // Handle postfix factorial operator: expr! → factorial(expr)
-        if matches!(self.current_kind(), Some(TokenKind::Bang)) {
+        // Colors: "#ff8800"
+        if let Some(TokenKind::HexColor(color)) = self.current_kind() {
+            let color = *color;
             self.advance();
-            return Ok(Expression::function_call("factorial", vec![expr]));
+            return Ok(Expression::number_with_unit(
+                Decimal::from(i64::from(color)),
+                Unit::Color(ColorModel::Hex),
+            ));
         }
 
-        Ok(expr)
-    }
+        // World clock: "time in Tokyo when 9am in New York"
+        if self.world_clock_ahead() {
+            return self.parse_world_clock();
+        }
 
-    fn percent_starts_binary_expression(&self) -> bool {
-        matches!(
-            self.peek_kind(),
-            Some(TokenKind::Number(_) | TokenKind::Identifier(_) | TokenKind::LeftParen)
-        )
-    }
+        // Handle "time until <datetime>" as a calendar breakdown
+        if self.check_time_until() {
+            self.advance(); // consume "time"
+            self.advance(); // consume "until"
+            let save_pos = self.pos;
+            let target = self.try_parse_until_target().or_else(|_| {
+                self.pos = save_pos;
+                self.nested(Self::parse_primary)
+            })?;
+            return Ok(Expression::function_call(
+                "time_until",
+                vec![target, Expression::Now],
+            ));
+        }
 
-    fn parse_primary(&mut self) -> Result<Expression, CalculatorError> {
         // Handle "until" keyword: "until <datetime>"
         if self.check_until() {
             self.advance(); // consume "until"
@@ -161,7 +241,7 @@ impl<'a> TokenParser<'a> {
             }
             // Fallback: parse as normal expression
             self.pos = save_pos;
-            let target = self.parse_primary()?;
+            let target = self.nested(Self::parse_primary)?;
             return Ok(Expression::Until(Box::new(target)));
         }
 
@@ -188,7 +268,7 @@ impl<'a> TokenParser<'a> {
         if let Some(TokenKind::DateLiteral(s)) = self.current_kind() {
             let s = s.clone();
             self.advance();
-            return crate::types::DateTime::parse(&s).map(Expression::DateTime);
+            return self.parse_date_literal(&s);
         }
 
         // Number with optional unit
@@ -255,13 +335,24 @@ impl<'a> TokenParser<'a> {
             // Check for unit (identifier following number that is not a function)
             let (unit, alternative_units) =
                 if let Some(TokenKind::Identifier(id)) = self.current_kind() {
-                    // Don't treat function names as units
-                    if !is_math_function(id) && !self.peek_is_left_paren() {
+                    // Don't treat function names as units, except the
+                    // angle units `deg` and `rad` that name conversions too
+                    if (!is_math_function(id) || AngleUnit::parse(id).is_some())
+                        && !self.is_variable(id)
+                        && !self.peek_is_left_paren()
+                        && NumberGrammar::operator_word(id).is_none()
+                        && !self.what_percent_ahead()
+                        && !self.factors_directive_ahead()
+                    {
                         let (unit, alts) = self
                             .number_grammar
                             .parse_unit_with_alternatives(id)
                             .unwrap_or_else(|_| (Unit::Custom(id.clone()), Vec::new()));
                         self.advance();
+                        // Units to a power: "5 m^2"
+                        let unit = self.parse_unit_exponent(unit);
+                        // Compound units: "60 km/h"
+                        let unit = self.parse_per_unit(&unit).unwrap_or(unit);
                         (unit, alts)
                     } else {
                         (Unit::None, Vec::new())
@@ -271,7 +362,9 @@ impl<'a> TokenParser<'a> {
                 };
 
             if alternative_units.is_empty() {
-                return Ok(Expression::number_with_unit(value, unit));
+                let literal =
+                    self.extend_duration_literal(Expression::number_with_unit(value, unit))?;
+                return self.extend_angle_literal(literal);
             }
             return Ok(Expression::number_with_unit_alternatives(
                 value,
@@ -280,6 +373,65 @@ impl<'a> TokenParser<'a> {
             ));
         }
 
+        // Physics formulas: "voltage with current 2 A and resistance 10 ohm"
+        if self.physics_formula_ahead() {
+            return self.parse_physics_formula();
+        }
+
+        // Equivalence: "is x^2 - 1 equivalent to (x-1)(x+1)"
+        if self.equivalence_ahead() {
+            return self.parse_equivalence();
+        }
+
+        // Calendar questions: "week number of 17 Feb 2027", "is 2028 a leap year"
+        if self.calendar_query_ahead() {
+            return self.parse_calendar_query();
+        }
+
+        // Countdowns: "working hours until Friday 5pm"
+        if self.countdown_ahead() {
+            return self.parse_countdown();
+        }
+
+        // Numbers in words: "two hundred", "a dozen", "half of 90"
+        if self.word_number_ahead() {
+            return self.parse_word_number();
+        }
+
+        // Proportions: "percent change from 80 to 100", "ratio 3:4 of 140"
+        if self.percent_change_ahead() {
+            return self.parse_percent_change();
+        }
+        if self.ratio_split_ahead() {
+            return self.parse_ratio_split();
+        }
+
+        // Text: "utf8 bytes of \"привет\"", "words in \"the quick brown fox\""
+        if self.text_question_ahead() {
+            return self.parse_text_question();
+        }
+
+        // Coordinates: "distance between (55.7558, 37.6173) and (48.8566, 2.3522)"
+        if self.distance_ahead() {
+            return self.parse_distance();
+        }
+
+        // VAT included: "gross 119 EUR at VAT 19%"
+        if self.gross_amount_ahead() {
+            return self.parse_gross_amount();
+        }
+
+        // Discounts undone: "final price 144 USD after 20% and 10% discounts, original?"
+        if self.final_price_ahead() {
+            return self.parse_original_price();
+        }
+
+        // Exchange rate lookup: "USD/EUR rate", "EUR to USD rate",
+        // "USD/EUR from 1 Jan 2026 to 31 Jan 2026"
+        if let Some(lookup) = self.parse_rate_lookup()? {
+            return Ok(lookup);
+        }
+
         // Standalone identifier (could be a function call, unit, variable, or datetime part)
         if let Some(TokenKind::Identifier(id)) = self.current_kind() {
             let id = id.clone();
@@ -310,23 +462,21 @@ impl<'a> TokenParser<'a> {
                 return Ok(Expression::Today);
             }
 
-            // Check for prefix currency symbol notation (e.g., $10, €5, £3).
-            if id.chars().count() == 1 {
-                let ch = id.chars().next().unwrap();
-                if !ch.is_ascii_alphabetic() {
-                    if let Some(currency_code) = crate::types::CurrencyDatabase::parse_currency(&id)
-                    {
-                        if let Some(TokenKind::Number(_)) = self.peek_kind() {
-                            self.advance(); // consume currency symbol
-                            if let Some(TokenKind::Number(n)) = self.current_kind() {
-                                let num_str = n.clone();
-                                self.advance();
-                                let value = self.number_grammar.parse_number(&num_str)?;
-                                return Ok(Expression::number_with_unit(
-                                    value,
-                                    Unit::currency(&currency_code),
-                                ));
-                            }
+            // Check for prefix currency symbol notation (e.g., $10, €5, £3, A$10).
+            let symbol =
+                id.chars().count() == 1 && !id.starts_with(|c: char| c.is_ascii_alphabetic());
+            if symbol || id.ends_with('$') {
+                if let Some(currency_code) = crate::types::CurrencyDatabase::parse_currency(&id) {
+                    if let Some(TokenKind::Number(_)) = self.peek_kind() {
+                        self.advance(); // consume currency symbol
+                        if let Some(TokenKind::Number(n)) = self.current_kind() {
+                            let num_str = n.clone();
+                            self.advance();
+                            let value = self.number_grammar.parse_number(&num_str)?;
+                            return Ok(Expression::number_with_unit(
+                                value,
+                                Unit::currency(&currency_code),
+                            ));
                         }
                     }
                 }
@@ -334,16 +484,40 @@ impl<'a> TokenParser<'a> {
 
             self.advance();
 
+            // "simplify <expr>" takes the rest of the input, even when it
+            // starts with a parenthesis: "simplify (x^2 - 1)/(x - 1)"
+            if id.eq_ignore_ascii_case("simplify") && !self.is_at_end() {
+                let variables = self.letter_variables(self.tokens.len());
+                let expr = self.with_variables(variables, Self::parse_expression)?;
+                return Ok(Expression::function_call("simplify", vec![expr]));
+            }
+
+            // Natural integration syntax, "integrate <expr> d<var>", even when
+            // the integrand starts with a parenthesis: "integrate (x+1)^2 dx"
+            if id.eq_ignore_ascii_case("integrate") && self.ends_with_differential() {
+                return self.parse_natural_integral();
+            }
+
             // Check if this is a function call (identifier followed by left paren)
             if self.check(&TokenKind::LeftParen) {
                 return self.parse_function_call(&id);
             }
 
+            // The latest result
+            if id.eq_ignore_ascii_case("ans") {
+                return Ok(Expression::HistoryRef(None));
+            }
+
             // Check for natural integration syntax: "integrate <expr> d<var>"
             if id.to_lowercase() == "integrate" {
                 return self.parse_natural_integral();
             }
 
+            // Check for natural plot syntax: "plot <expr> from <a> to <b>"
+            if id.eq_ignore_ascii_case("plot") {
+                return self.parse_natural_plot();
+            }
+
             // If it looks like a datetime start (month name, "time", "current", etc.), try to parse more
             if DateTimeGrammar::looks_like_datetime(&id) {
                 return self.try_parse_datetime_from_tokens(&id);
@@ -358,23 +532,23 @@ impl<'a> TokenParser<'a> {
 
             // Allow single-letter identifiers as variables (for use in integrate, etc.)
             // Variables will be validated at evaluation time
-            if id.len() == 1 && id.chars().next().unwrap().is_ascii_alphabetic() {
+            if id.len() == 1 && id.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                return Ok(Expression::variable(id));
+            }
+
+            // Infinity, accepted as an integration bound
+            if id.eq_ignore_ascii_case("inf") || id.eq_ignore_ascii_case("infinity") {
                 return Ok(Expression::variable(id));
             }
 
             // Otherwise it's probably just an identifier/unit (which is an error in expression context)
-            return Err(CalculatorError::parse(format!(
-                "Unexpected identifier: {id}"
-            )));
+            return Err(self.error_at(self.pos - 1, format!("Unexpected identifier: {id}")));
         }
 
-        Err(CalculatorError::parse(format!(
-            "Unexpected token: {:?}",
-            self.current()
-        )))
+        Err(self.error_at(self.pos, format!("Unexpected {}", self.describe_current())))
     }
 
-    fn consume_adjacent_si_suffix(&mut self, number_end: usize) -> Option<Decimal> {
+    fn consume_adjacent_si_suffix(&mut self, number_end: usize) -> Option<Rational> {
         let suffix = self.current().and_then(|token| {
             if token.start != number_end {
                 return None;
@@ -384,7 +558,7 @@ impl<'a> TokenParser<'a> {
                 return None;
             };
 
-            Some(id.clone())
+            (!self.is_variable(id)).then(|| id.clone())
         })?;
         let multiplier = NumberGrammar::si_suffix_multiplier(&suffix)?;
 
@@ -418,25 +592,52 @@ impl<'a> TokenParser<'a> {
         // We're positioned at the left paren
         self.expect(&TokenKind::LeftParen)?;
 
-        let mut args = Vec::new();
+        let variables = self.call_variables(name);
+        let mut args = self.with_variables(variables, |parser| {
+            let mut args = Vec::new();
 
-        // Check for empty argument list
-        if !self.check(&TokenKind::RightParen) {
-            // Parse first argument
-            args.push(self.parse_expression()?);
+            // Check for empty argument list
+            if !parser.check(&TokenKind::RightParen) {
+                // Parse first argument
+                args.push(parser.parse_function_argument(name)?);
 
-            // Parse remaining arguments
-            while self.check(&TokenKind::Comma) {
-                self.advance(); // consume comma
-                args.push(self.parse_expression()?);
+                // Parse remaining arguments
+                while parser.check(&TokenKind::Comma) {
+                    parser.advance(); // consume comma
+                    args.push(parser.parse_function_argument(name)?);
+                }
             }
-        }
+            Ok::<_, CalculatorError>(args)
+        })?;
 
         self.expect(&TokenKind::RightParen)?;
 
+        // age(date) is measured against today, time_until(date) against now
+        if args.len() == 1 {
+            args.extend(default_reference_date(name));
+        }
+
         Ok(Expression::function_call(name, args))
     }
 
+    /// Parses one argument of `name(...)`. Some functions also take a bare
+    /// word, such as the `male` in `bmr(male, 30 years, 180 cm, 75 kg)`.
+    fn parse_function_argument(&mut self, name: &str) -> Result<Expression, CalculatorError> {
+        if let Some(TokenKind::Identifier(word)) = self.current_kind() {
+            if takes_word_arguments(name)
+                && matches!(
+                    self.peek_kind(),
+                    Some(TokenKind::Comma | TokenKind::RightParen)
+                )
+            {
+                let word = Expression::variable(word.clone());
+                self.advance();
+                return Ok(word);
+            }
+        }
+        self.parse_expression()
+    }
+
     /// Tries to parse the remaining tokens after "until" as a datetime expression.
     /// Handles cases like "until 11:59pm EST January 26th" where the datetime
     /// starts with a number rather than a month name.
@@ -492,431 +693,6 @@ impl<'a> TokenParser<'a> {
         }
     }
 
-    /// Tries to parse a time/datetime expression that starts with a number followed by a colon,
-    /// e.g. "11:59pm EST on Monday, January 26th".
-    /// The `hour_str` is the number already consumed, and the current position is at the Colon.
-    fn try_parse_time_starting_with_number(
-        &mut self,
-        hour_str: &str,
-    ) -> Result<Expression, CalculatorError> {
-        let mut parts = vec![hour_str.to_string()];
-
-        while !self.is_at_end() {
-            match self.current_kind() {
-                Some(TokenKind::Number(n)) => {
-                    parts.push(n.clone());
-                    self.advance();
-                }
-                Some(TokenKind::Identifier(id)) => {
-                    let id_lower = id.to_lowercase();
-                    // Attach ordinal suffixes directly to preceding number
-                    if matches!(id_lower.as_str(), "st" | "nd" | "rd" | "th") {
-                        if let Some(last) = parts.last_mut() {
-                            if last.chars().all(|c| c.is_ascii_digit()) {
-                                last.push_str(id);
-                                self.advance();
-                                continue;
-                            }
-                        }
-                    }
-                    parts.push(id.clone());
-                    self.advance();
-                }
-                Some(TokenKind::Comma) => {
-                    parts.push(",".to_string());
-                    self.advance();
-                }
-                Some(TokenKind::Colon) => {
-                    parts.push(":".to_string());
-                    self.advance();
-                }
-                Some(TokenKind::At) => {
-                    let save_pos = self.pos;
-                    self.advance();
-                    if let Some(TokenKind::Identifier(tz_id)) = self.current_kind() {
-                        if crate::types::DateTime::parse_tz_abbreviation(tz_id).is_some() {
-                            parts.push(tz_id.clone());
-                            self.advance();
-                            continue;
-                        }
-                    }
-                    self.pos = save_pos;
-                    break;
-                }
-                _ => break,
-            }
-        }
-
-        let datetime_str = parts.join(" ").replace(" , ", ", ").replace(" : ", ":");
-        match crate::types::DateTime::parse(&datetime_str) {
-            Ok(dt) => Ok(Expression::DateTime(dt)),
-            Err(e) => Err(e),
-        }
-    }
-
-    /// Tries to parse "N AM/PM [TZ]" as a time expression.
-    ///
-    /// Called when the parser has consumed a number token and sees AM/PM next.
-    /// Handles patterns like "6 PM", "6 PM GMT", "6 PM MSK".
-    /// The timezone identifier is only consumed if it is a recognized timezone abbreviation.
-    fn try_parse_time_with_ampm(&mut self, hour_str: &str) -> Result<Expression, CalculatorError> {
-        // Consume the AM/PM identifier
-        let ampm = if let Some(TokenKind::Identifier(id)) = self.current_kind() {
-            let id_str = id.clone();
-            self.advance();
-            id_str
-        } else {
-            return Err(CalculatorError::parse("Expected AM/PM"));
-        };
-
-        // Check if the next identifier is a recognized timezone abbreviation
-        let mut datetime_str = format!("{hour_str}:00 {ampm}");
-        if let Some(TokenKind::Identifier(tz_id)) = self.current_kind() {
-            if crate::types::DateTime::parse_tz_abbreviation(tz_id).is_some() {
-                datetime_str = format!("{hour_str}:00 {} {}", ampm, tz_id);
-                self.advance(); // consume timezone token
-            }
-        }
-
-        match crate::types::DateTime::parse(&datetime_str) {
-            Ok(dt) => Ok(Expression::DateTime(dt)),
-            Err(e) => Err(e),
-        }
-    }
-
-    /// Tries to parse Russian "N по <TZ>" as "N:00 <TZ>".
-    fn try_parse_russian_time_by_timezone(
-        &mut self,
-        hour_str: &str,
-    ) -> Result<Expression, CalculatorError> {
-        self.advance(); // consume "по"
-
-        let Some(TokenKind::Identifier(tz_id)) = self.current_kind() else {
-            return Err(CalculatorError::parse("Expected timezone after по"));
-        };
-        let tz = tz_id.clone();
-        if crate::types::DateTime::parse_tz_abbreviation(&tz).is_none() {
-            return Err(CalculatorError::parse(format!("Unknown timezone: {tz}")));
-        }
-        self.advance();
-
-        let datetime_str = format!("{hour_str}:00 {tz}");
-        crate::types::DateTime::parse(&datetime_str).map(Expression::DateTime)
-    }
-
-    fn try_parse_datetime_from_tokens(
-        &mut self,
-        first: &str,
-    ) -> Result<Expression, CalculatorError> {
-        // Collect tokens that might be part of a datetime
-        let mut parts = vec![first.to_string()];
-        // Track token positions so we can backtrack to any prefix
-        let mut token_positions = vec![self.pos]; // position before each token was consumed
-
-        // Look for patterns like: Jan 22, 2026 or Jan 27, 8:59am UTC
-        // Also handles: Monday, January 26th, 2026
-        // Collect: numbers, identifiers, colons, commas
-        while !self.is_at_end() {
-            match self.current_kind() {
-                Some(TokenKind::Number(n)) => {
-                    token_positions.push(self.pos);
-                    parts.push(n.clone());
-                    self.advance();
-                }
-                Some(TokenKind::Identifier(id)) => {
-                    // Handle ordinal suffixes: if previous part is a number and
-                    // this is "st", "nd", "rd", or "th", attach without space
-                    let id_lower = id.to_lowercase();
-                    if matches!(id_lower.as_str(), "st" | "nd" | "rd" | "th") {
-                        // Append to previous number part (ordinal suffix)
-                        if let Some(last) = parts.last_mut() {
-                            if last.chars().all(|c| c.is_ascii_digit()) {
-                                token_positions.push(self.pos);
-                                last.push_str(id);
-                                self.advance();
-                                continue;
-                            }
-                        }
-                    }
-                    token_positions.push(self.pos);
-                    parts.push(id.clone());
-                    self.advance();
-                }
-                Some(TokenKind::Of) => {
-                    token_positions.push(self.pos);
-                    parts.push("of".to_string());
-                    self.advance();
-                }
-                Some(TokenKind::Comma) => {
-                    token_positions.push(self.pos);
-                    parts.push(",".to_string());
-                    self.advance();
-                }
-                Some(TokenKind::Colon) => {
-                    token_positions.push(self.pos);
-                    parts.push(":".to_string());
-                    self.advance();
-                }
-                _ => break,
-            }
-        }
-
-        // Try the full collected string first, then progressively shorter prefixes.
-        // This handles cases like "17 февраля 2027 - 6 months" where the datetime
-        // is "17 февраля 2027" but greedily collecting too many tokens would fail.
-        let end_pos = self.pos;
-        for len in (1..=parts.len()).rev() {
-            let candidate_parts = &parts[..len];
-            let datetime_str = candidate_parts
-                .join(" ")
-                .replace(" , ", ", ")
-                .replace(" : ", ":");
-            if let Ok(dt) = crate::types::DateTime::parse(&datetime_str) {
-                // Restore position to just after the tokens we actually consumed
-                // token_positions[len - 1] is the position *before* consuming parts[len-1]
-                // so the position after consuming parts[len-1] is:
-                // - for the last element: end_pos (we already advanced past all)
-                // - for shorter prefix: token_positions[len] (position before parts[len])
-                if len < parts.len() {
-                    self.pos = token_positions[len];
-                } else {
-                    self.pos = end_pos;
-                }
-                return Ok(Expression::DateTime(dt));
-            }
-        }
-
-        // No prefix worked — restore original position and report error
-        if parts.is_empty() {
-            return Err(crate::error::CalculatorError::parse("empty datetime"));
-        }
-        // Return error with the full string for better error messages
-        let datetime_str = parts.join(" ").replace(" , ", ", ").replace(" : ", ":");
-        Err(crate::error::CalculatorError::InvalidDateTime(format!(
-            "Could not parse '{datetime_str}' as a date or time"
-        )))
-    }
-
-    /// Parses natural integral notation: "integrate <expr> d<var>"
-    /// Examples:
-    /// - integrate sin(x)/x dx
-    /// - integrate x^2 dx
-    fn parse_natural_integral(&mut self) -> Result<Expression, CalculatorError> {
-        // We've already consumed "integrate", now we need to find the integrand and d<var>
-        // Strategy: collect tokens until we find "d<var>" pattern (identifier starting with 'd')
-
-        let start_pos = self.pos;
-        let mut integrand_end_pos = None;
-        let mut var_name = None;
-
-        // Scan forward to find the d<var> pattern
-        let mut scan_pos = self.pos;
-        while scan_pos < self.tokens.len() {
-            if let TokenKind::Identifier(id) = &self.tokens[scan_pos].kind {
-                // Check if this is a differential notation like "dx", "dy", "dt"
-                let id_lower = id.to_lowercase();
-                if id_lower.starts_with('d') && id_lower.len() == 2 {
-                    let var_char = id_lower.chars().nth(1).unwrap();
-                    if var_char.is_ascii_alphabetic() {
-                        integrand_end_pos = Some(scan_pos);
-                        var_name = Some(var_char.to_string());
-                        break;
-                    }
-                }
-            }
-            scan_pos += 1;
-        }
-
-        // If we didn't find d<var>, return an error with helpful message
-        let (Some(end_pos), Some(var)) = (integrand_end_pos, var_name) else {
-            return Err(CalculatorError::parse(
-                "Invalid integration syntax. Expected: integrate <expression> d<var> (e.g., integrate sin(x)/x dx)"
-            ));
-        };
-
-        // Reset position and parse the integrand expression
-        // We need a sub-parser that only parses up to the d<var> token
-        self.pos = start_pos;
-
-        // Parse the integrand by parsing an expression and stopping at the d<var>
-        let integrand = self.parse_integrand_until(end_pos)?;
-
-        // Now consume the d<var> token
-        self.pos = end_pos;
-        self.advance();
-
-        Ok(Expression::indefinite_integral(integrand, var))
-    }
-
-    /// Parse an integrand expression up to (but not including) the position `until_pos`.
-    fn parse_integrand_until(&mut self, until_pos: usize) -> Result<Expression, CalculatorError> {
-        // Save the tokens after until_pos temporarily
-        let original_len = self.tokens.len();
-
-        // We need to be careful - parse_expression will consume tokens
-        // We'll parse and then check we didn't go past until_pos
-        let result = self.parse_integrand_expression(until_pos)?;
-
-        // Verify we stopped at the right place
-        if self.pos > until_pos {
-            self.pos = until_pos;
-        }
-
-        let _ = original_len; // Suppress unused warning
-        Ok(result)
-    }
-
-    /// Parse integrand with awareness of the boundary.
-    fn parse_integrand_expression(
-        &mut self,
-        boundary: usize,
-    ) -> Result<Expression, CalculatorError> {
-        self.parse_integrand_additive(boundary)
-    }
-
-    fn parse_integrand_additive(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_integrand_multiplicative(boundary)?;
-
-        while self.pos < boundary {
-            if let Some(op) = self.match_additive_op() {
-                if self.pos >= boundary {
-                    // Put the operator back
-                    self.pos -= 1;
-                    break;
-                }
-                let right = self.parse_integrand_multiplicative(boundary)?;
-                left = Expression::binary(left, op, right);
-            } else {
-                break;
-            }
-        }
-
-        Ok(left)
-    }
-
-    fn parse_integrand_multiplicative(
-        &mut self,
-        boundary: usize,
-    ) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_integrand_power(boundary)?;
-
-        while self.pos < boundary {
-            if let Some(op) = self.match_multiplicative_op() {
-                if self.pos >= boundary {
-                    // Put the operator back
-                    self.pos -= 1;
-                    break;
-                }
-                let right = self.parse_integrand_power(boundary)?;
-                left = Expression::binary(left, op, right);
-            } else {
-                break;
-            }
-        }
-
-        Ok(left)
-    }
-
-    fn parse_integrand_power(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
-        let mut left = self.parse_integrand_unary(boundary)?;
-
-        if self.pos < boundary && self.check(&TokenKind::Caret) {
-            self.advance();
-            let right = self.parse_integrand_power(boundary)?;
-            left = Expression::power(left, right);
-        }
-
-        Ok(left)
-    }
-
-    fn parse_integrand_unary(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
-        if self.pos < boundary && self.check(&TokenKind::Minus) {
-            self.advance();
-            let expr = self.parse_integrand_unary(boundary)?;
-            return Ok(Expression::negate(expr));
-        }
-
-        self.parse_integrand_primary(boundary)
-    }
-
-    fn parse_integrand_primary(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
-        if self.pos >= boundary {
-            return Err(CalculatorError::parse("Unexpected end of integrand"));
-        }
-
-        // Parenthesized expression
-        if self.check(&TokenKind::LeftParen) {
-            self.advance();
-            let expr = self.parse_expression()?;
-            self.expect(&TokenKind::RightParen)?;
-            return Ok(Expression::group(expr));
-        }
-
-        // Number
-        if let Some(TokenKind::Number(n)) = self.current_kind() {
-            let num_str = n.clone();
-            self.advance();
-            let value = self.number_grammar.parse_number(&num_str)?;
-            return Ok(Expression::number(value));
-        }
-
-        // Identifier (function call or variable)
-        if let Some(TokenKind::Identifier(id)) = self.current_kind() {
-            let id = id.clone();
-            self.advance();
-
-            // Check if this is a function call
-            if self.pos < boundary && self.check(&TokenKind::LeftParen) {
-                return self.parse_function_call(&id);
-            }
-
-            // Check if this is a math constant
-            if is_math_function(&id) {
-                return Ok(Expression::function_call(id, vec![]));
-            }
-
-            // Single-letter identifier is a variable
-            if id.len() == 1 && id.chars().next().unwrap().is_ascii_alphabetic() {
-                return Ok(Expression::variable(id));
-            }
-
-            // Multi-letter identifier could be an implicit variable in integration context
-            return Ok(Expression::variable(id));
-        }
-
-        Err(CalculatorError::parse(format!(
-            "Unexpected token in integrand: {:?}",
-            self.current()
-        )))
-    }
-
-    fn match_additive_op(&mut self) -> Option<BinaryOp> {
-        if self.check(&TokenKind::Plus) {
-            self.advance();
-            Some(BinaryOp::Add)
-        } else if self.check(&TokenKind::Minus) {
-            self.advance();
-            Some(BinaryOp::Subtract)
-        } else {
-            None
-        }
-    }
-
-    fn match_multiplicative_op(&mut self) -> Option<BinaryOp> {
-        if self.check(&TokenKind::Star) {
-            self.advance();
-            Some(BinaryOp::Multiply)
-        } else if self.check(&TokenKind::Slash) {
-            self.advance();
-            Some(BinaryOp::Divide)
-        } else if self.check(&TokenKind::Percent) && self.percent_starts_binary_expression() {
-            self.advance();
-            Some(BinaryOp::Modulo)
-        } else {
-            None
-        }
-    }
-
     fn check(&self, kind: &TokenKind) -> bool {
         self.current_kind()
             .is_some_and(|k| std::mem::discriminant(k) == std::mem::discriminant(kind))
@@ -938,6 +714,11 @@ impl<'a> TokenParser<'a> {
         matches!(self.current_kind(), Some(TokenKind::To))
     }
 
+    fn check_time_until(&self) -> bool {
+        matches!(self.current_kind(), Some(TokenKind::Identifier(id)) if id.eq_ignore_ascii_case("time"))
+            && matches!(self.peek_kind(), Some(TokenKind::Until))
+    }
+
     fn check_until(&self) -> bool {
         matches!(self.current_kind(), Some(TokenKind::Until))
     }
@@ -973,11 +754,40 @@ impl<'a> TokenParser<'a> {
             self.advance();
             Ok(())
         } else {
-            Err(CalculatorError::unexpected_token(
-                &format!("{:?}", self.current_kind()),
-                &format!("{kind:?}"),
-                self.pos,
-            ))
+            let expected = match kind {
+                TokenKind::LeftParen => "'('",
+                TokenKind::RightParen => "')'",
+                TokenKind::LeftBracket => "'['",
+                TokenKind::RightBracket => "']'",
+                TokenKind::Comma => "','",
+                TokenKind::And => "'and'",
+                _ => "token",
+            };
+            let found = self.describe_current();
+            Err(self.error_at(self.pos, format!("Expected {expected}, found {found}")))
+        }
+    }
+
+    /// Describes the current token for error messages, e.g. `')'` or
+    /// `end of input`.
+    fn describe_current(&self) -> String {
+        match self.current() {
+            Some(token) if token.kind != TokenKind::Eof => format!("'{}'", token.text),
+            _ => "end of input".to_string(),
         }
     }
+
+    /// Creates a parse error pointing at the token with the given index.
+    ///
+    /// Past the last token the error points at the end of the input.
+    fn error_at(&self, index: usize, msg: impl Into<String>) -> CalculatorError {
+        let (start, end) = self.tokens.get(index).map_or_else(
+            || {
+                let end = self.original_input.chars().count();
+                (end, end)
+            },
+            |token| (token.start, token.end),
+        );
+        CalculatorError::parse_at(msg, start, end)
+    }
 }
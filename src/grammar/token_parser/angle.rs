@@ -0,0 +1,80 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{AngleUnit, BinaryOp, Expression, Rational, Unit};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Extends an angle in degrees with the minutes and seconds that follow
+    /// it, so `45° 30' 15"` reads as one angle and is shown the same way.
+    /// Minutes and seconds must be below 60, as `45° 61'` is a typo rather
+    /// than `46° 1'`.
+    /// A compass direction after the angle gives its sign, as coordinates
+    /// are written: `N` and `E` are positive, `S` and `W` negative.
+    pub(super) fn extend_angle_literal(
+        &mut self,
+        first: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        if !matches!(
+            first,
+            Expression::Number {
+                unit: Unit::Angle(AngleUnit::Degree),
+                ..
+            }
+        ) {
+            return Ok(first);
+        }
+
+        let mut literal = first;
+        for unit in [AngleUnit::Arcminute, AngleUnit::Arcsecond] {
+            let Some(amount) = self.angle_part_ahead(unit) else {
+                continue;
+            };
+            let amount = self.number_grammar.parse_number(&amount)?;
+            if amount >= Rational::from_integer(60) {
+                let part = if unit == AngleUnit::Arcminute {
+                    "Minutes"
+                } else {
+                    "Seconds"
+                };
+                return Err(self.error_at(
+                    self.pos,
+                    format!("{part} of an angle must be below 60, got {amount}"),
+                ));
+            }
+            self.pos += 2;
+            let part = Expression::number_with_unit(amount, Unit::Angle(unit));
+            literal = Expression::binary(literal, BinaryOp::Add, part);
+        }
+        if matches!(literal, Expression::Binary { .. }) {
+            literal = Expression::unit_conversion(
+                Expression::group(literal),
+                Unit::Angle(AngleUnit::Dms),
+            );
+        }
+
+        let negative = match self.current_kind() {
+            Some(TokenKind::Identifier(id)) if matches!(id.as_str(), "N" | "E") => false,
+            Some(TokenKind::Identifier(id)) if matches!(id.as_str(), "S" | "W") => true,
+            _ => return Ok(literal),
+        };
+        self.advance();
+        Ok(if negative {
+            Expression::negate(literal)
+        } else {
+            literal
+        })
+    }
+
+    /// Returns the number of a `<number> <unit>` part at the current token
+    /// if it is in the angle unit `unit`.
+    fn angle_part_ahead(&self, unit: AngleUnit) -> Option<String> {
+        let Some(TokenKind::Number(amount)) = self.current_kind() else {
+            return None;
+        };
+        let Some(TokenKind::Identifier(id)) = self.peek_kind() else {
+            return None;
+        };
+        (AngleUnit::parse(id) == Some(unit)).then(|| amount.clone())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns the calendar function asked for by a question starting at the
+    /// current token, and the number of words before its date or year.
+    fn calendar_query(&self) -> Option<(&'static str, usize)> {
+        let of_at =
+            |index: usize| matches!(self.tokens.get(index).map(|t| &t.kind), Some(TokenKind::Of));
+        let pos = self.pos;
+        if self.word_at(pos, "week") && self.word_at(pos + 1, "number") && of_at(pos + 2) {
+            return Some(("week_number", 3));
+        }
+        if self.word_at(pos, "day") && of_at(pos + 1) {
+            let week = if self.word_at(pos + 2, "the") {
+                pos + 3
+            } else {
+                pos + 2
+            };
+            if self.word_at(week, "week") && of_at(week + 1) {
+                return Some(("day_of_week", week + 2 - pos));
+            }
+        }
+        if self.word_at(pos, "days")
+            && matches!(
+                self.tokens.get(pos + 1).map(|t| &t.kind),
+                Some(TokenKind::In)
+            )
+        {
+            return Some(("days_in_month", 2));
+        }
+        if self.word_at(pos, "is")
+            && (pos + 2..self.tokens.len())
+                .any(|i| self.word_at(i, "leap") && self.word_at(i + 1, "year"))
+        {
+            return Some(("is_leap_year", 1));
+        }
+        None
+    }
+
+    /// Returns true if a calendar question such as `week number of <date>`
+    /// starts at the current token.
+    pub(super) fn calendar_query_ahead(&self) -> bool {
+        self.calendar_query().is_some()
+    }
+
+    /// Parses a calendar question:
+    /// - `week number of <date>`
+    /// - `day of [the] week of <date>`
+    /// - `days in <month> <year>` or `days in <year>`
+    /// - `is <year> [a] leap year`
+    pub(super) fn parse_calendar_query(&mut self) -> Result<Expression, CalculatorError> {
+        let Some((mut name, words)) = self.calendar_query() else {
+            return Err(self.error_at(self.pos, "Expected a calendar question"));
+        };
+        self.pos += words;
+
+        // A bare year is read on its own, so "2028 a" is not a unit
+        let year_ahead = matches!(self.current_kind(), Some(TokenKind::Number(_)))
+            && (self.word_at(self.pos + 1, "a")
+                || self.word_at(self.pos + 1, "leap")
+                || self.is_at_end_after(1));
+        let target = if year_ahead {
+            self.parse_plain_number()?
+        } else {
+            self.nested(Self::parse_primary)?
+        };
+        if name == "days_in_month" && matches!(target, Expression::Number { .. }) {
+            name = "days_in_year";
+        }
+
+        if name == "is_leap_year" {
+            if self.word_at(self.pos, "a") {
+                self.advance();
+            }
+            if !(self.word_at(self.pos, "leap") && self.word_at(self.pos + 1, "year")) {
+                return Err(self.error_at(self.pos, "Expected 'leap year'"));
+            }
+            self.pos += 2;
+        }
+
+        Ok(Expression::function_call(name, vec![target]))
+    }
+
+    /// Returns true if only the end of input follows the next `count` tokens.
+    pub(super) fn is_at_end_after(&self, count: usize) -> bool {
+        matches!(
+            self.tokens.get(self.pos + count).map(|t| &t.kind),
+            Some(TokenKind::Eof) | None
+        )
+    }
+}
@@ -16,21 +16,39 @@ impl TokenParser<'_> {
 
         if self.check_compare() {
             self.advance(); // consume "compare"
-            let left = self.parse_additive()?;
-            self.expect(&TokenKind::And)?;
-            let right = self.parse_additive()?;
-            return Ok(Expression::comparison(left, ComparisonOp::Compare, right));
+            let left = self.parse_offer()?;
+            if self.check_vs() {
+                self.advance();
+            } else {
+                self.expect(&TokenKind::And)?;
+            }
+            let right = self.parse_offer()?;
+            return self.compare_offers(left, right);
         }
 
         let left = self.parse_additive()?;
 
+        if self.word_at(self.pos, "for") {
+            let left = (left, self.parse_offer_quantity()?);
+            if !self.check_vs() {
+                return Err(self.error_at(self.pos, "Expected 'vs' and another offer"));
+            }
+            self.advance(); // consume "vs"
+            let right = self.parse_offer()?;
+            return self.compare_offers(left, right);
+        }
+
+        if self.what_percent_ahead() {
+            return self.parse_what_percent(left);
+        }
+
         if self.check_vs() {
             self.advance(); // consume "vs"
             let right = self.parse_additive()?;
             return Ok(Expression::comparison(left, ComparisonOp::Compare, right));
         }
 
-        if self.check(&TokenKind::Equals) {
+        if self.check(&TokenKind::Equals) && !self.precision_directive_ahead() {
             self.advance(); // consume '=' or '=='
             let right = self.parse_additive()?;
             return Ok(Expression::equality(left, right));
@@ -82,6 +100,46 @@ impl TokenParser<'_> {
         )))
     }
 
+    /// Parses a price, or an offer of a price for a quantity: `8 USD for
+    /// 550 g`.
+    fn parse_offer(&mut self) -> Result<(Expression, Option<Expression>), CalculatorError> {
+        let price = self.parse_additive()?;
+        Ok((price, self.parse_offer_quantity()?))
+    }
+
+    /// Parses the `for <quantity>` of an offer, if there is one.
+    fn parse_offer_quantity(&mut self) -> Result<Option<Expression>, CalculatorError> {
+        if !self.word_at(self.pos, "for") {
+            return Ok(None);
+        }
+        self.advance(); // consume "for"
+        self.parse_additive().map(Some)
+    }
+
+    /// Compares two values, or, when both are offers of a price for a
+    /// quantity, finds the better deal by their unit prices.
+    fn compare_offers(
+        &self,
+        left: (Expression, Option<Expression>),
+        right: (Expression, Option<Expression>),
+    ) -> Result<Expression, CalculatorError> {
+        match (left, right) {
+            ((left, None), (right, None)) => {
+                Ok(Expression::comparison(left, ComparisonOp::Compare, right))
+            }
+            ((left_price, Some(left_quantity)), (right_price, Some(right_quantity))) => {
+                Ok(Expression::function_call(
+                    "better_deal",
+                    vec![left_price, left_quantity, right_price, right_quantity],
+                ))
+            }
+            _ => Err(self.error_at(
+                self.pos.saturating_sub(1),
+                "Expected both offers to have a quantity, as in '5 USD for 300 g'",
+            )),
+        }
+    }
+
     fn match_ordering_op(&mut self) -> Option<ComparisonOp> {
         let op = match self.current_kind()? {
             TokenKind::DoubleEquals => ComparisonOp::Equal,
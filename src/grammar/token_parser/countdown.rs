@@ -0,0 +1,83 @@
+//! Countdowns to a deadline: `working hours until Friday 5pm` and `how
+//! many weekends until 1 Jun 2026`.
+
+use chrono::Weekday;
+
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns the countdown function asked for at the current token, and
+    /// the number of words before its deadline.
+    fn countdown(&self) -> Option<(&'static str, usize)> {
+        let until_at = |index: usize| {
+            matches!(
+                self.tokens.get(index).map(|t| &t.kind),
+                Some(TokenKind::Until)
+            )
+        };
+        let pos = self.pos;
+        if self.word_at(pos, "working") && self.word_at(pos + 1, "hours") && until_at(pos + 2) {
+            return Some(("working_hours_until", 3));
+        }
+        let weekends = if self.word_at(pos, "how") && self.word_at(pos + 1, "many") {
+            pos + 2
+        } else {
+            pos
+        };
+        (self.word_at(weekends, "weekends") && until_at(weekends + 1))
+            .then_some(("weekends_until", weekends + 2 - pos))
+    }
+
+    /// Returns true if a countdown such as `working hours until <date>`
+    /// starts at the current token.
+    pub(super) fn countdown_ahead(&self) -> bool {
+        self.countdown().is_some()
+    }
+
+    /// Parses a countdown to a deadline from now. The deadline may be a day
+    /// of the week, with or without a time, as in `Friday 5pm`: the next
+    /// such day.
+    pub(super) fn parse_countdown(&mut self) -> Result<Expression, CalculatorError> {
+        let Some((name, words)) = self.countdown() else {
+            return Err(self.error_at(self.pos, "Expected a countdown"));
+        };
+        self.pos += words;
+        let deadline = if let Some(deadline) = self.weekday_deadline()? {
+            deadline
+        } else {
+            let save_pos = self.pos;
+            self.try_parse_until_target().or_else(|_| {
+                self.pos = save_pos;
+                self.nested(Self::parse_primary)
+            })?
+        };
+        Ok(Expression::function_call(
+            name,
+            vec![deadline, Expression::Now],
+        ))
+    }
+
+    /// Parses a day of the week ending the input or followed by a clock
+    /// time, as the next such day.
+    fn weekday_deadline(&mut self) -> Result<Option<Expression>, CalculatorError> {
+        let Some(TokenKind::Identifier(day)) = self.current_kind() else {
+            return Ok(None);
+        };
+        if day.parse::<Weekday>().is_err()
+            || !(self.is_at_end_after(1) || self.clock_time_at(self.pos + 1))
+        {
+            return Ok(None);
+        }
+        let mut args = vec![Expression::Text(day.clone())];
+        self.advance();
+        if !self.is_at_end() {
+            args.push(Expression::DateTime(self.parse_clock_time()?));
+        }
+        args.push(Expression::Now);
+        Ok(Some(Expression::function_call("next_weekday", args)))
+    }
+}
@@ -0,0 +1,361 @@
+//! Dates and times written across several tokens, like `Jan 27, 8:59am UTC`,
+//! `6 PM GMT`, `11:59pm EST on Monday, January 26th` or
+//! `2026-01-27 08:59:00 +00:00`.
+
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{BinaryOp, DateTime, Expression};
+
+use super::TokenParser;
+
+/// Words that may name the time taken off a shift, as in `minus 45 minutes
+/// break`.
+const BREAK_WORDS: &[&str] = &["break", "breaks", "lunch", "pause"];
+
+impl TokenParser<'_> {
+    /// Parses the time that may follow a numeric date literal, already
+    /// consumed, as in `2026-01-30 12:00:00 UTC`: the form dates with times
+    /// are displayed in, and so written in links notation.
+    pub(super) fn parse_date_literal(&mut self, date: &str) -> Result<Expression, CalculatorError> {
+        let time_ahead = matches!(self.current_kind(), Some(TokenKind::Number(_)))
+            && matches!(self.peek_kind(), Some(TokenKind::Colon));
+        if time_ahead {
+            let save_pos = self.pos;
+            let time = self.collect_clock_time();
+            if let Ok(dt) = DateTime::parse(&format!("{date} {time}")) {
+                return Ok(Expression::DateTime(dt));
+            }
+            self.pos = save_pos;
+        }
+        DateTime::parse(date).map(Expression::DateTime)
+    }
+
+    /// Checks if `start`, a clock time without a date, is followed by `to`
+    /// and another clock time, as in the timesheet entry `9:00 to 17:30`.
+    pub(super) fn time_span_ahead(&self, start: &Expression) -> bool {
+        let is_clock_time =
+            matches!(start, Expression::DateTime(dt) if dt.has_time() && !dt.has_date());
+        is_clock_time && self.check_to() && self.clock_time_at(self.pos + 1)
+    }
+
+    /// Checks if a clock time such as `17:30` or `5pm` starts at token
+    /// `index`.
+    pub(super) fn clock_time_at(&self, index: usize) -> bool {
+        let kind = |index: usize| self.tokens.get(index).map(|token| &token.kind);
+        matches!(kind(index), Some(TokenKind::Number(_)))
+            && match kind(index + 1) {
+                Some(TokenKind::Colon) => true,
+                Some(TokenKind::Identifier(id)) => {
+                    id.eq_ignore_ascii_case("am") || id.eq_ignore_ascii_case("pm")
+                }
+                _ => false,
+            }
+    }
+
+    /// Parses `to <time>` after the clock time `start` as the time worked
+    /// between them, and the breaks taken off or time added after it:
+    /// `9:00 to 17:30 minus 45 minutes break`.
+    pub(super) fn parse_time_span(
+        &mut self,
+        start: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "to"
+        let end = self.parse_clock_time()?;
+        let mut span =
+            Expression::function_call("time_span", vec![start, Expression::DateTime(end)]);
+        loop {
+            let (op, width) = match self.current_kind() {
+                Some(TokenKind::Plus) => (BinaryOp::Add, 1),
+                Some(TokenKind::Minus) => (BinaryOp::Subtract, 1),
+                _ => match self.operator_word_ahead() {
+                    Some((op @ (BinaryOp::Add | BinaryOp::Subtract), width)) => (op, width),
+                    _ => break,
+                },
+            };
+            self.pos += width;
+            let amount = self.parse_multiplicative()?;
+            if BREAK_WORDS.iter().any(|word| self.word_at(self.pos, word)) {
+                self.advance();
+            }
+            span = Expression::binary(span, op, amount);
+        }
+        Ok(span)
+    }
+
+    /// Parses a clock time starting at an hour, such as `17:30`, `5pm` or
+    /// `9am EST`.
+    pub(super) fn parse_clock_time(&mut self) -> Result<DateTime, CalculatorError> {
+        let mut time = self.collect_clock_time();
+        if !time.contains(':') {
+            // "5pm" is read as "5:00pm"
+            let hour_len = time.chars().take_while(char::is_ascii_digit).count();
+            time.insert_str(hour_len, ":00");
+        }
+        DateTime::parse(&time)
+    }
+
+    /// Collects a clock time starting at an hour, such as `8:59am`,
+    /// `12:00:00 UTC` or `08:59:00 +00:00`.
+    fn collect_clock_time(&mut self) -> String {
+        let mut time = String::new();
+        if let Some(TokenKind::Number(hour)) = self.current_kind() {
+            time.push_str(hour);
+            self.advance();
+        }
+        while let (Some(TokenKind::Colon), Some(TokenKind::Number(part))) =
+            (self.current_kind(), self.peek_kind())
+        {
+            time.push(':');
+            time.push_str(part);
+            self.pos += 2;
+        }
+        if let Some(TokenKind::Identifier(id)) = self.current_kind() {
+            if id.eq_ignore_ascii_case("am") || id.eq_ignore_ascii_case("pm") {
+                time.push_str(id);
+                self.advance();
+            }
+        }
+        if let Some(TokenKind::Identifier(tz)) = self.current_kind() {
+            if DateTime::parse_tz_abbreviation(tz).is_some() {
+                time.push(' ');
+                time.push_str(tz);
+                self.advance();
+            }
+        } else if let Some(offset) = self.utc_offset_ahead() {
+            time.push(' ');
+            time.push_str(&offset);
+            self.pos += 4;
+        }
+        time
+    }
+
+    /// Returns a UTC offset such as `+05:00` at the current token, written
+    /// with the sign against the hours so that `12:00 - 05:00` stays a
+    /// subtraction.
+    fn utc_offset_ahead(&self) -> Option<String> {
+        let [sign_token, hours, colon, minutes] = self.tokens.get(self.pos..self.pos + 4)? else {
+            return None;
+        };
+        let sign = match sign_token.kind {
+            TokenKind::Plus => '+',
+            TokenKind::Minus => '-',
+            _ => return None,
+        };
+        match (&hours.kind, &colon.kind, &minutes.kind) {
+            (TokenKind::Number(h), TokenKind::Colon, TokenKind::Number(m))
+                if sign_token.end == hours.start =>
+            {
+                Some(format!("{sign}{h}:{m}"))
+            }
+            _ => None,
+        }
+    }
+
+    /// Tries to parse a time/datetime expression that starts with a number followed by a colon,
+    /// e.g. "11:59pm EST on Monday, January 26th".
+    /// The `hour_str` is the number already consumed, and the current position is at the Colon.
+    pub(super) fn try_parse_time_starting_with_number(
+        &mut self,
+        hour_str: &str,
+    ) -> Result<Expression, CalculatorError> {
+        let mut parts = vec![hour_str.to_string()];
+
+        while !self.is_at_end() {
+            match self.current_kind() {
+                Some(TokenKind::Number(n)) => {
+                    parts.push(n.clone());
+                    self.advance();
+                }
+                Some(TokenKind::Identifier(id)) => {
+                    let id_lower = id.to_lowercase();
+                    // Attach ordinal suffixes directly to preceding number
+                    if matches!(id_lower.as_str(), "st" | "nd" | "rd" | "th") {
+                        if let Some(last) = parts.last_mut() {
+                            if last.chars().all(|c| c.is_ascii_digit()) {
+                                last.push_str(id);
+                                self.advance();
+                                continue;
+                            }
+                        }
+                    }
+                    parts.push(id.clone());
+                    self.advance();
+                }
+                Some(TokenKind::Comma) => {
+                    parts.push(",".to_string());
+                    self.advance();
+                }
+                Some(TokenKind::Colon) => {
+                    parts.push(":".to_string());
+                    self.advance();
+                }
+                Some(TokenKind::At) => {
+                    let save_pos = self.pos;
+                    self.advance();
+                    if let Some(TokenKind::Identifier(tz_id)) = self.current_kind() {
+                        if DateTime::parse_tz_abbreviation(tz_id).is_some() {
+                            parts.push(tz_id.clone());
+                            self.advance();
+                            continue;
+                        }
+                    }
+                    self.pos = save_pos;
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        let datetime_str = parts.join(" ").replace(" , ", ", ").replace(" : ", ":");
+        match DateTime::parse(&datetime_str) {
+            Ok(dt) => Ok(Expression::DateTime(dt)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Tries to parse "N AM/PM [TZ]" as a time expression.
+    ///
+    /// Called when the parser has consumed a number token and sees AM/PM next.
+    /// Handles patterns like "6 PM", "6 PM GMT", "6 PM MSK".
+    /// The timezone identifier is only consumed if it is a recognized timezone abbreviation.
+    pub(super) fn try_parse_time_with_ampm(
+        &mut self,
+        hour_str: &str,
+    ) -> Result<Expression, CalculatorError> {
+        // Consume the AM/PM identifier
+        let ampm = if let Some(TokenKind::Identifier(id)) = self.current_kind() {
+            let id_str = id.clone();
+            self.advance();
+            id_str
+        } else {
+            return Err(CalculatorError::parse("Expected AM/PM"));
+        };
+
+        // Check if the next identifier is a recognized timezone abbreviation
+        let mut datetime_str = format!("{hour_str}:00 {ampm}");
+        if let Some(TokenKind::Identifier(tz_id)) = self.current_kind() {
+            if DateTime::parse_tz_abbreviation(tz_id).is_some() {
+                datetime_str = format!("{hour_str}:00 {} {}", ampm, tz_id);
+                self.advance(); // consume timezone token
+            }
+        }
+
+        match DateTime::parse(&datetime_str) {
+            Ok(dt) => Ok(Expression::DateTime(dt)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Tries to parse Russian "N по <TZ>" as "N:00 <TZ>".
+    pub(super) fn try_parse_russian_time_by_timezone(
+        &mut self,
+        hour_str: &str,
+    ) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "по"
+
+        let Some(TokenKind::Identifier(tz_id)) = self.current_kind() else {
+            return Err(CalculatorError::parse("Expected timezone after по"));
+        };
+        let tz = tz_id.clone();
+        if DateTime::parse_tz_abbreviation(&tz).is_none() {
+            return Err(CalculatorError::parse(format!("Unknown timezone: {tz}")));
+        }
+        self.advance();
+
+        let datetime_str = format!("{hour_str}:00 {tz}");
+        DateTime::parse(&datetime_str).map(Expression::DateTime)
+    }
+
+    pub(super) fn try_parse_datetime_from_tokens(
+        &mut self,
+        first: &str,
+    ) -> Result<Expression, CalculatorError> {
+        // Collect tokens that might be part of a datetime
+        let mut parts = vec![first.to_string()];
+        // Track token positions so we can backtrack to any prefix
+        let mut token_positions = vec![self.pos]; // position before each token was consumed
+
+        // Look for patterns like: Jan 22, 2026 or Jan 27, 8:59am UTC
+        // Also handles: Monday, January 26th, 2026
+        // Collect: numbers, identifiers, colons, commas
+        while !self.is_at_end() {
+            match self.current_kind() {
+                Some(TokenKind::Number(n)) => {
+                    token_positions.push(self.pos);
+                    parts.push(n.clone());
+                    self.advance();
+                }
+                Some(TokenKind::Identifier(id)) => {
+                    // Handle ordinal suffixes: if previous part is a number and
+                    // this is "st", "nd", "rd", or "th", attach without space
+                    let id_lower = id.to_lowercase();
+                    if matches!(id_lower.as_str(), "st" | "nd" | "rd" | "th") {
+                        // Append to previous number part (ordinal suffix)
+                        if let Some(last) = parts.last_mut() {
+                            if last.chars().all(|c| c.is_ascii_digit()) {
+                                token_positions.push(self.pos);
+                                last.push_str(id);
+                                self.advance();
+                                continue;
+                            }
+                        }
+                    }
+                    token_positions.push(self.pos);
+                    parts.push(id.clone());
+                    self.advance();
+                }
+                Some(TokenKind::Of) => {
+                    token_positions.push(self.pos);
+                    parts.push("of".to_string());
+                    self.advance();
+                }
+                Some(TokenKind::Comma) => {
+                    token_positions.push(self.pos);
+                    parts.push(",".to_string());
+                    self.advance();
+                }
+                Some(TokenKind::Colon) => {
+                    token_positions.push(self.pos);
+                    parts.push(":".to_string());
+                    self.advance();
+                }
+                _ => break,
+            }
+        }
+
+        // Try the full collected string first, then progressively shorter prefixes.
+        // This handles cases like "17 февраля 2027 - 6 months" where the datetime
+        // is "17 февраля 2027" but greedily collecting too many tokens would fail.
+        let end_pos = self.pos;
+        for len in (1..=parts.len()).rev() {
+            let candidate_parts = &parts[..len];
+            let datetime_str = candidate_parts
+                .join(" ")
+                .replace(" , ", ", ")
+                .replace(" : ", ":");
+            if let Ok(dt) = DateTime::parse(&datetime_str) {
+                // Restore position to just after the tokens we actually consumed
+                // token_positions[len - 1] is the position *before* consuming parts[len-1]
+                // so the position after consuming parts[len-1] is:
+                // - for the last element: end_pos (we already advanced past all)
+                // - for shorter prefix: token_positions[len] (position before parts[len])
+                if len < parts.len() {
+                    self.pos = token_positions[len];
+                } else {
+                    self.pos = end_pos;
+                }
+                return Ok(Expression::DateTime(dt));
+            }
+        }
+
+        // No prefix worked — restore original position and report error
+        if parts.is_empty() {
+            return Err(CalculatorError::parse("empty datetime"));
+        }
+        // Return error with the full string for better error messages
+        let datetime_str = parts.join(" ").replace(" , ", ", ").replace(" : ", ":");
+        Err(CalculatorError::InvalidDateTime(format!(
+            "Could not parse '{datetime_str}' as a date or time"
+        )))
+    }
+}
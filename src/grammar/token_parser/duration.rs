@@ -0,0 +1,66 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{BinaryOp, DurationUnit, Expression, Unit};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Extends a duration literal with the parts that follow it, so
+    /// `2 hours 15 minutes` reads as one value. Each part must use a
+    /// smaller fixed unit than the one before it; months and years vary
+    /// in length and never combine.
+    pub(super) fn extend_duration_literal(
+        &mut self,
+        first: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        let Some(mut previous) = fixed_duration_unit(&first) else {
+            return Ok(first);
+        };
+
+        let mut literal = first;
+        while let Some(unit) = self.next_duration_part(previous) {
+            let Some(TokenKind::Number(amount)) = self.current_kind() else {
+                break;
+            };
+            let amount = self.number_grammar.parse_number(amount)?;
+            self.pos += 2;
+            let part = Expression::number_with_unit(amount, Unit::Duration(unit));
+            literal = Expression::binary(literal, BinaryOp::Add, part);
+            previous = unit;
+        }
+
+        Ok(match literal {
+            Expression::Binary { .. } => Expression::group(literal),
+            literal => literal,
+        })
+    }
+
+    /// Returns the unit of a `<number> <unit>` part at the current token if
+    /// it is a fixed duration unit smaller than `previous`.
+    fn next_duration_part(&self, previous: DurationUnit) -> Option<DurationUnit> {
+        if !matches!(self.current_kind(), Some(TokenKind::Number(_))) {
+            return None;
+        }
+        let Some(TokenKind::Identifier(id)) = self.peek_kind() else {
+            return None;
+        };
+        let Ok(Unit::Duration(unit)) = self.number_grammar.parse_unit(id) else {
+            return None;
+        };
+        (is_fixed(unit) && unit.to_secs(1.0) < previous.to_secs(1.0)).then_some(unit)
+    }
+}
+
+fn fixed_duration_unit(expression: &Expression) -> Option<DurationUnit> {
+    match expression {
+        Expression::Number {
+            unit: Unit::Duration(unit),
+            ..
+        } if is_fixed(*unit) => Some(*unit),
+        _ => None,
+    }
+}
+
+const fn is_fixed(unit: DurationUnit) -> bool {
+    !matches!(unit, DurationUnit::Months | DurationUnit::Years)
+}
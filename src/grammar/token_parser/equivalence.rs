@@ -0,0 +1,60 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::plot::is_keyword;
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `is <expr> equivalent to <expr>` starts at the
+    /// current token.
+    pub(super) fn equivalence_ahead(&self) -> bool {
+        self.word_at(self.pos, "is")
+            && self
+                .find_top_level(|kind| is_keyword(kind, "equivalent"))
+                .is_some_and(|pos| {
+                    matches!(
+                        self.tokens.get(pos + 1).map(|t| &t.kind),
+                        Some(TokenKind::To)
+                    )
+                })
+    }
+
+    /// Parses `is <expr> equivalent to <expr>[?]`:
+    /// `is x^2 - 1 equivalent to (x-1)(x+1)`.
+    ///
+    /// Produces `equivalent(left, right)`.
+    pub(super) fn parse_equivalence(&mut self) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "is"
+        let equivalent_pos = self
+            .find_top_level(|kind| is_keyword(kind, "equivalent"))
+            .ok_or_else(|| self.error_at(self.pos, "Expected 'equivalent to'"))?;
+        let end = self
+            .find_top_level(|kind| matches!(kind, TokenKind::Question | TokenKind::Eof))
+            .unwrap_or(self.tokens.len());
+        let variables = self.letter_variables(end);
+        let (left, right) = self.with_variables(variables, |parser| {
+            let left = parser.parse_equivalence_side(equivalent_pos)?;
+            parser.pos += 2; // consume "equivalent to"
+            let right = parser.parse_equivalence_side(end)?;
+            Ok::<_, CalculatorError>((left, right))
+        })?;
+        if self.check(&TokenKind::Question) {
+            self.advance();
+        }
+        Ok(Expression::function_call("equivalent", vec![left, right]))
+    }
+
+    /// Parses one side of an equivalence, which must end exactly at
+    /// `boundary`.
+    fn parse_equivalence_side(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
+        let expr = self.parse_integrand_expression(boundary)?;
+        if self.pos != boundary {
+            return Err(self.error_at(
+                self.pos,
+                "Expected 'is <expression> equivalent to <expression>'",
+            ));
+        }
+        Ok(expr)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `with factors` starts at the current token.
+    pub(super) fn factors_directive_ahead(&self) -> bool {
+        self.word_at(self.pos, "with")
+            && (self.word_at(self.pos + 1, "factors")
+                || self.word_at(self.pos + 1, "factorization"))
+    }
+
+    /// Wraps `expr` as `factors(expr)` if a trailing `with factors` follows,
+    /// so the integer result is explained by its prime factors and
+    /// divisibility.
+    pub(super) fn parse_factors_directive(&mut self, expr: Expression) -> Expression {
+        if !self.factors_directive_ahead() {
+            return expr;
+        }
+        self.pos += 2;
+        Expression::function_call("factors", vec![expr])
+    }
+}
@@ -0,0 +1,230 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{BinaryOp, Decimal, Expression};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `+ VAT` starts at the current token.
+    pub(super) fn vat_addition_ahead(&self) -> bool {
+        self.check(&TokenKind::Plus) && self.word_at(self.pos + 1, "vat")
+    }
+
+    /// Parses `+ VAT [rate]` after `net`: `150 EUR + VAT 19%`.
+    pub(super) fn parse_vat_addition(
+        &mut self,
+        net: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.pos += 2; // consume "+ VAT"
+        let mut args = vec![net];
+        args.extend(self.parse_optional_vat_rate()?);
+        Ok(Expression::function_call("add_vat", args))
+    }
+
+    /// Returns true if `gross <amount>` starts at the current token.
+    pub(super) fn gross_amount_ahead(&self) -> bool {
+        self.word_at(self.pos, "gross")
+            && matches!(
+                self.peek_kind(),
+                Some(TokenKind::Number(_) | TokenKind::Identifier(_) | TokenKind::LeftParen)
+            )
+    }
+
+    /// Parses `gross <amount> [at VAT [rate]]`: `gross 119 EUR at VAT 19%`.
+    pub(super) fn parse_gross_amount(&mut self) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "gross"
+        let mut args = vec![self.parse_multiplicative()?];
+        if self.check_at() && self.word_at(self.pos + 1, "vat") {
+            self.pos += 2; // consume "at VAT"
+            args.extend(self.parse_optional_vat_rate()?);
+        }
+        Ok(Expression::function_call("remove_vat", args))
+    }
+
+    /// Parses the rate after `VAT`, if one is written.
+    fn parse_optional_vat_rate(&mut self) -> Result<Option<Expression>, CalculatorError> {
+        if self.percent_rate_ahead() {
+            return self.parse_percent_rate().map(Some);
+        }
+        if matches!(
+            self.current_kind(),
+            Some(TokenKind::Number(_) | TokenKind::LeftParen)
+        ) {
+            return self.parse_unary().map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Returns true if `+ <rate>% tip` starts at the current token.
+    pub(super) fn tip_addition_ahead(&self) -> bool {
+        self.check(&TokenKind::Plus)
+            && matches!(self.peek_kind(), Some(TokenKind::Number(_)))
+            && matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::Percent)
+            )
+            && self.word_at(self.pos + 3, "tip")
+    }
+
+    /// Parses `+ <rate>% tip` after `bill`: `86.40 USD + 18% tip`.
+    pub(super) fn parse_tip_addition(
+        &mut self,
+        bill: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "+"
+        let rate = self.parse_percent_rate()?;
+        self.advance(); // consume "tip"
+        Ok(Expression::function_call("add_tip", vec![bill, rate]))
+    }
+
+    /// Returns true if `- <rate>%` starts at the current token and ends the
+    /// percentage, so that `- 25 % 7` stays a modulo.
+    pub(super) fn percent_discount_ahead(&self) -> bool {
+        self.check(&TokenKind::Minus)
+            && matches!(self.peek_kind(), Some(TokenKind::Number(_)))
+            && matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::Percent)
+            )
+            && matches!(
+                self.tokens.get(self.pos + 3).map(|t| &t.kind),
+                None | Some(
+                    TokenKind::Eof
+                        | TokenKind::Minus
+                        | TokenKind::Plus
+                        | TokenKind::RightParen
+                        | TokenKind::Comma
+                        | TokenKind::As
+                        | TokenKind::In
+                        | TokenKind::To
+                )
+            )
+    }
+
+    /// Parses `- <rate>%` after an amount, and any more after it, as
+    /// discounts taken one after another: `200 USD - 20% - 10%`. Whether
+    /// they are percentages of the amount or plain numbers is left to the
+    /// amount's value.
+    pub(super) fn parse_percent_discounts(
+        &mut self,
+        amount: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        let mut args = vec![amount];
+        while self.percent_discount_ahead() {
+            self.advance(); // consume "-"
+            args.push(Expression::negate(self.parse_percent_rate()?));
+        }
+        Ok(Expression::function_call("apply_percentages", args))
+    }
+
+    /// Returns true if `final price` starts at the current token.
+    pub(super) fn final_price_ahead(&self) -> bool {
+        self.word_at(self.pos, "final") && self.word_at(self.pos + 1, "price")
+    }
+
+    /// Parses `final price <amount> after <rate>% [and <rate>%...]
+    /// [discounts][, original [price]][?]` as the price before the
+    /// discounts.
+    pub(super) fn parse_original_price(&mut self) -> Result<Expression, CalculatorError> {
+        self.pos += 2; // consume "final price"
+        let mut args = vec![self.parse_multiplicative()?];
+        if !self.word_at(self.pos, "after") {
+            return Err(self.error_at(self.pos, "Expected 'after' and the discounts"));
+        }
+        self.advance(); // consume "after"
+        loop {
+            if !self.percent_rate_ahead() {
+                return Err(self.error_at(self.pos, "Expected a discount, e.g. 20%"));
+            }
+            args.push(self.parse_percent_rate()?);
+            if !matches!(self.current_kind(), Some(TokenKind::And | TokenKind::Comma))
+                || !matches!(self.peek_kind(), Some(TokenKind::Number(_)))
+            {
+                break;
+            }
+            self.advance(); // consume "and" or ","
+        }
+        if self.word_at(self.pos, "discount") || self.word_at(self.pos, "discounts") {
+            self.advance();
+        }
+        if self.check(&TokenKind::Comma) {
+            self.advance();
+        }
+        if self.word_at(self.pos, "original") {
+            self.advance();
+            if self.word_at(self.pos, "price") {
+                self.advance();
+            }
+        }
+        if self.check(&TokenKind::Question) {
+            self.advance();
+        }
+        Ok(Expression::function_call("original_price", args))
+    }
+
+    /// Returns true if `split [between|among] <n>` starts at the current token.
+    pub(super) fn bill_split_ahead(&self) -> bool {
+        if !self.word_at(self.pos, "split") {
+            return false;
+        }
+        let count = if self.word_at(self.pos + 1, "between") || self.word_at(self.pos + 1, "among")
+        {
+            self.pos + 2
+        } else {
+            self.pos + 1
+        };
+        matches!(
+            self.tokens.get(count).map(|t| &t.kind),
+            Some(TokenKind::Number(_))
+        )
+    }
+
+    /// Parses `split [between|among] <n> [ways|people]` after `total`:
+    /// `101.95 USD split 4 ways`.
+    pub(super) fn parse_bill_split(
+        &mut self,
+        total: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "split"
+        if self.word_at(self.pos, "between") || self.word_at(self.pos, "among") {
+            self.advance();
+        }
+        let ways = self.parse_plain_number()?;
+        if ["ways", "people", "persons"]
+            .iter()
+            .any(|word| self.word_at(self.pos, word))
+        {
+            self.advance();
+        }
+        Ok(Expression::function_call("split_bill", vec![total, ways]))
+    }
+
+    /// Returns true if `<number>%` starts at the current token.
+    fn percent_rate_ahead(&self) -> bool {
+        matches!(self.current_kind(), Some(TokenKind::Number(_)))
+            && matches!(self.peek_kind(), Some(TokenKind::Percent))
+    }
+
+    /// Parses `<number>%` as a fraction, even when a word such as `tip` or
+    /// `split` follows that would otherwise make `%` a modulo.
+    fn parse_percent_rate(&mut self) -> Result<Expression, CalculatorError> {
+        let rate = self.parse_plain_number()?;
+        self.advance(); // consume "%"
+        Ok(Expression::binary(
+            rate,
+            BinaryOp::Divide,
+            Expression::number(Decimal::new(100)),
+        ))
+    }
+
+    /// Parses a bare number token, without the units or times that may
+    /// follow a number elsewhere.
+    pub(super) fn parse_plain_number(&mut self) -> Result<Expression, CalculatorError> {
+        let Some(TokenKind::Number(text)) = self.current_kind() else {
+            return Err(self.error_at(self.pos, "Expected a number"));
+        };
+        let value = self.number_grammar.parse_number(&text.clone())?;
+        self.advance();
+        Ok(Expression::number(value))
+    }
+}
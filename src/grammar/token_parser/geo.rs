@@ -0,0 +1,46 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `distance between (` starts at the current token.
+    pub(super) fn distance_ahead(&self) -> bool {
+        self.word_at(self.pos, "distance")
+            && self.word_at(self.pos + 1, "between")
+            && matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::LeftParen)
+            )
+    }
+
+    /// Parses `distance between (<lat>, <lon>) and (<lat>, <lon>)`:
+    /// `distance between (55.7558, 37.6173) and (48.8566, 2.3522)`.
+    pub(super) fn parse_distance(&mut self) -> Result<Expression, CalculatorError> {
+        self.pos += 2; // consume "distance between"
+        let (lat1, lon1) = self.parse_coordinates()?;
+        if !self.check(&TokenKind::And) {
+            return Err(self.error_at(
+                self.pos,
+                "Expected 'and' and the second coordinates after the first",
+            ));
+        }
+        self.advance(); // consume "and"
+        let (lat2, lon2) = self.parse_coordinates()?;
+        Ok(Expression::function_call(
+            "haversine",
+            vec![lat1, lon1, lat2, lon2],
+        ))
+    }
+
+    /// Parses a `(<latitude>, <longitude>)` pair.
+    fn parse_coordinates(&mut self) -> Result<(Expression, Expression), CalculatorError> {
+        self.expect(&TokenKind::LeftParen)?;
+        let latitude = self.parse_additive()?;
+        self.expect(&TokenKind::Comma)?;
+        let longitude = self.parse_additive()?;
+        self.expect(&TokenKind::RightParen)?;
+        Ok((latitude, longitude))
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::CalculatorError;
+use crate::grammar::{is_math_function, TokenKind};
+use crate::types::Expression;
+
+use super::operators::{Operands, Precedence};
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Parses natural integral notation: "integrate <expr> d<var>"
+    /// Examples:
+    /// - integrate sin(x)/x dx
+    /// - integrate x^2 dx
+    pub(super) fn parse_natural_integral(&mut self) -> Result<Expression, CalculatorError> {
+        // We've already consumed "integrate", now we need to find the integrand and d<var>
+        // Strategy: collect tokens until we find "d<var>" pattern (identifier starting with 'd')
+
+        let start_pos = self.pos;
+        let mut integrand_end_pos = None;
+        let mut var_name = None;
+
+        // Scan forward to find the d<var> pattern
+        let mut scan_pos = self.pos;
+        while scan_pos < self.tokens.len() {
+            if let Some(var) = self.differential_at(scan_pos) {
+                integrand_end_pos = Some(scan_pos);
+                var_name = Some(var);
+                break;
+            }
+            scan_pos += 1;
+        }
+
+        // If we didn't find d<var>, return an error with helpful message
+        let (Some(end_pos), Some(var)) = (integrand_end_pos, var_name) else {
+            return Err(CalculatorError::parse(
+                "Invalid integration syntax. Expected: integrate <expression> d<var> (e.g., integrate sin(x)/x dx)"
+            ));
+        };
+
+        // Reset position and parse the integrand expression
+        // We need a sub-parser that only parses up to the d<var> token
+        self.pos = start_pos;
+
+        // Parse the integrand by parsing an expression and stopping at the d<var>
+        let integrand = self.with_variables(vec![var.clone()], |parser| {
+            parser.parse_integrand_until(end_pos)
+        })?;
+
+        // Now consume the d<var> token
+        self.pos = end_pos;
+        self.advance();
+
+        Ok(Expression::indefinite_integral(integrand, var))
+    }
+
+    /// Returns the variable of a differential such as `dx` or `dt` at `pos`.
+    fn differential_at(&self, pos: usize) -> Option<String> {
+        let TokenKind::Identifier(id) = &self.tokens.get(pos)?.kind else {
+            return None;
+        };
+        let id_lower = id.to_lowercase();
+        let var_char = id_lower.strip_prefix('d')?.chars().next()?;
+        (id_lower.len() == 2 && var_char.is_ascii_alphabetic()).then(|| var_char.to_string())
+    }
+
+    /// Checks if the input ends with a differential, so that
+    /// `integrate (x+1)^2 dx` is the natural notation rather than a call
+    /// of `integrate` on `(x+1)`.
+    pub(super) fn ends_with_differential(&self) -> bool {
+        let last = self
+            .tokens
+            .iter()
+            .rposition(|token| token.kind != TokenKind::Eof);
+        last.is_some_and(|pos| pos >= self.pos && self.differential_at(pos).is_some())
+    }
+
+    /// Parse an integrand expression up to (but not including) the position `until_pos`.
+    fn parse_integrand_until(&mut self, until_pos: usize) -> Result<Expression, CalculatorError> {
+        // Save the tokens after until_pos temporarily
+        let original_len = self.tokens.len();
+
+        // We need to be careful - parse_expression will consume tokens
+        // We'll parse and then check we didn't go past until_pos
+        let result = self.parse_integrand_expression(until_pos)?;
+
+        // Verify we stopped at the right place
+        if self.pos > until_pos {
+            self.pos = until_pos;
+        }
+
+        let _ = original_len; // Suppress unused warning
+        Ok(result)
+    }
+
+    /// Parse integrand with awareness of the boundary.
+    pub(super) fn parse_integrand_expression(
+        &mut self,
+        boundary: usize,
+    ) -> Result<Expression, CalculatorError> {
+        self.parse_operators(Precedence::Sum, Operands::Bounded(boundary))
+    }
+
+    pub(super) fn parse_integrand_primary(
+        &mut self,
+        boundary: usize,
+    ) -> Result<Expression, CalculatorError> {
+        if self.pos >= boundary {
+            return Err(CalculatorError::parse("Unexpected end of integrand"));
+        }
+
+        // Parenthesized expression
+        if self.check(&TokenKind::LeftParen) {
+            self.advance();
+            let expr = self.parse_expression()?;
+            self.expect(&TokenKind::RightParen)?;
+            return Ok(Expression::group(expr));
+        }
+
+        // Number
+        if let Some(TokenKind::Number(n)) = self.current_kind() {
+            let num_str = n.clone();
+            self.advance();
+            let value = self.number_grammar.parse_number(&num_str)?;
+            return Ok(Expression::number(value));
+        }
+
+        // Identifier (function call or variable)
+        if let Some(TokenKind::Identifier(id)) = self.current_kind() {
+            let id = id.clone();
+            self.advance();
+
+            // Check if this is a function call
+            if self.pos < boundary && self.check(&TokenKind::LeftParen) {
+                return self.parse_function_call(&id);
+            }
+
+            // Check if this is a math constant
+            if is_math_function(&id) {
+                return Ok(Expression::function_call(id, vec![]));
+            }
+
+            // Single-letter identifier is a variable
+            if id.len() == 1 && id.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                return Ok(Expression::variable(id));
+            }
+
+            // Multi-letter identifier could be an implicit variable in integration context
+            return Ok(Expression::variable(id));
+        }
+
+        Err(self.error_at(
+            self.pos,
+            format!("Unexpected {} in integrand", self.describe_current()),
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Parses a list literal such as `[1, 2, 3]` or `[]`, or a matrix
+    /// literal such as `[[1, 2], [3, 4]]` as `matrix([1, 2], [3, 4])`.
+    pub(super) fn parse_list(&mut self) -> Result<Expression, CalculatorError> {
+        self.expect(&TokenKind::LeftBracket)?;
+        let mut items = Vec::new();
+        if !self.check(&TokenKind::RightBracket) {
+            loop {
+                items.push(self.parse_expression()?);
+                if !self.check(&TokenKind::Comma) {
+                    break;
+                }
+                self.advance(); // consume ','
+            }
+        }
+        self.expect(&TokenKind::RightBracket)?;
+        if is_matrix_literal(&items) {
+            return Ok(Expression::function_call("matrix", items));
+        }
+        Ok(Expression::list(items))
+    }
+
+    /// Parses postfix indexing (`expr[i]`, `expr[i][j]`) as `index(expr, i)`.
+    pub(super) fn parse_indexing(
+        &mut self,
+        mut expr: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        while self.check(&TokenKind::LeftBracket) {
+            self.advance(); // consume '['
+            let index = self.parse_expression()?;
+            self.expect(&TokenKind::RightBracket)?;
+            expr = Expression::function_call("index", vec![expr, index]);
+        }
+        Ok(expr)
+    }
+}
+
+/// Returns true if every item is a list literal of the same, non-zero length.
+fn is_matrix_literal(items: &[Expression]) -> bool {
+    let lengths: Option<Vec<usize>> = items
+        .iter()
+        .map(|item| match item {
+            Expression::List(row) if !row.is_empty() => Some(row.len()),
+            _ => None,
+        })
+        .collect();
+    lengths.is_some_and(|lengths| {
+        lengths
+            .first()
+            .is_some_and(|first| lengths.iter().all(|len| len == first))
+    })
+}
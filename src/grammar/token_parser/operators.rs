@@ -0,0 +1,283 @@
+//! Operator-precedence (Pratt) parsing of arithmetic.
+//!
+//! Infix operators are rows of [`INFIX_OPERATORS`], each with its precedence
+//! and associativity. [`TokenParser::parse_operators`] climbs that table, so
+//! a new operator is a new row rather than a new level of recursive descent.
+//! The same loop parses full expressions and the operands of integrals,
+//! plots and equivalences, which end before a known token.
+
+use crate::error::CalculatorError;
+use crate::grammar::{NumberGrammar, TokenKind};
+use crate::types::{BinaryOp, Decimal, Expression};
+
+use super::TokenParser;
+
+/// How tightly an infix operator binds, loosest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Precedence {
+    /// `+` and `-`.
+    Sum,
+    /// `*`, `/`, `%` and implicit multiplication, as in `3x`.
+    Product,
+    /// `^`.
+    Power,
+}
+
+impl Precedence {
+    /// The level of the right operand of a left-associative operator at
+    /// this level. Nothing binds tighter than a power.
+    const fn tighter(self) -> Self {
+        match self {
+            Self::Sum => Self::Product,
+            Self::Product | Self::Power => Self::Power,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Associativity {
+    /// `1 - 2 - 3` is `(1 - 2) - 3`.
+    Left,
+    /// `2 ^ 3 ^ 2` is `2 ^ (3 ^ 2)`.
+    Right,
+}
+
+/// What an infix operator builds from its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Infix {
+    Binary(BinaryOp),
+    Power,
+}
+
+impl Infix {
+    fn apply(self, left: Expression, right: Expression) -> Expression {
+        match self {
+            Self::Binary(op) => Expression::binary(left, op, right),
+            Self::Power => Expression::power(left, right),
+        }
+    }
+}
+
+/// An infix operator: the symbol it is written with, and how it binds.
+#[derive(Debug)]
+struct InfixOperator {
+    token: TokenKind,
+    infix: Infix,
+    precedence: Precedence,
+    associativity: Associativity,
+}
+
+impl InfixOperator {
+    const fn left(token: TokenKind, op: BinaryOp, precedence: Precedence) -> Self {
+        Self {
+            token,
+            infix: Infix::Binary(op),
+            precedence,
+            associativity: Associativity::Left,
+        }
+    }
+}
+
+/// The infix operators. Operator words such as `plus` and `divided by` bind
+/// like the symbol of the same operation.
+const INFIX_OPERATORS: &[InfixOperator] = &[
+    InfixOperator::left(TokenKind::Plus, BinaryOp::Add, Precedence::Sum),
+    InfixOperator::left(TokenKind::Minus, BinaryOp::Subtract, Precedence::Sum),
+    InfixOperator::left(TokenKind::Star, BinaryOp::Multiply, Precedence::Product),
+    InfixOperator::left(TokenKind::Slash, BinaryOp::Divide, Precedence::Product),
+    InfixOperator::left(TokenKind::Percent, BinaryOp::Modulo, Precedence::Product),
+    InfixOperator {
+        token: TokenKind::Caret,
+        infix: Infix::Power,
+        precedence: Precedence::Power,
+        associativity: Associativity::Right,
+    },
+];
+
+/// The infix operators as their symbol, how tightly they bind (higher binds
+/// tighter) and whether they group to the right, loosest first.
+pub fn infix_operators() -> impl Iterator<Item = (&'static str, u8, bool)> {
+    INFIX_OPERATORS.iter().map(|operator| {
+        let symbol = match operator.infix {
+            Infix::Binary(op) => op.symbol(),
+            Infix::Power => "^",
+        };
+        (
+            symbol,
+            operator.precedence as u8 + 1,
+            operator.associativity == Associativity::Right,
+        )
+    })
+}
+
+fn operator_for(infix: Infix) -> &'static InfixOperator {
+    INFIX_OPERATORS
+        .iter()
+        .find(|operator| operator.infix == infix)
+        .expect("every infix operation has a row in INFIX_OPERATORS")
+}
+
+/// What the operands of an arithmetic expression may be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Operands {
+    /// Any primary expression, with postfix `%`, `!` and indexing, and
+    /// `+ 20% VAT`-style additions.
+    Full,
+    /// Numbers, variables, calls and groups, ending before the token at the
+    /// given index, such as the `dx` of an integral. Operands written side
+    /// by side are multiplied, as in `2x` or `(x-1)(x+1)`.
+    Bounded(usize),
+}
+
+impl TokenParser<'_> {
+    /// Parses operands joined by infix operators that bind at least as
+    /// tightly as `min`.
+    pub(super) fn parse_operators(
+        &mut self,
+        min: Precedence,
+        operands: Operands,
+    ) -> Result<Expression, CalculatorError> {
+        let mut left = match operands {
+            Operands::Full => self.parse_unary()?,
+            Operands::Bounded(boundary) => self.parse_bounded_unary(boundary)?,
+        };
+
+        loop {
+            if min == Precedence::Sum && operands == Operands::Full {
+                if self.vat_addition_ahead() {
+                    left = self.parse_vat_addition(left)?;
+                    continue;
+                }
+                if self.tip_addition_ahead() {
+                    left = self.parse_tip_addition(left)?;
+                    continue;
+                }
+                if self.percent_discount_ahead() {
+                    left = self.parse_percent_discounts(left)?;
+                    continue;
+                }
+            }
+
+            let Some((operator, width)) = self.infix_ahead(operands) else {
+                break;
+            };
+            if operator.precedence < min {
+                break;
+            }
+            self.pos += width;
+
+            let right = match operator.associativity {
+                Associativity::Left => {
+                    self.parse_operators(operator.precedence.tighter(), operands)?
+                }
+                Associativity::Right => {
+                    self.nested(|parser| parser.parse_operators(operator.precedence, operands))?
+                }
+            };
+            left = operator.infix.apply(left, right);
+        }
+
+        Ok(left)
+    }
+
+    /// Parses a product, such as the amount before `per` in a rate.
+    pub(super) fn parse_multiplicative(&mut self) -> Result<Expression, CalculatorError> {
+        self.parse_operators(Precedence::Product, Operands::Full)
+    }
+
+    /// Parses a negation, or a primary expression with its postfix
+    /// operators. A power binds tighter than the negation, so `-2^2` is
+    /// `-(2^2)`.
+    pub(super) fn parse_unary(&mut self) -> Result<Expression, CalculatorError> {
+        if self.check(&TokenKind::Minus) {
+            self.advance();
+            let expr =
+                self.nested(|parser| parser.parse_operators(Precedence::Power, Operands::Full))?;
+            return Ok(Expression::negate(expr));
+        }
+
+        let primary = self.parse_primary()?;
+        let expr = self.parse_indexing(primary)?;
+
+        // Handle postfix percent operator: expr% → expr / 100
+        // With optional "of <rhs>": expr% of rhs → (expr / 100) * rhs
+        if matches!(self.current_kind(), Some(TokenKind::Percent))
+            && !self.percent_starts_binary_expression()
+        {
+            self.advance();
+            let percent_expr = Expression::binary(
+                expr,
+                BinaryOp::Divide,
+                Expression::number(Decimal::new(100)),
+            );
+            if matches!(self.current_kind(), Some(TokenKind::Of)) {
+                self.advance(); // consume "of"
+                let rhs = self.parse_primary()?;
+                return Ok(Expression::binary(percent_expr, BinaryOp::Multiply, rhs));
+            }
+            return Ok(percent_expr);
+        }
+
+        // Handle postfix factorial operator: expr! → factorial(expr)
+        if matches!(self.current_kind(), Some(TokenKind::Bang)) {
+            self.advance();
+            return Ok(Expression::function_call("factorial", vec![expr]));
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_bounded_unary(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
+        if self.pos < boundary && self.check(&TokenKind::Minus) {
+            self.advance();
+            let expr = self.nested(|parser| {
+                parser.parse_operators(Precedence::Power, Operands::Bounded(boundary))
+            })?;
+            return Ok(Expression::negate(expr));
+        }
+
+        self.parse_integrand_primary(boundary)
+    }
+
+    /// Returns the infix operator at the current token, and how many tokens
+    /// it is written with, without consuming it.
+    fn infix_ahead(&self, operands: Operands) -> Option<(&'static InfixOperator, usize)> {
+        if let Operands::Bounded(boundary) = operands {
+            if self.pos >= boundary {
+                return None;
+            }
+        }
+
+        let kind = self.current_kind()?;
+        if let Some(operator) = INFIX_OPERATORS
+            .iter()
+            .find(|operator| &operator.token == kind)
+        {
+            if operator.token == TokenKind::Percent && !self.percent_starts_binary_expression() {
+                return None;
+            }
+            return Some((operator, 1));
+        }
+
+        if let Some((op, width)) = self.operator_word_ahead() {
+            return Some((operator_for(Infix::Binary(op)), width));
+        }
+
+        let adjacent = matches!(kind, TokenKind::LeftParen | TokenKind::Identifier(_))
+            && !matches!(kind, TokenKind::Identifier(id) if NumberGrammar::operator_word(id).is_some());
+        if (matches!(operands, Operands::Bounded(_)) && adjacent) || self.variable_ahead() {
+            return Some((operator_for(Infix::Binary(BinaryOp::Multiply)), 0));
+        }
+
+        None
+    }
+
+    /// Returns true if a `%` at the current token is the remainder operator
+    /// rather than a percent sign.
+    pub(super) fn percent_starts_binary_expression(&self) -> bool {
+        matches!(
+            self.peek_kind(),
+            Some(TokenKind::Number(_) | TokenKind::Identifier(_) | TokenKind::LeftParen)
+        )
+    }
+}
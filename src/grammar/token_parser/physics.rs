@@ -0,0 +1,53 @@
+use crate::error::CalculatorError;
+use crate::grammar::{is_physics_quantity, TokenKind};
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `<quantity> with <quantity>` starts at the current
+    /// token.
+    pub(super) fn physics_formula_ahead(&self) -> bool {
+        self.quantity_at(self.pos)
+            && self.word_at(self.pos + 1, "with")
+            && self.quantity_at(self.pos + 2)
+    }
+
+    /// Parses `<unknown> with <quantity> <value> and <quantity> <value>`:
+    /// `voltage with current 2 A and resistance 10 ohm`.
+    pub(super) fn parse_physics_formula(&mut self) -> Result<Expression, CalculatorError> {
+        let mut args = vec![self.parse_quantity_name()?];
+        self.advance(); // consume "with"
+        loop {
+            if !self.quantity_at(self.pos) {
+                return Err(self.error_at(
+                    self.pos,
+                    "Expected a quantity such as current or resistance",
+                ));
+            }
+            args.push(self.parse_quantity_name()?);
+            args.push(self.parse_additive()?);
+            if !matches!(self.current_kind(), Some(TokenKind::And | TokenKind::Comma)) {
+                break;
+            }
+            self.advance(); // consume "and" or ","
+        }
+        Ok(Expression::function_call("physics", args))
+    }
+
+    fn quantity_at(&self, index: usize) -> bool {
+        matches!(
+            self.tokens.get(index).map(|t| &t.kind),
+            Some(TokenKind::Identifier(id)) if is_physics_quantity(id)
+        )
+    }
+
+    fn parse_quantity_name(&mut self) -> Result<Expression, CalculatorError> {
+        let Some(TokenKind::Identifier(name)) = self.current_kind() else {
+            return Err(self.error_at(self.pos, "Expected a quantity name"));
+        };
+        let name = Expression::variable(name.to_lowercase());
+        self.advance();
+        Ok(name)
+    }
+}
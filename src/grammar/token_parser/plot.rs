@@ -0,0 +1,127 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{Expression, Rational};
+
+use super::TokenParser;
+
+/// Number of points sampled when a plot does not specify a count.
+const DEFAULT_PLOT_SAMPLES: i64 = 200;
+
+/// Bound standing for a range picked from the functions themselves, for
+/// `plot <expression>` without `from <start> to <end>`.
+pub const AUTO_PLOT_BOUND: &str = "auto";
+
+impl TokenParser<'_> {
+    /// Parses natural plot notation (after the `plot` keyword):
+    /// - `plot sin(x) from -10 to 10`
+    /// - `plot sin(x), cos(x) from 0 to 2*pi with 500 points`
+    /// - `plot 1/x`, over a range around its zeros and poles
+    ///
+    /// Produces `plot(f1, ..., fn, lower, upper, samples)`, with both bounds
+    /// [`AUTO_PLOT_BOUND`] when no range is given.
+    pub(super) fn parse_natural_plot(&mut self) -> Result<Expression, CalculatorError> {
+        let syntax_error = || {
+            CalculatorError::parse(
+                "Invalid plot syntax. Expected: plot <expression>[, <expression>...] [from <start> to <end>] [with <n> points]",
+            )
+        };
+
+        let from_pos = self.find_top_level(|kind| is_keyword(kind, "from"));
+        let functions_end = from_pos
+            .or_else(|| {
+                self.find_top_level(|kind| {
+                    is_keyword(kind, "with") || matches!(kind, TokenKind::Eof)
+                })
+            })
+            .unwrap_or(self.tokens.len());
+
+        let variables = self.letter_variables(functions_end);
+        let mut args = self.with_variables(variables, |parser| {
+            let mut functions = Vec::new();
+            loop {
+                let boundary = parser
+                    .find_top_level(|kind| matches!(kind, TokenKind::Comma))
+                    .filter(|&pos| pos < functions_end)
+                    .unwrap_or(functions_end);
+                functions.push(parser.parse_plot_part(boundary)?);
+                if boundary == functions_end {
+                    break;
+                }
+                parser.advance(); // consume ','
+            }
+            Ok::<_, CalculatorError>(functions)
+        })?;
+
+        if from_pos.is_some() {
+            self.advance(); // consume "from"
+            let to_pos = self
+                .find_top_level(|kind| matches!(kind, TokenKind::To))
+                .ok_or_else(syntax_error)?;
+            args.push(self.parse_plot_part(to_pos)?);
+            self.advance(); // consume "to"
+
+            let end_pos = self
+                .find_top_level(|kind| is_keyword(kind, "with") || matches!(kind, TokenKind::Eof))
+                .unwrap_or(self.tokens.len());
+            args.push(self.parse_plot_part(end_pos)?);
+        } else {
+            args.push(Expression::variable(AUTO_PLOT_BOUND));
+            args.push(Expression::variable(AUTO_PLOT_BOUND));
+        }
+
+        let samples = if self
+            .current_kind()
+            .is_some_and(|kind| is_keyword(kind, "with"))
+        {
+            self.advance(); // consume "with"
+            let Some(TokenKind::Number(count)) = self.current_kind() else {
+                return Err(syntax_error());
+            };
+            let count = self.number_grammar.parse_number(&count.clone())?;
+            self.advance();
+            if !self
+                .current_kind()
+                .is_some_and(|kind| is_keyword(kind, "points") || is_keyword(kind, "samples"))
+            {
+                return Err(syntax_error());
+            }
+            self.advance();
+            count
+        } else {
+            Rational::from(DEFAULT_PLOT_SAMPLES)
+        };
+        args.push(Expression::number(samples));
+
+        Ok(Expression::function_call("plot", args))
+    }
+
+    /// Parses one part of a plot command, which must end exactly at `boundary`.
+    fn parse_plot_part(&mut self, boundary: usize) -> Result<Expression, CalculatorError> {
+        let expr = self.parse_integrand_expression(boundary)?;
+        if self.pos != boundary {
+            return Err(self.error_at(
+                self.pos,
+                format!("Unexpected {} in plot", self.describe_current()),
+            ));
+        }
+        Ok(expr)
+    }
+
+    /// Finds the next token outside parentheses that matches `predicate`.
+    pub(super) fn find_top_level(&self, predicate: impl Fn(&TokenKind) -> bool) -> Option<usize> {
+        let mut depth = 0_usize;
+        for (pos, token) in self.tokens.iter().enumerate().skip(self.pos) {
+            match &token.kind {
+                TokenKind::LeftParen => depth += 1,
+                TokenKind::RightParen => depth = depth.saturating_sub(1),
+                kind if depth == 0 && predicate(kind) => return Some(pos),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+pub(super) fn is_keyword(kind: &TokenKind, keyword: &str) -> bool {
+    matches!(kind, TokenKind::Identifier(id) if id.eq_ignore_ascii_case(keyword))
+}
@@ -0,0 +1,87 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Precision;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if a precision directive starts at the current token.
+    pub(super) fn precision_directive_ahead(&self) -> bool {
+        self.scan_precision_directive().is_some()
+    }
+
+    /// Parses a trailing precision directive, if one starts at the current token:
+    /// - `to 2 dp`, `to 3 sf`
+    /// - `round to 2 decimal places`, `rounded to 3 significant figures`
+    /// - `= to 2 dp`
+    ///
+    /// A count of significant figures must be at least 1, and neither count
+    /// may be more than [`Precision::MAX_DIGITS`].
+    pub(super) fn parse_precision_directive(
+        &mut self,
+    ) -> Result<Option<Precision>, CalculatorError> {
+        let Some((len, precision)) = self.scan_precision_directive() else {
+            return Ok(None);
+        };
+        let (count, min) = match precision {
+            Precision::DecimalPlaces(places) => (places, 0),
+            Precision::SignificantFigures(figures) => (figures, 1),
+        };
+        if !(min..=Precision::MAX_DIGITS).contains(&count) {
+            return Err(self.error_at(
+                self.pos,
+                format!(
+                    "Precision must be between {min} and {} {}",
+                    Precision::MAX_DIGITS,
+                    match precision {
+                        Precision::DecimalPlaces(_) => "decimal places",
+                        Precision::SignificantFigures(_) => "significant figures",
+                    }
+                ),
+            ));
+        }
+        self.pos += len;
+        Ok(Some(precision))
+    }
+
+    /// Returns the token count and precision of a directive at the current token.
+    fn scan_precision_directive(&self) -> Option<(usize, Precision)> {
+        let kind_at = |offset: usize| self.tokens.get(self.pos + offset).map(|t| &t.kind);
+        let word_at = |offset: usize| match kind_at(offset) {
+            Some(TokenKind::Identifier(id)) => Some(id.to_lowercase()),
+            _ => None,
+        };
+
+        let mut offset = 0;
+        if matches!(kind_at(offset), Some(TokenKind::Equals)) {
+            offset += 1;
+        }
+        if word_at(offset).is_some_and(|word| word == "round" || word == "rounded") {
+            offset += 1;
+        }
+        if !matches!(kind_at(offset), Some(TokenKind::To)) {
+            return None;
+        }
+        let Some(TokenKind::Number(count)) = kind_at(offset + 1) else {
+            return None;
+        };
+        if !count.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        // A count too large for a u32 is still a directive, just out of range
+        let count = count.parse::<u32>().unwrap_or(u32::MAX);
+        offset += 2;
+
+        let first = word_at(offset)?;
+        let second = word_at(offset + 1);
+        let (words, precision) = match (first.as_str(), second.as_deref()) {
+            ("decimal", Some("place" | "places")) => (2, Precision::DecimalPlaces(count)),
+            ("dp" | "decimals" | "place" | "places", _) => (1, Precision::DecimalPlaces(count)),
+            ("significant", Some("figure" | "figures" | "digit" | "digits"))
+            | ("sig", Some("fig" | "figs")) => (2, Precision::SignificantFigures(count)),
+            ("sf" | "figure" | "figures", _) => (1, Precision::SignificantFigures(count)),
+            _ => return None,
+        };
+        Some((offset + words, precision))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if `percent change from` starts at the current token.
+    pub(super) fn percent_change_ahead(&self) -> bool {
+        self.percent_word_at(self.pos)
+            && self.word_at(self.pos + 1, "change")
+            && self.word_at(self.pos + 2, "from")
+    }
+
+    /// Parses `percent change from <old> to <new>`:
+    /// `percent change from 80 to 100`.
+    pub(super) fn parse_percent_change(&mut self) -> Result<Expression, CalculatorError> {
+        self.pos += 3; // consume "percent change from"
+        let old = self.parse_multiplicative()?;
+        if !self.check_to() {
+            return Err(self.error_at(
+                self.pos,
+                "Expected 'to' and the new value after the old one",
+            ));
+        }
+        self.advance(); // consume "to"
+        let new = self.parse_multiplicative()?;
+        Ok(Expression::function_call("percent_change", vec![old, new]))
+    }
+
+    /// Returns true if `is what percent of` starts at the current token.
+    pub(super) fn what_percent_ahead(&self) -> bool {
+        self.word_at(self.pos, "is")
+            && self.word_at(self.pos + 1, "what")
+            && self.percent_word_at(self.pos + 2)
+            && matches!(
+                self.tokens.get(self.pos + 3).map(|t| &t.kind),
+                Some(TokenKind::Of)
+            )
+    }
+
+    /// Parses `is what percent of <whole>` after `part`:
+    /// `100 is what percent of 250`.
+    pub(super) fn parse_what_percent(
+        &mut self,
+        part: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.pos += 4; // consume "is what percent of"
+        let whole = self.parse_additive()?;
+        Ok(Expression::function_call(
+            "percent_of_total",
+            vec![part, whole],
+        ))
+    }
+
+    /// Returns true if `ratio <a>:<b>` starts at the current token.
+    pub(super) fn ratio_split_ahead(&self) -> bool {
+        self.word_at(self.pos, "ratio")
+            && matches!(self.peek_kind(), Some(TokenKind::Number(_)))
+            && matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::Colon)
+            )
+    }
+
+    /// Parses `ratio <a>:<b>[:<c>...] of <total>`: `ratio 3:4 of 140`.
+    pub(super) fn parse_ratio_split(&mut self) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "ratio"
+        let mut shares = vec![self.parse_plain_number()?];
+        while self.check(&TokenKind::Colon) {
+            self.advance();
+            shares.push(self.parse_plain_number()?);
+        }
+        if !self.check(&TokenKind::Of) {
+            return Err(self.error_at(
+                self.pos,
+                "Expected 'of' and the total to split after the ratio",
+            ));
+        }
+        self.advance(); // consume "of"
+        let mut args = vec![self.parse_multiplicative()?];
+        args.extend(shares);
+        Ok(Expression::function_call("ratio_split", args))
+    }
+
+    /// Returns true if the token at `index` is `percent` or `percentage`.
+    fn percent_word_at(&self, index: usize) -> bool {
+        self.word_at(index, "percent") || self.word_at(index, "percentage")
+    }
+}
@@ -0,0 +1,80 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{Expression, Unit};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Parses an exchange rate lookup, if one starts at the current token:
+    /// - `USD/EUR rate`
+    /// - `EUR to USD rate`, `EUR in USD exchange rate`
+    /// - `USD/EUR from 1 Jan 2026 to 31 Jan 2026`, the rates over a range
+    ///   of dates as `rate_history(USD/EUR rate, start, end)`
+    pub(super) fn parse_rate_lookup(&mut self) -> Result<Option<Expression>, CalculatorError> {
+        let Some(from) = self.currency_at(self.pos) else {
+            return Ok(None);
+        };
+        if !matches!(
+            self.tokens.get(self.pos + 1).map(|t| &t.kind),
+            Some(TokenKind::Slash | TokenKind::To | TokenKind::In)
+        ) {
+            return Ok(None);
+        }
+        let Some(to) = self.currency_at(self.pos + 2) else {
+            return Ok(None);
+        };
+
+        let mut len = 3;
+        if self.word_at(self.pos + len, "exchange") {
+            len += 1;
+        }
+        let is_rate = self.word_at(self.pos + len, "rate");
+        if is_rate {
+            len += 1;
+        }
+        let lookup = Expression::rate_lookup(from, to);
+        if self.word_at(self.pos + len, "from") {
+            self.pos += len + 1;
+            let start = self.parse_primary()?;
+            if !self.check_to() {
+                return Err(self.error_at(
+                    self.pos,
+                    "Expected 'to' and an end date after the start of the rate range",
+                ));
+            }
+            self.advance(); // consume "to"
+            let end = self.parse_primary()?;
+            return Ok(Some(Expression::function_call(
+                "rate_history",
+                vec![lookup, start, end],
+            )));
+        }
+        if !is_rate {
+            return Ok(None);
+        }
+        self.pos += len;
+        Ok(Some(lookup))
+    }
+
+    /// Returns the currency code named by the identifier at `index`.
+    fn currency_at(&self, index: usize) -> Option<String> {
+        let Some(TokenKind::Identifier(id)) = self.tokens.get(index).map(|t| &t.kind) else {
+            return None;
+        };
+        let (unit, alternatives) = self.number_grammar.parse_unit_with_alternatives(id).ok()?;
+        std::iter::once(unit)
+            .chain(alternatives)
+            .find_map(|unit| match unit {
+                Unit::Currency(code) => Some(code),
+                _ => None,
+            })
+    }
+
+    /// Returns true if the token at `index` is the identifier `word`, in any case.
+    pub(super) fn word_at(&self, index: usize, word: &str) -> bool {
+        matches!(
+            self.tokens.get(index).map(|t| &t.kind),
+            Some(TokenKind::Identifier(id)) if id.eq_ignore_ascii_case(word)
+        )
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::Expression;
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if a question about quoted text starts at the current
+    /// token: `words in "the quick brown fox"`.
+    pub(super) fn text_question_ahead(&self) -> bool {
+        self.text_question().is_some()
+    }
+
+    /// Parses `<question> of|in "<text>"`, where the question is `length`,
+    /// `characters`, `utf8 bytes`, `bytes` or `words`:
+    /// `utf8 bytes of "привет"`.
+    pub(super) fn parse_text_question(&mut self) -> Result<Expression, CalculatorError> {
+        let Some((function, words)) = self.text_question() else {
+            return Err(self.error_at(self.pos, "Expected a question about quoted text"));
+        };
+        self.pos += words + 1; // consume the question and "of" or "in"
+        let text = self.parse_primary()?;
+        Ok(Expression::function_call(function, vec![text]))
+    }
+
+    /// Returns the text function the question at the current token asks
+    /// for and how many words the question takes.
+    fn text_question(&self) -> Option<(&'static str, usize)> {
+        let (function, words) = if self.word_at(self.pos, "utf8") {
+            self.word_at(self.pos + 1, "bytes")
+                .then_some(("utf8_bytes", 2))?
+        } else if self.word_at(self.pos, "bytes") {
+            ("utf8_bytes", 1)
+        } else if ["length", "characters", "chars"]
+            .iter()
+            .any(|word| self.word_at(self.pos, word))
+        {
+            ("length", 1)
+        } else if self.word_at(self.pos, "words") {
+            ("words", 1)
+        } else {
+            return None;
+        };
+        let kind = |offset: usize| self.tokens.get(self.pos + offset).map(|t| &t.kind);
+        (matches!(kind(words), Some(TokenKind::Of | TokenKind::In))
+            && matches!(kind(words + 1), Some(TokenKind::Text(_))))
+        .then_some((function, words))
+    }
+}
@@ -1,18 +1,140 @@
 use crate::error::CalculatorError;
 use crate::grammar::TokenKind;
-use crate::types::{DataSizeUnit, Expression, MassUnit, Unit};
+use crate::types::{
+    AngleUnit, BinaryOp, ColorModel, DataSizeUnit, DurationUnit, Expression, LengthUnit, MassUnit,
+    Radix, Unit, VolumeUnit,
+};
 
 use super::TokenParser;
 
 impl TokenParser<'_> {
-    /// Parses a unit name after the `as`, `in`, or `to` keyword.
+    /// Parses input that consists of a single conversion target, e.g. `hours`.
+    pub fn parse_complete_unit(&mut self) -> Result<Unit, CalculatorError> {
+        let unit = self.parse_unit_for_conversion()?;
+        if !self.is_at_end() {
+            return Err(self.error_at(
+                self.pos,
+                format!(
+                    "Unexpected input after unit {}",
+                    unit.conversion_target_name()
+                ),
+            ));
+        }
+        Ok(unit)
+    }
+
+    /// Parses a unit name after the `as`, `in`, or `to` keyword, including
+    /// compound units such as `km/h` and `USD per liter`.
     pub(super) fn parse_unit_for_conversion(&mut self) -> Result<Unit, CalculatorError> {
+        let unit = self.parse_single_unit_for_conversion()?;
+        let unit = self.parse_unit_exponent(unit);
+        if (self.check(&TokenKind::Slash) || self.word_at(self.pos, "per"))
+            && matches!(self.peek_kind(), Some(TokenKind::Identifier(_)))
+        {
+            self.advance(); // consume "/" or "per"
+            let denominator = self.parse_single_unit_for_conversion()?;
+            let denominator = self.parse_unit_exponent(denominator);
+            if !denominator.is_measure() {
+                return Err(self.error_at(
+                    self.pos - 1,
+                    format!(
+                        "Cannot use '{}' as the denominator of a compound unit",
+                        denominator.display_name()
+                    ),
+                ));
+            }
+            return Ok(Unit::per(unit, denominator));
+        }
+        Ok(unit)
+    }
+
+    /// Returns true if `in <number> <duration>` starts at the current token,
+    /// as in `5 km in 25 minutes`.
+    pub(super) fn pace_ahead(&self) -> bool {
+        self.check_in()
+            && matches!(self.peek_kind(), Some(TokenKind::Number(_)))
+            && matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::Identifier(id)) if DurationUnit::parse(id).is_some()
+            )
+    }
+
+    /// Parses `in <time>` after a distance as the pace: `5 km in 25 minutes`
+    /// is `25 minutes / 5 km`, or 5 min/km.
+    pub(super) fn parse_pace(
+        &mut self,
+        distance: Expression,
+    ) -> Result<Expression, CalculatorError> {
+        self.advance(); // consume "in"
+        let time = self.parse_multiplicative()?;
+        Ok(Expression::binary(time, BinaryOp::Divide, distance))
+    }
+
+    /// Parses the `/h` of a compound unit such as `60 km/h` after a number
+    /// with `numerator` as its unit, if the word after the slash is a unit
+    /// of measure rather than a variable or function.
+    pub(super) fn parse_per_unit(&mut self, numerator: &Unit) -> Option<Unit> {
+        if !(numerator.is_measure() || numerator.is_currency())
+            || !self.check(&TokenKind::Slash)
+            || matches!(
+                self.tokens.get(self.pos + 2).map(|t| &t.kind),
+                Some(TokenKind::LeftParen)
+            )
+        {
+            return None;
+        }
+        let Some(TokenKind::Identifier(id)) = self.peek_kind() else {
+            return None;
+        };
+        let denominator = self.number_grammar.parse_unit(id).ok()?;
+        if !denominator.is_measure() {
+            return None;
+        }
+        self.pos += 2; // consume "/" and the unit
+        let denominator = self.parse_unit_exponent(denominator);
+        Some(Unit::per(numerator.clone(), denominator))
+    }
+
+    /// Parses `^2` after a length, mass or duration unit as its power, so
+    /// that `5 m^2` is five square meters rather than the square of `5 m`.
+    /// The power can be negative or in parentheses: `3 m^-1`, `3 m^(-1)`.
+    pub(super) fn parse_unit_exponent(&mut self, unit: Unit) -> Unit {
+        if !unit.is_powerable() || !self.check(&TokenKind::Caret) {
+            return unit;
+        }
+        let kind = |offset: usize| self.tokens.get(self.pos + offset).map(|t| &t.kind);
+        let parenthesized = matches!(kind(1), Some(TokenKind::LeftParen));
+        let start = 1 + usize::from(parenthesized);
+        let negative = matches!(kind(start), Some(TokenKind::Minus));
+        let digits = start + usize::from(negative);
+        let Some(TokenKind::Number(exponent)) = kind(digits) else {
+            return unit;
+        };
+        let Ok(exponent) = exponent.parse::<i32>() else {
+            return unit;
+        };
+        if parenthesized && !matches!(kind(digits + 1), Some(TokenKind::RightParen)) {
+            return unit;
+        }
+        // Consume "^", the exponent and any sign and parentheses
+        self.pos += digits + 1 + usize::from(parenthesized);
+        Unit::power(unit, if negative { -exponent } else { exponent })
+    }
+
+    fn parse_single_unit_for_conversion(&mut self) -> Result<Unit, CalculatorError> {
         let Some(TokenKind::Identifier(id)) = self.current_kind() else {
-            return Err(CalculatorError::parse(
+            return Err(self.error_at(
+                self.pos,
                 "Expected a unit name after 'as'/'in'/'to' (e.g., 'MB', 'kg', 'USD', 'dollars')",
             ));
         };
 
+        // Cities stand for their time zones: "now in New York"
+        if let Some((city, width)) = self.city_at(self.pos) {
+            self.pos += width;
+            return Ok(Unit::Timezone(city.name().to_string()));
+        }
+
         let unit_str = id.clone();
         self.advance();
 
@@ -20,6 +142,15 @@ impl TokenParser<'_> {
             return Ok(Unit::None);
         }
 
+        match Radix::parse_name(&unit_str) {
+            Some(Radix::Decimal) => return Ok(Unit::None),
+            Some(radix) => return Ok(Unit::Radix(radix)),
+            None => {}
+        }
+        if let Some(model) = ColorModel::parse_name(&unit_str) {
+            return Ok(Unit::Color(model));
+        }
+
         if let Some(data_size) = DataSizeUnit::parse(&unit_str) {
             return Ok(Unit::DataSize(data_size));
         }
@@ -35,9 +166,21 @@ impl TokenParser<'_> {
             return Ok(Unit::Mass(mass));
         }
 
-        if let Some(duration) = crate::types::DurationUnit::parse(&unit_str) {
+        if let Some(duration) = DurationUnit::parse(&unit_str) {
             return Ok(Unit::Duration(duration));
         }
+        if let Some(length) = LengthUnit::parse(&unit_str) {
+            return Ok(Unit::Length(length));
+        }
+        if let Some(volume) = VolumeUnit::parse(&unit_str) {
+            return Ok(Unit::Volume(volume));
+        }
+        if let Some(angle) = AngleUnit::parse(&unit_str) {
+            return Ok(Unit::Angle(angle));
+        }
+        if let Some(compound) = Unit::parse_compound_alias(&unit_str) {
+            return Ok(compound);
+        }
 
         // Timezone comes before currency because currency code parsing accepts
         // any 2-5 letter code.
@@ -49,16 +192,22 @@ impl TokenParser<'_> {
             return Ok(Unit::currency(&currency_code));
         }
 
-        Err(CalculatorError::parse(format!(
-            "Unknown unit '{unit_str}'. Supported conversions: \
+        Err(self.error_at(
+            self.pos - 1,
+            format!(
+                "Unknown unit '{unit_str}'. Supported conversions: \
              data sizes (B, KB, MB, GB, KiB, MiB, GiB, ...), \
              mass (g, kg, tons, lb, oz), \
+             length (mm, cm, m, km, ft, mi), volume (ml, L, gal), \
+             rates (km/h, mph, min/km, mpg), \
              currencies (USD, EUR, GBP, TON, BTC, ETH, ...) and natural language \
              aliases (dollars, euros, bitcoin, toncoin, ...), \
              timezones (UTC, GMT, EST, MSK, JST, ...), \
              time durations (ms, seconds, minutes, hours, days, weeks, months, years), \
+             number bases (hex, binary, octal, decimal), \
              and number/unitless."
-        )))
+            ),
+        ))
     }
 
     /// Resolves unit ambiguity when a conversion target provides context.
@@ -67,7 +216,7 @@ impl TokenParser<'_> {
         target_unit: &Unit,
     ) -> Expression {
         if let Expression::Number {
-            value,
+            ref value,
             ref unit,
             ref alternative_units,
         } = expr
@@ -85,7 +234,7 @@ impl TokenParser<'_> {
                         .collect();
                     new_alternatives.push(unit.clone());
                     return Expression::number_with_unit_alternatives(
-                        value,
+                        value.clone(),
                         alt.clone(),
                         new_alternatives,
                     );
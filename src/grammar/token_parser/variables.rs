@@ -0,0 +1,96 @@
+//! Variables, such as the `x` of `nderiv(3x^2, x, 1)`.
+//!
+//! Outside a context that names its variables, `3x` is 3 in the unit `x`
+//! and `2y` is two years. Inside one, a number followed by a variable is a
+//! product at the precedence of `*`, so `3x^2` is `3 * x^2`.
+
+use crate::grammar::{binds_variable, is_math_function, TokenKind};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Runs `parse` with `names` read as variables rather than units.
+    pub(super) fn with_variables<T>(
+        &mut self,
+        names: Vec<String>,
+        parse: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let outer = self.variables.len();
+        self.variables.extend(names);
+        let result = parse(self);
+        self.variables.truncate(outer);
+        result
+    }
+
+    /// Returns true if `id` names a variable of an enclosing call, integral
+    /// or plot.
+    pub(super) fn is_variable(&self, id: &str) -> bool {
+        self.variables.iter().any(|variable| variable == id)
+    }
+
+    /// Returns true if the current token is a variable written right after
+    /// an operand, as in `3x` or `(x+1)x`, which multiplies it.
+    pub(super) fn variable_ahead(&self) -> bool {
+        matches!(self.current_kind(), Some(TokenKind::Identifier(id)) if self.is_variable(id))
+            && !self.peek_is_left_paren()
+    }
+
+    /// The variables of a call of `name`, whose opening parenthesis has
+    /// been consumed: the second argument of `integrate`, `nderiv` and
+    /// `root`, read ahead of the first.
+    pub(super) fn call_variables(&self, name: &str) -> Vec<String> {
+        if !binds_variable(name) {
+            return Vec::new();
+        }
+        let Some(comma) = self.find_argument_end(self.pos) else {
+            return Vec::new();
+        };
+        match (self.kind_at(comma + 1), self.kind_at(comma + 2)) {
+            (Some(TokenKind::Identifier(id)), Some(TokenKind::Comma | TokenKind::RightParen))
+                if !is_math_function(id) =>
+            {
+                vec![id.clone()]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The single letters used as variables before the token at `end`,
+    /// such as the `x` of `plot sin(2x)` or `simplify 2x + 3x`.
+    pub(super) fn letter_variables(&self, end: usize) -> Vec<String> {
+        let end = end.min(self.tokens.len());
+        self.tokens[self.pos.min(end)..end]
+            .iter()
+            .filter_map(|token| match &token.kind {
+                TokenKind::Identifier(id)
+                    if id.len() == 1
+                        && id.starts_with(|c: char| c.is_ascii_alphabetic())
+                        && !is_math_function(id) =>
+                {
+                    Some(id.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the position of the comma that ends the call argument
+    /// starting at `start`, or `None` if it is the last argument.
+    fn find_argument_end(&self, start: usize) -> Option<usize> {
+        let mut depth = 0_usize;
+        for (pos, token) in self.tokens.iter().enumerate().skip(start) {
+            match token.kind {
+                TokenKind::LeftParen | TokenKind::LeftBracket => depth += 1,
+                TokenKind::RightParen | TokenKind::RightBracket if depth == 0 => return None,
+                TokenKind::RightParen | TokenKind::RightBracket => depth -= 1,
+                TokenKind::Comma if depth == 0 => return Some(pos),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn kind_at(&self, pos: usize) -> Option<&TokenKind> {
+        self.tokens.get(pos).map(|token| &token.kind)
+    }
+}
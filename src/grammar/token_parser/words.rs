@@ -0,0 +1,146 @@
+use crate::error::CalculatorError;
+use crate::grammar::{is_math_function, NumberGrammar, NumberWord, TokenKind};
+use crate::types::{BinaryOp, Decimal, Expression, Unit};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns true if a number written in words starts at the current
+    /// token: `two hundred`, `a dozen`, `half`.
+    pub(super) fn word_number_ahead(&self) -> bool {
+        let start = if self.article_at(self.pos) {
+            self.pos + 1
+        } else {
+            self.pos
+        };
+        self.number_word_at(start).is_some()
+            && !matches!(
+                self.tokens.get(start + 1).map(|t| &t.kind),
+                Some(TokenKind::LeftParen)
+            )
+    }
+
+    /// Parses a number written in words, with an optional unit
+    /// (`twenty dollars`), or a fraction with an optional `of` (`half of 90`).
+    pub(super) fn parse_word_number(&mut self) -> Result<Expression, CalculatorError> {
+        if self.article_at(self.pos) {
+            self.advance(); // consume "a"/"an"
+        }
+        let (mut total, mut current) = (0_i64, 0_i64);
+        let mut denominator = None;
+        while let Some(word) = self.number_word_at(self.pos) {
+            self.advance();
+            match word {
+                NumberWord::Value(n) => current = checked(current.checked_add(n as i64))?,
+                NumberWord::Multiplier(n) => {
+                    current = checked(current.max(1).checked_mul(n as i64))?;
+                }
+                NumberWord::Scale(n) => {
+                    let group = checked(current.max(1).checked_mul(n as i64))?;
+                    total = checked(total.checked_add(group))?;
+                    current = 0;
+                }
+                NumberWord::Fraction(n) => {
+                    denominator = Some(n as i64);
+                    break;
+                }
+            }
+            // "hundred and five", "thirty-five"
+            let joined = match word {
+                NumberWord::Multiplier(_) | NumberWord::Scale(_) => self.check(&TokenKind::And),
+                _ => self.check(&TokenKind::Minus) && self.hyphen_at(self.pos),
+            };
+            if joined && self.number_word_at(self.pos + 1).is_some() {
+                self.advance();
+            }
+        }
+        let value = checked(total.checked_add(current))?;
+
+        let Some(denominator) = denominator else {
+            let unit = self.parse_word_number_unit();
+            return Ok(Expression::number_with_unit(Decimal::new(value), unit));
+        };
+        let fraction = Expression::binary(
+            Expression::number(Decimal::new(value.max(1))),
+            BinaryOp::Divide,
+            Expression::number(Decimal::new(denominator)),
+        );
+        if self.check(&TokenKind::Of) {
+            self.advance(); // consume "of"
+            let whole = self.parse_primary()?;
+            return Ok(Expression::binary(fraction, BinaryOp::Multiply, whole));
+        }
+        Ok(fraction)
+    }
+
+    /// Returns the operator written as a word at the current token, such as
+    /// `plus`, `minus`, `times`, `multiplied by` or `разделить на`, and how
+    /// many tokens it takes, without consuming it.
+    pub(super) fn operator_word_ahead(&self) -> Option<(BinaryOp, usize)> {
+        let Some(TokenKind::Identifier(id)) = self.current_kind() else {
+            return None;
+        };
+        let (op, needs_by) = NumberGrammar::operator_word(id)?;
+        if !needs_by {
+            return Some((op, 1));
+        }
+        let by = self.word_at(self.pos + 1, "by")
+            || matches!(
+                self.tokens.get(self.pos + 1),
+                Some(token) if token.kind == TokenKind::At && token.text.to_lowercase() == "на"
+            );
+        by.then_some((op, 2))
+    }
+
+    fn number_word_at(&self, index: usize) -> Option<NumberWord> {
+        match self.tokens.get(index).map(|t| &t.kind) {
+            Some(TokenKind::Identifier(id)) => NumberGrammar::number_word(id),
+            _ => None,
+        }
+    }
+
+    fn article_at(&self, index: usize) -> bool {
+        (self.word_at(index, "a") || self.word_at(index, "an"))
+            && self.number_word_at(index + 1).is_some()
+    }
+
+    /// Returns true if the token at `index` is a hyphen joining two words,
+    /// with no spaces around it.
+    fn hyphen_at(&self, index: usize) -> bool {
+        match (
+            self.tokens.get(index - 1),
+            self.tokens.get(index),
+            self.tokens.get(index + 1),
+        ) {
+            (Some(before), Some(hyphen), Some(after)) => {
+                before.end == hyphen.start && hyphen.end == after.start
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses the unit after a number in words, such as `dollars` in
+    /// `twenty dollars`.
+    fn parse_word_number_unit(&mut self) -> Unit {
+        let Some(TokenKind::Identifier(id)) = self.current_kind() else {
+            return Unit::None;
+        };
+        if is_math_function(id)
+            || self.peek_is_left_paren()
+            || NumberGrammar::operator_word(id).is_some()
+        {
+            return Unit::None;
+        }
+        match self.number_grammar.parse_unit(id) {
+            Ok(Unit::Custom(_)) | Err(_) => Unit::None,
+            Ok(unit) => {
+                self.advance();
+                unit
+            }
+        }
+    }
+}
+
+fn checked(value: Option<i64>) -> Result<i64, CalculatorError> {
+    value.ok_or(CalculatorError::Overflow)
+}
@@ -0,0 +1,71 @@
+//! World-clock expressions: `time in Tokyo`, and `time in Tokyo when 9am
+//! in New York`, with cities named in place of time zones.
+
+use crate::error::CalculatorError;
+use crate::grammar::TokenKind;
+use crate::types::{City, Expression, Unit, MAX_CITY_WORDS};
+
+use super::TokenParser;
+
+impl TokenParser<'_> {
+    /// Returns the city named by the words starting at token `index`, and
+    /// how many tokens it is written with, preferring the longest name.
+    pub(super) fn city_at(&self, index: usize) -> Option<(City, usize)> {
+        let words: Vec<&str> = self
+            .tokens
+            .get(index..)?
+            .iter()
+            .take(MAX_CITY_WORDS)
+            .map_while(|token| match &token.kind {
+                TokenKind::Identifier(word) => Some(word.as_str()),
+                _ => None,
+            })
+            .collect();
+        (1..=words.len())
+            .rev()
+            .find_map(|width| City::find(&words[..width].join(" ")).map(|city| (city, width)))
+    }
+
+    /// Checks if `time in <city>` starts at the current token.
+    pub(super) fn world_clock_ahead(&self) -> bool {
+        self.word_at(self.pos, "time")
+            && matches!(self.peek_kind(), Some(TokenKind::In))
+            && self.city_at(self.pos + 2).is_some()
+    }
+
+    /// Parses `time in <city>` as the time there now, or, followed by
+    /// `when <time> in <city>`, as the time there at that time in the other
+    /// city: `time in Tokyo when 9am in New York`.
+    pub(super) fn parse_world_clock(&mut self) -> Result<Expression, CalculatorError> {
+        self.pos += 2; // consume "time in"
+        let (city, width) = self
+            .city_at(self.pos)
+            .ok_or_else(|| self.error_at(self.pos, "Expected a city after 'time in'"))?;
+        self.pos += width;
+        if !self.word_at(self.pos, "when") {
+            return Ok(Expression::unit_conversion(
+                Expression::Now,
+                Unit::Timezone(city.name().to_string()),
+            ));
+        }
+        self.advance(); // consume "when"
+        if self.word_at(self.pos, "it") && self.word_at(self.pos + 1, "is") {
+            self.pos += 2;
+        }
+        let mut time = self.parse_clock_time()?;
+        if self.check_in() {
+            self.advance();
+        }
+        if time.offset_seconds().is_none() {
+            let (source, width) = self
+                .city_at(self.pos)
+                .ok_or_else(|| self.error_at(self.pos, "Expected the city the time is in"))?;
+            self.pos += width;
+            time = source.local_time(&time);
+        }
+        Ok(Expression::unit_conversion(
+            Expression::DateTime(time),
+            Unit::Timezone(city.name().to_string()),
+        ))
+    }
+}
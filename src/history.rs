@@ -0,0 +1,46 @@
+//! Result history on the [`Calculator`], referenced as `ans` and `#n`.
+
+use std::sync::RwLockReadGuard;
+
+use crate::types::History;
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Returns the history as a JSON array of `{input, result, value}` entries,
+    /// oldest first (the first entry is `#1`).
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn history_json(&self) -> String {
+        serde_json::to_string(&*self.history()).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Replaces the history with entries previously returned by
+    /// [`Self::history_json`]. Returns `false` (leaving the current history
+    /// untouched) if the JSON cannot be parsed.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn load_history_json(&mut self, history_json: &str) -> bool {
+        match serde_json::from_str::<History>(history_json) {
+            Ok(history) => {
+                *self.parser.history_mut() = history;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Forgets all earlier results; numbering starts again at `#1`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_history(&mut self) {
+        self.parser.history_mut().clear();
+    }
+}
+
+impl Calculator {
+    /// Returns the results of earlier successful calculations, locked
+    /// against calculations on other threads adding to them.
+    pub fn history(&self) -> RwLockReadGuard<'_, History> {
+        self.parser.history()
+    }
+}
@@ -0,0 +1,409 @@
+//! Step templates by translation key, per language.
+//!
+//! The keys and templates are those of the web app's `steps` catalog;
+//! `{{name}}` marks a parameter.
+
+/// A translation key and its template.
+pub(super) type Template = (&'static str, &'static str);
+
+/// English step templates.
+pub(super) const EN: &[Template] = &[
+    ("inputExpression", "Input expression: {{expression}}"),
+    ("input", "Input: {{expression}}"),
+    ("literalValue", "Literal value: {{value}}"),
+    ("dateTimeValue", "DateTime value: {{value}}"),
+    ("utcEquivalent", "UTC equivalent: {{value}}"),
+    ("timeUntil", "Time until: {{duration}}"),
+    ("timeSince", "Time since: {{duration}} ago"),
+    ("currentTime", "Current time: {{time}}"),
+    ("timeUntilTarget", "Time until {{target}}: {{duration}}"),
+    ("timeSinceTarget", "Time since {{target}}: {{duration}} ago"),
+    ("compute", "Compute: {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "Negate: -{{value}} = {{result}}"),
+    ("evaluateGroup", "Evaluate grouped expression:"),
+    ("atTime", "At time: {{time}}"),
+    ("callFunction", "Call function: {{name}}({{args}})"),
+    ("computePower", "Compute: {{base}} ^ {{exponent}}"),
+    (
+        "numericalIntegration",
+        "Numerical integration: {{name}}(...)",
+    ),
+    (
+        "integrationErrorEstimate",
+        "Error estimate: ±{{error}} (subintervals: {{intervals}})",
+    ),
+    ("parseFirstDatetime", "Parse first datetime: {{datetime}}"),
+    ("parseSecondDatetime", "Parse second datetime: {{datetime}}"),
+    (
+        "calculateDifference",
+        "Calculate difference: {{dt1}} - {{dt2}}",
+    ),
+    ("result", "Result: {{value}}"),
+    ("finalResult", "Final result: {{value}}"),
+    (
+        "exchangeRate",
+        "Exchange rate: 1 {{from}} = {{rate}} {{to}} (source: {{source}}, date: {{date}})",
+    ),
+    ("convert", "Convert: {{value}} to {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "Indefinite integral: ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "Computed symbolic result"),
+    ("solveLinearEquation", "Solve linear equation:"),
+    ("solution", "Solution: {{value}}"),
+    ("checkEquality", "Check equality:"),
+    ("compare", "Compare: {{left}} = {{right}}"),
+    ("compareOperator", "Compare: {{left}} {{op}} {{right}}"),
+];
+
+/// Russian step templates.
+pub(super) const RU: &[Template] = &[
+    ("inputExpression", "Входное выражение: {{expression}}"),
+    ("input", "Ввод: {{expression}}"),
+    ("literalValue", "Литеральное значение: {{value}}"),
+    ("dateTimeValue", "Значение даты/времени: {{value}}"),
+    ("utcEquivalent", "Эквивалент UTC: {{value}}"),
+    ("timeUntil", "До события: {{duration}}"),
+    ("timeSince", "С момента события: {{duration}} назад"),
+    ("currentTime", "Текущее время: {{time}}"),
+    ("timeUntilTarget", "До {{target}}: {{duration}}"),
+    (
+        "timeSinceTarget",
+        "С момента {{target}}: {{duration}} назад",
+    ),
+    ("compute", "Вычислить: {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "Отрицание: -{{value}} = {{result}}"),
+    ("evaluateGroup", "Вычислить сгруппированное выражение:"),
+    ("atTime", "На момент времени: {{time}}"),
+    ("callFunction", "Вызов функции: {{name}}({{args}})"),
+    ("computePower", "Вычислить: {{base}} ^ {{exponent}}"),
+    (
+        "numericalIntegration",
+        "Численное интегрирование: {{name}}(...)",
+    ),
+    (
+        "integrationErrorEstimate",
+        "Оценка погрешности: ±{{error}} (подынтервалов: {{intervals}})",
+    ),
+    (
+        "parseFirstDatetime",
+        "Разбор первой даты/времени: {{datetime}}",
+    ),
+    (
+        "parseSecondDatetime",
+        "Разбор второй даты/времени: {{datetime}}",
+    ),
+    (
+        "calculateDifference",
+        "Вычислить разницу: {{dt1}} - {{dt2}}",
+    ),
+    ("result", "Результат: {{value}}"),
+    ("finalResult", "Итоговый результат: {{value}}"),
+    (
+        "exchangeRate",
+        "Курс обмена: 1 {{from}} = {{rate}} {{to}} (источник: {{source}}, дата: {{date}})",
+    ),
+    ("convert", "Преобразовать: {{value}} в {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "Неопределённый интеграл: ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "Вычислен символьный результат"),
+    ("solveLinearEquation", "Решить линейное уравнение:"),
+    ("solution", "Решение: {{value}}"),
+    ("checkEquality", "Проверить равенство:"),
+    ("compare", "Сравнить: {{left}} = {{right}}"),
+    ("compareOperator", "Сравнить: {{left}} {{op}} {{right}}"),
+];
+
+/// German step templates.
+pub(super) const DE: &[Template] = &[
+    ("inputExpression", "Eingabeausdruck: {{expression}}"),
+    ("input", "Eingabe: {{expression}}"),
+    ("literalValue", "Literalwert: {{value}}"),
+    ("dateTimeValue", "Datums-/Zeitwert: {{value}}"),
+    ("utcEquivalent", "UTC-Entsprechung: {{value}}"),
+    ("timeUntil", "Zeit bis dahin: {{duration}}"),
+    ("timeSince", "Zeit seitdem: vor {{duration}}"),
+    ("currentTime", "Aktuelle Zeit: {{time}}"),
+    ("timeUntilTarget", "Zeit bis {{target}}: {{duration}}"),
+    ("timeSinceTarget", "Zeit seit {{target}}: vor {{duration}}"),
+    ("compute", "Berechne: {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "Negation: -{{value}} = {{result}}"),
+    ("evaluateGroup", "Gruppierten Ausdruck auswerten:"),
+    ("atTime", "Zum Zeitpunkt: {{time}}"),
+    ("callFunction", "Funktionsaufruf: {{name}}({{args}})"),
+    ("computePower", "Berechne: {{base}} ^ {{exponent}}"),
+    (
+        "numericalIntegration",
+        "Numerische Integration: {{name}}(...)",
+    ),
+    (
+        "integrationErrorEstimate",
+        "Fehlerschätzung: ±{{error}} (Teilintervalle: {{intervals}})",
+    ),
+    (
+        "parseFirstDatetime",
+        "Erstes Datum/Zeit analysieren: {{datetime}}",
+    ),
+    (
+        "parseSecondDatetime",
+        "Zweites Datum/Zeit analysieren: {{datetime}}",
+    ),
+    (
+        "calculateDifference",
+        "Differenz berechnen: {{dt1}} - {{dt2}}",
+    ),
+    ("result", "Ergebnis: {{value}}"),
+    ("finalResult", "Endergebnis: {{value}}"),
+    (
+        "exchangeRate",
+        "Wechselkurs: 1 {{from}} = {{rate}} {{to}} (Quelle: {{source}}, Datum: {{date}})",
+    ),
+    ("convert", "Umrechnen: {{value}} in {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "Unbestimmtes Integral: ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "Symbolisches Ergebnis berechnet"),
+    ("solveLinearEquation", "Lineare Gleichung lösen:"),
+    ("solution", "Lösung: {{value}}"),
+    ("checkEquality", "Gleichheit prüfen:"),
+    ("compare", "Vergleichen: {{left}} = {{right}}"),
+    ("compareOperator", "Vergleichen: {{left}} {{op}} {{right}}"),
+];
+
+/// French step templates.
+pub(super) const FR: &[Template] = &[
+    ("inputExpression", "Expression d'entrée : {{expression}}"),
+    ("input", "Entrée : {{expression}}"),
+    ("literalValue", "Valeur littérale : {{value}}"),
+    ("dateTimeValue", "Valeur date/heure : {{value}}"),
+    ("utcEquivalent", "Équivalent UTC : {{value}}"),
+    ("timeUntil", "Temps restant : {{duration}}"),
+    ("timeSince", "Temps écoulé : {{duration}}"),
+    ("currentTime", "Heure actuelle : {{time}}"),
+    (
+        "timeUntilTarget",
+        "Temps restant jusqu'à {{target}} : {{duration}}",
+    ),
+    (
+        "timeSinceTarget",
+        "Temps écoulé depuis {{target}} : {{duration}}",
+    ),
+    ("compute", "Calculer : {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "Négation : -{{value}} = {{result}}"),
+    ("evaluateGroup", "Évaluer l'expression groupée :"),
+    ("atTime", "Au moment : {{time}}"),
+    ("callFunction", "Appel de fonction : {{name}}({{args}})"),
+    ("computePower", "Calculer : {{base}} ^ {{exponent}}"),
+    (
+        "numericalIntegration",
+        "Intégration numérique : {{name}}(...)",
+    ),
+    (
+        "integrationErrorEstimate",
+        "Estimation de l’erreur : ±{{error}} (sous-intervalles : {{intervals}})",
+    ),
+    (
+        "parseFirstDatetime",
+        "Analyser la première date/heure : {{datetime}}",
+    ),
+    (
+        "parseSecondDatetime",
+        "Analyser la deuxième date/heure : {{datetime}}",
+    ),
+    (
+        "calculateDifference",
+        "Calculer la différence : {{dt1}} - {{dt2}}",
+    ),
+    ("result", "Résultat : {{value}}"),
+    ("finalResult", "Résultat final : {{value}}"),
+    (
+        "exchangeRate",
+        "Taux de change : 1 {{from}} = {{rate}} {{to}} (source : {{source}}, date : {{date}})",
+    ),
+    ("convert", "Convertir : {{value}} en {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "Intégrale indéfinie : ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "Résultat symbolique calculé"),
+    ("solveLinearEquation", "Résoudre l'équation linéaire :"),
+    ("solution", "Solution : {{value}}"),
+    ("checkEquality", "Vérifier l'égalité :"),
+    ("compare", "Comparer : {{left}} = {{right}}"),
+    ("compareOperator", "Comparer : {{left}} {{op}} {{right}}"),
+];
+
+/// Chinese (Simplified) step templates.
+pub(super) const ZH: &[Template] = &[
+    ("inputExpression", "输入表达式：{{expression}}"),
+    ("input", "输入：{{expression}}"),
+    ("literalValue", "字面值：{{value}}"),
+    ("dateTimeValue", "日期时间值：{{value}}"),
+    ("utcEquivalent", "UTC 等效时间：{{value}}"),
+    ("timeUntil", "剩余时间：{{duration}}"),
+    ("timeSince", "已经过：{{duration}}"),
+    ("currentTime", "当前时间：{{time}}"),
+    ("timeUntilTarget", "距离 {{target}}：{{duration}}"),
+    ("timeSinceTarget", "自 {{target}} 已经过：{{duration}}"),
+    ("compute", "计算：{{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "取反：-{{value}} = {{result}}"),
+    ("evaluateGroup", "计算分组表达式："),
+    ("atTime", "在时间点：{{time}}"),
+    ("callFunction", "调用函数：{{name}}({{args}})"),
+    ("computePower", "计算：{{base}} ^ {{exponent}}"),
+    ("numericalIntegration", "数值积分：{{name}}(...)"),
+    (
+        "integrationErrorEstimate",
+        "误差估计：±{{error}}（子区间：{{intervals}}）",
+    ),
+    ("parseFirstDatetime", "解析第一个日期时间：{{datetime}}"),
+    ("parseSecondDatetime", "解析第二个日期时间：{{datetime}}"),
+    ("calculateDifference", "计算差值：{{dt1}} - {{dt2}}"),
+    ("result", "结果：{{value}}"),
+    ("finalResult", "最终结果：{{value}}"),
+    (
+        "exchangeRate",
+        "汇率：1 {{from}} = {{rate}} {{to}}（来源：{{source}}，日期：{{date}}）",
+    ),
+    ("convert", "转换：{{value}} 到 {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "不定积分：∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "已计算符号结果"),
+    ("solveLinearEquation", "求解线性方程："),
+    ("solution", "解：{{value}}"),
+    ("checkEquality", "检查相等性："),
+    ("compare", "比较：{{left}} = {{right}}"),
+    ("compareOperator", "比较：{{left}} {{op}} {{right}}"),
+];
+
+/// Hindi step templates.
+pub(super) const HI: &[Template] = &[
+    ("inputExpression", "इनपुट अभिव्यक्ति: {{expression}}"),
+    ("input", "इनपुट: {{expression}}"),
+    ("literalValue", "शाब्दिक मान: {{value}}"),
+    ("dateTimeValue", "दिनांक/समय मान: {{value}}"),
+    ("utcEquivalent", "UTC समतुल्य: {{value}}"),
+    ("timeUntil", "शेष समय: {{duration}}"),
+    ("timeSince", "बीता समय: {{duration}}"),
+    ("currentTime", "वर्तमान समय: {{time}}"),
+    ("timeUntilTarget", "{{target}} तक शेष समय: {{duration}}"),
+    ("timeSinceTarget", "{{target}} से बीता समय: {{duration}}"),
+    ("compute", "गणना: {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "नकारात्मक: -{{value}} = {{result}}"),
+    ("evaluateGroup", "समूहित अभिव्यक्ति का मूल्यांकन करें:"),
+    ("atTime", "समय पर: {{time}}"),
+    ("callFunction", "फ़ंक्शन कॉल: {{name}}({{args}})"),
+    ("computePower", "गणना: {{base}} ^ {{exponent}}"),
+    ("numericalIntegration", "संख्यात्मक एकीकरण: {{name}}(...)"),
+    (
+        "integrationErrorEstimate",
+        "त्रुटि अनुमान: ±{{error}} (उप-अंतराल: {{intervals}})",
+    ),
+    ("parseFirstDatetime", "पहला दिनांक/समय पार्स करें: {{datetime}}"),
+    (
+        "parseSecondDatetime",
+        "दूसरा दिनांक/समय पार्स करें: {{datetime}}",
+    ),
+    ("calculateDifference", "अंतर की गणना करें: {{dt1}} - {{dt2}}"),
+    ("result", "परिणाम: {{value}}"),
+    ("finalResult", "अंतिम परिणाम: {{value}}"),
+    (
+        "exchangeRate",
+        "विनिमय दर: 1 {{from}} = {{rate}} {{to}} (स्रोत: {{source}}, तारीख: {{date}})",
+    ),
+    ("convert", "रूपांतरित करें: {{value}} को {{unit}} में"),
+    (
+        "indefiniteIntegral",
+        "अनिश्चित समाकल: ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "प्रतीकात्मक परिणाम की गणना की गई"),
+    ("solveLinearEquation", "रेखीय समीकरण हल करें:"),
+    ("solution", "हल: {{value}}"),
+    ("checkEquality", "समानता जाँचें:"),
+    ("compare", "तुलना करें: {{left}} = {{right}}"),
+    ("compareOperator", "तुलना करें: {{left}} {{op}} {{right}}"),
+];
+
+/// Arabic step templates.
+pub(super) const AR: &[Template] = &[
+    ("inputExpression", "تعبير الإدخال: {{expression}}"),
+    ("input", "الإدخال: {{expression}}"),
+    ("literalValue", "القيمة الحرفية: {{value}}"),
+    ("dateTimeValue", "قيمة التاريخ/الوقت: {{value}}"),
+    ("utcEquivalent", "المكافئ بتوقيت UTC: {{value}}"),
+    ("timeUntil", "الوقت المتبقي: {{duration}}"),
+    ("timeSince", "الوقت المنقضي: {{duration}}"),
+    ("currentTime", "الوقت الحالي: {{time}}"),
+    (
+        "timeUntilTarget",
+        "الوقت المتبقي حتى {{target}}: {{duration}}",
+    ),
+    (
+        "timeSinceTarget",
+        "الوقت المنقضي منذ {{target}}: {{duration}}",
+    ),
+    ("compute", "حساب: {{left}} {{op}} {{right}}"),
+    ("equals", "= {{value}}"),
+    ("negate", "نفي: -{{value}} = {{result}}"),
+    ("evaluateGroup", "تقييم التعبير المجمع:"),
+    ("atTime", "في الوقت: {{time}}"),
+    ("callFunction", "استدعاء الدالة: {{name}}({{args}})"),
+    ("computePower", "حساب: {{base}} ^ {{exponent}}"),
+    ("numericalIntegration", "التكامل العددي: {{name}}(...)"),
+    (
+        "integrationErrorEstimate",
+        "تقدير الخطأ: ±{{error}} (الفترات الجزئية: {{intervals}})",
+    ),
+    (
+        "parseFirstDatetime",
+        "تحليل التاريخ/الوقت الأول: {{datetime}}",
+    ),
+    (
+        "parseSecondDatetime",
+        "تحليل التاريخ/الوقت الثاني: {{datetime}}",
+    ),
+    ("calculateDifference", "حساب الفرق: {{dt1}} - {{dt2}}"),
+    ("result", "النتيجة: {{value}}"),
+    ("finalResult", "النتيجة النهائية: {{value}}"),
+    (
+        "exchangeRate",
+        "سعر الصرف: 1 {{from}} = {{rate}} {{to}} (المصدر: {{source}}، التاريخ: {{date}})",
+    ),
+    ("convert", "تحويل: {{value}} إلى {{unit}}"),
+    (
+        "indefiniteIntegral",
+        "تكامل غير محدد: ∫ {{integrand}} d{{variable}}",
+    ),
+    ("computedSymbolicResult", "تم حساب نتيجة رمزية"),
+    ("solveLinearEquation", "حل المعادلة الخطية:"),
+    ("solution", "الحل: {{value}}"),
+    ("checkEquality", "التحقق من المساواة:"),
+    ("compare", "مقارنة: {{left}} = {{right}}"),
+    ("compareOperator", "مقارنة: {{left}} {{op}} {{right}}"),
+];
+
+/// Returns the step templates of a language, such as `ru` or `de`.
+pub(super) fn templates(language: &str) -> Option<&'static [Template]> {
+    match language {
+        "en" => Some(EN),
+        "ru" => Some(RU),
+        "de" => Some(DE),
+        "fr" => Some(FR),
+        "zh" => Some(ZH),
+        "hi" => Some(HI),
+        "ar" => Some(AR),
+        _ => None,
+    }
+}
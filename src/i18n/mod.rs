@@ -0,0 +1,128 @@
+//! Translation of calculation steps.
+//!
+//! The evaluator writes its steps in English. [`translate_step`] matches a
+//! step against the English templates of the catalog and fills the template
+//! with the same key in another language, so that the `steps` strings come
+//! out in the user's language. Steps without a template stay in English.
+
+mod catalog;
+
+/// Normalizes a language tag such as `ru-RU` or `zh_CN` to its language
+/// code (`ru`, `zh`).
+#[must_use]
+pub fn language_code(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+/// Returns true if steps can be translated to the language of `tag`.
+#[must_use]
+pub fn is_supported_language(tag: &str) -> bool {
+    catalog::templates(&language_code(tag)).is_some()
+}
+
+/// Translates an English step into the language of `tag`, or returns it
+/// unchanged when there is no template for it.
+#[must_use]
+pub fn translate_step(step: &str, tag: &str) -> String {
+    let Some(templates) = catalog::templates(&language_code(tag)) else {
+        return step.to_string();
+    };
+    for (key, english) in catalog::EN {
+        let Some(params) = match_template(english, step) else {
+            continue;
+        };
+        if let Some((_, template)) = templates.iter().find(|(k, _)| k == key) {
+            return fill_template(template, &params);
+        }
+    }
+    step.to_string()
+}
+
+/// Splits a template into its literal text and the names of its
+/// parameters: there is always one more literal than there are names.
+fn split_template(template: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut literals = Vec::new();
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some((literal, after)) = rest.split_once("{{") {
+        let (name, after) = after.split_once("}}").unwrap_or((after, ""));
+        literals.push(literal);
+        names.push(name);
+        rest = after;
+    }
+    literals.push(rest);
+    (literals, names)
+}
+
+/// Matches `text` against a template, returning the value of each
+/// parameter.
+fn match_template<'a, 't>(template: &'t str, text: &'a str) -> Option<Vec<(&'t str, &'a str)>> {
+    let (literals, names) = split_template(template);
+    let mut rest = text.strip_prefix(literals[0])?;
+    let mut params = Vec::with_capacity(names.len());
+    for (i, name) in names.iter().enumerate() {
+        let literal = literals[i + 1];
+        let end = if i + 1 == names.len() {
+            rest.strip_suffix(literal)?.len()
+        } else {
+            rest.find(literal)?
+        };
+        params.push((*name, &rest[..end]));
+        rest = &rest[end + literal.len()..];
+    }
+    rest.is_empty().then_some(params)
+}
+
+/// Fills the parameters of a template with their values.
+fn fill_template(template: &str, params: &[(&str, &str)]) -> String {
+    let (literals, names) = split_template(template);
+    let mut text = literals[0].to_string();
+    for (name, literal) in names.iter().zip(&literals[1..]) {
+        if let Some((_, value)) = params.iter().find(|(n, _)| n == name) {
+            text.push_str(value);
+        }
+        text.push_str(literal);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_language_has_every_key() {
+        for language in ["ru", "de", "fr", "zh", "hi", "ar"] {
+            let templates = catalog::templates(language).unwrap();
+            let keys: Vec<_> = templates.iter().map(|(key, _)| *key).collect();
+            let english: Vec<_> = catalog::EN.iter().map(|(key, _)| *key).collect();
+            assert_eq!(keys, english, "{language}");
+        }
+    }
+
+    #[test]
+    fn test_translates_steps_with_parameters() {
+        assert_eq!(
+            translate_step("Compute: 10 USD + 5 EUR", "ru-RU"),
+            "Вычислить: 10 USD + 5 EUR"
+        );
+        assert_eq!(
+            translate_step("Call function: max(1, 2)", "de"),
+            "Funktionsaufruf: max(1, 2)"
+        );
+        assert_eq!(translate_step("= 14", "fr"), "= 14");
+    }
+
+    #[test]
+    fn test_leaves_unknown_steps_and_languages_alone() {
+        assert_eq!(
+            translate_step("Solve for x: x = 2", "ru"),
+            "Solve for x: x = 2"
+        );
+        assert_eq!(translate_step("Final result: 2", "xx"), "Final result: 2");
+    }
+}
@@ -11,7 +11,7 @@
 //! ```
 //! use link_calculator::Calculator;
 //!
-//! let mut calculator = Calculator::new();
+//! let calculator = Calculator::new();
 //! let result = calculator.calculate_internal("2 + 3");
 //! assert!(result.success);
 //! assert_eq!(result.result, "5");
@@ -35,389 +35,106 @@ pub mod crypto_api;
 pub mod currency_api;
 pub mod error;
 pub mod grammar;
+pub mod i18n;
 pub mod lino;
+#[cfg(feature = "offline-rates")]
+pub mod offline_rates;
 pub mod plan;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "wasm")]
 pub mod wasm;
 
+mod benchmark;
+mod bookmarks;
+mod capabilities;
+mod finance;
+mod formatting;
+mod function_registry;
+mod history;
+mod limits;
+mod locale;
+mod options;
+mod parse_cache;
+mod plot_range;
+mod plotting;
+mod progress;
+mod representations;
+mod result;
 mod substitution;
-
+mod suggestions;
+mod sync;
+mod telemetry;
+mod validation;
+
+pub use benchmark::BenchmarkCase;
+pub use capabilities::{
+    Capabilities, CurrencyInfo, DateFormatInfo, Fixity, OperatorInfo, UnitCategory, UnitInfo,
+};
+pub use function_registry::{FunctionCategory, FunctionInfo, FUNCTIONS};
+pub use locale::{supported_locales, LocaleInfo};
+pub use options::CalculationOptions;
 pub use plan::{CalculationPlan, RateSource};
-pub use utils::{generate_issue_link, truncate};
-
-use error::{CalculatorError, ErrorInfo};
+pub use representations::{Representation, RepresentationKind};
+pub use result::{
+    result_from_json, BaseRepresentations, BreakdownItem, CalculationResult, CalculationStep,
+    PlotData, RepeatingDecimalFormats, ResultTable, UnresolvedExpression, RESULT_SCHEMA_VERSION,
+};
+pub use utils::{base64_decode, base64_encode, generate_issue_link, truncate, url_encode};
+pub use validation::{Diagnostic, FormatResult, ValidationResult};
+
+use error::CalculatorError;
 use grammar::ExpressionParser;
-use types::{DateTimeResult, Expression, Value, ValueKind};
+use types::{Expression, FormatOptions, Value, ValueKind};
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
 /// Package version (matches Cargo.toml version).
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-/// Data for plotting a function.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct PlotData {
-    /// X-axis values.
-    pub x_values: Vec<f64>,
-    /// Y-axis values.
-    pub y_values: Vec<f64>,
-    /// Label for the plot (e.g., "sin(x)/x").
-    pub label: String,
-    /// X-axis label.
-    pub x_label: String,
-    /// Y-axis label.
-    pub y_label: String,
-}
-
-/// A single calculation step with i18n support.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct CalculationStep {
-    /// The translation key for this step type.
-    pub key: String,
-    /// Parameters for interpolation in the translated message.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub params: Option<std::collections::HashMap<String, String>>,
-    /// The raw (English) text for fallback.
-    pub text: String,
-}
-
-impl CalculationStep {
-    /// Creates a new step with a translation key, params, and fallback text.
-    #[must_use]
-    pub fn new(
-        key: impl Into<String>,
-        params: Option<std::collections::HashMap<String, String>>,
-        text: impl Into<String>,
-    ) -> Self {
-        Self {
-            key: key.into(),
-            params,
-            text: text.into(),
-        }
-    }
-
-    /// Creates a simple step with just text (no translation key).
-    #[must_use]
-    pub fn text_only(text: impl Into<String>) -> Self {
-        let text = text.into();
-        Self {
-            key: String::new(),
-            params: None,
-            text,
-        }
-    }
-}
-
-/// Repeating decimal notation formats.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct RepeatingDecimalFormats {
-    /// Vinculum notation with overline: 0.3̅
-    pub vinculum: String,
-    /// Parenthesis notation: 0.(3)
-    pub parenthesis: String,
-    /// Ellipsis notation: 0.333...
-    pub ellipsis: String,
-    /// LaTeX notation: 0.\overline{3}
-    pub latex: String,
-    /// Fraction representation: 1/3
-    pub fraction: String,
-}
-
-/// Result of a calculation operation.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct CalculationResult {
-    /// The computed value as a string.
-    pub result: String,
-    /// The input interpreted in links notation format.
-    pub lino_interpretation: String,
-    /// Alternative links notation interpretations the user can switch between.
-    /// The first element is always the currently selected (default) interpretation.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub alternative_lino: Option<Vec<String>>,
-    /// Step-by-step explanation of the calculation (raw text for backwards compatibility).
-    pub steps: Vec<String>,
-    /// Step-by-step explanation with i18n support.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub steps_i18n: Option<Vec<CalculationStep>>,
-    /// Whether the calculation was successful.
-    pub success: bool,
-    /// Error message if calculation failed (raw text for backwards compatibility).
-    pub error: Option<String>,
-    /// Error information for i18n support.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_info: Option<ErrorInfo>,
-    /// Link to create an issue for unrecognized input.
-    pub issue_link: Option<String>,
-    /// LaTeX representation of the input (for rendering mathematical formulas).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latex_input: Option<String>,
-    /// LaTeX representation of the result (for rendering mathematical formulas).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latex_result: Option<String>,
-    /// Whether this is a symbolic result (e.g., indefinite integral).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_symbolic: Option<bool>,
-    /// Plot data points for graphing (x, y pairs).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub plot_data: Option<PlotData>,
-    /// Repeating decimal notations (if the result is a repeating decimal).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub repeating_decimal: Option<RepeatingDecimalFormats>,
-    /// Fraction representation of the result (if applicable).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fraction: Option<String>,
-    /// Whether the result represents a live (auto-updating) time expression.
-    /// When `true`, the frontend should periodically re-calculate the expression.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_live_time: Option<bool>,
-    /// Structured datetime metadata for browser-local and UTC conversion display.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub datetime_result: Option<DateTimeResult>,
-}
-
-impl CalculationResult {
-    /// Creates a successful calculation result.
-    #[must_use]
-    pub fn success(result: String, lino: String, steps: Vec<String>) -> Self {
-        Self {
-            result,
-            lino_interpretation: lino,
-            alternative_lino: None,
-            steps,
-            steps_i18n: None,
-            success: true,
-            error: None,
-            error_info: None,
-            issue_link: None,
-            latex_input: None,
-            latex_result: None,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-
-    /// Creates a successful calculation result with rational value information.
-    #[must_use]
-    pub fn success_with_value(value: &Value, lino: String, steps: Vec<String>) -> Self {
-        let result = value.to_display_string();
-
-        // Extract repeating decimal and fraction info if available
-        let (repeating_decimal, fraction) = if let Some(rational) = value.as_rational() {
-            let fraction = if !rational.is_integer() {
-                Some(rational.to_fraction_string())
-            } else {
-                None
-            };
-
-            let repeating =
-                rational
-                    .to_repeating_decimal_notation()
-                    .map(|rd| RepeatingDecimalFormats {
-                        vinculum: rd.to_vinculum_notation(),
-                        parenthesis: rd.to_parenthesis_notation(),
-                        ellipsis: rd.to_ellipsis_notation(),
-                        latex: rd.to_latex(),
-                        fraction: rational.to_fraction_string(),
-                    });
-
-            (repeating, fraction)
-        } else {
-            (None, None)
-        };
-        let datetime_result = if let ValueKind::DateTime(dt) = &value.kind {
-            DateTimeResult::from_datetime(dt)
-        } else {
-            None
-        };
-
-        Self {
-            result,
-            lino_interpretation: lino,
-            alternative_lino: None,
-            steps,
-            steps_i18n: None,
-            success: true,
-            error: None,
-            error_info: None,
-            issue_link: None,
-            latex_input: None,
-            latex_result: None,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal,
-            fraction,
-            is_live_time: None,
-            datetime_result,
-        }
-    }
-
-    /// Creates a successful calculation result with i18n step info.
-    #[must_use]
-    pub fn success_with_i18n(
-        result: String,
-        lino: String,
-        steps: Vec<String>,
-        steps_i18n: Vec<CalculationStep>,
-    ) -> Self {
-        Self {
-            result,
-            lino_interpretation: lino,
-            alternative_lino: None,
-            steps,
-            steps_i18n: Some(steps_i18n),
-            success: true,
-            error: None,
-            error_info: None,
-            issue_link: None,
-            latex_input: None,
-            latex_result: None,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-
-    /// Creates a successful calculation result with LaTeX formatting.
-    #[must_use]
-    pub fn success_with_latex(
-        result: String,
-        lino: String,
-        steps: Vec<String>,
-        latex_input: Option<String>,
-        latex_result: Option<String>,
-    ) -> Self {
-        Self {
-            result,
-            lino_interpretation: lino,
-            alternative_lino: None,
-            steps,
-            steps_i18n: None,
-            success: true,
-            error: None,
-            error_info: None,
-            issue_link: None,
-            latex_input,
-            latex_result,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-
-    /// Creates a symbolic result (e.g., for indefinite integrals).
-    #[must_use]
-    pub fn symbolic(
-        expression: &str,
-        result: String,
-        latex_input: String,
-        latex_result: String,
-        plot_data: Option<PlotData>,
-    ) -> Self {
-        Self {
-            result,
-            lino_interpretation: expression.to_string(),
-            alternative_lino: None,
-            steps: vec![
-                format!("Input: {}", expression),
-                "Computed symbolic result".to_string(),
-            ],
-            steps_i18n: None,
-            success: true,
-            error: None,
-            error_info: None,
-            issue_link: None,
-            latex_input: Some(latex_input),
-            latex_result: Some(latex_result),
-            is_symbolic: Some(true),
-            plot_data,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-
-    /// Creates a failed calculation result.
-    #[must_use]
-    pub fn failure(error: String, input: &str) -> Self {
-        let issue_link = generate_issue_link(input, &error);
-        Self {
-            result: String::new(),
-            lino_interpretation: String::new(),
-            alternative_lino: None,
-            steps: Vec::new(),
-            steps_i18n: None,
-            success: false,
-            error: Some(error),
-            error_info: None,
-            issue_link: Some(issue_link),
-            latex_input: None,
-            latex_result: None,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-
-    /// Creates a failed calculation result with i18n error info.
-    #[must_use]
-    pub fn failure_with_i18n(error: &CalculatorError, input: &str) -> Self {
-        let error_string = error.to_string();
-        let issue_link = generate_issue_link(input, &error_string);
-        Self {
-            result: String::new(),
-            lino_interpretation: String::new(),
-            alternative_lino: None,
-            steps: Vec::new(),
-            steps_i18n: None,
-            success: false,
-            error: Some(error_string),
-            error_info: Some(error.to_error_info()),
-            issue_link: Some(issue_link),
-            latex_input: None,
-            latex_result: None,
-            is_symbolic: None,
-            plot_data: None,
-            repeating_decimal: None,
-            fraction: None,
-            is_live_time: None,
-            datetime_result: None,
-        }
-    }
-}
-
 /// The main calculator struct.
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[derive(Debug, Default)]
 pub struct Calculator {
     parser: ExpressionParser,
+    format_options: FormatOptions,
+    include_ast: bool,
+    /// The language steps are written in, when not English.
+    language: Option<String>,
+    /// Receives metadata about each calculation, when set.
+    telemetry: telemetry::TelemetryHook,
+    /// Inputs saved under a name with [`Self::save_expression`].
+    bookmarks: types::Bookmarks,
+}
+
+/// The notation the input of a calculation is written in.
+#[derive(Debug, Clone, Copy)]
+enum Notation {
+    /// As typed: `84 USD - 34 EUR`.
+    Text,
+    /// Links notation: `((84 USD) - (34 EUR))`.
+    Lino,
 }
 
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Calculator {
     /// Creates a new Calculator instance.
-    #[wasm_bindgen(constructor)]
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
     #[must_use]
     pub fn new() -> Self {
         // Initialize panic hook for better error messages in WASM
-        #[cfg(target_arch = "wasm32")]
+        #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
         console_error_panic_hook::set_once();
 
         Self {
             parser: ExpressionParser::new(),
+            format_options: FormatOptions::default(),
+            include_ast: false,
+            language: None,
+            telemetry: telemetry::TelemetryHook::default(),
+            bookmarks: types::Bookmarks::new(),
         }
     }
 
@@ -431,7 +148,7 @@ impl Calculator {
     ///
     /// The worker should call this first, fetch the required rate sources,
     /// then call `execute()` to get the actual result.
-    #[wasm_bindgen]
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
     pub fn plan(&self, input: &str) -> String {
         let plan = self.plan_internal(input);
         serde_json::to_string(&plan).unwrap_or_else(|e| {
@@ -447,8 +164,8 @@ impl Calculator {
     /// This is the same as `calculate()` but named to clarify the plan→execute pipeline.
     /// The worker should call `plan()` first to determine required rate sources,
     /// fetch them, then call `execute()`.
-    #[wasm_bindgen]
-    pub fn execute(&mut self, input: &str) -> String {
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn execute(&self, input: &str) -> String {
         let result = self.calculate_internal(input);
         serde_json::to_string(&result).unwrap_or_else(|e| {
             format!(
@@ -460,10 +177,25 @@ impl Calculator {
 
     /// Calculates the result of an expression, returning a JSON string.
     ///
-    /// Kept for backwards compatibility. Equivalent to `execute()`.
-    #[wasm_bindgen]
-    pub fn calculate(&mut self, input: &str) -> String {
-        self.execute(input)
+    /// Without options this is equivalent to `execute()`. `options` is an
+    /// optional JSON object of [`CalculationOptions`], e.g.
+    /// `{"base_unit_steps": true}`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    #[allow(clippy::needless_pass_by_value)] // wasm_bindgen requires owned String
+    pub fn calculate(&self, input: &str, options: Option<String>) -> String {
+        let result = match options.as_deref().map(serde_json::from_str) {
+            None => return self.execute(input),
+            Some(Ok(options)) => self.calculate_with_options(input, &options),
+            Some(Err(e)) => {
+                CalculationResult::failure(format!("Invalid calculation options: {e}"), input)
+            }
+        };
+        serde_json::to_string(&result).unwrap_or_else(|e| {
+            format!(
+                r#"{{"success":false,"error":"Serialization error: {}"}}"#,
+                e
+            )
+        })
     }
 
     /// Sets the user's local timezone offset, in minutes east of UTC.
@@ -475,7 +207,7 @@ impl Calculator {
     /// Once set, bare times such as `12:30` and the `now` keyword are interpreted
     /// in this local timezone instead of UTC. Inputs with an explicit timezone
     /// (e.g. `12:30 UTC`, `now UTC`) are always honored regardless of this setting.
-    #[wasm_bindgen]
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
     pub fn set_timezone_offset(&mut self, offset_minutes: i32) {
         self.parser
             .set_local_offset_seconds(Some(offset_minutes * 60));
@@ -483,13 +215,69 @@ impl Calculator {
 
     /// Clears any previously configured local timezone offset, restoring the
     /// default UTC interpretation for `now` and bare times.
-    #[wasm_bindgen]
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
     pub fn clear_timezone_offset(&mut self) {
         self.parser.set_local_offset_seconds(None);
     }
 
+    /// Stops the clock at an RFC 3339 time such as `2026-03-15T12:00:00Z`,
+    /// so `now`, `today` and rate fetch times read it, e.g. to render on a
+    /// server with the time of the request. Returns false, leaving the clock
+    /// unchanged, for a time that is not RFC 3339.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_fixed_time(&mut self, time: &str) -> bool {
+        let Some(clock) = types::FixedClock::from_rfc3339(time) else {
+            return false;
+        };
+        self.set_clock(clock);
+        true
+    }
+
+    /// Goes back to reading the system clock after [`Self::set_fixed_time`]
+    /// or [`Self::set_clock`].
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn use_system_clock(&mut self) {
+        self.parser.set_clock(None);
+    }
+
+    /// Reads numbers in the convention of a language first, such as `ru`
+    /// for `1 234,56` or `de` for `1.234,56`. Returns false for a language
+    /// without a known number convention.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_number_locale(&mut self, language: &str) -> bool {
+        self.parser.set_number_locale(Some(language))
+    }
+
+    /// Clears the number locale, restoring auto-detection of decimal commas
+    /// and grouping separators.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_number_locale(&mut self) {
+        self.parser.set_number_locale(None);
+    }
+
+    /// Sets the language the `steps` strings are written in, such as `ru`
+    /// or `de-DE`. Returns false, and keeps the current language, for a
+    /// language without step translations.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_language(&mut self, language: &str) -> bool {
+        if !i18n::is_supported_language(language) {
+            return false;
+        }
+        let code = i18n::language_code(language);
+        self.language = (code != "en").then_some(code);
+        true
+    }
+
+    /// Includes the parsed expression tree as the `ast` field of results.
+    ///
+    /// Off by default, since most clients only need the links notation.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_include_ast(&mut self, include_ast: bool) {
+        self.include_ast = include_ast;
+    }
+
     /// Returns the version of the calculator.
-    #[wasm_bindgen]
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
     #[must_use]
     pub fn version() -> String {
         VERSION.to_string()
@@ -497,15 +285,15 @@ impl Calculator {
 
     /// Updates exchange rates from API response. Returns the number of rates updated.
     /// Args: `base` (e.g., "USD"), `date` (e.g., "2026-01-25"), `rates_json` (e.g., `{"eur": 0.92}`).
-    #[wasm_bindgen]
-    pub fn update_rates_from_api(&mut self, base: &str, date: &str, rates_json: &str) -> usize {
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn update_rates_from_api(&self, base: &str, date: &str, rates_json: &str) -> usize {
         let rates: std::collections::HashMap<String, f64> = match serde_json::from_str(rates_json) {
             Ok(r) => r,
             Err(_) => return 0,
         };
 
         let base_upper = base.to_uppercase();
-        let timestamp = chrono::Utc::now().to_rfc3339();
+        let timestamp = self.parser.clock_now().to_rfc3339();
         let mut count = 0;
 
         for (target, rate) in rates {
@@ -518,7 +306,7 @@ impl Calculator {
             let info = types::ExchangeRateInfo::new(rate, currency_api::API_SOURCE, date)
                 .with_fetched_at(&timestamp);
 
-            let currency_db = self.parser.currency_db_mut();
+            let currency_db = self.parser.currency_db();
             currency_db.set_rate_with_info(&base_upper, &target_upper, info.clone());
             currency_db.set_historical_rate_with_info(&base_upper, &target_upper, date, info);
 
@@ -538,14 +326,14 @@ impl Calculator {
     /// since CBR provides official RUB rates directly (no cross-rate needed).
     ///
     /// Args: `date` (e.g., "2026-02-25"), `rates_json` (currency_code → RUB amount).
-    #[wasm_bindgen]
-    pub fn update_cbr_rates_from_api(&mut self, date: &str, rates_json: &str) -> usize {
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn update_cbr_rates_from_api(&self, date: &str, rates_json: &str) -> usize {
         let rates: std::collections::HashMap<String, f64> = match serde_json::from_str(rates_json) {
             Ok(r) => r,
             Err(_) => return 0,
         };
 
-        let timestamp = chrono::Utc::now().to_rfc3339();
+        let timestamp = self.parser.clock_now().to_rfc3339();
         let mut count = 0;
 
         for (currency, rub_per_unit) in rates {
@@ -561,7 +349,7 @@ impl Calculator {
                 types::ExchangeRateInfo::new(rub_per_unit, currency_api::CBR_API_SOURCE, date)
                     .with_fetched_at(&timestamp);
 
-            let currency_db = self.parser.currency_db_mut();
+            let currency_db = self.parser.currency_db();
             currency_db.set_rate_with_info(&currency_upper, "RUB", info.clone());
             currency_db.set_historical_rate_with_info(&currency_upper, "RUB", date, info);
 
@@ -576,20 +364,15 @@ impl Calculator {
     ///
     /// Args: `base` (fiat currency, e.g., "USD"), `date` (e.g., "2026-01-25"),
     /// `rates_json` (e.g., `{"TON": 5.42, "BTC": 95000.0}`).
-    #[wasm_bindgen]
-    pub fn update_crypto_rates_from_api(
-        &mut self,
-        base: &str,
-        date: &str,
-        rates_json: &str,
-    ) -> usize {
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn update_crypto_rates_from_api(&self, base: &str, date: &str, rates_json: &str) -> usize {
         let rates: std::collections::HashMap<String, f64> = match serde_json::from_str(rates_json) {
             Ok(r) => r,
             Err(_) => return 0,
         };
 
         let base_upper = base.to_uppercase();
-        let timestamp = chrono::Utc::now().to_rfc3339();
+        let timestamp = self.parser.clock_now().to_rfc3339();
         let mut count = 0;
 
         for (ticker, price) in rates {
@@ -598,7 +381,7 @@ impl Calculator {
             let info = types::ExchangeRateInfo::new(price, crypto_api::COINGECKO_SOURCE, date)
                 .with_fetched_at(&timestamp);
 
-            let currency_db = self.parser.currency_db_mut();
+            let currency_db = self.parser.currency_db();
             currency_db.set_rate_with_info(&ticker_upper, &base_upper, info.clone());
             currency_db.set_historical_rate_with_info(&ticker_upper, &base_upper, date, info);
 
@@ -613,14 +396,91 @@ impl Calculator {
     ///
     /// Supports both the new format (conversion/rates) and legacy format (rates/data).
     /// Used by the web worker to populate historical CBR rate data from local .lino files.
-    #[wasm_bindgen]
-    pub fn load_rates_from_consolidated_lino(&mut self, content: &str) -> usize {
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn load_rates_from_consolidated_lino(&self, content: &str) -> usize {
         self.load_rates_from_consolidated_lino_impl(content)
             .unwrap_or_default()
     }
+
+    /// Sets the unit the trigonometric functions read plain numbers in, and
+    /// the inverse ones answer in: `radians` (the default), `degrees` or
+    /// `gradians`. Angles written with a unit, as in `sin(30°)`, keep it.
+    /// Returns false, leaving the mode unchanged, for an unknown name.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_angle_mode(&mut self, mode: &str) -> bool {
+        let Some(mode) =
+            types::AngleUnit::parse(mode).filter(|mode| types::AngleUnit::MODES.contains(mode))
+        else {
+            return false;
+        };
+        self.parser.set_angle_mode(mode);
+        true
+    }
+
+    /// Sets how the results of arithmetic on angles are brought within a
+    /// turn: `none` (the default), `360` for 0 up to 360° or `180` for
+    /// -180° to 180°. Returns false, leaving the wrapping unchanged, for an
+    /// unknown name.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_angle_wrap(&mut self, wrap: &str) -> bool {
+        let Some(wrap) = types::AngleWrap::parse(wrap) else {
+            return false;
+        };
+        self.parser.set_angle_wrap(wrap);
+        true
+    }
+
+    /// Sets how historical rates are chosen for dates between known rates:
+    /// `previous` (the default), `nearest` or `interpolate`.
+    /// Returns false, leaving the mode unchanged, for an unknown name.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_historical_rate_mode(&mut self, mode: &str) -> bool {
+        let Some(mode) = types::HistoricalRateMode::parse(mode) else {
+            return false;
+        };
+        self.parser.currency_db_mut().set_historical_rate_mode(mode);
+        true
+    }
+
+    /// Sets how many days a historical rate may be from the requested date
+    /// before the result carries a warning.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_stale_rate_days(&mut self, days: u32) {
+        self.parser.currency_db_mut().set_stale_rate_days(days);
+    }
+
+    /// Gives money results in `currency`, such as `EUR` or `euros`, so
+    /// `84 USD - 34 EUR` is answered in euros. An expression that ends in
+    /// its own conversion (`... in USD`) keeps it. Returns false, leaving
+    /// the setting unchanged, for an unknown currency.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_output_currency(&mut self, currency: &str) -> bool {
+        let Some(code) = types::CurrencyDatabase::parse_currency(currency) else {
+            return false;
+        };
+        if !self.parser.currency_db().is_known_currency(&code) {
+            return false;
+        }
+        self.parser
+            .set_output_currency(Some(types::Unit::currency(&code)));
+        true
+    }
+
+    /// Clears the output currency, so mixed-currency results are given in
+    /// the currency of their left operand again.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_output_currency(&mut self) {
+        self.parser.set_output_currency(None);
+    }
 }
 
 impl Calculator {
+    /// Reads the current time from `clock` instead of the system clock,
+    /// e.g. a [`types::FixedClock`] for deterministic tests.
+    pub fn set_clock(&mut self, clock: impl types::Clock + 'static) {
+        self.parser.set_clock(Some(Box::new(clock)));
+    }
+
     /// Internal planning method — parses expression and determines requirements.
     pub fn plan_internal(&self, input: &str) -> CalculationPlan {
         let input = input.trim();
@@ -643,10 +503,40 @@ impl Calculator {
     }
 
     /// Internal calculation method that returns a proper Result type.
-    pub fn calculate_internal(&mut self, input: &str) -> CalculationResult {
+    ///
+    /// Takes `&self`, so one calculator can calculate on several threads at
+    /// once; the history they add to is shared.
+    pub fn calculate_internal(&self, input: &str) -> CalculationResult {
+        self.working_copy().calculate_here(input, Notation::Text)
+    }
+
+    /// Returns a calculator for one calculation, with the settings of this
+    /// one and the same history, caches, rates and hooks.
+    fn working_copy(&self) -> Self {
+        Self {
+            parser: self.parser.working_copy(),
+            format_options: self.format_options.clone(),
+            include_ast: self.include_ast,
+            language: self.language.clone(),
+            telemetry: self.telemetry.clone(),
+            // Calculations do not read bookmarks
+            bookmarks: types::Bookmarks::new(),
+        }
+    }
+
+    /// Calculates `input`, written in `notation`, on this calculator's own
+    /// state.
+    fn calculate_here(&mut self, input: &str, notation: Notation) -> CalculationResult {
+        let started = self.telemetry_start();
+        self.parser
+            .set_datetime_format(self.format_options.datetime_format.clone());
         // Try to parse the expression to generate alternative interpretations
         // and detect live time expressions before evaluation.
-        let parsed_interpretations = self.parser.parse_interpretations(input).ok();
+        let parsed_interpretations = match notation {
+            Notation::Text => self.parser.parse_interpretations(input),
+            Notation::Lino => self.parser.parse_lino(input).map(|expr| vec![expr]),
+        }
+        .ok();
         let alternatives = parsed_interpretations
             .as_ref()
             .and_then(|interpretations| Self::combined_alternative_lino(interpretations));
@@ -655,39 +545,122 @@ impl Calculator {
             .and_then(|interpretations| interpretations.first())
             .is_some_and(Expression::contains_live_time);
 
-        let mut result = match self.parser.parse_and_evaluate(input) {
-            Ok((value, steps, lino)) => {
-                let mut r = CalculationResult::success_with_value(&value, lino, steps);
-                // Set is_live_time for any datetime result so the frontend
-                // auto-refreshes the "Time since/until" countdown display.
-                let value_is_datetime = matches!(&value.kind, ValueKind::DateTime(_));
-                if is_live_time || value_is_datetime {
-                    r.is_live_time = Some(true);
+        let plot_result = parsed_interpretations
+            .as_ref()
+            .and_then(|interpretations| interpretations.first())
+            .and_then(|expr| self.plot_command(input, expr));
+
+        let mut result = if let Some(plot_result) = plot_result {
+            plot_result
+        } else {
+            let evaluated = match notation {
+                Notation::Text => self.parser.parse_and_evaluate(input),
+                Notation::Lino => self.parser.parse_lino_and_evaluate(input),
+            };
+            match evaluated {
+                Ok((value, steps, lino)) => {
+                    let mut r = CalculationResult::success_with_value(&value, lino, steps);
+                    let options = self.display_options(
+                        parsed_interpretations
+                            .as_ref()
+                            .and_then(|interpretations| interpretations.first()),
+                    );
+                    r.result = self.display_value(&value, &options);
+                    let raw_result = self.display_value(
+                        &value,
+                        &FormatOptions {
+                            round_money: false,
+                            ..options.clone()
+                        },
+                    );
+                    if raw_result != r.result {
+                        r.raw_result = Some(raw_result);
+                    }
+                    r.warnings = self.parser.warnings().to_vec();
+                    r.rates_used = self.parser.rates_used().to_vec();
+                    r.phase_timings = self.parser.phase_timings();
+                    r.breakdown = self.breakdown_items(&options);
+                    let structured_steps = self.parser.structured_steps();
+                    if !structured_steps.is_empty() {
+                        r.structured_steps = Some(structured_steps.to_vec());
+                    }
+                    self.parser
+                        .record_history(input.trim(), r.result.clone(), value.clone());
+                    // Set is_live_time for any datetime result so the frontend
+                    // auto-refreshes the "Time since/until" countdown display.
+                    let value_is_datetime = matches!(&value.kind, ValueKind::DateTime(_));
+                    if is_live_time || value_is_datetime {
+                        r.is_live_time = Some(true);
+                    }
+                    if let Some(expr) = parsed_interpretations
+                        .as_ref()
+                        .and_then(|interpretations| interpretations.first())
+                    {
+                        r.plot_data = self
+                            .generate_plot_data_for_definite_integral(expr)
+                            .or_else(|| self.generate_plot_data_for_rate_history(expr));
+                        self.attach_amortization_schedule(expr, &mut r);
+                        if let Some(latex) = value.matrix_to_latex() {
+                            r.latex_input = Some(expr.to_latex());
+                            r.latex_result = Some(latex);
+                        }
+                        if self.include_ast {
+                            r.ast = Some(expr.clone());
+                        }
+                    }
+                    r
                 }
-                r
-            }
-            Err(CalculatorError::SymbolicResult {
-                expression,
-                result,
-                latex_input,
-                latex_result,
-            }) => {
-                // Generate plot data for the integrand function
-                let plot_data = self.generate_plot_data_for_integral(input);
-                CalculationResult::symbolic(
-                    &expression,
+                Err(CalculatorError::SymbolicResult {
+                    expression,
                     result,
                     latex_input,
                     latex_result,
-                    plot_data,
-                )
+                }) => {
+                    // Plot the integrand, and the antiderivative beside it
+                    let plots = self.generate_plots_for_integral(input);
+                    let mut r = CalculationResult::symbolic(
+                        &expression,
+                        result,
+                        latex_input,
+                        latex_result,
+                        plots.first().cloned(),
+                    );
+                    if !plots.is_empty() {
+                        r.plots = Some(plots);
+                    }
+                    r
+                }
+                Err(e) => {
+                    let result = CalculationResult::failure_with_i18n(&e, input);
+                    match parsed_interpretations
+                        .as_ref()
+                        .and_then(|interpretations| interpretations.first())
+                        .and_then(|expr| self.parser.evaluate_partially(expr))
+                    {
+                        Some(partial) => result.with_partial_evaluation(partial),
+                        None => result,
+                    }
+                }
             }
-            Err(e) => CalculationResult::failure_with_i18n(&e, input),
         };
 
         // Attach alternative interpretations if available
         result.alternative_lino = alternatives;
 
+        if let Some(language) = &self.language {
+            for step in &mut result.steps {
+                *step = i18n::translate_step(step, language);
+            }
+        }
+
+        if let Some(started) = started {
+            let kind = parsed_interpretations
+                .as_ref()
+                .and_then(|interpretations| interpretations.first())
+                .map(Expression::kind);
+            self.record_telemetry(input, started, kind, &result);
+        }
+
         result
     }
 
@@ -714,85 +687,40 @@ impl Calculator {
         }
     }
 
-    /// Generates plot data for an integral expression.
-    fn generate_plot_data_for_integral(&mut self, input: &str) -> Option<PlotData> {
-        // Try to parse and extract the integrand for plotting
-        let expr = self.parser.parse(input).ok()?;
-
-        if let types::Expression::IndefiniteIntegral {
-            integrand,
-            variable,
-        } = expr
-        {
-            // Generate plot points for the integrand
-            let mut x_values = Vec::new();
-            let mut y_values = Vec::new();
-
-            // Generate points from -10 to 10 with 200 steps
-            let num_points: i32 = 200;
-            let x_min = -10.0;
-            let x_max = 10.0;
-            let step = (x_max - x_min) / f64::from(num_points);
-
-            for i in 0..=num_points {
-                let x = f64::from(i).mul_add(step, x_min);
-
-                // Skip x = 0 for functions like sin(x)/x to avoid division issues
-                if x.abs() < 1e-10 {
-                    // For sin(x)/x, the limit at x=0 is 1
-                    x_values.push(x);
-                    y_values.push(1.0);
-                    continue;
-                }
-
-                // Try to evaluate the integrand at this point
-                if let Ok(y_val) = self.evaluate_at_point(&integrand, &variable, x) {
-                    if y_val.is_finite() {
-                        x_values.push(x);
-                        y_values.push(y_val);
-                    }
-                }
-            }
-
-            if !x_values.is_empty() {
-                return Some(PlotData {
-                    x_values,
-                    y_values,
-                    label: format!("{}", integrand),
-                    x_label: variable.clone(),
-                    y_label: format!("f({})", variable),
-                });
-            }
-        }
-
-        None
-    }
-
-    /// Evaluates an expression at a specific point.
-    fn evaluate_at_point(
-        &mut self,
-        expr: &types::Expression,
-        var: &str,
-        value: f64,
-    ) -> Result<f64, CalculatorError> {
-        let substituted = Self::substitute_variable(expr, var, value);
-        let result = self.parser.evaluate(&substituted)?;
-        result
-            .as_decimal()
-            .map(|d| d.to_f64())
-            .ok_or_else(|| CalculatorError::eval("Expected numeric result"))
-    }
-
     /// Parses an expression without evaluating it.
     pub fn parse(&self, input: &str) -> Result<types::Expression, CalculatorError> {
         self.parser.parse(input)
     }
 
     /// Evaluates a parsed expression.
-    pub fn evaluate(&mut self, expr: &types::Expression) -> Result<Value, CalculatorError> {
+    pub fn evaluate(&self, expr: &types::Expression) -> Result<Value, CalculatorError> {
         self.parser.evaluate(expr)
     }
 
+    /// Evaluates a parsed expression with its variables bound to values, so
+    /// that an expression parsed once can be evaluated for many values:
+    ///
+    /// ```
+    /// use link_calculator::types::Value;
+    /// use link_calculator::Calculator;
+    ///
+    /// let calculator = Calculator::new();
+    /// let expr = calculator.parse("x^2 + 3*x").unwrap();
+    /// for (x, expected) in [(1, "4"), (2, "10")] {
+    ///     let value = calculator
+    ///         .evaluate_with_bindings(&expr, &[("x", Value::from_integer(x))])
+    ///         .unwrap();
+    ///     assert_eq!(value.to_display_string(), expected);
+    /// }
+    /// ```
+    pub fn evaluate_with_bindings(
+        &self,
+        expr: &types::Expression,
+        bindings: &[(&str, Value)],
+    ) -> Result<Value, CalculatorError> {
+        self.parser.evaluate_with_bindings(expr, bindings)
+    }
+
     /// Returns a borrow of the underlying [`grammar::ExpressionParser`].
     ///
     /// Lets downstream consumers reach the parser's lower-level methods —
@@ -811,6 +739,27 @@ impl Calculator {
         &mut self.parser
     }
 
+    /// Creates a calculator that converts currencies with `currency_db`,
+    /// such as one made by [`types::CurrencyDatabase::shared`].
+    #[must_use]
+    pub fn with_currency_db(currency_db: types::CurrencyDatabase) -> Self {
+        let mut calculator = Self::new();
+        *calculator.parser.currency_db_mut() = currency_db;
+        calculator
+    }
+
+    /// Creates a calculator that shares this one's currencies and exchange
+    /// rates: rates loaded into either are used by both. Everything else,
+    /// such as history and options, starts afresh.
+    ///
+    /// To share rates across threads, send the database from
+    /// [`types::CurrencyDatabase::shared`] and build the calculator there
+    /// with [`Self::with_currency_db`].
+    #[must_use]
+    pub fn share_rates(&self) -> Self {
+        Self::with_currency_db(self.parser.currency_db().shared())
+    }
+
     /// Calculates an expression and returns every intermediate artefact.
     ///
     /// Unlike [`Self::calculate_internal`], which returns a UI-shaped
@@ -827,19 +776,28 @@ impl Calculator {
     /// returned tuple, so consumers (e.g. `formal-ai`) can re-encode the
     /// result as Links Notation, LaTeX, JSON, or any other format.
     pub fn calculate_with_value(
-        &mut self,
+        &self,
         input: &str,
     ) -> Result<(types::Expression, Value, Vec<String>, String), CalculatorError> {
         let input = input.trim();
         if input.is_empty() {
             return Err(CalculatorError::EmptyInput);
         }
-        let expr = self.parser.parse(input)?;
+        let mut calculator = self.working_copy();
+        let expr = calculator.parser.parse(input)?;
         let lino = expr.to_lino();
-        let (value, steps) = self.parser.evaluate_with_steps(&expr)?;
+        let (value, steps) = calculator.parser.evaluate_with_steps(&expr)?;
         Ok((expr, value, steps, lino))
     }
 
+    /// Calculates an expression given in links notation, such as the
+    /// `lino_interpretation` of an earlier result: `((84 USD) - (34 EUR))`.
+    /// The result is displayed and added to the history as
+    /// [`Self::calculate_internal`] would.
+    pub fn calculate_lino(&self, lino: &str) -> CalculationResult {
+        self.working_copy().calculate_here(lino, Notation::Lino)
+    }
+
     /// Loads a historical exchange rate from .lino format content.
     ///
     /// The .lino format for rates:
@@ -851,7 +809,7 @@ impl Calculator {
     ///   date 2026-01-25
     ///   source 'frankfurter.dev (ECB)'
     /// ```
-    pub fn load_rate_from_lino(&mut self, content: &str) -> Result<(), String> {
+    pub fn load_rate_from_lino(&self, content: &str) -> Result<(), String> {
         let mut from_currency: Option<String> = None;
         let mut to_currency: Option<String> = None;
         let mut value: Option<f64> = None;
@@ -891,7 +849,7 @@ impl Calculator {
         let rate_info = types::ExchangeRateInfo::new(rate_value, rate_source, rate_date.clone());
 
         self.parser
-            .currency_db_mut()
+            .currency_db()
             .set_historical_rate_with_info(&from, &to, &rate_date, rate_info);
 
         Ok(())
@@ -899,7 +857,7 @@ impl Calculator {
 
     /// Loads multiple historical exchange rates from a batch of .lino content.
     /// Each rate should be separated by double newlines or start with "rate:".
-    pub fn load_rates_batch(&mut self, contents: &[&str]) -> Result<usize, String> {
+    pub fn load_rates_batch(&self, contents: &[&str]) -> Result<usize, String> {
         let mut loaded = 0;
         for content in contents {
             if self.load_rate_from_lino(content).is_ok() {
@@ -910,10 +868,7 @@ impl Calculator {
         Ok(loaded)
     }
 
-    pub fn load_rates_from_consolidated_lino_impl(
-        &mut self,
-        content: &str,
-    ) -> Result<usize, String> {
+    pub fn load_rates_from_consolidated_lino_impl(&self, content: &str) -> Result<usize, String> {
         let mut from_currency: Option<String> = None;
         let mut to_currency: Option<String> = None;
         let mut source: Option<String> = None;
@@ -963,7 +918,7 @@ impl Calculator {
                             let rate_info =
                                 types::ExchangeRateInfo::new(value, rate_source, date.to_string());
                             self.parser
-                                .currency_db_mut()
+                                .currency_db()
                                 .set_historical_rate_with_info(from, to, date, rate_info);
                             loaded += 1;
                         }
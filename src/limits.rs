@@ -0,0 +1,87 @@
+//! Evaluation limits on the [`Calculator`].
+
+use crate::types::EvalConfig;
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Sets evaluation limits from a JSON object, e.g.
+    /// `{"max_depth": 100, "operation_budget": 100000}`.
+    ///
+    /// Missing fields keep their defaults. Returns `false` (leaving the current
+    /// limits untouched) if the JSON cannot be parsed.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_eval_config(&mut self, config_json: &str) -> bool {
+        match serde_json::from_str::<EvalConfig>(config_json) {
+            Ok(config) => {
+                self.parser.set_eval_config(config);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Sets the maximum nesting depth of an expression.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.parser.eval_config_mut().max_depth = max_depth;
+    }
+
+    /// Sets the maximum number of subintervals used for numerical integration.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_max_integration_subdivisions(&mut self, subdivisions: usize) {
+        self.parser.eval_config_mut().max_integration_subdivisions = subdivisions;
+    }
+
+    /// Sets the error numerical integration aims for.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_integration_tolerance(&mut self, tolerance: f64) {
+        self.parser.eval_config_mut().integration_tolerance = tolerance;
+    }
+
+    /// Sets how close `root(...)` gets to the root before it stops.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_root_tolerance(&mut self, tolerance: f64) {
+        self.parser.eval_config_mut().root_tolerance = tolerance;
+    }
+
+    /// Sets the maximum number of steps `root(...)` takes to find a root.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_max_root_iterations(&mut self, iterations: usize) {
+        self.parser.eval_config_mut().max_root_iterations = iterations;
+    }
+
+    /// Sets the largest `n` accepted by `factorial(n)` and `ncr(n, k)`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_max_factorial_input(&mut self, max_input: u32) {
+        self.parser.eval_config_mut().max_factorial_input = u64::from(max_input);
+    }
+
+    /// Sets how many results the history keeps.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_max_history_entries(&mut self, max_entries: usize) {
+        self.parser.eval_config_mut().max_history_entries = max_entries;
+    }
+
+    /// Limits the evaluation steps per calculation; `None` removes the limit.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_operation_budget(&mut self, budget: Option<u32>) {
+        self.parser.eval_config_mut().operation_budget = budget.map(u64::from);
+    }
+
+    /// Restores the default evaluation limits.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_eval_config(&mut self) {
+        self.parser.set_eval_config(EvalConfig::default());
+    }
+}
+
+impl Calculator {
+    /// Returns the limits enforced while parsing and evaluating.
+    #[must_use]
+    pub fn eval_config(&self) -> &EvalConfig {
+        self.parser.eval_config()
+    }
+}
@@ -0,0 +1,106 @@
+//! Doublets: the lowest-level form of links notation, where every link has
+//! exactly two references, a source and a target.
+//!
+//! A link with more references is folded from the left, so `(2 + 3)` becomes
+//! `(l1: 2 +)` and `(l2: l1 3)`. Identical links share one id, and ids are
+//! numbered in the order they are first built, so the same expression
+//! always produces the same doublets. Nested links come before the links
+//! that use them, so the root link comes last.
+
+use std::collections::HashMap;
+
+use super::{Link, LinkRef, LinoParser};
+
+/// Decomposes links notation into doublet links, one per line, with the
+/// root link last. Returns `None` if the input cannot be parsed or is a
+/// single reference that needs no links.
+#[must_use]
+pub fn lino_to_doublets(lino: &str) -> Option<String> {
+    let links = LinoParser::new().parse(lino).ok()?;
+    let doublets = to_doublets(&links);
+    (!doublets.is_empty()).then(|| {
+        doublets
+            .iter()
+            .map(Link::to_lino)
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+/// Decomposes links into doublet links with auto-generated ids (`l1`, `l2`, …).
+///
+/// Ids already present on the input links are not kept.
+#[must_use]
+pub fn to_doublets(links: &[Link]) -> Vec<Link> {
+    let mut builder = DoubletBuilder::default();
+    for link in links {
+        builder.reference(&link.refs);
+    }
+    builder.doublets
+}
+
+#[derive(Default)]
+struct DoubletBuilder {
+    doublets: Vec<Link>,
+    ids: HashMap<(String, String), String>,
+}
+
+impl DoubletBuilder {
+    /// Returns the reference standing for a sequence of link references.
+    fn reference(&mut self, refs: &[LinkRef]) -> String {
+        let mut items = refs.iter().map(|link_ref| match link_ref {
+            LinkRef::Literal(text) | LinkRef::Ref(text) => text.clone(),
+            LinkRef::Nested(link) => self.reference(&link.refs),
+        });
+        let Some(first) = items.next() else {
+            return "()".to_string();
+        };
+        let rest: Vec<_> = items.collect();
+        rest.into_iter()
+            .fold(first, |source, target| self.doublet(source, target))
+    }
+
+    fn doublet(&mut self, source: String, target: String) -> String {
+        let key = (source, target);
+        if let Some(id) = self.ids.get(&key) {
+            return id.clone();
+        }
+        let id = format!("l{}", self.doublets.len() + 1);
+        self.doublets.push(Link::with_id(
+            id.clone(),
+            vec![
+                LinkRef::Literal(key.0.clone()),
+                LinkRef::Literal(key.1.clone()),
+            ],
+        ));
+        self.ids.insert(key, id.clone());
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_expression() {
+        assert_eq!(
+            lino_to_doublets("((84 USD) - (34 EUR))").unwrap(),
+            "(l1: 84 USD)\n(l2: 34 EUR)\n(l3: l1 -)\n(l4: l3 l2)"
+        );
+    }
+
+    #[test]
+    fn test_identical_links_share_an_id() {
+        assert_eq!(
+            lino_to_doublets("((2 + 3) * (2 + 3))").unwrap(),
+            "(l1: 2 +)\n(l2: l1 3)\n(l3: l2 *)\n(l4: l3 l2)"
+        );
+    }
+
+    #[test]
+    fn test_single_reference_has_no_doublets() {
+        assert_eq!(lino_to_doublets("42"), None);
+        assert_eq!(lino_to_doublets("(now)"), None);
+    }
+}
@@ -16,6 +16,10 @@
 //! (84 USD - 34 EUR) at (22 Jan 2026)  -> ((((84 USD) - (34 EUR)) at (22 Jan 2026)))
 //! ```
 
+mod doublets;
+
+pub use doublets::{lino_to_doublets, to_doublets};
+
 use crate::types::Expression;
 
 /// Formats an expression into links notation.
@@ -24,6 +28,35 @@ pub fn to_lino(expr: &Expression) -> String {
     expr.to_lino()
 }
 
+/// Quotes text as one links notation token, escaping `"` and `\` with a
+/// backslash.
+#[must_use]
+pub fn quote(text: &str) -> String {
+    let mut token = String::with_capacity(text.len() + 2);
+    token.push('"');
+    for ch in text.chars() {
+        if matches!(ch, '"' | '\\') {
+            token.push('\\');
+        }
+        token.push(ch);
+    }
+    token.push('"');
+    token
+}
+
+/// The text of a `"quoted"` token, with `\"` and `\\` unescaped; `None` if
+/// the token is not quoted.
+#[must_use]
+pub fn unquote(token: &str) -> Option<String> {
+    let inner = token.strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        text.push(if ch == '\\' { chars.next()? } else { ch });
+    }
+    Some(text)
+}
+
 /// Represents a link in links notation.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Link {
@@ -239,9 +272,35 @@ impl LinoParser {
             pos += 1;
         }
 
+        // Quoted text such as `"the quick brown fox"` is one token, spaces
+        // and parentheses included
+        if chars.get(pos) == Some(&'"') {
+            token.push('"');
+            pos += 1;
+            while pos < chars.len() {
+                let ch = chars[pos];
+                token.push(ch);
+                pos += 1;
+                if ch == '\\' {
+                    if let Some(&escaped) = chars.get(pos) {
+                        token.push(escaped);
+                        pos += 1;
+                    }
+                } else if ch == '"' {
+                    return Ok((token, pos));
+                }
+            }
+            return Err(format!("Unterminated quoted token at position {pos}"));
+        }
+
         while pos < chars.len() {
             let ch = chars[pos];
-            if ch.is_whitespace() || ch == '(' || ch == ')' || ch == ':' {
+            // A colon between digits belongs to a time such as `12:30:00`
+            let is_time_colon = ch == ':'
+                && pos > 0
+                && chars[pos - 1].is_ascii_digit()
+                && chars.get(pos + 1).is_some_and(char::is_ascii_digit);
+            if ch.is_whitespace() || ch == '(' || ch == ')' || (ch == ':' && !is_time_colon) {
                 break;
             }
             token.push(ch);
@@ -299,6 +358,27 @@ mod tests {
         assert_eq!(links[0].refs.len(), 3);
     }
 
+    #[test]
+    fn test_parse_time_colons() {
+        let parser = LinoParser::new();
+        let links = parser.parse("(2026-01-22 12:30:00 UTC)").unwrap();
+        assert_eq!(links[0].refs.len(), 3);
+        assert_eq!(links[0].refs[1], LinkRef::Literal("12:30:00".to_string()));
+
+        let links = parser.parse("(sum: 2 + 3)").unwrap();
+        assert_eq!(links[0].id.as_deref(), Some("sum"));
+    }
+
+    #[test]
+    fn test_parse_quoted() {
+        let parser = LinoParser::new();
+        let links = parser.parse("(words \"the (quick) fox\")").unwrap();
+        assert_eq!(
+            links[0].refs[1],
+            LinkRef::Literal("\"the (quick) fox\"".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_nested() {
         let parser = LinoParser::new();
@@ -0,0 +1,107 @@
+//! Locales on the [`Calculator`]: one setting for how input is read and
+//! results are written in a UI language, so a language switcher changes
+//! `1 234,56`, `01/02/2026` and `¥` along with the UI strings.
+
+use serde::Serialize;
+
+use crate::grammar::InputLocale;
+use crate::i18n;
+use crate::types::FormatOptions;
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// The UI languages, with their English and native names.
+const LOCALES: &[(&str, &str, &str)] = &[
+    ("en", "English", "English"),
+    ("ru", "Russian", "Русский"),
+    ("zh", "Chinese", "中文"),
+    ("hi", "Hindi", "हिन्दी"),
+    ("ar", "Arabic", "العربية"),
+    ("de", "German", "Deutsch"),
+    ("fr", "French", "Français"),
+];
+
+/// A locale [`Calculator::set_locale`] accepts, and what it changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocaleInfo {
+    /// The language code: `en`, `ru`, `zh`, `hi`, `ar`, `de` or `fr`.
+    pub code: &'static str,
+    /// The name of the language in English.
+    pub name: &'static str,
+    /// The name of the language in itself.
+    pub native_name: &'static str,
+    /// The decimal separator numbers are read and written with.
+    pub decimal_separator: char,
+    /// The separator between groups of three digits.
+    pub group_separator: Option<char>,
+    /// Whether `01/02/2026` is the 1st of February rather than January 2.
+    pub day_first_dates: bool,
+    /// The currency `¥` stands for: `CNY` or `JPY`.
+    pub yen_sign: &'static str,
+}
+
+impl LocaleInfo {
+    fn new((code, name, native_name): (&'static str, &'static str, &'static str)) -> Self {
+        let format = FormatOptions::for_locale(code);
+        let input = InputLocale::for_language(code);
+        Self {
+            code,
+            name,
+            native_name,
+            decimal_separator: format.decimal_separator,
+            group_separator: format.group_separator,
+            day_first_dates: input.day_first_dates(),
+            yen_sign: if input.yuan_sign() { "CNY" } else { "JPY" },
+        }
+    }
+}
+
+/// Returns the locales [`Calculator::set_locale`] accepts.
+#[must_use]
+pub fn supported_locales() -> Vec<LocaleInfo> {
+    LOCALES.iter().copied().map(LocaleInfo::new).collect()
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Reads and writes numbers, dates and currency signs the way a UI
+    /// language such as `ru`, `de-DE` or `en-GB` does: `1 234,56` is read
+    /// and written as in Russian, `01/02/2026` is the 1st of February
+    /// outside the US, and `¥` is the yuan in Chinese. Rounding preferences
+    /// are kept, and steps stay in the language set with
+    /// [`Self::set_language`]. Returns false, changing nothing, for a
+    /// language not in [`Self::get_supported_locales`].
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_locale(&mut self, locale: &str) -> bool {
+        let code = i18n::language_code(locale);
+        if !LOCALES.iter().any(|(known, _, _)| *known == code) {
+            return false;
+        }
+        self.parser.set_number_locale(Some(locale));
+        self.parser.set_input_locale(Some(locale));
+        let format = FormatOptions::for_locale(locale);
+        self.format_options.group_separator = format.group_separator;
+        self.format_options.decimal_separator = format.decimal_separator;
+        true
+    }
+
+    /// Goes back to reading and writing numbers, dates and currency signs
+    /// the canonical way after [`Self::set_locale`].
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_locale(&mut self) {
+        self.parser.set_number_locale(None);
+        self.parser.set_input_locale(None);
+        let format = FormatOptions::default();
+        self.format_options.group_separator = format.group_separator;
+        self.format_options.decimal_separator = format.decimal_separator;
+    }
+
+    /// Returns a JSON array of the [`LocaleInfo`] of every locale
+    /// [`Self::set_locale`] accepts.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    #[must_use]
+    pub fn get_supported_locales() -> String {
+        serde_json::to_string(&supported_locales()).unwrap_or_else(|_| "[]".to_string())
+    }
+}
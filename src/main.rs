@@ -1,65 +1,558 @@
 //! Link Calculator CLI - A command-line interface for the calculator.
 
-use link_calculator::Calculator;
-use std::io::{self, BufRead, Write};
+use link_calculator::types::CancellationToken;
+use link_calculator::{CalculationResult, Calculator};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
-fn main() {
-    println!("Link Calculator v{}", link_calculator::VERSION);
-    println!("Type expressions to calculate, or 'quit' to exit.\n");
+const USAGE: &str = "Usage: link-calculator [OPTIONS] [EXPRESSION]...
 
-    let mut calculator = Calculator::new();
-    let stdin = io::stdin();
-    let mut stdout = io::stdout();
+Evaluates each EXPRESSION and exits. Without expressions, reads one
+expression per line from standard input when it is piped, or starts the
+interactive calculator when it is a terminal.
 
-    loop {
-        print!("> ");
-        stdout.flush().expect("Failed to flush stdout");
+Options:
+  --json        Print each result as a JSON object on its own line
+  --steps       Show calculation steps (default in interactive mode)
+  --no-steps    Hide calculation steps (default otherwise)
+  -i, --interactive
+                Start the interactive calculator even if input is piped
+  --rates-file <PATH>
+                Load exchange rates from a consolidated .lino rate file
+  --rates-dir <PATH>
+                Load exchange rates from every .lino file in a directory,
+                such as data/currency, for offline historical conversions
+  -h, --help    Show this help
+  -V, --version Show the version
 
-        let mut line = String::new();
-        if stdin.lock().read_line(&mut line).is_err() {
-            break;
+Exit codes: 0 if every expression succeeded, 1 if any failed, 2 for
+invalid options or unreadable rate files, 130 if interrupted.";
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default)]
+struct Output {
+    json: bool,
+    /// Whether to start the interactive calculator however input arrives.
+    interactive: bool,
+    /// Whether to show steps; `None` leaves it to the mode.
+    steps: Option<bool>,
+}
+
+/// Where to load exchange rates from.
+#[derive(Debug, Clone)]
+enum Rates {
+    File(PathBuf),
+    Dir(PathBuf),
+}
+
+/// What the command line asks for.
+enum Command {
+    Evaluate {
+        output: Output,
+        rates: Vec<Rates>,
+        expressions: Vec<String>,
+    },
+    Help,
+    Version,
+}
+
+/// Parses the arguments after the program name. Anything that is not an
+/// option is an expression, as is everything after `--`, so `-5 + 3` can be
+/// passed as is.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut output = Output::default();
+    let mut rates = Vec::new();
+    let mut expressions = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => output.json = true,
+            "-i" | "--interactive" => output.interactive = true,
+            "--steps" => output.steps = Some(true),
+            "--no-steps" => output.steps = Some(false),
+            "--rates-file" | "--rates-dir" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("'{arg}' needs a path"))?
+                    .into();
+                rates.push(if arg == "--rates-file" {
+                    Rates::File(path)
+                } else {
+                    Rates::Dir(path)
+                });
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--" => expressions.extend(args.by_ref()),
+            option if option.starts_with("--") => {
+                return Err(format!("unknown option '{option}'"));
+            }
+            _ => expressions.push(arg),
         }
+    }
+    Ok(Command::Evaluate {
+        output,
+        rates,
+        expressions,
+    })
+}
 
-        let input = line.trim();
-        if input.is_empty() {
-            continue;
+fn main() -> ExitCode {
+    let (output, rates, expressions) = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Evaluate {
+            output,
+            rates,
+            expressions,
+        }) => (output, rates, expressions),
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
         }
+        Ok(Command::Version) => {
+            println!("link-calculator {}", link_calculator::VERSION);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("Error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
 
-        if input.eq_ignore_ascii_case("quit") || input.eq_ignore_ascii_case("exit") {
-            println!("Goodbye!");
-            break;
+    let mut session = Session::new();
+    for source in &rates {
+        if let Err(message) = session.load_rates(source) {
+            eprintln!("Error: {message}");
+            return ExitCode::from(2);
         }
+    }
+    if !expressions.is_empty() {
+        return session.evaluate_all(expressions, output);
+    }
+    if output.interactive || io::stdin().is_terminal() {
+        session.interactive(output);
+        return ExitCode::SUCCESS;
+    }
+    let lines = io::stdin().lines().map_while(Result::ok);
+    session.evaluate_all(lines.filter(|line| !line.trim().is_empty()), output)
+}
+
+/// A calculator whose calculations Ctrl-C can cancel.
+struct Session {
+    calculator: Calculator,
+    cancellation: CancellationToken,
+    calculating: Arc<AtomicBool>,
+}
 
-        if input.eq_ignore_ascii_case("help") {
-            print_help();
-            continue;
+impl Session {
+    fn new() -> Self {
+        let mut calculator = Calculator::new();
+        let cancellation = CancellationToken::new();
+        let calculating = Arc::new(AtomicBool::new(false));
+        calculator.set_cancellation_token(Some(cancellation.clone()));
+        install_interrupt_handler(cancellation.clone(), Arc::clone(&calculating));
+        Self {
+            calculator,
+            cancellation,
+            calculating,
         }
+    }
 
-        let result = calculator.calculate_internal(input);
+    /// Loads every rate in a consolidated .lino file, or in each .lino file
+    /// of a directory, warning about files without any.
+    fn load_rates(&self, source: &Rates) -> Result<(), String> {
+        let files = match source {
+            Rates::File(path) => vec![path.clone()],
+            Rates::Dir(dir) => lino_files(dir)
+                .map_err(|e| format!("cannot read rates from {}: {e}", dir.display()))?,
+        };
+        for path in files {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read rates from {}: {e}", path.display()))?;
+            if self.calculator.load_rates_from_consolidated_lino(&content) == 0 {
+                eprintln!("Warning: no rates found in {}", path.display());
+            }
+        }
+        Ok(())
+    }
 
-        if result.success {
-            println!("Result: {}", result.result);
-            println!("Links notation: {}", result.lino_interpretation);
+    fn calculate(&self, input: &str) -> CalculationResult {
+        self.cancellation.reset();
+        self.calculating.store(true, Ordering::SeqCst);
+        let result = self.calculator.calculate_internal(input);
+        self.calculating.store(false, Ordering::SeqCst);
+        result
+    }
 
-            if !result.steps.is_empty() {
-                println!("\nSteps:");
-                for step in &result.steps {
-                    println!("  {step}");
+    /// Evaluates each expression in turn, printing only the results, and
+    /// fails if any of them does. Ctrl-C stops at the current expression.
+    fn evaluate_all(
+        &self,
+        expressions: impl IntoIterator<Item = String>,
+        output: Output,
+    ) -> ExitCode {
+        let show_steps = output.steps.unwrap_or(false);
+        let mut all_succeeded = true;
+        for expression in expressions {
+            let result = self.calculate(expression.trim());
+            if self.cancellation.is_cancelled() {
+                eprintln!("Interrupted");
+                return ExitCode::from(130);
+            }
+            all_succeeded &= result.success;
+            if output.json {
+                print_json(result, show_steps);
+            } else if result.success {
+                println!("{}", result.result);
+                if show_steps {
+                    for step in &result.steps {
+                        println!("  {step}");
+                    }
                 }
+            } else {
+                eprintln!("Error: {}", result.error.unwrap_or_default());
             }
+        }
+        if all_succeeded {
+            ExitCode::SUCCESS
         } else {
-            println!("Error: {}", result.error.unwrap_or_default());
-            if let Some(link) = result.issue_link {
-                println!("\nReport this issue: {link}");
+            ExitCode::FAILURE
+        }
+    }
+
+    fn interactive(&mut self, output: Output) {
+        println!("Link Calculator v{}", link_calculator::VERSION);
+        println!("Type expressions to calculate, or 'quit' to exit.\n");
+
+        let mut show_steps = output.steps.unwrap_or(true);
+        let mut reader = LineReader::new();
+        self.load_bookmarks();
+
+        while let Some(line) = read_input(&mut reader) {
+            let input = line.trim();
+            if input.is_empty() {
+                continue;
+            }
+
+            if input.eq_ignore_ascii_case("quit") || input.eq_ignore_ascii_case("exit") {
+                println!("Goodbye!");
+                break;
+            }
+
+            if input.eq_ignore_ascii_case("help") {
+                print_help();
+                continue;
+            }
+
+            let input = match input.strip_prefix(':') {
+                Some(command) => match self.run_command(command.trim(), &mut show_steps) {
+                    Some(recalled) => recalled,
+                    None => continue,
+                },
+                None => input.to_string(),
+            };
+
+            let result = self.calculate(&input);
+            if output.json {
+                print_json(result, show_steps);
+                continue;
+            }
+
+            if result.success {
+                println!("Result: {}", result.result);
+                println!("Links notation: {}", result.lino_interpretation);
+
+                if show_steps && !result.steps.is_empty() {
+                    println!("\nSteps:");
+                    for step in &result.steps {
+                        println!("  {step}");
+                    }
+                }
+            } else {
+                println!("Error: {}", result.error.unwrap_or_default());
+                if let Some(link) = result.issue_link {
+                    println!("\nReport this issue: {link}");
+                }
+            }
+            println!();
+        }
+        reader.save_history();
+    }
+
+    /// Runs a `:command` typed at the interactive prompt, returning the
+    /// saved expression to calculate for `:recall`.
+    fn run_command(&mut self, command: &str, show_steps: &mut bool) -> Option<String> {
+        let mut words = command.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("vars"), None, _) => self.print_vars(),
+            (Some("saved"), None, _) => self.print_saved(),
+            (Some("save"), Some(name), Some(_)) => {
+                let input = command["save".len()..].trim_start()[name.len()..].trim();
+                if self.calculator.save_expression(name, input) {
+                    self.save_bookmarks();
+                    println!("Saved {name} = {input}");
+                } else {
+                    println!("Cannot save '{input}' as {name}: the name must be a letter followed by letters, digits, _ or -, and the expression must parse");
+                }
+            }
+            (Some("recall"), Some(name), None) => {
+                let saved = self.calculator.saved_expressions().get(name);
+                if saved.is_none() {
+                    println!("Nothing is saved as {name}. See :saved");
+                }
+                return saved.map(str::to_string);
+            }
+            (Some("forget"), Some(name), None) => {
+                if self.calculator.remove_expression(name) {
+                    self.save_bookmarks();
+                    println!("Forgot {name}");
+                } else {
+                    println!("Nothing is saved as {name}. See :saved");
+                }
+            }
+            (Some("steps"), None, _) => {
+                println!("Steps are {}", if *show_steps { "on" } else { "off" });
+            }
+            (Some("steps"), Some(setting @ ("on" | "off")), None) => {
+                *show_steps = setting == "on";
+                println!("Steps are {setting}");
+            }
+            (Some("angle"), None, _) => self.print_angle_mode(),
+            (Some("angle"), Some(mode), None) => {
+                if self.calculator.set_angle_mode(mode) {
+                    self.print_angle_mode();
+                } else {
+                    println!("Unknown angle mode '{mode}'. Use radians, degrees or gradians");
+                }
+            }
+            _ => println!(
+                "Unknown command ':{command}'. Commands: :vars, :steps on|off, :angle, :save, :recall, :forget, :saved"
+            ),
+        }
+        None
+    }
+
+    /// Says which unit trigonometric functions take plain numbers in.
+    fn print_angle_mode(&self) {
+        let mode = self.calculator.parser().angle_mode();
+        println!("Angles are in {}", mode.name());
+    }
+
+    /// Lists the expressions saved with `:save`.
+    fn print_saved(&self) {
+        let saved = self.calculator.saved_expressions();
+        if saved.is_empty() {
+            println!("Nothing saved yet. Save an expression with :save <name> <expression>.");
+        }
+        for (name, input) in saved.iter() {
+            println!("  {name} = {input}");
+        }
+    }
+
+    /// Loads the expressions saved in earlier sessions.
+    fn load_bookmarks(&mut self) {
+        let Some(path) = bookmarks_file() else {
+            return;
+        };
+        // Nothing has been saved on the first run
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if !self.calculator.load_saved_expressions_lino(&content) {
+                eprintln!(
+                    "Warning: cannot read saved expressions from {}",
+                    path.display()
+                );
+            }
+        }
+    }
+
+    /// Keeps the saved expressions for later sessions.
+    fn save_bookmarks(&self) {
+        let Some(path) = bookmarks_file() else {
+            return;
+        };
+        if let Err(e) = std::fs::write(&path, self.calculator.saved_expressions_lino()) {
+            eprintln!(
+                "Warning: cannot save expressions to {}: {e}",
+                path.display()
+            );
+        }
+    }
+
+    /// Lists the earlier results that `#n` and `ans` refer to.
+    fn print_vars(&self) {
+        let history = self.calculator.history();
+        if history.is_empty() {
+            println!("No results yet. Each result can be used later as #1, #2, ... and ans.");
+            return;
+        }
+        for (number, entry) in history.numbered() {
+            println!("  #{number} = {}    ({})", entry.result, entry.input);
+        }
+        if let Some(entry) = history.entries().last() {
+            println!("  ans = {}", entry.result);
+        }
+    }
+}
+
+/// Reads an input, continuing onto further lines while its parentheses or
+/// brackets are unclosed.
+fn read_input(reader: &mut LineReader) -> Option<String> {
+    let mut input = reader.read_line("> ")?;
+    while unclosed_brackets(&input) {
+        match reader.read_line("... ") {
+            Some(line) => {
+                input.push(' ');
+                input.push_str(line.trim());
             }
+            None => break,
         }
-        println!();
     }
+    Some(input)
+}
+
+/// Returns true if `input` opens more parentheses or brackets than it
+/// closes.
+fn unclosed_brackets(input: &str) -> bool {
+    let depth = input.chars().fold(0_i32, |depth, c| match c {
+        '(' | '[' => depth + 1,
+        ')' | ']' => depth - 1,
+        _ => depth,
+    });
+    depth > 0
+}
+
+/// Reads lines at the interactive prompt: with line editing and a history
+/// kept across sessions when built with the `readline` feature and run in a
+/// terminal, otherwise plainly from standard input.
+struct LineReader {
+    #[cfg(feature = "readline")]
+    editor: Option<rustyline::DefaultEditor>,
+}
+
+// Without the feature there is no editor, so the methods could be simpler
+#[cfg_attr(
+    not(feature = "readline"),
+    allow(
+        clippy::missing_const_for_fn,
+        clippy::unused_self,
+        clippy::needless_pass_by_ref_mut
+    )
+)]
+impl LineReader {
+    fn new() -> Self {
+        #[cfg(feature = "readline")]
+        {
+            let mut editor = io::stdin()
+                .is_terminal()
+                .then(|| rustyline::DefaultEditor::new().ok())
+                .flatten();
+            if let (Some(editor), Some(path)) = (editor.as_mut(), history_file()) {
+                // There is no history yet on the first run
+                let _ = editor.load_history(&path);
+            }
+            Self { editor }
+        }
+        #[cfg(not(feature = "readline"))]
+        Self {}
+    }
+
+    /// Returns the next line, or `None` at the end of input.
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        #[cfg(feature = "readline")]
+        if let Some(editor) = self.editor.as_mut() {
+            return match editor.readline(prompt) {
+                Ok(line) => {
+                    let _ = editor.add_history_entry(line.as_str());
+                    Some(line)
+                }
+                // Ctrl-C at the prompt discards the line
+                Err(rustyline::error::ReadlineError::Interrupted) => Some(String::new()),
+                Err(_) => None,
+            };
+        }
+
+        print!("{prompt}");
+        io::stdout().flush().expect("Failed to flush stdout");
+        let mut line = String::new();
+        // Stop at end of input (Ctrl-D) as well as on errors
+        match io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    fn save_history(&mut self) {
+        #[cfg(feature = "readline")]
+        if let (Some(editor), Some(path)) = (self.editor.as_mut(), history_file()) {
+            if let Err(e) = editor.save_history(&path) {
+                eprintln!("Warning: cannot save history to {}: {e}", path.display());
+            }
+        }
+    }
+}
+
+/// Where the interactive history is kept between sessions.
+#[cfg(feature = "readline")]
+fn history_file() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".link_calculator_history"))
+}
+
+/// Where expressions saved with `:save` are kept between sessions.
+fn bookmarks_file() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".link_calculator_bookmarks.lino"))
+}
+
+/// The .lino files directly in `dir`, sorted so that later files
+/// consistently override earlier ones.
+fn lino_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "lino") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Prints a result as one line of JSON, without its steps unless asked.
+fn print_json(mut result: CalculationResult, show_steps: bool) {
+    if !show_steps {
+        result.steps.clear();
+        result.steps_i18n = None;
+    }
+    match serde_json::to_string(&result) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("Error: cannot serialize result: {e}"),
+    }
+}
+
+/// Makes Ctrl-C cancel the running calculation, or exit at the prompt.
+fn install_interrupt_handler(cancellation: CancellationToken, calculating: Arc<AtomicBool>) {
+    #[cfg(all(feature = "interrupt", not(target_arch = "wasm32")))]
+    {
+        let installed = ctrlc::set_handler(move || {
+            if calculating.load(Ordering::SeqCst) {
+                cancellation.cancel();
+            } else {
+                println!();
+                std::process::exit(130);
+            }
+        });
+        if let Err(e) = installed {
+            eprintln!("Warning: Ctrl-C cannot cancel calculations: {e}");
+        }
+    }
+    #[cfg(not(all(feature = "interrupt", not(target_arch = "wasm32"))))]
+    let _ = (cancellation, calculating);
 }
 
 fn print_help() {
     println!(
-        r"
+        r#"
 Link Calculator - Help
 
 Basic Operations:
@@ -82,9 +575,21 @@ Temporal Context:
   84 USD - 34 EUR at 22 Jan 2026
                      Use historical exchange rates
 
+Command Line:
+  link-calculator "2 + 3"          Evaluate and exit
+  link-calculator --json < file    Evaluate each line, printing JSON
+
 Commands:
+  :vars              List earlier results, usable as #1, #2, ... and ans
+  :steps on|off      Show or hide calculation steps
+  :angle degrees     Read trigonometric angles in radians, degrees or gradians
+  :save NAME EXPR    Save an expression to calculate again later
+  :recall NAME       Calculate a saved expression with today's rates
+  :forget NAME       Remove a saved expression
+  :saved             List saved expressions
+  Ctrl-C             Cancel a running calculation
   help               Show this help
   quit               Exit the calculator
-"
+"#
     );
 }
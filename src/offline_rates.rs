@@ -0,0 +1,83 @@
+//! Exchange rates embedded in the library at build time, so a calculator
+//! works offline with real historical rates.
+//!
+//! Enabled with the `offline-rates` feature, which adds the consolidated
+//! `.lino` rate files from `data/currency` to the binary (about 1.6 MB).
+
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// The embedded rate files, as (from, to, consolidated `.lino` content).
+pub const BUNDLED_RATES: &[(&str, &str, &str)] = &[
+    ("CHF", "RUB", include_str!("../data/currency/chf-rub.lino")),
+    ("CNY", "RUB", include_str!("../data/currency/cny-rub.lino")),
+    ("EUR", "CHF", include_str!("../data/currency/eur-chf.lino")),
+    ("EUR", "CNY", include_str!("../data/currency/eur-cny.lino")),
+    ("EUR", "GBP", include_str!("../data/currency/eur-gbp.lino")),
+    ("EUR", "JPY", include_str!("../data/currency/eur-jpy.lino")),
+    ("EUR", "RUB", include_str!("../data/currency/eur-rub.lino")),
+    ("EUR", "USD", include_str!("../data/currency/eur-usd.lino")),
+    ("GBP", "EUR", include_str!("../data/currency/gbp-eur.lino")),
+    ("GBP", "RUB", include_str!("../data/currency/gbp-rub.lino")),
+    ("GBP", "USD", include_str!("../data/currency/gbp-usd.lino")),
+    ("INR", "RUB", include_str!("../data/currency/inr-rub.lino")),
+    ("JPY", "RUB", include_str!("../data/currency/jpy-rub.lino")),
+    ("KZT", "RUB", include_str!("../data/currency/kzt-rub.lino")),
+    ("RUB", "CHF", include_str!("../data/currency/rub-chf.lino")),
+    ("RUB", "CNY", include_str!("../data/currency/rub-cny.lino")),
+    ("RUB", "EUR", include_str!("../data/currency/rub-eur.lino")),
+    ("RUB", "GBP", include_str!("../data/currency/rub-gbp.lino")),
+    ("RUB", "INR", include_str!("../data/currency/rub-inr.lino")),
+    ("RUB", "JPY", include_str!("../data/currency/rub-jpy.lino")),
+    ("RUB", "KZT", include_str!("../data/currency/rub-kzt.lino")),
+    ("RUB", "USD", include_str!("../data/currency/rub-usd.lino")),
+    ("RUB", "VND", include_str!("../data/currency/rub-vnd.lino")),
+    ("USD", "AUD", include_str!("../data/currency/usd-aud.lino")),
+    ("USD", "BRL", include_str!("../data/currency/usd-brl.lino")),
+    ("USD", "CAD", include_str!("../data/currency/usd-cad.lino")),
+    ("USD", "CHF", include_str!("../data/currency/usd-chf.lino")),
+    ("USD", "CLF", include_str!("../data/currency/usd-clf.lino")),
+    ("USD", "CNY", include_str!("../data/currency/usd-cny.lino")),
+    ("USD", "CZK", include_str!("../data/currency/usd-czk.lino")),
+    ("USD", "DKK", include_str!("../data/currency/usd-dkk.lino")),
+    ("USD", "EUR", include_str!("../data/currency/usd-eur.lino")),
+    ("USD", "GBP", include_str!("../data/currency/usd-gbp.lino")),
+    ("USD", "HKD", include_str!("../data/currency/usd-hkd.lino")),
+    ("USD", "HUF", include_str!("../data/currency/usd-huf.lino")),
+    ("USD", "INR", include_str!("../data/currency/usd-inr.lino")),
+    ("USD", "JPY", include_str!("../data/currency/usd-jpy.lino")),
+    ("USD", "KRW", include_str!("../data/currency/usd-krw.lino")),
+    ("USD", "MXN", include_str!("../data/currency/usd-mxn.lino")),
+    ("USD", "NOK", include_str!("../data/currency/usd-nok.lino")),
+    ("USD", "NZD", include_str!("../data/currency/usd-nzd.lino")),
+    ("USD", "PLN", include_str!("../data/currency/usd-pln.lino")),
+    ("USD", "RUB", include_str!("../data/currency/usd-rub.lino")),
+    ("USD", "SEK", include_str!("../data/currency/usd-sek.lino")),
+    ("USD", "SGD", include_str!("../data/currency/usd-sgd.lino")),
+    ("USD", "TRY", include_str!("../data/currency/usd-try.lino")),
+    ("USD", "ZAR", include_str!("../data/currency/usd-zar.lino")),
+    ("VND", "RUB", include_str!("../data/currency/vnd-rub.lino")),
+];
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Loads the historical exchange rates embedded at build time and
+    /// returns how many were loaded.
+    ///
+    /// The latest embedded rate of each pair also becomes its current rate,
+    /// unless a rate from another source, such as a rate API, is already
+    /// set.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn load_bundled_rates(&self) -> usize {
+        let mut loaded = 0;
+        for (from, to, content) in BUNDLED_RATES {
+            loaded += self.load_rates_from_consolidated_lino(content);
+            let db = self.parser.currency_db();
+            if let Some(latest) = db.latest_historical_rate(from, to) {
+                db.set_rate_if_default(from, to, latest);
+            }
+        }
+        loaded
+    }
+}
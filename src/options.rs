@@ -0,0 +1,74 @@
+//! Per-calculation options, passed to [`Calculator::calculate`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{DateTimeFormat, StepsLevel};
+use crate::{CalculationResult, Calculator, Notation};
+
+/// Options for a single calculation.
+///
+/// From JavaScript, pass them to `calculate()` as a JSON object, e.g.
+/// `{"base_unit_steps": true, "number_locale": "ru"}`. Missing fields
+/// keep their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalculationOptions {
+    /// Shows every unit-bearing operand and result normalized to base units
+    /// (meters, liters, seconds, kilograms, bytes) in the steps.
+    pub base_unit_steps: bool,
+    /// Shows the written working of basic arithmetic in the steps, for
+    /// learners: long multiplication, long division and adding fractions
+    /// over a common denominator.
+    pub teaching_steps: bool,
+    /// How much detail the steps include: `minimal`, `normal` (the
+    /// default), `verbose` (with base-unit steps) or `debug` (also with the
+    /// tokens and grammar branch chosen by the parser).
+    pub steps_level: StepsLevel,
+    /// Reads numbers in the convention of a language first, such as `"ru"`
+    /// for `1 234,56` or `"de"` for `1.234,56`, for this calculation only.
+    pub number_locale: Option<String>,
+    /// Writes dates in the result and steps as `"iso"`, `"long"`,
+    /// `"weekday"` or `{"pattern": "%d.%m.%Y"}`, for this calculation only.
+    pub datetime_format: Option<DateTimeFormat>,
+}
+
+impl Calculator {
+    /// Calculates the result of an expression with per-calculation options.
+    pub fn calculate_with_options(
+        &self,
+        input: &str,
+        options: &CalculationOptions,
+    ) -> CalculationResult {
+        if let Some(DateTimeFormat::Pattern(pattern)) =
+            options.datetime_format.as_ref().filter(|f| !f.is_valid())
+        {
+            return CalculationResult::failure(
+                format!("Invalid date format pattern: {pattern}"),
+                input,
+            );
+        }
+        let mut calculator = self.working_copy();
+        if let Some(language) = options.number_locale.as_deref() {
+            if !calculator.parser.set_number_locale(Some(language)) {
+                return CalculationResult::failure(
+                    format!("Unknown number locale: {language}"),
+                    input,
+                );
+            }
+        }
+        if let Some(format) = &options.datetime_format {
+            calculator.format_options.datetime_format = format.clone();
+        }
+        calculator
+            .parser
+            .set_base_unit_steps(options.base_unit_steps);
+        calculator.parser.set_teaching_steps(options.teaching_steps);
+        calculator.parser.set_steps_level(options.steps_level);
+        let mut result = calculator.calculate_here(input, Notation::Text);
+        if options.steps_level == StepsLevel::Debug && result.steps.is_empty() {
+            // Explain how the parser read input it could not evaluate
+            result.steps = calculator.parser.debug_steps(input);
+        }
+        result
+    }
+}
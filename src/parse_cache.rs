@@ -0,0 +1,28 @@
+//! The parse cache on the [`Calculator`], which lets the web app re-evaluate
+//! the input on every keystroke without parsing it again.
+
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Returns the parse cache's statistics as a JSON object with `hits`,
+    /// `misses`, `evictions`, `entries` and `capacity`, for debugging.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn parse_cache_stats(&self) -> String {
+        serde_json::to_string(&self.parser.parse_cache_stats()).unwrap_or_default()
+    }
+
+    /// Sets how many inputs the parse cache holds; 0 turns caching off.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn set_parse_cache_capacity(&mut self, capacity: usize) {
+        self.parser.set_parse_cache_capacity(capacity);
+    }
+
+    /// Empties the parse cache and resets its statistics.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_parse_cache(&mut self) {
+        self.parser.clear_parse_cache();
+    }
+}
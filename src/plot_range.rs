@@ -0,0 +1,157 @@
+//! Choosing what a plot shows: an x-range around the zeros, poles and
+//! domain edges of a function, and breaks in the curve at its poles.
+//!
+//! Both work on the function as sampled for plotting, `None` where it is
+//! undefined.
+
+/// Range shown for a function without zeros, poles or domain edges.
+const DEFAULT_RANGE: (f64, f64) = (-10.0, 10.0);
+
+/// Features within this distance of the origin are always shown; those
+/// further out only when nothing is closer.
+const NEAR_ORIGIN: f64 = 10.0;
+
+/// Smallest half-width of an automatic range.
+const MIN_HALF_WIDTH: f64 = 5.0;
+
+/// Fraction of the features' extent added on each side of them.
+const MARGIN: f64 = 0.25;
+
+/// Bisection steps used to tell a pole from a steep but finite change.
+const POLE_BISECTIONS: usize = 40;
+
+/// Magnitude a function must reach near a jump for it to count as a pole.
+const POLE_MAGNITUDE: f64 = 1e6;
+
+/// Picks an x-range around the zeros, poles and domain edges of `f`
+/// nearest the origin, or -10 to 10 if it has none.
+pub fn auto_range(f: &mut impl FnMut(f64) -> Option<f64>) -> (f64, f64) {
+    let xs = scan_points();
+    let ys: Vec<_> = xs.iter().map(|&x| f(x)).collect();
+    let mut features = Vec::new();
+    // The last point with a nonzero value, whose sign a zero crossing flips
+    let mut signed: Option<(f64, f64)> = None;
+    for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+        if i > 0 && ys[i - 1].is_some() != y.is_some() {
+            // Values this large are overflow rather than the edge of the domain
+            if ys[i - 1].or(y).is_some_and(|y| y.abs() < POLE_MAGNITUDE) {
+                features.push(0.5 * (xs[i - 1] + x));
+            }
+            signed = None;
+        }
+        match y {
+            Some(y) if y.abs() >= POLE_MAGNITUDE => signed = None,
+            Some(y) if y != 0.0 => {
+                if let Some((previous_x, previous_y)) = signed {
+                    if previous_y.signum() != y.signum() {
+                        features.push(0.5 * (previous_x + x));
+                    }
+                }
+                signed = Some((x, y));
+            }
+            _ => {}
+        }
+    }
+    let Some(nearest) = features.iter().map(|x| x.abs()).reduce(f64::min) else {
+        return DEFAULT_RANGE;
+    };
+
+    let reach = NEAR_ORIGIN.max(4.0 * nearest);
+    let (lo, hi) = features
+        .into_iter()
+        .filter(|x| x.abs() <= reach)
+        .fold((0.0_f64, 0.0_f64), |(lo, hi), x| (lo.min(x), hi.max(x)));
+    let half = nice_ceiling(((hi - lo) * (0.5 + MARGIN)).max(MIN_HALF_WIDTH));
+    let center = (0.5 * (lo + hi) / (half / 5.0)).round() * (half / 5.0);
+    (center - half, center + half)
+}
+
+/// Returns true if `f` has a pole between the sampled points `a` and `b`:
+/// bisecting towards the sign change between them finds the function
+/// undefined or large rather than crossing zero, or bisecting towards the
+/// larger value finds it growing far beyond both.
+pub fn has_pole(
+    f: &mut impl FnMut(f64) -> Option<f64>,
+    (mut a, mut ya): (f64, f64),
+    (mut b, mut yb): (f64, f64),
+) -> bool {
+    let crossing = ya.signum() != yb.signum();
+    let (small, large) = (ya.abs().min(yb.abs()), ya.abs().max(yb.abs()));
+    if !crossing && large <= 4.0 * small {
+        return false;
+    }
+    let threshold = POLE_MAGNITUDE.max(1e3 * large);
+    for _ in 0..POLE_BISECTIONS {
+        let middle = 0.5 * (a + b);
+        let Some(y) = f(middle) else {
+            return true;
+        };
+        if !crossing && y.abs() > threshold {
+            return true;
+        }
+        let towards_a = if crossing {
+            ya.signum() != y.signum()
+        } else {
+            ya.abs() > yb.abs()
+        };
+        if towards_a {
+            (b, yb) = (middle, y);
+        } else {
+            (a, ya) = (middle, y);
+        }
+    }
+    crossing && ya.abs().min(yb.abs()) > POLE_MAGNITUDE
+}
+
+/// Points from -1000 to 1000, denser near the origin.
+fn scan_points() -> Vec<f64> {
+    let positive: Vec<f64> = (-40..=60)
+        .map(|k| 10_f64.powf(f64::from(k) / 20.0))
+        .collect();
+    let mut points: Vec<f64> = positive.iter().rev().map(|x| -x).collect();
+    points.push(0.0);
+    points.extend(positive);
+    points
+}
+
+/// Rounds up to 1, 2 or 5 times a power of ten.
+fn nice_ceiling(value: f64) -> f64 {
+    let magnitude = 10_f64.powf(value.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|step| step * magnitude)
+        .find(|nice| *nice >= value)
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(f: fn(f64) -> f64) -> (f64, f64) {
+        auto_range(&mut |x| Some(f(x)).filter(|y| y.is_finite()))
+    }
+
+    #[test]
+    fn test_centers_on_zeros_and_poles() {
+        assert_eq!(range(f64::exp), DEFAULT_RANGE);
+        let (lo, hi) = range(|x| 1.0 / (x - 50.0));
+        assert!(lo < 0.0 && hi > 50.0, "{lo}..{hi}");
+        let (lo, hi) = range(f64::ln);
+        assert!(lo < 0.0 && hi > 1.0, "{lo}..{hi}");
+    }
+
+    #[test]
+    fn test_tells_poles_from_steep_crossings() {
+        let mut reciprocal = |x: f64| Some(1.0 / x).filter(|y| y.is_finite());
+        assert!(has_pole(&mut reciprocal, (-0.3, -1.0 / 0.3), (0.1, 10.0)));
+        let mut steep = |x: f64| Some(1000.0 * x);
+        assert!(!has_pole(&mut steep, (-0.3, -300.0), (0.1, 100.0)));
+        let mut growth = |x: f64| Some(x.exp());
+        assert!(!has_pole(
+            &mut growth,
+            (10.0, 10_f64.exp()),
+            (20.0, 20_f64.exp())
+        ));
+    }
+}
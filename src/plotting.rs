@@ -0,0 +1,405 @@
+//! Plot data generation for integrals and `plot` commands on the [`Calculator`].
+
+use crate::error::CalculatorError;
+use crate::grammar::{CompiledExpression, AUTO_PLOT_BOUND};
+use crate::plot_range::{auto_range, has_pole};
+use crate::types::{Decimal, Expression};
+use crate::{CalculationResult, Calculator, PlotData};
+
+/// Number of intervals sampled across the plotted range of an integral.
+const PLOT_INTERVALS: u32 = 200;
+
+/// Largest number of points a `plot` command may request per function.
+const MAX_PLOT_SAMPLES: u32 = 10_000;
+
+/// Fraction of the integration interval shown on each side of the bounds.
+const BOUNDS_MARGIN: f64 = 0.25;
+
+/// Offset used to approach a removable singularity from both sides.
+const NUDGE: f64 = 1e-7;
+
+impl Calculator {
+    /// Generates plot data for an indefinite integral expression: the
+    /// integrand and, when it can be integrated numerically, the
+    /// antiderivative that is 0 at the reference point, so that `Si(x) + C`
+    /// is drawn beside `sin(x)/x`.
+    pub(super) fn generate_plots_for_integral(&mut self, input: &str) -> Vec<PlotData> {
+        // Try to parse and extract the integrand for plotting
+        let Ok(Expression::IndefiniteIntegral {
+            integrand,
+            variable,
+        }) = self.parser.parse(input)
+        else {
+            return Vec::new();
+        };
+
+        let compiled = CompiledExpression::compile(&integrand, &variable);
+        let (x_min, x_max) = auto_range(&mut |x| {
+            self.evaluate_or_limit(compiled.as_ref(), &integrand, &variable, x)
+        });
+        let Ok(Some(plot)) = self.sample_plot(&integrand, &variable, x_min, x_max, PLOT_INTERVALS)
+        else {
+            return Vec::new();
+        };
+        let antiderivative = self.antiderivative_plot(&plot, &integrand, &variable);
+        std::iter::once(plot).chain(antiderivative).collect()
+    }
+
+    /// Integrates the sampled integrand outwards from the reference point,
+    /// 0 when it is plotted and the first sample otherwise, with Simpson's
+    /// rule between neighbouring samples. Beyond a pole of the integrand the
+    /// antiderivative is NaN.
+    fn antiderivative_plot(
+        &mut self,
+        integrand: &PlotData,
+        expr: &Expression,
+        variable: &str,
+    ) -> Option<PlotData> {
+        let xs = &integrand.x_values;
+        let ys = &integrand.y_values;
+        let reference = if xs.first()? <= &0.0 && xs.last()? >= &0.0 {
+            xs.iter()
+                .enumerate()
+                .filter(|(i, _)| ys[*i].is_finite())
+                .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+                .map(|(i, _)| i)?
+        } else {
+            ys.iter().position(|y| y.is_finite())?
+        };
+
+        let compiled = CompiledExpression::compile(expr, variable);
+        let area = |parser: &mut Self, i: usize| {
+            let mid = parser.evaluate_or_limit(
+                compiled.as_ref(),
+                expr,
+                variable,
+                0.5 * (xs[i] + xs[i + 1]),
+            )?;
+            let simpson = (xs[i + 1] - xs[i]) / 6.0 * (4.0f64.mul_add(mid, ys[i]) + ys[i + 1]);
+            simpson.is_finite().then_some(simpson)
+        };
+        let mut y_values = vec![f64::NAN; xs.len()];
+        y_values[reference] = 0.0;
+        for i in reference..xs.len() - 1 {
+            match area(self, i) {
+                Some(a) if y_values[i].is_finite() => y_values[i + 1] = y_values[i] + a,
+                _ => break,
+            }
+        }
+        for i in (0..reference).rev() {
+            match area(self, i) {
+                Some(a) if y_values[i + 1].is_finite() => y_values[i] = y_values[i + 1] - a,
+                _ => break,
+            }
+        }
+
+        Some(PlotData {
+            x_values: xs.clone(),
+            y_values,
+            label: format!("∫ {expr} d{variable} from {}", xs[reference]),
+            x_label: variable.to_string(),
+            y_label: format!("F({variable})"),
+            shade_from: None,
+            shade_to: None,
+        })
+    }
+
+    /// Generates plot data for `integrate(f, x, a, b)`: the integrand over
+    /// the bounds plus a margin, with the bounds marked for shading.
+    pub(super) fn generate_plot_data_for_definite_integral(
+        &mut self,
+        expr: &Expression,
+    ) -> Option<PlotData> {
+        let Expression::FunctionCall { name, args } = expr else {
+            return None;
+        };
+        if !name.eq_ignore_ascii_case("integrate") || args.len() != 4 {
+            return None;
+        }
+        let Expression::Variable(variable) = &args[1] else {
+            return None;
+        };
+
+        let lower = self.evaluate_bound(&args[2])?;
+        let upper = self.evaluate_bound(&args[3])?;
+        let width = (upper - lower).abs();
+        let margin = if width > 0.0 {
+            width * BOUNDS_MARGIN
+        } else {
+            1.0
+        };
+
+        let mut plot = self
+            .sample_plot(
+                &args[0],
+                variable,
+                lower.min(upper) - margin,
+                lower.max(upper) + margin,
+                PLOT_INTERVALS,
+            )
+            .ok()
+            .flatten()?;
+        plot.shade_from = Some(lower);
+        plot.shade_to = Some(upper);
+        Some(plot)
+    }
+
+    /// Generates the time series of a rate range such as
+    /// `USD/EUR from 1 Jan 2026 to 31 Jan 2026`: one point per known rate,
+    /// with x in seconds since the Unix epoch.
+    pub(super) fn generate_plot_data_for_rate_history(
+        &mut self,
+        expr: &Expression,
+    ) -> Option<PlotData> {
+        let mut call = expr;
+        while let Expression::Group(inner) | Expression::WithPrecision { value: inner, .. } = call {
+            call = inner;
+        }
+        let Expression::FunctionCall { name, args } = call else {
+            return None;
+        };
+        if !name.eq_ignore_ascii_case("rate_history") {
+            return None;
+        }
+
+        let history = self.parser.rate_history(args).ok()?;
+        let (x_values, y_values) = history
+            .rates
+            .iter()
+            .map(|(date, rate)| {
+                let seconds = date
+                    .and_hms_opt(0, 0, 0)
+                    .map_or(0, |dt| dt.and_utc().timestamp());
+                (seconds as f64, *rate)
+            })
+            .unzip();
+        Some(PlotData {
+            x_values,
+            y_values,
+            label: format!("{}/{}", history.from, history.to),
+            x_label: "date (Unix time)".to_string(),
+            y_label: format!("{} per {}", history.to, history.from),
+            shade_from: None,
+            shade_to: None,
+        })
+    }
+
+    /// Evaluates a `plot(f1, ..., fn, lower, upper, samples)` command into one
+    /// series per function. Returns `None` if `expr` is not a plot command.
+    pub(super) fn plot_command(
+        &mut self,
+        input: &str,
+        expr: &Expression,
+    ) -> Option<CalculationResult> {
+        let Expression::FunctionCall { name, args } = expr else {
+            return None;
+        };
+        if !name.eq_ignore_ascii_case("plot") || args.len() < 4 {
+            return None;
+        }
+        let (functions, range) = args.split_at(args.len() - 3);
+
+        Some(match self.plot_series(functions, range) {
+            Ok(plots) => {
+                let labels: Vec<String> = functions.iter().map(ToString::to_string).collect();
+                let (lower, upper) = if is_auto_range(range) {
+                    let xs = &plots[0].x_values;
+                    (xs[0].to_string(), xs[xs.len() - 1].to_string())
+                } else {
+                    (range[0].to_string(), range[1].to_string())
+                };
+                let result = format!("{} from {lower} to {upper}", labels.join(", "));
+                CalculationResult::plot(&expr.to_lino(), result, plots)
+            }
+            Err(e) => CalculationResult::failure_with_i18n(&e, input),
+        })
+    }
+
+    fn plot_series(
+        &mut self,
+        functions: &[Expression],
+        range: &[Expression],
+    ) -> Result<Vec<PlotData>, CalculatorError> {
+        // The bounds are evaluated leniently, so check for a cancelled calculation first
+        self.parser.check_cancelled()?;
+        let invalid = |message: &str| CalculatorError::invalid_args("plot", message);
+        let variable = functions
+            .iter()
+            .find_map(first_variable)
+            .unwrap_or_else(|| "x".to_string());
+        let (lower, upper) = if is_auto_range(range) {
+            self.auto_plot_range(functions, &variable)
+        } else {
+            let lower = self
+                .evaluate_bound(&range[0])
+                .ok_or_else(|| invalid("start of the range must be numeric"))?;
+            let upper = self
+                .evaluate_bound(&range[1])
+                .ok_or_else(|| invalid("end of the range must be numeric"))?;
+            (lower, upper)
+        };
+        if lower >= upper {
+            return Err(invalid("start of the range must be less than its end"));
+        }
+        let samples = self
+            .evaluate_bound(&range[2])
+            .filter(|n| n.fract() == 0.0 && (2.0..=f64::from(MAX_PLOT_SAMPLES)).contains(n))
+            .ok_or_else(|| invalid("number of points must be a whole number from 2 to 10000"))?;
+        let intervals = u32::try_from(samples as i64).unwrap_or(MAX_PLOT_SAMPLES) - 1;
+
+        functions
+            .iter()
+            .map(|function| {
+                self.sample_plot(function, &variable, lower, upper, intervals)?
+                    .ok_or_else(|| {
+                        CalculatorError::eval(format!(
+                            "Cannot evaluate {function} between {lower} and {upper}"
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Picks a range covering the zeros, poles and domain edges of every
+    /// function, as for the integrand of an indefinite integral.
+    fn auto_plot_range(&mut self, functions: &[Expression], variable: &str) -> (f64, f64) {
+        functions
+            .iter()
+            .map(|function| {
+                let compiled = CompiledExpression::compile(function, variable);
+                auto_range(&mut |x| {
+                    self.evaluate_or_limit(compiled.as_ref(), function, variable, x)
+                })
+            })
+            .fold(
+                (f64::INFINITY, f64::NEG_INFINITY),
+                |(lo, hi), (lower, upper)| (lo.min(lower), hi.max(upper)),
+            )
+    }
+
+    fn evaluate_bound(&self, bound: &Expression) -> Option<f64> {
+        let value = self.parser.evaluate(bound).ok()?.as_decimal()?.to_f64();
+        value.is_finite().then_some(value)
+    }
+
+    /// Samples `expr` over `[x_min, x_max]`. Where it is undefined, and
+    /// between samples on either side of a pole, the y-value is NaN so that
+    /// the curve is broken there. Fails only if the progress callback
+    /// cancels sampling.
+    fn sample_plot(
+        &mut self,
+        expr: &Expression,
+        variable: &str,
+        x_min: f64,
+        x_max: f64,
+        intervals: u32,
+    ) -> Result<Option<PlotData>, CalculatorError> {
+        let mut x_values = Vec::new();
+        let mut y_values = Vec::new();
+        let step = (x_max - x_min) / f64::from(intervals);
+        let scale = 10_f64.powf((6.0 - step.log10()).ceil());
+        let compiled = CompiledExpression::compile(expr, variable);
+        let mut previous = None;
+
+        for i in 0..=intervals {
+            // Rounded to decimal places well below the step, so that samples
+            // land exactly on round values such as 0 or 2, where poles often are
+            let fraction = f64::from(i) / f64::from(intervals);
+            let x = (fraction.mul_add(x_max - x_min, x_min) * scale).round() / scale;
+            let y = self.evaluate_or_limit(compiled.as_ref(), expr, variable, x);
+            if let (Some(start), Some(y)) = (previous, y) {
+                let mut f = |x| self.evaluate_or_limit(compiled.as_ref(), expr, variable, x);
+                if has_pole(&mut f, start, (x, y)) {
+                    x_values.push(0.5 * (start.0 + x));
+                    y_values.push(f64::NAN);
+                }
+            }
+            x_values.push(x);
+            y_values.push(y.unwrap_or(f64::NAN));
+            previous = y.map(|y| (x, y));
+            self.parser
+                .report_progress("plot", u64::from(i) + 1, u64::from(intervals) + 1)?;
+        }
+
+        if y_values.iter().all(|y| y.is_nan()) {
+            return Ok(None);
+        }
+        Ok(Some(PlotData {
+            x_values,
+            y_values,
+            label: format!("{}", expr),
+            x_label: variable.to_string(),
+            y_label: format!("f({})", variable),
+            shade_from: None,
+            shade_to: None,
+        }))
+    }
+
+    /// Evaluates `expr` at `x`. At removable singularities such as
+    /// `sin(x)/x` at 0, falls back to the average of the neighbouring values.
+    fn evaluate_or_limit(
+        &mut self,
+        compiled: Option<&CompiledExpression>,
+        expr: &Expression,
+        variable: &str,
+        x: f64,
+    ) -> Option<f64> {
+        if let Ok(y) = self.evaluate_at_point(compiled, expr, variable, x) {
+            if y.is_finite() {
+                return Some(y);
+            }
+        }
+        let left = self
+            .evaluate_at_point(compiled, expr, variable, x - NUDGE)
+            .ok()?;
+        let right = self
+            .evaluate_at_point(compiled, expr, variable, x + NUDGE)
+            .ok()?;
+        let limit = (left + right) / 2.0;
+        ((left - right).abs() < 1e-3 && limit.is_finite()).then_some(limit)
+    }
+
+    /// Evaluates an expression at a specific point, through `compiled` when
+    /// the expression could be compiled.
+    fn evaluate_at_point(
+        &mut self,
+        compiled: Option<&CompiledExpression>,
+        expr: &Expression,
+        var: &str,
+        value: f64,
+    ) -> Result<f64, CalculatorError> {
+        if let Some(compiled) = compiled {
+            return self.parser.evaluate_compiled(compiled, value);
+        }
+        let substituted = Self::substitute_variable(expr, var, Decimal::from_f64(value)?);
+        let result = self.parser.evaluate(&substituted)?;
+        result
+            .as_decimal()
+            .map(|d| d.to_f64())
+            .ok_or_else(|| CalculatorError::eval("Expected numeric result"))
+    }
+}
+
+/// Returns the name of the first variable in `expr`, if any.
+/// Returns true if the plot range is to be picked from the functions:
+/// `plot 1/x` without `from <start> to <end>`.
+fn is_auto_range(range: &[Expression]) -> bool {
+    range[..2]
+        .iter()
+        .all(|bound| matches!(bound, Expression::Variable(name) if name == AUTO_PLOT_BOUND))
+}
+
+fn first_variable(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::Variable(name) => Some(name.clone()),
+        Expression::Binary { left, right, .. } => {
+            first_variable(left).or_else(|| first_variable(right))
+        }
+        Expression::Power { base, exponent } => {
+            first_variable(base).or_else(|| first_variable(exponent))
+        }
+        Expression::Negate(inner) | Expression::Group(inner) => first_variable(inner),
+        Expression::FunctionCall { args, .. } => args.iter().find_map(first_variable),
+        _ => None,
+    }
+}
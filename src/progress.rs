@@ -0,0 +1,77 @@
+//! Progress callbacks and cancellation of long-running calculations on the
+//! [`Calculator`].
+
+use crate::types::{CancellationToken, Progress};
+use crate::{CalculationResult, Calculator};
+#[cfg(feature = "wasm")]
+use std::cell::RefCell;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm")]
+thread_local! {
+    /// The `on_progress` function of the running `calculate_with_progress`.
+    /// JavaScript functions cannot be sent to other threads, so the progress
+    /// callback, which can, looks it up here.
+    static ON_PROGRESS: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl Calculator {
+    /// Calculates an expression like `execute()`, calling `on_progress` with
+    /// a `{stage, done, total}` object while numerical integration or plot
+    /// sampling runs, so the frontend can show progress for heavy inputs.
+    ///
+    /// Returning `false` from `on_progress` cancels the calculation, which
+    /// then fails with the `errors.cancelled` error.
+    #[wasm_bindgen]
+    pub fn calculate_with_progress(
+        &mut self,
+        input: &str,
+        on_progress: &js_sys::Function,
+    ) -> String {
+        ON_PROGRESS.with(|function| *function.borrow_mut() = Some(on_progress.clone()));
+        let result = self.calculate_with_progress_callback(input, |progress| {
+            let progress = serde_wasm_bindgen::to_value(progress).unwrap_or(JsValue::NULL);
+            ON_PROGRESS.with(|function| {
+                function.borrow().as_ref().map_or(true, |on_progress| {
+                    on_progress
+                        .call1(&JsValue::NULL, &progress)
+                        .map_or(true, |keep_going| keep_going.as_bool() != Some(false))
+                })
+            })
+        });
+        ON_PROGRESS.with(|function| function.borrow_mut().take());
+        serde_json::to_string(&result).unwrap_or_else(|e| {
+            format!(
+                r#"{{"success":false,"error":"Serialization error: {}"}}"#,
+                e
+            )
+        })
+    }
+}
+
+impl Calculator {
+    /// Calculates an expression, calling `callback` with the progress of
+    /// numerical integration and plot sampling. Returning `false` from the
+    /// callback cancels the calculation with
+    /// [`crate::error::CalculatorError::Cancelled`].
+    pub fn calculate_with_progress_callback(
+        &mut self,
+        input: &str,
+        callback: impl FnMut(&Progress) -> bool + Send + 'static,
+    ) -> CalculationResult {
+        self.parser.set_progress_callback(Some(Box::new(callback)));
+        let result = self.calculate_internal(input);
+        self.parser.set_progress_callback(None);
+        result
+    }
+
+    /// Sets the token a host cancels running calculations with, e.g. from a
+    /// Ctrl-C handler on another thread; `None` removes it. A cancelled
+    /// calculation fails with [`crate::error::CalculatorError::Cancelled`].
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.parser.set_cancellation_token(token);
+    }
+}
@@ -0,0 +1,200 @@
+//! The same numeric result written several ways, so that the frontend can
+//! switch between them without calculating again. Colors are written in
+//! each color model instead.
+
+use crate::grammar::{format_prime_factors, prime_factors};
+use crate::types::{ColorModel, Rational, Unit, Value, ValueKind};
+
+/// How a [`Representation`] writes a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepresentationKind {
+    /// Decimal notation: 0.375
+    Decimal,
+    /// An exact fraction: 3/8
+    Fraction,
+    /// Scientific notation: 3.75e-1
+    Scientific,
+    /// Engineering notation, with an exponent that is a multiple of 3: 375e-3
+    Engineering,
+    /// A percentage: 37.5%
+    Percentage,
+    /// The prime factors of an integer: 2^3 * 3 * 5
+    PrimeFactorization,
+    /// The best fractions near an irrational number: 22/7, 333/106, 355/113
+    Approximations,
+    /// The continued fraction of an irrational number: [3; 7, 15, 1, 292, ...]
+    ContinuedFraction,
+    /// A color in hex: #ff8800
+    Hex,
+    /// A color by its channels: rgb(255, 136, 0)
+    Rgb,
+    /// A color by hue, saturation and lightness: hsl(32, 100%, 50%)
+    Hsl,
+}
+
+/// A numeric result written one way.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Representation {
+    /// The notation.
+    pub kind: RepresentationKind,
+    /// The result in that notation.
+    pub value: String,
+}
+
+impl Representation {
+    /// Fractions listed as approximations of an irrational number.
+    const APPROXIMATIONS: usize = 4;
+
+    /// The largest denominator of the continued fraction of an irrational
+    /// number, well within the precision of the f64 it is expanded from.
+    const MAX_CONTINUED_FRACTION_DENOMINATOR: i128 = 1_000_000;
+
+    /// Writes a plain number in every notation that applies to it: a
+    /// fraction only when it is exact and not an integer, and prime factors
+    /// only when it is an integer. A calculated number that is not a simple fraction, such
+    /// as `sqrt(2)`, also gets its nearest fractions and continued fraction.
+    /// A color is written in hex, rgb and hsl.
+    #[must_use]
+    pub fn all(value: &Value) -> Vec<Self> {
+        if let Some((color, _)) = value.as_color() {
+            return [
+                (RepresentationKind::Hex, ColorModel::Hex),
+                (RepresentationKind::Rgb, ColorModel::Rgb),
+                (RepresentationKind::Hsl, ColorModel::Hsl),
+            ]
+            .into_iter()
+            .map(|(kind, model)| Self::new(kind, color.format(model)))
+            .collect();
+        }
+        if value.unit != Unit::None {
+            return Vec::new();
+        }
+        let number = match &value.kind {
+            ValueKind::Rational(r) => r.clone(),
+            ValueKind::Number(n) => Rational::from_decimal(*n),
+            ValueKind::Symbolic { .. } => return Self::numeric(value),
+            _ => return Vec::new(),
+        };
+
+        // A number calculated to limited precision, such as `sqrt(2)`, is
+        // shown to 16 digits and is not the fraction its digits spell
+        let exact = matches!(value.kind, ValueKind::Rational(_));
+        let display = |number: &Rational| {
+            if exact {
+                number.to_display_string()
+            } else {
+                number.to_rounded_display_string()
+            }
+        };
+        let mut representations = vec![Self::new(RepresentationKind::Decimal, display(&number))];
+        if exact && !number.is_integer() && number.has_display_digits() {
+            representations.push(Self::new(
+                RepresentationKind::Fraction,
+                number.to_fraction_string(),
+            ));
+        }
+        if let Some(exponent) = number.decimal_exponent() {
+            let notations = [
+                (RepresentationKind::Scientific, exponent),
+                (
+                    RepresentationKind::Engineering,
+                    exponent - exponent.rem_euclid(3),
+                ),
+            ];
+            for (kind, shifted) in notations {
+                if let Some(notation) = exponent_notation(&number, exponent, shifted) {
+                    representations.push(Self::new(kind, notation));
+                }
+            }
+        }
+        let percent = number.clone() * Rational::from_integer(100);
+        representations.push(Self::new(
+            RepresentationKind::Percentage,
+            format!("{}%", display(&percent)),
+        ));
+        if let Some(factors) = prime_factorization(&number) {
+            representations.push(Self::new(RepresentationKind::PrimeFactorization, factors));
+        }
+        if matches!(value.kind, ValueKind::Number(_)) && !number.is_integer() {
+            representations.extend(Self::approximations(number.to_f64()));
+        }
+        representations
+    }
+
+    /// Lists the nearest fractions to `value` and its continued fraction,
+    /// or nothing when the expansion ends, as it does for a simple fraction.
+    fn approximations(value: f64) -> Vec<Self> {
+        let (terms, exact) =
+            Rational::continued_fraction(value.abs(), Self::MAX_CONTINUED_FRACTION_DENOMINATOR);
+        let Some((first, rest)) = terms
+            .split_first()
+            .filter(|(_, rest)| !exact && !rest.is_empty())
+        else {
+            return Vec::new();
+        };
+        let sign = if value < 0.0 { "-" } else { "" };
+        let fractions = Rational::convergents(&terms)
+            .into_iter()
+            .filter(|fraction| !fraction.is_integer())
+            .take(Self::APPROXIMATIONS)
+            .map(|fraction| format!("{sign}{}", fraction.to_fraction_string()))
+            .collect::<Vec<_>>();
+        let rest = rest.iter().map(ToString::to_string).collect::<Vec<_>>();
+        vec![
+            Self::new(RepresentationKind::Approximations, fractions.join(", ")),
+            Self::new(
+                RepresentationKind::ContinuedFraction,
+                format!("{sign}[{first}; {}, ...]", rest.join(", ")),
+            ),
+        ]
+    }
+
+    /// Lists the decimal and exponent forms and the nearest fractions of the
+    /// value of a symbolic constant such as `2π`, which has no exact
+    /// fraction.
+    fn numeric(value: &Value) -> Vec<Self> {
+        Self::all(&value.numeric())
+            .into_iter()
+            .filter(|representation| {
+                matches!(
+                    representation.kind,
+                    RepresentationKind::Decimal
+                        | RepresentationKind::Scientific
+                        | RepresentationKind::Engineering
+                        | RepresentationKind::Approximations
+                        | RepresentationKind::ContinuedFraction
+                )
+            })
+            .collect()
+    }
+
+    fn new(kind: RepresentationKind, value: String) -> Self {
+        Self { kind, value }
+    }
+}
+
+/// Writes `number`, whose own decimal exponent is `exponent`, as a mantissa
+/// times `10^shifted`: `3.75e-1` or `375e-3`. Both keep the significant
+/// digits of [`Rational::to_scientific_string`], however many of them end
+/// up before the point.
+fn exponent_notation(number: &Rational, exponent: i32, shifted: i32) -> Option<String> {
+    let decimals = i64::from(Rational::SCIENTIFIC_DECIMALS) - i64::from(exponent - shifted);
+    let mantissa = number.mantissa(shifted, u32::try_from(decimals).ok()?)?;
+    Some(format!("{mantissa}e{shifted}"))
+}
+
+/// Lists the prime factors of an integer from 2 up to
+/// [`MAX_FACTORIZED`](crate::grammar::MAX_FACTORIZED) in magnitude: `2^3 * 3 * 5`.
+fn prime_factorization(number: &Rational) -> Option<String> {
+    if !number.is_integer() {
+        return None;
+    }
+    let n = u64::try_from(number.numer_bigint().magnitude()).ok()?;
+    let factors = format_prime_factors(&prime_factors(n)?);
+    Some(if number.is_negative() {
+        format!("-1 * {factors}")
+    } else {
+        factors
+    })
+}
@@ -0,0 +1,714 @@
+//! Calculation result types returned by [`crate::Calculator`].
+//!
+//! These are serialized to JSON at the WASM boundary, so every optional field
+//! is skipped when absent to keep the payload small.
+
+use crate::error::{CalculatorError, ErrorInfo, ErrorSpan};
+use crate::grammar::PartialEvaluation;
+use crate::lino::lino_to_doublets;
+use crate::representations::Representation;
+use crate::types::{
+    DateTimeResult, Expression, PhaseTimings, Radix, RateUsage, StructuredStep, Unit, Value,
+    ValueKind,
+};
+use crate::utils::generate_issue_link;
+
+/// A table of rows under named columns, such as an amortization schedule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResultTable {
+    /// Column headings.
+    pub columns: Vec<String>,
+    /// Rows of display values, one per column.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A labelled part of a result, such as the VAT in `150 EUR + VAT 19%`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BreakdownItem {
+    /// What the part is (e.g., "Net", "VAT 19%", "Gross").
+    pub label: String,
+    /// The part's value, formatted for display.
+    pub value: String,
+}
+
+/// Data for plotting a function.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PlotData {
+    /// X-axis values.
+    pub x_values: Vec<f64>,
+    /// Y-axis values: NaN (`null` in JSON) where the function is undefined,
+    /// which breaks the curve, as at the pole of `1/x`.
+    #[serde(deserialize_with = "deserialize_y_values")]
+    pub y_values: Vec<f64>,
+    /// Label for the plot (e.g., "sin(x)/x").
+    pub label: String,
+    /// X-axis label.
+    pub x_label: String,
+    /// Y-axis label.
+    pub y_label: String,
+    /// Start of the region to shade under the curve (e.g., a definite integral's lower bound).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shade_from: Option<f64>,
+    /// End of the region to shade under the curve (e.g., a definite integral's upper bound).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shade_to: Option<f64>,
+}
+
+/// Reads y-values back from JSON, where NaN is written as `null`.
+fn deserialize_y_values<'de, D>(deserializer: D) -> Result<Vec<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values: Vec<Option<f64>> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|y| y.unwrap_or(f64::NAN)).collect())
+}
+
+/// A single calculation step with i18n support.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CalculationStep {
+    /// The translation key for this step type.
+    pub key: String,
+    /// Parameters for interpolation in the translated message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<std::collections::BTreeMap<String, String>>,
+    /// The raw (English) text for fallback.
+    pub text: String,
+}
+
+impl CalculationStep {
+    /// Creates a new step with a translation key, params, and fallback text.
+    #[must_use]
+    pub fn new(
+        key: impl Into<String>,
+        params: Option<std::collections::BTreeMap<String, String>>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            params,
+            text: text.into(),
+        }
+    }
+
+    /// Creates a simple step with just text (no translation key).
+    #[must_use]
+    pub fn text_only(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            key: String::new(),
+            params: None,
+            text,
+        }
+    }
+}
+
+/// Repeating decimal notation formats.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RepeatingDecimalFormats {
+    /// Vinculum notation with overline: 0.3̅
+    pub vinculum: String,
+    /// Parenthesis notation: 0.(3)
+    pub parenthesis: String,
+    /// Ellipsis notation: 0.333...
+    pub ellipsis: String,
+    /// LaTeX notation: 0.\overline{3}
+    pub latex: String,
+    /// Fraction representation: 1/3
+    pub fraction: String,
+}
+
+/// An integer written in the supported number bases.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BaseRepresentations {
+    /// Binary notation: 0b1111
+    pub binary: String,
+    /// Octal notation: 0o17
+    pub octal: String,
+    /// Decimal notation: 15
+    pub decimal: String,
+    /// Hexadecimal notation: 0xF
+    pub hexadecimal: String,
+}
+
+impl BaseRepresentations {
+    /// Largest magnitude (in bits) shown in other bases, so huge results
+    /// such as `1000!` do not produce kilobytes of binary digits.
+    const MAX_BITS: u64 = 128;
+
+    /// Builds base representations for plain or base-converted integers.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Option<Self> {
+        if !matches!(value.unit, Unit::None | Unit::Radix(_)) {
+            return None;
+        }
+        let rational = value.as_rational()?;
+        if !rational.is_integer() || rational.numer_bigint().bits() > Self::MAX_BITS {
+            return None;
+        }
+
+        let n = rational.numer_bigint();
+        Some(Self {
+            binary: Radix::Binary.format(n),
+            octal: Radix::Octal.format(n),
+            decimal: Radix::Decimal.format(n),
+            hexadecimal: Radix::Hexadecimal.format(n),
+        })
+    }
+}
+
+/// A subexpression that could not be evaluated in a partial result.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UnresolvedExpression {
+    /// The subexpression as text (e.g., "84 USD + 10 ABC").
+    pub expression: String,
+    /// Why it could not be evaluated (raw text).
+    pub error: String,
+    /// Error information for i18n support.
+    pub error_info: ErrorInfo,
+}
+
+/// The version of the JSON shape of [`CalculationResult`].
+///
+/// It goes up when a field is removed, renamed or changes meaning. Adding an
+/// optional field keeps it, since older readers skip fields they don't know.
+pub const RESULT_SCHEMA_VERSION: u32 = 1;
+
+/// Result of a calculation operation.
+///
+/// The JSON is deterministic: fields are written in the order they are
+/// declared here, new fields are added at the end, and maps such as error
+/// parameters are written with their keys sorted, so the same result always
+/// serializes to the same string and can be cached and compared.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CalculationResult {
+    /// The version of this JSON shape, [`RESULT_SCHEMA_VERSION`]. Results
+    /// saved before it was written are version 1.
+    #[serde(default = "first_schema_version")]
+    pub schema_version: u32,
+    /// The computed value as a string.
+    pub result: String,
+    /// The input interpreted in links notation format.
+    pub lino_interpretation: String,
+    /// Alternative links notation interpretations the user can switch between.
+    /// The first element is always the currently selected (default) interpretation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternative_lino: Option<Vec<String>>,
+    /// Step-by-step explanation of the calculation (raw text for backwards compatibility).
+    pub steps: Vec<String>,
+    /// Step-by-step explanation with i18n support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps_i18n: Option<Vec<CalculationStep>>,
+    /// Whether the calculation was successful.
+    pub success: bool,
+    /// Error message if calculation failed (raw text for backwards compatibility).
+    pub error: Option<String>,
+    /// Error information for i18n support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_info: Option<ErrorInfo>,
+    /// Link to create an issue for unrecognized input.
+    pub issue_link: Option<String>,
+    /// LaTeX representation of the input (for rendering mathematical formulas).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latex_input: Option<String>,
+    /// LaTeX representation of the result (for rendering mathematical formulas).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latex_result: Option<String>,
+    /// Whether this is a symbolic result (e.g., indefinite integral).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_symbolic: Option<bool>,
+    /// Plot data points for graphing (x, y pairs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plot_data: Option<PlotData>,
+    /// Repeating decimal notations (if the result is a repeating decimal).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeating_decimal: Option<RepeatingDecimalFormats>,
+    /// Fraction representation of the result (if applicable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraction: Option<String>,
+    /// Whether the result represents a live (auto-updating) time expression.
+    /// When `true`, the frontend should periodically re-calculate the expression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_live_time: Option<bool>,
+    /// Structured datetime metadata for browser-local and UTC conversion display.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime_result: Option<DateTimeResult>,
+    /// The integer result written in other number bases.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bases: Option<BaseRepresentations>,
+    /// A plain number result written in other notations: decimal, fraction,
+    /// scientific, engineering, percentage and prime factors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<Representation>,
+    /// All plotted series, one per function (e.g., `plot sin(x), cos(x) from 0 to 5`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plots: Option<Vec<PlotData>>,
+    /// The links notation interpretation decomposed into doublet links (each
+    /// with exactly a source and a target), one per line with the root last.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lino_doublets: Option<String>,
+    /// The parsed expression tree, included when requested with
+    /// [`crate::Calculator::set_include_ast`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ast: Option<Expression>,
+    /// The part of the input a parse error points at, in characters, so the
+    /// frontend can underline it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_span: Option<ErrorSpan>,
+    /// When evaluation failed part-way, the input with every part that did
+    /// evaluate replaced by its value (e.g., "(84 USD + 10 ABC) * 2 + 7").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_result: Option<String>,
+    /// The subexpressions that kept `partial_result` from being evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unresolved: Option<Vec<UnresolvedExpression>>,
+    /// Caveats about an otherwise successful result, such as an exchange rate
+    /// from long before the requested date.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Every exchange rate the calculation used, with the date asked for
+    /// and the date of the rate, in the order the conversions were made.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rates_used: Vec<RateUsage>,
+    /// A breakdown of the result, such as the periods of `amortize(...)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table: Option<ResultTable>,
+    /// The labelled parts of the result, such as net, VAT and gross.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<Vec<BreakdownItem>>,
+    /// The computation graph of the result: one step per operation, with
+    /// the ids of its operands and any exchange rates it used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_steps: Option<Vec<StructuredStep>>,
+    /// The result before an amount of money was rounded to the decimals of
+    /// its currency, when rounding changed it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_result: Option<String>,
+    /// How long lexing, parsing and evaluation took, at the debug steps
+    /// level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase_timings: Option<PhaseTimings>,
+}
+
+/// The schema version of results saved without one.
+const fn first_schema_version() -> u32 {
+    1
+}
+
+/// Reads a result saved as JSON, such as one cached by an earlier version.
+///
+/// Fails for JSON that is not a result, or a result written by a newer
+/// calculator with a schema this one doesn't know.
+pub fn result_from_json(json: &str) -> Result<CalculationResult, String> {
+    let result: CalculationResult =
+        serde_json::from_str(json).map_err(|e| format!("Invalid result JSON: {e}"))?;
+    if result.schema_version > RESULT_SCHEMA_VERSION {
+        return Err(format!(
+            "Result schema version {} is newer than the supported version {}",
+            result.schema_version, RESULT_SCHEMA_VERSION
+        ));
+    }
+    Ok(result)
+}
+
+impl CalculationResult {
+    /// Creates a successful calculation result.
+    #[must_use]
+    pub fn success(result: String, lino: String, steps: Vec<String>) -> Self {
+        let lino_doublets = lino_to_doublets(&lino);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: lino,
+            alternative_lino: None,
+            steps,
+            steps_i18n: None,
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a successful calculation result with rational value information.
+    #[must_use]
+    pub fn success_with_value(value: &Value, lino: String, steps: Vec<String>) -> Self {
+        let result = value.to_display_string();
+
+        // Extract repeating decimal and fraction info if available
+        let (repeating_decimal, fraction) = if let Some(rational) = value.as_rational() {
+            let fraction = if rational.is_integer() {
+                None
+            } else {
+                value.to_fraction_string()
+            };
+
+            let repeating =
+                rational
+                    .to_repeating_decimal_notation()
+                    .map(|rd| RepeatingDecimalFormats {
+                        vinculum: rd.to_vinculum_notation(),
+                        parenthesis: rd.to_parenthesis_notation(),
+                        ellipsis: rd.to_ellipsis_notation(),
+                        latex: rd.to_latex(),
+                        fraction: rational.to_fraction_string(),
+                    });
+
+            (repeating, fraction)
+        } else {
+            (None, None)
+        };
+        let datetime_result = if let ValueKind::DateTime(dt) = &value.kind {
+            DateTimeResult::from_datetime(dt)
+        } else {
+            None
+        };
+
+        let lino_doublets = lino_to_doublets(&lino);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: lino,
+            alternative_lino: None,
+            steps,
+            steps_i18n: None,
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal,
+            fraction,
+            is_live_time: None,
+            datetime_result,
+            bases: BaseRepresentations::from_value(value),
+            alternatives: Representation::all(value),
+            plots: None,
+            lino_doublets,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a successful calculation result with i18n step info.
+    #[must_use]
+    pub fn success_with_i18n(
+        result: String,
+        lino: String,
+        steps: Vec<String>,
+        steps_i18n: Vec<CalculationStep>,
+    ) -> Self {
+        let lino_doublets = lino_to_doublets(&lino);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: lino,
+            alternative_lino: None,
+            steps,
+            steps_i18n: Some(steps_i18n),
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a successful calculation result with LaTeX formatting.
+    #[must_use]
+    pub fn success_with_latex(
+        result: String,
+        lino: String,
+        steps: Vec<String>,
+        latex_input: Option<String>,
+        latex_result: Option<String>,
+    ) -> Self {
+        let lino_doublets = lino_to_doublets(&lino);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: lino,
+            alternative_lino: None,
+            steps,
+            steps_i18n: None,
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input,
+            latex_result,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a symbolic result (e.g., for indefinite integrals).
+    #[must_use]
+    pub fn symbolic(
+        expression: &str,
+        result: String,
+        latex_input: String,
+        latex_result: String,
+        plot_data: Option<PlotData>,
+    ) -> Self {
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: expression.to_string(),
+            alternative_lino: None,
+            steps: vec![
+                format!("Input: {}", expression),
+                "Computed symbolic result".to_string(),
+            ],
+            steps_i18n: None,
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input: Some(latex_input),
+            latex_result: Some(latex_result),
+            is_symbolic: Some(true),
+            plot_data,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets: None,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a plot result with one series per plotted function.
+    ///
+    /// `plot_data` holds the first series for consumers that draw a single plot.
+    #[must_use]
+    pub fn plot(expression: &str, result: String, plots: Vec<PlotData>) -> Self {
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result,
+            lino_interpretation: expression.to_string(),
+            alternative_lino: None,
+            steps: vec![
+                format!("Input: {}", expression),
+                format!("Plotted {} function(s)", plots.len()),
+            ],
+            steps_i18n: None,
+            success: true,
+            error: None,
+            error_info: None,
+            issue_link: None,
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: plots.first().cloned(),
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: Some(plots),
+            lino_doublets: None,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a failed calculation result.
+    #[must_use]
+    pub fn failure(error: String, input: &str) -> Self {
+        let issue_link = generate_issue_link(input, &error);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result: String::new(),
+            lino_interpretation: String::new(),
+            alternative_lino: None,
+            steps: Vec::new(),
+            steps_i18n: None,
+            success: false,
+            error: Some(error),
+            error_info: None,
+            issue_link: Some(issue_link),
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets: None,
+            ast: None,
+            error_span: None,
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Creates a failed calculation result with i18n error info.
+    #[must_use]
+    pub fn failure_with_i18n(error: &CalculatorError, input: &str) -> Self {
+        let error_string = error.to_string();
+        let issue_link = generate_issue_link(input, &error_string);
+        Self {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result: String::new(),
+            lino_interpretation: String::new(),
+            alternative_lino: None,
+            steps: Vec::new(),
+            steps_i18n: None,
+            success: false,
+            error: Some(error_string),
+            error_info: Some(error.to_error_info()),
+            issue_link: Some(issue_link),
+            latex_input: None,
+            latex_result: None,
+            is_symbolic: None,
+            plot_data: None,
+            repeating_decimal: None,
+            fraction: None,
+            is_live_time: None,
+            datetime_result: None,
+            bases: None,
+            alternatives: Vec::new(),
+            plots: None,
+            lino_doublets: None,
+            ast: None,
+            error_span: error.span(),
+            partial_result: None,
+            unresolved: None,
+            warnings: Vec::new(),
+            rates_used: Vec::new(),
+            table: None,
+            breakdown: None,
+            structured_steps: None,
+            raw_result: None,
+            phase_timings: None,
+        }
+    }
+
+    /// Attaches the best-effort result of an evaluation that failed part-way.
+    #[must_use]
+    pub fn with_partial_evaluation(mut self, partial: PartialEvaluation) -> Self {
+        self.partial_result = Some(partial.expression.to_string());
+        self.unresolved = Some(
+            partial
+                .unresolved
+                .into_iter()
+                .map(|(expression, error)| UnresolvedExpression {
+                    expression: expression.to_string(),
+                    error: error.to_string(),
+                    error_info: error.to_error_info(),
+                })
+                .collect(),
+        );
+        self
+    }
+}
@@ -6,17 +6,19 @@ impl Calculator {
     pub(super) fn substitute_variable(
         expr: &types::Expression,
         var: &str,
-        value: f64,
+        value: Decimal,
     ) -> types::Expression {
         match expr {
-            Expression::Variable(name) if name == var => {
-                Expression::number(Decimal::from_f64(value))
-            }
+            Expression::Variable(name) if name == var => Expression::number(value),
             Expression::Variable(_)
             | Expression::Number { .. }
+            | Expression::Text(_)
             | Expression::DateTime(_)
             | Expression::Now
-            | Expression::Today => expr.clone(),
+            | Expression::Today
+            | Expression::HistoryRef(_)
+            | Expression::HistoryRange(..)
+            | Expression::RateLookup { .. } => expr.clone(),
             Expression::Until(inner) => {
                 Expression::Until(Box::new(Self::substitute_variable(inner, var, value)))
             }
@@ -59,6 +61,10 @@ impl Calculator {
                 Self::substitute_variable(v, var, value),
                 target_unit.clone(),
             ),
+            Expression::WithPrecision {
+                value: v,
+                precision,
+            } => Expression::with_precision(Self::substitute_variable(v, var, value), *precision),
             Expression::Equality { left, right } => Expression::equality(
                 Self::substitute_variable(left, var, value),
                 Self::substitute_variable(right, var, value),
@@ -68,6 +74,12 @@ impl Calculator {
                 *op,
                 Self::substitute_variable(right, var, value),
             ),
+            Expression::List(items) => Expression::list(
+                items
+                    .iter()
+                    .map(|item| Self::substitute_variable(item, var, value))
+                    .collect(),
+            ),
         }
     }
 }
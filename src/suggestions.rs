@@ -0,0 +1,39 @@
+//! Function suggestions on the [`Calculator`], for completing a function
+//! name as the user types it.
+
+use crate::function_registry::{functions_starting_with, FunctionInfo};
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Suggests the functions whose name starts with the word at the end of
+    /// `input`, returning a JSON array of [`FunctionInfo`].
+    ///
+    /// `bm` suggests `bmi` and `bmr`; an input that does not end in a word
+    /// suggests nothing.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn suggest(&self, input: &str) -> String {
+        let suggestions = self.suggest_internal(input);
+        serde_json::to_string(&suggestions).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+impl Calculator {
+    /// Internal suggestion method — completes the word at the end of the
+    /// input from the function registry.
+    #[must_use]
+    pub fn suggest_internal(&self, input: &str) -> Vec<FunctionInfo> {
+        let start = input
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |index| {
+                index + input[index..].chars().next().map_or(1, char::len_utf8)
+            });
+        let word = &input[start..];
+        if !word.starts_with(|c: char| c.is_alphabetic()) {
+            return Vec::new();
+        }
+        functions_starting_with(word).into_iter().copied().collect()
+    }
+}
@@ -0,0 +1,23 @@
+//! Locks shared between the calculations of one calculator.
+//!
+//! The rate store, the history, the parse cache and the hooks are each
+//! changed by whole entries, so a panic while one is locked cannot leave it
+//! half-updated. These helpers therefore take a poisoned lock rather than
+//! passing the panic on to every later calculation.
+
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Locks `mutex`, even if a panic poisoned it.
+pub fn lock_recover<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Locks `lock` for reading, even if a panic poisoned it.
+pub fn read_recover<T: ?Sized>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Locks `lock` for writing, even if a panic poisoned it.
+pub fn write_recover<T: ?Sized>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,109 @@
+//! Telemetry hooks on the [`Calculator`]: an [`EvaluationEvent`] after
+//! every calculation, so a host can aggregate what kinds of input fail.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+
+use crate::sync::lock_recover;
+use crate::types::{EvaluationEvent, PhaseTimings, Telemetry};
+use crate::{CalculationResult, Calculator};
+#[cfg(feature = "wasm")]
+use std::cell::RefCell;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm")]
+thread_local! {
+    /// The function given to `set_telemetry_callback`. JavaScript functions
+    /// cannot be sent to other threads, so the receiver, which can, looks it
+    /// up here.
+    static ON_EVENT: RefCell<Option<js_sys::Function>> = const { RefCell::new(None) };
+}
+
+/// The telemetry receiver of a calculator, if any, shared by its
+/// calculations.
+#[derive(Clone, Default)]
+pub struct TelemetryHook(Arc<Mutex<Option<Box<dyn Telemetry>>>>);
+
+impl TelemetryHook {
+    /// Locks the receiver. A receiver that panicked is still called again.
+    fn receiver(&self) -> MutexGuard<'_, Option<Box<dyn Telemetry>>> {
+        lock_recover(&self.0)
+    }
+}
+
+impl fmt::Debug for TelemetryHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TelemetryHook")
+            .field(&self.receiver().as_ref().map(|_| "telemetry"))
+            .finish()
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl Calculator {
+    /// Calls `on_event` after every calculation with an
+    /// `{input_length, expression_kind, duration_ms, success, error_code}`
+    /// object. The input itself is never passed.
+    #[wasm_bindgen]
+    pub fn set_telemetry_callback(&mut self, on_event: &js_sys::Function) {
+        ON_EVENT.with(|function| *function.borrow_mut() = Some(on_event.clone()));
+        self.set_telemetry(|event: &EvaluationEvent| {
+            let event = serde_wasm_bindgen::to_value(event).unwrap_or(JsValue::NULL);
+            ON_EVENT.with(|function| {
+                if let Some(on_event) = function.borrow().as_ref() {
+                    let _ = on_event.call1(&JsValue::NULL, &event);
+                }
+            });
+        });
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Stops sending telemetry after [`Self::set_telemetry`] or
+    /// `set_telemetry_callback`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn clear_telemetry(&mut self) {
+        *self.telemetry.receiver() = None;
+    }
+}
+
+impl Calculator {
+    /// Sends an [`EvaluationEvent`] to `telemetry` after every calculation,
+    /// replacing any earlier receiver.
+    pub fn set_telemetry(&mut self, telemetry: impl Telemetry + 'static) {
+        *self.telemetry.receiver() = Some(Box::new(telemetry));
+    }
+
+    /// Returns the time a calculation starts at, when telemetry is on.
+    pub(crate) fn telemetry_start(&self) -> Option<DateTime<Utc>> {
+        self.telemetry
+            .receiver()
+            .as_ref()
+            .map(|_| self.parser.clock_now())
+    }
+
+    /// Sends the event of a calculation of `input` started at `started`.
+    pub(crate) fn record_telemetry(
+        &self,
+        input: &str,
+        started: DateTime<Utc>,
+        expression_kind: Option<&str>,
+        result: &CalculationResult,
+    ) {
+        let event = EvaluationEvent {
+            input_length: input.trim().chars().count(),
+            expression_kind: expression_kind.map(str::to_string),
+            duration_ms: PhaseTimings::millis_between(started, self.parser.clock_now()),
+            success: result.success,
+            error_code: result.error_info.as_ref().map(|info| info.key.clone()),
+        };
+        if let Some(telemetry) = self.telemetry.receiver().as_mut() {
+            telemetry.record(&event);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Property-test generators for expressions, enabled by the `testing`
+//! feature.
+//!
+//! [`expression`] generates trees whose printed form the parser reads back
+//! as the same tree, so a property test can check that every generated
+//! expression round-trips through [`Expression`]'s `Display` and
+//! [`crate::grammar::ExpressionParser::parse`]. Compound operands are always
+//! parenthesized, so the printed form never depends on precedence.
+//! [`input`] generates text that looks like calculator input, for checking
+//! that parsing never panics. [`corpus`] runs the expressions of filed
+//! issues against the results they should give.
+
+pub mod corpus;
+
+use chrono::NaiveDate;
+use proptest::prelude::*;
+
+use crate::types::{BinaryOp, DateTime, Decimal, Expression, Unit};
+
+/// Currencies amounts are generated in.
+const CURRENCIES: &[&str] = &["USD", "EUR", "GBP", "JPY", "RUB"];
+
+/// Functions calls are generated for, with their argument counts.
+const FUNCTIONS: &[(&str, usize)] = &[("sqrt", 1), ("abs", 1), ("sin", 1), ("max", 2), ("min", 2)];
+
+/// Words of the grammar that [`input`] mixes with numbers and symbols.
+const WORDS: &[&str] = &[
+    "USD",
+    "EUR",
+    "as",
+    "in",
+    "to",
+    "at",
+    "of",
+    "now",
+    "today",
+    "until",
+    "ans",
+    "sqrt",
+    "integrate",
+    "dx",
+    "x",
+    "days",
+    "KB",
+    "UTC",
+    "Jan",
+    "rate",
+    "dp",
+    "and",
+];
+
+/// Non-negative numbers with up to four decimal places, such as `42` or
+/// `3.1416`.
+pub fn number() -> impl Strategy<Value = Decimal> {
+    (0_u32..100_000, 0_u32..5).prop_map(|(digits, scale)| {
+        let text = digits.to_string();
+        let scale = (scale as usize).min(text.len() - 1);
+        let (whole, fraction) = text.split_at(text.len() - scale);
+        let literal = if fraction.is_empty() {
+            whole.to_string()
+        } else {
+            format!("{whole}.{fraction}")
+        };
+        literal.parse().unwrap_or_else(|_| Decimal::new(0))
+    })
+}
+
+/// ISO dates such as `2026-01-22`, between 1970 and 2099.
+pub fn iso_date() -> impl Strategy<Value = DateTime> {
+    (1970_i32..2100, 1_u32..13, 1_u32..29).prop_map(|(year, month, day)| {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map_or_else(|| DateTime::from_date(NaiveDate::MIN), DateTime::from_date)
+    })
+}
+
+/// Numbers, currency amounts and dates.
+pub fn leaf() -> impl Strategy<Value = Expression> {
+    prop_oneof![
+        4 => number().prop_map(Expression::number),
+        2 => (number(), prop::sample::select(CURRENCIES))
+            .prop_map(|(amount, code)| Expression::currency(amount, code)),
+        1 => iso_date().prop_map(Expression::DateTime),
+    ]
+}
+
+/// Expression trees up to `depth` levels deep built from [`leaf`]s with
+/// arithmetic, powers, negation, function calls and currency conversion.
+pub fn expression_with_depth(depth: u32) -> impl Strategy<Value = Expression> {
+    leaf().prop_recursive(depth, 64, 2, |inner| {
+        let operand = inner.clone().prop_map(parenthesize);
+        prop_oneof![
+            (
+                operand.clone(),
+                prop::sample::select(vec![
+                    BinaryOp::Add,
+                    BinaryOp::Subtract,
+                    BinaryOp::Multiply,
+                    BinaryOp::Divide,
+                ]),
+                operand.clone(),
+            )
+                .prop_map(|(left, op, right)| Expression::binary(left, op, right)),
+            (operand.clone(), operand.clone())
+                .prop_map(|(base, exponent)| Expression::power(base, exponent)),
+            inner
+                .clone()
+                .prop_map(|expr| Expression::negate(Expression::group(expr))),
+            (
+                prop::sample::select(FUNCTIONS),
+                prop::collection::vec(inner.clone(), 2)
+            )
+                .prop_map(|((name, arity), mut args)| {
+                    args.truncate(arity);
+                    Expression::function_call(name, args)
+                }),
+            (operand, prop::sample::select(CURRENCIES)).prop_map(|(value, code)| {
+                Expression::unit_conversion(value, Unit::currency(code))
+            }),
+            inner.prop_map(Expression::group),
+        ]
+    })
+}
+
+/// Expression trees up to four levels deep (see [`expression_with_depth`]).
+pub fn expression() -> impl Strategy<Value = Expression> {
+    expression_with_depth(4)
+}
+
+/// Text made of the characters, words and literals calculator input uses,
+/// valid or not.
+pub fn input() -> impl Strategy<Value = String> {
+    let token = prop_oneof![
+        "[0-9]{1,4}(\\.[0-9]{1,3})?",
+        "[-+*/^%!=<>(),\\[\\]#$€:.]",
+        "[0-9]{4}-[0-9]{2}-[0-9]{2}",
+        "[0-9]{1,2}:[0-9]{2}(am|pm)?",
+        prop::sample::select(WORDS).prop_map(str::to_string),
+    ];
+    prop::collection::vec(token, 0..12).prop_map(|tokens| tokens.join(" "))
+}
+
+/// Wraps compound expressions in parentheses, so they print as a single
+/// operand.
+fn parenthesize(expr: Expression) -> Expression {
+    match expr {
+        Expression::Binary { .. }
+        | Expression::Power { .. }
+        | Expression::Negate(_)
+        | Expression::UnitConversion { .. } => Expression::group(expr),
+        Expression::Number { ref unit, .. } if *unit != Unit::None => Expression::group(expr),
+        Expression::DateTime(_) => Expression::group(expr),
+        _ => expr,
+    }
+}
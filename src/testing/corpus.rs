@@ -0,0 +1,175 @@
+//! Regression fixtures from the case studies in `docs/case-studies`: each
+//! filed issue's expression with the result it should give, so that every
+//! fixed issue stays fixed.
+//!
+//! The corpus is links notation with one link per case, named by the issue,
+//! holding the quoted input and expected result:
+//!
+//! ```text
+//! (issue-132: "63!" "1982608315404440064116146708361898137544773690227268628106279599612729753600000000000000")
+//! (issue-145: "8% of $50" "4 USD")
+//! ```
+//!
+//! A JSON array of `{"case", "input", "expected"}` objects is read too.
+//! [`run_corpus`] calculates every case on a fresh [`Calculator`] and
+//! reports the ones whose result differs.
+
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::lino::{unquote, LinkRef, LinoParser};
+use crate::Calculator;
+
+/// An expression of the corpus with the result it should give.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorpusCase {
+    /// Where the case comes from, such as `issue-145`.
+    pub case: String,
+    /// The input to calculate.
+    pub input: String,
+    /// The expected result text, units included.
+    pub expected: String,
+}
+
+/// A case whose result differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CorpusMismatch {
+    /// The case that failed.
+    pub case: CorpusCase,
+    /// The result it gave, or `error: ` and the message if it failed.
+    pub actual: String,
+}
+
+/// The outcome of [`run_corpus`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CorpusReport {
+    /// How many cases gave their expected result.
+    pub passed: usize,
+    /// The cases that did not.
+    pub mismatches: Vec<CorpusMismatch>,
+}
+
+impl CorpusReport {
+    /// Returns true if every case gave its expected result.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for CorpusReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} passed, {} failed",
+            self.passed,
+            self.mismatches.len()
+        )?;
+        for mismatch in &self.mismatches {
+            writeln!(
+                f,
+                "{}: {}\n  expected: {}\n  actual:   {}",
+                mismatch.case.case, mismatch.case.input, mismatch.case.expected, mismatch.actual
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a corpus file, links notation or JSON.
+pub fn load_corpus(path: impl AsRef<Path>) -> Result<Vec<CorpusCase>, String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read corpus {}: {e}", path.display()))?;
+    parse_corpus(&text)
+}
+
+/// Parses a corpus, read as JSON when it starts with `[` and as links
+/// notation otherwise.
+pub fn parse_corpus(text: &str) -> Result<Vec<CorpusCase>, String> {
+    if text.trim_start().starts_with('[') {
+        return serde_json::from_str(text).map_err(|e| format!("Invalid corpus JSON: {e}"));
+    }
+    LinoParser::new()
+        .parse(text)?
+        .into_iter()
+        .map(|link| match (link.id.clone(), link.refs.as_slice()) {
+            (Some(case), [LinkRef::Literal(input), LinkRef::Literal(expected)]) => Ok(CorpusCase {
+                input: unquote(input).ok_or_else(|| format!("{case}: the input must be quoted"))?,
+                expected: unquote(expected)
+                    .ok_or_else(|| format!("{case}: the expected result must be quoted"))?,
+                case,
+            }),
+            _ => Err(format!(
+                "Expected (case: \"input\" \"expected\"), found {}",
+                link.to_lino()
+            )),
+        })
+        .collect()
+}
+
+/// Calculates every case on a fresh calculator and collects the ones whose
+/// result differs from the expected one.
+#[must_use]
+pub fn run_corpus(cases: &[CorpusCase]) -> CorpusReport {
+    let mut report = CorpusReport::default();
+    for case in cases {
+        let result = Calculator::new().calculate_internal(&case.input);
+        let actual = if result.success {
+            result.result
+        } else {
+            format!("error: {}", result.error.unwrap_or_default())
+        };
+        if actual == case.expected {
+            report.passed += 1;
+        } else {
+            report.mismatches.push(CorpusMismatch {
+                case: case.clone(),
+                actual,
+            });
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lino_corpus() {
+        let cases =
+            parse_corpus("(issue-145: \"8% of $50\" \"4 USD\")\n(quote: \"\\\"a b\\\"\" \"x\")")
+                .unwrap();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].case, "issue-145");
+        assert_eq!(cases[0].input, "8% of $50");
+        assert_eq!(cases[0].expected, "4 USD");
+        assert_eq!(cases[1].input, "\"a b\"");
+    }
+
+    #[test]
+    fn test_parse_json_corpus() {
+        let cases =
+            parse_corpus(r#"[{"case": "issue-132", "input": "5!", "expected": "120"}]"#).unwrap();
+        assert_eq!(cases[0].input, "5!");
+    }
+
+    #[test]
+    fn test_malformed_case_is_an_error() {
+        assert!(parse_corpus("(issue-1: \"2 + 2\")").is_err());
+        assert!(parse_corpus("(issue-1: 4 \"4\")").is_err());
+    }
+
+    #[test]
+    fn test_run_reports_mismatches() {
+        let cases = parse_corpus("(ok: \"2 + 2\" \"4\")\n(wrong: \"2 + 3\" \"4\")").unwrap();
+        let report = run_corpus(&cases);
+        assert_eq!(report.passed, 1);
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].actual, "5");
+        assert!(report.to_string().contains("wrong: 2 + 3"));
+    }
+}
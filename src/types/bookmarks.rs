@@ -0,0 +1,147 @@
+//! Named saved expressions, so frequently used formulas such as a salary
+//! conversion can be recalled by name and calculated again with the rates
+//! of the day.
+//!
+//! They are kept as links notation, one link per expression:
+//!
+//! ```text
+//! (salary: "5000 USD as EUR")
+//! (rent_share: "1200 EUR / 3")
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::CalculatorError;
+use crate::lino::{quote, unquote, LinkRef, LinoParser};
+
+/// Inputs saved under a name, listed by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bookmarks {
+    expressions: BTreeMap<String, String>,
+}
+
+impl Bookmarks {
+    /// Creates an empty set of bookmarks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saves `input` under `name`, replacing what was saved there.
+    ///
+    /// Names start with a letter and hold letters, digits, `_` and `-`.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        input: impl Into<String>,
+    ) -> Result<(), CalculatorError> {
+        let name = name.into();
+        if !is_valid_name(&name) {
+            return Err(CalculatorError::eval(format!(
+                "Invalid bookmark name '{name}': use a letter followed by letters, digits, _ or -"
+            )));
+        }
+        self.expressions.insert(name, input.into());
+        Ok(())
+    }
+
+    /// Returns the input saved under `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.expressions.get(name).map(String::as_str)
+    }
+
+    /// Forgets the input saved under `name`, returning it.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.expressions.remove(name)
+    }
+
+    /// Returns the names and saved inputs, by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.expressions
+            .iter()
+            .map(|(name, input)| (name.as_str(), input.as_str()))
+    }
+
+    /// Returns the number of saved expressions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.expressions.len()
+    }
+
+    /// Returns true if nothing has been saved.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.expressions.is_empty()
+    }
+
+    /// Writes the bookmarks as links notation, one `(name: "input")` line
+    /// each.
+    #[must_use]
+    pub fn to_lino(&self) -> String {
+        let mut lino = String::new();
+        for (name, input) in self.iter() {
+            let _ = writeln!(lino, "({name}: {})", quote(input));
+        }
+        lino
+    }
+
+    /// Reads bookmarks written by [`Self::to_lino`].
+    pub fn from_lino(text: &str) -> Result<Self, String> {
+        let mut bookmarks = Self::new();
+        for link in LinoParser::new().parse(text)? {
+            let input = match (&link.id, link.refs.as_slice()) {
+                (Some(_), [LinkRef::Literal(input)]) => unquote(input),
+                _ => None,
+            };
+            let (Some(name), Some(input)) = (link.id.clone(), input) else {
+                return Err(format!(
+                    "Expected (name: \"input\"), found {}",
+                    link.to_lino()
+                ));
+            };
+            bookmarks
+                .insert(name, input)
+                .map_err(|error| error.to_string())?;
+        }
+        Ok(bookmarks)
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(char::is_alphabetic)
+        && chars.all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lino_round_trip() {
+        let mut bookmarks = Bookmarks::new();
+        bookmarks.insert("salary", "5000 USD as EUR").unwrap();
+        bookmarks
+            .insert("quoted", "length(\"a \\\"b\\\"\")")
+            .unwrap();
+        let lino = bookmarks.to_lino();
+        assert!(lino.contains("(salary: \"5000 USD as EUR\")\n"), "{lino}");
+        assert_eq!(Bookmarks::from_lino(&lino).unwrap(), bookmarks);
+    }
+
+    #[test]
+    fn test_invalid_names_and_documents() {
+        let mut bookmarks = Bookmarks::new();
+        assert!(bookmarks.insert("", "1").is_err());
+        assert!(bookmarks.insert("two words", "1").is_err());
+        assert!(bookmarks.insert("1st", "1").is_err());
+        assert!(bookmarks.insert("net-pay_2", "1").is_ok());
+        assert!(Bookmarks::from_lino("(salary: 5000)").is_err());
+        assert!(Bookmarks::from_lino("(\"5000 USD\")").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Cooperative cancellation of a running calculation.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A flag a host sets to stop a calculation mid-way, e.g. from a Ctrl-C
+/// handler. Clones share the flag.
+///
+/// The evaluator checks it on every evaluation step and in integration and
+/// plotting loops, and stops with
+/// [`crate::error::CalculatorError::Cancelled`] once it is set.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks calculations using this token to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true once [`Self::cancel`] has been called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Clears the flag, so the token can be reused for the next calculation.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,72 @@
+//! The source of the current time for `now`, `today` and dated rates.
+//!
+//! A calculator reads the system clock unless it is given another one, so
+//! tests can fix the time and server-side rendering can render with the
+//! time of the request.
+
+use chrono::{DateTime as ChronoDateTime, Utc};
+use std::fmt;
+
+/// Tells the calculator what time it is, from whichever thread evaluates.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> ChronoDateTime<Utc>;
+}
+
+/// The clock of the machine: the JavaScript `Date` in WebAssembly builds
+/// with the `wasm` feature, the operating system clock otherwise.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    fn now(&self) -> ChronoDateTime<Utc> {
+        #[allow(clippy::cast_possible_truncation)] // milliseconds since 1970 fit in i64
+        let millis = js_sys::Date::now() as i64;
+        ChronoDateTime::from_timestamp_millis(millis).unwrap_or_else(Utc::now)
+    }
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    fn now(&self) -> ChronoDateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(ChronoDateTime<Utc>);
+
+impl FixedClock {
+    /// Creates a clock that always reads `instant`.
+    #[must_use]
+    pub const fn new(instant: ChronoDateTime<Utc>) -> Self {
+        Self(instant)
+    }
+
+    /// Creates a clock from an RFC 3339 time such as
+    /// `2026-03-15T12:00:00Z`, or returns `None` if it is not one.
+    #[must_use]
+    pub fn from_rfc3339(time: &str) -> Option<Self> {
+        ChronoDateTime::parse_from_rfc3339(time)
+            .ok()
+            .map(|time| Self(time.with_timezone(&Utc)))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> ChronoDateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock::from_rfc3339("2026-03-15T14:30:00+02:00").unwrap();
+        assert_eq!(clock.now().to_rfc3339(), "2026-03-15T12:30:00+00:00");
+        assert_eq!(FixedClock::from_rfc3339("15 March"), None);
+    }
+}
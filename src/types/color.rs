@@ -0,0 +1,252 @@
+//! Colors such as `#ff8800`, and the models they can be written in.
+//!
+//! A color value is its 24-bit `0xRRGGBB` number with a [`ColorModel`] unit,
+//! the way `255 in hex` is a number with a radix unit, so `#ff8800 in rgb`
+//! only changes how it is written.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a color is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorModel {
+    /// `#ff8800`
+    Hex,
+    /// `rgb(255, 136, 0)`
+    Rgb,
+    /// `hsl(32, 100%, 50%)`
+    Hsl,
+}
+
+impl ColorModel {
+    /// Parses a conversion target name: `rgb` or `hsl`. `hex` is read as a
+    /// radix and applied to colors when converting.
+    #[must_use]
+    pub fn parse_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "rgb" => Some(Self::Rgb),
+            "hsl" => Some(Self::Hsl),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ColorModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Hex => "hex",
+            Self::Rgb => "rgb",
+            Self::Hsl => "hsl",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// An sRGB color with 8-bit channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// Red, 0 to 255.
+    pub r: u8,
+    /// Green, 0 to 255.
+    pub g: u8,
+    /// Blue, 0 to 255.
+    pub b: u8,
+}
+
+impl Color {
+    /// Creates a color from its channels.
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parses the digits of a hex color without the `#`: `ff8800` or the
+    /// short form `f80`.
+    #[must_use]
+    pub fn parse_hex(digits: &str) -> Option<Self> {
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+        match digits.len() {
+            6 => Some(Self::new(
+                channel(&digits[0..2])?,
+                channel(&digits[2..4])?,
+                channel(&digits[4..6])?,
+            )),
+            3 => {
+                let doubled: String = digits.chars().flat_map(|c| [c, c]).collect();
+                Self::parse_hex(&doubled)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the color of a `0xRRGGBB` number, or `None` if it is out of
+    /// range.
+    #[must_use]
+    pub fn from_number(n: u32) -> Option<Self> {
+        let [_, r, g, b] = n.to_be_bytes();
+        (n <= 0xFF_FFFF).then_some(Self::new(r, g, b))
+    }
+
+    /// Returns the color as a `0xRRGGBB` number.
+    #[must_use]
+    pub fn to_number(self) -> u32 {
+        u32::from_be_bytes([0, self.r, self.g, self.b])
+    }
+
+    /// Creates a color from hue in degrees and saturation and lightness
+    /// from 0 to 1.
+    #[must_use]
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - 2.0f64.mul_add(lightness, -1.0).abs()) * saturation;
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (red, green, blue) = match sector {
+            s if s < 1.0 => (chroma, second, 0.0),
+            s if s < 2.0 => (second, chroma, 0.0),
+            s if s < 3.0 => (0.0, chroma, second),
+            s if s < 4.0 => (0.0, second, chroma),
+            s if s < 5.0 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let lowest = lightness - chroma / 2.0;
+        Self::new(
+            channel(red + lowest),
+            channel(green + lowest),
+            channel(blue + lowest),
+        )
+    }
+
+    /// Returns hue in degrees and saturation and lightness from 0 to 1.
+    #[must_use]
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let highest = self.r.max(self.g).max(self.b);
+        let lowest = self.r.min(self.g).min(self.b);
+        let [r, g, b, max, min] =
+            [self.r, self.g, self.b, highest, lowest].map(|c| f64::from(c) / 255.0);
+        let lightness = (max + min) / 2.0;
+        if highest == lowest {
+            return (0.0, 0.0, lightness);
+        }
+        let delta = max - min;
+        let saturation = delta / (1.0 - 2.0f64.mul_add(lightness, -1.0).abs());
+        let hue = if highest == self.r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if highest == self.g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        (hue * 60.0, saturation, lightness)
+    }
+
+    /// Mixes with `other`, taking `weight` (0 to 1) of this color.
+    #[must_use]
+    pub fn mix(self, other: Self, weight: f64) -> Self {
+        let w = weight.clamp(0.0, 1.0);
+        let blend =
+            |a: u8, b: u8| channel(f64::from(a).mul_add(w, f64::from(b) * (1.0 - w)) / 255.0);
+        Self::new(
+            blend(self.r, other.r),
+            blend(self.g, other.g),
+            blend(self.b, other.b),
+        )
+    }
+
+    /// Adds `amount` (-1 to 1) to the HSL lightness.
+    #[must_use]
+    pub fn lighten(self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount)
+    }
+
+    /// Adds channel by channel, stopping at 255.
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self::new(
+            self.r.saturating_add(other.r),
+            self.g.saturating_add(other.g),
+            self.b.saturating_add(other.b),
+        )
+    }
+
+    /// Subtracts channel by channel, stopping at 0.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self::new(
+            self.r.saturating_sub(other.r),
+            self.g.saturating_sub(other.g),
+            self.b.saturating_sub(other.b),
+        )
+    }
+
+    /// Multiplies every channel by `factor`, keeping it within 0 to 255.
+    #[must_use]
+    pub fn scale(self, factor: f64) -> Self {
+        let scaled = |c: u8| channel(f64::from(c) * factor / 255.0);
+        Self::new(scaled(self.r), scaled(self.g), scaled(self.b))
+    }
+
+    /// Writes the color in `model`.
+    #[must_use]
+    pub fn format(self, model: ColorModel) -> String {
+        match model {
+            ColorModel::Hex => format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b),
+            ColorModel::Rgb => format!("rgb({}, {}, {})", self.r, self.g, self.b),
+            ColorModel::Hsl => {
+                let (h, s, l) = self.to_hsl();
+                format!(
+                    "hsl({}, {}%, {}%)",
+                    h.round(),
+                    (s * 100.0).round(),
+                    (l * 100.0).round()
+                )
+            }
+        }
+    }
+}
+
+/// Rounds a channel from 0 to 1 to 0 to 255.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to 0..=255
+fn channel(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let orange = Color::parse_hex("ff8800").unwrap();
+        assert_eq!(orange, Color::new(255, 136, 0));
+        assert_eq!(Color::parse_hex("f80"), Some(orange));
+        assert_eq!(orange.format(ColorModel::Hex), "#ff8800");
+        assert_eq!(orange.format(ColorModel::Rgb), "rgb(255, 136, 0)");
+        assert_eq!(orange.format(ColorModel::Hsl), "hsl(32, 100%, 50%)");
+        assert_eq!(Color::parse_hex("ff88"), None);
+        assert_eq!(Color::from_number(orange.to_number()), Some(orange));
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for hex in ["336699", "ff0000", "00ff00", "808080", "123456"] {
+            let color = Color::parse_hex(hex).unwrap();
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color, "{hex}");
+        }
+    }
+
+    #[test]
+    fn test_mix_and_lighten() {
+        let red = Color::new(255, 0, 0);
+        let blue = Color::new(0, 0, 255);
+        assert_eq!(red.mix(blue, 0.5), Color::new(128, 0, 128));
+        let lighter = Color::parse_hex("336699").unwrap().lighten(0.1);
+        assert_eq!(lighter.format(ColorModel::Hex), "#4080bf");
+    }
+}
@@ -1,11 +1,18 @@
 //! Currency types and exchange rate database.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 use crate::error::CalculatorError;
 use crate::types::DateTime;
 
+mod decimals;
+mod historical;
+mod receipt;
+mod store;
+pub use historical::HistoricalRateMode;
+pub use receipt::{Conversion, ConversionReceipt, RateUsage};
+use store::{RateStore, SharedRateStore};
+
 /// Information about an exchange rate, including its source and timestamp.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExchangeRateInfo {
@@ -42,6 +49,13 @@ impl ExchangeRateInfo {
         }
     }
 
+    /// Returns true if this is a built-in rate of unknown date rather than
+    /// one published for a day.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.date == "unknown"
+    }
+
     /// Sets the fetched_at timestamp.
     #[must_use]
     pub fn with_fetched_at(mut self, timestamp: impl Into<String>) -> Self {
@@ -141,40 +155,42 @@ impl Currency {
 }
 
 /// A database of exchange rates, supporting historical data.
-#[derive(Debug, Clone, Default)]
+///
+/// The currencies and rates may be shared with other databases (see
+/// [`Self::shared`]); the historical rate settings belong to this database
+/// alone. Conversions report the rates they used in a [`ConversionReceipt`]
+/// rather than recording them, so they only need a shared reference. Cloning
+/// copies the rates rather than sharing them.
+#[derive(Debug, Default)]
 pub struct CurrencyDatabase {
-    /// Known currencies.
-    currencies: HashMap<String, Currency>,
-    /// Exchange rates with full metadata: (from, to) -> rate info
-    /// Rate means: 1 unit of 'from' = rate units of 'to'
-    rates: HashMap<(String, String), ExchangeRateInfo>,
-    /// Legacy rates map for compatibility (will be deprecated)
-    #[allow(dead_code)]
-    legacy_rates: HashMap<(String, String), f64>,
-    /// Historical rates: (from, to, `date_string`) -> rate info
-    historical_rates: HashMap<(String, String, String), ExchangeRateInfo>,
-    /// All rate infos used in the last conversion (for step display).
-    /// May contain multiple entries for cross-rate (triangulated) conversions.
-    last_used_rates: Vec<(String, String, ExchangeRateInfo)>,
+    /// Known currencies and exchange rates.
+    store: SharedRateStore,
+    /// How historical rates are chosen between known dates.
+    historical_rate_mode: HistoricalRateMode,
+    /// Days a historical rate may be from the requested date without a warning.
+    stale_rate_days: u32,
 }
 
 impl CurrencyDatabase {
     /// Creates a new currency database with default currencies and rates.
     #[must_use]
     pub fn new() -> Self {
-        let mut db = Self {
-            currencies: HashMap::new(),
-            rates: HashMap::new(),
-            legacy_rates: HashMap::new(),
-            historical_rates: HashMap::new(),
-            last_used_rates: Vec::new(),
-        };
+        let db = Self::with_store(SharedRateStore::default());
         db.initialize_default_currencies();
         db.initialize_default_rates();
         db
     }
 
-    fn initialize_default_currencies(&mut self) {
+    /// Creates a database over `store` with default settings.
+    fn with_store(store: SharedRateStore) -> Self {
+        Self {
+            store,
+            historical_rate_mode: HistoricalRateMode::default(),
+            stale_rate_days: 7,
+        }
+    }
+
+    fn initialize_default_currencies(&self) {
         let currencies = vec![
             Currency::usd(),
             Currency::eur(),
@@ -188,12 +204,13 @@ impl CurrencyDatabase {
             Currency::kzt(),
         ];
 
+        let mut store = self.store_mut();
         for currency in currencies {
-            self.currencies.insert(currency.code.clone(), currency);
+            store.currencies.insert(currency.code.clone(), currency);
         }
     }
 
-    fn initialize_default_rates(&mut self) {
+    fn initialize_default_rates(&self) {
         // Default fallback rates - these are used only when API rates are unavailable.
         // In production, rates should be fetched from the currency API.
         // Note: These are approximate rates and may be outdated.
@@ -225,56 +242,60 @@ impl CurrencyDatabase {
         self.set_rate_with_info("GBP", "USD", ExchangeRateInfo::default_rate(1.27));
         self.set_rate_with_info("GBP", "EUR", ExchangeRateInfo::default_rate(1.16));
 
-        // Add some historical rates for demonstration
+        // Add some historical rates for demonstration. They are not the
+        // rates published on those days, so their date is unknown
         self.set_historical_rate_with_info(
             "USD",
             "EUR",
             "2026-01-22",
-            ExchangeRateInfo::new(0.921, "default (hardcoded)", "2026-01-22"),
+            ExchangeRateInfo::default_rate(0.921),
         );
         self.set_historical_rate_with_info(
             "USD",
             "EUR",
             "2026-01-20",
-            ExchangeRateInfo::new(0.918, "default (hardcoded)", "2026-01-20"),
+            ExchangeRateInfo::default_rate(0.918),
         );
         self.set_historical_rate_with_info(
             "USD",
             "EUR",
             "2026-01-15",
-            ExchangeRateInfo::new(0.925, "default (hardcoded)", "2026-01-15"),
+            ExchangeRateInfo::default_rate(0.925),
         );
     }
 
     /// Sets an exchange rate with full metadata.
-    pub fn set_rate_with_info(&mut self, from: &str, to: &str, info: ExchangeRateInfo) {
+    pub fn set_rate_with_info(&self, from: &str, to: &str, info: ExchangeRateInfo) {
         let from_upper = from.to_uppercase();
         let to_upper = to.to_uppercase();
 
-        // Store the forward rate
-        self.rates
-            .insert((from_upper.clone(), to_upper.clone()), info.clone());
+        self.store_mut().insert_rate(from_upper, to_upper, info);
+    }
 
-        // Also add the inverse rate
-        if info.rate != 0.0 {
-            let inverse_info = ExchangeRateInfo {
-                rate: 1.0 / info.rate,
-                source: info.source.clone(),
-                date: info.date.clone(),
-                fetched_at: info.fetched_at,
-            };
-            self.rates.insert((to_upper, from_upper), inverse_info);
+    /// Sets an exchange rate with full metadata unless the pair already has
+    /// a rate other than a hard-coded default. The check and the update
+    /// happen under one lock, so a rate set meanwhile through a database
+    /// sharing this one's store is never overwritten. Returns true if the
+    /// rate was set.
+    pub fn set_rate_if_default(&self, from: &str, to: &str, info: ExchangeRateInfo) -> bool {
+        let key = (from.to_uppercase(), to.to_uppercase());
+        let mut store = self.store_mut();
+        let current = store.rates.get(&key);
+        if current.is_some_and(|current| *current != ExchangeRateInfo::default_rate(current.rate)) {
+            return false;
         }
+        store.insert_rate(key.0, key.1, info);
+        true
     }
 
     /// Sets an exchange rate (legacy method for compatibility).
-    pub fn set_rate(&mut self, from: &str, to: &str, rate: f64) {
+    pub fn set_rate(&self, from: &str, to: &str, rate: f64) {
         self.set_rate_with_info(from, to, ExchangeRateInfo::default_rate(rate));
     }
 
     /// Sets a historical exchange rate with full metadata.
     pub fn set_historical_rate_with_info(
-        &mut self,
+        &self,
         from: &str,
         to: &str,
         date: &str,
@@ -283,7 +304,8 @@ impl CurrencyDatabase {
         let from_upper = from.to_uppercase();
         let to_upper = to.to_uppercase();
 
-        self.historical_rates.insert(
+        let mut store = self.store_mut();
+        store.historical_rates.insert(
             (from_upper.clone(), to_upper.clone(), date.to_string()),
             info.clone(),
         );
@@ -296,13 +318,14 @@ impl CurrencyDatabase {
                 date: info.date.clone(),
                 fetched_at: info.fetched_at,
             };
-            self.historical_rates
+            store
+                .historical_rates
                 .insert((to_upper, from_upper, date.to_string()), inverse_info);
         }
     }
 
     /// Sets a historical exchange rate for a specific date (legacy method).
-    pub fn set_historical_rate(&mut self, from: &str, to: &str, date: &str, rate: f64) {
+    pub fn set_historical_rate(&self, from: &str, to: &str, date: &str, rate: f64) {
         self.set_historical_rate_with_info(
             from,
             to,
@@ -313,11 +336,14 @@ impl CurrencyDatabase {
 
     /// Gets the current exchange rate info.
     #[must_use]
-    pub fn get_rate_info(&self, from: &str, to: &str) -> Option<&ExchangeRateInfo> {
+    pub fn get_rate_info(&self, from: &str, to: &str) -> Option<ExchangeRateInfo> {
         if from.eq_ignore_ascii_case(to) {
             return None; // Same currency, no rate needed
         }
-        self.rates.get(&(from.to_uppercase(), to.to_uppercase()))
+        self.store()
+            .rates
+            .get(&(from.to_uppercase(), to.to_uppercase()))
+            .cloned()
     }
 
     /// Gets the current exchange rate.
@@ -326,23 +352,12 @@ impl CurrencyDatabase {
         if from.eq_ignore_ascii_case(to) {
             return Some(1.0);
         }
-        self.rates
+        self.store()
+            .rates
             .get(&(from.to_uppercase(), to.to_uppercase()))
             .map(|info| info.rate)
     }
 
-    /// Gets all rate infos used in the last conversion (for display in calculation steps).
-    /// Returns multiple entries for cross-rate (triangulated) conversions.
-    #[must_use]
-    pub fn get_last_used_rates(&self) -> &[(String, String, ExchangeRateInfo)] {
-        &self.last_used_rates
-    }
-
-    /// Clears the last used rate info.
-    pub fn clear_last_used_rate(&mut self) {
-        self.last_used_rates.clear();
-    }
-
     /// Gets a historical exchange rate for a specific date.
     #[must_use]
     pub fn get_historical_rate(&self, from: &str, to: &str, date: &DateTime) -> Option<f64> {
@@ -350,54 +365,34 @@ impl CurrencyDatabase {
             return Some(1.0);
         }
 
-        self.get_historical_rate_info(from, to, date)
-            .map(|info| info.rate)
+        self.find_historical_rate(from, to, date)
+            .map(|found| found.info.rate)
     }
 
-    fn get_historical_rate_info(
+    /// Converts an amount from one currency to another, returning the rates
+    /// used in the conversion's receipt.
+    pub fn convert(
         &self,
+        amount: f64,
         from: &str,
         to: &str,
-        date: &DateTime,
-    ) -> Option<&ExchangeRateInfo> {
-        let from_upper = from.to_uppercase();
-        let to_upper = to.to_uppercase();
-        let date_str = format!("{}", date.as_chrono().format("%Y-%m-%d"));
-
-        if let Some(info) =
-            self.historical_rates
-                .get(&(from_upper.clone(), to_upper.clone(), date_str.clone()))
-        {
-            return Some(info);
-        }
-
-        self.historical_rates
-            .iter()
-            .filter_map(|((rate_from, rate_to, rate_date), info)| {
-                if rate_from == &from_upper && rate_to == &to_upper && rate_date <= &date_str {
-                    Some((rate_date, info))
-                } else {
-                    None
-                }
-            })
-            .max_by_key(|(rate_date, _)| *rate_date)
-            .map(|(_, info)| info)
-    }
-
-    /// Converts an amount from one currency to another, tracking the rate used.
-    pub fn convert(&mut self, amount: f64, from: &str, to: &str) -> Result<f64, CalculatorError> {
+    ) -> Result<Conversion, CalculatorError> {
         let from_upper = from.to_uppercase();
         let to_upper = to.to_uppercase();
 
         if from_upper == to_upper {
-            self.last_used_rates.clear();
-            return Ok(amount);
+            return Ok(Conversion::unconverted(amount));
         }
 
-        if let Some(info) = self.rates.get(&(from_upper.clone(), to_upper.clone())) {
-            let result = amount * info.rate;
-            self.last_used_rates = vec![(from_upper, to_upper, info.clone())];
-            return Ok(result);
+        let direct = self.get_rate_info(&from_upper, &to_upper);
+        if let Some(info) = direct {
+            return Ok(Conversion {
+                amount: amount * info.rate,
+                receipt: ConversionReceipt {
+                    rates: vec![(from_upper, to_upper, info)],
+                    warnings: Vec::new(),
+                },
+            });
         }
 
         // Try triangulation via USD as a bridge currency.
@@ -405,20 +400,21 @@ impl CurrencyDatabase {
         // where no direct rate exists but both currencies have USD rates.
         if from_upper != "USD" && to_upper != "USD" {
             if let (Some(from_usd_info), Some(usd_to_info)) = (
-                self.rates
-                    .get(&(from_upper.clone(), "USD".to_string()))
-                    .cloned(),
-                self.rates
-                    .get(&("USD".to_string(), to_upper.clone()))
-                    .cloned(),
+                self.get_rate_info(&from_upper, "USD"),
+                self.get_rate_info("USD", &to_upper),
             ) {
                 let triangulated_rate = from_usd_info.rate * usd_to_info.rate;
-                // Store both individual rate steps so callers can show each hop explicitly
-                self.last_used_rates = vec![
-                    (from_upper, "USD".to_string(), from_usd_info),
-                    ("USD".to_string(), to_upper, usd_to_info),
-                ];
-                return Ok(amount * triangulated_rate);
+                // Report both individual rate steps so callers can show each hop explicitly
+                return Ok(Conversion {
+                    amount: amount * triangulated_rate,
+                    receipt: ConversionReceipt {
+                        rates: vec![
+                            (from_upper, "USD".to_string(), from_usd_info),
+                            ("USD".to_string(), to_upper, usd_to_info),
+                        ],
+                        warnings: Vec::new(),
+                    },
+                });
             }
         }
 
@@ -431,26 +427,38 @@ impl CurrencyDatabase {
 
     /// Converts with a specific date for historical rates.
     pub fn convert_at_date(
-        &mut self,
+        &self,
         amount: f64,
         from: &str,
         to: &str,
         date: &DateTime,
-    ) -> Result<f64, CalculatorError> {
+    ) -> Result<Conversion, CalculatorError> {
         let from_upper = from.to_uppercase();
         let to_upper = to.to_uppercase();
 
         if from_upper == to_upper {
-            self.last_used_rates.clear();
-            return Ok(amount);
+            return Ok(Conversion::unconverted(amount));
         }
 
-        if let Some(info) = self
-            .get_historical_rate_info(&from_upper, &to_upper, date)
-            .cloned()
-        {
-            self.last_used_rates = vec![(from_upper, to_upper, info.clone())];
-            return Ok(amount * info.rate);
+        if let Some(found) = self.find_historical_rate(&from_upper, &to_upper, date) {
+            // A built-in rate has no date to be stale against
+            let warning = if found.info.is_default() {
+                Some(format!(
+                    "No historical {from_upper}/{to_upper} rate was available for {}; \
+                     a built-in rate of unknown date was used",
+                    date.as_chrono().date_naive()
+                ))
+            } else {
+                self.staleness_warning(&from_upper, &to_upper, &found, date)
+            };
+            let warnings = warning.into_iter().collect();
+            return Ok(Conversion {
+                amount: amount * found.info.rate,
+                receipt: ConversionReceipt {
+                    rates: vec![(from_upper, to_upper, found.info)],
+                    warnings,
+                },
+            });
         }
 
         Err(CalculatorError::NoHistoricalRate {
@@ -459,22 +467,36 @@ impl CurrencyDatabase {
         })
     }
 
+    /// Converts at `date` when given, otherwise at the current rate.
+    pub fn convert_at(
+        &self,
+        amount: f64,
+        from: &str,
+        to: &str,
+        date: Option<&DateTime>,
+    ) -> Result<Conversion, CalculatorError> {
+        match date {
+            Some(date) => self.convert_at_date(amount, from, to, date),
+            None => self.convert(amount, from, to),
+        }
+    }
+
     /// Checks if a currency code is known.
     #[must_use]
     pub fn is_known_currency(&self, code: &str) -> bool {
-        self.currencies.contains_key(&code.to_uppercase())
+        self.store().currencies.contains_key(&code.to_uppercase())
     }
 
     /// Gets currency metadata.
     #[must_use]
-    pub fn get_currency(&self, code: &str) -> Option<&Currency> {
-        self.currencies.get(&code.to_uppercase())
+    pub fn get_currency(&self, code: &str) -> Option<Currency> {
+        self.store().currencies.get(&code.to_uppercase()).cloned()
     }
 
     /// Returns all supported currency codes.
     #[must_use]
     pub fn supported_currencies(&self) -> Vec<String> {
-        self.currencies.keys().cloned().collect()
+        self.store().currencies.keys().cloned().collect()
     }
 
     /// Parses a currency code from a string.
@@ -490,6 +512,20 @@ impl CurrencyDatabase {
         // Fiat currency codes and symbols
         match input.as_str() {
             "USD" | "US$" | "$" => return Some("USD".to_string()),
+            "AUD" | "A$" | "AU$" => return Some("AUD".to_string()),
+            "CAD" | "C$" | "CA$" => return Some("CAD".to_string()),
+            "NZD" | "NZ$" => return Some("NZD".to_string()),
+            "HKD" | "HK$" => return Some("HKD".to_string()),
+            "SGD" | "S$" | "SG$" => return Some("SGD".to_string()),
+            "BRL" | "R$" => return Some("BRL".to_string()),
+            "MXN" | "MX$" => return Some("MXN".to_string()),
+            "TWD" | "NT$" => return Some("TWD".to_string()),
+            "TRY" | "₺" => return Some("TRY".to_string()),
+            "UAH" | "₴" => return Some("UAH".to_string()),
+            "ILS" | "₪" => return Some("ILS".to_string()),
+            "PHP" | "₱" => return Some("PHP".to_string()),
+            "NGN" | "₦" => return Some("NGN".to_string()),
+            "PLN" | "ZŁ" => return Some("PLN".to_string()),
             "EUR" | "€" => return Some("EUR".to_string()),
             "GBP" | "£" => return Some("GBP".to_string()),
             "JPY" | "¥" => return Some("JPY".to_string()),
@@ -509,14 +545,17 @@ impl CurrencyDatabase {
         // Natural language fiat currency names (lowercase comparison)
         let input_lower = input.to_lowercase();
         match input_lower.as_str() {
-            "dollar" | "dollars" | "usdollar" | "usdollars" | "us dollar" | "us dollars" => {
-                return Some("USD".to_string())
-            }
+            "dollar" | "dollars" | "usdollar" | "usdollars" | "us dollar" | "us dollars"
+            | "buck" | "bucks" => return Some("USD".to_string()),
             "euro" | "euros" => return Some("EUR".to_string()),
             "pound" | "pounds" | "sterling" | "british pound" | "british pounds" => {
                 return Some("GBP".to_string())
             }
             "yen" | "japanese yen" => return Some("JPY".to_string()),
+            "won" | "korean won" => return Some("KRW".to_string()),
+            "lira" | "liras" | "turkish lira" => return Some("TRY".to_string()),
+            "hryvnia" | "hryvnias" | "hryvnya" => return Some("UAH".to_string()),
+            "zloty" | "zlotys" | "polish zloty" => return Some("PLN".to_string()),
             "franc" | "francs" | "swiss franc" | "swiss francs" => return Some("CHF".to_string()),
             "yuan" | "renminbi" | "chinese yuan" => return Some("CNY".to_string()),
             "ruble" | "rubles" | "rouble" | "roubles" => return Some("RUB".to_string()),
@@ -558,6 +597,24 @@ impl CurrencyDatabase {
             "иена" | "иены" | "иене" | "иену" | "иеной" | "иенами" | "иенах" | "иен" => {
                 return Some("JPY".to_string())
             }
+            // Russian spelling "йена" of JPY, as common as "иена"
+            "йена" | "йены" | "йене" | "йену" | "йеной" | "йенами" | "йенах" | "йен" => {
+                return Some("JPY".to_string())
+            }
+            // Russian language names for UAH (Ukrainian hryvnia, all grammatical cases/forms)
+            "гривна" | "гривны" | "гривне" | "гривну" | "гривной" | "гривен" | "гривнам"
+            | "гривнами" | "гривнах" => return Some("UAH".to_string()),
+            // Ukrainian language names for UAH
+            "гривня" | "гривні" | "гривню" | "гривнею" | "гривень" | "гривням" | "гривнями"
+            | "гривнях" => return Some("UAH".to_string()),
+            // Russian language names for PLN (Polish zloty, all grammatical cases/forms)
+            "злотый" | "злотых" | "злотому" | "злотым" | "злотом" | "злотые" | "злотыми" => {
+                return Some("PLN".to_string())
+            }
+            // Russian language names for TRY (Turkish lira, all grammatical cases/forms)
+            "лира" | "лиры" | "лире" | "лиру" | "лирой" | "лир" | "лирам" | "лирами" | "лирах" => {
+                return Some("TRY".to_string())
+            }
             // Russian language names for INR (Indian Rupee, all grammatical cases/forms)
             "рупия" | "рупии" | "рупий" | "рупию" | "рупией" | "рупиях" => {
                 return Some("INR".to_string())
@@ -607,6 +664,29 @@ impl CurrencyDatabase {
             "roupie" | "roupies" | "roupie indienne" | "roupies indiennes" => {
                 return Some("INR".to_string())
             }
+            // Spanish and Portuguese language names for currencies
+            // USD: dólar/dólares (also written without the accent)
+            "dólar" | "dólares" | "dolar" | "dolares" | "dólar estadounidense" => {
+                return Some("USD".to_string())
+            }
+            // GBP: libra/libras esterlinas
+            "libra" | "libras" | "libra esterlina" | "libras esterlinas" => {
+                return Some("GBP".to_string())
+            }
+            // BRL: real/reais (Brazilian real)
+            "real" | "reais" | "real brasileiro" | "reais brasileiros" => {
+                return Some("BRL".to_string())
+            }
+            // MXN: peso mexicano
+            "peso mexicano" | "pesos mexicanos" => return Some("MXN".to_string()),
+            // Polish language names for PLN: złoty, złote, złotych
+            "zł" | "złoty" | "złote" | "złotych" => return Some("PLN".to_string()),
+            // Japanese language names for currencies
+            "円" | "日本円" => return Some("JPY".to_string()),
+            "ドル" | "米ドル" => return Some("USD".to_string()),
+            "ユーロ" => return Some("EUR".to_string()),
+            // Korean language names for KRW: 원 (won)
+            "원" => return Some("KRW".to_string()),
             // Chinese (Simplified) language names for currencies ("换成"/"兑换成" etc. added to lexer)
             // USD: 美元 (měi yuán) standard; 美金 (měi jīn) colloquial
             "美元" | "美金" => return Some("USD".to_string()),
@@ -717,6 +797,17 @@ impl CurrencyDatabase {
     }
 }
 
+impl Clone for CurrencyDatabase {
+    fn clone(&self) -> Self {
+        let store: RateStore = self.store().clone();
+        Self {
+            store: SharedRateStore::new(store.into()),
+            historical_rate_mode: self.historical_rate_mode,
+            stale_rate_days: self.stale_rate_days,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -752,8 +843,8 @@ mod tests {
 
     #[test]
     fn test_convert() {
-        let mut db = CurrencyDatabase::new();
-        let result = db.convert(100.0, "USD", "EUR").unwrap();
+        let db = CurrencyDatabase::new();
+        let result = db.convert(100.0, "USD", "EUR").unwrap().amount;
         assert!(result > 80.0 && result < 100.0);
     }
 
@@ -808,7 +899,7 @@ mod tests {
     #[test]
     #[allow(clippy::float_cmp)]
     fn test_set_rate_with_info() {
-        let mut db = CurrencyDatabase::new();
+        let db = CurrencyDatabase::new();
         let info = ExchangeRateInfo::new(75.0, "test-api", "2026-01-25");
         db.set_rate_with_info("USD", "RUB", info);
 
@@ -823,15 +914,15 @@ mod tests {
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_convert_tracks_rate_used() {
-        let mut db = CurrencyDatabase::new();
+    fn test_convert_returns_rate_used() {
+        let db = CurrencyDatabase::new();
         let info = ExchangeRateInfo::new(75.0, "test-api", "2026-01-25");
         db.set_rate_with_info("USD", "RUB", info);
 
         let result = db.convert(100.0, "USD", "RUB").unwrap();
-        assert_eq!(result, 7500.0);
+        assert_eq!(result.amount, 7500.0);
 
-        let last_rates = db.get_last_used_rates();
+        let last_rates = &result.receipt.rates;
         assert_eq!(last_rates.len(), 1);
         let last_rate = &last_rates[0];
         assert_eq!(last_rate.0, "USD");
@@ -843,9 +934,9 @@ mod tests {
     #[test]
     #[allow(clippy::float_cmp)]
     fn test_same_currency_no_rate_tracking() {
-        let mut db = CurrencyDatabase::new();
+        let db = CurrencyDatabase::new();
         let result = db.convert(100.0, "USD", "USD").unwrap();
-        assert_eq!(result, 100.0);
-        assert!(db.get_last_used_rates().is_empty());
+        assert_eq!(result.amount, 100.0);
+        assert!(result.receipt.is_empty());
     }
 }
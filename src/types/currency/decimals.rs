@@ -0,0 +1,83 @@
+//! The decimals amounts of each currency are written with, per ISO 4217
+//! for national currencies and the smallest unit for cryptocurrencies.
+
+use super::CurrencyDatabase;
+
+/// ISO 4217 currencies without minor units, such as the yen and the won.
+const NO_DECIMALS: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+
+/// ISO 4217 currencies with three decimals, such as the Kuwaiti dinar.
+const THREE_DECIMALS: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+
+/// ISO 4217 currencies with four decimals, such as the Chilean UF.
+const FOUR_DECIMALS: &[&str] = &["CLF", "UYW"];
+
+/// Other ISO 4217 currencies in use, all with two decimals.
+const TWO_DECIMALS: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHF",
+    "CNY", "COP", "CRC", "CUP", "CVE", "CZK", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR",
+    "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF",
+    "IDR", "ILS", "INR", "IRR", "JMD", "KES", "KGS", "KHR", "KPW", "KYD", "KZT", "LAK", "LBP",
+    "LKR", "LRD", "LSL", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR",
+    "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "PAB", "PEN", "PGK",
+    "PHP", "PKR", "PLN", "QAR", "RON", "RSD", "RUB", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD",
+    "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TOP",
+    "TRY", "TTD", "TWD", "TZS", "UAH", "USD", "UYU", "UZS", "VES", "WST", "XCD", "YER", "ZAR",
+    "ZMW", "ZWL",
+];
+
+/// Cryptocurrencies and the decimals of their smallest unit, such as the
+/// satoshi, a hundred-millionth of a bitcoin.
+const CRYPTO_DECIMALS: &[(&str, u8)] = &[
+    ("ADA", 6),
+    ("BNB", 18),
+    ("BTC", 8),
+    ("DOGE", 8),
+    ("DOT", 10),
+    ("ETH", 18),
+    ("LINK", 18),
+    ("LTC", 8),
+    ("SOL", 9),
+    ("TON", 9),
+    ("UNI", 18),
+    ("USDC", 6),
+    ("USDT", 6),
+    ("XRP", 6),
+];
+
+/// Returns the decimals amounts in the currency `code` are written with,
+/// or `None` for a currency whose precision is not known.
+fn standard_decimals(code: &str) -> Option<u8> {
+    let code = code.to_uppercase();
+    let is = |codes: &[&str]| codes.contains(&code.as_str());
+    if is(NO_DECIMALS) {
+        Some(0)
+    } else if is(TWO_DECIMALS) {
+        Some(2)
+    } else if is(THREE_DECIMALS) {
+        Some(3)
+    } else if is(FOUR_DECIMALS) {
+        Some(4)
+    } else {
+        CRYPTO_DECIMALS
+            .iter()
+            .find(|(crypto, _)| *crypto == code)
+            .map(|(_, decimals)| *decimals)
+    }
+}
+
+impl CurrencyDatabase {
+    /// Returns the decimals amounts in the currency `code` are written
+    /// with: those of a registered currency, or else the standard ones.
+    /// `None` for a currency whose precision is not known.
+    #[must_use]
+    pub fn decimals(&self, code: &str) -> Option<u8> {
+        self.get_currency(code)
+            .map(|currency| currency.decimals)
+            .or_else(|| standard_decimals(code))
+    }
+}
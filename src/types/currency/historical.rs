@@ -0,0 +1,258 @@
+//! Historical rate selection for dates between known rates, and warnings for
+//! rates that are far from the requested date.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{CurrencyDatabase, ExchangeRateInfo};
+use crate::types::DateTime;
+
+/// How a historical rate is chosen when none is known for the requested date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoricalRateMode {
+    /// Use the latest rate on or before the requested date.
+    #[default]
+    Previous,
+    /// Use the known rate closest to the requested date, before or after it.
+    Nearest,
+    /// Interpolate linearly between the known rates either side of the
+    /// requested date, falling back to the previous rate.
+    Interpolate,
+}
+
+impl HistoricalRateMode {
+    /// Parses a mode name: `previous`, `nearest` or `interpolate`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "previous" => Some(Self::Previous),
+            "nearest" => Some(Self::Nearest),
+            "interpolate" | "interpolated" => Some(Self::Interpolate),
+            _ => None,
+        }
+    }
+}
+
+/// A known rate and the date it is from.
+type DatedRate = (NaiveDate, ExchangeRateInfo);
+
+/// A historical rate chosen for a requested date.
+pub(super) struct HistoricalRate {
+    /// The rate, interpolated if it falls between two known rates.
+    pub info: ExchangeRateInfo,
+    /// The date of the known rate closest to the requested date.
+    pub nearest_date: NaiveDate,
+}
+
+impl CurrencyDatabase {
+    /// Returns how historical rates are chosen between known dates.
+    #[must_use]
+    pub fn historical_rate_mode(&self) -> HistoricalRateMode {
+        self.historical_rate_mode
+    }
+
+    /// Sets how historical rates are chosen between known dates.
+    pub fn set_historical_rate_mode(&mut self, mode: HistoricalRateMode) {
+        self.historical_rate_mode = mode;
+    }
+
+    /// Returns how many days a historical rate may be from the requested
+    /// date before conversions using it carry a warning.
+    #[must_use]
+    pub fn stale_rate_days(&self) -> u32 {
+        self.stale_rate_days
+    }
+
+    /// Sets how many days a historical rate may be from the requested date
+    /// before conversions using it carry a warning.
+    pub fn set_stale_rate_days(&mut self, days: u32) {
+        self.stale_rate_days = days;
+    }
+
+    /// Returns the most recent known historical rate from `from` to `to`.
+    #[must_use]
+    pub fn latest_historical_rate(&self, from: &str, to: &str) -> Option<ExchangeRateInfo> {
+        let from_upper = from.to_uppercase();
+        let to_upper = to.to_uppercase();
+        self.store()
+            .historical_rates
+            .iter()
+            .filter(|((rate_from, rate_to, _), _)| *rate_from == from_upper && *rate_to == to_upper)
+            .filter_map(|((_, _, date), info)| {
+                let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+                Some((date, info))
+            })
+            .max_by_key(|(date, _)| *date)
+            .map(|(_, info)| info.clone())
+    }
+
+    /// Returns the known historical rates from `from` to `to` dated from
+    /// `start` to `end` inclusive, oldest first.
+    #[must_use]
+    pub fn historical_rates_between(
+        &self,
+        from: &str,
+        to: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<(NaiveDate, ExchangeRateInfo)> {
+        let from_upper = from.to_uppercase();
+        let to_upper = to.to_uppercase();
+        let mut rates: Vec<_> = self
+            .store()
+            .historical_rates
+            .iter()
+            .filter(|((rate_from, rate_to, _), _)| *rate_from == from_upper && *rate_to == to_upper)
+            .filter_map(|((_, _, date), info)| {
+                let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+                (start..=end).contains(&date).then(|| (date, info.clone()))
+            })
+            .collect();
+        rates.sort_by_key(|(date, _)| *date);
+        rates
+    }
+
+    /// Chooses the rate for `date` according to the historical rate mode.
+    pub(super) fn find_historical_rate(
+        &self,
+        from: &str,
+        to: &str,
+        date: &DateTime,
+    ) -> Option<HistoricalRate> {
+        let from_upper = from.to_uppercase();
+        let to_upper = to.to_uppercase();
+        let requested = date.as_chrono().date_naive();
+
+        let (before, after) = self.known_rates_around(&from_upper, &to_upper, requested);
+        let known = |(nearest_date, info): DatedRate| HistoricalRate { info, nearest_date };
+        match (self.historical_rate_mode, before, after) {
+            (_, Some((date, info)), _) if date == requested => Some(known((date, info))),
+            (HistoricalRateMode::Nearest, Some(before), Some(after)) => {
+                let closer = if after.0 - requested < requested - before.0 {
+                    after
+                } else {
+                    before
+                };
+                Some(known(closer))
+            }
+            (HistoricalRateMode::Nearest, None, after) => after.map(known),
+            (HistoricalRateMode::Interpolate, Some(before), Some(after)) => {
+                let span = (after.0 - before.0).num_days() as f64;
+                let weight = (requested - before.0).num_days() as f64 / span;
+                let rate = (after.1.rate - before.1.rate).mul_add(weight, before.1.rate);
+                let nearest_date = if weight > 0.5 { after.0 } else { before.0 };
+                Some(HistoricalRate {
+                    info: ExchangeRateInfo::new(
+                        rate,
+                        format!(
+                            "{}, interpolated between {} and {}",
+                            before.1.source, before.0, after.0
+                        ),
+                        requested.to_string(),
+                    ),
+                    nearest_date,
+                })
+            }
+            (_, before, _) => before.map(known),
+        }
+    }
+
+    /// Finds the known rates closest to `requested`: the latest on or before
+    /// it and the earliest after it.
+    fn known_rates_around(
+        &self,
+        from: &str,
+        to: &str,
+        requested: NaiveDate,
+    ) -> (Option<DatedRate>, Option<DatedRate>) {
+        let store = self.store();
+        let mut before: Option<(NaiveDate, &ExchangeRateInfo)> = None;
+        let mut after: Option<(NaiveDate, &ExchangeRateInfo)> = None;
+        for ((rate_from, rate_to, rate_date), info) in &store.historical_rates {
+            if rate_from != from || rate_to != to {
+                continue;
+            }
+            let Ok(rate_date) = NaiveDate::parse_from_str(rate_date, "%Y-%m-%d") else {
+                continue;
+            };
+            if rate_date <= requested {
+                if before.map_or(true, |(best, _)| rate_date > best) {
+                    before = Some((rate_date, info));
+                }
+            } else if after.map_or(true, |(best, _)| rate_date < best) {
+                after = Some((rate_date, info));
+            }
+        }
+        let owned = |(date, info): (NaiveDate, &ExchangeRateInfo)| (date, info.clone());
+        let around = (before.map(owned), after.map(owned));
+        drop(store);
+        around
+    }
+
+    /// Describes how far `rate` is from the requested date, if that is
+    /// further than [`Self::stale_rate_days`].
+    pub(super) fn staleness_warning(
+        &self,
+        from: &str,
+        to: &str,
+        rate: &HistoricalRate,
+        date: &DateTime,
+    ) -> Option<String> {
+        let requested = date.as_chrono().date_naive();
+        let days = (requested - rate.nearest_date).num_days();
+        if days.unsigned_abs() <= u64::from(self.stale_rate_days) {
+            return None;
+        }
+        let direction = if days > 0 { "before" } else { "after" };
+        Some(format!(
+            "The nearest known {}/{} rate is from {}, {} days {direction} {requested}",
+            from.to_uppercase(),
+            to.to_uppercase(),
+            rate.nearest_date,
+            days.unsigned_abs()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database_with_rates(mode: HistoricalRateMode) -> CurrencyDatabase {
+        let mut db = CurrencyDatabase::new();
+        db.set_historical_rate("USD", "GBP", "2021-01-01", 0.70);
+        db.set_historical_rate("USD", "GBP", "2021-01-11", 0.80);
+        db.set_historical_rate_mode(mode);
+        db
+    }
+
+    fn rate_on(db: &CurrencyDatabase, date: &str) -> Option<f64> {
+        db.get_historical_rate("USD", "GBP", &DateTime::parse(date).unwrap())
+    }
+
+    #[test]
+    fn test_modes_between_known_dates() {
+        let previous = database_with_rates(HistoricalRateMode::Previous);
+        assert_eq!(rate_on(&previous, "2021-01-09"), Some(0.70));
+
+        let nearest = database_with_rates(HistoricalRateMode::Nearest);
+        assert_eq!(rate_on(&nearest, "2021-01-09"), Some(0.80));
+        assert_eq!(rate_on(&nearest, "2020-12-25"), Some(0.70));
+
+        let interpolated = database_with_rates(HistoricalRateMode::Interpolate);
+        let rate = rate_on(&interpolated, "2021-01-09").unwrap();
+        assert!((rate - 0.78).abs() < 1e-9);
+        assert_eq!(rate_on(&interpolated, "2021-01-20"), Some(0.80));
+        assert_eq!(rate_on(&interpolated, "2020-12-25"), None);
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(
+            HistoricalRateMode::parse("Nearest"),
+            Some(HistoricalRateMode::Nearest)
+        );
+        assert_eq!(HistoricalRateMode::parse("latest"), None);
+    }
+}
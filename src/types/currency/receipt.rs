@@ -0,0 +1,112 @@
+//! Conversion receipts: the rates a currency conversion used and the warnings
+//! about them, returned alongside the converted amount.
+
+use serde::{Deserialize, Serialize};
+
+use super::ExchangeRateInfo;
+use crate::types::{DateTime, Decimal, Rational};
+
+/// The rates used by one or more currency conversions, and any warnings
+/// about them, such as a historical rate far from the requested date.
+///
+/// Conversions return a receipt instead of recording the rates in the
+/// database, so a database can convert through a shared reference and
+/// concurrent calculations never see each other's rates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversionReceipt {
+    /// Each rate used as (from, to, info). Cross-rate (triangulated)
+    /// conversions use one rate per hop.
+    pub rates: Vec<(String, String, ExchangeRateInfo)>,
+    /// Warnings about the rates used.
+    pub warnings: Vec<String>,
+}
+
+impl ConversionReceipt {
+    /// Returns true if no rate was used.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty() && self.warnings.is_empty()
+    }
+
+    /// Adds the rates and warnings of `other` to this receipt.
+    pub fn extend(&mut self, other: Self) {
+        self.rates.extend(other.rates);
+        self.warnings.extend(other.warnings);
+    }
+
+    /// The rate of the whole conversion, as the exact product of the rates
+    /// of each hop as they are written (`0.92` rather than the nearest
+    /// binary float), so converting an amount does not go through `f64`.
+    #[must_use]
+    pub fn rate(&self) -> Rational {
+        self.rates
+            .iter()
+            .map(|(_, _, info)| {
+                Decimal::try_from_f64(info.rate)
+                    .map_or_else(|| Rational::from_f64(info.rate), Rational::from_decimal)
+            })
+            .fold(Rational::one(), |rate, hop| rate * hop)
+    }
+}
+
+/// One exchange rate used by a calculation, with the date it was asked for
+/// and the date of the rate actually used, for auditing results that
+/// convert several times.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateUsage {
+    /// The currency converted from.
+    pub from: String,
+    /// The currency converted to.
+    pub to: String,
+    /// Units of `to` per unit of `from`.
+    pub rate: f64,
+    /// The date the conversion asked for (YYYY-MM-DD), or `None` for the
+    /// current rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_requested: Option<String>,
+    /// The date of the rate used (YYYY-MM-DD).
+    pub date_used: String,
+    /// Where the rate came from.
+    pub source: String,
+}
+
+impl ConversionReceipt {
+    /// Lists the rates of this receipt as used by a conversion that asked
+    /// for the rates of `requested`, or the current rates.
+    #[must_use]
+    pub fn usages(&self, requested: Option<&DateTime>) -> Vec<RateUsage> {
+        let date_requested =
+            requested.map(|date| date.as_chrono().date_naive().format("%Y-%m-%d").to_string());
+        self.rates
+            .iter()
+            .map(|(from, to, info)| RateUsage {
+                from: from.clone(),
+                to: to.clone(),
+                rate: info.rate,
+                date_requested: date_requested.clone(),
+                date_used: info.date.clone(),
+                source: info.source.clone(),
+            })
+            .collect()
+    }
+}
+
+/// An amount converted to another currency, with the receipt for the rates
+/// used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// The converted amount.
+    pub amount: f64,
+    /// The rates used and any warnings about them.
+    pub receipt: ConversionReceipt,
+}
+
+impl Conversion {
+    /// A conversion that used no rate, e.g. between the same currency.
+    pub(super) fn unconverted(amount: f64) -> Self {
+        Self {
+            amount,
+            receipt: ConversionReceipt::default(),
+        }
+    }
+}
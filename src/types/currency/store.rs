@@ -0,0 +1,71 @@
+//! The currencies and exchange rates behind a [`CurrencyDatabase`], kept
+//! behind a lock so that several databases can share them.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use super::{Currency, CurrencyDatabase, ExchangeRateInfo};
+use crate::sync::{read_recover, write_recover};
+
+/// Known currencies and their current and historical exchange rates.
+#[derive(Debug, Clone, Default)]
+pub(super) struct RateStore {
+    /// Known currencies.
+    pub currencies: HashMap<String, Currency>,
+    /// Exchange rates with full metadata: (from, to) -> rate info
+    /// Rate means: 1 unit of 'from' = rate units of 'to'
+    pub rates: HashMap<(String, String), ExchangeRateInfo>,
+    /// Legacy rates map for compatibility (will be deprecated)
+    #[allow(dead_code)]
+    pub legacy_rates: HashMap<(String, String), f64>,
+    /// Historical rates: (from, to, `date_string`) -> rate info
+    pub historical_rates: HashMap<(String, String, String), ExchangeRateInfo>,
+}
+
+impl RateStore {
+    /// Sets the current rate from `from` to `to`, and its inverse.
+    pub fn insert_rate(&mut self, from: String, to: String, info: ExchangeRateInfo) {
+        self.rates.insert((from.clone(), to.clone()), info.clone());
+        if info.rate != 0.0 {
+            let inverse_info = ExchangeRateInfo {
+                rate: 1.0 / info.rate,
+                source: info.source.clone(),
+                date: info.date.clone(),
+                fetched_at: info.fetched_at,
+            };
+            self.rates.insert((to, from), inverse_info);
+        }
+    }
+}
+
+/// A rate store that several databases read and update.
+pub(super) type SharedRateStore = Arc<RwLock<RateStore>>;
+
+impl CurrencyDatabase {
+    /// Creates a database over the same currencies and rates as this one,
+    /// so that rates set through either are seen by both.
+    ///
+    /// This lets the calculators of a server share one rate store that a
+    /// background task keeps up to date. The new database starts with the
+    /// default historical rate settings.
+    #[must_use]
+    pub fn shared(&self) -> Self {
+        Self::with_store(Arc::clone(&self.store))
+    }
+
+    /// Returns true if this database shares its rates with `other`.
+    #[must_use]
+    pub fn shares_rates_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.store, &other.store)
+    }
+
+    /// Locks the store for reading.
+    pub(super) fn store(&self) -> RwLockReadGuard<'_, RateStore> {
+        read_recover(&self.store)
+    }
+
+    /// Locks the store for writing.
+    pub(super) fn store_mut(&self) -> RwLockWriteGuard<'_, RateStore> {
+        write_recover(&self.store)
+    }
+}
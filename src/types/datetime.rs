@@ -9,7 +9,10 @@ use std::fmt;
 use std::str::FromStr;
 
 use crate::error::CalculatorError;
+use crate::types::DateTimeFormat;
 
+#[path = "datetime_now.rs"]
+mod now;
 #[path = "datetime_parse.rs"]
 mod parse;
 use parse::{
@@ -150,10 +153,27 @@ impl DateTime {
     /// represented by `offset_seconds` (seconds east of UTC).
     #[must_use]
     pub fn today(offset_seconds: i32) -> Self {
-        let local_now = Utc::now() + Duration::seconds(i64::from(offset_seconds));
+        Self::today_at(Utc::now(), offset_seconds)
+    }
+
+    /// Creates a date-only value for the calendar date of `instant` in the
+    /// timezone represented by `offset_seconds` (seconds east of UTC).
+    #[must_use]
+    pub fn today_at(instant: ChronoDateTime<Utc>, offset_seconds: i32) -> Self {
+        let local_now = instant + Duration::seconds(i64::from(offset_seconds));
         Self::from_date(local_now.date_naive())
     }
 
+    /// Moves a live time such as `now` or `current EST time` to `instant`,
+    /// keeping its label and timezone.
+    #[must_use]
+    pub fn at_instant(self, instant: ChronoDateTime<Utc>) -> Self {
+        Self {
+            inner: instant,
+            ..self
+        }
+    }
+
     /// Re-anchors a timezone-less ("naive") time or datetime to a local timezone.
     ///
     /// Bare times like `12:30` are parsed with their wall-clock reading stored as
@@ -324,63 +344,6 @@ impl DateTime {
         )))
     }
 
-    /// Checks if input represents "now" (current time).
-    fn try_parse_now(input: &str) -> Option<Self> {
-        let lower = input.to_lowercase();
-        let trimmed = lower.trim();
-
-        // Exact "now" or "now" with timezone
-        match trimmed {
-            "now" => {
-                return Some(Self::now_with_label(
-                    "current UTC time",
-                    Some(0),
-                    Some("UTC".to_string()),
-                ))
-            }
-            "now utc" | "utc now" | "now gmt" | "gmt now" => {
-                return Some(Self::now_with_label(
-                    "current UTC time",
-                    Some(0),
-                    Some("UTC".to_string()),
-                ))
-            }
-            _ => {}
-        }
-
-        // "now <timezone>" pattern
-        if let Some(rest) = trimmed.strip_prefix("now ") {
-            let rest = rest.trim();
-            if let Some(offset) = parse_tz_abbreviation(rest) {
-                let tz_upper = rest.to_uppercase();
-                let label = format!("current {tz_upper} time");
-                let offset_secs = offset.local_minus_utc();
-                return Some(Self::now_with_label(
-                    label,
-                    Some(offset_secs),
-                    Some(tz_upper),
-                ));
-            }
-        }
-
-        // "<timezone> now" pattern
-        if let Some(rest) = trimmed.strip_suffix(" now") {
-            let rest = rest.trim();
-            if let Some(offset) = parse_tz_abbreviation(rest) {
-                let tz_upper = rest.to_uppercase();
-                let label = format!("current {tz_upper} time");
-                let offset_secs = offset.local_minus_utc();
-                return Some(Self::now_with_label(
-                    label,
-                    Some(offset_secs),
-                    Some(tz_upper),
-                ));
-            }
-        }
-
-        None
-    }
-
     /// Formats the timezone offset for display, e.g., `UTC (+00:00)` or `EST (-05:00)`.
     /// When `tz_name` is provided, it is prepended to the numeric offset.
     fn format_tz_for_display(offset_seconds: i32, tz_name: Option<&str>) -> String {
@@ -396,66 +359,6 @@ impl DateTime {
         }
     }
 
-    /// Checks if input represents a "current time" phrase.
-    fn try_parse_current_time_phrase(input: &str) -> Option<Self> {
-        let lower = input.to_lowercase();
-        let trimmed = lower.trim();
-
-        // Phrases that mean "current UTC time" — map phrase to (label, offset_seconds, tz_abbrev)
-        let current_time_phrases: &[(&str, &str, Option<i32>, &str)] = &[
-            ("utc time", "current UTC time", Some(0), "UTC"),
-            ("time utc", "current UTC time", Some(0), "UTC"),
-            ("current time", "current UTC time", Some(0), "UTC"),
-            ("current utc time", "current UTC time", Some(0), "UTC"),
-            ("gmt time", "current GMT time", Some(0), "GMT"),
-            ("time gmt", "current GMT time", Some(0), "GMT"),
-        ];
-
-        for &(phrase, label, offset_secs, tz_abbrev) in current_time_phrases {
-            if trimmed == phrase {
-                return Some(Self::now_with_label(
-                    label,
-                    offset_secs,
-                    Some(tz_abbrev.to_string()),
-                ));
-            }
-        }
-
-        // "current <TZ> time" or "<TZ> time" patterns
-        for prefix in &["current ", ""] {
-            if let Some(rest) = trimmed.strip_prefix(prefix) {
-                if let Some(tz_part) = rest.strip_suffix(" time") {
-                    let tz_part = tz_part.trim();
-                    if let Some(offset) = parse_tz_abbreviation(tz_part) {
-                        let tz_upper = tz_part.to_uppercase();
-                        let label = format!("current {tz_upper} time");
-                        let offset_secs = offset.local_minus_utc();
-                        return Some(Self::now_with_label(
-                            label,
-                            Some(offset_secs),
-                            Some(tz_upper),
-                        ));
-                    }
-                }
-                if let Some(tz_part) = rest.strip_prefix("time ") {
-                    let tz_part = tz_part.trim();
-                    if let Some(offset) = parse_tz_abbreviation(tz_part) {
-                        let tz_upper = tz_part.to_uppercase();
-                        let label = format!("current {tz_upper} time");
-                        let offset_secs = offset.local_minus_utc();
-                        return Some(Self::now_with_label(
-                            label,
-                            Some(offset_secs),
-                            Some(tz_upper),
-                        ));
-                    }
-                }
-            }
-        }
-
-        None
-    }
-
     fn try_parse_date_formats(input: &str) -> Option<Self> {
         // ISO format: 2026-01-22
         if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
@@ -500,8 +403,22 @@ impl DateTime {
             return Some(Self::from_date(date));
         }
 
-        // Month name formats: Jan 22, 2026 or January 22, 2026
         let normalized = normalize_month_name(input);
+
+        // Month and year only: "February 2028" is the first day of the month.
+        // Checked first, as "%B %d %Y" would read it as February 20, year 28
+        if let Some((month, year)) = normalized.split_once(' ') {
+            if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) {
+                let first = format!("1 {month} {year}");
+                for format in ["%d %b %Y", "%d %B %Y"] {
+                    if let Ok(date) = NaiveDate::parse_from_str(&first, format) {
+                        return Some(Self::from_date(date));
+                    }
+                }
+            }
+        }
+
+        // Month name formats: Jan 22, 2026 or January 22, 2026
         if let Ok(date) = NaiveDate::parse_from_str(&normalized, "%b %d, %Y") {
             return Some(Self::from_date(date));
         }
@@ -606,6 +523,10 @@ impl DateTime {
             return Some(dt);
         }
 
+        if let Some(dt) = Self::try_parse_date_then_time(input) {
+            return Some(dt);
+        }
+
         // ISO 8601 format
         if let Ok(dt) = ChronoDateTime::parse_from_rfc3339(input) {
             return Some(Self {
@@ -633,6 +554,34 @@ impl DateTime {
         None
     }
 
+    /// Parses a numeric date followed by a time, such as
+    /// `2026-01-30 12:00:00 UTC` or `2026-01-27 08:59:00 +00:00`, the form
+    /// dates with times are displayed in.
+    fn try_parse_date_then_time(input: &str) -> Option<Self> {
+        let (date_part, time_part) = input.trim().split_once(' ')?;
+        let date = Self::try_parse_date_formats(date_part)?;
+        let time = Self::try_parse_time_formats(time_part)?;
+        let offset = time.get_offset();
+        let local_time = offset.map_or_else(
+            || time.inner.time(),
+            |offset| time.inner.with_timezone(&offset).time(),
+        );
+        let local = date.inner.date_naive().and_time(local_time);
+        let inner = match offset {
+            Some(offset) => offset
+                .from_local_datetime(&local)
+                .single()?
+                .with_timezone(&Utc),
+            None => local.and_utc(),
+        };
+        Some(Self {
+            inner,
+            has_date: true,
+            has_time: true,
+            ..time
+        })
+    }
+
     /// Try to parse "time date" patterns like "11:59pm EST January 26"
     fn try_parse_time_then_date(input: &str) -> Option<Self> {
         let input = input.trim();
@@ -822,6 +771,44 @@ impl DateTime {
         self.inner.year()
     }
 
+    /// Returns the calendar date in this value's own timezone.
+    #[must_use]
+    pub fn local_date(&self) -> NaiveDate {
+        match self.get_offset() {
+            Some(offset) => self.inner.with_timezone(&offset).date_naive(),
+            None => self.inner.date_naive(),
+        }
+    }
+
+    /// Formats this value for display in the given format.
+    ///
+    /// A pattern covers the whole value; the long formats replace the date
+    /// and keep the time and timezone as in ISO output. Times without a date
+    /// are always shown in ISO form.
+    #[must_use]
+    pub fn format_as(&self, format: &DateTimeFormat) -> String {
+        let Some(pattern) = format.date_pattern().filter(|_| self.has_date) else {
+            return self.to_string();
+        };
+        let local = match self.get_offset() {
+            Some(offset) => self.inner.with_timezone(&offset),
+            None => self.inner.fixed_offset(),
+        };
+        let mut text = local.format(pattern).to_string();
+        if self.has_time && !matches!(format, DateTimeFormat::Pattern(_)) {
+            let time = local.format("%H:%M:%S");
+            text = match (&self.tz_abbrev, self.get_offset()) {
+                (Some(tz), Some(_)) => format!("{text} {time} {tz}"),
+                (None, Some(_)) => format!("{text} {time} {}", local.format("%:z")),
+                (_, None) => format!("{text} {time} UTC"),
+            };
+        }
+        match &self.label {
+            Some(label) => format!("('{label}': {text})"),
+            None => text,
+        }
+    }
+
     /// Parses common timezone abbreviations to `FixedOffset`.
     ///
     /// Returns `None` if the abbreviation is not recognized.
@@ -982,4 +969,12 @@ mod tests {
         assert!(dt.has_date);
         assert_eq!(dt.year(), 2026);
     }
+
+    #[test]
+    fn test_month_and_year_is_the_first_day() {
+        let dt = DateTime::parse("February 2028").unwrap();
+        assert_eq!(dt.to_string(), "2028-02-01");
+        let dt = DateTime::parse("Feb 2027").unwrap();
+        assert_eq!(dt.to_string(), "2027-02-01");
+    }
 }
@@ -0,0 +1,91 @@
+//! How dates and times are rendered in results and steps.
+
+use chrono::format::{Item, StrftimeItems};
+use serde::{Deserialize, Serialize};
+
+/// The format of `DateTime` values in results and steps.
+///
+/// From JSON, use `"iso"`, `"long"`, `"weekday"` or
+/// `{"pattern": "%d.%m.%Y"}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateTimeFormat {
+    /// ISO 8601 dates and times, such as `2027-02-17 14:30:00 UTC`.
+    #[default]
+    Iso,
+    /// The day, month name and year, such as `17 February 2027`.
+    Long,
+    /// The long format preceded by the day of the week, such as
+    /// `Wednesday, 17 February 2027`.
+    Weekday,
+    /// A strftime-like pattern, such as `%d.%m.%Y` or `%A %-d %B`.
+    Pattern(String),
+}
+
+impl DateTimeFormat {
+    /// Parses a format name: `iso`, `long` or `weekday`. Any other text
+    /// containing `%` is read as a pattern.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "iso" => Some(Self::Iso),
+            "long" => Some(Self::Long),
+            "weekday" => Some(Self::Weekday),
+            _ if name.contains('%') => Some(Self::Pattern(name.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Returns false for a pattern with an unknown or incomplete `%`
+    /// specifier. Such patterns are rendered as ISO.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Pattern(pattern) => {
+                !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the pattern for the date part of a value, or `None` for ISO
+    /// output.
+    pub(crate) fn date_pattern(&self) -> Option<&str> {
+        match self {
+            Self::Iso => None,
+            Self::Long => Some("%-d %B %Y"),
+            Self::Weekday => Some("%A, %-d %B %Y"),
+            Self::Pattern(pattern) => self.is_valid().then_some(pattern.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_names_and_patterns() {
+        assert_eq!(DateTimeFormat::parse("Long"), Some(DateTimeFormat::Long));
+        assert_eq!(
+            DateTimeFormat::parse("%d.%m.%Y"),
+            Some(DateTimeFormat::Pattern("%d.%m.%Y".to_string()))
+        );
+        assert_eq!(DateTimeFormat::parse("short"), None);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(DateTimeFormat::Pattern("%Y-%m".to_string()).is_valid());
+        assert!(!DateTimeFormat::Pattern("%Q".to_string()).is_valid());
+        assert!(!DateTimeFormat::Pattern("%".to_string()).is_valid());
+    }
+
+    #[test]
+    fn test_json_forms() {
+        let long: DateTimeFormat = serde_json::from_str(r#""long""#).unwrap();
+        assert_eq!(long, DateTimeFormat::Long);
+        let pattern: DateTimeFormat = serde_json::from_str(r#"{"pattern": "%Y"}"#).unwrap();
+        assert_eq!(pattern, DateTimeFormat::Pattern("%Y".to_string()));
+    }
+}
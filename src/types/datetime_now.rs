@@ -0,0 +1,123 @@
+//! Phrases for the current time, like `now`, `now EST` or `current UTC time`.
+
+use super::parse::parse_tz_abbreviation;
+use super::DateTime;
+
+impl DateTime {
+    /// Checks if input represents "now" (current time).
+    pub(super) fn try_parse_now(input: &str) -> Option<Self> {
+        let lower = input.to_lowercase();
+        let trimmed = lower.trim();
+
+        // Exact "now" or "now" with timezone
+        match trimmed {
+            "now" => {
+                return Some(Self::now_with_label(
+                    "current UTC time",
+                    Some(0),
+                    Some("UTC".to_string()),
+                ))
+            }
+            "now utc" | "utc now" | "now gmt" | "gmt now" => {
+                return Some(Self::now_with_label(
+                    "current UTC time",
+                    Some(0),
+                    Some("UTC".to_string()),
+                ))
+            }
+            _ => {}
+        }
+
+        // "now <timezone>" pattern
+        if let Some(rest) = trimmed.strip_prefix("now ") {
+            let rest = rest.trim();
+            if let Some(offset) = parse_tz_abbreviation(rest) {
+                let tz_upper = rest.to_uppercase();
+                let label = format!("current {tz_upper} time");
+                let offset_secs = offset.local_minus_utc();
+                return Some(Self::now_with_label(
+                    label,
+                    Some(offset_secs),
+                    Some(tz_upper),
+                ));
+            }
+        }
+
+        // "<timezone> now" pattern
+        if let Some(rest) = trimmed.strip_suffix(" now") {
+            let rest = rest.trim();
+            if let Some(offset) = parse_tz_abbreviation(rest) {
+                let tz_upper = rest.to_uppercase();
+                let label = format!("current {tz_upper} time");
+                let offset_secs = offset.local_minus_utc();
+                return Some(Self::now_with_label(
+                    label,
+                    Some(offset_secs),
+                    Some(tz_upper),
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Checks if input represents a "current time" phrase.
+    pub(super) fn try_parse_current_time_phrase(input: &str) -> Option<Self> {
+        let lower = input.to_lowercase();
+        let trimmed = lower.trim();
+
+        // Phrases that mean "current UTC time" — map phrase to (label, offset_seconds, tz_abbrev)
+        let current_time_phrases: &[(&str, &str, Option<i32>, &str)] = &[
+            ("utc time", "current UTC time", Some(0), "UTC"),
+            ("time utc", "current UTC time", Some(0), "UTC"),
+            ("current time", "current UTC time", Some(0), "UTC"),
+            ("current utc time", "current UTC time", Some(0), "UTC"),
+            ("gmt time", "current GMT time", Some(0), "GMT"),
+            ("time gmt", "current GMT time", Some(0), "GMT"),
+        ];
+
+        for &(phrase, label, offset_secs, tz_abbrev) in current_time_phrases {
+            if trimmed == phrase {
+                return Some(Self::now_with_label(
+                    label,
+                    offset_secs,
+                    Some(tz_abbrev.to_string()),
+                ));
+            }
+        }
+
+        // "current <TZ> time" or "<TZ> time" patterns
+        for prefix in &["current ", ""] {
+            if let Some(rest) = trimmed.strip_prefix(prefix) {
+                if let Some(tz_part) = rest.strip_suffix(" time") {
+                    let tz_part = tz_part.trim();
+                    if let Some(offset) = parse_tz_abbreviation(tz_part) {
+                        let tz_upper = tz_part.to_uppercase();
+                        let label = format!("current {tz_upper} time");
+                        let offset_secs = offset.local_minus_utc();
+                        return Some(Self::now_with_label(
+                            label,
+                            Some(offset_secs),
+                            Some(tz_upper),
+                        ));
+                    }
+                }
+                if let Some(tz_part) = rest.strip_prefix("time ") {
+                    let tz_part = tz_part.trim();
+                    if let Some(offset) = parse_tz_abbreviation(tz_part) {
+                        let tz_upper = tz_part.to_uppercase();
+                        let label = format!("current {tz_upper} time");
+                        let offset_secs = offset.local_minus_utc();
+                        return Some(Self::now_with_label(
+                            label,
+                            Some(offset_secs),
+                            Some(tz_upper),
+                        ));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
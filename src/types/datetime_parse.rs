@@ -463,7 +463,7 @@ pub(super) fn extract_timezone(input: &str) -> (&str, Option<FixedOffset>, Optio
     }) {
         let offset_str = &input[idx..];
         if let Some(offset) = parse_offset(offset_str) {
-            return (&input[..idx], Some(offset), None);
+            return (input[..idx].trim_end(), Some(offset), None);
         }
     }
 
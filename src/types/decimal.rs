@@ -1,11 +1,13 @@
 //! Decimal number type for precise calculations.
 
-use rust_decimal::Decimal as RustDecimal;
+use rust_decimal::{Decimal as RustDecimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::str::FromStr;
 
+use crate::error::CalculatorError;
+
 /// A decimal number with arbitrary precision.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Decimal(RustDecimal);
@@ -19,16 +21,35 @@ impl Decimal {
 
     /// Creates a new Decimal from a float (may lose precision).
     /// Returns None if the conversion fails.
+    ///
+    /// The shortest digits that read back as the same float are used, so
+    /// that `erf(1)` keeps all of `0.8427007929497148` and `1e25` stays
+    /// `10000000000000000000000000` instead of gaining binary noise.
     #[must_use]
     pub fn try_from_f64(value: f64) -> Option<Self> {
-        RustDecimal::try_from(value).ok().map(Self)
+        if !value.is_finite() {
+            return None;
+        }
+        RustDecimal::from_str(&value.to_string())
+            .ok()
+            .or_else(|| RustDecimal::try_from(value).ok())
+            .map(Self)
     }
 
-    /// Creates a new Decimal from a float, defaulting to zero on failure.
-    /// Useful for mathematical functions where we expect valid results.
+    /// Creates a Decimal equal to `mantissa / 10^scale`, or `None` if it
+    /// does not fit in 96 bits or the scale is past 28.
     #[must_use]
-    pub fn from_f64(value: f64) -> Self {
-        Self::try_from_f64(value).unwrap_or_else(Self::zero)
+    pub fn from_scaled(mantissa: i128, scale: u32) -> Option<Self> {
+        RustDecimal::try_from_i128_with_scale(mantissa, scale)
+            .ok()
+            .map(Self)
+    }
+
+    /// Creates a new Decimal from a float, failing with
+    /// [`CalculatorError::Overflow`] if it is not finite or is too large for
+    /// a Decimal, rather than quietly becoming zero.
+    pub fn from_f64(value: f64) -> Result<Self, CalculatorError> {
+        Self::try_from_f64(value).ok_or(CalculatorError::Overflow)
     }
 
     /// Returns zero.
@@ -68,12 +89,67 @@ impl Decimal {
         self.0.to_f64().unwrap_or(0.0)
     }
 
+    /// Converts to i64 if the value is a whole number that fits.
+    #[must_use]
+    pub fn to_i64(&self) -> Option<i64> {
+        use rust_decimal::prelude::ToPrimitive;
+        if self.0.fract().is_zero() {
+            self.0.to_i64()
+        } else {
+            None
+        }
+    }
+
     /// Rounds to the specified number of decimal places.
     #[must_use]
     pub fn round(&self, dp: u32) -> Self {
         Self(self.0.round_dp(dp))
     }
 
+    /// Rounds to the specified number of decimal places, with halves
+    /// rounded away from zero (`2.5` becomes `3`, `-2.5` becomes `-3`).
+    #[must_use]
+    pub fn round_half_away_from_zero(&self, dp: u32) -> Self {
+        Self(
+            self.0
+                .round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero),
+        )
+    }
+
+    /// Rounds down to an integer.
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self(self.0.floor())
+    }
+
+    /// Rounds up to an integer.
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self(self.0.ceil())
+    }
+
+    /// Drops the fractional part.
+    #[must_use]
+    pub fn trunc(&self) -> Self {
+        Self(self.0.trunc())
+    }
+
+    /// Returns `-1`, `0` or `1` by the sign of the value.
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        match self.0.cmp(&RustDecimal::ZERO) {
+            std::cmp::Ordering::Less => Self::new(-1),
+            std::cmp::Ordering::Equal => Self::zero(),
+            std::cmp::Ordering::Greater => Self::one(),
+        }
+    }
+
+    /// Checked multiplication that returns None on overflow.
+    #[must_use]
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(Self)
+    }
+
     /// Normalizes the decimal (removes trailing zeros).
     #[must_use]
     pub fn normalize(&self) -> Self {
@@ -204,6 +280,28 @@ mod tests {
         assert!(a.checked_div(&zero).is_none());
     }
 
+    #[test]
+    fn test_decimal_from_large_f64_has_no_binary_noise() {
+        assert_eq!(
+            Decimal::from_f64(1e25).unwrap().to_string(),
+            "10000000000000000000000000"
+        );
+        assert_eq!(
+            Decimal::from_f64(0.1 + 0.2).unwrap().to_string(),
+            "0.30000000000000004"
+        );
+    }
+
+    #[test]
+    fn test_decimal_rounding() {
+        let x: Decimal = "-2.5".parse().unwrap();
+        assert_eq!(x.floor().to_string(), "-3");
+        assert_eq!(x.ceil().to_string(), "-2");
+        assert_eq!(x.trunc().to_string(), "-2");
+        assert_eq!(x.signum().to_string(), "-1");
+        assert_eq!(Decimal::zero().signum().to_string(), "0");
+    }
+
     #[test]
     fn test_decimal_is_negative() {
         let pos = Decimal::new(5);
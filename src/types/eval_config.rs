@@ -0,0 +1,68 @@
+//! Limits that keep pathological inputs from hanging the calculator.
+//!
+//! The WASM build runs on a single thread with a small stack, so a deeply
+//! nested or enormous computation would freeze the page or crash it. Each
+//! limit fails the calculation with [`crate::error::CalculatorError::LimitExceeded`]
+//! instead.
+
+use serde::{Deserialize, Serialize};
+
+/// Limits enforced while parsing and evaluating.
+///
+/// The defaults accept anything a person would reasonably type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvalConfig {
+    /// Maximum nesting depth of an expression (parentheses, operators,
+    /// function calls), which bounds recursion during parsing and evaluation.
+    pub max_depth: usize,
+    /// Maximum number of subintervals numerical integration splits the
+    /// range into.
+    pub max_integration_subdivisions: usize,
+    /// Error numerical integration aims for, absolute or relative to the
+    /// result, whichever is larger.
+    pub integration_tolerance: f64,
+    /// How close `root(...)` gets to the root before it stops.
+    pub root_tolerance: f64,
+    /// Maximum number of steps `root(...)` takes to find a root.
+    pub max_root_iterations: usize,
+    /// Largest `n` accepted by `factorial(n)`, `n!` and `ncr(n, k)`.
+    pub max_factorial_input: u64,
+    /// Number of results kept in the history; older ones are forgotten.
+    pub max_history_entries: usize,
+    /// Maximum number of evaluation steps per calculation, if limited.
+    pub operation_budget: Option<u64>,
+    /// Longest input, in bytes, accepted from untrusted sources (see
+    /// [`crate::grammar::ExpressionParser::parse_untrusted`]), and longest
+    /// number literal accepted from any source.
+    pub max_input_length: usize,
+}
+
+impl Default for EvalConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 200,
+            max_integration_subdivisions: 1000,
+            integration_tolerance: 1e-10,
+            root_tolerance: 1e-12,
+            max_root_iterations: 100,
+            max_factorial_input: 10_000,
+            max_history_entries: 1000,
+            operation_budget: None,
+            max_input_length: 10_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_json_keeps_defaults() {
+        let config: EvalConfig = serde_json::from_str(r#"{"max_depth": 16}"#).unwrap();
+        assert_eq!(config.max_depth, 16);
+        assert_eq!(config.max_history_entries, 1000);
+        assert_eq!(config.operation_budget, None);
+    }
+}
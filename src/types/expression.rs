@@ -1,9 +1,13 @@
 //! Expression types for the calculator grammar.
 
+mod canonical;
+mod inspect;
+mod latex;
+
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use crate::types::{DateTime, Decimal, Unit};
+use crate::types::{Color, ColorModel, DateTime, Decimal, Precision, Rational, Unit};
 
 /// A binary operation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -88,9 +92,15 @@ impl fmt::Display for ComparisonOp {
 /// An expression in the calculator grammar.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Expression {
-    /// A literal number.
+    /// Quoted text: `"hello"`.
+    Text(String),
+    /// A literal number, with every digit it was written with.
     Number {
-        value: Decimal,
+        #[serde(
+            serialize_with = "serialize_literal",
+            deserialize_with = "deserialize_literal"
+        )]
+        value: Rational,
         unit: Unit,
         /// Alternative unit interpretations for ambiguous identifiers
         /// (e.g., "ton" → Mass(MetricTon) primary, Currency("TON") alternative).
@@ -103,6 +113,11 @@ pub enum Expression {
     Now,
     /// The current calendar date ("today").
     Today,
+    /// A reference to an earlier result: `ans` (the latest) or `#3` (the third).
+    HistoryRef(Option<usize>),
+    /// The earlier results from the first to the last number, as a list:
+    /// `#2:#5`.
+    HistoryRange(usize, usize),
     /// "until <datetime>" - duration from now to a target datetime.
     Until(Box<Expression>),
     /// A binary operation.
@@ -159,14 +174,57 @@ pub enum Expression {
         /// The right-hand side expression.
         right: Box<Expression>,
     },
+    /// A list literal (e.g., `[1, 2, 3]`).
+    List(Vec<Expression>),
+    /// A display precision for the result (e.g., `1/3 to 2 dp`).
+    ///
+    /// The value is computed exactly; only the displayed result is rounded.
+    WithPrecision {
+        /// The expression whose result is rounded for display.
+        value: Box<Expression>,
+        /// The requested precision.
+        precision: Precision,
+    },
+    /// An exchange rate lookup (e.g., `USD/EUR rate`, `EUR to USD rate`).
+    ///
+    /// Evaluates to how many units of `to` one unit of `from` buys, on the
+    /// date of an enclosing `at` if there is one.
+    RateLookup {
+        /// The currency being priced.
+        from: String,
+        /// The currency the rate is quoted in.
+        to: String,
+    },
+}
+
+/// Writes the value of a number literal as its digits: `"2.5"`.
+fn serialize_literal<S>(value: &Rational, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    value.to_literal_string().serialize(serializer)
+}
+
+fn deserialize_literal<'de, D>(deserializer: D) -> Result<Rational, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let digits = String::deserialize(deserializer)?;
+    Rational::parse_decimal_digits(&digits)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid number: {digits}")))
+}
+
+/// Returns true for the constants `pi` and `e`, which are calls without arguments.
+fn is_constant_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case("pi") || name.eq_ignore_ascii_case("e")
 }
 
 impl Expression {
     /// Creates a number expression.
     #[must_use]
-    pub fn number(value: Decimal) -> Self {
+    pub fn number(value: impl Into<Rational>) -> Self {
         Self::Number {
-            value,
+            value: value.into(),
             unit: Unit::None,
             alternative_units: Vec::new(),
         }
@@ -174,9 +232,9 @@ impl Expression {
 
     /// Creates a number expression with a unit.
     #[must_use]
-    pub fn number_with_unit(value: Decimal, unit: Unit) -> Self {
+    pub fn number_with_unit(value: impl Into<Rational>, unit: Unit) -> Self {
         Self::Number {
-            value,
+            value: value.into(),
             unit,
             alternative_units: Vec::new(),
         }
@@ -185,12 +243,12 @@ impl Expression {
     /// Creates a number expression with a unit and alternative unit interpretations.
     #[must_use]
     pub fn number_with_unit_alternatives(
-        value: Decimal,
+        value: impl Into<Rational>,
         unit: Unit,
         alternative_units: Vec<Unit>,
     ) -> Self {
         Self::Number {
-            value,
+            value: value.into(),
             unit,
             alternative_units,
         }
@@ -200,7 +258,7 @@ impl Expression {
     #[must_use]
     pub fn currency(amount: Decimal, code: &str) -> Self {
         Self::Number {
-            value: amount,
+            value: amount.into(),
             unit: Unit::currency(code),
             alternative_units: Vec::new(),
         }
@@ -298,6 +356,39 @@ impl Expression {
         }
     }
 
+    /// Creates a list literal.
+    #[must_use]
+    pub fn list(items: Vec<Expression>) -> Self {
+        Self::List(items)
+    }
+
+    /// Creates a display precision directive (e.g., `1/3 to 2 dp`).
+    #[must_use]
+    pub fn with_precision(value: Expression, precision: Precision) -> Self {
+        Self::WithPrecision {
+            value: Box::new(value),
+            precision,
+        }
+    }
+
+    /// Creates an exchange rate lookup (e.g., `USD/EUR rate`).
+    #[must_use]
+    pub fn rate_lookup(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self::RateLookup {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// Returns the display precision requested for the whole expression.
+    #[must_use]
+    pub fn display_precision(&self) -> Option<Precision> {
+        match self {
+            Self::WithPrecision { precision, .. } => Some(*precision),
+            _ => None,
+        }
+    }
+
     /// Converts the expression to links notation format.
     ///
     /// Links notation wraps all compound expressions in parentheses:
@@ -337,7 +428,7 @@ impl Expression {
                 value.apply_local_offset(offset_seconds);
                 time.apply_local_offset(offset_seconds);
             }
-            Self::FunctionCall { args, .. } => {
+            Self::FunctionCall { args, .. } | Self::List(args) => {
                 for arg in args {
                     arg.apply_local_offset(offset_seconds);
                 }
@@ -345,8 +436,17 @@ impl Expression {
             Self::IndefiniteIntegral { integrand, .. } => {
                 integrand.apply_local_offset(offset_seconds);
             }
-            Self::UnitConversion { value, .. } => value.apply_local_offset(offset_seconds),
-            Self::Number { .. } | Self::Now | Self::Today | Self::Variable(_) => {}
+            Self::UnitConversion { value, .. } | Self::WithPrecision { value, .. } => {
+                value.apply_local_offset(offset_seconds);
+            }
+            Self::Number { .. }
+            | Self::Text(_)
+            | Self::Now
+            | Self::Today
+            | Self::HistoryRef(_)
+            | Self::HistoryRange(..)
+            | Self::Variable(_)
+            | Self::RateLookup { .. } => {}
         }
     }
 
@@ -355,8 +455,13 @@ impl Expression {
     /// if we need parentheses for this subexpression.
     fn to_lino_internal(&self, _parent_op: Option<&BinaryOp>) -> String {
         match self {
+            Self::Number {
+                unit: Unit::Color(_),
+                ..
+            }
+            | Self::Text(_) => self.to_string(),
             Self::Number { value, unit, .. } => {
-                let num_str = value.to_string();
+                let num_str = value.to_literal_string();
                 if *unit == Unit::None {
                     num_str
                 } else {
@@ -366,6 +471,9 @@ impl Expression {
             Self::DateTime(dt) => format!("({})", dt),
             Self::Now => "(now)".to_string(),
             Self::Today => "(today)".to_string(),
+            Self::HistoryRef(_) | Self::HistoryRange(..) | Self::RateLookup { .. } => {
+                format!("({self})")
+            }
             Self::Until(inner) => {
                 let inner_str = inner.to_lino_internal(None);
                 format!("(until {inner_str})")
@@ -430,6 +538,10 @@ impl Expression {
                 let target = target_unit.conversion_target_name();
                 format!("({value_str} as {target})")
             }
+            Self::WithPrecision { value, precision } => {
+                let value_str = value.to_lino_internal(None);
+                format!("({value_str} to {precision})")
+            }
             Self::Equality { left, right } => {
                 let left_str = left.to_lino_internal(None);
                 let right_str = right.to_lino_internal(None);
@@ -444,6 +556,14 @@ impl Expression {
                     format!("({left_str} {op} {right_str})")
                 }
             }
+            Self::List(items) => {
+                let items_str = items
+                    .iter()
+                    .map(|item| item.to_lino_internal(None))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("(list ({items_str}))")
+            }
         }
     }
 
@@ -492,7 +612,7 @@ impl Expression {
                 ..
             } if !alternative_units.is_empty() => {
                 for alt_unit in alternative_units {
-                    let num_str = value.to_string();
+                    let num_str = value.to_literal_string();
                     let alt = if *alt_unit == Unit::None {
                         num_str
                     } else {
@@ -609,308 +729,48 @@ impl Expression {
                 | Self::UnitConversion { .. }
         )
     }
-
-    /// Returns true if this expression will evaluate to a DateTime value.
-    /// Used to determine if the result should auto-refresh (for countdown/elapsed display).
-    #[must_use]
-    pub fn evaluates_to_datetime(&self) -> bool {
-        match self {
-            Self::DateTime(_) | Self::Now | Self::Today => true,
-            Self::Group(inner) => inner.evaluates_to_datetime(),
-            _ => false,
-        }
-    }
-
-    /// Returns true if this expression contains a live time reference
-    /// (e.g., "now", "current UTC time", "UTC time").
-    /// Used to determine if the result should auto-refresh.
-    #[must_use]
-    pub fn contains_live_time(&self) -> bool {
-        match self {
-            Self::DateTime(dt) => dt.is_live_time(),
-            Self::Now => true,
-            Self::Today => true,
-            Self::Until(inner) => inner.contains_live_time(),
-            Self::Binary { left, right, .. } => {
-                left.contains_live_time() || right.contains_live_time()
-            }
-            Self::Negate(inner) | Self::Group(inner) => inner.contains_live_time(),
-            Self::AtTime { value, time } => value.contains_live_time() || time.contains_live_time(),
-            Self::FunctionCall { args, .. } => args.iter().any(Self::contains_live_time),
-            Self::Power { base, exponent } => {
-                base.contains_live_time() || exponent.contains_live_time()
-            }
-            Self::UnitConversion { value, .. } => value.contains_live_time(),
-            Self::Equality { left, right } | Self::Comparison { left, right, .. } => {
-                left.contains_live_time() || right.contains_live_time()
-            }
-            Self::IndefiniteIntegral { integrand, .. } => integrand.contains_live_time(),
-            Self::Number { .. } | Self::Variable(_) => false,
-        }
-    }
-
-    /// Collects all currency codes referenced in this expression.
-    ///
-    /// Walks the AST and gathers every `Unit::Currency(code)` value, including
-    /// target units in `UnitConversion` nodes. The returned set contains
-    /// uppercase currency codes (e.g., "USD", "RUB", "TON").
-    #[must_use]
-    pub fn collect_currencies(&self) -> std::collections::HashSet<String> {
-        let mut currencies = std::collections::HashSet::new();
-        self.collect_currencies_inner(&mut currencies);
-        currencies
-    }
-
-    fn collect_currencies_inner(&self, currencies: &mut std::collections::HashSet<String>) {
-        match self {
-            Self::Number { unit, .. } => {
-                if let Unit::Currency(code) = unit {
-                    currencies.insert(code.to_uppercase());
-                }
-            }
-            Self::Binary { left, right, .. }
-            | Self::Power {
-                base: left,
-                exponent: right,
-            }
-            | Self::Equality { left, right }
-            | Self::Comparison { left, right, .. } => {
-                left.collect_currencies_inner(currencies);
-                right.collect_currencies_inner(currencies);
-            }
-            Self::Negate(inner) | Self::Group(inner) | Self::Until(inner) => {
-                inner.collect_currencies_inner(currencies);
-            }
-            Self::AtTime { value, time } => {
-                value.collect_currencies_inner(currencies);
-                time.collect_currencies_inner(currencies);
-            }
-            Self::FunctionCall { args, .. } => {
-                for arg in args {
-                    arg.collect_currencies_inner(currencies);
-                }
-            }
-            Self::IndefiniteIntegral { integrand, .. } => {
-                integrand.collect_currencies_inner(currencies);
-            }
-            Self::UnitConversion { value, target_unit } => {
-                value.collect_currencies_inner(currencies);
-                if let Unit::Currency(code) = target_unit {
-                    currencies.insert(code.to_uppercase());
-                }
-            }
-            Self::DateTime(_) | Self::Now | Self::Today | Self::Variable(_) => {}
-        }
-    }
-
-    /// Returns the depth of the expression tree.
-    #[must_use]
-    pub fn depth(&self) -> usize {
-        match self {
-            Self::Number { .. }
-            | Self::DateTime(_)
-            | Self::Variable(_)
-            | Self::Now
-            | Self::Today => 1,
-            Self::Binary { left, right, .. }
-            | Self::Power {
-                base: left,
-                exponent: right,
-            }
-            | Self::Comparison { left, right, .. } => 1 + left.depth().max(right.depth()),
-            Self::Negate(inner) | Self::Group(inner) | Self::Until(inner) => 1 + inner.depth(),
-            Self::AtTime { value, time } => 1 + value.depth().max(time.depth()),
-            Self::FunctionCall { args, .. } => {
-                1 + args.iter().map(Expression::depth).max().unwrap_or(0)
-            }
-            Self::IndefiniteIntegral { integrand, .. } => 1 + integrand.depth(),
-            Self::UnitConversion { value, .. } => 1 + value.depth(),
-            Self::Equality { left, right } => 1 + left.depth().max(right.depth()),
-        }
-    }
-
-    /// Converts the expression to a LaTeX representation.
-    #[must_use]
-    pub fn to_latex(&self) -> String {
-        match self {
-            Self::Number { value, unit, .. } => {
-                let num_str = value.to_string();
-                if *unit == Unit::None {
-                    num_str
-                } else {
-                    format!("{num_str} \\text{{{unit}}}")
-                }
-            }
-            Self::DateTime(dt) => format!("\\text{{{dt}}}"),
-            Self::Now => "\\text{now}".to_string(),
-            Self::Today => "\\text{today}".to_string(),
-            Self::Until(inner) => {
-                format!("\\text{{until }} {}", inner.to_latex())
-            }
-            Self::Binary { left, op, right } => {
-                let left_str = left.to_latex();
-                let right_str = right.to_latex();
-                match op {
-                    BinaryOp::Add => format!("{left_str} + {right_str}"),
-                    BinaryOp::Subtract => format!("{left_str} - {right_str}"),
-                    BinaryOp::Multiply => format!("{left_str} \\cdot {right_str}"),
-                    BinaryOp::Divide => format!("\\frac{{{left_str}}}{{{right_str}}}"),
-                    BinaryOp::Modulo => format!("{left_str} \\bmod {right_str}"),
-                }
-            }
-            Self::Negate(inner) => format!("-{}", inner.to_latex()),
-            Self::Group(inner) => format!("\\left({} \\right)", inner.to_latex()),
-            Self::AtTime { value, time } => {
-                format!("{} \\text{{ at }} {}", value.to_latex(), time.to_latex())
-            }
-            Self::FunctionCall { name, args } => {
-                let name_lower = name.to_lowercase();
-                match name_lower.as_str() {
-                    "sin" | "cos" | "tan" | "cot" | "sec" | "csc" | "sinh" | "cosh" | "tanh"
-                    | "coth" | "sech" | "csch" | "arcsin" | "arccos" | "arctan" | "ln" | "log"
-                    | "exp" => {
-                        if args.len() == 1 {
-                            format!("\\{name_lower}\\left({} \\right)", args[0].to_latex())
-                        } else {
-                            let args_str = args
-                                .iter()
-                                .map(Expression::to_latex)
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            format!("\\{name_lower}\\left({args_str} \\right)")
-                        }
-                    }
-                    "sqrt" => {
-                        if args.len() == 1 {
-                            format!("\\sqrt{{{}}}", args[0].to_latex())
-                        } else {
-                            let args_str = args
-                                .iter()
-                                .map(Expression::to_latex)
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            format!("\\sqrt{{{args_str}}}")
-                        }
-                    }
-                    "abs" => {
-                        if args.len() == 1 {
-                            format!("\\left| {} \\right|", args[0].to_latex())
-                        } else {
-                            let args_str = args
-                                .iter()
-                                .map(Expression::to_latex)
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            format!("\\left| {args_str} \\right|")
-                        }
-                    }
-                    "factorial" if args.len() == 1 => {
-                        // Render as n! in LaTeX — wrap in braces if compound expression
-                        match args[0] {
-                            Self::Number { .. } | Self::Variable(_) => {
-                                format!("{}!", args[0].to_latex())
-                            }
-                            _ => format!("\\left({}\\right)!", args[0].to_latex()),
-                        }
-                    }
-                    "pi" => "\\pi".to_string(),
-                    "e" => "e".to_string(),
-                    "integrate" => {
-                        if args.len() == 4 {
-                            format!(
-                                "\\int_{{{}}}^{{{}}} {} \\, d{}",
-                                args[2].to_latex(),
-                                args[3].to_latex(),
-                                args[0].to_latex(),
-                                args[1].to_latex()
-                            )
-                        } else {
-                            let args_str = args
-                                .iter()
-                                .map(Expression::to_latex)
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            format!("\\text{{integrate}}({args_str})")
-                        }
-                    }
-                    _ => {
-                        let args_str = args
-                            .iter()
-                            .map(Expression::to_latex)
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("\\text{{{name}}}({args_str})")
-                    }
-                }
-            }
-            Self::Variable(name) => name.clone(),
-            Self::Power { base, exponent } => {
-                let base_latex = base.to_latex();
-                let exp_latex = exponent.to_latex();
-                // Wrap base in braces if it's complex
-                match base.as_ref() {
-                    Self::Number { .. } | Self::Variable(_) => {
-                        format!("{base_latex}^{{{exp_latex}}}")
-                    }
-                    _ => format!("\\left({base_latex}\\right)^{{{exp_latex}}}"),
-                }
-            }
-            Self::IndefiniteIntegral {
-                integrand,
-                variable,
-            } => {
-                format!("\\int {} \\, d{}", integrand.to_latex(), variable)
-            }
-            Self::UnitConversion { value, target_unit } => {
-                let target = target_unit.conversion_target_name();
-                format!("{} \\to \\text{{{target}}}", value.to_latex())
-            }
-            Self::Equality { left, right } => {
-                format!("{} = {}", left.to_latex(), right.to_latex())
-            }
-            Self::Comparison { left, op, right } => {
-                let latex_op = match op {
-                    ComparisonOp::Less => "<",
-                    ComparisonOp::Equal => "=",
-                    ComparisonOp::LessOrEqual => "\\le",
-                    ComparisonOp::Greater => ">",
-                    ComparisonOp::GreaterOrEqual => "\\ge",
-                    ComparisonOp::NotEqual => "\\ne",
-                    ComparisonOp::Compare => "\\operatorname{compare}",
-                };
-                if *op == ComparisonOp::Compare {
-                    format!(
-                        "{}\\left({}, {}\\right)",
-                        latex_op,
-                        left.to_latex(),
-                        right.to_latex()
-                    )
-                } else {
-                    format!("{} {} {}", left.to_latex(), latex_op, right.to_latex())
-                }
-            }
-        }
-    }
 }
 
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Number {
+                value,
+                unit: Unit::Color(_),
+                ..
+            } => match value
+                .to_literal_string()
+                .parse()
+                .ok()
+                .and_then(Color::from_number)
+            {
+                Some(color) => write!(f, "{}", color.format(ColorModel::Hex)),
+                None => write!(f, "{}", value.to_literal_string()),
+            },
             Self::Number { value, unit, .. } => {
+                let value = value.to_literal_string();
                 if *unit == Unit::None {
                     write!(f, "{value}")
                 } else {
                     write!(f, "{value} {unit}")
                 }
             }
+            Self::Text(text) => write!(f, "{text:?}"),
             Self::DateTime(dt) => write!(f, "{dt}"),
             Self::Now => write!(f, "now"),
             Self::Today => write!(f, "today"),
+            Self::HistoryRef(None) => write!(f, "ans"),
+            Self::HistoryRef(Some(index)) => write!(f, "#{index}"),
+            Self::HistoryRange(first, last) => write!(f, "#{first}:#{last}"),
             Self::Until(inner) => write!(f, "until {inner}"),
             Self::Binary { left, op, right } => write!(f, "{left} {op} {right}"),
             Self::Negate(inner) => write!(f, "-{inner}"),
             Self::Group(inner) => write!(f, "({inner})"),
             Self::AtTime { value, time } => write!(f, "{value} at {time}"),
+            // The constants read as plain names, so `e^x` does not show as `e()^x`
+            Self::FunctionCall { name, args } if args.is_empty() && is_constant_name(name) => {
+                write!(f, "{name}")
+            }
             Self::FunctionCall { name, args } => {
                 let args_str = args
                     .iter()
@@ -931,6 +791,8 @@ impl fmt::Display for Expression {
                 let target = target_unit.conversion_target_name();
                 write!(f, "{value} as {target}")
             }
+            Self::WithPrecision { value, precision } => write!(f, "{value} to {precision}"),
+            Self::RateLookup { from, to } => write!(f, "{from}/{to} rate"),
             Self::Equality { left, right } => write!(f, "{left} = {right}"),
             Self::Comparison { left, op, right } => {
                 if *op == ComparisonOp::Compare {
@@ -939,6 +801,14 @@ impl fmt::Display for Expression {
                     write!(f, "{left} {op} {right}")
                 }
             }
+            Self::List(items) => {
+                let items_str = items
+                    .iter()
+                    .map(std::string::ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "[{items_str}]")
+            }
         }
     }
 }
@@ -0,0 +1,128 @@
+//! Canonical formatting for [`Expression`]: the parsed input re-emitted in
+//! a normalized, evenly spaced form.
+
+use super::{ComparisonOp, Expression};
+
+/// How tightly an expression binds: an operand binding looser than its
+/// position requires is wrapped in parentheses.
+mod level {
+    pub const COMPARISON: u8 = 0;
+    pub const SUFFIX: u8 = 1;
+    pub const NEGATE: u8 = 4;
+    pub const POWER: u8 = 5;
+    pub const ATOM: u8 = 6;
+}
+
+impl Expression {
+    /// Re-emits the expression in canonical form: one space around binary
+    /// operators, `, ` between arguments, and parentheses exactly where the
+    /// grammar needs them, so `((2+3))*4` becomes `(2 + 3) * 4`.
+    ///
+    /// Unlike [`Self::to_lino`], which parenthesizes every operation, the
+    /// canonical form reads like the input and parses back to the same
+    /// expression.
+    #[must_use]
+    pub fn to_canonical_string(&self) -> String {
+        match self {
+            Self::Group(inner) => inner.to_canonical_string(),
+            Self::Until(inner) => format!("until {}", inner.to_canonical_string()),
+            Self::Binary { left, op, right } => {
+                let precedence = op.precedence() + level::SUFFIX;
+                format!(
+                    "{} {op} {}",
+                    left.canonical_operand(precedence),
+                    right.canonical_operand(precedence + 1)
+                )
+            }
+            // A power binds tighter than a sign: -x^2 is -(x^2)
+            Self::Negate(inner) => format!("-{}", inner.canonical_operand(level::POWER)),
+            Self::AtTime { value, time } => format!(
+                "{} at {}",
+                value.canonical_operand(level::ATOM),
+                time.canonical_operand(level::ATOM)
+            ),
+            Self::FunctionCall { name, args } => {
+                format!("{name}({})", canonical_list(args))
+            }
+            Self::Power { base, exponent } => format!(
+                "{}^{}",
+                base.canonical_operand(level::ATOM),
+                exponent.canonical_operand(level::POWER)
+            ),
+            Self::IndefiniteIntegral {
+                integrand,
+                variable,
+            } => format!("integrate {} d{variable}", integrand.to_canonical_string()),
+            Self::UnitConversion { value, target_unit } => format!(
+                "{} as {}",
+                value.canonical_operand(level::SUFFIX + 1),
+                target_unit.conversion_target_name()
+            ),
+            Self::WithPrecision { value, precision } => format!(
+                "{} to {precision}",
+                value.canonical_operand(level::SUFFIX + 1)
+            ),
+            Self::Equality { left, right } => format!(
+                "{} = {}",
+                left.canonical_operand(level::SUFFIX),
+                right.canonical_operand(level::SUFFIX)
+            ),
+            Self::Comparison { left, op, right } => {
+                let (left, right) = (
+                    left.canonical_operand(level::SUFFIX),
+                    right.canonical_operand(level::SUFFIX),
+                );
+                if *op == ComparisonOp::Compare {
+                    format!("compare {left} and {right}")
+                } else {
+                    format!("{left} {op} {right}")
+                }
+            }
+            Self::List(items) => format!("[{}]", canonical_list(items)),
+            Self::Number { .. }
+            | Self::Text(_)
+            | Self::DateTime(_)
+            | Self::Now
+            | Self::Today
+            | Self::HistoryRef(_)
+            | Self::HistoryRange(..)
+            | Self::Variable(_)
+            | Self::RateLookup { .. } => self.to_string(),
+        }
+    }
+
+    /// Formats an operand, parenthesized if it binds looser than `min_level`.
+    fn canonical_operand(&self, min_level: u8) -> String {
+        let text = self.to_canonical_string();
+        if self.binding_level() < min_level {
+            format!("({text})")
+        } else {
+            text
+        }
+    }
+
+    fn binding_level(&self) -> u8 {
+        match self {
+            Self::Group(inner) => inner.binding_level(),
+            Self::Equality { .. } | Self::Comparison { .. } => level::COMPARISON,
+            Self::Until(_)
+            | Self::AtTime { .. }
+            | Self::IndefiniteIntegral { .. }
+            | Self::UnitConversion { .. }
+            | Self::WithPrecision { .. } => level::SUFFIX,
+            Self::Binary { op, .. } => op.precedence() + level::SUFFIX,
+            Self::Negate(_) => level::NEGATE,
+            Self::Number { value, .. } if value.is_negative() => level::NEGATE,
+            Self::Power { .. } => level::POWER,
+            _ => level::ATOM,
+        }
+    }
+}
+
+fn canonical_list(items: &[Expression]) -> String {
+    items
+        .iter()
+        .map(Expression::to_canonical_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,180 @@
+//! Queries over an [`Expression`] tree: what kind of expression it is,
+//! whether it reads the clock, which currencies it uses and how deep it is.
+
+use super::Expression;
+use crate::types::Unit;
+
+impl Expression {
+    /// Returns what kind of expression this is, such as `"binary operation"`
+    /// or `"unit conversion"`, without any of the values written in it.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Number { .. } => "number",
+            Self::Text(_) => "text",
+            Self::DateTime(_) => "datetime",
+            Self::Now => "current time",
+            Self::Today => "today's date",
+            Self::HistoryRef(_) => "history reference",
+            Self::HistoryRange(..) => "history range",
+            Self::Until(_) => "time until",
+            Self::Binary { .. } => "binary operation",
+            Self::Negate(_) => "negation",
+            Self::Group(inner) => inner.kind(),
+            Self::AtTime { .. } => "value at time",
+            Self::FunctionCall { .. } => "function call",
+            Self::Variable(_) => "variable",
+            Self::Power { .. } => "power",
+            Self::IndefiniteIntegral { .. } => "indefinite integral",
+            Self::UnitConversion { .. } => "unit conversion",
+            Self::Equality { .. } => "equation",
+            Self::Comparison { .. } => "comparison",
+            Self::List(_) => "list",
+            Self::WithPrecision { .. } => "precision directive",
+            Self::RateLookup { .. } => "rate lookup",
+        }
+    }
+
+    /// Returns true if this expression will evaluate to a DateTime value.
+    /// Used to determine if the result should auto-refresh (for countdown/elapsed display).
+    #[must_use]
+    pub fn evaluates_to_datetime(&self) -> bool {
+        match self {
+            Self::DateTime(_) | Self::Now | Self::Today => true,
+            Self::Group(inner) => inner.evaluates_to_datetime(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if this expression contains a live time reference
+    /// (e.g., "now", "current UTC time", "UTC time").
+    /// Used to determine if the result should auto-refresh.
+    #[must_use]
+    pub fn contains_live_time(&self) -> bool {
+        match self {
+            Self::DateTime(dt) => dt.is_live_time(),
+            Self::Now => true,
+            Self::Today => true,
+            Self::HistoryRef(_) | Self::HistoryRange(..) => false,
+            Self::Until(inner) => inner.contains_live_time(),
+            Self::Binary { left, right, .. } => {
+                left.contains_live_time() || right.contains_live_time()
+            }
+            Self::Negate(inner) | Self::Group(inner) => inner.contains_live_time(),
+            Self::AtTime { value, time } => value.contains_live_time() || time.contains_live_time(),
+            Self::FunctionCall { args, .. } | Self::List(args) => {
+                args.iter().any(Self::contains_live_time)
+            }
+            Self::Power { base, exponent } => {
+                base.contains_live_time() || exponent.contains_live_time()
+            }
+            Self::UnitConversion { value, .. } | Self::WithPrecision { value, .. } => {
+                value.contains_live_time()
+            }
+            Self::Equality { left, right } | Self::Comparison { left, right, .. } => {
+                left.contains_live_time() || right.contains_live_time()
+            }
+            Self::IndefiniteIntegral { integrand, .. } => integrand.contains_live_time(),
+            Self::Number { .. } | Self::Text(_) | Self::Variable(_) | Self::RateLookup { .. } => {
+                false
+            }
+        }
+    }
+
+    /// Collects all currency codes referenced in this expression.
+    ///
+    /// Walks the AST and gathers every `Unit::Currency(code)` value, including
+    /// target units in `UnitConversion` nodes. The returned set contains
+    /// uppercase currency codes (e.g., "USD", "RUB", "TON").
+    #[must_use]
+    pub fn collect_currencies(&self) -> std::collections::HashSet<String> {
+        let mut currencies = std::collections::HashSet::new();
+        self.collect_currencies_inner(&mut currencies);
+        currencies
+    }
+
+    fn collect_currencies_inner(&self, currencies: &mut std::collections::HashSet<String>) {
+        match self {
+            Self::Number { unit, .. } => {
+                if let Unit::Currency(code) = unit {
+                    currencies.insert(code.to_uppercase());
+                }
+            }
+            Self::Binary { left, right, .. }
+            | Self::Power {
+                base: left,
+                exponent: right,
+            }
+            | Self::Equality { left, right }
+            | Self::Comparison { left, right, .. } => {
+                left.collect_currencies_inner(currencies);
+                right.collect_currencies_inner(currencies);
+            }
+            Self::Negate(inner) | Self::Group(inner) | Self::Until(inner) => {
+                inner.collect_currencies_inner(currencies);
+            }
+            Self::AtTime { value, time } => {
+                value.collect_currencies_inner(currencies);
+                time.collect_currencies_inner(currencies);
+            }
+            Self::FunctionCall { args, .. } | Self::List(args) => {
+                for arg in args {
+                    arg.collect_currencies_inner(currencies);
+                }
+            }
+            Self::IndefiniteIntegral { integrand, .. } => {
+                integrand.collect_currencies_inner(currencies);
+            }
+            Self::UnitConversion { value, target_unit } => {
+                value.collect_currencies_inner(currencies);
+                if let Unit::Currency(code) = target_unit {
+                    currencies.insert(code.to_uppercase());
+                }
+            }
+            Self::WithPrecision { value, .. } => value.collect_currencies_inner(currencies),
+            Self::RateLookup { from, to } => {
+                currencies.insert(from.to_uppercase());
+                currencies.insert(to.to_uppercase());
+            }
+            Self::Text(_)
+            | Self::DateTime(_)
+            | Self::Now
+            | Self::Today
+            | Self::HistoryRef(_)
+            | Self::HistoryRange(..)
+            | Self::Variable(_) => {}
+        }
+    }
+
+    /// Returns the depth of the expression tree.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Number { .. }
+            | Self::Text(_)
+            | Self::DateTime(_)
+            | Self::Variable(_)
+            | Self::Now
+            | Self::Today
+            | Self::HistoryRef(_)
+            | Self::HistoryRange(..)
+            | Self::RateLookup { .. } => 1,
+            Self::Binary { left, right, .. }
+            | Self::Power {
+                base: left,
+                exponent: right,
+            }
+            | Self::Comparison { left, right, .. } => 1 + left.depth().max(right.depth()),
+            Self::Negate(inner) | Self::Group(inner) | Self::Until(inner) => 1 + inner.depth(),
+            Self::AtTime { value, time } => 1 + value.depth().max(time.depth()),
+            Self::FunctionCall { args, .. } | Self::List(args) => {
+                1 + args.iter().map(Expression::depth).max().unwrap_or(0)
+            }
+            Self::IndefiniteIntegral { integrand, .. } => 1 + integrand.depth(),
+            Self::UnitConversion { value, .. } | Self::WithPrecision { value, .. } => {
+                1 + value.depth()
+            }
+            Self::Equality { left, right } => 1 + left.depth().max(right.depth()),
+        }
+    }
+}
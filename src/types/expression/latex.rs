@@ -0,0 +1,214 @@
+//! LaTeX rendering for [`Expression`].
+
+use super::{is_constant_name, BinaryOp, ComparisonOp, Expression};
+use crate::types::Unit;
+
+impl Expression {
+    /// Converts the expression to a LaTeX representation.
+    #[must_use]
+    pub fn to_latex(&self) -> String {
+        match self {
+            Self::Number { value, unit, .. } => {
+                let num_str = value.to_literal_string();
+                if *unit == Unit::None {
+                    num_str
+                } else {
+                    format!("{num_str} \\text{{{unit}}}")
+                }
+            }
+            Self::Text(text) => format!("\\text{{{text:?}}}"),
+            Self::DateTime(dt) => format!("\\text{{{dt}}}"),
+            Self::Now => "\\text{now}".to_string(),
+            Self::Today => "\\text{today}".to_string(),
+            Self::HistoryRef(None) => "\\text{ans}".to_string(),
+            Self::HistoryRef(Some(index)) => format!("\\#{index}"),
+            Self::HistoryRange(first, last) => format!("\\#{first}{{:}}\\#{last}"),
+            Self::Until(inner) => {
+                format!("\\text{{until }} {}", inner.to_latex())
+            }
+            Self::Binary { left, op, right } => {
+                let left_str = left.to_latex();
+                let right_str = right.to_latex();
+                match op {
+                    BinaryOp::Add => format!("{left_str} + {right_str}"),
+                    BinaryOp::Subtract => format!("{left_str} - {right_str}"),
+                    BinaryOp::Multiply => format!("{left_str} \\cdot {right_str}"),
+                    BinaryOp::Divide => format!("\\frac{{{left_str}}}{{{right_str}}}"),
+                    BinaryOp::Modulo => format!("{left_str} \\bmod {right_str}"),
+                }
+            }
+            Self::Negate(inner) => format!("-{}", inner.to_latex()),
+            Self::Group(inner) => format!("\\left({} \\right)", inner.to_latex()),
+            Self::AtTime { value, time } => {
+                format!("{} \\text{{ at }} {}", value.to_latex(), time.to_latex())
+            }
+            Self::FunctionCall { name, args } => {
+                let name_lower = name.to_lowercase();
+                match name_lower.as_str() {
+                    "sin" | "cos" | "tan" | "cot" | "sec" | "csc" | "sinh" | "cosh" | "tanh"
+                    | "coth" | "sech" | "csch" | "arcsin" | "arccos" | "arctan" | "ln" | "log"
+                    | "exp" => {
+                        if args.len() == 1 {
+                            format!("\\{name_lower}\\left({} \\right)", args[0].to_latex())
+                        } else {
+                            let args_str = args
+                                .iter()
+                                .map(Expression::to_latex)
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!("\\{name_lower}\\left({args_str} \\right)")
+                        }
+                    }
+                    "matrix" if args.iter().all(|row| matches!(row, Self::List(_))) => {
+                        let rows = args
+                            .iter()
+                            .filter_map(|row| match row {
+                                Self::List(items) => Some(
+                                    items
+                                        .iter()
+                                        .map(Expression::to_latex)
+                                        .collect::<Vec<_>>()
+                                        .join(" & "),
+                                ),
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" \\\\ ");
+                        format!("\\begin{{bmatrix}} {rows} \\end{{bmatrix}}")
+                    }
+                    "transpose" if args.len() == 1 => format!("{{{}}}^{{T}}", args[0].to_latex()),
+                    "inverse" | "inv" if args.len() == 1 => {
+                        format!("{{{}}}^{{-1}}", args[0].to_latex())
+                    }
+                    "det" | "determinant" if args.len() == 1 => {
+                        format!("\\det {}", args[0].to_latex())
+                    }
+                    "sqrt" => {
+                        if args.len() == 1 {
+                            format!("\\sqrt{{{}}}", args[0].to_latex())
+                        } else {
+                            let args_str = args
+                                .iter()
+                                .map(Expression::to_latex)
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!("\\sqrt{{{args_str}}}")
+                        }
+                    }
+                    "abs" => {
+                        if args.len() == 1 {
+                            format!("\\left| {} \\right|", args[0].to_latex())
+                        } else {
+                            let args_str = args
+                                .iter()
+                                .map(Expression::to_latex)
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!("\\left| {args_str} \\right|")
+                        }
+                    }
+                    "factorial" if args.len() == 1 => {
+                        // Render as n! in LaTeX — wrap in braces if compound expression
+                        match args[0] {
+                            Self::Number { .. } | Self::Variable(_) => {
+                                format!("{}!", args[0].to_latex())
+                            }
+                            _ => format!("\\left({}\\right)!", args[0].to_latex()),
+                        }
+                    }
+                    "pi" => "\\pi".to_string(),
+                    "e" => "e".to_string(),
+                    "integrate" => {
+                        if args.len() == 4 {
+                            format!(
+                                "\\int_{{{}}}^{{{}}} {} \\, d{}",
+                                args[2].to_latex(),
+                                args[3].to_latex(),
+                                args[0].to_latex(),
+                                args[1].to_latex()
+                            )
+                        } else {
+                            let args_str = args
+                                .iter()
+                                .map(Expression::to_latex)
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!("\\text{{integrate}}({args_str})")
+                        }
+                    }
+                    _ => {
+                        let args_str = args
+                            .iter()
+                            .map(Expression::to_latex)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("\\text{{{name}}}({args_str})")
+                    }
+                }
+            }
+            Self::Variable(name) => name.clone(),
+            Self::Power { base, exponent } => {
+                let base_latex = base.to_latex();
+                let exp_latex = exponent.to_latex();
+                // Wrap base in braces if it's complex
+                match base.as_ref() {
+                    Self::Number { .. } | Self::Variable(_) => {
+                        format!("{base_latex}^{{{exp_latex}}}")
+                    }
+                    Self::FunctionCall { name, args }
+                        if args.is_empty() && is_constant_name(name) =>
+                    {
+                        format!("{base_latex}^{{{exp_latex}}}")
+                    }
+                    _ => format!("\\left({base_latex}\\right)^{{{exp_latex}}}"),
+                }
+            }
+            Self::IndefiniteIntegral {
+                integrand,
+                variable,
+            } => {
+                format!("\\int {} \\, d{}", integrand.to_latex(), variable)
+            }
+            Self::UnitConversion { value, target_unit } => {
+                let target = target_unit.conversion_target_name();
+                format!("{} \\to \\text{{{target}}}", value.to_latex())
+            }
+            Self::WithPrecision { value, precision } => {
+                format!("{} \\text{{ to {precision}}}", value.to_latex())
+            }
+            Self::RateLookup { from, to } => format!("\\text{{{from}/{to} rate}}"),
+            Self::Equality { left, right } => {
+                format!("{} = {}", left.to_latex(), right.to_latex())
+            }
+            Self::Comparison { left, op, right } => {
+                let latex_op = match op {
+                    ComparisonOp::Less => "<",
+                    ComparisonOp::Equal => "=",
+                    ComparisonOp::LessOrEqual => "\\le",
+                    ComparisonOp::Greater => ">",
+                    ComparisonOp::GreaterOrEqual => "\\ge",
+                    ComparisonOp::NotEqual => "\\ne",
+                    ComparisonOp::Compare => "\\operatorname{compare}",
+                };
+                if *op == ComparisonOp::Compare {
+                    format!(
+                        "{}\\left({}, {}\\right)",
+                        latex_op,
+                        left.to_latex(),
+                        right.to_latex()
+                    )
+                } else {
+                    format!("{} {} {}", left.to_latex(), latex_op, right.to_latex())
+                }
+            }
+            Self::List(items) => {
+                let items_str = items
+                    .iter()
+                    .map(Expression::to_latex)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("\\left[{items_str}\\right]")
+            }
+        }
+    }
+}
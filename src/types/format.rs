@@ -0,0 +1,505 @@
+//! Number formatting preferences for displaying results.
+//!
+//! Values are always computed exactly; these options only change how the
+//! final number is rendered (digit grouping, decimal separator, rounding)
+//! and how dates are written.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::types::{DateTimeFormat, Rational};
+
+/// A precision requested for one result, e.g. with `1/3 to 2 dp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Precision {
+    /// A fixed number of decimal places (`to 2 dp`).
+    DecimalPlaces(u32),
+    /// A number of significant figures (`to 3 sf`).
+    SignificantFigures(u32),
+}
+
+impl Precision {
+    /// The most decimal places or significant figures a directive may ask
+    /// for; past this the digits are not worth building.
+    pub const MAX_DIGITS: u32 = 100;
+}
+
+impl fmt::Display for Precision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DecimalPlaces(places) => write!(f, "{places} dp"),
+            Self::SignificantFigures(figures) => write!(f, "{figures} sf"),
+        }
+    }
+}
+
+/// How a digit exactly halfway between two roundings is rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round halves away from zero (`2.675` to `2.68`).
+    #[default]
+    HalfUp,
+    /// Round halves to the even neighbour, also called banker's rounding
+    /// (`2.665` to `2.66`, `2.675` to `2.68`).
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Parses a mode name: `half_up` or `half_even` (also `bankers`).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "half_up" => Some(Self::HalfUp),
+            "half_even" | "bankers" | "banker's" => Some(Self::HalfEven),
+            _ => None,
+        }
+    }
+}
+
+/// User preferences for rendering numbers and dates in results.
+///
+/// The default options write numbers canonically (`1234.5`), switching to
+/// scientific notation from `10^15` up and below `10^-6`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatOptions {
+    /// Separator inserted between groups of three integer digits, if any.
+    pub group_separator: Option<char>,
+    /// Character separating the integer part from the fractional part.
+    pub decimal_separator: char,
+    /// Maximum number of fractional digits; extra digits are rounded with
+    /// `rounding_mode`.
+    pub max_fraction_digits: Option<u32>,
+    /// Number of significant figures to round to.
+    pub significant_figures: Option<u32>,
+    /// Keeps trailing zeros up to the rounding precision (`0.50` rather
+    /// than `0.5` for two decimal places).
+    pub pad_to_precision: bool,
+    /// The format of dates and times.
+    pub datetime_format: DateTimeFormat,
+    /// How halves are rounded.
+    pub rounding_mode: RoundingMode,
+    /// Rounds amounts of money to the decimals of their currency (`JPY` to
+    /// whole yen, `USD` to cents) unless a precision is requested. On by
+    /// default.
+    pub round_money: bool,
+    /// Writes amounts of the units of physics quantities, such as volts and
+    /// ohms, and durations under a tenth of a second with the SI prefix that
+    /// leaves between 1 and 1000 before it, as `eng()` does, by the unit's
+    /// symbol (`4.7 kΩ`, `10 Ω`, `12 µs`). Amounts from a tenth up to one
+    /// keep the unprefixed unit (`0.5 A`). On by default.
+    pub si_prefixes: bool,
+    /// Writes numbers of `10^n` or more in scientific notation
+    /// (`1.551121004333099e25` for `25!`), if set. Defaults to `10^15`.
+    pub scientific_above: Option<i32>,
+    /// Writes numbers below `10^n` in scientific notation (`1.2e-7`), if
+    /// set. Defaults to `10^-6`.
+    pub scientific_below: Option<i32>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            group_separator: None,
+            decimal_separator: '.',
+            max_fraction_digits: None,
+            significant_figures: None,
+            pad_to_precision: false,
+            datetime_format: DateTimeFormat::Iso,
+            rounding_mode: RoundingMode::HalfUp,
+            round_money: true,
+            si_prefixes: true,
+            scientific_above: Some(15),
+            scientific_below: Some(-6),
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Returns the grouping and decimal separators used by a UI locale
+    /// such as `en`, `de-DE` or `ru`. Unknown locales keep the defaults.
+    #[must_use]
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let (group_separator, decimal_separator) = match language.as_str() {
+            "en" | "hi" | "zh" | "ar" => (',', '.'),
+            "de" => ('.', ','),
+            "fr" | "ru" => ('\u{a0}', ','),
+            _ => return Self::default(),
+        };
+
+        Self {
+            group_separator: Some(group_separator),
+            decimal_separator,
+            ..Self::default()
+        }
+    }
+
+    /// Returns these options with `precision` replacing any rounding
+    /// preferences, keeping trailing zeros so the precision is visible.
+    #[must_use]
+    pub fn with_precision(&self, precision: Precision) -> Self {
+        let (max_fraction_digits, significant_figures) = match precision {
+            Precision::DecimalPlaces(places) => (Some(places), None),
+            Precision::SignificantFigures(figures) => (None, Some(figures)),
+        };
+        Self {
+            max_fraction_digits,
+            significant_figures,
+            pad_to_precision: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns these options rounding an amount to `decimals` places, as
+    /// the currency of the amount is written, when money is rounded and no
+    /// other rounding is asked for. A tiny amount rounds rather than being
+    /// written in scientific notation: `0.0000001 USD` is `0 USD`.
+    #[must_use]
+    pub fn for_money(&self, decimals: u32) -> Self {
+        if !self.round_money
+            || self.max_fraction_digits.is_some()
+            || self.significant_figures.is_some()
+        {
+            return self.clone();
+        }
+        Self {
+            max_fraction_digits: Some(decimals),
+            scientific_below: None,
+            ..self.clone()
+        }
+    }
+
+    /// Writes `number` in scientific notation, with the separators and
+    /// rounding of these options applied to the mantissa, when it is past
+    /// the thresholds. Returns `None` otherwise.
+    #[must_use]
+    pub fn scientific(&self, number: &Rational) -> Option<String> {
+        if self.scientific_above.is_none() && self.scientific_below.is_none() {
+            return None;
+        }
+        let exponent = number.decimal_exponent()?;
+        let large = self.scientific_above.is_some_and(|above| exponent >= above);
+        let small = self.scientific_below.is_some_and(|below| exponent < below);
+        if !(large || small) {
+            return None;
+        }
+        let (mantissa, exponent) = number.scientific_parts(Rational::SCIENTIFIC_DECIMALS)?;
+        Some(format!(
+            "{}e{exponent}",
+            self.format_number(&mantissa.to_string())
+        ))
+    }
+
+    /// Formats an exact number. A precision is rounded from the exact value
+    /// rather than from the 16 digits a repeating decimal is shown with, so
+    /// `1/3` to 20 places is `0.33333333333333333333`.
+    #[must_use]
+    pub fn format_rational(&self, number: &Rational) -> String {
+        let figures_places = self.significant_figures.filter(|&n| n > 0).map(|figures| {
+            let exponent = number.decimal_exponent().unwrap_or_default();
+            (i64::from(figures) - 1 - i64::from(exponent)).max(0)
+        });
+        let places = [self.max_fraction_digits.map(i64::from), figures_places]
+            .into_iter()
+            .flatten()
+            .max();
+        let Some(places) = places else {
+            return self.format_number(&number.to_display_string());
+        };
+        let whole_digits = number.decimal_exponent().unwrap_or_default().max(0) + 1;
+        if places + i64::from(whole_digits) > i64::from(Rational::MAX_DISPLAY_DIGITS) {
+            let scientific = self
+                .significant_figures
+                .and_then(|figures| number.scientific_parts(figures.saturating_sub(1)))
+                .map_or_else(
+                    || number.to_scientific_string(),
+                    |(mantissa, exponent)| format!("{mantissa}e{exponent}"),
+                );
+            return self.format_number(&scientific);
+        }
+        // One digit more than is kept decides the rounding
+        let places = u32::try_from(places + 1).unwrap_or(u32::MAX);
+        self.format_number(&number.to_rounding_digits(places))
+    }
+
+    /// Formats a number calculated to limited precision, such as
+    /// `sqrt(2)`, which is shown to 16 significant digits unless a
+    /// precision asks for more of its digits.
+    #[must_use]
+    pub fn format_inexact(&self, number: &Rational) -> String {
+        if self.max_fraction_digits.is_none() && self.significant_figures.is_none() {
+            return self.format_number(&number.to_rounded_display_string());
+        }
+        self.format_rational(number)
+    }
+
+    /// Returns true if these options leave numbers in canonical form.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.group_separator.is_none()
+            && self.decimal_separator == '.'
+            && self.max_fraction_digits.is_none()
+            && self.significant_figures.is_none()
+    }
+
+    /// Formats a canonical number string such as `-1234.5678`.
+    ///
+    /// Strings that are not plain decimal numbers are returned unchanged.
+    #[must_use]
+    pub fn format_number(&self, number: &str) -> String {
+        if self.is_default() {
+            return number.to_string();
+        }
+
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, number),
+        };
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+        let is_plain = !int_part.is_empty()
+            && int_part.bytes().all(|b| b.is_ascii_digit())
+            && frac_part.bytes().all(|b| b.is_ascii_digit());
+        if !is_plain {
+            return number.to_string();
+        }
+
+        let (mut int_part, mut frac_part) = (int_part.to_string(), frac_part.to_string());
+        let mut precision = if let Some(figures) = self.significant_figures.filter(|&n| n > 0) {
+            let keep = significant_fraction_digits(&int_part, &frac_part, figures);
+            (int_part, frac_part) = round_digits(&int_part, &frac_part, keep, self.rounding_mode);
+            // Rounding up can add an integer digit (9.99 to 3 sf is 10.0)
+            let int_digits = int_part.trim_start_matches('0').len();
+            Some(if int_digits == 0 {
+                keep
+            } else {
+                i64::from(figures) - int_digits as i64
+            })
+        } else {
+            None
+        };
+        if let Some(max) = self.max_fraction_digits.map(i64::from) {
+            (int_part, frac_part) = round_digits(&int_part, &frac_part, max, self.rounding_mode);
+            precision = Some(precision.map_or(max, |p| p.min(max)));
+        }
+
+        let int_part = int_part.trim_start_matches('0');
+        let int_part = if int_part.is_empty() { "0" } else { int_part };
+        let mut frac_part = frac_part.trim_end_matches('0').to_string();
+        if let Some(width) = precision.filter(|_| self.pad_to_precision) {
+            let width = usize::try_from(width).unwrap_or(0);
+            while frac_part.len() < width {
+                frac_part.push('0');
+            }
+        }
+        let is_zero = int_part == "0" && frac_part.bytes().all(|b| b == b'0');
+
+        let mut result = String::new();
+        if negative && !is_zero {
+            result.push('-');
+        }
+        result.push_str(&self.group_integer(int_part));
+        if !frac_part.is_empty() {
+            result.push(self.decimal_separator);
+            result.push_str(&frac_part);
+        }
+        result
+    }
+
+    fn group_integer(&self, digits: &str) -> String {
+        let Some(separator) = self.group_separator else {
+            return digits.to_string();
+        };
+
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(ch);
+        }
+        grouped
+    }
+}
+
+/// Number of fractional digits to keep so that `figures` significant
+/// digits remain. Negative values round into the integer part.
+fn significant_fraction_digits(int_part: &str, frac_part: &str, figures: u32) -> i64 {
+    let figures = i64::from(figures);
+    let int_digits = int_part.trim_start_matches('0');
+    if !int_digits.is_empty() {
+        return figures - int_digits.len() as i64;
+    }
+    let leading_zeros = frac_part.bytes().take_while(|&b| b == b'0').count();
+    figures + leading_zeros as i64
+}
+
+/// Rounds a split decimal to `keep_frac` fractional digits.
+fn round_digits(
+    int_part: &str,
+    frac_part: &str,
+    keep_frac: i64,
+    mode: RoundingMode,
+) -> (String, String) {
+    let mut digits: Vec<u8> = int_part
+        .bytes()
+        .chain(frac_part.bytes())
+        .map(|b| b - b'0')
+        .collect();
+    let mut int_len = int_part.len();
+
+    let Ok(cut) = usize::try_from(int_len as i64 + keep_frac) else {
+        return ("0".to_string(), String::new());
+    };
+    if cut >= digits.len() {
+        return (int_part.to_string(), frac_part.to_string());
+    }
+
+    let round_up = match mode {
+        RoundingMode::HalfUp => digits[cut] >= 5,
+        RoundingMode::HalfEven => {
+            let beyond_half = digits[cut + 1..].iter().any(|&d| d > 0);
+            let odd = cut > 0 && digits[cut - 1] % 2 == 1;
+            digits[cut] > 5 || (digits[cut] == 5 && (beyond_half || odd))
+        }
+    };
+    digits.truncate(cut);
+    if round_up {
+        let mut i = cut;
+        loop {
+            if i == 0 {
+                digits.insert(0, 1);
+                int_len += 1;
+                break;
+            }
+            i -= 1;
+            if digits[i] == 9 {
+                digits[i] = 0;
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+    if digits.len() < int_len {
+        digits.resize(int_len, 0);
+    }
+
+    let to_string = |ds: &[u8]| ds.iter().map(|d| char::from(b'0' + d)).collect::<String>();
+    (to_string(&digits[..int_len]), to_string(&digits[int_len..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options_are_identity() {
+        let options = FormatOptions::default();
+        assert_eq!(options.format_number("1234567.891"), "1234567.891");
+        assert_eq!(options.format_number("-0.5"), "-0.5");
+    }
+
+    #[test]
+    fn test_digit_grouping() {
+        let options = FormatOptions::for_locale("en-US");
+        assert_eq!(options.format_number("1234567.891"), "1,234,567.891");
+        assert_eq!(options.format_number("-123"), "-123");
+        assert_eq!(options.format_number("-1234"), "-1,234");
+    }
+
+    #[test]
+    fn test_decimal_comma() {
+        let options = FormatOptions::for_locale("de");
+        assert_eq!(options.format_number("1234567.891"), "1.234.567,891");
+    }
+
+    #[test]
+    fn test_max_fraction_digits_rounds_half_up() {
+        let options = FormatOptions {
+            max_fraction_digits: Some(2),
+            ..FormatOptions::default()
+        };
+        assert_eq!(options.format_number("3.14159"), "3.14");
+        assert_eq!(options.format_number("2.675"), "2.68");
+        assert_eq!(options.format_number("9.999"), "10");
+        assert_eq!(options.format_number("-0.001"), "0");
+    }
+
+    #[test]
+    fn test_half_even_rounds_halves_to_even() {
+        let options = FormatOptions {
+            max_fraction_digits: Some(2),
+            rounding_mode: RoundingMode::HalfEven,
+            ..FormatOptions::default()
+        };
+        assert_eq!(options.format_number("2.665"), "2.66");
+        assert_eq!(options.format_number("2.675"), "2.68");
+        assert_eq!(options.format_number("2.6651"), "2.67");
+        assert_eq!(options.format_number("0.5"), "0.5");
+        let whole = FormatOptions {
+            max_fraction_digits: Some(0),
+            ..options
+        };
+        assert_eq!(whole.format_number("0.5"), "0");
+        assert_eq!(whole.format_number("1.5"), "2");
+    }
+
+    #[test]
+    fn test_money_rounding_gives_way_to_precision() {
+        let options = FormatOptions {
+            round_money: true,
+            ..FormatOptions::default()
+        };
+        assert_eq!(options.for_money(0).format_number("14850.4"), "14850");
+        let dp = options.with_precision(Precision::DecimalPlaces(3));
+        assert_eq!(dp.for_money(2).format_number("1.23456"), "1.235");
+        let unrounded = FormatOptions {
+            round_money: false,
+            ..FormatOptions::default()
+        };
+        assert!(unrounded.for_money(2).is_default());
+    }
+
+    #[test]
+    fn test_significant_figures() {
+        let options = FormatOptions {
+            significant_figures: Some(3),
+            ..FormatOptions::default()
+        };
+        assert_eq!(options.format_number("123456"), "123000");
+        assert_eq!(options.format_number("0.00123456"), "0.00123");
+        assert_eq!(options.format_number("99.96"), "100");
+        assert_eq!(options.format_number("0"), "0");
+    }
+
+    #[test]
+    fn test_non_numeric_is_unchanged() {
+        let options = FormatOptions::for_locale("en");
+        assert_eq!(options.format_number("1/3"), "1/3");
+    }
+
+    #[test]
+    fn test_unknown_locale_uses_defaults() {
+        assert!(FormatOptions::for_locale("xx").is_default());
+    }
+
+    #[test]
+    fn test_precision_keeps_trailing_zeros() {
+        let options = FormatOptions::default();
+        let dp = options.with_precision(Precision::DecimalPlaces(2));
+        assert_eq!(dp.format_number("0.5"), "0.50");
+        assert_eq!(dp.format_number("2"), "2.00");
+        assert_eq!(dp.format_number("-0.001"), "0.00");
+        let sf = options.with_precision(Precision::SignificantFigures(3));
+        assert_eq!(sf.format_number("9.999"), "10.0");
+        assert_eq!(sf.format_number("0.0012"), "0.00120");
+        assert_eq!(sf.format_number("12345"), "12300");
+    }
+}
@@ -0,0 +1,193 @@
+//! Calculation history, so later inputs can refer to earlier results with
+//! `ans` (the latest result), `#3` (the third result) or `#2:#5` (the second
+//! to fifth results, as a list).
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::CalculatorError;
+use crate::types::Value;
+
+/// One recorded calculation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// The input as typed.
+    pub input: String,
+    /// The displayed result.
+    pub result: String,
+    /// The result value that `ans` and `#n` evaluate to.
+    pub value: Value,
+}
+
+/// Results of earlier calculations, numbered from 1 in the order they were made.
+///
+/// Forgetting old results does not renumber the rest: after the first ten
+/// are forgotten, the oldest remaining result is still `#11`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+    /// How many of the oldest results have been forgotten.
+    #[serde(skip)]
+    forgotten: usize,
+}
+
+impl History {
+    /// Creates an empty history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a calculation and returns its history number.
+    pub fn push(
+        &mut self,
+        input: impl Into<String>,
+        result: impl Into<String>,
+        value: Value,
+    ) -> usize {
+        self.entries.push(HistoryEntry {
+            input: input.into(),
+            result: result.into(),
+            value,
+        });
+        self.forgotten + self.entries.len()
+    }
+
+    /// Forgets the oldest results until at most `max_entries` remain.
+    pub fn keep_latest(&mut self, max_entries: usize) {
+        let excess = self.entries.len().saturating_sub(max_entries);
+        self.entries.drain(..excess);
+        self.forgotten += excess;
+    }
+
+    /// Returns the entries still remembered, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Returns the entries still remembered with their history numbers,
+    /// oldest first.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &HistoryEntry)> {
+        (self.forgotten + 1..).zip(&self.entries)
+    }
+
+    /// Returns the entry with the given history number (starting at 1).
+    #[must_use]
+    pub fn get(&self, number: usize) -> Option<&HistoryEntry> {
+        number
+            .checked_sub(self.forgotten + 1)
+            .and_then(|index| self.entries.get(index))
+    }
+
+    /// Returns the number of remembered calculations.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing has been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries; numbering starts again at `#1`.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.forgotten = 0;
+    }
+
+    /// Resolves a history reference: `None` is `ans`, `Some(n)` is `#n`.
+    pub fn resolve(&self, reference: Option<usize>) -> Result<Value, CalculatorError> {
+        let entry = match reference {
+            None => self
+                .entries
+                .last()
+                .ok_or_else(|| CalculatorError::eval("No previous result for ans"))?,
+            Some(number) => self.entry(number)?,
+        };
+        Ok(entry.value.clone())
+    }
+
+    /// Resolves a history range `#first:#last` to the list of those results,
+    /// both ends included.
+    pub fn resolve_range(&self, first: usize, last: usize) -> Result<Value, CalculatorError> {
+        if first > last {
+            return Err(CalculatorError::eval(format!(
+                "History range #{first}:#{last} must start at the earlier result"
+            )));
+        }
+        let values = (first..=last)
+            .map(|number| self.entry(number).map(|entry| entry.value.clone()))
+            .collect::<Result<_, _>>()?;
+        Ok(Value::list(values))
+    }
+
+    fn entry(&self, number: usize) -> Result<&HistoryEntry, CalculatorError> {
+        self.get(number).ok_or_else(|| {
+            if number > self.forgotten {
+                CalculatorError::eval(format!(
+                    "No result #{number} in history ({} so far)",
+                    self.forgotten + self.entries.len()
+                ))
+            } else {
+                CalculatorError::eval(format!("Result #{number} has been forgotten"))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let mut history = History::new();
+        assert!(history.resolve(None).is_err());
+
+        assert_eq!(history.push("1 + 1", "2", Value::from_integer(2)), 1);
+        assert_eq!(history.push("2 * 5", "10", Value::from_integer(10)), 2);
+        assert_eq!(history.resolve(None).unwrap(), Value::from_integer(10));
+        assert_eq!(history.resolve(Some(1)).unwrap(), Value::from_integer(2));
+        assert!(history.resolve(Some(0)).is_err());
+        assert!(history.resolve(Some(3)).is_err());
+    }
+
+    #[test]
+    fn test_keep_latest_keeps_numbering() {
+        let mut history = History::new();
+        for n in 1..=5 {
+            history.push(n.to_string(), n.to_string(), Value::from_integer(n));
+        }
+        history.keep_latest(2);
+        assert_eq!(history.len(), 2);
+        assert!(history.resolve(Some(3)).is_err());
+        assert_eq!(history.resolve(Some(4)).unwrap(), Value::from_integer(4));
+        assert_eq!(history.push("6", "6", Value::from_integer(6)), 6);
+        let numbers: Vec<usize> = history.numbered().map(|(number, _)| number).collect();
+        assert_eq!(numbers, [4, 5, 6]);
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let mut history = History::new();
+        for n in 1..=4 {
+            history.push(n.to_string(), n.to_string(), Value::from_integer(n));
+        }
+        let range = history.resolve_range(2, 4).unwrap();
+        assert_eq!(range.as_list().unwrap(), [2, 3, 4].map(Value::from_integer));
+        assert!(history.resolve_range(3, 2).is_err());
+        assert!(history.resolve_range(3, 5).is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let mut history = History::new();
+        history.push("3 kg * 2", "6 kg", Value::from_integer(6));
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.starts_with('['));
+        assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+    }
+}
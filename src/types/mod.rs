@@ -1,17 +1,51 @@
 //! Core types for the Link Calculator.
 
+mod bookmarks;
+mod cancellation;
+mod clock;
+mod color;
 mod currency;
 mod datetime;
+mod datetime_format;
 mod decimal;
+mod eval_config;
 mod expression;
+mod format;
+mod history;
+mod progress;
+mod radix;
 mod rational;
+mod steps_level;
+mod structured_step;
+mod telemetry;
 mod unit;
 mod value;
+mod world_clock;
 
-pub use currency::{Currency, CurrencyDatabase, ExchangeRateInfo};
+pub use bookmarks::Bookmarks;
+pub use cancellation::CancellationToken;
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use color::{Color, ColorModel};
+pub use currency::{
+    Conversion, ConversionReceipt, Currency, CurrencyDatabase, ExchangeRateInfo,
+    HistoricalRateMode, RateUsage,
+};
 pub use datetime::{DateTime, DateTimeResult};
+pub use datetime_format::DateTimeFormat;
 pub use decimal::Decimal;
+pub use eval_config::EvalConfig;
 pub use expression::{BinaryOp, ComparisonOp, Expression};
+pub use format::{FormatOptions, Precision, RoundingMode};
+pub use history::{History, HistoryEntry};
+pub use progress::Progress;
+pub use radix::Radix;
 pub use rational::{Rational, RepeatingDecimal};
-pub use unit::{DataSizeUnit, DurationUnit, MassUnit, Unit};
-pub use value::{Value, ValueKind};
+pub use steps_level::StepsLevel;
+pub use structured_step::{StepRate, StructuredStep};
+pub use telemetry::{EvaluationEvent, PhaseTimings, Telemetry};
+pub use unit::{
+    AngleUnit, AngleWrap, DataSizeUnit, DurationUnit, LengthUnit, MassUnit, Unit, VolumeUnit,
+};
+pub(crate) use value::bare_year_datetime;
+pub use value::{SymbolicConstant, Value, ValueKind};
+pub use world_clock::{City, MAX_CITY_WORDS};
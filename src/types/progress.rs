@@ -0,0 +1,27 @@
+//! Progress of long-running parts of a calculation.
+
+use serde::{Deserialize, Serialize};
+
+/// How far a long-running part of a calculation, such as numerical
+/// integration or plot sampling, has come.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    /// What is running: `"integration"` or `"plot"`.
+    pub stage: String,
+    /// Work items done so far, such as integrand evaluations.
+    pub done: u64,
+    /// Work items in total.
+    pub total: u64,
+}
+
+impl Progress {
+    /// Returns the fraction of the work done, from 0 to 1.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Number bases (radixes) for integer literals and base conversions.
+
+use num_bigint::BigInt;
+use num_traits::Signed;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A number base used for integer literals such as `0xFF` and for
+/// conversions such as `255 in hex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Radix {
+    /// Base 2 (`0b1010`).
+    Binary,
+    /// Base 8 (`0o17`).
+    Octal,
+    /// Base 10.
+    Decimal,
+    /// Base 16 (`0xFF`).
+    Hexadecimal,
+}
+
+impl Radix {
+    /// Returns the numeric base.
+    #[must_use]
+    pub const fn base(self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+
+    /// Returns the literal prefix (`0b`, `0o`, `0x`); empty for decimal.
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+        }
+    }
+
+    /// Returns the radix for a literal prefix character (`x`, `b`, `o`).
+    #[must_use]
+    pub const fn from_prefix_char(ch: char) -> Option<Self> {
+        match ch {
+            'b' | 'B' => Some(Self::Binary),
+            'o' | 'O' => Some(Self::Octal),
+            'x' | 'X' => Some(Self::Hexadecimal),
+            _ => None,
+        }
+    }
+
+    /// Parses a conversion target name such as `hex`, `binary` or `decimal`.
+    #[must_use]
+    pub fn parse_name(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "bin" | "binary" => Some(Self::Binary),
+            "oct" | "octal" => Some(Self::Octal),
+            "dec" | "decimal" => Some(Self::Decimal),
+            "hex" | "hexadecimal" => Some(Self::Hexadecimal),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `ch` is a valid digit in this base.
+    #[must_use]
+    pub fn is_digit(self, ch: char) -> bool {
+        ch.is_digit(self.base())
+    }
+
+    /// Splits a prefixed literal such as `0xFF` into its radix and digits.
+    #[must_use]
+    pub fn split_literal(s: &str) -> Option<(Self, &str)> {
+        let rest = s.strip_prefix('0')?;
+        let mut chars = rest.chars();
+        let radix = Self::from_prefix_char(chars.next()?)?;
+        let digits = chars.as_str();
+        (!digits.is_empty() && digits.chars().all(|c| radix.is_digit(c))).then_some((radix, digits))
+    }
+
+    /// Formats an integer in this base with its prefix, e.g. `0xFF` or `-0b101`.
+    #[must_use]
+    pub fn format(self, n: &BigInt) -> String {
+        let digits = n.abs().to_str_radix(self.base()).to_uppercase();
+        let sign = if n.is_negative() { "-" } else { "" };
+        format!("{sign}{}{digits}", self.prefix())
+    }
+}
+
+impl fmt::Display for Radix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Binary => "binary",
+            Self::Octal => "octal",
+            Self::Decimal => "decimal",
+            Self::Hexadecimal => "hex",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_literal() {
+        assert_eq!(
+            Radix::split_literal("0xFF"),
+            Some((Radix::Hexadecimal, "FF"))
+        );
+        assert_eq!(
+            Radix::split_literal("0b1010"),
+            Some((Radix::Binary, "1010"))
+        );
+        assert_eq!(Radix::split_literal("0o17"), Some((Radix::Octal, "17")));
+        assert_eq!(Radix::split_literal("0b102"), None);
+        assert_eq!(Radix::split_literal("0x"), None);
+        assert_eq!(Radix::split_literal("42"), None);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Radix::Hexadecimal.format(&BigInt::from(255)), "0xFF");
+        assert_eq!(Radix::Binary.format(&BigInt::from(-5)), "-0b101");
+        assert_eq!(Radix::Octal.format(&BigInt::from(8)), "0o10");
+        assert_eq!(Radix::Decimal.format(&BigInt::from(31)), "31");
+    }
+
+    #[test]
+    fn test_parse_name() {
+        assert_eq!(Radix::parse_name("HEX"), Some(Radix::Hexadecimal));
+        assert_eq!(Radix::parse_name("binary"), Some(Radix::Binary));
+        assert_eq!(Radix::parse_name("kg"), None);
+    }
+}
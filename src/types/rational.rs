@@ -6,6 +6,7 @@
 //! representation of large numbers like `10^100`.
 
 use num_bigint::BigInt;
+use num_integer::Integer;
 use num_rational::Ratio;
 use num_traits::{One, Pow, Signed, ToPrimitive, Zero};
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,8 @@ use std::str::FromStr;
 
 use crate::types::Decimal;
 
+mod digits;
+
 /// A rational number represented as a fraction (numerator/denominator)
 /// with arbitrary-precision integers.
 ///
@@ -111,49 +114,10 @@ impl Rational {
         }
     }
 
-    /// Creates a rational number from a Decimal value.
-    ///
-    /// This converts the decimal to a fraction by using powers of 10.
+    /// Creates a rational number from a Decimal value, exactly.
     #[must_use]
     pub fn from_decimal(d: Decimal) -> Self {
-        let s = d.to_string();
-
-        if let Some(dot_pos) = s.find('.') {
-            let sign: BigInt = if s.starts_with('-') {
-                BigInt::from(-1)
-            } else {
-                BigInt::from(1)
-            };
-            let s_abs = s.trim_start_matches('-');
-            let dot_pos_abs = if s.starts_with('-') {
-                dot_pos - 1
-            } else {
-                dot_pos
-            };
-
-            let int_part = &s_abs[..dot_pos_abs];
-            let frac_part = &s_abs[dot_pos_abs + 1..];
-
-            let decimal_places = frac_part.len() as u32;
-            let denom: BigInt = BigInt::from(10).pow(decimal_places);
-
-            let int_val: BigInt = if int_part.is_empty() {
-                BigInt::zero()
-            } else {
-                int_part.parse().unwrap_or_else(|_| BigInt::zero())
-            };
-            let frac_val: BigInt = if frac_part.is_empty() {
-                BigInt::zero()
-            } else {
-                frac_part.parse().unwrap_or_else(|_| BigInt::zero())
-            };
-
-            let numer = sign * (&int_val * &denom + frac_val);
-            Self::new_bigint(numer, denom)
-        } else {
-            let n: BigInt = s.parse().unwrap_or_else(|_| BigInt::zero());
-            Self::from_bigint(n)
-        }
+        Self::parse_decimal_digits(&d.to_string()).unwrap_or_default()
     }
 
     /// Creates a rational number from an f64.
@@ -165,22 +129,23 @@ impl Rational {
             return Self::from_integer(0);
         }
 
-        let (numer, denom) = Self::f64_to_rational_continued_fraction(value, 1_000_000_000);
-        Self::new(numer, denom)
-    }
-
-    /// Converts an f64 to a rational using continued fraction expansion.
-    fn f64_to_rational_continued_fraction(value: f64, max_denom: i128) -> (i128, i128) {
-        if value == 0.0 {
-            return (0, 1);
+        let (terms, _) = Self::continued_fraction(value.abs(), 1_000_000_000);
+        let approximation = Self::convergents(&terms).pop().unwrap_or_default();
+        if value < 0.0 {
+            -approximation
+        } else {
+            approximation
         }
+    }
 
-        let sign = if value < 0.0 { -1 } else { 1 };
-        let value = value.abs();
-
-        let mut h_prev = 0i128;
+    /// Expands a non-negative `value` as a continued fraction
+    /// `[a0; a1, a2, ...]`, stopping before the denominator of a convergent
+    /// would pass `max_denom`. The flag is true when the expansion ended on
+    /// its own, as it does for a simple fraction: 0.375 is `[0; 2, 1, 2]`.
+    #[must_use]
+    pub fn continued_fraction(value: f64, max_denom: i128) -> (Vec<i128>, bool) {
+        let mut terms = Vec::new();
         let mut k_prev = 1i128;
-        let mut h_curr = 1i128;
         let mut k_curr = 0i128;
 
         let mut x = value;
@@ -189,27 +154,48 @@ impl Rational {
             #[allow(clippy::cast_possible_truncation)]
             let a = x.floor() as i128;
 
-            let h_next = a.saturating_mul(h_curr).saturating_add(h_prev);
             let k_next = a.saturating_mul(k_curr).saturating_add(k_prev);
-
             if k_next > max_denom {
-                break;
+                return (terms, false);
             }
 
-            h_prev = h_curr;
+            terms.push(a);
             k_prev = k_curr;
-            h_curr = h_next;
             k_curr = k_next;
 
             let frac = x - (a as f64);
             if frac.abs() < 1e-15 {
-                break;
+                return (terms, true);
             }
 
             x = 1.0 / frac;
         }
 
-        (sign * h_curr, k_curr)
+        (terms, false)
+    }
+
+    /// Returns the convergents of the continued fraction `terms`: each is
+    /// the best rational approximation of its value with a denominator no
+    /// larger than its own.
+    #[must_use]
+    pub fn convergents(terms: &[i128]) -> Vec<Self> {
+        let mut h_prev = 0i128;
+        let mut k_prev = 1i128;
+        let mut h_curr = 1i128;
+        let mut k_curr = 0i128;
+
+        terms
+            .iter()
+            .map(|&a| {
+                let h_next = a.saturating_mul(h_curr).saturating_add(h_prev);
+                let k_next = a.saturating_mul(k_curr).saturating_add(k_prev);
+                h_prev = h_curr;
+                k_prev = k_curr;
+                h_curr = h_next;
+                k_curr = k_next;
+                Self::new(h_curr, k_curr)
+            })
+            .collect()
     }
 
     /// Returns the numerator as a `BigInt` reference.
@@ -278,17 +264,6 @@ impl Rational {
         n / d
     }
 
-    /// Converts to a Decimal (may lose precision for very large numbers).
-    #[must_use]
-    pub fn to_decimal(&self) -> Decimal {
-        if self.is_integer() {
-            if let Some(n) = self.inner.numer().to_i64() {
-                return Decimal::new(n);
-            }
-        }
-        Decimal::from_f64(self.to_f64())
-    }
-
     /// Returns zero.
     #[must_use]
     pub fn zero() -> Self {
@@ -330,15 +305,132 @@ impl Rational {
         }
     }
 
+    /// Returns the `degree`-th root when it is rational, as the cube root
+    /// of 8/27 is 2/3, or `None`. Negative numbers have odd roots only.
+    #[must_use]
+    pub fn nth_root(&self, degree: u32) -> Option<Self> {
+        if degree == 0 || (degree % 2 == 0 && self.is_negative()) {
+            return None;
+        }
+        let root = |n: &BigInt| {
+            let root = n.nth_root(degree);
+            (Pow::pow(&root, degree) == *n).then_some(root)
+        };
+        Some(Self {
+            inner: Ratio::new(root(self.inner.numer())?, root(self.inner.denom())?),
+        })
+    }
+
+    /// Returns the `degree`-th root, exactly when it is rational and cut to
+    /// `digits` significant digits otherwise, so `√99999999999999999999` is
+    /// not rounded to a whole number the way an `f64` would round it.
+    /// Negative numbers have odd roots only.
+    #[must_use]
+    pub fn root_to_digits(&self, degree: u32, digits: u32) -> Option<Self> {
+        if let Some(root) = self.nth_root(degree) {
+            return Some(root);
+        }
+        if degree == 0 || (degree % 2 == 0 && self.is_negative()) {
+            return None;
+        }
+        let magnitude = self.abs();
+        let exponent = i64::from(magnitude.decimal_exponent()?);
+        // The root's leading digit is about 10^(exponent / degree), so
+        // scaling by 10^(degree * places) leaves `digits` digits to take
+        // the integer root of.
+        let places = i64::from(digits) - exponent.div_euclid(i64::from(degree));
+        let ten = Self::from_integer(10);
+        let scaled = magnitude * ten.pow_i32(i32::try_from(places * i64::from(degree)).ok()?);
+        let root = Self::from_bigint(scaled.inner.to_integer().nth_root(degree))
+            / ten.pow_i32(i32::try_from(places).ok()?);
+        Some(if self.is_negative() { -root } else { root })
+    }
+
+    /// Returns the power of ten of the leading digit (2 for 375, -1 for
+    /// 0.375), or `None` for zero.
+    #[must_use]
+    pub fn decimal_exponent(&self) -> Option<i32> {
+        if self.is_zero() {
+            return None;
+        }
+        let magnitude = self.abs();
+        let log10 = magnitude.to_f64().log10();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+        let mut exponent = if log10.is_finite() {
+            // |log10| of a finite f64 is at most ~324
+            log10.floor() as i32
+        } else {
+            // Past the range of f64, estimate from the bit lengths instead
+            let bits =
+                magnitude.inner.numer().bits() as f64 - magnitude.inner.denom().bits() as f64;
+            (bits * std::f64::consts::LOG10_2).floor() as i32
+        };
+        // The estimate can be one off either way near powers of ten.
+        // Comparing against powers of ten, rather than dividing by them,
+        // keeps huge integers such as `factorial(5000)` fast.
+        let ten = Self::from_integer(10);
+        while magnitude >= ten.pow_i32(exponent + 1) {
+            exponent += 1;
+        }
+        while magnitude < ten.pow_i32(exponent) {
+            exponent -= 1;
+        }
+        Some(exponent)
+    }
+
+    /// Returns the mantissa, rounded to `decimals`, and the exponent of this
+    /// number in scientific notation: `(3.75, -1)` for 0.375, or `None` for
+    /// zero. A mantissa that rounds up to 10 carries into the exponent, so
+    /// 99.99 to one decimal is `(1, 2)` rather than `(10, 1)`.
+    #[must_use]
+    pub fn scientific_parts(&self, decimals: u32) -> Option<(Decimal, i32)> {
+        let exponent = self.decimal_exponent()?;
+        let mantissa = self.mantissa(exponent, decimals)?;
+        let ten = Decimal::new(10);
+        if mantissa.abs() >= ten {
+            return Some(((mantissa / ten).normalize(), exponent + 1));
+        }
+        Some((mantissa, exponent))
+    }
+
+    /// Returns the mantissa of this number written times `10^exponent`,
+    /// rounded to `decimals`: 3.75 for 0.375 and exponent -1. Returns `None`
+    /// if the mantissa is past the range of a Decimal.
+    #[must_use]
+    pub fn mantissa(&self, exponent: i32, decimals: u32) -> Option<Decimal> {
+        let mantissa = self.shifted(-exponent, decimals + 4)?.to_decimal()?;
+        Some(mantissa.round_half_away_from_zero(decimals).normalize())
+    }
+
+    /// Returns `self * 10^places`, cut `guard` digits past the decimal
+    /// point. Dividing the integers once is much faster than reducing the
+    /// exact fraction when they have thousands of digits, as
+    /// `factorial(5000)` does. Returns `None` if the power of ten has more
+    /// digits than a `u32` counts.
+    fn shifted(&self, places: i32, guard: u32) -> Option<Self> {
+        let scale = i64::from(places) + i64::from(guard);
+        let ten = BigInt::from(10);
+        let mut numerator = self.inner.numer().clone();
+        let mut denominator = self.inner.denom().clone();
+        let power = Pow::pow(&ten, u32::try_from(scale.unsigned_abs()).ok()?);
+        if scale >= 0 {
+            numerator *= power;
+        } else {
+            denominator *= power;
+        }
+        Some(Self::new_bigint(
+            numerator / denominator,
+            Pow::pow(&ten, guard),
+        ))
+    }
+
     /// Checked division that returns None on division by zero.
     #[must_use]
     pub fn checked_div(&self, other: &Self) -> Option<Self> {
         if other.is_zero() {
             None
         } else {
-            Some(Self {
-                inner: &self.inner / &other.inner,
-            })
+            Some(self.clone() / other.clone())
         }
     }
 
@@ -349,26 +441,9 @@ impl Rational {
             return None;
         }
 
-        let quotient_ratio = &self.inner / &other.inner;
-        let quotient = quotient_ratio.numer().clone() / quotient_ratio.denom().clone();
-        let remainder = self.inner.clone() - other.inner.clone() * Ratio::from_integer(quotient);
-
-        Some(Self { inner: remainder })
-    }
-
-    /// Converts the rational to a display string.
-    ///
-    /// If the rational is an integer, returns the exact integer string
-    /// (using arbitrary precision, so `10^100` displays all 101 digits).
-    /// Otherwise, returns the decimal representation.
-    #[must_use]
-    pub fn to_display_string(&self) -> String {
-        if self.is_integer() {
-            self.inner.numer().to_string()
-        } else {
-            let approx = self.to_decimal();
-            approx.normalize().to_string()
-        }
+        let quotient = self.clone() / other.clone();
+        let quotient = Self::from_bigint(quotient.numer_bigint() / quotient.denom_bigint());
+        Some(self.clone() - other.clone() * quotient)
     }
 
     /// Returns a fractional representation (e.g., "1/3").
@@ -453,13 +528,28 @@ impl From<Decimal> for Rational {
     }
 }
 
+// The operators reduce with `gcd` below rather than leaving it to `Ratio`,
+// which reduces with a binary gcd that takes time quadratic in the larger
+// number when the other is small: `(1/3)^1000000 * 100` would take minutes.
+
 impl Add for Rational {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Self {
-            inner: self.inner + other.inner,
+        let (numer, denom) = self.inner.into();
+        let (other_numer, other_denom) = other.inner.into();
+        if denom == other_denom {
+            let sum = numer + other_numer;
+            let common = gcd(&sum, &denom);
+            return Self::reduced(sum / &common, denom / common);
         }
+        // a/b + c/d = (a d/g + c b/g) / (b d/g) with g = gcd(b, d); only a
+        // factor of g can be left to cancel
+        let common = gcd(&denom, &other_denom);
+        let (denom_part, other_part) = (&denom / &common, &other_denom / &common);
+        let sum = numer * &other_part + other_numer * &denom_part;
+        let left = gcd(&sum, &common);
+        Self::reduced(sum / &left, denom_part * (other_denom / left))
     }
 }
 
@@ -467,9 +557,7 @@ impl Sub for Rational {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Self {
-            inner: self.inner - other.inner,
-        }
+        self + -other
     }
 }
 
@@ -477,9 +565,10 @@ impl Mul for Rational {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        Self {
-            inner: self.inner * other.inner,
-        }
+        let (a, b) = self.inner.into();
+        let (c, d) = other.inner.into();
+        let (g1, g2) = (gcd(&a, &d), gcd(&c, &b));
+        Self::reduced((a / &g1) * (c / &g2), (b / g2) * (d / g1))
     }
 }
 
@@ -487,9 +576,45 @@ impl Div for Rational {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "Division by zero");
+        self * Self {
+            inner: other.inner.recip(),
+        }
+    }
+}
+
+impl Rational {
+    /// Wraps a fraction already in lowest terms, moving the sign to the
+    /// numerator.
+    fn reduced(numer: BigInt, denom: BigInt) -> Self {
+        let (numer, denom) = if numer.is_zero() {
+            (numer, BigInt::one())
+        } else if denom.is_negative() {
+            (-numer, -denom)
+        } else {
+            (numer, denom)
+        };
         Self {
-            inner: self.inner / other.inner,
+            inner: Ratio::new_raw(numer, denom),
+        }
+    }
+}
+
+/// The greatest common divisor, taking Euclid's remainder steps while one
+/// number is much longer than the other.
+fn gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    loop {
+        if a.is_zero() || b.is_zero() {
+            return a + b;
+        }
+        if a.bits().abs_diff(b.bits()) <= 64 {
+            return a.gcd(&b);
+        }
+        if a < b {
+            std::mem::swap(&mut a, &mut b);
         }
+        a %= &b;
     }
 }
 
@@ -789,7 +914,7 @@ mod tests {
 
     #[test]
     fn test_from_decimal() {
-        let d = Decimal::from_f64(0.5);
+        let d = Decimal::from_f64(0.5).unwrap();
         let r = Rational::from_decimal(d);
         assert_eq!(r.numer(), 1);
         assert_eq!(r.denom(), 2);
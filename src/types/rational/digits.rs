@@ -0,0 +1,357 @@
+//! Decimal digits of a [`Rational`], worked out on its integers so that no
+//! binary float rounds them: `0.1 + 0.2` is `0.3` and `12345678901234567.5`
+//! keeps its last digits.
+//!
+//! Writing the digits takes time quadratic in their number, so a number
+//! past [`Rational::MAX_DISPLAY_DIGITS`] digits is written in scientific
+//! notation instead: `(2/3)^100000` is `7.483321982518254e-17610`.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Pow, Signed, ToPrimitive, Zero};
+
+use super::Rational;
+use crate::types::Decimal;
+
+/// Significant digits a [`Decimal`] holds.
+const DECIMAL_DIGITS: i32 = 28;
+
+/// Significant digits shown for a decimal that repeats forever, like `1/3`.
+const REPEATING_DIGITS: i32 = 16;
+
+impl Rational {
+    /// The most digits a number is written out with; past this it is shown
+    /// in scientific notation. `10^9999` is still written in full.
+    pub const MAX_DISPLAY_DIGITS: u32 = 10_000;
+
+    /// Decimals kept in the mantissa of a number written in scientific
+    /// notation, for the same 16 significant digits as a number written
+    /// out: `1.551121004333099e25` for `25!`.
+    pub const SCIENTIFIC_DECIMALS: u32 = REPEATING_DIGITS as u32 - 1;
+
+    /// Returns true if the numerator and denominator together have at most
+    /// [`Self::MAX_DISPLAY_DIGITS`] digits, so the number can be written
+    /// out as a fraction or an integer without a long wait.
+    #[must_use]
+    pub fn has_display_digits(&self) -> bool {
+        let mut digits = integer_digits(self.inner.numer());
+        if !self.is_integer() {
+            digits += integer_digits(self.inner.denom());
+        }
+        digits <= u64::from(Self::MAX_DISPLAY_DIGITS)
+    }
+
+    /// Writes the number in scientific notation with
+    /// [`Self::SCIENTIFIC_DECIMALS`] decimals: `7.483321982518254e-17610`.
+    #[must_use]
+    pub fn to_scientific_string(&self) -> String {
+        let Some((mantissa, exponent)) = self.scientific_parts(Self::SCIENTIFIC_DECIMALS) else {
+            return "0".to_string();
+        };
+        format!("{mantissa}e{exponent}")
+    }
+
+    /// Converts to a Decimal, exactly when 28 significant digits are enough
+    /// and rounded to 28 digits otherwise, or `None` if the number is too
+    /// large for a Decimal (past about `7.9e28`).
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        let Some(exponent) = self.decimal_exponent() else {
+            return Some(Decimal::zero());
+        };
+        let places = (DECIMAL_DIGITS - 1 - exponent).clamp(0, DECIMAL_DIGITS);
+        #[allow(clippy::cast_sign_loss)] // clamped to 0..=28
+        let places = places as u32;
+        self.scaled_to(places)
+            .to_i128()
+            .and_then(|mantissa| Decimal::from_scaled(mantissa, places))
+            .map(|d| d.normalize())
+    }
+
+    /// Reads decimal digits such as `-12.5` exactly, however many there
+    /// are: `0.123456789012345678901234567891` keeps its last digit.
+    #[must_use]
+    pub fn parse_decimal_digits(s: &str) -> Option<Self> {
+        let (negative, unsigned) = s.strip_prefix('-').map_or((false, s), |rest| (true, rest));
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let digits = format!("{whole}{fraction}");
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let places = u32::try_from(fraction.len()).ok()?;
+        let value = Self::new_bigint(digits.parse().ok()?, Pow::pow(&BigInt::from(10), places));
+        Some(if negative { -value } else { value })
+    }
+
+    /// Writes every digit of a number whose decimals end, as a number
+    /// literal is written: `0.123456789012345678901234567891` in full. Any
+    /// other number is written as [`Self::to_display_string`] does.
+    #[must_use]
+    pub fn to_literal_string(&self) -> String {
+        match self.terminating_places() {
+            Some(places) if places <= Self::MAX_DISPLAY_DIGITS && self.has_display_digits() => {
+                format_scaled(&self.scaled_to(places), places)
+            }
+            _ => self.to_display_string(),
+        }
+    }
+
+    /// Converts the rational to a display string.
+    ///
+    /// If the rational is an integer, returns the exact integer string
+    /// (using arbitrary precision, so `10^100` displays all 101 digits).
+    /// A decimal that ends within 28 significant digits is shown in full;
+    /// any other is rounded to 16 significant digits (`0.3333333333333333`).
+    /// A number that would take more than [`Self::MAX_DISPLAY_DIGITS`]
+    /// digits is shown in scientific notation.
+    #[must_use]
+    pub fn to_display_string(&self) -> String {
+        if self.is_integer() {
+            if !self.has_display_digits() {
+                return self.to_scientific_string();
+            }
+            return self.inner.numer().to_string();
+        }
+        let exponent = self.decimal_exponent().unwrap_or_default();
+        let places = match self.terminating_places() {
+            Some(places) if i64::from(places) + i64::from(exponent) < i64::from(DECIMAL_DIGITS) => {
+                places
+            }
+            _ => u32::try_from(REPEATING_DIGITS - 1 - exponent).unwrap_or(0),
+        };
+        if i64::from(places) + i64::from(exponent.max(0)) > i64::from(Self::MAX_DISPLAY_DIGITS) {
+            return self.to_scientific_string();
+        }
+        format_scaled(&self.scaled_to(places), places)
+    }
+
+    /// Writes a number that was itself calculated to limited precision,
+    /// such as `sqrt(2)`, rounded to the 16 significant digits a repeating
+    /// decimal is shown with: `1.414213562373095`.
+    #[must_use]
+    pub fn to_rounded_display_string(&self) -> String {
+        let exponent = self.decimal_exponent().unwrap_or_default();
+        self.round_to_places(REPEATING_DIGITS - 1 - exponent)
+            .to_display_string()
+    }
+
+    /// Writes the number cut to `places` decimal places, followed by one
+    /// more digit that is `1` if anything was cut and `0` otherwise, so that
+    /// rounding the digits to fewer places rounds the exact number:
+    /// `1/3` to 3 places is `0.3331`, and `1/4` is `0.2500`.
+    #[must_use]
+    pub fn to_rounding_digits(&self, places: u32) -> String {
+        let scale = Pow::pow(&BigInt::from(10), places);
+        let (cut, remainder) = (self.inner.numer().abs() * scale).div_rem(self.inner.denom());
+        let sticky = if remainder.is_zero() { '0' } else { '1' };
+        let digits = zero_padded(&cut, places as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - places as usize);
+        let sign = if self.is_negative() { "-" } else { "" };
+        format!("{sign}{whole}.{fraction}{sticky}")
+    }
+
+    /// Rounds to `places` decimal places, halves away from zero. Negative
+    /// places round to tens, hundreds and so on.
+    #[must_use]
+    pub fn round_to_places(&self, places: i32) -> Self {
+        let scale = Self::from_integer(10).pow_i32(places);
+        let scaled = self.clone() * scale.clone();
+        Self::from_bigint(scaled.scaled_to(0)) / scale
+    }
+
+    /// Rounds down to an integer.
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self {
+            inner: self.inner.floor(),
+        }
+    }
+
+    /// Rounds up to an integer.
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self {
+            inner: self.inner.ceil(),
+        }
+    }
+
+    /// Drops the fractional part.
+    #[must_use]
+    pub fn trunc(&self) -> Self {
+        Self {
+            inner: self.inner.trunc(),
+        }
+    }
+
+    /// The number of decimal places the number ends after, or `None` if its
+    /// digits repeat forever (the denominator has a prime factor other than
+    /// 2 and 5).
+    fn terminating_places(&self) -> Option<u32> {
+        let mut denom = self.inner.denom().clone();
+        let twos = denom.trailing_zeros().unwrap_or(0);
+        denom >>= twos;
+        let five = BigInt::from(5);
+        let mut fives = 0;
+        loop {
+            let (quotient, remainder) = denom.div_rem(&five);
+            if !remainder.is_zero() {
+                break;
+            }
+            denom = quotient;
+            fives += 1;
+        }
+        (denom == BigInt::from(1)).then(|| u32::try_from(twos.max(fives)).unwrap_or(u32::MAX))
+    }
+
+    /// The number times `10^places`, rounded to an integer with halves away
+    /// from zero.
+    fn scaled_to(&self, places: u32) -> BigInt {
+        let scale = Pow::pow(&BigInt::from(10), places);
+        let (quotient, remainder) = (self.inner.numer().abs() * scale).div_rem(self.inner.denom());
+        let rounded = if remainder * 2 >= *self.inner.denom() {
+            quotient + 1
+        } else {
+            quotient
+        };
+        if self.is_negative() {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+}
+
+/// Writes `scaled / 10^places` with trailing zeros dropped: `12500, 3`
+/// is `12.5`.
+fn format_scaled(scaled: &BigInt, places: u32) -> String {
+    let sign = if scaled.is_negative() { "-" } else { "" };
+    let digits = scaled.abs().to_string();
+    let places = places as usize;
+    if places == 0 {
+        return format!("{sign}{digits}");
+    }
+    let digits = zero_padded(&digits, places + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - places);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{sign}{whole}")
+    } else {
+        format!("{sign}{whole}.{fraction}")
+    }
+}
+
+/// The number of decimal digits of `n`, estimated from its bits and only
+/// counted exactly near [`Rational::MAX_DISPLAY_DIGITS`].
+fn integer_digits(n: &BigInt) -> u64 {
+    // An n-bit integer has between (n - 1) * log10(2) and n * log10(2) digits,
+    // rounded down, plus one
+    #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    let estimate = |bits: u64| (bits as f64 * std::f64::consts::LOG10_2) as u64 + 1;
+    let bits = n.bits();
+    let (low, high) = (estimate(bits.saturating_sub(1)), estimate(bits));
+    let max = u64::from(Rational::MAX_DISPLAY_DIGITS);
+    if high <= max / 2 || low > max {
+        return high;
+    }
+    Rational::from_bigint(n.clone())
+        .decimal_exponent()
+        .map_or(1, |exponent| u64::from(exponent.unsigned_abs()) + 1)
+}
+
+/// Writes `digits` with leading zeros up to `width` characters. Unlike a
+/// `format!` width this has no upper limit.
+fn zero_padded(digits: &impl ToString, width: usize) -> String {
+    let digits = digits.to_string();
+    let zeros = width.saturating_sub(digits.len());
+    let mut padded = "0".repeat(zeros);
+    padded.push_str(&digits);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminating_decimals_keep_every_digit() {
+        let r = Rational::from_decimal("12345678901234567.5".parse().unwrap());
+        assert_eq!(r.to_display_string(), "12345678901234567.5");
+        assert_eq!(r.to_decimal().unwrap().to_string(), "12345678901234567.5");
+        assert_eq!(Rational::new(-1, 8).to_display_string(), "-0.125");
+    }
+
+    #[test]
+    fn test_decimal_digits_are_read_exactly() {
+        let digits = "0.123456789012345678901234567891";
+        let r = Rational::parse_decimal_digits(digits).unwrap();
+        assert_eq!(r.to_literal_string(), digits);
+        assert_eq!(
+            Rational::parse_decimal_digits("-123456789012345678901234567890123"),
+            Some(Rational::from_bigint(
+                "-123456789012345678901234567890123".parse().unwrap()
+            ))
+        );
+        assert_eq!(
+            Rational::parse_decimal_digits(".5"),
+            Some(Rational::new(1, 2))
+        );
+        assert_eq!(Rational::parse_decimal_digits("1.2.3"), None);
+        assert_eq!(Rational::parse_decimal_digits("."), None);
+    }
+
+    #[test]
+    fn test_repeating_decimals_are_rounded() {
+        assert_eq!(
+            Rational::new(1, 3).to_display_string(),
+            "0.3333333333333333"
+        );
+        assert_eq!(
+            Rational::new(2, 3).to_display_string(),
+            "0.6666666666666667"
+        );
+        assert_eq!(
+            Rational::new(10, 3).to_display_string(),
+            "3.333333333333333"
+        );
+        assert_eq!(
+            Rational::new(1, 3).to_decimal().unwrap().to_string(),
+            "0.3333333333333333333333333333"
+        );
+    }
+
+    #[test]
+    fn test_long_numbers_are_shown_in_scientific_notation() {
+        let ten = Rational::from_integer(10);
+        assert_eq!(ten.pow_i32(9999).to_display_string().len(), 10_000);
+        assert_eq!(ten.pow_i32(10_000).to_display_string(), "1e10000");
+        assert_eq!(
+            Rational::new(2, 3).pow_i32(100_000).to_display_string(),
+            "7.483321982518254e-17610"
+        );
+        assert!(!Rational::new(2, 3).pow_i32(100_000).has_display_digits());
+    }
+
+    #[test]
+    fn test_rounding_digits_mark_what_was_cut() {
+        assert_eq!(Rational::new(1, 3).to_rounding_digits(3), "0.3331");
+        assert_eq!(Rational::new(1, 4).to_rounding_digits(3), "0.2500");
+        assert_eq!(Rational::new(-5, 2).to_rounding_digits(1), "-2.50");
+    }
+
+    #[test]
+    fn test_rounding() {
+        let x = Rational::new(-5, 2);
+        assert_eq!(x.floor(), Rational::from_integer(-3));
+        assert_eq!(x.ceil(), Rational::from_integer(-2));
+        assert_eq!(x.trunc(), Rational::from_integer(-2));
+        assert_eq!(x.round_to_places(0), Rational::from_integer(-3));
+        assert_eq!(
+            Rational::new(2675, 1000).round_to_places(2),
+            Rational::new(268, 100)
+        );
+        assert_eq!(
+            Rational::from_integer(1250).round_to_places(-2),
+            Rational::from_integer(1300)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! How much detail the steps of a calculation include.
+
+use serde::{Deserialize, Serialize};
+
+/// The detail level of calculation steps.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum StepsLevel {
+    /// Only the input expression and the final result.
+    Minimal,
+    /// Every evaluation step.
+    #[default]
+    Normal,
+    /// Every evaluation step, with unit-bearing values normalized to base
+    /// units.
+    Verbose,
+    /// Verbose steps preceded by the parser's decisions: the tokens and the
+    /// grammar branch chosen for the input.
+    Debug,
+}
+
+impl StepsLevel {
+    /// Parses a level name: `minimal`, `normal`, `verbose` or `debug`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "minimal" => Some(Self::Minimal),
+            "normal" => Some(Self::Normal),
+            "verbose" => Some(Self::Verbose),
+            "debug" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Machine-readable calculation steps that form a computation graph.
+
+use serde::{Deserialize, Serialize};
+
+/// One operation of a calculation, as a node of its computation graph.
+///
+/// Steps are listed so that every input comes before the step using it; the
+/// last step produces the result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredStep {
+    /// Id of the value this step produces.
+    pub id: usize,
+    /// The kind of operation: `literal`, `add`, `multiply`, `power`,
+    /// `function`, `conversion`, and so on.
+    pub operation: String,
+    /// The function name, target unit or variable the operation refers to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Ids of the values this step consumes, in operand order.
+    pub inputs: Vec<usize>,
+    /// The value produced, as displayed.
+    pub output: String,
+    /// The unit of the value produced, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Exchange rates used by this step.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rates: Vec<StepRate>,
+}
+
+/// An exchange rate used by a [`StructuredStep`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRate {
+    /// The currency converted from.
+    pub from: String,
+    /// The currency converted to.
+    pub to: String,
+    /// Units of `to` per unit of `from`.
+    pub rate: f64,
+    /// Where the rate came from.
+    pub source: String,
+    /// The date of the rate (YYYY-MM-DD).
+    pub date: String,
+}
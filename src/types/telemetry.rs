@@ -0,0 +1,58 @@
+//! Anonymized metadata about each calculation, for hosts that aggregate
+//! which inputs fail most, and how long each phase of a calculation takes.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What a calculation was and how it went, without the input itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvaluationEvent {
+    /// Length of the input in characters.
+    pub input_length: usize,
+    /// What the input parsed as, such as `"unit conversion"`, or `None` if
+    /// it did not parse.
+    pub expression_kind: Option<String>,
+    /// How long the calculation took, in milliseconds, by the calculator's
+    /// clock.
+    pub duration_ms: f64,
+    /// Whether the calculation succeeded.
+    pub success: bool,
+    /// The translation key of the error, such as `"errors.parseError"`, if
+    /// the calculation failed.
+    pub error_code: Option<String>,
+}
+
+/// Receives an [`EvaluationEvent`] after every calculation.
+///
+/// Implemented for closures, so a host can pass `|event| ...` directly. A
+/// receiver is `Send` because calculations on any thread report to it.
+pub trait Telemetry: Send {
+    /// Records one calculation.
+    fn record(&mut self, event: &EvaluationEvent);
+}
+
+impl<F: FnMut(&EvaluationEvent) + Send> Telemetry for F {
+    fn record(&mut self, event: &EvaluationEvent) {
+        self(event);
+    }
+}
+
+/// How long each phase of a calculation took, in milliseconds by the
+/// calculator's clock.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTimings {
+    /// Splitting the input into tokens.
+    pub lex_ms: f64,
+    /// Building the expression from the tokens.
+    pub parse_ms: f64,
+    /// Evaluating the expression, with its steps.
+    pub eval_ms: f64,
+}
+
+impl PhaseTimings {
+    /// Returns the milliseconds from `start` to `end`.
+    #[must_use]
+    pub fn millis_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        (end - start).num_microseconds().unwrap_or(i64::MAX) as f64 / 1000.0
+    }
+}
@@ -3,6 +3,14 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::{ColorModel, Radix};
+
+mod angle;
+mod measure;
+mod power;
+pub use angle::{AngleUnit, AngleWrap};
+pub use measure::{LengthUnit, VolumeUnit};
+
 /// Represents a unit of measurement.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum Unit {
@@ -17,8 +25,32 @@ pub enum Unit {
     DataSize(DataSizeUnit),
     /// Mass/weight unit (e.g., kg, ton, lb).
     Mass(MassUnit),
+    /// Length unit (e.g., km, m, mi).
+    Length(LengthUnit),
+    /// Volume unit (e.g., L, ml, gal).
+    Volume(VolumeUnit),
+    /// Angle unit (e.g., °, rad, grad).
+    Angle(AngleUnit),
+    /// One unit per another (e.g., km/h, min/km, USD/kg).
+    Compound {
+        /// The unit being measured.
+        numerator: Box<Unit>,
+        /// The unit it is measured per.
+        denominator: Box<Unit>,
+    },
+    /// A unit to a whole power other than 0 and 1 (e.g., m², m³, s⁻¹).
+    Power {
+        /// The unit being raised.
+        base: Box<Unit>,
+        /// The power it is raised to.
+        exponent: i32,
+    },
     /// Timezone for datetime conversion (e.g., MSK, EST, GMT).
     Timezone(String),
+    /// Number base for integer display (e.g., `255 in hex`).
+    Radix(Radix),
+    /// A `0xRRGGBB` color and how it is written (e.g., `#ff8800 in rgb`).
+    Color(ColorModel),
     /// Custom unit.
     Custom(String),
 }
@@ -95,6 +127,32 @@ pub enum DataSizeUnit {
 }
 
 impl DataSizeUnit {
+    /// Every data size unit: bits, then bytes, each decimal before binary.
+    pub const ALL: &'static [Self] = &[
+        Self::Bit,
+        Self::Kilobit,
+        Self::Megabit,
+        Self::Gigabit,
+        Self::Terabit,
+        Self::Petabit,
+        Self::Kibibit,
+        Self::Mebibit,
+        Self::Gibibit,
+        Self::Tebibit,
+        Self::Pebibit,
+        Self::Byte,
+        Self::Kilobyte,
+        Self::Megabyte,
+        Self::Gigabyte,
+        Self::Terabyte,
+        Self::Petabyte,
+        Self::Kibibyte,
+        Self::Mebibyte,
+        Self::Gibibyte,
+        Self::Tebibyte,
+        Self::Pebibyte,
+    ];
+
     /// Returns the number of bits this unit represents.
     /// Uses exact integer arithmetic via u128 to avoid floating-point precision errors.
     #[must_use]
@@ -225,6 +283,16 @@ pub enum MassUnit {
 }
 
 impl MassUnit {
+    /// Every mass unit, metric first.
+    pub const ALL: &'static [Self] = &[
+        Self::Milligram,
+        Self::Gram,
+        Self::Kilogram,
+        Self::MetricTon,
+        Self::Pound,
+        Self::Ounce,
+    ];
+
     /// Returns the number of grams this unit represents (as f64).
     #[must_use]
     pub fn grams(self) -> f64 {
@@ -455,7 +523,12 @@ impl Unit {
                 | (Self::Duration(_), Self::Duration(_))
                 | (Self::DataSize(_), Self::DataSize(_))
                 | (Self::Mass(_), Self::Mass(_))
+                | (Self::Length(_), Self::Length(_))
+                | (Self::Volume(_), Self::Volume(_))
+                | (Self::Angle(_), Self::Angle(_))
                 | (Self::Timezone(_), Self::Timezone(_))
+                | (Self::Radix(_), Self::Radix(_))
+                | (Self::Color(_), Self::Color(_))
                 | (Self::Custom(_), Self::Custom(_))
         )
     }
@@ -503,7 +576,17 @@ impl Unit {
             Self::Duration(d) => d.to_string(),
             Self::DataSize(d) => d.abbreviation().to_string(),
             Self::Mass(m) => m.abbreviation().to_string(),
+            Self::Length(l) => l.abbreviation().to_string(),
+            Self::Volume(v) => v.abbreviation().to_string(),
+            Self::Angle(a) => a.abbreviation().to_string(),
+            Self::Compound {
+                numerator,
+                denominator,
+            } => format!("{}/{}", numerator.symbol(), denominator.symbol()),
+            Self::Power { base, exponent } => power::power_name(base, *exponent),
             Self::Timezone(tz) => tz.clone(),
+            Self::Radix(radix) => radix.to_string(),
+            Self::Color(model) => model.to_string(),
             Self::Custom(name) => name.clone(),
         }
     }
@@ -526,7 +609,13 @@ impl fmt::Display for Unit {
             Self::Duration(d) => write!(f, "{d}"),
             Self::DataSize(d) => write!(f, "{d}"),
             Self::Mass(m) => write!(f, "{m}"),
+            Self::Length(l) => write!(f, "{l}"),
+            Self::Volume(v) => write!(f, "{v}"),
+            Self::Angle(a) => write!(f, "{a}"),
+            Self::Compound { .. } | Self::Power { .. } => write!(f, "{}", self.display_name()),
             Self::Timezone(tz) => write!(f, "{tz}"),
+            Self::Radix(radix) => write!(f, "{radix}"),
+            Self::Color(model) => write!(f, "{model}"),
             Self::Custom(name) => write!(f, "{name}"),
         }
     }
@@ -555,6 +644,18 @@ impl fmt::Display for DataSizeUnit {
 }
 
 impl DurationUnit {
+    /// Every duration unit, shortest first.
+    pub const ALL: &'static [Self] = &[
+        Self::Milliseconds,
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hours,
+        Self::Days,
+        Self::Weeks,
+        Self::Months,
+        Self::Years,
+    ];
+
     /// Parses a string into a `DurationUnit`, returning `None` if not recognized.
     ///
     /// Supports English and Russian duration unit names (all grammatical cases).
@@ -658,6 +759,21 @@ impl DurationUnit {
         }
     }
 
+    /// Returns the singular name of the unit (e.g. `week`), used for amounts of exactly one.
+    #[must_use]
+    pub const fn singular_name(self) -> &'static str {
+        match self {
+            Self::Milliseconds => "millisecond",
+            Self::Seconds => "second",
+            Self::Minutes => "minute",
+            Self::Hours => "hour",
+            Self::Days => "day",
+            Self::Weeks => "week",
+            Self::Months => "month",
+            Self::Years => "year",
+        }
+    }
+
     /// Converts a duration to seconds.
     #[must_use]
     pub fn to_secs(self, value: f64) -> f64 {
@@ -0,0 +1,202 @@
+//! Angle units: `30°`, `1.2 rad`, `100 grad`, `30'`, `15"`, and degrees,
+//! minutes and seconds together: `45° 30' 15"`. Radians, degrees and
+//! gradians also name the angle mode, the unit the trigonometric functions
+//! read plain numbers in.
+
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+
+use crate::types::Rational;
+
+/// Angle units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AngleUnit {
+    /// 1 radian, the angle whose arc equals the radius
+    #[default]
+    Radian,
+    /// 1 degree = π/180 radians
+    Degree,
+    /// 1 gradian (gon) = π/200 radians
+    Gradian,
+    /// 1 minute of arc = 1/60 degree
+    Arcminute,
+    /// 1 second of arc = 1/60 minute of arc
+    Arcsecond,
+    /// Degrees, shown as whole degrees, minutes and seconds: `45° 30' 15"`
+    Dms,
+}
+
+impl AngleUnit {
+    /// Every angle unit, radians first.
+    pub const ALL: &'static [Self] = &[
+        Self::Radian,
+        Self::Degree,
+        Self::Gradian,
+        Self::Arcminute,
+        Self::Arcsecond,
+        Self::Dms,
+    ];
+
+    /// The units that can be the angle mode.
+    pub const MODES: &'static [Self] = &[Self::Radian, Self::Degree, Self::Gradian];
+
+    /// Returns the number of radians this unit represents (as f64).
+    #[must_use]
+    pub fn radians(self) -> f64 {
+        match self {
+            Self::Radian => 1.0,
+            Self::Degree | Self::Dms => PI / 180.0,
+            Self::Gradian => PI / 200.0,
+            Self::Arcminute => PI / 10_800.0,
+            Self::Arcsecond => PI / 648_000.0,
+        }
+    }
+
+    /// Returns one full turn in this unit: 360 for degrees. A turn in
+    /// radians, 2π, is approximated.
+    #[must_use]
+    pub fn turn(self) -> Rational {
+        match self {
+            Self::Radian => Rational::from_f64(2.0 * PI),
+            Self::Degree | Self::Dms => Rational::from_integer(360),
+            Self::Gradian => Rational::from_integer(400),
+            Self::Arcminute => Rational::from_integer(21_600),
+            Self::Arcsecond => Rational::from_integer(1_296_000),
+        }
+    }
+
+    /// Returns the standard abbreviation for this unit.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Radian => "rad",
+            Self::Degree => "°",
+            Self::Gradian => "grad",
+            Self::Arcminute => "'",
+            Self::Arcsecond => "\"",
+            Self::Dms => "dms",
+        }
+    }
+
+    /// Returns the plural name, as the angle mode is called: `degrees`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Radian => "radians",
+            Self::Degree => "degrees",
+            Self::Gradian => "gradians",
+            Self::Arcminute => "arcminutes",
+            Self::Arcsecond => "arcseconds",
+            Self::Dms => "degrees, minutes and seconds",
+        }
+    }
+
+    /// Parses a string into an `AngleUnit`, returning `None` if not
+    /// recognized.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "rad" | "radian" | "radians" | "радиан" | "радиана" | "радианов" => {
+                Some(Self::Radian)
+            }
+            "°" | "deg" | "degree" | "degrees" | "градус" | "градуса" | "градусов" => {
+                Some(Self::Degree)
+            }
+            "grad" | "grads" | "gradian" | "gradians" | "gon" | "gons" => Some(Self::Gradian),
+            "'" | "′" | "arcmin" | "arcminute" | "arcminutes" => Some(Self::Arcminute),
+            "\"" | "″" | "''" | "arcsec" | "arcsecond" | "arcseconds" => Some(Self::Arcsecond),
+            "dms" => Some(Self::Dms),
+            _ => None,
+        }
+    }
+}
+
+/// How the results of arithmetic on angles are brought within one turn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AngleWrap {
+    /// Angles are left as calculated: `350° + 20°` is `370°`.
+    #[default]
+    None,
+    /// From 0 up to a full turn: `350° + 20°` is `10°`.
+    Full,
+    /// From minus up to plus half a turn: `170° + 20°` is `-170°`.
+    Half,
+}
+
+impl AngleWrap {
+    /// Parses a wrapping name: `none`, `360` (also `0-360`, `full`) or
+    /// `180` (also `±180`, `half`).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "none" | "off" => Some(Self::None),
+            "360" | "0-360" | "0..360" | "full" => Some(Self::Full),
+            "180" | "±180" | "-180-180" | "-180..180" | "half" => Some(Self::Half),
+            _ => None,
+        }
+    }
+
+    /// Brings `angle`, in `unit`, within the range of this wrapping.
+    #[must_use]
+    pub fn apply(self, angle: &Rational, unit: AngleUnit) -> Rational {
+        let turn = unit.turn();
+        let within_turn = |angle: &Rational| {
+            let rest = angle.remainder(&turn).unwrap_or_default();
+            if rest.is_negative() {
+                rest + turn.clone()
+            } else {
+                rest
+            }
+        };
+        match self {
+            Self::None => angle.clone(),
+            Self::Full => within_turn(angle),
+            Self::Half => {
+                let rest = within_turn(angle);
+                if rest > turn.clone() / Rational::from_integer(2) {
+                    rest - turn.clone()
+                } else {
+                    rest
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for AngleUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_radians() {
+        assert_eq!(AngleUnit::parse("°"), Some(AngleUnit::Degree));
+        assert_eq!(AngleUnit::parse("Degrees"), Some(AngleUnit::Degree));
+        assert_eq!(AngleUnit::parse("gon"), Some(AngleUnit::Gradian));
+        assert_eq!(AngleUnit::parse("turns"), None);
+        let degrees = 90.0 * AngleUnit::Degree.radians();
+        let gradians = 100.0 * AngleUnit::Gradian.radians();
+        assert!((degrees - gradians).abs() < 1e-15);
+        assert_eq!(AngleUnit::parse("″"), Some(AngleUnit::Arcsecond));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let wrap = |mode: AngleWrap, degrees| {
+            mode.apply(&Rational::from_integer(degrees), AngleUnit::Degree)
+        };
+        assert_eq!(wrap(AngleWrap::Full, 370), Rational::from_integer(10));
+        assert_eq!(wrap(AngleWrap::Full, -30), Rational::from_integer(330));
+        assert_eq!(wrap(AngleWrap::Half, 190), Rational::from_integer(-170));
+        assert_eq!(wrap(AngleWrap::Half, 180), Rational::from_integer(180));
+        assert_eq!(wrap(AngleWrap::None, 720), Rational::from_integer(720));
+    }
+}
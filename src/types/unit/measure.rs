@@ -0,0 +1,422 @@
+//! Length and volume units, and compound units such as `km/h` or `min/km`
+//! formed by dividing one unit by another.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{AngleUnit, DataSizeUnit, DurationUnit, MassUnit, Unit};
+use crate::types::Rational;
+
+/// Length/distance units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LengthUnit {
+    /// 1 millimeter = 0.001 meters
+    Millimeter,
+    /// 1 centimeter = 0.01 meters
+    Centimeter,
+    /// 1 meter
+    Meter,
+    /// 1 kilometer = 1000 meters
+    Kilometer,
+    /// 1 inch = 0.0254 meters
+    Inch,
+    /// 1 foot = 0.3048 meters
+    Foot,
+    /// 1 yard = 0.9144 meters
+    Yard,
+    /// 1 mile = 1609.344 meters
+    Mile,
+}
+
+impl LengthUnit {
+    /// Every length unit, metric first.
+    pub const ALL: &'static [Self] = &[
+        Self::Millimeter,
+        Self::Centimeter,
+        Self::Meter,
+        Self::Kilometer,
+        Self::Inch,
+        Self::Foot,
+        Self::Yard,
+        Self::Mile,
+    ];
+
+    /// Returns the number of meters this unit represents (as f64).
+    #[must_use]
+    pub fn meters(self) -> f64 {
+        match self {
+            Self::Millimeter => 0.001,
+            Self::Centimeter => 0.01,
+            Self::Meter => 1.0,
+            Self::Kilometer => 1000.0,
+            Self::Inch => 0.0254,
+            Self::Foot => 0.3048,
+            Self::Yard => 0.9144,
+            Self::Mile => 1609.344,
+        }
+    }
+
+    /// Returns the standard abbreviation for this unit.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Millimeter => "mm",
+            Self::Centimeter => "cm",
+            Self::Meter => "m",
+            Self::Kilometer => "km",
+            Self::Inch => "inch",
+            Self::Foot => "ft",
+            Self::Yard => "yd",
+            Self::Mile => "mi",
+        }
+    }
+
+    /// Parses a string into a `LengthUnit`, returning `None` if not recognized.
+    ///
+    /// `in` is deliberately not an alias for inches, since it is the
+    /// conversion keyword.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            // ── English ──────────────────────────────────────────────────────
+            "mm" | "millimeter" | "millimeters" | "millimetre" | "millimetres" => {
+                Some(Self::Millimeter)
+            }
+            "cm" | "centimeter" | "centimeters" | "centimetre" | "centimetres" => {
+                Some(Self::Centimeter)
+            }
+            "m" | "meter" | "meters" | "metre" | "metres" => Some(Self::Meter),
+            "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => Some(Self::Kilometer),
+            "inch" | "inches" => Some(Self::Inch),
+            "ft" | "foot" | "feet" => Some(Self::Foot),
+            "yd" | "yard" | "yards" => Some(Self::Yard),
+            "mi" | "mile" | "miles" => Some(Self::Mile),
+            // ── Russian (ru) ─────────────────────────────────────────────────
+            "мм" | "миллиметр" | "миллиметра" | "миллиметров" => {
+                Some(Self::Millimeter)
+            }
+            "см" | "сантиметр" | "сантиметра" | "сантиметров" => {
+                Some(Self::Centimeter)
+            }
+            "м" | "метр" | "метра" | "метров" => Some(Self::Meter),
+            "км" | "километр" | "километра" | "километров" => {
+                Some(Self::Kilometer)
+            }
+            "миля" | "мили" | "миль" => Some(Self::Mile),
+            // ── German (de) and French (fr) ──────────────────────────────────
+            "metern" => Some(Self::Meter),
+            "kilometern" => Some(Self::Kilometer),
+            "meile" | "meilen" => Some(Self::Mile),
+            "mètre" | "mètres" => Some(Self::Meter),
+            "kilomètre" | "kilomètres" => Some(Self::Kilometer),
+            // ── Chinese (zh), Hindi (hi) and Arabic (ar) ─────────────────────
+            "米" | "मीटर" | "متر" => Some(Self::Meter),
+            "公里" | "千米" | "किलोमीटर" | "كيلومتر" | "كم" => {
+                Some(Self::Kilometer)
+            }
+            "英里" | "मील" | "ميل" => Some(Self::Mile),
+            _ => None,
+        }
+    }
+}
+
+/// Volume units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolumeUnit {
+    /// 1 milliliter = 0.001 liters
+    Milliliter,
+    /// 1 liter
+    Liter,
+    /// 1 US gallon ≈ 3.785 liters
+    Gallon,
+}
+
+impl VolumeUnit {
+    /// Every volume unit, metric first.
+    pub const ALL: &'static [Self] = &[Self::Milliliter, Self::Liter, Self::Gallon];
+
+    /// Returns the number of liters this unit represents (as f64).
+    #[must_use]
+    pub fn liters(self) -> f64 {
+        match self {
+            Self::Milliliter => 0.001,
+            Self::Liter => 1.0,
+            Self::Gallon => 3.785_411_784,
+        }
+    }
+
+    /// Returns the standard abbreviation for this unit.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Milliliter => "ml",
+            Self::Liter => "L",
+            Self::Gallon => "gal",
+        }
+    }
+
+    /// Parses a string into a `VolumeUnit`, returning `None` if not recognized.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" | "мл" | "毫升" => {
+                Some(Self::Milliliter)
+            }
+            "l" | "liter" | "liters" | "litre" | "litres" | "л" | "литр" | "литра" | "литров"
+            | "升" | "लीटर" | "لتر" => Some(Self::Liter),
+            "gal" | "gallon" | "gallons" => Some(Self::Gallon),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl fmt::Display for VolumeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl Unit {
+    /// Creates the compound unit `numerator/denominator`, e.g. `km/h`.
+    #[must_use]
+    pub fn per(numerator: Self, denominator: Self) -> Self {
+        Self::Compound {
+            numerator: Box::new(numerator),
+            denominator: Box::new(denominator),
+        }
+    }
+
+    /// Parses a single-word compound unit: `mph`, `kph`/`kmh` or `mpg`.
+    #[must_use]
+    pub fn parse_compound_alias(s: &str) -> Option<Self> {
+        let (numerator, denominator) = match s.to_lowercase().as_str() {
+            "mph" => (
+                Self::Length(LengthUnit::Mile),
+                Self::Duration(DurationUnit::Hours),
+            ),
+            "kph" | "kmh" | "kmph" => (
+                Self::Length(LengthUnit::Kilometer),
+                Self::Duration(DurationUnit::Hours),
+            ),
+            "mpg" => (
+                Self::Length(LengthUnit::Mile),
+                Self::Volume(VolumeUnit::Gallon),
+            ),
+            _ => return None,
+        };
+        Some(Self::per(numerator, denominator))
+    }
+
+    /// Checks if the unit measures a physical or digital quantity that can
+    /// be part of a compound unit: length, volume, duration, mass or data
+    /// size. Currencies may only be the numerator of a compound unit.
+    #[must_use]
+    pub fn is_measure(&self) -> bool {
+        matches!(
+            self,
+            Self::Length(_)
+                | Self::Volume(_)
+                | Self::Angle(_)
+                | Self::Duration(_)
+                | Self::Mass(_)
+                | Self::DataSize(_)
+                | Self::Compound { .. }
+                | Self::Power { .. }
+        )
+    }
+
+    /// Checks if amounts in this unit have a dimension, so that the unit of
+    /// a product or quotient of two of them must come from a rule, as
+    /// `m²` does for `2 m * 3 m`: measures, currencies and custom units.
+    #[must_use]
+    pub fn has_dimension(&self) -> bool {
+        self.is_measure() || self.is_currency() || matches!(self, Self::Custom(_))
+    }
+
+    /// Returns the factor that converts an amount in this unit to `to`, if
+    /// both measure the same kind of quantity without needing exchange
+    /// rates.
+    #[must_use]
+    pub fn conversion_factor(&self, to: &Self) -> Option<f64> {
+        match (self, to) {
+            (Self::Length(a), Self::Length(b)) => Some(a.meters() / b.meters()),
+            (Self::Volume(a), Self::Volume(b)) => Some(a.liters() / b.liters()),
+            (Self::Angle(a), Self::Angle(b)) => Some(a.radians() / b.radians()),
+            (Self::Duration(a), Self::Duration(b)) => Some(a.to_secs(1.0) / b.to_secs(1.0)),
+            (Self::Mass(a), Self::Mass(b)) => Some(a.convert(1.0, *b)),
+            (Self::DataSize(a), Self::DataSize(b)) => Some(a.convert(1.0, *b)),
+            (Self::Currency(a), Self::Currency(b)) if a == b => Some(1.0),
+            (
+                Self::Compound {
+                    numerator: n1,
+                    denominator: d1,
+                },
+                Self::Compound {
+                    numerator: n2,
+                    denominator: d2,
+                },
+            ) => Some(n1.conversion_factor(n2)? / d1.conversion_factor(d2)?),
+            (
+                Self::Power {
+                    base: a,
+                    exponent: m,
+                },
+                Self::Power {
+                    base: b,
+                    exponent: n,
+                },
+            ) if m == n => Some(a.conversion_factor(b)?.powi(*m)),
+            // A cubic length is a volume: 1 m³ is 1000 L
+            (Self::Power { base, exponent: 3 }, Self::Volume(volume)) => match **base {
+                Self::Length(length) => Some(length.meters().powi(3) * 1000.0 / volume.liters()),
+                _ => None,
+            },
+            (Self::Volume(_), Self::Power { .. }) => Some(1.0 / to.conversion_factor(self)?),
+            _ => None,
+        }
+    }
+
+    /// Returns [`Self::conversion_factor`] as an exact fraction of the
+    /// decimal sizes of the two units rather than their rounded `f64`
+    /// quotient, so `1 ft` is exactly `0.3048 m`. Kinds without decimal
+    /// sizes, such as angles, fall back to the `f64` factor.
+    #[must_use]
+    pub fn exact_conversion_factor(&self, to: &Self) -> Option<Rational> {
+        match (self, to) {
+            (
+                Self::Compound {
+                    numerator: n1,
+                    denominator: d1,
+                },
+                Self::Compound {
+                    numerator: n2,
+                    denominator: d2,
+                },
+            ) => Some(n1.exact_conversion_factor(n2)? / d1.exact_conversion_factor(d2)?),
+            (
+                Self::Power {
+                    base: a,
+                    exponent: m,
+                },
+                Self::Power {
+                    base: b,
+                    exponent: n,
+                },
+            ) if m == n => Some(a.exact_conversion_factor(b)?.pow_i32(*m)),
+            _ => {
+                let factor = self.conversion_factor(to)?;
+                match (self.decimal_size(), to.decimal_size()) {
+                    (Some(from), Some(to)) => Some(from / to),
+                    _ => Some(Rational::from_f64(factor)),
+                }
+            }
+        }
+    }
+
+    /// The size of this unit in its kind's base unit, read from the decimal
+    /// digits it is defined with.
+    fn decimal_size(&self) -> Option<Rational> {
+        let size = match self {
+            Self::Length(unit) => unit.meters(),
+            Self::Volume(unit) => unit.liters(),
+            Self::Duration(unit) => unit.to_secs(1.0),
+            Self::Mass(unit) => unit.grams(),
+            Self::DataSize(unit) => {
+                return Some(Rational::from_integer(i128::try_from(unit.bits()).ok()?))
+            }
+            _ => return None,
+        };
+        Rational::parse_decimal_digits(&size.to_string())
+    }
+
+    /// Returns the base unit that amounts of this kind are normalized to:
+    /// meters, liters, radians, seconds, kilograms or bytes, or their
+    /// quotient for a compound unit (`m/s`). Returns `None` for units
+    /// without one.
+    #[must_use]
+    pub fn base_unit(&self) -> Option<Self> {
+        match self {
+            Self::Length(_) => Some(Self::Length(LengthUnit::Meter)),
+            Self::Volume(_) => Some(Self::Volume(VolumeUnit::Liter)),
+            Self::Angle(_) => Some(Self::Angle(AngleUnit::Radian)),
+            Self::Duration(_) => Some(Self::Duration(DurationUnit::Seconds)),
+            Self::Mass(_) => Some(Self::Mass(MassUnit::Kilogram)),
+            Self::DataSize(_) => Some(Self::DataSize(DataSizeUnit::Byte)),
+            Self::Compound {
+                numerator,
+                denominator,
+            } => Some(Self::per(numerator.base_unit()?, denominator.base_unit()?)),
+            Self::Power { base, exponent } => Some(Self::power(base.base_unit()?, *exponent)),
+            _ => None,
+        }
+    }
+
+    /// Returns the short name used inside a compound unit: `h` rather than
+    /// `hours` in `km/h`.
+    pub(super) fn symbol(&self) -> String {
+        match self {
+            Self::Duration(duration) => duration.abbreviation().to_string(),
+            _ => self.display_name(),
+        }
+    }
+}
+
+impl DurationUnit {
+    /// Returns the standard abbreviation for this unit, as used in `km/h`.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+            Self::Days => "d",
+            Self::Weeks => "wk",
+            Self::Months => "mo",
+            Self::Years => "yr",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compound_unit_display() {
+        let speed = Unit::per(
+            Unit::Length(LengthUnit::Kilometer),
+            Unit::Duration(DurationUnit::Hours),
+        );
+        assert_eq!(speed.to_string(), "km/h");
+        assert_eq!(
+            Unit::parse_compound_alias("mph").unwrap().to_string(),
+            "mi/h"
+        );
+    }
+
+    #[test]
+    fn test_compound_conversion_factor() {
+        let mph = Unit::parse_compound_alias("mph").unwrap();
+        let kph = Unit::parse_compound_alias("kph").unwrap();
+        let factor = mph.conversion_factor(&kph).unwrap();
+        assert!((factor - 1.609_344).abs() < 1e-12);
+        assert_eq!(
+            Unit::Length(LengthUnit::Mile).conversion_factor(&Unit::Volume(VolumeUnit::Liter)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_base_unit() {
+        let kph = Unit::parse_compound_alias("kph").unwrap();
+        assert_eq!(kph.base_unit().unwrap().to_string(), "m/s");
+        assert_eq!(Unit::None.base_unit(), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! Units to whole powers: `m²` from `2 m * 3 m`, `m³` from `5 m^3`.
+
+use super::Unit;
+
+/// Superscript digits, by value.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl Unit {
+    /// Raises `base` to `exponent`: `m` squared is `m²`, and `m²` cubed is
+    /// `m⁶`. The zeroth power is no unit and the first power the base.
+    #[must_use]
+    pub fn power(base: Self, exponent: i32) -> Self {
+        let (base, exponent) = match base {
+            Self::Power {
+                base,
+                exponent: inner,
+            } => (*base, inner.saturating_mul(exponent)),
+            base => (base, exponent),
+        };
+        match exponent {
+            0 => Self::None,
+            1 => base,
+            _ => Self::Power {
+                base: Box::new(base),
+                exponent,
+            },
+        }
+    }
+
+    /// Returns the unit this one is a power of, and the power: `m²` is
+    /// `(m, 2)`, and any other unit is itself to the first power.
+    #[must_use]
+    pub fn base_and_exponent(&self) -> (&Self, i32) {
+        match self {
+            Self::Power { base, exponent } => (base, *exponent),
+            unit => (unit, 1),
+        }
+    }
+
+    /// Checks if amounts in this unit multiply into a power of it: lengths,
+    /// masses and durations.
+    #[must_use]
+    pub const fn is_powerable(&self) -> bool {
+        matches!(self, Self::Length(_) | Self::Mass(_) | Self::Duration(_))
+    }
+
+    /// Parses a unit written with a superscript power, such as `m²`, `cm³`
+    /// or `s⁻¹`, with `parse` reading the base unit.
+    pub fn parse_superscript_power(s: &str, parse: impl Fn(&str) -> Option<Self>) -> Option<Self> {
+        let digits_start = s
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| SUPERSCRIPT_DIGITS.contains(c) || *c == '⁻')
+            .last()
+            .map(|(i, _)| i)?;
+        let (base, power) = s.split_at(digits_start);
+        let exponent = power
+            .chars()
+            .map(|c| match SUPERSCRIPT_DIGITS.iter().position(|&d| d == c) {
+                Some(digit) => {
+                    char::from_digit(u32::try_from(digit).unwrap_or(0), 10).unwrap_or('0')
+                }
+                None => '-',
+            })
+            .collect::<String>()
+            .parse()
+            .ok()?;
+        let base = parse(base).filter(Self::is_powerable)?;
+        Some(Self::power(base, exponent))
+    }
+}
+
+/// Writes `base` with `exponent` in superscript: `m²`, `s⁻¹`.
+pub(super) fn power_name(base: &Unit, exponent: i32) -> String {
+    let superscript: String = exponent
+        .to_string()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map_or('⁻', |digit| SUPERSCRIPT_DIGITS[digit as usize])
+        })
+        .collect();
+    format!("{}{superscript}", base.symbol())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DurationUnit, LengthUnit};
+
+    const METER: Unit = Unit::Length(LengthUnit::Meter);
+
+    #[test]
+    fn test_power_names() {
+        assert_eq!(Unit::power(METER, 2).to_string(), "m²");
+        assert_eq!(
+            Unit::power(Unit::Duration(DurationUnit::Seconds), -1).to_string(),
+            "s⁻¹"
+        );
+        assert_eq!(Unit::power(Unit::power(METER, 2), 3).to_string(), "m⁶");
+        assert_eq!(Unit::power(METER, 1), METER);
+        assert_eq!(Unit::power(METER, 0), Unit::None);
+    }
+
+    #[test]
+    fn test_parse_superscript_power() {
+        let parse = |s: &str| LengthUnit::parse(s).map(Unit::Length);
+        assert_eq!(
+            Unit::parse_superscript_power("m²", parse),
+            Some(Unit::power(METER, 2))
+        );
+        assert_eq!(
+            Unit::parse_superscript_power("cm³", parse),
+            Some(Unit::power(Unit::Length(LengthUnit::Centimeter), 3))
+        );
+        assert_eq!(Unit::parse_superscript_power("m", parse), None);
+        assert_eq!(Unit::parse_superscript_power("x²", parse), None);
+    }
+}
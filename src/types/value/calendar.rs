@@ -0,0 +1,134 @@
+//! Calendar-aware spans between dates, as used by `age(...)` and `time until ...`.
+
+use chrono::{Datelike, Duration};
+
+use super::duration::{convert_raw_duration, duration_unit_seconds, format_duration};
+use super::Value;
+use crate::error::CalculatorError;
+use crate::types::{DateTime, DurationUnit, Rational, Unit, ValueKind};
+
+impl Value {
+    /// Creates a calendar span value from its parts.
+    #[must_use]
+    pub fn calendar_span(months: i64, seconds: i64, total_seconds: i64) -> Self {
+        Self {
+            kind: ValueKind::CalendarSpan {
+                months,
+                seconds,
+                total_seconds,
+            },
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Measures the span from `from` to `to` in whole calendar months plus the
+    /// remaining time, so 15 June 1990 to 1 January 2026 is 35 years, 6 months
+    /// and 17 days regardless of month lengths.
+    ///
+    /// When only one side has a time of day (e.g. `now` against a plain date),
+    /// both sides are compared as dates. The span is negative when `to` is
+    /// before `from`.
+    #[must_use]
+    pub fn calendar_span_between(from: &DateTime, to: &DateTime) -> Self {
+        let (from, to) = if from.has_time() == to.has_time() {
+            (from.clone(), to.clone())
+        } else {
+            (date_only(from), date_only(to))
+        };
+        if to.inner_utc() < from.inner_utc() {
+            return Self::calendar_span_between(&to, &from).negate();
+        }
+
+        let (start, end) = (from.inner_utc(), to.inner_utc());
+        let mut months = i64::from(end.year() - start.year()) * 12 + i64::from(end.month())
+            - i64::from(start.month());
+        let shifted = |months: i64| from.add_calendar_months(i32::try_from(months).unwrap_or(0));
+        if rounded_seconds_between(&shifted(months), &to) < 0 {
+            months -= 1;
+        }
+        let seconds = rounded_seconds_between(&shifted(months), &to);
+        Self::calendar_span(months, seconds, rounded_seconds_between(&from, &to))
+    }
+
+    /// Creates the duration from `earlier` to `later`. It shows as a plain
+    /// duration, such as `438 days`, but keeps the calendar months between
+    /// the dates, so that in months or years it counts them by the calendar.
+    #[must_use]
+    pub fn date_difference(later: &DateTime, earlier: &DateTime) -> Self {
+        let calendar = match Self::calendar_span_between(earlier, later).kind {
+            ValueKind::CalendarSpan {
+                months, seconds, ..
+            } => Some((months, seconds)),
+            _ => None,
+        };
+        Self {
+            kind: ValueKind::Duration {
+                seconds: later.signed_subtract_seconds(earlier),
+                calendar,
+            },
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+}
+
+/// The calendar date of `dt`, read in its own timezone.
+fn date_only(dt: &DateTime) -> DateTime {
+    let offset = Duration::seconds(i64::from(dt.offset_seconds().unwrap_or(0)));
+    DateTime::from_date((dt.inner_utc() + offset).date_naive())
+}
+
+/// Whole seconds from `from` to `to`, rounded so that two reads of `now`
+/// a few microseconds apart do not lose a second.
+fn rounded_seconds_between(from: &DateTime, to: &DateTime) -> i64 {
+    let millis = to
+        .inner_utc()
+        .signed_duration_since(from.inner_utc())
+        .num_milliseconds();
+    (millis + 500).div_euclid(1000)
+}
+
+/// Formats a calendar span as `35 years, 6 months, 17 days`.
+pub(super) fn format_calendar_span(months: i64, seconds: i64) -> String {
+    let sign = if months < 0 || seconds < 0 { "-" } else { "" };
+    let (months, seconds) = (months.abs(), seconds.abs());
+
+    let mut parts = Vec::new();
+    for (amount, name) in [(months / 12, "year"), (months % 12, "month")] {
+        if amount > 0 {
+            parts.push(format!(
+                "{amount} {name}{}",
+                if amount == 1 { "" } else { "s" }
+            ));
+        }
+    }
+    if seconds > 0 {
+        parts.push(format_duration(seconds));
+    } else if parts.is_empty() {
+        parts.push("0 days".to_string());
+    }
+
+    format!("{sign}{}", parts.join(", "))
+}
+
+/// Converts a calendar span to a duration unit. Months and years count the
+/// whole calendar months exactly; other units use the span's length in seconds.
+pub(super) fn convert_calendar_span(
+    months: i64,
+    seconds: i64,
+    total_seconds: i64,
+    target_unit: &Unit,
+) -> Result<Value, CalculatorError> {
+    let months_per_unit = match target_unit {
+        Unit::Duration(DurationUnit::Months) => 1,
+        Unit::Duration(DurationUnit::Years) => 12,
+        _ => return convert_raw_duration(total_seconds, target_unit),
+    };
+    let total_months = Rational::from_integer(i128::from(months))
+        + Rational::from_integer(i128::from(seconds)) / duration_unit_seconds(DurationUnit::Months);
+    Ok(Value::rational_with_unit(
+        total_months / Rational::from_integer(months_per_unit),
+        target_unit.clone(),
+    ))
+}
@@ -0,0 +1,76 @@
+//! Colors such as `#ff8800`, held as their `0xRRGGBB` number.
+
+use super::Value;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Color, ColorModel, Rational, Unit};
+
+impl Value {
+    /// Creates a color value written in `model`.
+    #[must_use]
+    pub fn color(color: Color, model: ColorModel) -> Self {
+        Self::rational_with_unit(
+            Rational::from_integer(i128::from(color.to_number())),
+            Unit::Color(model),
+        )
+    }
+
+    /// Returns the color and the model it is written in if this is a color.
+    #[must_use]
+    pub fn as_color(&self) -> Option<(Color, ColorModel)> {
+        let Unit::Color(model) = self.unit else {
+            return None;
+        };
+        let n = self.to_rational().filter(Rational::is_integer)?;
+        let color = Color::from_number(u32::try_from(n.numer_bigint()).ok()?)?;
+        Some((color, model))
+    }
+
+    /// Applies a binary operation when either operand is a color.
+    ///
+    /// Colors add and subtract channel by channel, and multiply or divide
+    /// by a plain number, each channel kept within 0 to 255. The result is
+    /// written the way the left color is. Returns `None` when neither
+    /// operand is a color.
+    pub fn color_op(
+        left: &Self,
+        op: BinaryOp,
+        right: &Self,
+    ) -> Option<Result<Self, CalculatorError>> {
+        let factor = |value: &Self| {
+            value
+                .to_rational()
+                .filter(|_| value.unit == Unit::None)
+                .map(|n| n.to_f64())
+        };
+        let result = match (left.as_color(), op, right.as_color()) {
+            (None, _, None) => return None,
+            (Some((a, model)), BinaryOp::Add, Some((b, _))) => Some((a.saturating_add(b), model)),
+            (Some((a, model)), BinaryOp::Subtract, Some((b, _))) => {
+                Some((a.saturating_sub(b), model))
+            }
+            (Some((a, model)), BinaryOp::Multiply, None) => {
+                factor(right).map(|k| (a.scale(k), model))
+            }
+            (None, BinaryOp::Multiply, Some((b, model))) => {
+                factor(left).map(|k| (b.scale(k), model))
+            }
+            (Some((a, model)), BinaryOp::Divide, None) => match factor(right) {
+                Some(0.0) => return Some(Err(CalculatorError::DivisionByZero)),
+                k => k.map(|k| (a.scale(1.0 / k), model)),
+            },
+            _ => None,
+        };
+        Some(
+            result
+                .map(|(color, model)| Self::color(color, model))
+                .ok_or_else(|| {
+                    CalculatorError::InvalidOperation(format!(
+                        "Cannot apply {op} to {} and {}; colors add and subtract \
+                         each other and scale by plain numbers",
+                        left.to_display_string(),
+                        right.to_display_string()
+                    ))
+                }),
+        )
+    }
+}
@@ -0,0 +1,186 @@
+//! Arithmetic on lengths, volumes and compound units: `120 km / 2 hours`
+//! gives `60 km/h`, `60 km/h * 30 minutes` gives `30 km`, and `2 m * 3 m`
+//! gives `6 m²`.
+
+use super::Value;
+use crate::error::CalculatorError;
+use crate::types::{Rational, Unit};
+
+impl Value {
+    /// Returns `other` converted to this value's unit when both are amounts
+    /// of the same length, volume or rate in different units, so that
+    /// `1 km + 500 m` can be added.
+    pub(super) fn align_measure(&self, other: &Self) -> Option<Self> {
+        if self.unit == other.unit || self.to_rational().is_none() {
+            return None;
+        }
+        other.convert_measure(&self.unit)
+    }
+
+    /// Converts a length, volume or rate to another unit of the same kind:
+    /// `60 mph in km/h`.
+    pub(super) fn convert_measure(&self, target: &Unit) -> Option<Self> {
+        let converts = |unit: &Unit| {
+            matches!(
+                unit,
+                Unit::Length(_)
+                    | Unit::Volume(_)
+                    | Unit::Angle(_)
+                    | Unit::Compound { .. }
+                    | Unit::Power { .. }
+            )
+        };
+        if !converts(&self.unit) || !converts(target) {
+            return None;
+        }
+        let factor = self.unit.conversion_factor(target)?;
+        let amount = self.to_rational()? * Rational::from_f64(factor);
+        Some(Self::rational_with_unit(amount, target.clone()))
+    }
+
+    /// Returns this amount in its base unit (see [`Unit::base_unit`]), or
+    /// `None` if it has no base unit or is already in it.
+    #[must_use]
+    pub fn to_base_unit(&self) -> Option<Self> {
+        let base = self.unit.base_unit().filter(|base| *base != self.unit)?;
+        let factor = self.unit.conversion_factor(&base)?;
+        let amount = self.to_rational()? * Rational::from_f64(factor);
+        Some(Self::rational_with_unit(amount, base))
+    }
+
+    /// Multiplies a rate by an amount of what it is measured per:
+    /// `60 km/h * 30 minutes` gives `30 km`, and `9.8 m/s² * 2 s` gives
+    /// `19.6 m/s`. An amount of what the rate measures raises its
+    /// numerator instead: `2 m² * 3 m/s` gives `6 m³/s`.
+    pub(super) fn multiply_compound(&self, other: &Self) -> Option<Self> {
+        let (rate, amount) = match (&self.unit, &other.unit) {
+            (Unit::Compound { .. }, _) => (self, other),
+            (_, Unit::Compound { .. }) => (other, self),
+            _ => return None,
+        };
+        let Unit::Compound {
+            numerator,
+            denominator,
+        } = &rate.unit
+        else {
+            return None;
+        };
+        let (per, per_exponent) = denominator.base_and_exponent();
+        let (base, exponent) = amount.unit.base_and_exponent();
+        if base.conversion_factor(per).is_none() {
+            return Self::multiply_numerator(rate, numerator, denominator, amount);
+        }
+        if exponent > per_exponent {
+            return None;
+        }
+        let factor = Rational::from_f64(base.conversion_factor(per)?).pow_i32(exponent);
+        let product = rate.to_rational()? * amount.to_rational()? * factor;
+        let unit = match Unit::power(per.clone(), per_exponent - exponent) {
+            Unit::None => (**numerator).clone(),
+            remaining => Unit::per((**numerator).clone(), remaining),
+        };
+        Some(Self::rational_with_unit(product, unit))
+    }
+
+    /// Multiplies a rate by an amount in powers of its numerator's unit:
+    /// `3 m/s * 2 m²` gives `6 m³/s`.
+    fn multiply_numerator(
+        rate: &Self,
+        numerator: &Unit,
+        denominator: &Unit,
+        amount: &Self,
+    ) -> Option<Self> {
+        let (base, exponent) = numerator.base_and_exponent();
+        let (other_base, other_exponent) = amount.unit.base_and_exponent();
+        if !base.is_powerable() {
+            return None;
+        }
+        let factor =
+            Rational::from_f64(other_base.conversion_factor(base)?).pow_i32(other_exponent);
+        let product = rate.to_rational()? * amount.to_rational()? * factor;
+        let unit = match Unit::power(base.clone(), exponent + other_exponent) {
+            Unit::None => Unit::power(denominator.clone(), -1),
+            raised => Unit::per(raised, denominator.clone()),
+        };
+        Some(Self::rational_with_unit(product, unit))
+    }
+
+    /// Multiplies amounts of the same kind of measure into a power of the
+    /// first one's unit: `2 m * 3 m` gives `6 m²`, `2 m² * 3 m` gives
+    /// `6 m³`, and `2 km * 500 m` gives `1 km²`.
+    pub(super) fn multiply_powers(&self, other: &Self) -> Option<Self> {
+        let (base, exponent, factor) = self.power_alignment(other)?;
+        let product = self.to_rational()? * other.to_rational()? * factor;
+        Some(Self::rational_with_unit(
+            product,
+            Unit::power(base, exponent.0 + exponent.1),
+        ))
+    }
+
+    /// Divides amounts of the same kind of measure into a power of the
+    /// first one's unit: `8 m³ / 2 m` gives `4 m²`, and `6 m² / 3 m²` gives
+    /// `2`.
+    pub(super) fn divide_powers(&self, other: &Self) -> Result<Option<Self>, CalculatorError> {
+        let Some((base, exponent, factor)) = self.power_alignment(other) else {
+            return Ok(None);
+        };
+        let (Some(left), Some(right)) = (self.to_rational(), other.to_rational()) else {
+            return Ok(None);
+        };
+        if right.is_zero() {
+            return Err(CalculatorError::DivisionByZero);
+        }
+        Ok(Some(Self::rational_with_unit(
+            left / (right * factor),
+            Unit::power(base, exponent.0 - exponent.1),
+        )))
+    }
+
+    /// For two amounts in powers of units of the same kind, such as `km²`
+    /// and `m`, returns the first one's base unit, both exponents, and the
+    /// factor that turns the second amount into powers of that base.
+    fn power_alignment(&self, other: &Self) -> Option<(Unit, (i32, i32), Rational)> {
+        let (base, exponent) = self.unit.base_and_exponent();
+        let (other_base, other_exponent) = other.unit.base_and_exponent();
+        if !base.is_powerable() {
+            return None;
+        }
+        let factor = Rational::from_f64(other_base.conversion_factor(base)?);
+        Some((
+            base.clone(),
+            (exponent, other_exponent),
+            factor.pow_i32(other_exponent),
+        ))
+    }
+
+    /// Divides two amounts of different measures into a rate
+    /// (`120 km / 2 hours` gives `60 km/h`), or an amount by a rate of it
+    /// (`120 km / 60 km/h` gives `2 h`).
+    pub(super) fn divide_compound(&self, other: &Self) -> Result<Option<Self>, CalculatorError> {
+        let (Some(left), Some(right)) = (self.to_rational(), other.to_rational()) else {
+            return Ok(None);
+        };
+        let (quotient, unit) = if let Unit::Compound {
+            numerator,
+            denominator,
+        } = &other.unit
+        {
+            let Some(factor) = self.unit.conversion_factor(numerator) else {
+                return Ok(None);
+            };
+            (left * Rational::from_f64(factor), (**denominator).clone())
+        } else if (self.unit.is_measure() || self.unit.is_currency())
+            && other.unit.is_measure()
+            && !matches!(self.unit, Unit::Compound { .. })
+            && self.unit.conversion_factor(&other.unit).is_none()
+        {
+            (left, Unit::per(self.unit.clone(), other.unit.clone()))
+        } else {
+            return Ok(None);
+        };
+        if right.is_zero() {
+            return Err(CalculatorError::DivisionByZero);
+        }
+        Ok(Some(Self::rational_with_unit(quotient / right, unit)))
+    }
+}
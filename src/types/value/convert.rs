@@ -0,0 +1,195 @@
+//! Unit conversion for values: `741 KB as MB`, `100 USD in EUR`,
+//! `60 mph in km/h`.
+
+use super::{
+    apply_duration_unit, convert_calendar_span, convert_duration_unit, convert_raw_duration, Value,
+    ValueKind,
+};
+use crate::error::CalculatorError;
+use crate::types::{
+    City, ColorModel, ConversionReceipt, CurrencyDatabase, DateTime, Radix, Rational, Unit,
+};
+
+impl Value {
+    /// Converts this value to the given unit.
+    ///
+    /// Supports conversion between data size units (KB, KiB, MB, MiB, etc.)
+    /// and currency conversions (USD → EUR, etc.).
+    pub fn convert_to_unit(
+        &self,
+        target_unit: &Unit,
+        currency_db: &CurrencyDatabase,
+    ) -> Result<Self, CalculatorError> {
+        self.convert_to_unit_at_date(target_unit, currency_db, None)
+            .map(|(converted, _)| converted)
+    }
+
+    /// Converts this value to the given unit, using a historical exchange
+    /// rate if `date` is provided, and returns the receipt for the rates used.
+    pub fn convert_to_unit_at_date(
+        &self,
+        target_unit: &Unit,
+        currency_db: &CurrencyDatabase,
+        date: Option<&DateTime>,
+    ) -> Result<(Self, ConversionReceipt), CalculatorError> {
+        if let ValueKind::List(items) = &self.kind {
+            let mut receipt = ConversionReceipt::default();
+            let mut converted = Vec::with_capacity(items.len());
+            for item in items {
+                let (value, item_receipt) =
+                    item.convert_to_unit_at_date(target_unit, currency_db, date)?;
+                converted.push(value);
+                receipt.extend(item_receipt);
+            }
+            return Ok((Self::list(converted), receipt));
+        }
+        if let (Unit::Currency(from), Unit::Currency(to)) = (&self.unit, target_unit) {
+            let amount = self.to_rational().ok_or_else(|| {
+                CalculatorError::InvalidOperation(
+                    "currency conversion requires a numeric value".into(),
+                )
+            })?;
+            let conversion = currency_db.convert_at(amount.to_f64(), from, to, date)?;
+            let amount = amount * conversion.receipt.rate();
+            return Ok((
+                Value::rational_with_unit(amount, Unit::currency(to)),
+                conversion.receipt,
+            ));
+        }
+        self.convert_without_rates(target_unit)
+            .map(|converted| (converted, ConversionReceipt::default()))
+    }
+
+    fn convert_without_rates(&self, target_unit: &Unit) -> Result<Self, CalculatorError> {
+        if let ValueKind::Duration { seconds, calendar } = &self.kind {
+            return match calendar {
+                Some((months, rest)) => {
+                    convert_calendar_span(*months, *rest, *seconds, target_unit)
+                }
+                None => convert_raw_duration(*seconds, target_unit),
+            };
+        }
+        if let ValueKind::CalendarSpan {
+            months,
+            seconds,
+            total_seconds,
+        } = &self.kind
+        {
+            return convert_calendar_span(*months, *seconds, *total_seconds, target_unit);
+        }
+
+        if let Some(converted) = self.convert_measure(target_unit) {
+            return Ok(converted);
+        }
+        match (&self.unit, target_unit) {
+            (_, Unit::None) => {
+                let value = self.to_rational().ok_or_else(|| {
+                    CalculatorError::InvalidOperation(
+                        "number conversion requires a numeric value".into(),
+                    )
+                })?;
+                Ok(Value::rational(value))
+            }
+            // Data size to data size and mass to mass conversion, exact on
+            // the amount
+            (Unit::DataSize(_), Unit::DataSize(_)) | (Unit::Mass(_), Unit::Mass(_)) => {
+                let amount = self.to_rational().ok_or_else(|| {
+                    CalculatorError::InvalidOperation(
+                        "unit conversion requires a numeric value".into(),
+                    )
+                })?;
+                let factor = self.unit.conversion_factor(target_unit).unwrap_or(1.0);
+                Ok(Value::rational_with_unit(
+                    amount * Rational::from_f64(factor),
+                    target_unit.clone(),
+                ))
+            }
+            // Duration to duration conversion (e.g., "300000 ms in seconds")
+            (Unit::Duration(from), Unit::Duration(to)) => convert_duration_unit(self, *from, *to),
+            // Dimensionless value: just apply the target unit (e.g. "5 as MB")
+            (Unit::None, Unit::DataSize(_)) => {
+                let value_f64 = self.as_decimal().ok_or_else(|| {
+                    CalculatorError::InvalidOperation(
+                        "unit conversion requires a numeric value".into(),
+                    )
+                })?;
+                Ok(Value::number_with_unit(value_f64, target_unit.clone()))
+            }
+            // Dimensionless value: just apply the mass target unit (e.g. "5 as kg")
+            (
+                Unit::None,
+                Unit::Mass(_)
+                | Unit::Length(_)
+                | Unit::Volume(_)
+                | Unit::Angle(_)
+                | Unit::Compound { .. }
+                | Unit::Power { .. },
+            ) => {
+                let value_f64 = self.as_decimal().ok_or_else(|| {
+                    CalculatorError::InvalidOperation(
+                        "unit conversion requires a numeric value".into(),
+                    )
+                })?;
+                Ok(Value::number_with_unit(value_f64, target_unit.clone()))
+            }
+            (Unit::None, Unit::Duration(unit)) => apply_duration_unit(self, *unit),
+            // Color models (e.g. "#ff8800 in rgb"); "in hex" is read as a radix
+            (Unit::Color(_), Unit::Color(_) | Unit::Radix(Radix::Hexadecimal)) => {
+                let model = match target_unit {
+                    Unit::Color(model) => *model,
+                    _ => ColorModel::Hex,
+                };
+                self.as_color()
+                    .map(|(color, _)| Value::color(color, model))
+                    .ok_or_else(|| {
+                        CalculatorError::InvalidOperation(format!(
+                            "{} is not a color",
+                            self.to_display_string()
+                        ))
+                    })
+            }
+            // Integer base conversion (e.g. "255 in hex")
+            (Unit::None | Unit::Radix(_), Unit::Radix(_)) => match self.to_rational() {
+                Some(value) if value.is_integer() => {
+                    Ok(Value::rational_with_unit(value, target_unit.clone()))
+                }
+                _ => Err(CalculatorError::InvalidOperation(format!(
+                    "Cannot convert {} to {}; only integers can be shown in another base",
+                    self.to_display_string(),
+                    target_unit.display_name()
+                ))),
+            },
+            // DateTime timezone conversion (e.g., "6 PM GMT as MSK")
+            (_, Unit::Timezone(tz_abbrev)) => {
+                if let ValueKind::DateTime(dt) = &self.kind {
+                    // A city keeps the offset in effect at that instant: "now in Tokyo"
+                    if let Some(city) = City::find(tz_abbrev) {
+                        let instant = dt.inner_utc();
+                        let converted = dt.with_timezone_offset(
+                            city.offset_at(instant),
+                            city.abbreviation_at(instant),
+                        );
+                        return Ok(Value::datetime(converted));
+                    }
+                    let target_offset =
+                        DateTime::parse_tz_abbreviation(tz_abbrev).ok_or_else(|| {
+                            CalculatorError::parse(format!("Unknown timezone: {tz_abbrev}"))
+                        })?;
+                    let converted = dt.with_timezone_offset(target_offset, tz_abbrev);
+                    Ok(Value::datetime(converted))
+                } else {
+                    Err(CalculatorError::InvalidOperation(format!(
+                        "Cannot convert {} to timezone {}; only DateTime values can be converted to a timezone",
+                        self.to_display_string(),
+                        tz_abbrev
+                    )))
+                }
+            }
+            (from_unit, to_unit) => Err(CalculatorError::InvalidOperation(format!(
+                "Cannot convert {} to {}",
+                from_unit.display_name(),
+                to_unit.display_name()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Display formatting for values.
+
+use super::{format_calendar_span, format_duration, format_symbolic, format_time_of_day, Value};
+use crate::types::{AngleUnit, Decimal, FormatOptions, Rational, Unit, ValueKind};
+
+impl Value {
+    /// Converts the value to a display string using the given number
+    /// formatting preferences.
+    #[must_use]
+    pub fn to_display_string_with(&self, options: &FormatOptions) -> String {
+        if let Some((color, model)) = self.as_color() {
+            return color.format(model);
+        }
+        if let (Unit::Angle(AngleUnit::Dms), Some(degrees)) = (&self.unit, self.to_rational()) {
+            return format_dms(&degrees, options);
+        }
+        match &self.kind {
+            ValueKind::Number(n) => {
+                let n = Rational::from_decimal(*n);
+                let n_str = options
+                    .scientific(&n)
+                    .unwrap_or_else(|| options.format_inexact(&n));
+                self.with_unit_suffix(n_str)
+            }
+            ValueKind::Rational(r) => match self.unit {
+                Unit::Radix(radix) if r.is_integer() => radix.format(r.numer_bigint()),
+                _ => {
+                    let r_str = options
+                        .scientific(r)
+                        .unwrap_or_else(|| options.format_rational(r));
+                    self.with_unit_suffix(r_str)
+                }
+            },
+            // A precision asks for the digits of the value
+            ValueKind::Symbolic { .. }
+                if options.max_fraction_digits.is_some()
+                    || options.significant_figures.is_some() =>
+            {
+                let n = self.as_number().unwrap_or_default();
+                options.format_number(&n.normalize().to_string())
+            }
+            // Past the thresholds of scientific notation, the digits of the
+            // coefficient would run on: "3.141592653589793e20", not "100…0π"
+            ValueKind::Symbolic {
+                coefficient,
+                constant,
+                power,
+            } => options
+                .scientific(
+                    &(coefficient.clone() * Rational::from_f64(constant.value().powi(*power))),
+                )
+                .unwrap_or_else(|| format_symbolic(coefficient, *constant, *power)),
+            ValueKind::DateTime(dt) => dt.format_as(&options.datetime_format),
+            ValueKind::TimeOfDay { seconds } => format_time_of_day(*seconds),
+            ValueKind::Duration { seconds, .. } => format_duration(*seconds),
+            ValueKind::CalendarSpan {
+                months, seconds, ..
+            } => format_calendar_span(*months, *seconds),
+            ValueKind::Boolean(b) => b.to_string(),
+            ValueKind::Text(text) => text.clone(),
+            ValueKind::Comparison {
+                left,
+                relation,
+                right,
+            } => format!("{left} {relation} {right}"),
+            ValueKind::EquationSolution { variable, value } => {
+                format!("{variable} = {}", options.format_rational(value))
+            }
+            ValueKind::EquationSolutions { variable, values } => values
+                .iter()
+                .map(|value| format!("{variable} = {}", options.format_rational(value)))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            ValueKind::SymbolicEquationSolution {
+                variable,
+                expression,
+            } => {
+                format!("{variable} = {expression}")
+            }
+            ValueKind::List(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| item.to_display_string_with(options))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("[{items}]")
+            }
+            ValueKind::Matrix(rows) => {
+                let rows = rows
+                    .iter()
+                    .map(|row| {
+                        let row = row
+                            .iter()
+                            .map(|x| options.format_rational(x))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("[{row}]")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("[{rows}]")
+            }
+        }
+    }
+
+    fn with_unit_suffix(&self, number: String) -> String {
+        match &self.unit {
+            Unit::None => number,
+            // "1 week" rather than "1 weeks"
+            Unit::Duration(unit) if number.trim_start_matches('-') == "1" => {
+                format!("{number} {}", unit.singular_name())
+            }
+            // "30°" rather than "30 °", and "30'" and "15\"" alike
+            Unit::Angle(
+                unit @ (AngleUnit::Degree | AngleUnit::Arcminute | AngleUnit::Arcsecond),
+            ) => {
+                format!("{number}{unit}")
+            }
+            unit => format!("{number} {unit}"),
+        }
+    }
+}
+
+/// Writes an angle in degrees as whole degrees, minutes and seconds, the
+/// seconds to hundredths: `45° 30' 15"`.
+fn format_dms(degrees: &Rational, options: &FormatOptions) -> String {
+    let hundredths = (degrees.abs() * Rational::from_integer(360_000))
+        .to_decimal()
+        .and_then(|hundredths| hundredths.round_half_away_from_zero(0).to_i64())
+        .unwrap_or(0);
+    let sign = if degrees.is_negative() && hundredths != 0 {
+        "-"
+    } else {
+        ""
+    };
+    let (whole_degrees, rest) = (hundredths / 360_000, hundredths % 360_000);
+    let (minutes, seconds) = (rest / 6000, rest % 6000);
+    let seconds = (Decimal::new(seconds) / Decimal::new(100)).normalize();
+    format!(
+        "{sign}{whole_degrees}° {minutes}' {}\"",
+        options.format_number(&seconds.to_string())
+    )
+}
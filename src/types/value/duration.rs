@@ -3,7 +3,7 @@ use crate::error::CalculatorError;
 use crate::types::{DateTime, DurationUnit, Rational, Unit, ValueKind};
 
 /// Interprets an unadorned four-digit integer as January 1 of that year.
-pub(super) fn bare_year_datetime(value: &Value) -> Option<DateTime> {
+pub fn bare_year_datetime(value: &Value) -> Option<DateTime> {
     if value.unit != Unit::None {
         return None;
     }
@@ -90,6 +90,78 @@ pub(super) fn divide_duration_units(
     Ok(Some(Value::rational(left_seconds / right_seconds)))
 }
 
+/// Returns a raw duration, or an amount in a fixed duration unit (weeks and
+/// smaller), as exact seconds.
+fn fixed_duration_seconds(value: &Value) -> Option<Rational> {
+    match (&value.kind, &value.unit) {
+        (ValueKind::Duration { seconds, .. }, Unit::None) => {
+            Some(Rational::from_integer(i128::from(*seconds)))
+        }
+        (_, Unit::Duration(DurationUnit::Months | DurationUnit::Years)) => None,
+        (_, Unit::Duration(unit)) => Some(value.to_rational()? * duration_unit_seconds(*unit)),
+        _ => None,
+    }
+}
+
+/// Builds a raw duration from exact seconds, or an amount in seconds when
+/// they are not whole.
+fn duration_from_seconds(seconds: Rational) -> Value {
+    if seconds.is_integer() {
+        if let Ok(whole) = i64::try_from(seconds.numer()) {
+            return Value::duration(whole);
+        }
+    }
+    Value::rational_with_unit(seconds, Unit::Duration(DurationUnit::Seconds))
+}
+
+/// Adds (or subtracts) durations in different fixed units as a raw
+/// duration: `2 hours + 15 minutes` is `2 hours, 15 minutes`.
+pub(super) fn combine_duration_units(left: &Value, right: &Value, subtract: bool) -> Option<Value> {
+    if left.unit == right.unit {
+        return None;
+    }
+    let left_seconds = fixed_duration_seconds(left)?;
+    let right_seconds = fixed_duration_seconds(right)?;
+    Some(duration_from_seconds(if subtract {
+        left_seconds - right_seconds
+    } else {
+        left_seconds + right_seconds
+    }))
+}
+
+/// Scales a duration by a unitless factor. Raw durations stay raw; an
+/// amount in a fixed unit that no longer comes out whole becomes a raw
+/// duration, so a quarter of `1 day` is `6 hours`.
+pub(super) fn scale_duration(duration: &Value, factor: &Value) -> Option<Value> {
+    if factor.unit != Unit::None {
+        return None;
+    }
+    let factor = factor.to_rational()?;
+    let seconds = fixed_duration_seconds(duration)? * factor.clone();
+    if matches!(duration.kind, ValueKind::Duration { .. }) {
+        return Some(duration_from_seconds(seconds));
+    }
+
+    let amount = duration.to_rational()? * factor;
+    (!amount.is_integer() && seconds.is_integer()).then(|| duration_from_seconds(seconds))
+}
+
+/// Divides two durations, at least one of them raw, as a unitless ratio.
+pub(super) fn duration_ratio(
+    left: &Value,
+    right: &Value,
+) -> Result<Option<Value>, CalculatorError> {
+    let (Some(left_seconds), Some(right_seconds)) =
+        (fixed_duration_seconds(left), fixed_duration_seconds(right))
+    else {
+        return Ok(None);
+    };
+    if right_seconds.is_zero() {
+        return Err(CalculatorError::DivisionByZero);
+    }
+    Ok(Some(Value::rational(left_seconds / right_seconds)))
+}
+
 /// Converts a raw duration in seconds into a numeric amount in `unit`.
 pub(super) fn duration_seconds_to_unit(seconds: i64, unit: DurationUnit) -> Rational {
     Rational::from_integer(i128::from(seconds)) / duration_unit_seconds(unit)
@@ -148,6 +220,22 @@ pub(super) fn convert_raw_duration(
     }
 }
 
+/// Converts an amount in one duration unit to another, exactly
+/// (`10 days in weeks` is `10/7` weeks).
+pub(super) fn convert_duration_unit(
+    value: &Value,
+    from: DurationUnit,
+    to: DurationUnit,
+) -> Result<Value, CalculatorError> {
+    let amount = value.to_rational().ok_or_else(|| {
+        CalculatorError::InvalidOperation("duration conversion requires a numeric value".into())
+    })?;
+    Ok(Value::rational_with_unit(
+        amount * duration_unit_seconds(from) / duration_unit_seconds(to),
+        Unit::Duration(to),
+    ))
+}
+
 pub(super) fn apply_duration_unit(
     value: &Value,
     unit: DurationUnit,
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use super::{SymbolicConstant, Value};
 use crate::types::{DateTime, Decimal, Rational};
 
 /// Different kinds of values the calculator can work with.
@@ -9,15 +10,47 @@ pub enum ValueKind {
     Number(Decimal),
     /// A rational number for exact fractional arithmetic.
     Rational(Rational),
+    /// A rational multiple of a power of π or e, such as `2π`, kept exact.
+    Symbolic {
+        /// The rational factor in front of the constant.
+        coefficient: Rational,
+        /// The constant.
+        constant: SymbolicConstant,
+        /// The power of the constant, never 0.
+        power: i32,
+    },
     /// A date and/or time.
     DateTime(DateTime),
+    /// A clock time without a date, such as the result of `14:30 + 2:45`.
+    TimeOfDay {
+        /// Seconds since midnight, past 86 400 once the time runs into the
+        /// next day and below 0 once it runs back into the day before.
+        seconds: i64,
+    },
     /// A duration (difference between two datetimes).
     Duration {
         /// Duration in seconds.
         seconds: i64,
+        /// The whole calendar months in the span and the seconds left over,
+        /// when the duration is the difference of two dates, so that it
+        /// converts to months and years by the calendar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        calendar: Option<(i64, i64)>,
+    },
+    /// A calendar-aware span between two dates (e.g., `age(15 June 1990)`),
+    /// shown as years, months and days.
+    CalendarSpan {
+        /// Whole calendar months in the span.
+        months: i64,
+        /// Seconds left over after the whole months.
+        seconds: i64,
+        /// Exact length of the span in seconds.
+        total_seconds: i64,
     },
     /// A boolean value.
     Boolean(bool),
+    /// A name, such as the day of the week of a date.
+    Text(String),
     /// A generic comparison result such as `1 < 2`.
     Comparison {
         /// Display string for the left value.
@@ -48,4 +81,8 @@ pub enum ValueKind {
         /// The symbolic expression assigned to the variable.
         expression: String,
     },
+    /// A list of values (e.g., `[1, 2, 3]`).
+    List(Vec<Value>),
+    /// A matrix with exact rational entries (e.g., `[[1, 2], [3, 4]]`).
+    Matrix(Vec<Vec<Rational>>),
 }
@@ -0,0 +1,85 @@
+//! List values and element-wise operations.
+
+use super::Value;
+use crate::error::CalculatorError;
+use crate::types::{Unit, ValueKind};
+
+impl Value {
+    /// Creates a list value.
+    #[must_use]
+    pub fn list(items: Vec<Value>) -> Self {
+        Self {
+            kind: ValueKind::List(items),
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Returns the items if this is a list.
+    #[must_use]
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match &self.kind {
+            ValueKind::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Applies a binary operation element-wise when either operand is a list.
+    ///
+    /// A list combined with a scalar applies the operation to every item
+    /// (`[1, 2, 3] * 2`); two lists are combined pairwise and must have the
+    /// same length. Returns `None` when neither operand is a list.
+    pub fn broadcast(
+        left: &Self,
+        right: &Self,
+        mut op: impl FnMut(&Self, &Self) -> Result<Self, CalculatorError>,
+    ) -> Option<Result<Self, CalculatorError>> {
+        let items: Result<Vec<_>, _> = match (left.as_list(), right.as_list()) {
+            (None, None) => return None,
+            (Some(a), Some(b)) if a.len() != b.len() => {
+                return Some(Err(CalculatorError::InvalidOperation(format!(
+                    "Cannot combine lists of different lengths ({} and {})",
+                    a.len(),
+                    b.len()
+                ))))
+            }
+            (Some(a), Some(b)) => a.iter().zip(b).map(|(x, y)| op(x, y)).collect(),
+            (Some(a), None) => a.iter().map(|x| op(x, right)).collect(),
+            (None, Some(b)) => b.iter().map(|y| op(left, y)).collect(),
+        };
+        Some(items.map(Self::list))
+    }
+
+    /// Returns the list item at a zero-based `index`; negative indices count
+    /// from the end (`[1, 2, 3][-1]` is `3`).
+    pub fn index(&self, index: &Self) -> Result<Self, CalculatorError> {
+        if let Some(rows) = self.matrix_rows() {
+            return rows.index(index);
+        }
+        let items = self.as_list().ok_or_else(|| {
+            CalculatorError::InvalidOperation(format!("Cannot index a {}", self.type_name()))
+        })?;
+        let position = index
+            .to_rational()
+            .filter(|r| r.is_integer() && index.unit == Unit::None)
+            .and_then(|r| i64::try_from(r.numer()).ok())
+            .ok_or_else(|| {
+                CalculatorError::InvalidOperation("List index must be a whole number".to_string())
+            })?;
+        let len = items.len() as i64;
+        let resolved = if position < 0 {
+            len + position
+        } else {
+            position
+        };
+        usize::try_from(resolved)
+            .ok()
+            .and_then(|i| items.get(i))
+            .cloned()
+            .ok_or_else(|| {
+                CalculatorError::InvalidOperation(format!(
+                    "List index {position} is out of range for a list of {len} items"
+                ))
+            })
+    }
+}
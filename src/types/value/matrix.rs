@@ -0,0 +1,340 @@
+//! Matrices with exact rational entries: `[[1, 2], [3, 4]]`.
+
+use super::Value;
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Rational, Unit, ValueKind};
+
+impl Value {
+    /// Creates a matrix value from its rows.
+    #[must_use]
+    pub fn matrix(rows: Vec<Vec<Rational>>) -> Self {
+        Self {
+            kind: ValueKind::Matrix(rows),
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Returns the rows if this is a matrix.
+    #[must_use]
+    pub fn as_matrix(&self) -> Option<&[Vec<Rational>]> {
+        match &self.kind {
+            ValueKind::Matrix(rows) => Some(rows),
+            _ => None,
+        }
+    }
+
+    /// Builds a matrix from rows given as lists of plain numbers, or returns
+    /// `None` if the rows are empty, differ in length or hold anything else.
+    #[must_use]
+    pub fn matrix_from_rows(rows: &[Self]) -> Option<Self> {
+        let rows: Option<Vec<Vec<Rational>>> = rows
+            .iter()
+            .map(|row| {
+                row.as_list()?
+                    .iter()
+                    .map(|item| item.to_rational().filter(|_| item.unit == Unit::None))
+                    .collect()
+            })
+            .collect();
+        let rows = rows?;
+        let columns = rows.first()?.len();
+        (columns > 0 && rows.iter().all(|row| row.len() == columns)).then(|| Self::matrix(rows))
+    }
+
+    /// Applies a binary operation when either operand is a matrix.
+    ///
+    /// Matrices of the same shape add and subtract entry by entry, multiply
+    /// as matrices, and scale by (or divide by) a plain number. Returns
+    /// `None` when neither operand is a matrix.
+    pub fn matrix_op(
+        left: &Self,
+        op: BinaryOp,
+        right: &Self,
+    ) -> Option<Result<Self, CalculatorError>> {
+        let result = match (left.as_matrix(), op, right.as_matrix()) {
+            (None, _, None) => return None,
+            (Some(a), BinaryOp::Add | BinaryOp::Subtract, Some(b)) => {
+                entrywise(a, b, |x, y| match op {
+                    BinaryOp::Add => x + y,
+                    _ => x - y,
+                })
+            }
+            (Some(a), BinaryOp::Multiply, Some(b)) => multiply(a, b),
+            (Some(a), BinaryOp::Multiply, None) => scalar(right).map(|k| scale(a, &k)),
+            (None, BinaryOp::Multiply, Some(b)) => scalar(left).map(|k| scale(b, &k)),
+            (Some(a), BinaryOp::Divide, None) => scalar(right).and_then(|k| {
+                if k.is_zero() {
+                    return Err(CalculatorError::DivisionByZero);
+                }
+                Ok(scale(a, &(Rational::one() / k)))
+            }),
+            _ => Err(CalculatorError::InvalidOperation(format!(
+                "Cannot apply {op} to a {} and a {}",
+                left.type_name(),
+                right.type_name()
+            ))),
+        };
+        Some(result.map(Self::matrix))
+    }
+
+    /// Raises a square matrix to a whole power; `M^-1` is the inverse.
+    /// Returns `None` when the base is not a matrix.
+    pub fn matrix_power(&self, exponent: &Self) -> Option<Result<Self, CalculatorError>> {
+        let rows = self.as_matrix()?;
+        let n = exponent
+            .to_rational()
+            .filter(|n| n.is_integer() && exponent.unit == Unit::None)
+            .and_then(|n| i32::try_from(n.numer()).ok())
+            .filter(|n| n.unsigned_abs() <= 1_000);
+        let Some(n) = n else {
+            return Some(Err(CalculatorError::InvalidOperation(
+                "A matrix can only be raised to a whole power".to_string(),
+            )));
+        };
+        let result = square_size(rows).and_then(|size| {
+            let base = if n < 0 { inverse(rows)? } else { rows.to_vec() };
+            let mut result = identity(size);
+            for _ in 0..n.unsigned_abs() {
+                result = multiply(&result, &base)?;
+            }
+            Ok(result)
+        });
+        Some(result.map(Self::matrix))
+    }
+
+    /// Returns the transpose of a matrix.
+    pub fn transpose(&self) -> Result<Self, CalculatorError> {
+        let rows = self.expect_matrix("transpose")?;
+        let columns = (0..rows[0].len())
+            .map(|j| rows.iter().map(|row| row[j].clone()).collect())
+            .collect();
+        Ok(Self::matrix(columns))
+    }
+
+    /// Returns the determinant of a square matrix.
+    pub fn determinant(&self) -> Result<Self, CalculatorError> {
+        let rows = self.expect_matrix("det")?;
+        square_size(rows)?;
+        Ok(Self::rational(determinant(rows.to_vec())))
+    }
+
+    /// Returns the inverse of a square, non-singular matrix.
+    pub fn inverse(&self) -> Result<Self, CalculatorError> {
+        let rows = self.expect_matrix("inverse")?;
+        inverse(rows).map(Self::matrix)
+    }
+
+    /// Renders a matrix as a LaTeX `bmatrix`, or `None` for other values.
+    #[must_use]
+    pub fn matrix_to_latex(&self) -> Option<String> {
+        let rows = self.as_matrix()?;
+        let body = rows
+            .iter()
+            .map(|row| row.iter().map(entry_latex).collect::<Vec<_>>().join(" & "))
+            .collect::<Vec<_>>()
+            .join(" \\\\ ");
+        Some(format!("\\begin{{bmatrix}} {body} \\end{{bmatrix}}"))
+    }
+
+    /// Returns the rows of a matrix as a list of lists, so that matrices can
+    /// be indexed like nested lists (`[[1, 2], [3, 4]][1][0]`).
+    pub(super) fn matrix_rows(&self) -> Option<Self> {
+        let rows = self.as_matrix()?;
+        Some(Self::list(
+            rows.iter()
+                .map(|row| Self::list(row.iter().cloned().map(Self::rational).collect()))
+                .collect(),
+        ))
+    }
+
+    fn expect_matrix(&self, function: &str) -> Result<&[Vec<Rational>], CalculatorError> {
+        self.as_matrix().ok_or_else(|| {
+            CalculatorError::invalid_args(
+                function,
+                format!("expected a matrix, got a {}", self.type_name()),
+            )
+        })
+    }
+}
+
+type Rows = Vec<Vec<Rational>>;
+
+/// Renders a matrix entry, with fractions as `\frac{1}{2}`.
+fn entry_latex(x: &Rational) -> String {
+    if x.is_integer() {
+        return x.numer_bigint().to_string();
+    }
+    let sign = if x.is_negative() { "-" } else { "" };
+    let numerator = x.abs().numer_bigint().to_string();
+    format!("{sign}\\frac{{{numerator}}}{{{}}}", x.denom_bigint())
+}
+
+fn scalar(value: &Value) -> Result<Rational, CalculatorError> {
+    value
+        .to_rational()
+        .filter(|_| value.unit == Unit::None)
+        .ok_or_else(|| {
+            CalculatorError::InvalidOperation(format!(
+                "Cannot combine a matrix with a {}",
+                value.type_name()
+            ))
+        })
+}
+
+fn shape(rows: &[Vec<Rational>]) -> (usize, usize) {
+    (rows.len(), rows.first().map_or(0, Vec::len))
+}
+
+fn square_size(rows: &[Vec<Rational>]) -> Result<usize, CalculatorError> {
+    let (n, m) = shape(rows);
+    if n == m {
+        Ok(n)
+    } else {
+        Err(CalculatorError::InvalidOperation(format!(
+            "Expected a square matrix, got a {n}×{m} matrix"
+        )))
+    }
+}
+
+fn identity(size: usize) -> Rows {
+    (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| {
+                    if i == j {
+                        Rational::one()
+                    } else {
+                        Rational::zero()
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn entrywise(
+    a: &[Vec<Rational>],
+    b: &[Vec<Rational>],
+    op: impl Fn(Rational, Rational) -> Rational,
+) -> Result<Rows, CalculatorError> {
+    if shape(a) != shape(b) {
+        let ((n1, m1), (n2, m2)) = (shape(a), shape(b));
+        return Err(CalculatorError::InvalidOperation(format!(
+            "Cannot combine a {n1}×{m1} matrix with a {n2}×{m2} matrix"
+        )));
+    }
+    Ok(a.iter()
+        .zip(b)
+        .map(|(x, y)| {
+            x.iter()
+                .zip(y)
+                .map(|(x, y)| op(x.clone(), y.clone()))
+                .collect()
+        })
+        .collect())
+}
+
+fn multiply(a: &[Vec<Rational>], b: &[Vec<Rational>]) -> Result<Rows, CalculatorError> {
+    let ((rows, inner), (b_rows, columns)) = (shape(a), shape(b));
+    if inner != b_rows {
+        return Err(CalculatorError::InvalidOperation(format!(
+            "Cannot multiply a {rows}×{inner} matrix by a {b_rows}×{columns} matrix"
+        )));
+    }
+    Ok(a.iter()
+        .map(|row| {
+            (0..columns)
+                .map(|j| {
+                    row.iter().zip(b).fold(Rational::zero(), |sum, (x, b_row)| {
+                        sum + x.clone() * b_row[j].clone()
+                    })
+                })
+                .collect()
+        })
+        .collect())
+}
+
+fn scale(rows: &[Vec<Rational>], factor: &Rational) -> Rows {
+    rows.iter()
+        .map(|row| row.iter().map(|x| x.clone() * factor.clone()).collect())
+        .collect()
+}
+
+/// Computes the determinant by Gaussian elimination.
+fn determinant(mut rows: Rows) -> Rational {
+    let n = rows.len();
+    let mut det = Rational::one();
+    for col in 0..n {
+        let Some(pivot) = (col..n).find(|&r| !rows[r][col].is_zero()) else {
+            return Rational::zero();
+        };
+        if pivot != col {
+            rows.swap(pivot, col);
+            det = -det;
+        }
+        let pivot_value = rows[col][col].clone();
+        det = det * pivot_value.clone();
+        let pivot_row = rows[col].clone();
+        for row in &mut rows[col + 1..] {
+            let factor = row[col].clone() / pivot_value.clone();
+            for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *entry = entry.clone() - factor.clone() * pivot_entry.clone();
+            }
+        }
+    }
+    det
+}
+
+/// Computes the inverse by Gauss-Jordan elimination.
+fn inverse(rows: &[Vec<Rational>]) -> Result<Rows, CalculatorError> {
+    let n = square_size(rows)?;
+    let mut left = rows.to_vec();
+    let mut right = identity(n);
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|&r| !left[r][col].is_zero())
+            .ok_or_else(|| CalculatorError::domain("the matrix is singular and has no inverse"))?;
+        left.swap(pivot, col);
+        right.swap(pivot, col);
+        let pivot_value = left[col][col].clone();
+        for c in 0..n {
+            left[col][c] = left[col][c].clone() / pivot_value.clone();
+            right[col][c] = right[col][c].clone() / pivot_value.clone();
+        }
+        for r in (0..n).filter(|&r| r != col) {
+            let factor = left[r][col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for c in 0..n {
+                let (l, k) = (left[col][c].clone(), right[col][c].clone());
+                left[r][c] = left[r][c].clone() - factor.clone() * l;
+                right[r][c] = right[r][c].clone() - factor.clone() * k;
+            }
+        }
+    }
+    Ok(right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[i64]]) -> Value {
+        Value::matrix(
+            rows.iter()
+                .map(|row| row.iter().map(|&x| Rational::from(x)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_determinant_and_inverse() {
+        let m = matrix(&[&[1, 2], &[3, 4]]);
+        assert_eq!(m.determinant().unwrap(), Value::from_integer(-2));
+        let product = Value::matrix_op(&m, BinaryOp::Multiply, &m.inverse().unwrap()).unwrap();
+        assert_eq!(product.unwrap(), matrix(&[&[1, 0], &[0, 1]]));
+        assert!(matrix(&[&[1, 2], &[2, 4]]).inverse().is_err());
+    }
+}
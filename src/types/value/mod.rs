@@ -1,18 +1,35 @@
 //! Value type representing typed values with units.
 
+mod calendar;
+mod color;
+mod compound;
+mod convert;
+mod display;
 mod duration;
 mod kind;
+mod list;
+mod matrix;
+mod symbolic;
+mod time_of_day;
+use calendar::{convert_calendar_span, format_calendar_span};
+pub use duration::bare_year_datetime;
 use duration::{
-    add_calendar_months_or_duration, apply_duration_unit, bare_year_datetime, convert_raw_duration,
-    divide_duration_units, divide_raw_duration, format_duration,
+    add_calendar_months_or_duration, apply_duration_unit, combine_duration_units,
+    convert_duration_unit, convert_raw_duration, divide_duration_units, divide_raw_duration,
+    duration_ratio, format_duration, scale_duration,
 };
 pub use kind::ValueKind;
+pub use symbolic::SymbolicConstant;
+use symbolic::{format_symbolic, symbolic_decimal};
+use time_of_day::format_time_of_day;
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::error::CalculatorError;
-use crate::types::{CurrencyDatabase, DateTime, Decimal, Rational, Unit};
+use crate::types::{
+    ConversionReceipt, CurrencyDatabase, DateTime, Decimal, FormatOptions, Rational, Unit,
+};
 
 /// A typed value with an optional unit.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +38,11 @@ pub struct Value {
     pub kind: ValueKind,
     /// The unit of measurement.
     pub unit: Unit,
+    /// The date whose exchange rates convert this amount, set by an `at`
+    /// clause such as `100 USD at 1 Jan 2025`. Wins over the date of any
+    /// enclosing `at` clause.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_date: Option<DateTime>,
 }
 
 impl Value {
@@ -30,6 +52,7 @@ impl Value {
         Self {
             kind: ValueKind::Number(n),
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -39,6 +62,7 @@ impl Value {
         Self {
             kind: ValueKind::Number(n),
             unit,
+            rate_date: None,
         }
     }
 
@@ -48,6 +72,7 @@ impl Value {
         Self {
             kind: ValueKind::Rational(r),
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -57,6 +82,25 @@ impl Value {
         Self {
             kind: ValueKind::Rational(r),
             unit,
+            rate_date: None,
+        }
+    }
+
+    /// Marks a result computed from an inexact number, such as `sqrt(2) + 1`,
+    /// as inexact itself, so it is not offered as the fraction its digits
+    /// spell. Numbers too large for a Decimal stay rational.
+    #[must_use]
+    pub fn inexact(self) -> Self {
+        let decimal = match &self.kind {
+            ValueKind::Rational(r) => r.to_decimal(),
+            _ => None,
+        };
+        match decimal {
+            Some(d) => Self {
+                kind: ValueKind::Number(d),
+                ..self
+            },
+            None => self,
         }
     }
 
@@ -66,6 +110,7 @@ impl Value {
         Self {
             kind: ValueKind::Rational(Rational::from_integer(i128::from(n))),
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -75,6 +120,7 @@ impl Value {
         Self {
             kind: ValueKind::Rational(Rational::from_integer(i128::from(n))),
             unit,
+            rate_date: None,
         }
     }
 
@@ -84,6 +130,7 @@ impl Value {
         Self {
             kind: ValueKind::Number(amount),
             unit: Unit::currency(currency_code),
+            rate_date: None,
         }
     }
 
@@ -93,6 +140,7 @@ impl Value {
         Self {
             kind: ValueKind::DateTime(dt),
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -100,8 +148,12 @@ impl Value {
     #[must_use]
     pub fn duration(seconds: i64) -> Self {
         Self {
-            kind: ValueKind::Duration { seconds },
+            kind: ValueKind::Duration {
+                seconds,
+                calendar: None,
+            },
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -111,6 +163,17 @@ impl Value {
         Self {
             kind: ValueKind::Boolean(b),
             unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Creates a text value, such as the name of a weekday.
+    #[must_use]
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            kind: ValueKind::Text(text.into()),
+            unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -128,6 +191,7 @@ impl Value {
                 right: right.into(),
             },
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -140,6 +204,7 @@ impl Value {
                 value,
             },
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -152,6 +217,7 @@ impl Value {
                 values,
             },
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -167,6 +233,7 @@ impl Value {
                 expression: expression.into(),
             },
             unit: Unit::None,
+            rate_date: None,
         }
     }
 
@@ -174,44 +241,83 @@ impl Value {
     pub fn add(
         &self,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
+        currency_db: &CurrencyDatabase,
     ) -> Result<Self, CalculatorError> {
         self.add_at_date(other, currency_db, None)
+            .map(|(sum, _)| sum)
     }
 
-    /// Adds two values with optional date context for historical currency conversion.
+    /// Adds two values with optional date context for historical currency
+    /// conversion, returning the receipt for any exchange rate used.
     pub fn add_at_date(
         &self,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
+        currency_db: &CurrencyDatabase,
         date: Option<&DateTime>,
-    ) -> Result<Self, CalculatorError> {
+    ) -> Result<(Self, ConversionReceipt), CalculatorError> {
+        if let Some((a, b, receipt)) = self.currency_operands(other, currency_db, date)? {
+            return Ok((Value::rational_with_unit(a + b, self.unit.clone()), receipt));
+        }
+        self.add_values(other)
+            .map(|sum| (sum, ConversionReceipt::default()))
+    }
+
+    /// Converts `other` into this value's currency when both are amounts in
+    /// different currencies, returning both amounts and the receipt for the
+    /// rate used.
+    fn currency_operands(
+        &self,
+        other: &Self,
+        currency_db: &CurrencyDatabase,
+        date: Option<&DateTime>,
+    ) -> Result<Option<(Rational, Rational, ConversionReceipt)>, CalculatorError> {
+        let (Unit::Currency(c1), Unit::Currency(c2)) = (&self.unit, &other.unit) else {
+            return Ok(None);
+        };
+        let (Some(a), Some(b)) = (self.to_rational(), other.to_rational()) else {
+            return Ok(None);
+        };
+        if c1 == c2 {
+            return Ok(None);
+        }
+        let conversion = currency_db.convert_at(b.to_f64(), c2, c1, date)?;
+        let b = b * conversion.receipt.rate();
+        Ok(Some((a, b, conversion.receipt)))
+    }
+
+    fn add_values(&self, other: &Self) -> Result<Self, CalculatorError> {
+        if let Some(aligned) = self.align_measure(other) {
+            return self.add_values(&aligned);
+        }
+        if let Some(sum) = combine_duration_units(self, other, false) {
+            return Ok(sum);
+        }
         match (&self.kind, &other.kind) {
             // Rational + Rational
             (ValueKind::Rational(a), ValueKind::Rational(b)) => {
-                self.add_rationals(a.clone(), b.clone(), other, currency_db, date)
+                self.add_rationals(a.clone(), b.clone(), other)
             }
             // Number + Number (legacy)
-            (ValueKind::Number(a), ValueKind::Number(b)) => {
-                self.add_numbers(*a, *b, other, currency_db, date)
-            }
-            // Mixed: convert Decimal to Rational
+            (ValueKind::Number(a), ValueKind::Number(b)) => self.add_numbers(*a, *b, other),
+            // Mixed: convert Decimal to Rational, and the result back
             (ValueKind::Rational(a), ValueKind::Number(b)) => {
                 let b_rat = Rational::from_decimal(*b);
-                self.add_rationals(a.clone(), b_rat, other, currency_db, date)
+                self.add_rationals(a.clone(), b_rat, other)
+                    .map(Self::inexact)
             }
             (ValueKind::Number(a), ValueKind::Rational(b)) => {
                 let a_rat = Rational::from_decimal(*a);
-                self.add_rationals(a_rat, b.clone(), other, currency_db, date)
+                self.add_rationals(a_rat, b.clone(), other)
+                    .map(Self::inexact)
             }
-            (ValueKind::DateTime(dt), ValueKind::Duration { seconds }) => {
+            (ValueKind::DateTime(dt), ValueKind::Duration { seconds, .. }) => {
                 Ok(Value::datetime(dt.add_duration(*seconds)))
             }
-            (ValueKind::Duration { seconds }, ValueKind::DateTime(dt)) => {
+            (ValueKind::Duration { seconds, .. }, ValueKind::DateTime(dt)) => {
                 // Duration + DateTime = DateTime (commutative)
                 Ok(Value::datetime(dt.add_duration(*seconds)))
             }
-            (ValueKind::Duration { seconds: s1 }, ValueKind::Duration { seconds: s2 }) => {
+            (ValueKind::Duration { seconds: s1, .. }, ValueKind::Duration { seconds: s2, .. }) => {
                 Ok(Value::duration(s1 + s2))
             }
             // DateTime + number-with-duration-unit (e.g. "now + 10 days")
@@ -281,8 +387,6 @@ impl Value {
         a: Rational,
         b: Rational,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
-        date: Option<&DateTime>,
     ) -> Result<Self, CalculatorError> {
         match (&self.unit, &other.unit) {
             (Unit::None, Unit::None) => Ok(Value::rational(a + b)),
@@ -300,26 +404,10 @@ impl Value {
             (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => {
                 Ok(Value::rational_with_unit(a + b, self.unit.clone()))
             }
-            // Different currencies - need conversion (uses Decimal for approximation)
-            (Unit::Currency(c1), Unit::Currency(c2)) => {
-                let a_dec = a.to_decimal();
-                let b_dec = b.to_decimal();
-                // Use historical rate if date is provided
-                let converted = if let Some(dt) = date {
-                    currency_db.convert_at_date(b_dec.to_f64(), c2, c1, dt)?
-                } else {
-                    currency_db.convert(b_dec.to_f64(), c2, c1)?
-                };
-                let converted_decimal = Decimal::from_f64(converted);
-                Ok(Value::currency(a_dec + converted_decimal, c1))
-            }
             // Mass + different mass unit (convert to first unit's type)
             (Unit::Mass(m1), Unit::Mass(m2)) if m1 != m2 => {
-                let a_val = a.to_f64();
-                let b_val = b.to_f64();
-                let b_converted = m2.convert(b_val, *m1);
-                let result = Decimal::from_f64(a_val + b_converted);
-                Ok(Value::number_with_unit(result, Unit::Mass(*m1)))
+                let factor = Rational::from_f64(m2.convert(1.0, *m1));
+                Ok(Value::rational_with_unit(a + b * factor, Unit::Mass(*m1)))
             }
             (u1, u2) if u1 == u2 => Ok(Value::rational_with_unit(a + b, u1.clone())),
             (u1, u2) => Err(CalculatorError::unit_mismatch(
@@ -330,14 +418,7 @@ impl Value {
         }
     }
 
-    fn add_numbers(
-        &self,
-        a: Decimal,
-        b: Decimal,
-        other: &Self,
-        currency_db: &mut CurrencyDatabase,
-        date: Option<&DateTime>,
-    ) -> Result<Self, CalculatorError> {
+    fn add_numbers(&self, a: Decimal, b: Decimal, other: &Self) -> Result<Self, CalculatorError> {
         match (&self.unit, &other.unit) {
             (Unit::None, Unit::None) => Ok(Value::number(a + b)),
             (Unit::None, Unit::Custom(_)) | (Unit::Custom(_), Unit::None) => {
@@ -351,16 +432,6 @@ impl Value {
                 Ok(Value::number_with_unit(a + b, unit.clone()))
             }
             (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => Ok(Value::currency(a + b, c1)),
-            (Unit::Currency(c1), Unit::Currency(c2)) => {
-                // Convert c2 to c1, using historical rate if date is provided
-                let converted = if let Some(dt) = date {
-                    currency_db.convert_at_date(b.to_f64(), c2, c1, dt)?
-                } else {
-                    currency_db.convert(b.to_f64(), c2, c1)?
-                };
-                let converted_decimal = Decimal::from_f64(converted);
-                Ok(Value::currency(a + converted_decimal, c1))
-            }
             (u1, u2) if u1 == u2 => Ok(Value::number_with_unit(a + b, u1.clone())),
             (u1, u2) => Err(CalculatorError::unit_mismatch(
                 "add",
@@ -374,54 +445,70 @@ impl Value {
     pub fn subtract(
         &self,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
+        currency_db: &CurrencyDatabase,
     ) -> Result<Self, CalculatorError> {
         self.subtract_at_date(other, currency_db, None)
+            .map(|(difference, _)| difference)
     }
 
-    /// Subtracts two values with optional date context for historical currency conversion.
+    /// Subtracts two values with optional date context for historical
+    /// currency conversion, returning the receipt for any exchange rate used.
     pub fn subtract_at_date(
         &self,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
+        currency_db: &CurrencyDatabase,
         date: Option<&DateTime>,
-    ) -> Result<Self, CalculatorError> {
+    ) -> Result<(Self, ConversionReceipt), CalculatorError> {
+        if let Some((a, b, receipt)) = self.currency_operands(other, currency_db, date)? {
+            return Ok((Value::rational_with_unit(a - b, self.unit.clone()), receipt));
+        }
+        self.subtract_values(other)
+            .map(|difference| (difference, ConversionReceipt::default()))
+    }
+
+    fn subtract_values(&self, other: &Self) -> Result<Self, CalculatorError> {
         if let (ValueKind::DateTime(datetime), Some(year)) = (&self.kind, bare_year_datetime(other))
         {
-            return Ok(Value::duration(datetime.signed_subtract_seconds(&year)));
+            return Ok(Value::date_difference(datetime, &year));
         }
         if let (Some(year), ValueKind::DateTime(datetime)) = (bare_year_datetime(self), &other.kind)
         {
-            return Ok(Value::duration(year.signed_subtract_seconds(datetime)));
+            return Ok(Value::date_difference(&year, datetime));
         }
 
+        if let Some(aligned) = self.align_measure(other) {
+            return self.subtract_values(&aligned);
+        }
+        if let Some(difference) = combine_duration_units(self, other, true) {
+            return Ok(difference);
+        }
         match (&self.kind, &other.kind) {
             // Rational - Rational
             (ValueKind::Rational(a), ValueKind::Rational(b)) => {
-                self.subtract_rationals(a.clone(), b.clone(), other, currency_db, date)
+                self.subtract_rationals(a.clone(), b.clone(), other)
             }
             // Number - Number (legacy)
-            (ValueKind::Number(a), ValueKind::Number(b)) => {
-                self.subtract_numbers(*a, *b, other, currency_db, date)
-            }
-            // Mixed: convert Decimal to Rational
+            (ValueKind::Number(a), ValueKind::Number(b)) => self.subtract_numbers(*a, *b, other),
+            // Mixed: convert Decimal to Rational, and the result back
             (ValueKind::Rational(a), ValueKind::Number(b)) => {
                 let b_rat = Rational::from_decimal(*b);
-                self.subtract_rationals(a.clone(), b_rat, other, currency_db, date)
+                self.subtract_rationals(a.clone(), b_rat, other)
+                    .map(Self::inexact)
             }
             (ValueKind::Number(a), ValueKind::Rational(b)) => {
                 let a_rat = Rational::from_decimal(*a);
-                self.subtract_rationals(a_rat, b.clone(), other, currency_db, date)
+                self.subtract_rationals(a_rat, b.clone(), other)
+                    .map(Self::inexact)
             }
             (ValueKind::DateTime(dt1), ValueKind::DateTime(dt2)) => {
                 // Signed difference (dt1 - dt2): a negative result (dt1 earlier
                 // than dt2) is preserved instead of collapsing to zero.
-                Ok(Value::duration(dt1.signed_subtract_seconds(dt2)))
+                Ok(Value::date_difference(dt1, dt2))
             }
-            (ValueKind::DateTime(dt), ValueKind::Duration { seconds }) => {
+            (ValueKind::DateTime(dt), ValueKind::Duration { seconds, .. }) => {
                 Ok(Value::datetime(dt.add_duration(-seconds)))
             }
-            (ValueKind::Duration { seconds: s1 }, ValueKind::Duration { seconds: s2 }) => {
+            (ValueKind::Duration { seconds: s1, .. }, ValueKind::Duration { seconds: s2, .. }) => {
                 Ok(Value::duration(s1 - s2))
             }
             // DateTime - number-with-duration-unit (e.g. "now - 10 days")
@@ -464,8 +551,6 @@ impl Value {
         a: Rational,
         b: Rational,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
-        date: Option<&DateTime>,
     ) -> Result<Self, CalculatorError> {
         match (&self.unit, &other.unit) {
             (Unit::None, Unit::None) => Ok(Value::rational(a - b)),
@@ -482,26 +567,10 @@ impl Value {
             (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => {
                 Ok(Value::rational_with_unit(a - b, self.unit.clone()))
             }
-            // Different currencies - need conversion (uses Decimal for approximation)
-            (Unit::Currency(c1), Unit::Currency(c2)) => {
-                let a_dec = a.to_decimal();
-                let b_dec = b.to_decimal();
-                // Use historical rate if date is provided
-                let converted = if let Some(dt) = date {
-                    currency_db.convert_at_date(b_dec.to_f64(), c2, c1, dt)?
-                } else {
-                    currency_db.convert(b_dec.to_f64(), c2, c1)?
-                };
-                let converted_decimal = Decimal::from_f64(converted);
-                Ok(Value::currency(a_dec - converted_decimal, c1))
-            }
             // Mass - different mass unit (convert to first unit's type)
             (Unit::Mass(m1), Unit::Mass(m2)) if m1 != m2 => {
-                let a_val = a.to_f64();
-                let b_val = b.to_f64();
-                let b_converted = m2.convert(b_val, *m1);
-                let result = Decimal::from_f64(a_val - b_converted);
-                Ok(Value::number_with_unit(result, Unit::Mass(*m1)))
+                let factor = Rational::from_f64(m2.convert(1.0, *m1));
+                Ok(Value::rational_with_unit(a - b * factor, Unit::Mass(*m1)))
             }
             (u1, u2) if u1 == u2 => Ok(Value::rational_with_unit(a - b, u1.clone())),
             (u1, u2) => Err(CalculatorError::unit_mismatch(
@@ -517,8 +586,6 @@ impl Value {
         a: Decimal,
         b: Decimal,
         other: &Self,
-        currency_db: &mut CurrencyDatabase,
-        date: Option<&DateTime>,
     ) -> Result<Self, CalculatorError> {
         match (&self.unit, &other.unit) {
             (Unit::None, Unit::None) => Ok(Value::number(a - b)),
@@ -532,16 +599,6 @@ impl Value {
             (unit, Unit::None) => Ok(Value::number_with_unit(a - b, unit.clone())),
             (Unit::None, unit) => Ok(Value::number_with_unit(a - b, unit.clone())),
             (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => Ok(Value::currency(a - b, c1)),
-            (Unit::Currency(c1), Unit::Currency(c2)) => {
-                // Convert c2 to c1, using historical rate if date is provided
-                let converted = if let Some(dt) = date {
-                    currency_db.convert_at_date(b.to_f64(), c2, c1, dt)?
-                } else {
-                    currency_db.convert(b.to_f64(), c2, c1)?
-                };
-                let converted_decimal = Decimal::from_f64(converted);
-                Ok(Value::currency(a - converted_decimal, c1))
-            }
             (u1, u2) if u1 == u2 => Ok(Value::number_with_unit(a - b, u1.clone())),
             (u1, u2) => Err(CalculatorError::unit_mismatch(
                 "subtract",
@@ -553,47 +610,40 @@ impl Value {
 
     /// Multiplies two values.
     pub fn multiply(&self, other: &Self) -> Result<Self, CalculatorError> {
+        if let Some(product) = self.multiply_compound(other) {
+            return Ok(product);
+        }
+        if let Some(product) = self.multiply_powers(other) {
+            return Ok(product);
+        }
+        if let Some(product) = scale_duration(self, other).or_else(|| scale_duration(other, self)) {
+            return Ok(product);
+        }
         match (&self.kind, &other.kind) {
             // Rational * Rational
             (ValueKind::Rational(a), ValueKind::Rational(b)) => {
                 let result = a.clone() * b.clone();
-                let unit = if self.unit != Unit::None {
-                    self.unit.clone()
-                } else {
-                    other.unit.clone()
-                };
+                let unit = self.product_unit(other)?;
                 Ok(Value::rational_with_unit(result, unit))
             }
             // Number * Number (legacy)
             (ValueKind::Number(a), ValueKind::Number(b)) => {
                 let result = *a * *b;
-                let unit = if self.unit != Unit::None {
-                    self.unit.clone()
-                } else {
-                    other.unit.clone()
-                };
+                let unit = self.product_unit(other)?;
                 Ok(Value::number_with_unit(result, unit))
             }
-            // Mixed: convert Decimal to Rational
+            // Mixed: convert Decimal to Rational, and the result back
             (ValueKind::Rational(a), ValueKind::Number(b)) => {
                 let b_rat = Rational::from_decimal(*b);
                 let result = a.clone() * b_rat;
-                let unit = if self.unit != Unit::None {
-                    self.unit.clone()
-                } else {
-                    other.unit.clone()
-                };
-                Ok(Value::rational_with_unit(result, unit))
+                let unit = self.product_unit(other)?;
+                Ok(Value::rational_with_unit(result, unit).inexact())
             }
             (ValueKind::Number(a), ValueKind::Rational(b)) => {
                 let a_rat = Rational::from_decimal(*a);
                 let result = a_rat * b.clone();
-                let unit = if self.unit != Unit::None {
-                    self.unit.clone()
-                } else {
-                    other.unit.clone()
-                };
-                Ok(Value::rational_with_unit(result, unit))
+                let unit = self.product_unit(other)?;
+                Ok(Value::rational_with_unit(result, unit).inexact())
             }
             _ => Err(CalculatorError::InvalidOperation(format!(
                 "Cannot multiply {} and {}",
@@ -605,6 +655,18 @@ impl Value {
 
     /// Divides two values.
     pub fn divide(&self, other: &Self) -> Result<Self, CalculatorError> {
+        if let Some(aligned) = self.align_measure(other) {
+            return self.divide(&aligned);
+        }
+        if let Some(quotient) = self.divide_powers(other)? {
+            return Ok(quotient);
+        }
+        if let Some(quotient) = self.divide_compound(other)? {
+            return Ok(quotient);
+        }
+        if let Some(quotient) = self.divide_duration(other)? {
+            return Ok(quotient);
+        }
         match (&self.kind, &other.kind) {
             // Rational / Rational
             (ValueKind::Rational(a), ValueKind::Rational(b)) => {
@@ -617,7 +679,7 @@ impl Value {
                 let result = a.clone() / b.clone();
 
                 // Handle unit division
-                let unit = Self::division_result_unit(&self.unit, &other.unit);
+                let unit = Self::division_result_unit(&self.unit, &other.unit)?;
 
                 Ok(Value::rational_with_unit(result, unit))
             }
@@ -632,11 +694,12 @@ impl Value {
                 let result = a.checked_div(b).ok_or(CalculatorError::Overflow)?;
 
                 // Handle unit division
-                let unit = Self::division_result_unit(&self.unit, &other.unit);
+                let unit = Self::division_result_unit(&self.unit, &other.unit)?;
 
                 Ok(Value::number_with_unit(result, unit))
             }
-            // Mixed: convert Decimal to Rational for exact division
+            // Mixed: convert Decimal to Rational for exact division, and the
+            // result back
             (ValueKind::Rational(a), ValueKind::Number(b)) => {
                 if b.is_zero() {
                     return Err(CalculatorError::DivisionByZero);
@@ -647,9 +710,9 @@ impl Value {
                 let b_rat = Rational::from_decimal(*b);
                 let result = a.clone() / b_rat;
 
-                let unit = Self::division_result_unit(&self.unit, &other.unit);
+                let unit = Self::division_result_unit(&self.unit, &other.unit)?;
 
-                Ok(Value::rational_with_unit(result, unit))
+                Ok(Value::rational_with_unit(result, unit).inexact())
             }
             (ValueKind::Number(a), ValueKind::Rational(b)) => {
                 if b.is_zero() {
@@ -661,13 +724,14 @@ impl Value {
                 let a_rat = Rational::from_decimal(*a);
                 let result = a_rat / b.clone();
 
-                let unit = Self::division_result_unit(&self.unit, &other.unit);
+                let unit = Self::division_result_unit(&self.unit, &other.unit)?;
 
-                Ok(Value::rational_with_unit(result, unit))
-            }
-            (ValueKind::Duration { seconds }, ValueKind::Number(_) | ValueKind::Rational(_)) => {
-                divide_raw_duration(*seconds, other)
+                Ok(Value::rational_with_unit(result, unit).inexact())
             }
+            (
+                ValueKind::Duration { seconds, .. },
+                ValueKind::Number(_) | ValueKind::Rational(_),
+            ) => divide_raw_duration(*seconds, other),
             _ => Err(CalculatorError::InvalidOperation(format!(
                 "Cannot divide {} by {}",
                 self.type_name(),
@@ -676,14 +740,59 @@ impl Value {
         }
     }
 
-    fn division_result_unit(left: &Unit, right: &Unit) -> Unit {
+    /// Divides a duration by a plain number or by another duration when one
+    /// of them is raw. A raw duration of whole days keeps dividing as its day
+    /// count, so `(8 Aug - 17 Jun) / 30` is `26/15`.
+    fn divide_duration(&self, other: &Self) -> Result<Option<Self>, CalculatorError> {
+        if let ValueKind::Duration { seconds, .. } = self.kind {
+            if other.unit == Unit::None && other.to_rational().is_some() && seconds % 86_400 == 0 {
+                return Ok(None);
+            }
+        }
+        if matches!(self.kind, ValueKind::Duration { .. })
+            || matches!(other.kind, ValueKind::Duration { .. })
+        {
+            if let Some(ratio) = duration_ratio(self, other)? {
+                return Ok(Some(ratio));
+            }
+        }
+        match other.to_rational() {
+            Some(divisor) if other.unit == Unit::None && !divisor.is_zero() => Ok(scale_duration(
+                self,
+                &Value::rational(Rational::one() / divisor),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn division_result_unit(left: &Unit, right: &Unit) -> Result<Unit, CalculatorError> {
         match (left, right) {
-            (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => Unit::None,
-            (Unit::Duration(_), Unit::Currency(_)) => Unit::None,
-            (unit, Unit::None) => unit.clone(),
-            (Unit::None, _) => Unit::None,
-            (u1, u2) if u1 == u2 => Unit::None,
-            _ => left.clone(),
+            (Unit::Currency(c1), Unit::Currency(c2)) if c1 == c2 => Ok(Unit::None),
+            (Unit::Duration(_), Unit::Currency(_)) => Ok(Unit::None),
+            (unit, Unit::None) => Ok(unit.clone()),
+            (Unit::None, _) => Ok(Unit::None),
+            (u1, u2) if u1 == u2 => Ok(Unit::None),
+            (u1, u2) if u1.has_dimension() && u2.has_dimension() => Err(
+                CalculatorError::unit_mismatch("divide", &u1.display_name(), &u2.display_name()),
+            ),
+            _ => Ok(left.clone()),
+        }
+    }
+
+    /// The unit of a product that no unit rule applies to: the unit of
+    /// whichever factor has one. Two amounts with dimensions, such as
+    /// `2 kg * 3 m`, have no such unit, and keeping either would be wrong.
+    fn product_unit(&self, other: &Self) -> Result<Unit, CalculatorError> {
+        match (&self.unit, &other.unit) {
+            (Unit::None, unit) => Ok(unit.clone()),
+            (left, right) if left.has_dimension() && right.has_dimension() => {
+                Err(CalculatorError::unit_mismatch(
+                    "multiply",
+                    &left.display_name(),
+                    &right.display_name(),
+                ))
+            }
+            (unit, _) => Ok(unit.clone()),
         }
     }
 
@@ -711,143 +820,30 @@ impl Value {
         Ok(Value::rational(result))
     }
 
-    /// Converts this value to the given unit.
-    ///
-    /// Supports conversion between data size units (KB, KiB, MB, MiB, etc.)
-    /// and currency conversions (USD → EUR, etc.).
-    pub fn convert_to_unit(
-        &self,
-        target_unit: &Unit,
-        currency_db: &mut CurrencyDatabase,
-    ) -> Result<Self, CalculatorError> {
-        self.convert_to_unit_at_date(target_unit, currency_db, None)
-    }
-
-    /// Converts this value to the given unit, using a historical exchange rate if `date` is provided.
-    pub fn convert_to_unit_at_date(
-        &self,
-        target_unit: &Unit,
-        currency_db: &mut CurrencyDatabase,
-        date: Option<&DateTime>,
-    ) -> Result<Self, CalculatorError> {
-        if let ValueKind::Duration { seconds } = &self.kind {
-            return convert_raw_duration(*seconds, target_unit);
-        }
-
-        match (&self.unit, target_unit) {
-            (_, Unit::None) => {
-                let value = self.to_rational().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "number conversion requires a numeric value".into(),
-                    )
-                })?;
-                Ok(Value::rational(value))
-            }
-            // Data size to data size conversion
-            (Unit::DataSize(from), Unit::DataSize(to)) => {
-                let value_f64 = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "data size conversion requires a numeric value".into(),
-                    )
-                })?;
-                let result = from.convert(value_f64.to_f64(), *to);
-                Ok(Value::number_with_unit(
-                    Decimal::from_f64(result),
-                    Unit::DataSize(*to),
-                ))
-            }
-            // Currency to currency conversion
-            (Unit::Currency(from), Unit::Currency(to)) => {
-                let amount = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "currency conversion requires a numeric value".into(),
-                    )
-                })?;
-                let converted = if let Some(dt) = date {
-                    currency_db.convert_at_date(amount.to_f64(), from, to, dt)?
-                } else {
-                    currency_db.convert(amount.to_f64(), from, to)?
-                };
-                Ok(Value::currency(Decimal::from_f64(converted), to))
-            }
-            // Mass to mass conversion
-            (Unit::Mass(from), Unit::Mass(to)) => {
-                let value_f64 = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "mass conversion requires a numeric value".into(),
-                    )
-                })?;
-                let result = from.convert(value_f64.to_f64(), *to);
-                Ok(Value::number_with_unit(
-                    Decimal::from_f64(result),
-                    Unit::Mass(*to),
-                ))
-            }
-            // Duration to duration conversion (e.g., "300000 ms in seconds")
-            (Unit::Duration(from), Unit::Duration(to)) => {
-                let value_f64 = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "duration conversion requires a numeric value".into(),
-                    )
-                })?;
-                let secs = from.to_secs(value_f64.to_f64());
-                let result = to.secs_to_unit(secs);
-                Ok(Value::number_with_unit(
-                    Decimal::from_f64(result),
-                    Unit::Duration(*to),
-                ))
-            }
-            // Dimensionless value: just apply the target unit (e.g. "5 as MB")
-            (Unit::None, Unit::DataSize(_)) => {
-                let value_f64 = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "unit conversion requires a numeric value".into(),
-                    )
-                })?;
-                Ok(Value::number_with_unit(value_f64, target_unit.clone()))
-            }
-            // Dimensionless value: just apply the mass target unit (e.g. "5 as kg")
-            (Unit::None, Unit::Mass(_)) => {
-                let value_f64 = self.as_decimal().ok_or_else(|| {
-                    CalculatorError::InvalidOperation(
-                        "unit conversion requires a numeric value".into(),
-                    )
-                })?;
-                Ok(Value::number_with_unit(value_f64, target_unit.clone()))
-            }
-            (Unit::None, Unit::Duration(unit)) => apply_duration_unit(self, *unit),
-            // DateTime timezone conversion (e.g., "6 PM GMT as MSK")
-            (_, Unit::Timezone(tz_abbrev)) => {
-                if let ValueKind::DateTime(dt) = &self.kind {
-                    let target_offset =
-                        DateTime::parse_tz_abbreviation(tz_abbrev).ok_or_else(|| {
-                            CalculatorError::parse(format!("Unknown timezone: {tz_abbrev}"))
-                        })?;
-                    let converted = dt.with_timezone_offset(target_offset, tz_abbrev);
-                    Ok(Value::datetime(converted))
-                } else {
-                    Err(CalculatorError::InvalidOperation(format!(
-                        "Cannot convert {} to timezone {}; only DateTime values can be converted to a timezone",
-                        self.to_display_string(),
-                        tz_abbrev
-                    )))
-                }
-            }
-            (from_unit, to_unit) => Err(CalculatorError::InvalidOperation(format!(
-                "Cannot convert {} to {}",
-                from_unit.display_name(),
-                to_unit.display_name()
-            ))),
-        }
-    }
-
     /// Negates the value.
     #[must_use]
     pub fn negate(&self) -> Self {
         match &self.kind {
             ValueKind::Number(n) => Value::number_with_unit(-*n, self.unit.clone()),
             ValueKind::Rational(r) => Value::rational_with_unit(-r.clone(), self.unit.clone()),
-            ValueKind::Duration { seconds } => Value::duration(-seconds),
+            ValueKind::Symbolic {
+                coefficient,
+                constant,
+                power,
+            } => Value::symbolic(-coefficient.clone(), *constant, *power),
+            ValueKind::Duration { seconds, calendar } => Self {
+                kind: ValueKind::Duration {
+                    seconds: -seconds,
+                    calendar: calendar.map(|(months, rest)| (-months, -rest)),
+                },
+                unit: Unit::None,
+                rate_date: None,
+            },
+            ValueKind::CalendarSpan {
+                months,
+                seconds,
+                total_seconds,
+            } => Value::calendar_span(-months, -seconds, -total_seconds),
             _ => self.clone(),
         }
     }
@@ -857,66 +853,34 @@ impl Value {
     pub fn type_name(&self) -> &'static str {
         match self.kind {
             ValueKind::Number(_) => "number",
-            ValueKind::Rational(_) => "number",
+            ValueKind::Rational(_) | ValueKind::Symbolic { .. } => "number",
             ValueKind::DateTime(_) => "datetime",
-            ValueKind::Duration { .. } => "duration",
+            ValueKind::TimeOfDay { .. } => "time of day",
+            ValueKind::Duration { .. } | ValueKind::CalendarSpan { .. } => "duration",
             ValueKind::Boolean(_) => "boolean",
+            ValueKind::Text(_) => "text",
             ValueKind::Comparison { .. } => "comparison result",
             ValueKind::EquationSolution { .. }
             | ValueKind::EquationSolutions { .. }
             | ValueKind::SymbolicEquationSolution { .. } => "equation solution",
+            ValueKind::List(_) => "list",
+            ValueKind::Matrix(_) => "matrix",
         }
     }
 
     /// Converts the value to a display string.
     #[must_use]
     pub fn to_display_string(&self) -> String {
-        match &self.kind {
-            ValueKind::Number(n) => {
-                let n_str = n.normalize().to_string();
-                if self.unit == Unit::None {
-                    n_str
-                } else {
-                    format!("{} {}", n_str, self.unit)
-                }
-            }
-            ValueKind::Rational(r) => {
-                let r_str = r.to_display_string();
-                if self.unit == Unit::None {
-                    r_str
-                } else {
-                    format!("{} {}", r_str, self.unit)
-                }
-            }
-            ValueKind::DateTime(dt) => dt.to_string(),
-            ValueKind::Duration { seconds } => format_duration(*seconds),
-            ValueKind::Boolean(b) => b.to_string(),
-            ValueKind::Comparison {
-                left,
-                relation,
-                right,
-            } => format!("{left} {relation} {right}"),
-            ValueKind::EquationSolution { variable, value } => {
-                format!("{variable} = {}", value.to_display_string())
-            }
-            ValueKind::EquationSolutions { variable, values } => values
-                .iter()
-                .map(|value| format!("{variable} = {}", value.to_display_string()))
-                .collect::<Vec<_>>()
-                .join(" or "),
-            ValueKind::SymbolicEquationSolution {
-                variable,
-                expression,
-            } => {
-                format!("{variable} = {expression}")
-            }
-        }
+        self.to_display_string_with(&FormatOptions::default())
     }
 
-    /// Returns true if this is a number (either Decimal or Rational).
+    /// Returns true if this is a number (Decimal, Rational or symbolic).
     #[must_use]
     pub fn is_number(&self) -> bool {
-        matches!(self.kind, ValueKind::Number(_) | ValueKind::Rational(_))
+        matches!(
+            self.kind,
+            ValueKind::Number(_) | ValueKind::Rational(_) | ValueKind::Symbolic { .. }
+        )
     }
 
     /// Returns the decimal value if this is a number.
@@ -924,7 +888,12 @@ impl Value {
     pub fn as_number(&self) -> Option<Decimal> {
         match &self.kind {
             ValueKind::Number(n) => Some(*n),
-            ValueKind::Rational(r) => Some(r.to_decimal()),
+            ValueKind::Rational(r) => r.to_decimal(),
+            ValueKind::Symbolic {
+                coefficient,
+                constant,
+                power,
+            } => symbolic_decimal(coefficient, *constant, *power),
             _ => None,
         }
     }
@@ -950,6 +919,7 @@ impl Value {
         match &self.kind {
             ValueKind::Rational(r) => Some(r.clone()),
             ValueKind::Number(d) => Some(Rational::from_decimal(*d)),
+            ValueKind::Symbolic { .. } => self.as_number().map(Rational::from_decimal),
             _ => None,
         }
     }
@@ -958,7 +928,7 @@ impl Value {
     #[must_use]
     pub fn to_fraction_string(&self) -> Option<String> {
         match &self.kind {
-            ValueKind::Rational(r) => Some(r.to_fraction_string()),
+            ValueKind::Rational(r) if r.has_display_digits() => Some(r.to_fraction_string()),
             _ => None,
         }
     }
@@ -982,10 +952,18 @@ impl PartialEq for Value {
             (ValueKind::Rational(a), ValueKind::Number(b)) => {
                 (a.to_f64() - b.to_f64()).abs() < 1e-10 && self.unit == other.unit
             }
+            (ValueKind::Symbolic { .. }, ValueKind::Symbolic { .. }) => self.kind == other.kind,
+            (ValueKind::Symbolic { .. }, _) | (_, ValueKind::Symbolic { .. }) => {
+                self.numeric() == other.numeric()
+            }
             _ => self.kind == other.kind && self.unit == other.unit,
         }
     }
 }
 
+// Lists compare their items with `==`, so `ValueKind` (which derives `Eq`)
+// needs `Value: Eq` as well.
+impl Eq for Value {}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,258 @@
+//! Multiples of powers of π and e kept exact, so that `2 * pi` is `2π`
+//! rather than `6.283185307179586`. Arithmetic with no exact form, such as
+//! `pi + 1`, is calculated with the constant's value instead.
+
+use std::f64::consts::{E, PI};
+
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+use super::{Value, ValueKind};
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, Decimal, Rational, Unit};
+
+/// A constant kept symbolic in results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolicConstant {
+    /// π, the ratio of a circle's circumference to its diameter.
+    Pi,
+    /// e, the base of the natural logarithm.
+    E,
+}
+
+impl SymbolicConstant {
+    /// Parses the name of a constant: `pi`, `π` or `e`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "pi" | "π" => Some(Self::Pi),
+            "e" => Some(Self::E),
+            _ => None,
+        }
+    }
+
+    /// Returns the symbol the constant is shown with.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Pi => "π",
+            Self::E => "e",
+        }
+    }
+
+    /// Returns the value of the constant (as f64).
+    #[must_use]
+    pub const fn value(self) -> f64 {
+        match self {
+            Self::Pi => PI,
+            Self::E => E,
+        }
+    }
+}
+
+impl Value {
+    /// Creates `coefficient × constant^power`, which is the plain number
+    /// `coefficient` when the power is 0 and `0` when the coefficient is.
+    #[must_use]
+    pub fn symbolic(coefficient: Rational, constant: SymbolicConstant, power: i32) -> Self {
+        if power == 0 || coefficient.is_zero() {
+            return Self::rational(coefficient);
+        }
+        Self {
+            kind: ValueKind::Symbolic {
+                coefficient,
+                constant,
+                power,
+            },
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Returns the constant called `name` (`pi` or `e`) as a symbolic value.
+    #[must_use]
+    pub fn constant(name: &str) -> Option<Self> {
+        SymbolicConstant::parse(name)
+            .map(|constant| Self::symbolic(Rational::from_integer(1), constant, 1))
+    }
+
+    /// Returns the coefficient, constant and power of a symbolic value.
+    #[must_use]
+    pub fn as_symbolic(&self) -> Option<(&Rational, SymbolicConstant, i32)> {
+        match &self.kind {
+            ValueKind::Symbolic {
+                coefficient,
+                constant,
+                power,
+            } => Some((coefficient, *constant, *power)),
+            _ => None,
+        }
+    }
+
+    /// Returns this value with a symbolic constant replaced by its decimal
+    /// value.
+    #[must_use]
+    pub fn numeric(&self) -> Self {
+        match &self.kind {
+            ValueKind::Symbolic { .. } => Self::number(self.as_number().unwrap_or_default()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Applies a binary operation when either operand is symbolic.
+    ///
+    /// Multiplying or dividing by a plain number scales the coefficient,
+    /// multiplying or dividing powers of the same constant adds or subtracts
+    /// the powers, and like terms add and subtract. Anything else is passed
+    /// to `apply` with the constants replaced by their values. Returns `None`
+    /// when neither operand is symbolic.
+    #[allow(clippy::many_single_char_names)]
+    pub fn symbolic_op(
+        left: &Self,
+        op: BinaryOp,
+        right: &Self,
+        apply: impl FnOnce(&Self, &Self) -> Result<Self, CalculatorError>,
+    ) -> Option<Result<Self, CalculatorError>> {
+        let (lhs, rhs) = (left.as_symbolic(), right.as_symbolic());
+        if lhs.is_none() && rhs.is_none() {
+            return None;
+        }
+        let plain = |value: &Self| value.to_rational().filter(|_| value.unit == Unit::None);
+        let exact = match (lhs, op, rhs) {
+            (Some((a, k, p)), BinaryOp::Multiply, None) => {
+                plain(right).map(|n| (a.clone() * n, k, p))
+            }
+            (None, BinaryOp::Multiply, Some((b, k, p))) => {
+                plain(left).map(|n| (n * b.clone(), k, p))
+            }
+            (Some((a, k, p)), BinaryOp::Divide, None) => plain(right)
+                .filter(|n| !n.is_zero())
+                .map(|n| (a.clone() / n, k, p)),
+            (None, BinaryOp::Divide, Some((b, k, p))) => {
+                plain(left).map(|n| (n / b.clone(), k, -p))
+            }
+            (Some((a, k, p)), BinaryOp::Multiply, Some((b, k2, q))) if k == k2 => p
+                .checked_add(q)
+                .map(|power| (a.clone() * b.clone(), k, power)),
+            (Some((a, k, p)), BinaryOp::Divide, Some((b, k2, q))) if k == k2 => p
+                .checked_sub(q)
+                .map(|power| (a.clone() / b.clone(), k, power)),
+            (Some((a, k, p)), BinaryOp::Add, Some((b, k2, q))) if k == k2 && p == q => {
+                Some((a.clone() + b.clone(), k, p))
+            }
+            (Some((a, k, p)), BinaryOp::Subtract, Some((b, k2, q))) if k == k2 && p == q => {
+                Some((a.clone() - b.clone(), k, p))
+            }
+            _ => None,
+        };
+        Some(match exact {
+            Some((coefficient, constant, power)) => {
+                Ok(Self::symbolic(coefficient, constant, power))
+            }
+            None => apply(&left.numeric(), &right.numeric()),
+        })
+    }
+
+    /// Raises a symbolic value to a whole power exactly: `pi^2` is `π²`.
+    /// Returns `None` for any other base or exponent.
+    #[must_use]
+    pub fn symbolic_power(&self, exponent: &Self) -> Option<Self> {
+        let (coefficient, constant, power) = self.as_symbolic()?;
+        let n = exponent
+            .to_rational()
+            .filter(|n| n.is_integer() && exponent.unit == Unit::None)?;
+        let n = i32::try_from(n.numer()).ok().filter(|n| n.abs() <= 1000)?;
+        if n < 0 && coefficient.is_zero() {
+            return None;
+        }
+        Some(Self::symbolic(
+            coefficient.pow_i32(n),
+            constant,
+            power.checked_mul(n)?,
+        ))
+    }
+}
+
+/// Returns the decimal value of `coefficient × constant^power`, or `None`
+/// if it is too large for a Decimal.
+pub(super) fn symbolic_decimal(
+    coefficient: &Rational,
+    constant: SymbolicConstant,
+    power: i32,
+) -> Option<Decimal> {
+    coefficient
+        .to_decimal()?
+        .checked_mul(&Decimal::try_from_f64(constant.value().powi(power))?)
+}
+
+/// Writes `coefficient × constant^power` the way it is written by hand:
+/// `2π`, `-π`, `3π/4`, `π²`, `2/π`, `1/(2π)`.
+pub(super) fn format_symbolic(
+    coefficient: &Rational,
+    constant: SymbolicConstant,
+    power: i32,
+) -> String {
+    let mut term = constant.symbol().to_string();
+    if power.unsigned_abs() > 1 {
+        term.push_str(&superscript(power.unsigned_abs()));
+    }
+    let numer = coefficient.numer_bigint();
+    let denom = coefficient.denom_bigint();
+    let one = BigInt::from(1);
+    let numer_str = if *numer == one {
+        String::new()
+    } else if *numer == -BigInt::from(1) {
+        "-".to_string()
+    } else {
+        numer.to_string()
+    };
+    if power > 0 {
+        if *denom == one {
+            format!("{numer_str}{term}")
+        } else {
+            format!("{numer_str}{term}/{denom}")
+        }
+    } else {
+        let numer_str = match numer_str.as_str() {
+            "" => "1".to_string(),
+            "-" => "-1".to_string(),
+            _ => numer_str,
+        };
+        if *denom == one {
+            format!("{numer_str}/{term}")
+        } else {
+            format!("{numer_str}/({denom}{term})")
+        }
+    }
+}
+
+/// Writes `n` in superscript digits: `²`.
+fn superscript(n: u32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    n.to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10).map(|d| DIGITS[d as usize]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_symbolic() {
+        let pi = SymbolicConstant::Pi;
+        let format = |n, d, power| format_symbolic(&Rational::new(n, d), pi, power);
+        assert_eq!(format(2, 1, 1), "2π");
+        assert_eq!(format(-1, 1, 1), "-π");
+        assert_eq!(format(3, 4, 1), "3π/4");
+        assert_eq!(format(1, 1, 2), "π²");
+        assert_eq!(format(2, 1, -1), "2/π");
+        assert_eq!(format(1, 2, -1), "1/(2π)");
+        assert_eq!(
+            format_symbolic(&Rational::new(1, 1), SymbolicConstant::E, 12),
+            "e¹²"
+        );
+    }
+}
@@ -18,8 +18,8 @@ fn test_currency_value() {
 fn test_number_addition() {
     let a = Value::number(Decimal::new(2));
     let b = Value::number(Decimal::new(3));
-    let mut db = CurrencyDatabase::new();
-    let result = a.add(&b, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = a.add(&b, &db).unwrap();
     assert_eq!(result.to_display_string(), "5");
 }
 
@@ -27,8 +27,8 @@ fn test_number_addition() {
 fn test_rational_addition() {
     let a = Value::rational(Rational::new(1, 3));
     let b = Value::rational(Rational::new(1, 3));
-    let mut db = CurrencyDatabase::new();
-    let result = a.add(&b, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = a.add(&b, &db).unwrap();
     // 1/3 + 1/3 = 2/3
     assert_eq!(result.to_fraction_string(), Some("2/3".to_string()));
 }
@@ -37,8 +37,8 @@ fn test_rational_addition() {
 fn test_currency_addition_same() {
     let a = Value::currency(Decimal::new(100), "USD");
     let b = Value::currency(Decimal::new(50), "USD");
-    let mut db = CurrencyDatabase::new();
-    let result = a.add(&b, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = a.add(&b, &db).unwrap();
     assert_eq!(result.to_display_string(), "150 USD");
 }
 
@@ -98,8 +98,8 @@ fn test_format_duration() {
 fn test_datetime_subtraction() {
     let dt1 = Value::datetime(DateTime::parse("2026-01-27").unwrap());
     let dt2 = Value::datetime(DateTime::parse("2026-01-25").unwrap());
-    let mut db = CurrencyDatabase::new();
-    let result = dt1.subtract(&dt2, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = dt1.subtract(&dt2, &db).unwrap();
     assert!(matches!(result.kind, ValueKind::Duration { .. }));
 }
 
@@ -107,8 +107,8 @@ fn test_datetime_subtraction() {
 fn test_datetime_plus_duration() {
     let dt = Value::datetime(DateTime::parse("2026-01-25").unwrap());
     let dur = Value::duration(86400); // 1 day in seconds
-    let mut db = CurrencyDatabase::new();
-    let result = dt.add(&dur, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = dt.add(&dur, &db).unwrap();
     assert!(matches!(result.kind, ValueKind::DateTime(_)));
 }
 
@@ -116,8 +116,8 @@ fn test_datetime_plus_duration() {
 fn test_duration_plus_datetime() {
     let dur = Value::duration(86400); // 1 day in seconds (issue #8: duration + datetime)
     let dt = Value::datetime(DateTime::parse("2026-01-25").unwrap());
-    let mut db = CurrencyDatabase::new();
-    let result = dur.add(&dt, &mut db).unwrap();
+    let db = CurrencyDatabase::new();
+    let result = dur.add(&dt, &db).unwrap();
     assert!(matches!(result.kind, ValueKind::DateTime(_)));
 }
 
@@ -128,12 +128,27 @@ fn test_issue_8_expression() {
     let dt1 = Value::datetime(DateTime::parse("Jan 27, 8:59am UTC").unwrap());
     let dt2 = Value::datetime(DateTime::parse("Jan 25, 12:51pm UTC").unwrap());
     let dt3 = Value::datetime(DateTime::parse("Jan 25, 12:51pm UTC").unwrap());
-    let mut db = CurrencyDatabase::new();
+    let db = CurrencyDatabase::new();
 
     // First: dt1 - dt2 = duration
-    let duration = dt1.subtract(&dt2, &mut db).unwrap();
+    let duration = dt1.subtract(&dt2, &db).unwrap();
     assert!(matches!(duration.kind, ValueKind::Duration { .. }));
     // Second: duration + dt3 = datetime (this was failing before the fix)
-    let result = duration.add(&dt3, &mut db).unwrap();
+    let result = duration.add(&dt3, &db).unwrap();
     assert!(matches!(result.kind, ValueKind::DateTime(_)));
 }
+
+#[test]
+fn test_currency_addition_returns_receipt() {
+    let a = Value::currency(Decimal::new(100), "USD");
+    let b = Value::currency(Decimal::new(50), "EUR");
+    let db = CurrencyDatabase::new();
+    let (_, receipt) = a.add_at_date(&b, &db, None).unwrap();
+    assert_eq!(receipt.rates.len(), 1);
+    assert_eq!(receipt.rates[0].0, "EUR");
+    assert_eq!(receipt.rates[0].1, "USD");
+
+    let same = Value::currency(Decimal::new(50), "USD");
+    let (_, receipt) = a.add_at_date(&same, &db, None).unwrap();
+    assert!(receipt.is_empty());
+}
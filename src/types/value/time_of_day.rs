@@ -0,0 +1,148 @@
+//! Clock times without a date, for timesheet-style arithmetic: `14:30 +
+//! 2:45` is `17:15`, and `22:00 + 3:30` is `01:30 (+1 day)`.
+
+use chrono::{Duration, Timelike};
+
+use super::duration::duration_unit_seconds;
+use super::{Value, ValueKind};
+use crate::error::CalculatorError;
+use crate::types::{BinaryOp, DateTime, DurationUnit, Unit};
+
+/// Seconds in a day.
+const DAY_SECONDS: i64 = 86_400;
+
+impl Value {
+    /// Creates a time of day `seconds` after midnight, counting into the
+    /// following days past 24 hours and into the day before below 0.
+    #[must_use]
+    pub fn time_of_day(seconds: i64) -> Self {
+        Self {
+            kind: ValueKind::TimeOfDay { seconds },
+            unit: Unit::None,
+            rate_date: None,
+        }
+    }
+
+    /// Applies a binary operation on times of day.
+    ///
+    /// Adding two clock times without a date, such as `14:30 + 2:45`, reads
+    /// the second as hours and minutes and gives a time of day. A time of
+    /// day then adds and subtracts durations and clock times, and two of
+    /// them subtract to the duration between them. Returns `None` for any
+    /// other operands.
+    pub fn time_of_day_op(
+        left: &Self,
+        op: BinaryOp,
+        right: &Self,
+    ) -> Option<Result<Self, CalculatorError>> {
+        let time = |value: &Self| match &value.kind {
+            ValueKind::TimeOfDay { seconds } => Some(*seconds),
+            ValueKind::DateTime(dt) => clock_seconds(dt),
+            _ => None,
+        };
+        let is_time_of_day = |value: &Self| matches!(value.kind, ValueKind::TimeOfDay { .. });
+        let result = match (time(left), op, time(right)) {
+            (Some(a), BinaryOp::Add, Some(b)) => Self::time_of_day(a.checked_add(b)?),
+            (Some(a), BinaryOp::Subtract, Some(b))
+                if is_time_of_day(left) || is_time_of_day(right) =>
+            {
+                if is_time_of_day(right) {
+                    Self::duration(a.checked_sub(b)?)
+                } else {
+                    Self::time_of_day(a.checked_sub(b)?)
+                }
+            }
+            (Some(a), BinaryOp::Add, None) if is_time_of_day(left) => {
+                Self::time_of_day(a.checked_add(duration_seconds(right)?)?)
+            }
+            (None, BinaryOp::Add, Some(b)) if is_time_of_day(right) => {
+                Self::time_of_day(duration_seconds(left)?.checked_add(b)?)
+            }
+            (Some(a), BinaryOp::Subtract, None) if is_time_of_day(left) => {
+                Self::time_of_day(a.checked_sub(duration_seconds(right)?)?)
+            }
+            _ => return None,
+        };
+        Some(Ok(result))
+    }
+
+    /// Returns the time worked from the clock time `start` to `end`, taken
+    /// to be on the next day when it is earlier: `22:00` to `6:00` is 8
+    /// hours.
+    pub fn time_span(start: &Self, end: &Self) -> Result<Self, CalculatorError> {
+        let clock = |value: &Self| match &value.kind {
+            ValueKind::TimeOfDay { seconds } => Some(seconds.rem_euclid(DAY_SECONDS)),
+            ValueKind::DateTime(dt) => clock_seconds(dt),
+            _ => None,
+        };
+        match (clock(start), clock(end)) {
+            (Some(start), Some(end)) => Ok(Self::duration((end - start).rem_euclid(DAY_SECONDS))),
+            _ => Err(CalculatorError::invalid_args(
+                "time_span",
+                format!(
+                    "expected two clock times, got {} and {}",
+                    start.type_name(),
+                    end.type_name()
+                ),
+            )),
+        }
+    }
+}
+
+/// Returns the seconds since midnight of a clock time written without a
+/// date or a named timezone, on the wall clock it was written in.
+fn clock_seconds(dt: &DateTime) -> Option<i64> {
+    if !dt.has_time() || dt.has_date() || dt.timezone_abbreviation().is_some() {
+        return None;
+    }
+    let offset = Duration::seconds(i64::from(dt.offset_seconds().unwrap_or(0)));
+    let wall_clock = dt.inner_utc().naive_utc() + offset;
+    Some(i64::from(wall_clock.num_seconds_from_midnight()))
+}
+
+/// Returns the length of a duration of a fixed number of seconds, such as
+/// `45 minutes`, in whole seconds.
+fn duration_seconds(value: &Value) -> Option<i64> {
+    match (&value.kind, &value.unit) {
+        (ValueKind::Duration { seconds, .. }, _) => Some(*seconds),
+        (_, Unit::Duration(unit))
+            if !matches!(unit, DurationUnit::Months | DurationUnit::Years) =>
+        {
+            let seconds = value.to_rational()? * duration_unit_seconds(*unit);
+            i64::try_from(seconds.round_to_places(0).numer()).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Writes a time of day as `17:15`, with the seconds when there are any,
+/// and the days it has passed into: `01:30 (+1 day)`.
+pub(super) fn format_time_of_day(seconds: i64) -> String {
+    let days = seconds.div_euclid(DAY_SECONDS);
+    let time = seconds.rem_euclid(DAY_SECONDS);
+    let (hours, minutes, rest) = (time / 3600, time % 3600 / 60, time % 60);
+    let clock = if rest == 0 {
+        format!("{hours:02}:{minutes:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{rest:02}")
+    };
+    match days {
+        0 => clock,
+        1 | -1 => format!("{clock} ({days:+} day)"),
+        _ => format!("{clock} ({days:+} days)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_time_of_day() {
+        assert_eq!(format_time_of_day(17 * 3600 + 15 * 60), "17:15");
+        assert_eq!(format_time_of_day(9 * 3600 + 5), "09:00:05");
+        assert_eq!(format_time_of_day(DAY_SECONDS + 5400), "01:30 (+1 day)");
+        assert_eq!(format_time_of_day(-3600), "23:00 (-1 day)");
+        assert_eq!(format_time_of_day(2 * DAY_SECONDS), "00:00 (+2 days)");
+    }
+}
@@ -0,0 +1,302 @@
+//! Cities and the time zones they keep, for world-clock expressions such as
+//! `time in Tokyo when 9am in New York`.
+//!
+//! The table is embedded rather than read from the tz database: each city
+//! has its standard offset from UTC and, where it changes clocks, the rule
+//! it changes them by. That covers the present-day rules of the cities
+//! listed, not their history.
+
+use chrono::{
+    DateTime as ChronoDateTime, Datelike, Duration, FixedOffset, NaiveDate, Offset, Utc, Weekday,
+};
+
+use crate::types::DateTime;
+
+/// A rule for changing the clocks to daylight saving time and back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaylightSaving {
+    /// The second Sunday of March to the first Sunday of November, at 2:00
+    /// local time.
+    UnitedStates,
+    /// The last Sunday of March to the last Sunday of October, at 1:00 UTC.
+    Europe,
+    /// The first Sunday of October to the first Sunday of April, at 2:00
+    /// local standard time.
+    Australia,
+    /// The last Sunday of September to the first Sunday of April, at 2:00
+    /// local standard time.
+    NewZealand,
+}
+
+/// Which Sunday of a month a clock change falls on.
+#[derive(Debug, Clone, Copy)]
+enum Sunday {
+    First,
+    Second,
+    Last,
+}
+
+impl DaylightSaving {
+    /// Returns the instants in `year` the clocks go forward and back, for a
+    /// zone `standard` seconds east of UTC.
+    fn changes(
+        self,
+        year: i32,
+        standard: i32,
+    ) -> Option<(ChronoDateTime<Utc>, ChronoDateTime<Utc>)> {
+        // 2:00 local standard time, in UTC
+        let local = |month, sunday| change_at(year, month, sunday, 2 * 3600 - standard);
+        let utc = |month, sunday| change_at(year, month, sunday, 3600);
+        Some(match self {
+            // back at 2:00 daylight time, 1:00 standard time
+            Self::UnitedStates => (
+                local(3, Sunday::Second)?,
+                change_at(year, 11, Sunday::First, 3600 - standard)?,
+            ),
+            Self::Europe => (utc(3, Sunday::Last)?, utc(10, Sunday::Last)?),
+            Self::Australia => (local(10, Sunday::First)?, local(4, Sunday::First)?),
+            Self::NewZealand => (local(9, Sunday::Last)?, local(4, Sunday::First)?),
+        })
+    }
+}
+
+/// Returns the instant `seconds` after midnight UTC of a Sunday of a month.
+fn change_at(year: i32, month: u32, sunday: Sunday, seconds: i32) -> Option<ChronoDateTime<Utc>> {
+    let date = match sunday {
+        Sunday::First => NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, 1)?,
+        Sunday::Second => NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, 2)?,
+        Sunday::Last => {
+            let next_month = if month == 12 {
+                NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+            } else {
+                NaiveDate::from_ymd_opt(year, month + 1, 1)?
+            };
+            let last_day = next_month.pred_opt()?;
+            let back = last_day.weekday().num_days_from_sunday();
+            last_day - Duration::days(i64::from(back))
+        }
+    };
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc() + Duration::seconds(i64::from(seconds)))
+}
+
+/// A city and the time zone it keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct City {
+    names: &'static [&'static str],
+    /// Standard offset from UTC in minutes.
+    standard_minutes: i32,
+    standard: &'static str,
+    daylight: Option<(&'static str, DaylightSaving)>,
+}
+
+const fn city(
+    names: &'static [&'static str],
+    standard_minutes: i32,
+    standard: &'static str,
+) -> City {
+    City {
+        names,
+        standard_minutes,
+        standard,
+        daylight: None,
+    }
+}
+
+const fn dst(
+    names: &'static [&'static str],
+    standard_minutes: i32,
+    standard: &'static str,
+    daylight: &'static str,
+    rule: DaylightSaving,
+) -> City {
+    City {
+        names,
+        standard_minutes,
+        standard,
+        daylight: Some((daylight, rule)),
+    }
+}
+
+// The rules as the table below writes them
+const US: DaylightSaving = DaylightSaving::UnitedStates;
+const EU: DaylightSaving = DaylightSaving::Europe;
+const AU: DaylightSaving = DaylightSaving::Australia;
+const NZ: DaylightSaving = DaylightSaving::NewZealand;
+
+/// The cities known by name, with their other names and spellings after
+/// the one they are shown with.
+const CITIES: &[City] = &[
+    // North America
+    dst(&["New York", "NYC"], -300, "EST", "EDT", US),
+    dst(&["Washington"], -300, "EST", "EDT", US),
+    dst(&["Boston"], -300, "EST", "EDT", US),
+    dst(&["Miami"], -300, "EST", "EDT", US),
+    dst(&["Toronto"], -300, "EST", "EDT", US),
+    dst(&["Chicago"], -360, "CST", "CDT", US),
+    dst(&["Denver"], -420, "MST", "MDT", US),
+    city(&["Phoenix"], -420, "MST"),
+    dst(&["Los Angeles"], -480, "PST", "PDT", US),
+    dst(&["San Francisco"], -480, "PST", "PDT", US),
+    dst(&["Seattle"], -480, "PST", "PDT", US),
+    dst(&["Vancouver"], -480, "PST", "PDT", US),
+    dst(&["Anchorage"], -540, "AKST", "AKDT", US),
+    city(&["Honolulu"], -600, "HST"),
+    city(&["Mexico City"], -360, "CST"),
+    // South America
+    city(&["São Paulo", "Sao Paulo"], -180, "BRT"),
+    city(&["Rio de Janeiro", "Rio"], -180, "BRT"),
+    city(&["Buenos Aires"], -180, "ART"),
+    // Europe
+    dst(&["London"], 0, "GMT", "BST", EU),
+    dst(&["Lisbon"], 0, "WET", "WEST", EU),
+    dst(&["Paris"], 60, "CET", "CEST", EU),
+    dst(&["Berlin"], 60, "CET", "CEST", EU),
+    dst(&["Madrid"], 60, "CET", "CEST", EU),
+    dst(&["Rome"], 60, "CET", "CEST", EU),
+    dst(&["Amsterdam"], 60, "CET", "CEST", EU),
+    dst(&["Brussels"], 60, "CET", "CEST", EU),
+    dst(&["Vienna"], 60, "CET", "CEST", EU),
+    dst(&["Zurich"], 60, "CET", "CEST", EU),
+    dst(&["Stockholm"], 60, "CET", "CEST", EU),
+    dst(&["Warsaw"], 60, "CET", "CEST", EU),
+    dst(&["Prague"], 60, "CET", "CEST", EU),
+    dst(&["Athens"], 120, "EET", "EEST", EU),
+    dst(&["Helsinki"], 120, "EET", "EEST", EU),
+    dst(&["Kyiv", "Kiev"], 120, "EET", "EEST", EU),
+    city(&["Istanbul"], 180, "TRT"),
+    city(&["Moscow", "Москва"], 180, "MSK"),
+    city(&["Saint Petersburg", "St Petersburg"], 180, "MSK"),
+    // Africa and the Middle East
+    city(&["Lagos"], 60, "WAT"),
+    city(&["Johannesburg"], 120, "SAST"),
+    city(&["Cape Town"], 120, "SAST"),
+    city(&["Nairobi"], 180, "EAT"),
+    city(&["Tehran"], 210, "IRST"),
+    city(&["Dubai"], 240, "GST"),
+    // Asia
+    city(&["Karachi"], 300, "PKT"),
+    city(&["Mumbai", "Bombay"], 330, "IST"),
+    city(&["Delhi", "New Delhi"], 330, "IST"),
+    city(&["Kolkata", "Calcutta"], 330, "IST"),
+    city(&["Bangalore", "Bengaluru"], 330, "IST"),
+    city(&["Kathmandu"], 345, "NPT"),
+    city(&["Novosibirsk"], 420, "NOVT"),
+    city(&["Bangkok"], 420, "ICT"),
+    city(&["Jakarta"], 420, "WIB"),
+    city(&["Singapore"], 480, "SGT"),
+    city(&["Hong Kong"], 480, "HKT"),
+    city(&["Beijing", "Peking"], 480, "CST"),
+    city(&["Shanghai"], 480, "CST"),
+    city(&["Taipei"], 480, "CST"),
+    city(&["Manila"], 480, "PHT"),
+    city(&["Seoul"], 540, "KST"),
+    city(&["Tokyo"], 540, "JST"),
+    city(&["Vladivostok"], 600, "VLAT"),
+    // Oceania
+    city(&["Perth"], 480, "AWST"),
+    dst(&["Adelaide"], 570, "ACST", "ACDT", AU),
+    city(&["Brisbane"], 600, "AEST"),
+    dst(&["Sydney"], 600, "AEST", "AEDT", AU),
+    dst(&["Melbourne"], 600, "AEST", "AEDT", AU),
+    dst(&["Canberra"], 600, "AEST", "AEDT", AU),
+    dst(&["Auckland"], 720, "NZST", "NZDT", NZ),
+    dst(&["Wellington"], 720, "NZST", "NZDT", NZ),
+];
+
+/// The most words a city name is written with: `Rio de Janeiro`.
+pub const MAX_CITY_WORDS: usize = 3;
+
+impl City {
+    /// Finds a city by name, ignoring case: `new york`, `Tokyo`.
+    #[must_use]
+    pub fn find(name: &str) -> Option<Self> {
+        let name = name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        CITIES
+            .iter()
+            .find(|city| city.names.iter().any(|known| known.to_lowercase() == name))
+            .copied()
+    }
+
+    /// Returns the name the city is shown with.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.names[0]
+    }
+
+    /// Returns the offset from UTC the city's clocks show at `instant`.
+    #[must_use]
+    pub fn offset_at(&self, instant: ChronoDateTime<Utc>) -> FixedOffset {
+        let minutes = if self.is_daylight_saving(instant) {
+            self.standard_minutes + 60
+        } else {
+            self.standard_minutes
+        };
+        FixedOffset::east_opt(minutes * 60).unwrap_or_else(|| Utc.fix())
+    }
+
+    /// Returns the abbreviation of the city's time zone at `instant`:
+    /// `EST` or `EDT` for New York.
+    #[must_use]
+    pub fn abbreviation_at(&self, instant: ChronoDateTime<Utc>) -> &'static str {
+        match self.daylight {
+            Some((daylight, _)) if self.is_daylight_saving(instant) => daylight,
+            _ => self.standard,
+        }
+    }
+
+    /// Reads a time written without a time zone, such as the `9am` of `9am
+    /// in New York`, as the time on the city's clocks.
+    #[must_use]
+    pub fn local_time(&self, time: &DateTime) -> DateTime {
+        let instant = time.inner_utc() - Duration::minutes(i64::from(self.standard_minutes));
+        let offset = self.offset_at(instant);
+        time.reinterpret_naive_as_local(offset.local_minus_utc())
+            .with_timezone_offset(offset, self.abbreviation_at(instant))
+    }
+
+    fn is_daylight_saving(&self, instant: ChronoDateTime<Utc>) -> bool {
+        let Some((_, rule)) = self.daylight else {
+            return false;
+        };
+        let Some((forward, back)) = rule.changes(instant.year(), self.standard_minutes * 60) else {
+            return false;
+        };
+        if forward < back {
+            forward <= instant && instant < back
+        } else {
+            // Southern hemisphere: summer spans the new year
+            instant >= forward || instant < back
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_daylight_saving() {
+        let new_york = City::find("new york").unwrap();
+        let winter = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2026, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(new_york.offset_at(winter).local_minus_utc(), -5 * 3600);
+        assert_eq!(new_york.offset_at(summer).local_minus_utc(), -4 * 3600);
+        assert_eq!(new_york.abbreviation_at(summer), "EDT");
+        // 8 March 2026, 2:00 EST
+        let forward = Utc.with_ymd_and_hms(2026, 3, 8, 7, 0, 0).unwrap();
+        assert_eq!(
+            new_york.abbreviation_at(forward - Duration::seconds(1)),
+            "EST"
+        );
+        assert_eq!(new_york.abbreviation_at(forward), "EDT");
+        let sydney = City::find("Sydney").unwrap();
+        assert_eq!(sydney.abbreviation_at(winter), "AEDT");
+        assert_eq!(sydney.abbreviation_at(summer), "AEST");
+        assert_eq!(City::find("Atlantis"), None);
+    }
+}
@@ -13,8 +13,8 @@ pub fn generate_issue_link(input: &str, error: &str) -> String {
     );
     format!(
         "https://github.com/link-assistant/calculator/issues/new?title={}&body={}",
-        urlencoding_encode(&title),
-        urlencoding_encode(&body)
+        url_encode(&title),
+        url_encode(&body)
     )
 }
 
@@ -25,23 +25,74 @@ pub fn truncate(s: &str, max_chars: usize) -> &str {
     }
 }
 
-fn urlencoding_encode(s: &str) -> String {
+/// Percent-encodes text for a URL query value: every UTF-8 byte other than
+/// letters, digits and `-_.~` becomes `%XX`, so a space is `%20`.
+#[must_use]
+pub fn url_encode(s: &str) -> String {
     let mut result = String::new();
-    for c in s.chars() {
-        match c {
-            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => result.push(c),
-            ' ' => result.push_str("%20"),
-            '\n' => result.push_str("%0A"),
-            _ => {
-                for byte in c.to_string().as_bytes() {
-                    result.push_str(&format!("%{byte:02X}"));
-                }
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(char::from(byte));
             }
+            _ => result.push_str(&format!("%{byte:02X}")),
         }
     }
     result
 }
 
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes in standard base64 with `=` padding.
+#[must_use]
+pub fn base64_encode(bytes: &[u8]) -> String {
+    let mut result = String::new();
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3F;
+                result.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+/// Decodes standard base64, with or without `=` padding, ignoring
+/// whitespace. Returns `None` if the input is not base64.
+#[must_use]
+pub fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = s
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .collect();
+    let digits = digits
+        .strip_suffix(b"==")
+        .or_else(|| digits.strip_suffix(b"="))
+        .unwrap_or(&digits);
+    if digits.len() % 4 == 1 {
+        return None;
+    }
+    let mut result = Vec::new();
+    for chunk in digits.chunks(4) {
+        let mut group = 0u32;
+        for (i, &digit) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|&c| c == digit)?;
+            group |= u32::try_from(value).ok()? << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            result.push(group.to_be_bytes()[i + 1]);
+        }
+    }
+    Some(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,6 +104,29 @@ mod tests {
         assert!(link.contains("issues/new"));
     }
 
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(url_encode("a b&c"), "a%20b%26c");
+        assert_eq!(url_encode("line\nбук"), "line%0A%D0%B1%D1%83%D0%BA");
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        for (text, encoded) in [
+            ("", ""),
+            ("h", "aA=="),
+            ("he", "aGU="),
+            ("hello", "aGVsbG8="),
+            ("привет", "0L/RgNC40LLQtdGC"),
+        ] {
+            assert_eq!(base64_encode(text.as_bytes()), encoded);
+            assert_eq!(base64_decode(encoded).unwrap(), text.as_bytes());
+        }
+        assert_eq!(base64_decode("aGVsbG8").unwrap(), b"hello");
+        assert_eq!(base64_decode("a*=="), None);
+        assert_eq!(base64_decode("aGVsb"), None);
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(truncate("hello world", 5), "hello");
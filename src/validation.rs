@@ -0,0 +1,116 @@
+//! Parse-only validation on the [`Calculator`], for showing errors as the
+//! user types without paying for a full evaluation.
+
+use crate::error::{CalculatorError, ErrorInfo, ErrorSpan};
+use crate::Calculator;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// A problem found while validating an input.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Diagnostic {
+    /// The error message (raw text).
+    pub message: String,
+    /// Error information for i18n support.
+    pub error_info: ErrorInfo,
+    /// The part of the input the problem is in, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<ErrorSpan>,
+}
+
+impl From<&CalculatorError> for Diagnostic {
+    fn from(error: &CalculatorError) -> Self {
+        Self {
+            message: error.to_string(),
+            error_info: error.to_error_info(),
+            span: error.span(),
+        }
+    }
+}
+
+/// The outcome of [`Calculator::format_internal`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FormatResult {
+    /// The input in canonical form, if it parses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+    /// Problems found; empty when the input parses.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The outcome of [`Calculator::validate_internal`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ValidationResult {
+    /// Whether the input parses.
+    pub valid: bool,
+    /// Problems found; empty when the input is valid.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Calculator {
+    /// Checks an expression without evaluating it, returning a JSON
+    /// [`ValidationResult`].
+    ///
+    /// Only the lexer and parser run: nothing is evaluated, no exchange rates
+    /// are looked up and the history is untouched, so this is cheap enough to
+    /// call on every keystroke.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn validate(&self, input: &str) -> String {
+        let result = self.validate_internal(input);
+        serde_json::to_string(&result).unwrap_or_else(|e| {
+            format!(
+                r#"{{"valid":false,"diagnostics":[],"error":"Serialization error: {}"}}"#,
+                e
+            )
+        })
+    }
+
+    /// Re-emits an expression in canonical form without evaluating it,
+    /// returning a JSON [`FormatResult`]: a "did we understand you
+    /// correctly?" echo of the input, such as `(2 + 3) * 4` for `((2+3))*4`.
+    #[cfg_attr(feature = "wasm", wasm_bindgen)]
+    pub fn format(&self, input: &str) -> String {
+        let result = self.format_internal(input);
+        serde_json::to_string(&result).unwrap_or_else(|e| {
+            format!(
+                r#"{{"diagnostics":[],"error":"Serialization error: {}"}}"#,
+                e
+            )
+        })
+    }
+}
+
+impl Calculator {
+    /// Internal validation method — parses the expression and reports problems.
+    pub fn validate_internal(&self, input: &str) -> ValidationResult {
+        match self.parser.validate(input) {
+            Ok(()) => ValidationResult {
+                valid: true,
+                diagnostics: Vec::new(),
+            },
+            Err(e) => ValidationResult {
+                valid: false,
+                diagnostics: vec![Diagnostic::from(&e)],
+            },
+        }
+    }
+}
+
+impl Calculator {
+    /// Internal formatting method — parses the expression and re-emits it
+    /// with [`crate::types::Expression::to_canonical_string`].
+    pub fn format_internal(&self, input: &str) -> FormatResult {
+        let leading_whitespace = input.chars().take_while(|c| c.is_whitespace()).count();
+        match self.parser.parse(input.trim()) {
+            Ok(expr) => FormatResult {
+                formatted: Some(expr.to_canonical_string()),
+                diagnostics: Vec::new(),
+            },
+            Err(e) => FormatResult {
+                formatted: None,
+                diagnostics: vec![Diagnostic::from(&e.offset_span(leading_whitespace))],
+            },
+        }
+    }
+}
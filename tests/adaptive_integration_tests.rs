@@ -0,0 +1,92 @@
+//! Tests for adaptive numerical integration: singular integrands, infinite
+//! bounds, the tolerance setting, and the error estimate in the steps.
+
+use link_calculator::Calculator;
+
+fn integral(calculator: &Calculator, input: &str) -> f64 {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.result.parse().unwrap()
+}
+
+#[test]
+fn test_endpoint_singularity() {
+    let calculator = Calculator::new();
+    let value = integral(&calculator, "integrate(1/sqrt(x), x, 0, 1)");
+    assert!((value - 2.0).abs() < 1e-8, "{value}");
+}
+
+#[test]
+fn test_removable_singularity_inside_the_range() {
+    let calculator = Calculator::new();
+    let value = integral(&calculator, "integrate(sin(x)/x, x, -1, 1)");
+    assert!((value - 1.892_166_140_734_366).abs() < 1e-9, "{value}");
+}
+
+#[test]
+fn test_infinite_bounds() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator
+            .calculate_internal("integrate(exp(-x), x, 0, inf)")
+            .result,
+        "1"
+    );
+    let value = integral(&calculator, "integrate(1/x^2, x, -infinity, -1)");
+    assert!((value - 1.0).abs() < 1e-9, "{value}");
+    let value = integral(&calculator, "integrate(exp(-(x^2)), x, -inf, inf)");
+    assert!(
+        (value - std::f64::consts::PI.sqrt()).abs() < 1e-9,
+        "{value}"
+    );
+}
+
+#[test]
+fn test_reversed_bounds() {
+    let calculator = Calculator::new();
+    let value = integral(&calculator, "integrate(exp(-x), x, inf, 0)");
+    assert!((value + 1.0).abs() < 1e-9, "{value}");
+}
+
+#[test]
+fn test_tolerance_is_configurable() {
+    let mut calculator = Calculator::new();
+    calculator.set_integration_tolerance(1e-3);
+    let loose = integral(&calculator, "integrate(sqrt(x), x, 0, 1)");
+    calculator.set_integration_tolerance(1e-12);
+    let tight = integral(&calculator, "integrate(sqrt(x), x, 0, 1)");
+    assert!((tight - 2.0 / 3.0).abs() < 1e-11, "{tight}");
+    assert!((tight - 2.0 / 3.0).abs() <= (loose - 2.0 / 3.0).abs());
+}
+
+#[test]
+fn test_steps_report_the_error_estimate() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("integrate(x^2, x, 0, 3)");
+    assert_eq!(result.result, "9");
+    let estimate = result
+        .steps
+        .iter()
+        .find(|step| step.starts_with("Error estimate: ±"))
+        .expect("error estimate step");
+    assert!(estimate.ends_with("(subintervals: 1)"), "{estimate}");
+}
+
+#[test]
+fn test_divergent_integral_fails() {
+    let calculator = Calculator::new();
+    assert!(
+        !calculator
+            .calculate_internal("integrate(sin(x), x, 0, inf)")
+            .success
+    );
+}
+
+#[test]
+fn test_bounds_past_a_decimal_are_out_of_range() {
+    let error = Calculator::new()
+        .calculate_internal("integrate(x, x, 0, 10^30)")
+        .error
+        .unwrap_or_default();
+    assert!(error.contains("upper bound is too large"), "{error}");
+}
@@ -0,0 +1,65 @@
+//! Tests for calendar-aware spans: `age(date)` and `time until <date>`.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_age_on_a_given_date() {
+    assert_eq!(
+        calculate("age(15 June 1990, 1 Jan 2026)"),
+        "35 years, 6 months, 17 days"
+    );
+    assert_eq!(calculate("age(15 June 1990, 15 June 2025)"), "35 years");
+    assert_eq!(calculate("age(Jan 31 2024, Mar 1 2024)"), "1 month, 1 day");
+}
+
+#[test]
+fn test_age_defaults_to_today() {
+    assert_eq!(calculate("age(today - 2 years)"), "2 years");
+    assert_eq!(calculate("age(today)"), "0 days");
+
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("age(15 June 1990)");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert!(result.result.contains("years"), "got: {}", result.result);
+    assert_eq!(result.lino_interpretation, "(age ((1990-06-15) (today)))");
+}
+
+#[test]
+fn test_age_before_the_date_is_negative() {
+    assert_eq!(
+        calculate("age(1 Jan 2026, 15 June 1990)"),
+        "-35 years, 6 months, 17 days"
+    );
+}
+
+#[test]
+fn test_age_converts_to_duration_units() {
+    assert_eq!(
+        calculate("age(15 June 1990, 15 Dec 2025) in years"),
+        "35.5 years"
+    );
+    assert_eq!(calculate("age(1 Jan 2025, 1 Jan 2026) in days"), "365 days");
+}
+
+#[test]
+fn test_time_until_uses_calendar_months() {
+    assert_eq!(calculate("time until (today + 1 year)"), "1 year");
+    assert_eq!(calculate("time until (now + 1 month)"), "1 month");
+    assert_eq!(calculate("time until (now + 3 hours)"), "3 hours");
+
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("time until 1 Jan 2030");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert!(result.result.contains("years"), "got: {}", result.result);
+}
+
+#[test]
+fn test_calendar_functions_require_dates() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("age(5)");
+    assert!(!result.success);
+    assert!(result.error.unwrap_or_default().contains("expected a date"));
+}
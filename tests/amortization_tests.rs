@@ -0,0 +1,59 @@
+//! Tests for `amortize(principal, rate, term)` and its schedule table.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_amortize_summary_and_table() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("amortize(200000 USD, 3.5%, 30 years)");
+    assert!(result.success, "{:?}", result.error);
+    assert!(
+        result
+            .result
+            .starts_with("898.09 USD per month; total paid "),
+        "{}",
+        result.result
+    );
+
+    let table = result.table.expect("amortize should return a schedule");
+    assert_eq!(
+        table.columns,
+        ["Period", "Payment", "Interest", "Principal", "Balance"]
+    );
+    assert_eq!(table.rows.len(), 360);
+    assert_eq!(
+        table.rows[0],
+        [
+            "1",
+            "898.09 USD",
+            "583.33 USD",
+            "314.76 USD",
+            "199685.24 USD"
+        ]
+    );
+    assert_eq!(table.rows[359][4], "0 USD");
+}
+
+#[test]
+fn test_amortize_evaluates_to_the_payment() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("amortize(1200, 0%, 12 months) * 2");
+    assert_eq!(result.result, "200");
+    assert!(result.table.is_none());
+
+    calculator.calculate_internal("amortize(200000 USD, 3.5%, 30 years)");
+    assert_eq!(calculator.calculate_internal("ans").result, "898.09 USD");
+}
+
+#[test]
+fn test_amortize_rejects_bad_terms() {
+    let calculator = Calculator::new();
+    for input in [
+        "amortize(1000, 5%, 3 days)",
+        "amortize(1000, 5%)",
+        "amortize(-1000, 5%, 1 years)",
+        "amortize(1000, 5 USD, 1 years)",
+    ] {
+        assert!(!calculator.calculate_internal(input).success, "{input}");
+    }
+}
@@ -0,0 +1,93 @@
+//! Tests for angle units and the angle mode of the trigonometric functions.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::Calculator;
+
+#[test]
+fn test_angles_with_a_unit_are_read_in_it() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "sin(30°)"), "0.5");
+    assert_eq!(calculate_with(&calculator, "sin(30 deg)"), "0.5");
+    assert_eq!(calculate_with(&calculator, "cos(90°)"), "0");
+    assert_eq!(calculate_with(&calculator, "sin(100 grad)"), "1");
+    assert_eq!(calculate_with(&calculator, "tan(45 degrees)"), "1");
+    // Plain numbers stay radians by default
+    assert_eq!(
+        calculate_with(&calculator, "sin(30)"),
+        "-0.9880316240928618"
+    );
+}
+
+#[test]
+fn test_angles_convert_between_units() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "30°"), "30°");
+    assert_eq!(calculate_with(&calculator, "100 grad as deg"), "90°");
+    assert_eq!(
+        calculate_with(&calculator, "90 degrees as rad"),
+        "1.570796326794897 rad"
+    );
+    assert_eq!(calculate_with(&calculator, "45° + 45°"), "90°");
+}
+
+#[test]
+fn test_degrees_mode_reads_and_answers_in_degrees() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_angle_mode("degrees"));
+    assert_eq!(calculate_with(&calculator, "sin(30)"), "0.5");
+    assert_eq!(calculate_with(&calculator, "tan(45)"), "1");
+    assert_eq!(calculate_with(&calculator, "asin(0.5)"), "30");
+    assert_eq!(calculate_with(&calculator, "acos(-1)"), "180");
+    assert_eq!(calculate_with(&calculator, "atan2(1, 1)"), "45");
+    assert_eq!(calculate_with(&calculator, "sin(asin(0.5))"), "0.5");
+    // A written unit wins over the mode
+    assert_eq!(
+        calculate_with(&calculator, "sin(1 rad)"),
+        "0.8414709848078965"
+    );
+    assert!(!calculator.calculate_internal("tan(90)").success);
+}
+
+#[test]
+fn test_gradians_mode() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_angle_mode("gon"));
+    assert_eq!(calculate_with(&calculator, "sin(100)"), "1");
+    assert_eq!(calculate_with(&calculator, "acos(0)"), "100");
+    assert!(!calculator.set_angle_mode("turns"));
+    assert_eq!(calculate_with(&calculator, "sin(100)"), "1");
+}
+
+#[test]
+fn test_the_mode_is_shown_in_the_steps() {
+    let mut calculator = Calculator::new();
+    calculator.set_angle_mode("degrees");
+    let steps = calculator.calculate_internal("sin(30)").steps;
+    assert!(
+        steps.contains(&"Angle mode degrees: 30 is 30° = 0.5235987755982988 rad".to_string()),
+        "{steps:?}"
+    );
+    let steps = calculator.calculate_internal("asin(1)").steps;
+    assert!(
+        steps.iter().any(|step| step.ends_with("rad is 90°")),
+        "{steps:?}"
+    );
+    let steps = calculator.calculate_internal("sin(90°)").steps;
+    assert!(
+        steps.contains(&"Convert 90° to radians: 1.570796326794897 rad".to_string()),
+        "{steps:?}"
+    );
+}
+
+#[test]
+fn test_calculus_follows_the_mode() {
+    let mut calculator = Calculator::new();
+    calculator.set_angle_mode("degrees");
+    // The integral of sin(x°) from 0° to 90° is 180/π
+    let integral: f64 = calculate_with(&calculator, "integrate(sin(x), x, 0, 90)")
+        .parse()
+        .unwrap();
+    assert!((integral - 180.0 / std::f64::consts::PI).abs() < 1e-9);
+}
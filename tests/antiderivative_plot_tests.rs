@@ -0,0 +1,66 @@
+//! Tests for the antiderivative plotted beside the integrand of an
+//! indefinite integral, integrated numerically from a reference point.
+
+use link_calculator::{Calculator, PlotData};
+
+fn plots(input: &str) -> Vec<PlotData> {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.plots.expect("plots")
+}
+
+/// The y-value at the sample closest to `x`.
+fn y_at(plot: &PlotData, x: f64) -> f64 {
+    let (i, _) = plot
+        .x_values
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+        .unwrap();
+    plot.y_values[i]
+}
+
+#[test]
+fn test_special_function_results_plot_their_antiderivative() {
+    let plots = plots("integrate sin(x)/x dx");
+    assert_eq!(plots.len(), 2);
+    assert_eq!(plots[0].label, "sin(x) / x");
+    let si = &plots[1];
+    assert_eq!(si.x_values, plots[0].x_values);
+    assert_eq!(si.y_label, "F(x)");
+    assert!(si.label.ends_with("from 0"), "{}", si.label);
+    assert!(y_at(si, 0.0).abs() < f64::EPSILON);
+    // Si(2) = 1.6054129768...
+    let x = si.x_values[si.x_values.iter().position(|x| *x >= 2.0).unwrap()];
+    let expected = (x - 2.0).mul_add(2.0_f64.sin() / 2.0, 1.605_412_976_802_695);
+    assert!((y_at(si, x) - expected).abs() < 1e-3, "{}", y_at(si, x));
+    // Si is odd
+    assert!((y_at(si, x) + y_at(si, -x)).abs() < 1e-6);
+}
+
+#[test]
+fn test_antiderivative_matches_the_closed_form() {
+    let plots = plots("integrate cos(x) dx");
+    for (x, y) in plots[1].x_values.iter().zip(&plots[1].y_values) {
+        assert!((y - x.sin()).abs() < 1e-6, "F({x}) = {y}");
+    }
+}
+
+#[test]
+fn test_antiderivative_stops_at_a_pole() {
+    let plots = plots("integrate 1/x dx");
+    let antiderivative = &plots[1];
+    assert!(antiderivative.y_values.iter().any(|y| y.is_finite()));
+    let positive = antiderivative.x_values.iter().any(|x| *x > 0.0);
+    let negative = antiderivative.x_values.iter().any(|x| *x < 0.0);
+    if positive && negative {
+        let finite_sides: Vec<bool> = antiderivative
+            .x_values
+            .iter()
+            .zip(&antiderivative.y_values)
+            .filter(|(_, y)| y.is_finite())
+            .map(|(x, _)| *x > 0.0)
+            .collect();
+        assert!(finite_sides.iter().all(|side| *side == finite_sides[0]));
+    }
+}
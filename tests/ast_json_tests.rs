@@ -0,0 +1,47 @@
+//! Tests for the opt-in `ast` field of calculation results.
+
+use link_calculator::types::{BinaryOp, Expression};
+use link_calculator::Calculator;
+
+#[test]
+fn test_ast_is_omitted_by_default() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("2 + 3");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert!(result.ast.is_none());
+    assert!(!calculator.execute("2 + 3").contains("\"ast\""));
+}
+
+#[test]
+fn test_ast_is_the_parsed_expression_when_enabled() {
+    let mut calculator = Calculator::new();
+    calculator.set_include_ast(true);
+    let result = calculator.calculate_internal("2 + 3 * 4");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.ast, Some(calculator.parse("2 + 3 * 4").unwrap()));
+    assert!(matches!(
+        result.ast,
+        Some(Expression::Binary {
+            op: BinaryOp::Add,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_ast_is_serialized_as_a_json_tree() {
+    let mut calculator = Calculator::new();
+    calculator.set_include_ast(true);
+    let json: serde_json::Value = serde_json::from_str(&calculator.execute("2 + 3 kg")).unwrap();
+    let ast = &json["ast"]["Binary"];
+    assert_eq!(ast["op"], "Add");
+    assert_eq!(ast["left"]["Number"]["value"], "2");
+    assert_eq!(ast["right"]["Number"]["unit"]["Mass"], "Kilogram");
+}
+
+#[test]
+fn test_failed_calculations_have_no_ast() {
+    let mut calculator = Calculator::new();
+    calculator.set_include_ast(true);
+    assert!(calculator.calculate_internal("1 +").ast.is_none());
+}
@@ -0,0 +1,36 @@
+//! Tests for `tobase` and `frombase`, which convert integers to and from
+//! any base from 2 to 36.
+
+mod common;
+
+use common::{calculate, error};
+
+#[test]
+fn test_tobase_writes_digits() {
+    assert_eq!(calculate("tobase(255, 16)"), "FF");
+    assert_eq!(calculate("tobase(1295, 36)"), "ZZ");
+    assert_eq!(calculate("tobase(10, 2)"), "1010");
+    assert_eq!(calculate("tobase(-42, 7)"), "-60");
+    assert_eq!(calculate("tobase(0, 5)"), "0");
+}
+
+#[test]
+fn test_frombase_reads_digits() {
+    assert_eq!(calculate("frombase(\"zz\", 36)"), "1295");
+    assert_eq!(calculate("frombase(\"FF\", 16)"), "255");
+    assert_eq!(calculate("frombase(\"-60\", 7)"), "-42");
+    assert_eq!(calculate("frombase(1010, 2)"), "10");
+}
+
+#[test]
+fn test_conversions_round_trip_big_integers() {
+    assert_eq!(calculate("frombase(tobase(2^100, 36), 36) - 2^100"), "0");
+}
+
+#[test]
+fn test_invalid_bases_and_digits_are_rejected() {
+    assert!(error("tobase(5, 1)").contains("from 2 to 36"));
+    assert!(error("tobase(5, 37)").contains("from 2 to 36"));
+    assert!(error("tobase(1.5, 2)").contains("only integers"));
+    assert!(error("frombase(\"12\", 2)").contains("not a number in base 2"));
+}
@@ -0,0 +1,66 @@
+//! Tests for steps that show operands normalized to base units.
+
+use link_calculator::{CalculationOptions, Calculator};
+
+fn steps(input: &str, base_unit_steps: bool) -> Vec<String> {
+    let calculator = Calculator::new();
+    let options = CalculationOptions {
+        base_unit_steps,
+        ..CalculationOptions::default()
+    };
+    let result = calculator.calculate_with_options(input, &options);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.steps
+}
+
+#[test]
+fn test_operands_are_normalized() {
+    let steps = steps("5 km + 300 m", true);
+    assert!(
+        steps.contains(&"Normalize to base units: 5 km = 5000 m".to_string()),
+        "{steps:?}"
+    );
+    assert!(
+        !steps.iter().any(|step| step.contains("300 m =")),
+        "300 m is already in base units: {steps:?}"
+    );
+}
+
+#[test]
+fn test_result_and_rates_are_normalized() {
+    let steps = steps("120 km / 2 hours", true);
+    assert!(
+        steps.contains(&"Normalize to base units: 2 hours = 7200 seconds".to_string()),
+        "{steps:?}"
+    );
+    assert!(
+        steps
+            .iter()
+            .any(|step| step.contains("60 km/h =") && step.ends_with("m/s")),
+        "{steps:?}"
+    );
+}
+
+#[test]
+fn test_disabled_by_default() {
+    assert!(!steps("5 km + 300 m", false)
+        .iter()
+        .any(|step| step.starts_with("Normalize")));
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("5 km + 300 m");
+    assert!(!result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Normalize")));
+}
+
+#[test]
+fn test_options_json() {
+    let calculator = Calculator::new();
+    let json = calculator.calculate("5 km + 300 m", Some(r#"{"base_unit_steps": true}"#.into()));
+    assert!(json.contains("Normalize to base units"), "{json}");
+    let json = calculator.calculate("5 km + 300 m", Some("{not json".into()));
+    assert!(json.contains("Invalid calculation options"), "{json}");
+    let json = calculator.calculate("5 km + 300 m", None);
+    assert!(!json.contains("Normalize"), "{json}");
+}
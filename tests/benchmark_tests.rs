@@ -0,0 +1,49 @@
+//! Tests for the benchmark corpus and per-phase timings.
+
+use link_calculator::types::{FixedClock, PhaseTimings, StepsLevel};
+use link_calculator::{CalculationOptions, Calculator};
+
+#[test]
+fn test_every_corpus_case_calculates() {
+    let calculator = Calculator::new();
+    let corpus = Calculator::benchmark_corpus();
+    for case in corpus {
+        let result = calculator.calculate_internal(case.input);
+        assert!(result.success, "{}: {:?}", case.name, result.error);
+    }
+    let mut names: Vec<_> = corpus.iter().map(|case| case.name).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), corpus.len());
+    for category in ["dates", "currencies", "integrals", "plots"] {
+        assert!(
+            corpus.iter().any(|case| case.category == category),
+            "{category}"
+        );
+    }
+}
+
+#[test]
+fn test_corpus_is_available_as_json() {
+    let json: serde_json::Value =
+        serde_json::from_str(&Calculator::get_benchmark_corpus()).unwrap();
+    assert_eq!(json[0]["name"], "fractions");
+    assert_eq!(json[0]["input"], "1/3 + 1/6");
+}
+
+#[test]
+fn test_debug_steps_include_phase_timings() {
+    let mut calculator = Calculator::new();
+    calculator.set_clock(FixedClock::from_rfc3339("2026-03-15T12:30:00Z").unwrap());
+    let debug = CalculationOptions {
+        steps_level: StepsLevel::Debug,
+        ..CalculationOptions::default()
+    };
+    let result = calculator.calculate_with_options("2 + 3", &debug);
+    assert_eq!(result.phase_timings, Some(PhaseTimings::default()));
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.contains(r#""phase_timings":{"lex_ms":0.0,"parse_ms":0.0,"eval_ms":0.0}"#));
+
+    let result = calculator.calculate_internal("2 + 3");
+    assert_eq!(result.phase_timings, None);
+}
@@ -0,0 +1,116 @@
+//! Tests for evaluating a parsed expression many times with different
+//! values bound to its variables.
+
+use link_calculator::types::{BinaryOp, Expression, LengthUnit, Unit, Value};
+use link_calculator::Calculator;
+
+fn meters(n: i64) -> Value {
+    Value::from_integer_with_unit(n, Unit::Length(LengthUnit::Meter))
+}
+
+#[test]
+fn test_one_parse_serves_many_evaluations() {
+    let calculator = Calculator::new();
+    let expr = calculator.parse("x^2 + 3*x").unwrap();
+    let results: Vec<String> = (0..4)
+        .map(|x| {
+            calculator
+                .evaluate_with_bindings(&expr, &[("x", Value::from_integer(x))])
+                .unwrap()
+                .to_display_string()
+        })
+        .collect();
+    assert_eq!(results, ["0", "4", "10", "18"]);
+}
+
+#[test]
+fn test_bound_values_keep_their_units() {
+    let calculator = Calculator::new();
+    let expr = calculator.parse("x * y + 1 m^2").unwrap();
+    let area = calculator
+        .evaluate_with_bindings(&expr, &[("x", meters(2)), ("y", meters(3))])
+        .unwrap();
+    assert_eq!(area.to_display_string(), "7 m²");
+}
+
+#[test]
+fn test_bindings_reach_functions_and_named_variables() {
+    let calculator = Calculator::new();
+    let expr = calculator.parse("sqrt(a) + max(a, b)").unwrap();
+    let value = calculator
+        .evaluate_with_bindings(
+            &expr,
+            &[
+                ("a", Value::from_integer(16)),
+                ("b", Value::from_integer(20)),
+            ],
+        )
+        .unwrap();
+    assert_eq!(value.to_display_string(), "24");
+
+    let rate = Expression::binary(
+        Expression::variable("rate"),
+        BinaryOp::Multiply,
+        Expression::number(12),
+    );
+    let value = calculator
+        .evaluate_with_bindings(&rate, &[("rate", Value::from_integer(5))])
+        .unwrap();
+    assert_eq!(value.to_display_string(), "60");
+}
+
+#[test]
+fn test_unbound_variables_are_errors_and_bindings_do_not_linger() {
+    let calculator = Calculator::new();
+    let expr = calculator.parse("x + y").unwrap();
+    let error = calculator
+        .evaluate_with_bindings(&expr, &[("x", Value::from_integer(1))])
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("undefined variable: y"),
+        "{error}"
+    );
+
+    calculator
+        .evaluate_with_bindings(
+            &expr,
+            &[("x", Value::from_integer(1)), ("y", Value::from_integer(2))],
+        )
+        .unwrap();
+    assert!(calculator.evaluate(&expr).is_err());
+}
+
+#[test]
+fn test_bindings_reach_integrands() {
+    let calculator = Calculator::new();
+    let expr = calculator.parse("integrate(k * x, x, 0, 2)").unwrap();
+    let value = calculator
+        .evaluate_with_bindings(&expr, &[("k", Value::from_integer(3))])
+        .unwrap();
+    assert_eq!(value.to_display_string(), "6");
+}
+
+#[test]
+fn test_numbers_written_against_a_bound_name_multiply_it() {
+    let calculator = Calculator::new();
+    let evaluate = |input: &str, name: &str, value: i64| {
+        let expr = calculator.parse(input).unwrap();
+        calculator
+            .evaluate_with_bindings(&expr, &[(name, Value::from_integer(value))])
+            .unwrap()
+            .to_display_string()
+    };
+    assert_eq!(evaluate("3x", "x", 2), "6");
+    assert_eq!(evaluate("3x^2", "x", 2), "12");
+    assert_eq!(evaluate("3x^2 + 2x + 1", "x", 2), "17");
+    assert_eq!(evaluate("2y", "y", 5), "10");
+    assert_eq!(evaluate("2y^2", "y", 5), "50");
+    assert_eq!(evaluate("sqrt(2x)", "x", 8), "4");
+
+    // Unbound, the name is still a unit
+    let expr = calculator.parse("2y").unwrap();
+    assert_eq!(
+        calculator.evaluate(&expr).unwrap().to_display_string(),
+        "2 years"
+    );
+}
@@ -0,0 +1,58 @@
+//! Tests for named saved expressions: saving, recalling with the current
+//! rates, and keeping them as a links notation document.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_saved_expressions_are_recalled_by_name() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.save_expression("area", "3 m * 4 m"));
+    let result = calculator.recall("area");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "12 m²");
+    assert_eq!(calculator.history().len(), 1);
+}
+
+#[test]
+fn test_recall_uses_the_current_rates() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.save_expression("salary", "5000 USD as EUR"));
+    calculator.update_rates_from_api("USD", "2026-01-22", r#"{"EUR": 0.5}"#);
+    assert_eq!(calculator.recall("salary").result, "2500 EUR");
+    calculator.update_rates_from_api("USD", "2026-01-23", r#"{"EUR": 0.8}"#);
+    assert_eq!(calculator.recall("salary").result, "4000 EUR");
+}
+
+#[test]
+fn test_invalid_names_and_inputs_are_not_saved() {
+    let mut calculator = Calculator::new();
+    assert!(!calculator.save_expression("two words", "1 + 1"));
+    assert!(!calculator.save_expression("sum", "(1 +"));
+    assert!(calculator.saved_expressions().is_empty());
+
+    let result = calculator.recall("missing");
+    assert!(!result.success);
+    assert!(result.error.unwrap_or_default().contains("missing"));
+}
+
+#[test]
+fn test_saved_expressions_round_trip_through_lino() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.save_expression("greeting", "length(\"hi there\")"));
+    assert!(calculator.save_expression("rent-share", "1200 EUR / 3"));
+    let lino = calculator.saved_expressions_lino();
+    assert_eq!(
+        lino,
+        "(greeting: \"length(\\\"hi there\\\")\")\n(rent-share: \"1200 EUR / 3\")\n"
+    );
+
+    let mut restored = Calculator::new();
+    assert!(restored.load_saved_expressions_lino(&lino));
+    assert_eq!(restored.recall("greeting").result, "8");
+    assert!(!restored.load_saved_expressions_lino("(greeting: 8)"));
+    assert_eq!(restored.saved_expressions().len(), 2);
+
+    assert!(restored.remove_expression("greeting"));
+    assert!(!restored.remove_expression("greeting"));
+    assert_eq!(restored.saved_expressions().len(), 1);
+}
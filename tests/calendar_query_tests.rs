@@ -0,0 +1,63 @@
+//! Tests for calendar questions: week numbers, weekdays, month lengths and
+//! leap years.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_week_number_of_a_date() {
+    assert_eq!(calculate("week number of 17 Feb 2027"), "7");
+    // ISO weeks: 1 January 2027 is in the last week of 2026
+    assert_eq!(calculate("week number of 2027-01-01"), "53");
+}
+
+#[test]
+fn test_days_in_a_month() {
+    assert_eq!(calculate("days in February 2028"), "29");
+    assert_eq!(calculate("days in Feb 2027"), "28");
+    assert_eq!(calculate("days in 2026-04-15"), "30");
+}
+
+#[test]
+fn test_days_in_a_year() {
+    assert_eq!(calculate("days in 2028"), "366");
+    assert_eq!(calculate("days in 2027"), "365");
+}
+
+#[test]
+fn test_leap_years() {
+    assert_eq!(calculate("is 2028 a leap year"), "true");
+    assert_eq!(calculate("is 2100 a leap year"), "false");
+    assert_eq!(calculate("is 2000 leap year"), "true");
+    assert_eq!(calculate("is 17 Feb 2027 a leap year"), "false");
+}
+
+#[test]
+fn test_day_of_week_names() {
+    assert_eq!(calculate("day of week of 2026-05-01"), "Friday");
+    assert_eq!(calculate("day of the week of 17 Feb 2027"), "Wednesday");
+}
+
+#[test]
+fn test_function_forms() {
+    assert_eq!(calculate("week_number(2026-01-05)"), "2");
+    assert_eq!(calculate("day_of_week(2026-05-02)"), "Saturday");
+    assert_eq!(calculate("days_in_month(2026-02-10)"), "28");
+    assert_eq!(calculate("is_leap_year(2024)"), "true");
+}
+
+#[test]
+fn test_answers_are_values() {
+    assert_eq!(calculate("days in February 2028 * 24"), "696");
+    assert_eq!(calculate("week number of 17 Feb 2027 + 1"), "8");
+}
+
+#[test]
+fn test_invalid_arguments_are_errors() {
+    let calc = Calculator::new();
+    assert!(!calc.calculate_internal("day_of_week(5)").success);
+    assert!(!calc.calculate_internal("is_leap_year(2.5)").success);
+    assert!(!calc.calculate_internal("is 2028 a leap").success);
+}
@@ -0,0 +1,66 @@
+//! Tests for cancelling a running calculation with a `CancellationToken`.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use link_calculator::types::CancellationToken;
+use link_calculator::Calculator;
+
+#[test]
+fn test_cancelled_token_stops_evaluation() {
+    let mut calculator = Calculator::new();
+    let token = CancellationToken::new();
+    calculator.set_cancellation_token(Some(token.clone()));
+    token.cancel();
+
+    let result = calculator.calculate_internal("2 + 3");
+    assert!(!result.success);
+    assert_eq!(result.error.as_deref(), Some("Calculation cancelled"));
+    assert_eq!(result.error_info.unwrap().key, "errors.cancelled");
+
+    token.reset();
+    assert_eq!(calculator.calculate_internal("2 + 3").result, "5");
+}
+
+#[test]
+fn test_cancel_from_another_thread_stops_integration() {
+    let mut calculator = Calculator::new();
+    calculator.set_max_integration_subdivisions(50_000_000);
+    calculator.set_integration_tolerance(0.0);
+    let token = CancellationToken::new();
+    calculator.set_cancellation_token(Some(token.clone()));
+
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        token.cancel();
+    });
+    let started = Instant::now();
+    let result = calculator.calculate_internal("integrate(sin(x), x, 0, 3)");
+    canceller.join().unwrap();
+
+    assert!(!result.success);
+    assert_eq!(result.error.as_deref(), Some("Calculation cancelled"));
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[test]
+fn test_cancelled_plot_fails() {
+    let mut calculator = Calculator::new();
+    let token = CancellationToken::new();
+    calculator.set_cancellation_token(Some(token.clone()));
+    token.cancel();
+
+    let result = calculator.calculate_internal("plot(x^2, 0, 1, 100)");
+    assert!(!result.success);
+    assert_eq!(result.error.as_deref(), Some("Calculation cancelled"));
+}
+
+#[test]
+fn test_removing_the_token_stops_cancellation() {
+    let mut calculator = Calculator::new();
+    let token = CancellationToken::new();
+    token.cancel();
+    calculator.set_cancellation_token(Some(token));
+    calculator.set_cancellation_token(None);
+    assert!(calculator.calculate_internal("2 + 3").success);
+}
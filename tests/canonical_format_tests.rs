@@ -0,0 +1,65 @@
+//! Tests for canonical formatting with `Calculator::format` and
+//! `Expression::to_canonical_string`.
+
+use link_calculator::error::ErrorSpan;
+use link_calculator::Calculator;
+
+fn format(input: &str) -> String {
+    let result = Calculator::new().format_internal(input);
+    assert!(
+        result.diagnostics.is_empty(),
+        "{input}: {:?}",
+        result.diagnostics
+    );
+    result.formatted.unwrap()
+}
+
+#[test]
+fn test_normalizes_spacing_and_parentheses() {
+    assert_eq!(format("((2+3))*4"), "(2 + 3) * 4");
+    assert_eq!(format("2+3*4"), "2 + 3 * 4");
+    assert_eq!(format("(2-3)-4"), "2 - 3 - 4");
+    assert_eq!(format("2-(3-4)"), "2 - (3 - 4)");
+    assert_eq!(format("max(1,2,  3)"), "max(1, 2, 3)");
+    assert_eq!(format("[1,2,3]"), "[1, 2, 3]");
+}
+
+#[test]
+fn test_powers_and_signs() {
+    assert_eq!(format("-2^2"), "-2^2");
+    assert_eq!(format("-(2^2)"), "-2^2");
+    assert_eq!(format("(-2)^2"), "(-2)^2");
+    assert_eq!(format("(2^3)^2"), "(2^3)^2");
+    assert_eq!(format("2^3^2"), "2^3^2");
+}
+
+#[test]
+fn test_units_and_comparisons() {
+    assert_eq!(format("2 + 3 USD in EUR"), "2 + 3 USD as EUR");
+    assert_eq!(format("x+1=3*2"), "x + 1 = 3 * 2");
+    assert_eq!(format("(2+3) > 4"), "2 + 3 > 4");
+}
+
+#[test]
+fn test_canonical_form_parses_to_the_same_expression() {
+    let calculator = Calculator::new();
+    for input in [
+        "((2+3))*4",
+        "2-(3-4)",
+        "-(2^2)",
+        "(2^3)^2",
+        "2 * -3",
+        "1/3 to 2 dp",
+    ] {
+        let expr = calculator.parse(input).unwrap();
+        let reparsed = calculator.parse(&expr.to_canonical_string()).unwrap();
+        assert_eq!(reparsed.to_lino(), expr.to_lino(), "{input}");
+    }
+}
+
+#[test]
+fn test_invalid_input_reports_span() {
+    let result = Calculator::new().format_internal("  2 @ 3");
+    assert!(result.formatted.is_none());
+    assert_eq!(result.diagnostics[0].span, Some(ErrorSpan::new(4, 5)));
+}
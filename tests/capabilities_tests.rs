@@ -0,0 +1,246 @@
+//! Tests for `Calculator::capabilities`, the description of the grammar
+//! that help pages and autocomplete are built from.
+
+use link_calculator::{Calculator, Fixity, UnitCategory};
+
+#[test]
+fn test_functions_come_from_the_registry() {
+    let capabilities = Calculator::new().capabilities();
+    assert!(capabilities.functions.iter().any(|f| f.name == "factorial"));
+    assert_eq!(
+        capabilities.functions.len(),
+        link_calculator::FUNCTIONS.len()
+    );
+}
+
+/// A call of each registered function, in the syntax it is written with.
+fn example(name: &str) -> &'static str {
+    match name {
+        "abs" => "abs(-3)",
+        "acos" => "acos(1)",
+        "asin" => "asin(0)",
+        "atan" => "atan(1)",
+        "atan2" => "atan2(1, 1)",
+        "average" => "average(1, 2, 3)",
+        "beta" => "beta(2, 3)",
+        "cbrt" => "cbrt(27)",
+        "ceil" => "ceil(2.1)",
+        "ci" => "Ci(1)",
+        "cos" => "cos(0)",
+        "cosh" => "cosh(1)",
+        "count" => "count(1, 2, 3)",
+        "deg" => "deg(pi)",
+        "ei" => "Ei(1)",
+        "erf" => "erf(1)",
+        "erfc" => "erfc(1)",
+        "exp" => "exp(1)",
+        "factorial" => "factorial(5)",
+        "factors" => "360 with factors",
+        "floor" => "floor(2.7)",
+        "frombase" => "frombase(\"ff\", 16)",
+        "gamma" => "gamma(5)",
+        "index" => "index([10, 20, 30], 1)",
+        "ln" => "ln(2)",
+        "log" => "log(100)",
+        "log10" => "log10(100)",
+        "log2" => "log2(8)",
+        "max" => "max(1, 2)",
+        "mean" => "mean(1, 2)",
+        "median" => "median(1, 2, 3)",
+        "min" => "min(1, 2)",
+        "mode" => "mode(1, 1, 2)",
+        "ncr" => "ncr(5, 2)",
+        "pow" => "pow(2, 10)",
+        "rad" => "rad(180)",
+        "round" => "round(2.675, 2)",
+        "si" => "Si(1)",
+        "sign" => "sign(-2)",
+        "sin" => "sin(0)",
+        "sinh" => "sinh(1)",
+        "sqrt" => "sqrt(2)",
+        "stddev" => "stddev(1, 2, 3)",
+        "sum" => "sum(1, 2, 3)",
+        "tan" => "tan(1)",
+        "tanh" => "tanh(1)",
+        "tobase" => "tobase(255, 16)",
+        "trunc" => "trunc(2.7)",
+        "variance" => "variance(1, 2, 3)",
+        "equivalent" => "is x^2 - 1 equivalent to (x - 1)(x + 1)",
+        "integrate" => "integrate(x^2, x, 0, 1)",
+        "nderiv" => "nderiv(x^2, x, 3)",
+        "plot" => "plot sin(x) from 0 to 1",
+        "root" => "root(x^2 - 2, x, 0, 2)",
+        "simplify" => "simplify 2x + 3x",
+        "det" => "det([[1, 2], [3, 4]])",
+        "inverse" => "inverse([[1, 2], [3, 4]])",
+        "matrix" => "matrix([1, 2], [3, 4])",
+        "transpose" => "transpose([[1, 2], [3, 4]])",
+        "percent_change" => "percent_change(80, 100)",
+        "percent_of_total" => "percent_of_total(100, 250)",
+        "ratio_split" => "ratio_split(140, 3, 4)",
+        "add_tip" => "add_tip(100 USD, 15%)",
+        "add_vat" => "add_vat(100 EUR, 19%)",
+        "amortize" => "amortize(10000 USD, 5%, 2 years)",
+        "apply_discounts" => "apply_discounts(200 USD, 20%, 10%)",
+        "apply_percentages" => "apply_percentages(200 USD, -20%, 5%)",
+        "better_deal" => "compare 5 USD for 300 g vs 8 USD for 550 g",
+        "original_price" => "original_price(144 USD, 20%, 10%)",
+        "rate_history" => "USD/EUR from 1 Jan 2026 to 31 Jan 2026",
+        "remove_vat" => "remove_vat(119 EUR, 19%)",
+        "split_bill" => "split_bill(100 USD, 4)",
+        "age" => "age(15 June 1990)",
+        "day_of_week" => "day_of_week(16 Oct 2026)",
+        "days_in_month" => "days_in_month(1 Feb 2026)",
+        "days_in_year" => "days_in_year(2024)",
+        "is_leap_year" => "is_leap_year(2024)",
+        "next_weekday" => "next_weekday(\"Friday\", 1 Jan 2026)",
+        "time_span" => "9:00 to 17:30",
+        "time_until" => "time_until(1 Jan 2100)",
+        "week_number" => "week_number(16 Oct 2026)",
+        "weekends_until" => "weekends until 1 Jan 2100",
+        "working_hours_until" => "working hours until Friday",
+        "eng" => "eng(4700 ohm)",
+        "haversine" => "haversine(51.5, -0.1, 48.9, 2.4)",
+        "bmi" => "bmi(70 kg, 175 cm)",
+        "bmr" => "bmr(male, 30 years, 180 cm, 75 kg)",
+        "physics" => "physics(current, voltage, 12 V, resistance, 4 ohm)",
+        "darken" => "darken(#3366cc, 10%)",
+        "hsl" => "hsl(210, 50%, 50%)",
+        "lighten" => "lighten(#3366cc, 10%)",
+        "mix" => "mix(#ff0000, #0000ff)",
+        "rgb" => "rgb(51, 102, 204)",
+        "length" => "length(\"hello\")",
+        "utf8_bytes" => "utf8_bytes(\"hello\")",
+        "words" => "words(\"hello world\")",
+        "base64" => "base64(\"hello\")",
+        "base64decode" => "base64decode(\"aGVsbG8=\")",
+        "urlencode" => "urlencode(\"a b\")",
+        "crc32" => "crc32(\"hello\")",
+        "md5" => "md5(\"hello\")",
+        "sha256" => "sha256(\"hello\")",
+        _ => panic!("{name} has no example"),
+    }
+}
+
+#[test]
+fn test_every_listed_function_evaluates() {
+    for function in Calculator::new().capabilities().functions {
+        let input = example(function.name);
+        let result = Calculator::new().calculate_internal(input);
+        assert!(result.success, "{input}: {:?}", result.error);
+    }
+}
+
+#[test]
+fn test_infix_operators_follow_the_precedence_table() {
+    let capabilities = Calculator::new().capabilities();
+    let precedence = |symbol: &str| {
+        capabilities
+            .operators
+            .iter()
+            .find(|op| op.symbol == symbol && op.fixity == Fixity::Infix)
+            .and_then(|op| op.precedence)
+            .unwrap()
+    };
+    assert!(precedence("+") < precedence("*"));
+    assert!(precedence("*") < precedence("^"));
+
+    let power = capabilities.operators.iter().find(|op| op.symbol == "^");
+    assert!(power.is_some_and(|op| op.right_associative));
+    assert!(capabilities
+        .operators
+        .iter()
+        .any(|op| op.symbol == "!" && op.fixity == Fixity::Postfix));
+}
+
+#[test]
+fn test_every_listed_unit_is_accepted() {
+    let capabilities = Calculator::new().capabilities();
+    assert!(capabilities
+        .units
+        .iter()
+        .any(|unit| unit.symbol == "MiB" && unit.category == UnitCategory::DataSize));
+    for (symbol, category) in [
+        ("Ω", UnitCategory::Physics),
+        ("W", UnitCategory::Physics),
+        ("km/h", UnitCategory::Rate),
+        ("m/s²", UnitCategory::Rate),
+    ] {
+        assert!(
+            capabilities
+                .units
+                .iter()
+                .any(|unit| unit.symbol == symbol && unit.category == category),
+            "{symbol} is not listed"
+        );
+    }
+    for unit in &capabilities.units {
+        let input = format!("2 {}", unit.symbol);
+        let result = Calculator::new().calculate_internal(&input);
+        assert!(result.success, "{input}: {:?}", result.error);
+        // Physics units and rates read back as themselves, not as another
+        // unit spelled alike, as `W` once read as weeks
+        if matches!(unit.category, UnitCategory::Physics | UnitCategory::Rate) {
+            assert_eq!(result.result, input);
+        }
+    }
+}
+
+#[test]
+fn test_every_date_example_is_accepted() {
+    for locale in [None, Some("ru"), Some("zh")] {
+        let mut calc = Calculator::new();
+        if let Some(locale) = locale {
+            calc.set_language(locale);
+            calc.set_locale(locale);
+        }
+        for format in calc.capabilities().date_formats {
+            let result = calc.calculate_internal(&format.example);
+            assert!(result.success, "{}: {:?}", format.example, result.error);
+        }
+    }
+}
+
+#[test]
+fn test_names_follow_the_language() {
+    let mut calc = Calculator::new();
+    let kilogram = |calc: &Calculator| {
+        calc.capabilities()
+            .units
+            .into_iter()
+            .find(|unit| unit.symbol == "kg")
+            .map(|unit| unit.display_name)
+    };
+    assert_eq!(kilogram(&calc).as_deref(), Some("kilogram"));
+
+    calc.set_language("ru");
+    assert_eq!(kilogram(&calc).as_deref(), Some("килограмм"));
+    let capabilities = calc.capabilities();
+    let usd = capabilities.currencies.iter().find(|c| c.code == "USD");
+    assert_eq!(usd.map(|c| c.display_name.as_str()), Some("доллар США"));
+}
+
+#[test]
+fn test_slash_dates_follow_the_locale() {
+    let mut calc = Calculator::new();
+    let slashed = |calc: &Calculator| {
+        calc.capabilities()
+            .date_formats
+            .into_iter()
+            .find(|format| format.pattern.contains('/'))
+            .map(|format| format.pattern)
+    };
+    assert_eq!(slashed(&calc), Some("MM/DD/YYYY"));
+    calc.set_locale("de");
+    assert_eq!(slashed(&calc), Some("DD/MM/YYYY"));
+}
+
+#[test]
+fn test_capabilities_serialize_to_json() {
+    let json: serde_json::Value =
+        serde_json::from_str(&Calculator::new().get_capabilities()).unwrap();
+    assert!(json["units"]
+        .as_array()
+        .is_some_and(|units| !units.is_empty()));
+    assert_eq!(json["operators"][0]["fixity"], "infix");
+}
@@ -0,0 +1,15 @@
+//! Runs the expressions of filed issues collected in
+//! `docs/case-studies/corpus.lino` against the results they should give.
+
+use link_calculator::testing::corpus::{load_corpus, run_corpus};
+
+#[test]
+fn test_case_study_corpus_gives_the_expected_results() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/case-studies/corpus.lino");
+    let cases = load_corpus(path).unwrap();
+    assert!(!cases.is_empty());
+
+    let report = run_corpus(&cases);
+    assert!(report.is_success(), "{report}");
+    assert_eq!(report.passed, cases.len());
+}
@@ -0,0 +1,163 @@
+//! Tests for the non-interactive command line: one-shot expressions, piped
+//! input, JSON output and exit codes.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: Option<&str>) -> Output {
+    run_at_home(None, args, stdin)
+}
+
+/// Runs the CLI with `HOME` set to `home`, where it keeps saved expressions.
+fn run_at_home(home: Option<&Path>, args: &[&str], stdin: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_link-calculator"));
+    if let Some(home) = home {
+        command.env("HOME", home);
+    }
+    let mut child = command
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the CLI starts");
+    let mut input = child.stdin.take().expect("stdin is piped");
+    input
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .expect("stdin accepts input");
+    drop(input);
+    child.wait_with_output().expect("the CLI exits")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_one_shot_expression() {
+    let output = run(&["2 + 3"], None);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "5\n");
+}
+
+#[test]
+fn test_failure_sets_exit_code() {
+    let output = run(&["2 + 3", "1/0"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "5\n");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Division by zero"));
+}
+
+#[test]
+fn test_piped_expressions_one_per_line() {
+    let output = run(&[], Some("2 + 3\n\n4 * 4\n"));
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "5\n16\n");
+}
+
+#[test]
+fn test_steps_flags() {
+    let output = run(&["--steps", "2 * 3"], None);
+    assert!(stdout(&output).contains("Final result: 6"));
+    let output = run(&["--no-steps", "2 * 3"], None);
+    assert_eq!(stdout(&output), "6\n");
+}
+
+#[test]
+fn test_json_output() {
+    let output = run(&["--json", "2 * 3", "1/0"], None);
+    assert_eq!(output.status.code(), Some(1));
+    let results: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+        .collect();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["result"], "6");
+    assert_eq!(results[0]["steps"], serde_json::json!([]));
+    assert_eq!(results[1]["success"], false);
+
+    let output = run(&["--json", "--steps", "2 * 3"], None);
+    let result: serde_json::Value = serde_json::from_str(stdout(&output).trim()).unwrap();
+    assert!(!result["steps"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_negative_expressions_and_options() {
+    let output = run(&["-5 + 3"], None);
+    assert_eq!(stdout(&output), "-2\n");
+    let output = run(&["--", "--steps"], None);
+    assert_eq!(output.status.code(), Some(1));
+    let output = run(&["--bogus"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(run(&["--help"], None).status.success());
+}
+
+#[test]
+fn test_rates_dir_enables_offline_historical_conversion() {
+    let expression = "100 USD as HKD at 2021-01-26";
+    let output = run(&[expression], None);
+    assert_eq!(output.status.code(), Some(1));
+
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/data/currency");
+    let output = run(&["--rates-dir", dir, expression], None);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "775.2 HKD\n");
+
+    let file = concat!(env!("CARGO_MANIFEST_DIR"), "/data/currency/usd-hkd.lino");
+    let output = run(&["--rates-file", file, expression], None);
+    assert_eq!(stdout(&output), "775.2 HKD\n");
+}
+
+#[test]
+fn test_unreadable_rates_are_rejected() {
+    let output = run(&["--rates-dir", "no/such/dir", "1"], None);
+    assert_eq!(output.status.code(), Some(2));
+    let output = run(&["1", "--rates-file"], None);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_interactive_commands_and_continuation() {
+    let input = "(1 +\n2) * 3\n:vars\n:steps off\nans * 2\n:bogus\nquit\n";
+    let output = run(&["--interactive"], Some(input));
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("Result: 9"), "{text}");
+    assert!(text.contains("#1 = 9    ((1 + 2) * 3)"), "{text}");
+    assert!(text.contains("ans = 9"), "{text}");
+    assert!(text.contains("Steps are off"), "{text}");
+    let after_off = &text[text.find("Steps are off").unwrap()..];
+    assert!(after_off.contains("Result: 18"), "{text}");
+    assert!(!after_off.contains("Final result"), "{text}");
+    assert!(text.contains("Unknown command ':bogus'"), "{text}");
+    assert!(text.contains("Goodbye!"), "{text}");
+}
+
+#[test]
+fn test_interactive_angle_mode() {
+    let input = ":angle\nsin(30)\n:angle degrees\nsin(30)\n:angle turns\nquit\n";
+    let text = stdout(&run(&["--interactive"], Some(input)));
+    assert!(text.contains("Angles are in radians"), "{text}");
+    let after = &text[text.find("Angles are in degrees").unwrap()..];
+    assert!(after.contains("Result: 0.5"), "{text}");
+    assert!(text.contains("Unknown angle mode 'turns'"), "{text}");
+}
+
+#[test]
+fn test_interactive_saved_expressions_persist() {
+    let home = std::env::temp_dir().join(format!("link-calculator-home-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+    let input = ":save area 3 m * 4 m\n:save two words\n:saved\nquit\n";
+    let text = stdout(&run_at_home(Some(&home), &["--interactive"], Some(input)));
+    assert!(text.contains("Saved area = 3 m * 4 m"), "{text}");
+    assert!(text.contains("Cannot save 'words' as two"), "{text}");
+    assert!(text.contains("  area = 3 m * 4 m"), "{text}");
+
+    let input = ":recall area\n:forget area\n:recall area\nquit\n";
+    let text = stdout(&run_at_home(Some(&home), &["--interactive"], Some(input)));
+    assert!(text.contains("Result: 12 m²"), "{text}");
+    assert!(text.contains("Forgot area"), "{text}");
+    assert!(text.contains("Nothing is saved as area"), "{text}");
+    std::fs::remove_dir_all(&home).unwrap();
+}
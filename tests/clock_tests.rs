@@ -0,0 +1,61 @@
+//! Tests for reading the current time from a clock set on the calculator.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::types::FixedClock;
+use link_calculator::Calculator;
+
+fn fixed_calculator(time: &str) -> Calculator {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_fixed_time(time), "{time}");
+    calculator
+}
+
+#[test]
+fn test_now_and_today_read_the_fixed_time() {
+    let calculator = fixed_calculator("2026-03-15T12:30:00Z");
+    assert_eq!(
+        calculate_with(&calculator, "now"),
+        "('current UTC time': 2026-03-15 12:30:00 UTC (+00:00))"
+    );
+    assert_eq!(calculate_with(&calculator, "today"), "2026-03-15");
+    assert_eq!(calculate_with(&calculator, "today + 1 day"), "2026-03-16");
+    assert_eq!(
+        calculate_with(&calculator, "now - 1 hour"),
+        "2026-03-15 11:30:00 +00:00"
+    );
+    assert_eq!(calculate_with(&calculator, "week number of today"), "11");
+}
+
+#[test]
+fn test_live_times_with_a_timezone_read_the_fixed_time() {
+    let calculator = fixed_calculator("2026-03-15T12:30:00Z");
+    assert_eq!(
+        calculate_with(&calculator, "now EST"),
+        "('current EST time': 2026-03-15 07:30:00 EST (-05:00))"
+    );
+}
+
+#[test]
+fn test_local_timezone_applies_to_the_fixed_time() {
+    let mut calculator = fixed_calculator("2026-03-15T08:00:00Z");
+    calculator.set_timezone_offset(-14 * 60);
+    assert_eq!(calculate_with(&calculator, "today"), "2026-03-14");
+}
+
+#[test]
+fn test_clock_can_be_moved_and_released() {
+    let mut calculator = fixed_calculator("2026-03-15T12:30:00Z");
+    assert_eq!(calculate_with(&calculator, "today"), "2026-03-15");
+    calculator.set_clock(FixedClock::from_rfc3339("2030-01-01T00:00:00Z").unwrap());
+    assert_eq!(calculate_with(&calculator, "today"), "2030-01-01");
+    calculator.use_system_clock();
+    assert_ne!(calculate_with(&calculator, "today"), "2030-01-01");
+}
+
+#[test]
+fn test_invalid_fixed_time_is_rejected() {
+    let mut calculator = Calculator::new();
+    assert!(!calculator.set_fixed_time("15 March 2026"));
+}
@@ -0,0 +1,84 @@
+//! Tests for hex colors: converting between color models, mixing and
+//! lightening, and the hex, rgb and hsl alternatives of a color result.
+
+mod common;
+
+use common::{calculate, error};
+use link_calculator::{Calculator, RepresentationKind};
+
+#[test]
+fn test_hex_color_converts_between_models() {
+    assert_eq!(calculate("#ff8800"), "#ff8800");
+    assert_eq!(calculate("#ff8800 in rgb"), "rgb(255, 136, 0)");
+    assert_eq!(calculate("#ff8800 in hsl"), "hsl(32, 100%, 50%)");
+    assert_eq!(calculate("#FF8800 in hex"), "#ff8800");
+    assert_eq!(calculate("#f80 in rgb"), "rgb(255, 136, 0)");
+}
+
+#[test]
+fn test_mix_blends_two_colors() {
+    assert_eq!(calculate("mix(#ff0000, #0000ff, 50%)"), "#800080");
+    assert_eq!(calculate("mix(#ff0000, #0000ff)"), "#800080");
+    assert_eq!(calculate("mix(#ff0000, #0000ff, 100%)"), "#ff0000");
+    assert_eq!(
+        calculate("mix(#ff0000, #0000ff, 25%) in rgb"),
+        "rgb(64, 0, 191)"
+    );
+}
+
+#[test]
+fn test_lighten_and_darken_change_hsl_lightness() {
+    assert_eq!(calculate("lighten(#336699, 10%)"), "#4080bf");
+    assert_eq!(calculate("darken(#336699, 10%)"), "#264d73");
+    assert_eq!(calculate("lighten(#336699, 100%)"), "#ffffff");
+}
+
+#[test]
+fn test_colors_from_channels() {
+    assert_eq!(calculate("rgb(255, 136, 0)"), "rgb(255, 136, 0)");
+    assert_eq!(calculate("rgb(255, 136, 0) in hex"), "#ff8800");
+    assert_eq!(calculate("hsl(210, 50%, 50%) in hex"), "#4080bf");
+}
+
+#[test]
+fn test_color_arithmetic_works_channel_by_channel() {
+    assert_eq!(calculate("#ff0000 + #0000ff"), "#ff00ff");
+    assert_eq!(calculate("#808080 - #101010"), "#707070");
+    assert_eq!(calculate("#ff8800 * 2"), "#ffff00");
+    assert_eq!(calculate("#808080 / 2"), "#404040");
+    assert!(error("#ff0000 * #00ff00").contains("Cannot apply *"));
+}
+
+#[test]
+fn test_color_result_has_model_alternatives() {
+    let result = Calculator::new().calculate_internal("lighten(#336699, 10%)");
+    let alternatives: Vec<_> = result
+        .alternatives
+        .iter()
+        .map(|a| (a.kind, a.value.as_str()))
+        .collect();
+    assert_eq!(
+        alternatives,
+        [
+            (RepresentationKind::Hex, "#4080bf"),
+            (RepresentationKind::Rgb, "rgb(64, 128, 191)"),
+            (RepresentationKind::Hsl, "hsl(210, 50%, 50%)"),
+        ]
+    );
+}
+
+#[test]
+fn test_history_references_are_not_colors() {
+    let calculator = Calculator::new();
+    calculator.calculate_internal("2 + 2");
+    let result = calculator.calculate_internal("#1 * 10");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "40");
+}
+
+#[test]
+fn test_invalid_color_arguments_are_rejected() {
+    assert!(error("lighten(5, 10%)").contains("not a color"));
+    assert!(error("mix(#ff0000, #0000ff, 150%)").contains("from 0% to 100%"));
+    assert!(error("rgb(256, 0, 0)").contains("from 0 to 255"));
+}
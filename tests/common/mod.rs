@@ -0,0 +1,100 @@
+//! Helpers shared by the integration tests.
+
+// Each test crate uses only some of the helpers
+#![allow(dead_code)]
+
+use link_calculator::{CalculationResult, Calculator, RepresentationKind};
+
+/// A new calculator with the exchange rates of a consolidated `.lino` rate
+/// file, which must load at least one rate.
+pub fn calculator_with_lino_rates(lino: &str) -> Calculator {
+    let calculator = Calculator::new();
+    assert!(
+        calculator.load_rates_from_consolidated_lino(lino) > 0,
+        "no rates loaded from {lino}"
+    );
+    calculator
+}
+
+/// A new calculator with the current exchange rates of rate API responses
+/// on `date`, given as pairs of a base currency and its rates in JSON. Each
+/// response must update every rate in it.
+pub fn calculator_with_api_rates(date: &str, responses: &[(&str, &str)]) -> Calculator {
+    let calculator = Calculator::new();
+    for (base, rates_json) in responses {
+        let rates: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(rates_json).expect("rates are a JSON object");
+        assert_eq!(
+            calculator.update_rates_from_api(base, date, rates_json),
+            rates.len(),
+            "{base}: {rates_json}"
+        );
+    }
+    calculator
+}
+
+/// Calculates `input` on a new calculator, which must succeed, and returns
+/// the result.
+pub fn calculate(input: &str) -> String {
+    calculate_with(&Calculator::new(), input)
+}
+
+/// Calculates `input` on `calculator`, which must succeed, and returns the
+/// result.
+pub fn calculate_with(calculator: &Calculator, input: &str) -> String {
+    calculate_result_with(calculator, input).result
+}
+
+/// Calculates `input` on a new calculator, which must succeed, and returns
+/// the whole calculation result.
+pub fn calculate_result(input: &str) -> CalculationResult {
+    calculate_result_with(&Calculator::new(), input)
+}
+
+fn calculate_result_with(calculator: &Calculator, input: &str) -> CalculationResult {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result
+}
+
+/// Calculates `input` on a new calculator, which must succeed, and returns
+/// the result with its steps.
+pub fn calculate_with_steps(input: &str) -> (String, Vec<String>) {
+    let result = calculate_result(input);
+    (result.result, result.steps)
+}
+
+/// Calculates `input` on a new calculator, which must fail, and returns the
+/// error.
+pub fn error(input: &str) -> String {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(
+        !result.success,
+        "{input} should fail, got {}",
+        result.result
+    );
+    result.error.unwrap_or_default()
+}
+
+/// The labels and values of a result's breakdown, such as the net amount
+/// and the VAT on it.
+pub fn breakdown(result: &CalculationResult) -> Vec<(String, String)> {
+    result
+        .breakdown
+        .as_ref()
+        .expect("the result has a breakdown")
+        .iter()
+        .map(|item| (item.label.clone(), item.value.clone()))
+        .collect()
+}
+
+/// The digits of a result, which is itself written in scientific notation
+/// from `10^15` up.
+pub fn exact_digits(result: &CalculationResult) -> &str {
+    result
+        .alternatives
+        .iter()
+        .find(|representation| representation.kind == RepresentationKind::Decimal)
+        .map(|representation| representation.value.as_str())
+        .unwrap_or_default()
+}
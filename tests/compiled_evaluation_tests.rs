@@ -0,0 +1,55 @@
+//! Tests for compiled expressions, the fast path plotting and numerical
+//! integration take for expressions of plain numbers and math functions.
+
+use link_calculator::grammar::{CompiledExpression, ExpressionParser};
+use link_calculator::Calculator;
+
+fn compile(input: &str) -> Option<CompiledExpression> {
+    let expr = ExpressionParser::new().parse(input).unwrap();
+    CompiledExpression::compile(&expr, "x")
+}
+
+#[test]
+fn test_compiled_expression_matches_the_evaluator() {
+    let compiled = compile("x^3 - 2*x + sqrt(abs(x)) / (1 + x^2)").unwrap();
+    let mut parser = ExpressionParser::new();
+    let expr = parser
+        .parse("x^3 - 2*x + sqrt(abs(x)) / (1 + x^2)")
+        .unwrap();
+    for x in [-2.5, -1.0, 0.0, 0.5, 3.0] {
+        let expected = parser.evaluate_at(&expr, "x", x).unwrap().to_f64();
+        let actual = compiled.evaluate(x).unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "{x}: {actual} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_units_fall_back_to_the_evaluator() {
+    assert!(compile("x * 2 meters").is_none());
+    let result = Calculator::new().calculate_internal("plot(x * 2, 0, 1, 5)");
+    assert!(result.success, "{:?}", result.error);
+}
+
+#[test]
+fn test_plot_values_through_the_compiled_path() {
+    let result = Calculator::new().calculate_internal("plot(1/x, -1, 1, 5)");
+    assert!(result.success, "{:?}", result.error);
+    let plot = &result.plots.unwrap()[0];
+    // x = 0 breaks the curve; the other points are exact
+    assert_eq!(plot.x_values, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+    assert!(plot.y_values[2].is_nan());
+    assert_eq!(plot.y_values[3..], [2.0, 1.0]);
+}
+
+#[test]
+fn test_divergent_integral_fails() {
+    let result = Calculator::new().calculate_internal("integrate(1/x, x, 0, 1)");
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Domain error: integral does not converge")
+    );
+}
@@ -0,0 +1,57 @@
+//! Tests for calculating on one `Calculator` from several threads.
+
+use std::sync::Arc;
+use std::thread;
+
+use link_calculator::Calculator;
+use serde_json::Value;
+
+fn stats(calculator: &Calculator) -> Value {
+    serde_json::from_str(&calculator.parse_cache_stats()).unwrap()
+}
+
+#[test]
+fn test_calculator_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Calculator>();
+}
+
+#[test]
+fn test_threads_share_one_calculator() {
+    let calculator = Arc::new(Calculator::new());
+    let handles: Vec<_> = (1..=8)
+        .map(|n| {
+            let calculator = Arc::clone(&calculator);
+            thread::spawn(move || {
+                (0..10)
+                    .map(|i| calculator.calculate_internal(&format!("{n} * {i} km in m")))
+                    .map(|result| result.result)
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    for (n, handle) in (1..=8).zip(handles) {
+        let results = handle.join().unwrap();
+        let expected: Vec<_> = (0..10).map(|i| format!("{} m", n * i * 1000)).collect();
+        assert_eq!(results, expected);
+    }
+    assert_eq!(calculator.history().len(), 80);
+}
+
+#[test]
+fn test_threads_share_history_and_parse_cache() {
+    let calculator = Arc::new(Calculator::new());
+    assert_eq!(calculator.calculate_internal("2 + 3").result, "5");
+    let misses = stats(&calculator)["misses"].clone();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let calculator = Arc::clone(&calculator);
+            thread::spawn(move || calculator.calculate_internal("2 + 3").result)
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), "5");
+    }
+    assert_eq!(calculator.history().len(), 5);
+    assert_eq!(stats(&calculator)["misses"], misses);
+}
@@ -0,0 +1,94 @@
+//! Tests for conversion receipts: conversions report the rates they used in
+//! their return value, so one currency database serves many threads at once.
+
+use link_calculator::types::{CurrencyDatabase, DateTime, Decimal, ExchangeRateInfo, Unit, Value};
+use link_calculator::Calculator;
+use std::thread;
+
+fn database() -> CurrencyDatabase {
+    let db = CurrencyDatabase::new();
+    db.set_rate_with_info(
+        "INR",
+        "USD",
+        ExchangeRateInfo::new(0.5, "test", "2026-01-25"),
+    );
+    db.set_rate_with_info(
+        "USD",
+        "RUB",
+        ExchangeRateInfo::new(2.0, "test", "2026-01-25"),
+    );
+    db
+}
+
+#[test]
+fn test_cross_rate_receipt_lists_each_hop() {
+    let conversion = database().convert(10.0, "INR", "RUB").unwrap();
+    assert!((conversion.amount - 10.0).abs() < 1e-9);
+    let hops: Vec<_> = conversion
+        .receipt
+        .rates
+        .iter()
+        .map(|(from, to, _)| format!("{from}->{to}"))
+        .collect();
+    assert_eq!(hops, ["INR->USD", "USD->RUB"]);
+}
+
+#[test]
+fn test_historical_receipt_carries_staleness_warning() {
+    let db = database();
+    db.set_historical_rate_with_info(
+        "USD",
+        "EUR",
+        "2025-11-01",
+        ExchangeRateInfo::new(0.9, "test", "2025-11-01"),
+    );
+    let date = DateTime::parse("2026-01-01").unwrap();
+    let conversion = db.convert_at_date(1.0, "USD", "EUR", &date).unwrap();
+    assert_eq!(conversion.receipt.rates.len(), 1);
+    assert_eq!(conversion.receipt.warnings.len(), 1);
+    assert!(conversion.receipt.warnings[0].contains("2025-11-01"));
+}
+
+#[test]
+fn test_list_conversion_collects_every_receipt() {
+    let db = database();
+    let list = Value::list(vec![
+        Value::currency(Decimal::new(10), "USD"),
+        Value::currency(Decimal::new(10), "INR"),
+    ]);
+    let (_, receipt) = list
+        .convert_to_unit_at_date(&Unit::currency("RUB"), &db, None)
+        .unwrap();
+    assert_eq!(receipt.rates.len(), 3);
+}
+
+#[test]
+fn test_threads_convert_through_one_shared_database() {
+    let db = database();
+    let hops = |from: &str| {
+        let amount = Value::currency(Decimal::new(100), from);
+        (0..100)
+            .map(|_| {
+                let (_, receipt) = amount
+                    .convert_to_unit_at_date(&Unit::currency("RUB"), &db, None)
+                    .unwrap();
+                receipt.rates.len()
+            })
+            .max()
+    };
+    thread::scope(|scope| {
+        let direct = scope.spawn(|| hops("USD"));
+        let cross = scope.spawn(|| hops("INR"));
+        assert_eq!(direct.join().unwrap(), Some(1));
+        assert_eq!(cross.join().unwrap(), Some(2));
+    });
+}
+
+#[test]
+fn test_steps_report_the_receipt_of_each_operation() {
+    let calculator = Calculator::with_currency_db(database());
+    let result = calculator.calculate_internal("(10 USD as RUB) * 2");
+    assert!(result.success, "{:?}", result.error);
+    let steps = result.steps.join("\n");
+    assert_eq!(steps.matches("Exchange rate:").count(), 1, "{steps}");
+}
@@ -0,0 +1,52 @@
+//! Tests for the great-circle distance between two coordinates:
+//! `distance between (55.7558, 37.6173) and (48.8566, 2.3522)`.
+
+mod common;
+
+use common::{calculate, error};
+
+#[test]
+fn test_distance_between_moscow_and_paris() {
+    assert_eq!(
+        calculate("distance between (55.7558, 37.6173) and (48.8566, 2.3522)"),
+        "2486.248 km"
+    );
+}
+
+#[test]
+fn test_distance_converts_to_miles() {
+    assert_eq!(
+        calculate("distance between (55.7558, 37.6173) and (48.8566, 2.3522) in miles"),
+        "1544.883 mi"
+    );
+    assert_eq!(
+        calculate("haversine(0, 0, 0, 90) in feet"),
+        "32833146.325 ft"
+    );
+}
+
+#[test]
+fn test_southern_and_western_coordinates_are_negative() {
+    assert_eq!(
+        calculate("distance between (-33.8688, 151.2093) and (40.7128, -74.0060)"),
+        "15988.756 km"
+    );
+}
+
+#[test]
+fn test_haversine_function_takes_four_degrees() {
+    assert_eq!(calculate("haversine(0, 0, 0, 180)"), "20015.087 km");
+    assert_eq!(calculate("haversine(10, 20, 10, 20)"), "0 km");
+}
+
+#[test]
+fn test_out_of_range_coordinates_are_rejected() {
+    assert!(error("distance between (95, 0) and (0, 0)").contains("latitude 95"));
+    assert!(error("distance between (0, 0) and (0, -200)").contains("longitude -200"));
+}
+
+#[test]
+fn test_coordinates_need_both_parts() {
+    error("distance between (55.7558) and (48.8566, 2.3522)");
+    error("distance between (55.7558, 37.6173) (48.8566, 2.3522)");
+}
@@ -0,0 +1,67 @@
+//! Tests for countdowns to a deadline on the business-day calendar.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::types::FixedClock;
+use link_calculator::Calculator;
+
+/// A calculator whose clock reads Monday 16 March 2026, 10:00 UTC.
+fn calculator() -> Calculator {
+    let mut calculator = Calculator::new();
+    calculator.set_clock(FixedClock::from_rfc3339("2026-03-16T10:00:00Z").unwrap());
+    calculator
+}
+
+#[test]
+fn test_working_hours_count_weekdays_from_nine_to_five() {
+    let calculator = calculator();
+    assert_eq!(
+        calculate_with(&calculator, "working hours until Friday 5pm"),
+        "39 hours"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "working hours until Wednesday 12:00"),
+        "18 hours"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "working hours until 23 March 2026"),
+        "39 hours"
+    );
+}
+
+#[test]
+fn test_a_deadline_that_has_passed_is_rejected() {
+    let calculator = calculator();
+    for input in [
+        "working hours until 2026-03-16 09:00 UTC",
+        "working hours until 1 Jan 1990",
+        "weekends until 1 Jan 1990",
+    ] {
+        let result = calculator.calculate_internal(input);
+        assert!(!result.success, "{input}: {}", result.result);
+        let error = result.error.unwrap_or_default();
+        assert!(error.contains("already passed"), "{input}: {error}");
+    }
+}
+
+#[test]
+fn test_working_hours_follow_the_local_clock() {
+    let mut calculator = calculator();
+    calculator.set_timezone_offset(180);
+    assert_eq!(
+        calculate_with(&calculator, "working hours until Friday 5pm"),
+        "36 hours"
+    );
+}
+
+#[test]
+fn test_weekends_are_counted_before_the_deadline() {
+    let calculator = calculator();
+    assert_eq!(
+        calculate_with(&calculator, "how many weekends until 1 Jun 2026"),
+        "11"
+    );
+    assert_eq!(calculate_with(&calculator, "weekends until Sunday"), "1");
+    assert_eq!(calculate_with(&calculator, "weekends until Saturday"), "0");
+}
@@ -22,7 +22,7 @@ use link_calculator::Calculator;
 /// Issue #51: Dollar sign prefix `$10` should parse as `10 USD`.
 #[test]
 fn test_issue_51_dollar_prefix_parses() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("$10");
     assert!(
         result.success,
@@ -36,7 +36,7 @@ fn test_issue_51_dollar_prefix_parses() {
 /// Issue #51: Dollar sign mixed with other currency expressions should work.
 #[test]
 fn test_issue_51_dollar_prefix_in_expression() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     // $10 + 5 USD = 15 USD
     let result = calculator.calculate_internal("$10 + 5 USD");
     assert!(
@@ -51,7 +51,7 @@ fn test_issue_51_dollar_prefix_in_expression() {
 /// Euro prefix symbol `€` should work similarly to `$`.
 #[test]
 fn test_issue_51_euro_prefix_parses() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("€5");
     assert!(
         result.success,
@@ -65,7 +65,7 @@ fn test_issue_51_euro_prefix_parses() {
 /// British pound prefix `£` should work.
 #[test]
 fn test_issue_51_pound_prefix_parses() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("£3");
     assert!(
         result.success,
@@ -79,7 +79,7 @@ fn test_issue_51_pound_prefix_parses() {
 /// Ruble symbol `₽` as prefix should work.
 #[test]
 fn test_issue_51_ruble_prefix_parses() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("₽100");
     assert!(
         result.success,
@@ -93,7 +93,7 @@ fn test_issue_51_ruble_prefix_parses() {
 /// Indian rupee symbol `₹` as prefix should work.
 #[test]
 fn test_issue_51_rupee_prefix_parses() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("₹10");
     assert!(
         result.success,
@@ -107,7 +107,7 @@ fn test_issue_51_rupee_prefix_parses() {
 /// Issue #51+52 combined: Full expression from issue #51.
 #[test]
 fn test_issue_51_full_expression() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 рублей + $10 + 10 рупий");
     assert!(
         result.success,
@@ -123,7 +123,7 @@ fn test_issue_51_full_expression() {
 /// Issue #52: Russian word for rubles (рублей) should be recognized as RUB.
 #[test]
 fn test_issue_52_russian_rublei_recognized() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 рублей + 10 RUB");
     assert!(
         result.success,
@@ -137,7 +137,7 @@ fn test_issue_52_russian_rublei_recognized() {
 /// Issue #52: Russian word рублей mixed with USD should convert correctly.
 #[test]
 fn test_issue_52_russian_rublei_with_usd() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 рублей + 10 USD");
     assert!(
         result.success,
@@ -151,7 +151,7 @@ fn test_issue_52_russian_rublei_with_usd() {
 /// Issue #52: Russian word рупий (Indian rupee) should be recognized as INR.
 #[test]
 fn test_issue_52_russian_rupiy_recognized() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 рупий + 10 INR");
     assert!(
         result.success,
@@ -165,7 +165,7 @@ fn test_issue_52_russian_rupiy_recognized() {
 /// Issue #52: Full expression from the issue: `10 рублей + 10 USD + 10 рупий`.
 #[test]
 fn test_issue_52_full_expression() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 рублей + 10 USD + 10 рупий");
     assert!(
         result.success,
@@ -181,7 +181,7 @@ fn test_issue_52_full_expression() {
 /// Issue #53: `10 RUB + 10 USD + 10 INR` should succeed.
 #[test]
 fn test_issue_53_rub_usd_inr_expression() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 RUB + 10 USD + 10 INR");
     assert!(
         result.success,
@@ -195,7 +195,7 @@ fn test_issue_53_rub_usd_inr_expression() {
 /// INR should be a recognized currency.
 #[test]
 fn test_issue_53_inr_is_recognized() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("100 INR");
     assert!(
         result.success,
@@ -209,7 +209,7 @@ fn test_issue_53_inr_is_recognized() {
 /// INR to USD conversion via default rates should work.
 #[test]
 fn test_issue_53_inr_to_usd_conversion() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("0 USD + 86.5 INR");
     assert!(
         result.success,
@@ -222,7 +222,7 @@ fn test_issue_53_inr_to_usd_conversion() {
 /// RUB to INR triangulation via USD should work.
 #[test]
 fn test_issue_53_rub_to_inr_triangulation() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("0 INR + 100 RUB");
     assert!(
         result.success,
@@ -235,7 +235,7 @@ fn test_issue_53_rub_to_inr_triangulation() {
 /// English name "rupee" should be recognized as INR.
 #[test]
 fn test_issue_53_english_rupee_name() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("10 rupees + 10 INR");
     assert!(
         result.success,
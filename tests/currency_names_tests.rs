@@ -0,0 +1,55 @@
+//! Tests for currency names in several languages and prefix currency
+//! symbols attached to numbers.
+
+use link_calculator::Calculator;
+
+fn lino(input: &str) -> String {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(result.success, "{input} failed: {:?}", result.error);
+    result.lino_interpretation
+}
+
+#[test]
+fn test_english_names_and_symbols() {
+    assert_eq!(lino("84 dollars - 34 euros"), "((84 USD) - (34 EUR))");
+    assert_eq!(lino("£50 + $20"), "((50 GBP) + (20 USD))");
+    assert_eq!(lino("5 bucks"), "(5 USD)");
+    assert_eq!(lino("5 won"), "(5 KRW)");
+}
+
+#[test]
+fn test_russian_and_ukrainian_names() {
+    assert_eq!(lino("1000 рублей"), "(1000 RUB)");
+    assert_eq!(lino("10 йен"), "(10 JPY)");
+    assert_eq!(lino("10 гривен"), "(10 UAH)");
+    assert_eq!(lino("10 гривень"), "(10 UAH)");
+    assert_eq!(lino("5 злотых"), "(5 PLN)");
+    assert_eq!(lino("5 лир"), "(5 TRY)");
+}
+
+#[test]
+fn test_spanish_portuguese_polish_and_japanese_names() {
+    assert_eq!(lino("10 dólares"), "(10 USD)");
+    assert_eq!(lino("3 libras"), "(3 GBP)");
+    assert_eq!(lino("20 reais"), "(20 BRL)");
+    assert_eq!(lino("10 złotych"), "(10 PLN)");
+    assert_eq!(lino("10 zł"), "(10 PLN)");
+    assert_eq!(lino("100 円"), "(100 JPY)");
+    assert_eq!(lino("100 ユーロ"), "(100 EUR)");
+}
+
+#[test]
+fn test_prefixed_dollar_signs() {
+    assert_eq!(lino("A$10"), "(10 AUD)");
+    assert_eq!(lino("C$5"), "(5 CAD)");
+    assert_eq!(lino("R$5"), "(5 BRL)");
+    assert_eq!(lino("HK$100"), "(100 HKD)");
+    assert_eq!(lino("US$5"), "(5 USD)");
+}
+
+#[test]
+fn test_more_prefix_symbols() {
+    assert_eq!(lino("₺10"), "(10 TRY)");
+    assert_eq!(lino("₴10"), "(10 UAH)");
+    assert_eq!(lino("₪10"), "(10 ILS)");
+}
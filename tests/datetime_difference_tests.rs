@@ -0,0 +1,56 @@
+//! Tests for the difference of two dates, parsed by the main grammar as a
+//! duration that also counts calendar months.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_date_difference_shows_as_a_duration() {
+    assert_eq!(
+        calculate("(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC)"),
+        "1 day, 20 hours, 8 minutes"
+    );
+    assert_eq!(calculate("(2026-03-01) - (2025-01-15)"), "410 days");
+}
+
+#[test]
+fn test_date_difference_converts_to_calendar_units() {
+    assert_eq!(
+        calculate("((2026-03-15) - (2025-01-15)) in months"),
+        "14 months"
+    );
+    assert_eq!(
+        calculate("((2026-03-15) - (2024-03-15)) in years"),
+        "2 years"
+    );
+    assert_eq!(
+        calculate("((2025-01-15) - (2026-03-15)) in months"),
+        "-14 months"
+    );
+}
+
+#[test]
+fn test_date_difference_takes_part_in_further_arithmetic() {
+    assert_eq!(
+        calculate("(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC) + 2 hours"),
+        "1 day, 22 hours, 8 minutes"
+    );
+    assert_eq!(calculate("(2026-03-15) - (2025-01-15) > 400 days"), "true");
+}
+
+#[test]
+fn test_links_notation_of_a_date_difference_parses_back() {
+    let calc = Calculator::new();
+    let first = calc.calculate_internal("(Jan 30, 12:00pm UTC) - (Jan 27, 8:59am UTC)");
+    assert!(first.success, "{:?}", first.error);
+
+    let again = calc.calculate_internal(&first.lino_interpretation);
+    assert!(
+        again.success,
+        "{}: {:?}",
+        first.lino_interpretation, again.error
+    );
+    assert_eq!(again.result, first.result);
+}
@@ -0,0 +1,120 @@
+//! Tests for the formats dates and times are written in.
+
+use link_calculator::types::DateTimeFormat;
+use link_calculator::{CalculationOptions, Calculator};
+
+fn calculate(input: &str, format: DateTimeFormat) -> link_calculator::CalculationResult {
+    let calc = Calculator::new();
+    let options = CalculationOptions {
+        datetime_format: Some(format),
+        ..CalculationOptions::default()
+    };
+    let result = calc.calculate_with_options(input, &options);
+    assert!(result.success, "{input} failed: {:?}", result.error);
+    result
+}
+
+#[test]
+fn test_iso_is_the_default() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("17 Feb 2027");
+    assert_eq!(result.result, "2027-02-17");
+}
+
+#[test]
+fn test_long_format() {
+    let result = calculate("17 Feb 2027", DateTimeFormat::Long);
+    assert_eq!(result.result, "17 February 2027");
+}
+
+#[test]
+fn test_weekday_format() {
+    let result = calculate("2027-02-17", DateTimeFormat::Weekday);
+    assert_eq!(result.result, "Wednesday, 17 February 2027");
+}
+
+#[test]
+fn test_long_format_keeps_time_and_timezone() {
+    let result = calculate("2:30pm UTC Feb 17 2027", DateTimeFormat::Long);
+    assert_eq!(result.result, "17 February 2027 14:30:00 +00:00");
+}
+
+#[test]
+fn test_custom_pattern() {
+    let result = calculate(
+        "2027-02-17 + 3 days",
+        DateTimeFormat::Pattern("%d.%m.%Y".to_string()),
+    );
+    assert_eq!(result.result, "20.02.2027");
+}
+
+#[test]
+fn test_steps_use_the_same_format() {
+    let result = calculate("2027-02-17 + 3 days", DateTimeFormat::Long);
+    assert!(
+        result
+            .steps
+            .iter()
+            .any(|s| s == "DateTime value: 17 February 2027"),
+        "{:?}",
+        result.steps
+    );
+    assert!(
+        result.steps.iter().any(|s| s == "= 20 February 2027"),
+        "{:?}",
+        result.steps
+    );
+    assert_eq!(
+        result.steps.last().map(String::as_str),
+        Some("Final result: 20 February 2027")
+    );
+}
+
+#[test]
+fn test_format_applies_to_one_calculation_only() {
+    let calc = Calculator::new();
+    let options = CalculationOptions {
+        datetime_format: Some(DateTimeFormat::Long),
+        ..CalculationOptions::default()
+    };
+    calc.calculate_with_options("17 Feb 2027", &options);
+    let result = calc.calculate_internal("17 Feb 2027");
+    assert_eq!(result.result, "2027-02-17");
+    assert_eq!(result.steps.last().unwrap(), "Final result: 2027-02-17");
+}
+
+#[test]
+fn test_calculator_wide_format_from_json() {
+    let mut calc = Calculator::new();
+    assert!(calc.set_format_options(r#"{"datetime_format": {"pattern": "%B %Y"}}"#));
+    let result = calc.calculate_internal("17 Feb 2027");
+    assert_eq!(result.result, "February 2027");
+}
+
+#[test]
+fn test_invalid_pattern_is_an_error() {
+    let calc = Calculator::new();
+    let options = CalculationOptions {
+        datetime_format: Some(DateTimeFormat::Pattern("%Q".to_string())),
+        ..CalculationOptions::default()
+    };
+    let result = calc.calculate_with_options("17 Feb 2027", &options);
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Invalid date format pattern: %Q")
+    );
+}
+
+#[test]
+fn test_times_without_a_date_stay_iso() {
+    let result = calculate("14:30 UTC", DateTimeFormat::Long);
+    assert_eq!(result.result, "14:30:00 UTC");
+}
+
+#[test]
+fn test_options_from_json() {
+    let options: CalculationOptions =
+        serde_json::from_str(r#"{"datetime_format": "weekday"}"#).unwrap();
+    assert_eq!(options.datetime_format, Some(DateTimeFormat::Weekday));
+}
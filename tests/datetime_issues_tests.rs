@@ -9,7 +9,7 @@ mod issue_34_now_keyword {
 
     #[test]
     fn test_now_standalone() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now");
         assert!(result.success, "now should succeed: {:?}", result.error);
         // Result should be a datetime string
@@ -22,7 +22,7 @@ mod issue_34_now_keyword {
 
     #[test]
     fn test_now_utc() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now UTC");
         assert!(result.success, "now UTC should succeed: {:?}", result.error);
         assert!(
@@ -34,21 +34,21 @@ mod issue_34_now_keyword {
 
     #[test]
     fn test_utc_now() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC now");
         assert!(result.success, "UTC now should succeed: {:?}", result.error);
     }
 
     #[test]
     fn test_now_in_parentheses() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("(now)");
         assert!(result.success, "(now) should succeed: {:?}", result.error);
     }
 
     #[test]
     fn test_now_in_subtraction() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // Use a fixed past date so the result is always a positive duration
         let result = calc.calculate_internal("(now) - (Jan 1, 12:00am UTC)");
         assert!(
@@ -66,7 +66,7 @@ mod issue_34_now_keyword {
 
     #[test]
     fn test_datetime_minus_now_utc() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // The exact expression from issue #34
         let result = calc.calculate_internal("(Jan 27, 8:59am UTC) - (now UTC)");
         assert!(
@@ -78,14 +78,14 @@ mod issue_34_now_keyword {
 
     #[test]
     fn test_now_est() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now EST");
         assert!(result.success, "now EST should succeed: {:?}", result.error);
     }
 
     #[test]
     fn test_now_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now");
         assert!(result.success);
         // Lino should wrap now in parentheses
@@ -102,7 +102,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_until_datetime() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("until Jan 27, 11:59pm UTC");
         assert!(result.success, "until should succeed: {:?}", result.error);
         // Result should be a duration
@@ -118,7 +118,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_timezone_est() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("8:59am EST");
         assert!(
             result.success,
@@ -129,7 +129,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_timezone_pst() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("11:59pm PST");
         assert!(
             result.success,
@@ -140,7 +140,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_timezone_cet() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2:30pm CET");
         assert!(
             result.success,
@@ -151,7 +151,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_ordinal_date() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // "January 26th" should be parsed as "January 26"
         let result = calc.calculate_internal("January 26th, 2026");
         assert!(
@@ -163,7 +163,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_until_with_ordinal_and_timezone() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("until 11:59pm EST January 26th");
         assert!(
             result.success,
@@ -174,7 +174,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_day_name_stripping() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // "Monday, January 26" should parse after stripping day name
         let result = calc.calculate_internal("Monday, January 26th, 2026");
         assert!(
@@ -186,7 +186,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_until_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("until Jan 27, 11:59pm UTC");
         assert!(result.success);
         assert!(
@@ -199,7 +199,7 @@ mod issue_23_until_and_natural_dates {
     // Tests for the exact failing input from issue #23
     #[test]
     fn test_until_with_on_and_day_name() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // The exact input from the issue report
         let result = calc.calculate_internal("until 11:59pm EST on Monday, January 26th");
         assert!(
@@ -220,7 +220,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_time_with_on_and_day_name() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // The second form from the issue: standalone time+date with "on" and day name
         // Previously returned "11" (wrong), should now return a datetime
         let result = calc.calculate_internal("11:59pm EST on Monday, January 26th");
@@ -243,7 +243,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_time_with_on_preposition() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // "on" preposition before date without day name
         let result = calc.calculate_internal("11:59pm EST on January 26");
         assert!(
@@ -256,7 +256,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_time_with_timezone_and_ordinal_date() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // Time with timezone and ordinal date (no "on")
         let result = calc.calculate_internal("11:59pm EST January 26th");
         assert!(
@@ -269,7 +269,7 @@ mod issue_23_until_and_natural_dates {
 
     #[test]
     fn test_time_with_on_day_name_lino_is_datetime() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("11:59pm EST on Monday, January 26th");
         assert!(result.success);
         // Lino should be a datetime wrapped in parens, not just "11"
@@ -291,7 +291,7 @@ mod issue_68_utc_time {
 
     #[test]
     fn test_utc_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(
             result.success,
@@ -308,7 +308,7 @@ mod issue_68_utc_time {
 
     #[test]
     fn test_time_utc() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("time UTC");
         assert!(
             result.success,
@@ -319,7 +319,7 @@ mod issue_68_utc_time {
 
     #[test]
     fn test_current_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("current time");
         assert!(
             result.success,
@@ -330,7 +330,7 @@ mod issue_68_utc_time {
 
     #[test]
     fn test_current_utc_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("current UTC time");
         assert!(
             result.success,
@@ -345,7 +345,7 @@ mod issue_45_datetime_display {
 
     #[test]
     fn test_standalone_datetime_shows_elapsed() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // Use a past date
         let result = calc.calculate_internal("Jan 1, 12:00am UTC");
         assert!(result.success);
@@ -360,7 +360,7 @@ mod issue_45_datetime_display {
 
     #[test]
     fn test_standalone_datetime_lino_wrapped() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("Jan 27, 9:33am UTC");
         assert!(result.success);
         // Lino should wrap datetime in parentheses
@@ -373,7 +373,7 @@ mod issue_45_datetime_display {
 
     #[test]
     fn test_datetime_subtraction_still_works() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC)");
         assert!(result.success);
         assert!(
@@ -385,7 +385,7 @@ mod issue_45_datetime_display {
 
     #[test]
     fn test_natural_date_shows_elapsed() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // Note: ISO format "2026-01-01" is parsed as arithmetic (2026-1-1=2024)
         // by the lexer, so we use natural date format instead.
         let result = calc.calculate_internal("January 1, 2026");
@@ -0,0 +1,76 @@
+//! Tests for plot data attached to definite integral results.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_definite_integral_has_plot_with_shaded_bounds() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("integrate(sin(x), x, 0, pi)");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+
+    let plot = result
+        .plot_data
+        .expect("definite integral should have plot data");
+    assert_eq!(plot.shade_from, Some(0.0));
+    let upper = plot.shade_to.expect("upper bound should be marked");
+    assert!((upper - std::f64::consts::PI).abs() < 1e-9);
+
+    // The curve extends past both bounds so the shaded area has context.
+    let first = plot.x_values.first().copied().unwrap_or_default();
+    let last = plot.x_values.last().copied().unwrap_or_default();
+    assert!(first < 0.0, "plot should start before the lower bound");
+    assert!(last > upper, "plot should end after the upper bound");
+    assert_eq!(plot.x_values.len(), plot.y_values.len());
+    assert_eq!(plot.label, "sin(x)");
+}
+
+#[test]
+fn test_reversed_bounds_keep_their_order() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("integrate(x^2, x, 3, 1)");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+
+    let plot = result
+        .plot_data
+        .expect("definite integral should have plot data");
+    assert_eq!(plot.shade_from, Some(3.0));
+    assert_eq!(plot.shade_to, Some(1.0));
+    assert!(plot.x_values.first().copied().unwrap_or_default() < 1.0);
+}
+
+#[test]
+fn test_removable_singularity_is_plotted() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("integrate(sin(x)/x, x, -1, 1)");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+
+    let plot = result
+        .plot_data
+        .expect("definite integral should have plot data");
+    let (i, _) = plot
+        .x_values
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .expect("plot has points");
+    assert!((plot.y_values[i] - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_plain_arithmetic_has_no_plot() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("2 + 3");
+    assert!(result.plot_data.is_none());
+}
+
+#[test]
+fn test_shade_fields_are_omitted_for_indefinite_integrals() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("integrate x^2 dx");
+    let plot = result
+        .plot_data
+        .expect("indefinite integral should have plot data");
+    assert!(plot.shade_from.is_none());
+    let json = serde_json::to_string(&plot).expect("plot data serializes");
+    assert!(!json.contains("shade_from"));
+}
@@ -0,0 +1,107 @@
+//! Tests for discount chains (`200 USD - 20% - 10%`) and the original price
+//! before them.
+
+mod common;
+
+use common::breakdown;
+use link_calculator::Calculator;
+
+#[test]
+fn test_discounts_apply_one_after_another() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("200 USD - 20% - 10%");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "144 USD");
+    assert_eq!(
+        breakdown(&result),
+        [
+            ("Original price".to_string(), "200 USD".to_string()),
+            ("After 20% off".to_string(), "160 USD".to_string()),
+            ("After 10% off".to_string(), "144 USD".to_string()),
+            ("Total discount 28%".to_string(), "56 USD".to_string()),
+        ]
+    );
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step == "After 20% off: 160 USD"));
+
+    let result = calculator.calculate_internal("$200 - 20% - 10% + 5 USD");
+    assert_eq!(result.result, "149 USD");
+}
+
+#[test]
+fn test_plain_numbers_keep_percent_arithmetic() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("200 - 20% - 10%").result,
+        "199.7"
+    );
+    assert_eq!(calculator.calculate_internal("100 - 25%").result, "99.75");
+    assert_eq!(
+        calculator.calculate_internal("100 USD - 25 % 7").result,
+        "96 USD"
+    );
+}
+
+#[test]
+fn test_added_percentages_keep_percent_arithmetic() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("200 USD + 5%").result,
+        "200.05 USD"
+    );
+    assert_eq!(
+        calculator.calculate_internal("200 USD - 20% + 5%").result,
+        "160.05 USD"
+    );
+    assert_eq!(calculator.calculate_internal("100 + 10%").result, "100.1");
+}
+
+#[test]
+fn test_markups_are_taken_in_turn_by_the_function() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("apply_percentages(200 USD, -20%, 5%)");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "168 USD");
+    assert_eq!(
+        breakdown(&result),
+        [
+            ("Original price".to_string(), "200 USD".to_string()),
+            ("After 20% off".to_string(), "160 USD".to_string()),
+            ("After 5% markup".to_string(), "168 USD".to_string()),
+            ("Total discount 16%".to_string(), "32 USD".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_the_value_of_the_amount_decides_the_rule() {
+    let calculator = Calculator::new();
+    for (input, expected) in [
+        ("(200 USD / 100 USD) - 20%", "1.8"),
+        ("200 m - 20%", "199.8 m"),
+    ] {
+        assert_eq!(
+            calculator.calculate_internal(input).result,
+            expected,
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_original_price_before_discounts() {
+    let calculator = Calculator::new();
+    let result =
+        calculator.calculate_internal("final price 144 USD after 20% and 10% discounts, original?");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "200 USD");
+    assert_eq!(breakdown(&result)[1].1, "160 USD");
+
+    let result = calculator.calculate_internal("final price 144 USD after 20% discount");
+    assert_eq!(result.result, "180 USD");
+
+    let result = calculator.calculate_internal("final price 10 USD after 100% discount");
+    assert!(!result.success);
+}
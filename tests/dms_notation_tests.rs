@@ -0,0 +1,99 @@
+//! Tests for angles in degrees, minutes and seconds, compass coordinates
+//! and wrapping arithmetic on angles into one turn.
+
+mod common;
+
+use common::{calculate_with, error};
+use link_calculator::Calculator;
+
+#[test]
+fn test_dms_literals_are_shown_as_written() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "45° 30' 15\""), "45° 30' 15\"");
+    assert_eq!(calculate_with(&calculator, "45°30′15″"), "45° 30' 15\"");
+    assert_eq!(calculate_with(&calculator, "12° 5'"), "12° 5' 0\"");
+    assert_eq!(calculate_with(&calculator, "30'"), "30'");
+}
+
+#[test]
+fn test_dms_converts_to_and_from_decimal_degrees() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "45° 30' 15\" as deg"),
+        "45.50416666666667°"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "45.5042 deg as dms"),
+        "45° 30' 15.12\""
+    );
+    assert_eq!(calculate_with(&calculator, "100 grad as dms"), "90° 0' 0\"");
+    assert_eq!(calculate_with(&calculator, "90'' as '"), "1.5'");
+}
+
+#[test]
+fn test_plain_numbers_convert_as_angles_in_the_angle_mode() {
+    let mut calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "pi in degrees"), "180°");
+    assert_eq!(calculate_with(&calculator, "2*pi in degrees"), "360°");
+    assert_eq!(calculate_with(&calculator, "pi/2 as dms"), "90° 0' 0\"");
+    assert!(calculator.set_angle_mode("degrees"));
+    assert_eq!(calculate_with(&calculator, "90 in degrees"), "90°");
+    assert_eq!(calculate_with(&calculator, "180 in grad"), "200 grad");
+}
+
+#[test]
+fn test_dms_minutes_and_seconds_stay_below_60() {
+    assert!(error("45° 61'").contains("Minutes of an angle must be below 60"));
+    assert!(error("45° 30' 75\"").contains("Seconds of an angle must be below 60"));
+    assert!(error("45° 60'").contains("below 60"));
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "45° 59' 59.5\""),
+        "45° 59' 59.5\""
+    );
+}
+
+#[test]
+fn test_arithmetic_on_dms_angles() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "10° 45' + 20° 30'"),
+        "31° 15' 0\""
+    );
+    assert_eq!(
+        calculate_with(&calculator, "45° 30' 15\" / 2"),
+        "22° 45' 7.5\""
+    );
+    assert_eq!(calculate_with(&calculator, "sin(30° 0' 0\")"), "0.5");
+}
+
+#[test]
+fn test_compass_directions_give_the_sign_of_coordinates() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "33° 52' S"), "-33° 52' 0\"");
+    assert_eq!(
+        calculate_with(
+            &calculator,
+            "distance between (55° 45' 21\" N, 37° 37' 2\" E) and (48° 51' 24\" N, 2° 21' 8\" E)"
+        ),
+        "2486.238 km"
+    );
+}
+
+#[test]
+fn test_angle_arithmetic_wraps_when_asked() {
+    let mut calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "350° + 20°"), "370°");
+    assert!(calculator.set_angle_wrap("360"));
+    assert_eq!(calculate_with(&calculator, "350° + 20°"), "10°");
+    assert_eq!(calculate_with(&calculator, "10° - 30°"), "340°");
+    assert!(calculator.set_angle_wrap("180"));
+    assert_eq!(calculate_with(&calculator, "170° + 20°"), "-170°");
+    assert_eq!(
+        calculate_with(&calculator, "179° 30' + 1° 15'"),
+        "-179° 15' 0\""
+    );
+    assert!(!calculator.set_angle_wrap("sideways"));
+    // Minutes of arc are not an angle mode
+    assert!(!calculator.set_angle_mode("arcmin"));
+}
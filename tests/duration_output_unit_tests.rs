@@ -0,0 +1,47 @@
+//! Tests for displaying durations in a requested unit (`... in weeks`).
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_date_difference_in_requested_unit() {
+    assert_eq!(calculate("((Jan 27) - (Jan 20)) in hours"), "168 hours");
+    assert_eq!(
+        calculate("((Jan 27) - (Jan 20)) in minutes"),
+        "10080 minutes"
+    );
+    assert_eq!(calculate("((Jan 27) - (Jan 20)) in weeks"), "1 week");
+    assert_eq!(calculate("((Jan 20) - (Jan 27)) in weeks"), "-1 week");
+}
+
+#[test]
+fn test_date_difference_with_fractional_output() {
+    assert_eq!(
+        calculate("((Jan 30, 12:00) - (Jan 20)) in weeks"),
+        "1.5 weeks"
+    );
+    assert_eq!(
+        calculate("((Jan 21, 6:00) - (Jan 20)) in days"),
+        "1.25 days"
+    );
+}
+
+#[test]
+fn test_duration_unit_conversion_is_exact() {
+    assert_eq!(calculate("90 minutes in hours"), "1.5 hours");
+    assert_eq!(calculate("21 days in weeks"), "3 weeks");
+
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("10 days in weeks");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.fraction.as_deref(), Some("10/7"));
+}
+
+#[test]
+fn test_single_units_use_singular_names() {
+    assert_eq!(calculate("1 hour"), "1 hour");
+    assert_eq!(calculate("60 minutes in hours"), "1 hour");
+    assert_eq!(calculate("2 hours"), "2 hours");
+}
@@ -0,0 +1,53 @@
+//! Tests for multiplying and dividing durations by numbers, and for
+//! duration literals with several parts.
+
+mod common;
+
+use common::calculate;
+
+#[test]
+fn test_duration_literal_with_several_parts() {
+    assert_eq!(calculate("2 hours 15 minutes"), "2 hours, 15 minutes");
+    assert_eq!(calculate("1 week 2 days"), "9 days");
+    assert_eq!(calculate("2 hours 15 minutes as minutes"), "135 minutes");
+}
+
+#[test]
+fn test_number_times_duration() {
+    assert_eq!(calculate("3 * (2 hours 15 minutes)"), "6 hours, 45 minutes");
+    assert_eq!(calculate("(2 hours 15 minutes) * 3"), "6 hours, 45 minutes");
+    assert_eq!(calculate("3 * 2 hours"), "6 hours");
+    assert_eq!(calculate("1.5 hours * 3"), "4 hours, 30 minutes");
+}
+
+#[test]
+fn test_duration_divided_by_number() {
+    assert_eq!(calculate("(1 day) / 4"), "6 hours");
+    assert_eq!(calculate("1 hour / 2"), "30 minutes");
+    assert_eq!(calculate("(2 hours 15 minutes) / 3"), "45 minutes");
+    // Months and years vary in length, so they stay in their unit
+    assert_eq!(calculate("1 month / 2"), "0.5 months");
+}
+
+#[test]
+fn test_duration_divided_by_duration_is_a_ratio() {
+    assert_eq!(calculate("(2 hours)/(30 minutes)"), "4");
+    assert_eq!(
+        calculate("((2026-01-03) - (2026-01-01)) / ((2026-01-02) - (2026-01-01))"),
+        "2"
+    );
+    assert_eq!(calculate("(1 day) / ((2026-01-02) - (2026-01-01))"), "1");
+}
+
+#[test]
+fn test_adding_durations_in_different_units() {
+    assert_eq!(calculate("2 hours + 15 minutes"), "2 hours, 15 minutes");
+    assert_eq!(calculate("1 day - 1 hour"), "23 hours");
+}
+
+#[test]
+fn test_date_difference_scaled_by_number() {
+    assert_eq!(calculate("((2026-01-03) - (2026-01-01)) * 2"), "4 days");
+    // Whole days still divide as a day count (issue #191)
+    assert_eq!(calculate("((2026-01-03) - (2026-01-01)) / 4"), "0.5");
+}
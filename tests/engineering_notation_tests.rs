@@ -0,0 +1,87 @@
+//! Tests for `eng(x)`, which writes an amount with an SI prefix.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_eng_picks_the_prefix_for_each_unit() {
+    assert_eq!(calculate("eng(4700 ohm)"), "4.7 kΩ");
+    assert_eq!(calculate("eng(0.000012 s)"), "12 µs");
+    assert_eq!(calculate("eng(0.0047 km)"), "4.7 m");
+    assert_eq!(calculate("eng(2500 kg)"), "2.5 Mg");
+    assert_eq!(calculate("eng(4700)"), "4.7k");
+    assert_eq!(calculate("eng(3 * 10^9)"), "3G");
+}
+
+#[test]
+fn test_eng_moves_to_the_next_prefix_when_rounding_reaches_1000() {
+    assert_eq!(calculate("eng(999999.9999999999999)"), "1M");
+    assert_eq!(calculate("eng(-999999.9999999999999)"), "-1M");
+    assert_eq!(calculate("eng(0.00099999999999999999 s)"), "1 ms");
+    assert_eq!(calculate("eng(999999.999)"), "999.999999k");
+}
+
+#[test]
+fn test_short_durations_are_written_with_prefix_symbols() {
+    assert_eq!(calculate("0.05 s"), "50 ms");
+    assert_eq!(calculate("0.000001 s"), "1 µs");
+    assert_eq!(calculate("0.000000003 s"), "3 ns");
+    assert_eq!(calculate("eng(0.5 s)"), "500 ms");
+}
+
+#[test]
+fn test_tenths_keep_the_base_unit() {
+    assert_eq!(calculate("0.5 seconds"), "0.5 seconds");
+    assert_eq!(calculate("0.5 A"), "0.5 A");
+    assert_eq!(calculate("0.0005 kW"), "0.5 W");
+    assert_eq!(calculate("1500 ms"), "1500 milliseconds");
+    assert_eq!(calculate("0.5 s in ms"), "500 milliseconds");
+}
+
+#[test]
+fn test_eng_explains_the_call_in_steps() {
+    let result = Calculator::new().calculate_internal("eng(4700 ohm)");
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Call function: eng(")));
+}
+
+#[test]
+fn test_eng_rejects_money() {
+    let result = Calculator::new().calculate_internal("eng(5 USD)");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_physics_units_and_short_durations_get_prefixes_on_their_own() {
+    assert_eq!(calculate("4700 ohm"), "4.7 kΩ");
+    assert_eq!(calculate("0.000012 s"), "12 µs");
+    assert_eq!(calculate("12 V / 4700 ohm"), "2.553191489361702 mA");
+    assert_eq!(calculate("0.5 kW"), "500 W");
+    assert_eq!(calculate("10 ohm"), "10 Ω");
+    assert_eq!(calculate("1500 s"), "1500 seconds");
+    assert_eq!(calculate("1500 m"), "1500 m");
+    assert_eq!(calculate("0.0025 A to 2 dp"), "0.00 A");
+    assert_eq!(calculate("0.5 s in seconds"), "0.5 seconds");
+}
+
+#[test]
+fn test_eng_writes_prefixes_like_the_automatic_ones() {
+    for input in ["0.000012 s", "10 ohm", "4700 ohm", "30 V / 10 A", "0.012 A"] {
+        assert_eq!(calculate(&format!("eng({input})")), calculate(input));
+    }
+}
+
+#[test]
+fn test_automatic_prefixes_can_be_turned_off() {
+    let mut calculator = Calculator::new();
+    calculator.set_si_prefixes(false);
+    assert_eq!(calculator.calculate_internal("4700 ohm").result, "4700 ohm");
+    assert_eq!(
+        calculator.calculate_internal("0.000012 s").result,
+        "0.000012 seconds"
+    );
+}
@@ -12,7 +12,7 @@ use link_calculator::Calculator;
 /// Tests for the exact example from issue #87.
 #[test]
 fn test_issue_87_example() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 * (2 / 3) = (1 * 2) / 3");
     assert!(
         result.success,
@@ -27,7 +27,7 @@ fn test_issue_87_example() {
 
 #[test]
 fn test_simple_equality_true() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 + 1 = 2");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -35,7 +35,7 @@ fn test_simple_equality_true() {
 
 #[test]
 fn test_simple_equality_false() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 + 1 = 3");
     assert!(result.success);
     assert_eq!(result.result, "false");
@@ -43,7 +43,7 @@ fn test_simple_equality_false() {
 
 #[test]
 fn test_equality_with_multiplication() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("3 * 4 = 12");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -51,7 +51,7 @@ fn test_equality_with_multiplication() {
 
 #[test]
 fn test_equality_with_division() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10 / 2 = 5");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -59,7 +59,7 @@ fn test_equality_with_division() {
 
 #[test]
 fn test_equality_both_sides_have_operations() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // 2 + 3 = 1 + 4 → both equal 5 → true
     let result = calc.calculate_internal("2 + 3 = 1 + 4");
     assert!(result.success);
@@ -68,7 +68,7 @@ fn test_equality_both_sides_have_operations() {
 
 #[test]
 fn test_equality_lino_notation() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 + 1 = 2");
     assert!(result.success);
     assert!(
@@ -81,7 +81,7 @@ fn test_equality_lino_notation() {
 
 #[test]
 fn test_equality_issue_87_lino_notation() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 * (2 / 3) = (1 * 2) / 3");
     assert!(result.success);
     // Both sides should be wrapped in their own lino expressions
@@ -94,7 +94,7 @@ fn test_equality_issue_87_lino_notation() {
 
 #[test]
 fn test_equality_steps_show_comparison() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2 + 2 = 4");
     assert!(result.success);
     let steps = result.steps.join("\n");
@@ -110,7 +110,7 @@ fn test_equality_steps_show_comparison() {
 
 #[test]
 fn test_equality_with_decimal() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("0.5 + 0.5 = 1");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -118,7 +118,7 @@ fn test_equality_with_decimal() {
 
 #[test]
 fn test_equality_negative_numbers() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("-1 + -1 = -2");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -126,7 +126,7 @@ fn test_equality_negative_numbers() {
 
 #[test]
 fn test_equality_with_parentheses() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(2 + 3) * 4 = 20");
     assert!(result.success);
     assert_eq!(result.result, "true");
@@ -134,7 +134,7 @@ fn test_equality_with_parentheses() {
 
 #[test]
 fn test_equality_associativity() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Multiplication is associative: (a * b) * c = a * (b * c)
     let result = calc.calculate_internal("(2 * 3) * 4 = 2 * (3 * 4)");
     assert!(result.success);
@@ -143,7 +143,7 @@ fn test_equality_associativity() {
 
 #[test]
 fn test_equality_unequal_different_operations() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // 5 - 3 = 4 - 1 → 2 ≠ 3 → false
     let result = calc.calculate_internal("5 - 3 = 4 - 1");
     assert!(result.success);
@@ -0,0 +1,60 @@
+//! Tests for `is <expr> equivalent to <expr>`.
+
+mod common;
+
+use common::{calculate, calculate_result};
+
+#[test]
+fn test_factored_form_is_equivalent_by_simplification() {
+    let result = calculate_result("is x^2 - 1 equivalent to (x-1)(x+1)");
+    assert_eq!(result.result, "true");
+    assert!(result
+        .steps
+        .contains(&"The difference is 0, so the expressions are equivalent".to_string()));
+    assert_eq!(calculate("is x^2 - 1 equivalent to (x-1)(x+1)?"), "true");
+}
+
+#[test]
+fn test_identities_simplification_misses_are_probed_numerically() {
+    let result = calculate_result("is sin(x)^2 + cos(x)^2 equivalent to 1");
+    assert_eq!(result.result, "true");
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Both sides agree at")));
+}
+
+#[test]
+fn test_different_expressions_are_not_equivalent() {
+    let result = calculate_result("is (x+1)^2 equivalent to x^2 + 1");
+    assert_eq!(result.result, "false");
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("The sides differ at x = ")));
+    assert_eq!(calculate("is 2 + 2 equivalent to 5"), "false");
+}
+
+#[test]
+fn test_several_variables_and_implicit_products() {
+    assert_eq!(calculate("is x y + y equivalent to y*(x+1)"), "true");
+    assert_eq!(calculate("is 2x + 3 equivalent to 3 + x*2"), "true");
+    assert_eq!(calculate("is 1/x equivalent to 2/(2x)"), "true");
+    assert_eq!(
+        calculate("is sin(2x) equivalent to 2*sin(x)*cos(x)"),
+        "true"
+    );
+    assert_eq!(calculate("is 1/x equivalent to 3/(2x)"), "false");
+}
+
+#[test]
+fn test_the_same_verdict_every_time() {
+    let first = calculate_result("is sin(x) equivalent to x").steps;
+    let second = calculate_result("is sin(x) equivalent to x").steps;
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_leap_year_questions_still_work() {
+    assert_eq!(calculate("is 2028 a leap year"), "true");
+}
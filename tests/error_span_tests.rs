@@ -0,0 +1,72 @@
+//! Tests for error spans pointing at the offending part of the input.
+
+use link_calculator::error::ErrorSpan;
+use link_calculator::Calculator;
+
+fn error_span(input: &str) -> Option<ErrorSpan> {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(!result.success, "{input} should fail");
+    result.error_span
+}
+
+#[test]
+fn test_unexpected_character_span() {
+    assert_eq!(error_span("2 @ 3"), Some(ErrorSpan::new(2, 3)));
+}
+
+#[test]
+fn test_span_accounts_for_leading_whitespace() {
+    assert_eq!(error_span("   2 @ 3"), Some(ErrorSpan::new(5, 6)));
+}
+
+#[test]
+fn test_span_counts_characters_not_bytes() {
+    assert_eq!(error_span("€5 + @"), Some(ErrorSpan::new(5, 6)));
+}
+
+#[test]
+fn test_trailing_input_span() {
+    assert_eq!(error_span("2 + 3)"), Some(ErrorSpan::new(5, 6)));
+}
+
+#[test]
+fn test_missing_closing_paren_points_at_end() {
+    assert_eq!(error_span("(2 + 3"), Some(ErrorSpan::new(6, 6)));
+}
+
+#[test]
+fn test_unknown_unit_span() {
+    assert_eq!(error_span("10 kg in flibbles"), Some(ErrorSpan::new(9, 17)));
+}
+
+#[test]
+fn test_evaluation_errors_have_no_span() {
+    assert_eq!(error_span("1 / 0"), None);
+}
+
+#[test]
+fn test_span_is_serialized() {
+    let result = Calculator::new().calculate_internal("2 @ 3");
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["error_span"]["start"], 2);
+    assert_eq!(json["error_span"]["end"], 3);
+
+    let ok = Calculator::new().calculate_internal("2 + 3");
+    let json = serde_json::to_value(&ok).unwrap();
+    assert!(json.get("error_span").is_none());
+}
+
+#[test]
+fn test_parse_errors_name_the_offending_token() {
+    let message = |input: &str| Calculator::new().calculate_internal(input).error.unwrap();
+    assert_eq!(message("2 + "), "Parse error: Unexpected end of input");
+    assert_eq!(message(")"), "Parse error: Unexpected ')'");
+    assert_eq!(
+        message("(2"),
+        "Parse error: Expected ')', found end of input"
+    );
+    assert_eq!(
+        message("integrate x + ) dx"),
+        "Parse error: Unexpected ')' in integrand"
+    );
+}
@@ -0,0 +1,109 @@
+//! Tests for configurable evaluation limits (`EvalConfig`).
+
+use link_calculator::Calculator;
+
+fn error_key(calculator: &Calculator, input: &str) -> String {
+    let result = calculator.calculate_internal(input);
+    assert!(!result.success, "{input} should fail");
+    result.error_info.unwrap().key
+}
+
+#[test]
+fn test_deep_nesting_is_rejected_before_parsing() {
+    let calculator = Calculator::new();
+    let input = format!("{}1{}", "(".repeat(5000), ")".repeat(5000));
+    assert_eq!(error_key(&calculator, &input), "errors.limitExceeded");
+    assert!(!calculator.validate_internal(&input).valid);
+}
+
+#[test]
+fn test_long_number_literals_are_rejected_before_parsing() {
+    let calculator = Calculator::new();
+    let input = format!("0.{}1", "0".repeat(30_000));
+    assert_eq!(error_key(&calculator, &input), "errors.limitExceeded");
+    let input = format!("0.{}1", "0".repeat(100));
+    assert!(calculator.calculate_internal(&input).success);
+}
+
+#[test]
+fn test_max_depth_is_configurable() {
+    let mut calculator = Calculator::new();
+    calculator.set_max_depth(4);
+    assert!(calculator.calculate_internal("((1 + 2))").success);
+    assert_eq!(
+        error_key(&calculator, "(((1 + 2)))"),
+        "errors.limitExceeded"
+    );
+    // Long flat sums nest one level per operator
+    assert_eq!(
+        error_key(&calculator, "1 + 1 + 1 + 1 + 1"),
+        "errors.limitExceeded"
+    );
+}
+
+#[test]
+fn test_max_factorial_input() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.calculate_internal("20!").success);
+    calculator.set_max_factorial_input(10);
+    assert!(calculator.calculate_internal("10!").success);
+    assert_eq!(error_key(&calculator, "11!"), "errors.limitExceeded");
+    assert_eq!(
+        error_key(&calculator, "factorial(12)"),
+        "errors.limitExceeded"
+    );
+}
+
+#[test]
+fn test_operation_budget() {
+    let mut calculator = Calculator::new();
+    calculator.set_operation_budget(Some(50));
+    assert!(calculator.calculate_internal("2 + 3 * 4").success);
+    assert_eq!(
+        error_key(&calculator, "integrate(sqrt(x), x, 0, 1)"),
+        "errors.limitExceeded"
+    );
+    // The budget is per calculation
+    assert!(calculator.calculate_internal("2 + 3 * 4").success);
+
+    calculator.set_operation_budget(None);
+    assert!(
+        calculator
+            .calculate_internal("integrate(x^2, x, 0, 1)")
+            .success
+    );
+}
+
+#[test]
+fn test_integration_subdivisions() {
+    let mut calculator = Calculator::new();
+    calculator.set_max_integration_subdivisions(1);
+    // Gauss–Kronrod is exact for cubics without subdividing at all
+    let result = calculator.calculate_internal("integrate(x^3, x, 0, 2)");
+    assert_eq!(result.result, "4");
+}
+
+#[test]
+fn test_history_keeps_latest_entries() {
+    let mut calculator = Calculator::new();
+    calculator.set_max_history_entries(2);
+    for input in ["1", "2", "3"] {
+        assert!(calculator.calculate_internal(input).success);
+    }
+    assert_eq!(calculator.history().len(), 2);
+    assert_eq!(calculator.calculate_internal("#3").result, "3");
+    assert!(!calculator.calculate_internal("#1").success);
+}
+
+#[test]
+fn test_set_eval_config_json() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_eval_config(r#"{"max_depth": 3, "operation_budget": 10}"#));
+    assert_eq!(calculator.eval_config().max_depth, 3);
+    assert_eq!(calculator.eval_config().operation_budget, Some(10));
+    assert_eq!(calculator.eval_config().max_history_entries, 1000);
+    assert!(!calculator.set_eval_config("not json"));
+
+    calculator.clear_eval_config();
+    assert_eq!(calculator.eval_config().max_depth, 200);
+}
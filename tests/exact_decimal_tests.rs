@@ -0,0 +1,131 @@
+//! Tests that decimal arithmetic, rounding and unit conversions stay exact
+//! instead of picking up binary floating-point noise.
+
+mod common;
+
+use common::{calculate, calculate_result, exact_digits};
+use link_calculator::Calculator;
+
+#[test]
+fn test_decimal_sums_have_no_binary_noise() {
+    assert_eq!(calculate("0.1 + 0.2"), "0.3");
+    assert_eq!(calculate("123456789012345.67 + 0"), "123456789012345.67");
+    assert_eq!(calculate("100 USD / 3"), "33.33 USD");
+}
+
+#[test]
+fn test_literals_past_28_digits_keep_every_digit() {
+    let big = "123456789012345678901234567890123";
+    assert_eq!(exact_digits(&calculate_result(big)), big);
+    assert_eq!(
+        calculate(&format!("{big} - 123456789012345678901234567890122")),
+        "1"
+    );
+    assert_eq!(
+        calculate("0.123456789012345678901234567891 - 0.12345678901234567890123456789"),
+        "1e-30"
+    );
+    assert_eq!(
+        exact_digits(&calculate_result(
+            "0.123456789012345678901234567891 * 10^30"
+        )),
+        "123456789012345678901234567891"
+    );
+}
+
+#[test]
+fn test_rounding_functions_are_exact() {
+    assert_eq!(calculate("floor(123456789012345.67)"), "123456789012345");
+    assert_eq!(
+        calculate("abs(-1234567890.123456789)"),
+        "1234567890.123456789"
+    );
+    assert_eq!(calculate("round(2.675, 2)"), "2.68");
+    assert_eq!(calculate("round(1250, -2)"), "1300");
+    assert_eq!(calculate("floor(2.7 kg)"), "2 kg");
+}
+
+#[test]
+fn test_sign_of_zero_is_zero() {
+    assert_eq!(calculate("sign(0)"), "0");
+    assert_eq!(calculate("sign(-3 kg)"), "-1");
+}
+
+#[test]
+fn test_min_and_max_keep_every_digit() {
+    assert_eq!(
+        calculate("min(0.123456789012345678, 1)"),
+        "0.123456789012345678"
+    );
+    assert_eq!(calculate("max(0.1, 0.2, 0.15)"), "0.2");
+}
+
+#[test]
+fn test_mass_and_data_size_conversions_are_exact() {
+    assert_eq!(calculate("1 lb as g"), "453.59237 g");
+    assert_eq!(calculate("1 GiB as MB"), "1073.741824 MB");
+}
+
+#[test]
+fn test_roots_of_large_numbers_are_not_rounded_through_floats() {
+    assert_eq!(calculate("sqrt(10^40)"), "1e20");
+    assert_eq!(calculate("sqrt(10^40) - 10^20"), "0");
+    // Shown to 16 digits, but a precision gets the rest
+    assert_eq!(calculate("sqrt(99999999999999999999)"), "10000000000");
+    assert_eq!(
+        calculate("sqrt(99999999999999999999) to 11 dp"),
+        "9999999999.99999999995"
+    );
+    assert_eq!(calculate("cbrt(10^40)"), "21544346900318.84");
+    assert_eq!(
+        calculate("cbrt(10^40) to 14 dp"),
+        "21544346900318.83721759293567"
+    );
+}
+
+#[test]
+fn test_roots_past_the_decimal_range_are_shown_in_scientific_notation() {
+    assert_eq!(calculate("(2^1000)^(1/3)"), "2.204610583642e100");
+    assert_eq!(calculate("1e400^(1/3)"), "2.154434690032e133");
+    assert_eq!(calculate("2^100.5"), "1.7927286711932e30");
+    // Too many digits to take the root of, so taken on a log scale
+    assert_eq!(calculate("(2^300001)^(1/3)"), "1.2586637659e30103");
+}
+
+#[test]
+fn test_high_roots_of_numbers_past_a_decimal_are_taken_on_a_log_scale() {
+    assert_eq!(calculate("(2^1024)^(1/1024)"), "2");
+    assert_eq!(calculate("(2^1024)^(-1/1024)"), "0.5");
+    assert_eq!(calculate("(10^-400)^(1/1001)"), "0.398473643076459");
+}
+
+#[test]
+fn test_irrational_results_are_shown_to_16_digits() {
+    assert_eq!(calculate("sqrt(2)"), "1.414213562373095");
+    assert_eq!(calculate("sqrt(2) * sqrt(3)"), "2.449489742783178");
+    assert_eq!(calculate("sqrt(2) to 4 sf"), "1.414");
+    let result = calculate_result("sqrt(2)");
+    assert_eq!(exact_digits(&result), "1.414213562373095");
+    assert!(result
+        .alternatives
+        .iter()
+        .any(|representation| representation.value == "141.4213562373095%"));
+}
+
+#[test]
+fn test_arguments_past_the_decimal_range_are_an_error() {
+    for input in ["sin(10^30)", "ln(10^40)"] {
+        let result = Calculator::new().calculate_internal(input);
+        assert!(!result.success, "{input} gave {}", result.result);
+    }
+}
+
+#[test]
+fn test_very_large_and_small_numbers_are_shown_in_scientific_notation() {
+    assert_eq!(calculate("2^500"), "3.273390607896142e150");
+    assert_eq!(calculate("10^15 - 1"), "999999999999999");
+    assert_eq!(calculate("0.000001"), "0.000001");
+    assert_eq!(calculate("0.0000001"), "1e-7");
+    assert_eq!(calculate("(2/3)^100000"), "7.483321982518254e-17610");
+    assert_eq!(calculate("10^10000"), "1e10000");
+}
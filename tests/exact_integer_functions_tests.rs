@@ -0,0 +1,102 @@
+//! Tests for factorials, binomial coefficients and powers computed exactly
+//! over big integers.
+
+mod common;
+
+use common::{calculate, calculate_result, exact_digits};
+use link_calculator::{Calculator, RepresentationKind};
+
+#[test]
+fn test_factorial_is_exact_past_the_range_of_floats() {
+    assert_eq!(calculate("25!"), "1.551121004333099e25");
+    assert_eq!(
+        exact_digits(&calculate_result("25!")),
+        "15511210043330985984000000"
+    );
+
+    let result = calculate_result("factorial(1000)");
+    let digits = exact_digits(&result);
+    assert_eq!(digits.len(), 2568);
+    assert!(digits.starts_with("402387260077093773543702433923"));
+    assert!(digits.ends_with(&"0".repeat(249)));
+}
+
+#[test]
+fn test_big_integers_have_a_scientific_approximation() {
+    let result = calculate_result("factorial(1000)");
+    let scientific = result
+        .alternatives
+        .iter()
+        .find(|representation| representation.kind == RepresentationKind::Scientific)
+        .map(|representation| representation.value.as_str());
+    assert_eq!(scientific, Some("4.023872600770938e2567"));
+}
+
+#[test]
+fn test_big_integers_are_grouped_by_the_format_locale() {
+    let mut calc = Calculator::new();
+    calc.set_format_locale("en");
+    let result = calc.calculate_internal("factorial(17)");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "355,687,428,096,000");
+}
+
+#[test]
+fn test_binomial_coefficients() {
+    assert_eq!(calculate("ncr(10, 3)"), "120");
+    assert_eq!(
+        exact_digits(&calculate_result("choose(100, 50)")),
+        "100891344545564193334812497256"
+    );
+    assert_eq!(calculate("ncr(3, 5)"), "0");
+
+    let result = Calculator::new().calculate_internal("ncr(10, -1)");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_pow_matches_the_power_operator() {
+    assert_eq!(calculate("pow(2, 500)"), calculate("2^500"));
+    assert_eq!(calculate("pow(2, -3)"), "0.125");
+}
+
+#[test]
+fn test_fractional_powers_are_exact_when_the_root_is_rational() {
+    assert_eq!(calculate("8^(1/3)"), "2");
+    assert_eq!(calculate("4^(3/2)"), "8");
+    assert_eq!(calculate("16^-0.25"), "0.5");
+    assert_eq!(calculate("(-8)^(1/3)"), "-2");
+    assert_eq!(
+        calculate_result("(8/27)^(2/3)").fraction.as_deref(),
+        Some("4/9")
+    );
+    // Irrational roots are calculated to 28 digits and shown to 16
+    assert_eq!(calculate("2^(1/2)"), "1.414213562373095");
+    assert_eq!(
+        calculate("2^(1/2) to 27 dp"),
+        "1.414213562373095048801688724"
+    );
+    assert_eq!(calculate("2^(1/2)"), calculate("sqrt(2)"));
+}
+
+#[test]
+fn test_irrational_roots_of_big_numbers_are_not_rounded_through_floats() {
+    assert_eq!(
+        calculate("99999999999999999999^(1/2) to 11 dp"),
+        "9999999999.99999999995"
+    );
+    assert_eq!(calculate("(10^40+1)^(1/2)"), "1e20");
+    assert_eq!(calculate("10^(1/3)"), "2.154434690031884");
+    assert_eq!(
+        calculate("10^(1/3) to 27 dp"),
+        "2.154434690031883721759293567"
+    );
+}
+
+#[test]
+fn test_binomial_input_is_limited_like_factorial() {
+    let mut calc = Calculator::new();
+    calc.set_max_factorial_input(50);
+    let result = calc.calculate_internal("ncr(51, 2)");
+    assert_eq!(result.error_info.unwrap().key, "errors.limitExceeded");
+}
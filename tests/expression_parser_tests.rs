@@ -1,7 +1,7 @@
 //! Unit tests for the expression parser module.
 
 use link_calculator::grammar::ExpressionParser;
-use link_calculator::types::{Decimal, Expression, Unit};
+use link_calculator::types::{Expression, Rational, Unit};
 
 #[test]
 fn test_parse_simple_number() {
@@ -22,7 +22,7 @@ fn test_parse_currency() {
     let parser = ExpressionParser::new();
     let expr = parser.parse("100 USD").unwrap();
     if let Expression::Number { value, unit, .. } = expr {
-        assert_eq!(value, Decimal::new(100));
+        assert_eq!(value, Rational::from_integer(100));
         assert_eq!(unit, Unit::currency("USD"));
     } else {
         panic!("Expected Number expression");
@@ -0,0 +1,75 @@
+//! Tests for the health formulas `bmi` and `bmr`, and for finding them
+//! through function suggestions.
+
+mod common;
+
+use common::{calculate_with_steps, error};
+use link_calculator::{Calculator, FunctionCategory};
+
+#[test]
+fn test_bmi_from_metric_measurements() {
+    let (result, steps) = calculate_with_steps("bmi(70 kg, 1.75 m)");
+    assert_eq!(result, "22.9");
+    assert!(steps.iter().any(|s| s == "= 70 / 1.75^2"));
+    assert!(steps
+        .iter()
+        .any(|s| s == "Classification: normal weight (18.5 to 24.9)"));
+}
+
+#[test]
+fn test_bmi_converts_imperial_measurements() {
+    let (result, _) = calculate_with_steps("bmi(154 lb, 5.75 ft)");
+    assert_eq!(result, "22.7");
+    let (result, steps) = calculate_with_steps("bmi(95 kg, 175 cm)");
+    assert_eq!(result, "31");
+    assert!(steps
+        .iter()
+        .any(|s| s == "Classification: obese (30 or more)"));
+}
+
+#[test]
+fn test_bmr_by_mifflin_st_jeor() {
+    let (result, steps) = calculate_with_steps("bmr(male, 30 years, 180 cm, 75 kg)");
+    assert_eq!(result, "1730 kcal/day");
+    assert!(steps
+        .iter()
+        .any(|s| s == "= 10 * 75 + 6.25 * 180 - 5 * 30 + 5"));
+    let (result, _) = calculate_with_steps("bmr(female, 25, 165, 60)");
+    assert_eq!(result, "1345 kcal/day");
+}
+
+#[test]
+fn test_invalid_measurements_are_rejected() {
+    assert!(error("bmi(70 kg, 0 m)").contains("height must be positive"));
+    assert!(error("bmi(70 kg, 5 s)").contains("height"));
+    assert!(error("bmr(alien, 30, 180, 75)").contains("male or female"));
+    assert!(error("bmr(male, 30, 180)").contains("4 arguments"));
+}
+
+#[test]
+fn test_health_functions_are_suggested() {
+    let calculator = Calculator::new();
+    let suggestions = calculator.suggest_internal("bm");
+    let names: Vec<_> = suggestions.iter().map(|f| f.name).collect();
+    assert_eq!(names, ["bmi", "bmr"]);
+    assert!(suggestions
+        .iter()
+        .all(|f| f.category == FunctionCategory::Health));
+    assert_eq!(suggestions[0].signature, "bmi(weight, height)");
+}
+
+#[test]
+fn test_suggestions_complete_the_last_word() {
+    let calculator = Calculator::new();
+    let names: Vec<_> = calculator
+        .suggest_internal("2 * sq")
+        .iter()
+        .map(|f| f.name)
+        .collect();
+    assert_eq!(names, ["sqrt"]);
+    assert!(calculator.suggest_internal("2 + ").is_empty());
+    assert!(calculator.suggest_internal("").is_empty());
+    let json = calculator.suggest("BM");
+    assert!(json.contains(r#""name":"bmi""#), "{json}");
+    assert!(json.contains(r#""category":"health""#), "{json}");
+}
@@ -0,0 +1,59 @@
+//! Tests for historical rate interpolation and stale rate warnings.
+
+mod common;
+
+use common::calculator_with_lino_rates;
+
+const USD_GBP_RATES: &str = "conversion:
+  from USD
+  to GBP
+  source 'test'
+  rates:
+    2021-01-01 0.70
+    2021-01-11 0.80";
+
+#[test]
+fn test_previous_rate_is_the_default() {
+    let calculator = calculator_with_lino_rates(USD_GBP_RATES);
+    let result = calculator.calculate_internal("USD/GBP rate at Jan 5, 2021");
+    assert_eq!(result.result, "0.7");
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_stale_rate_warns() {
+    let mut calculator = calculator_with_lino_rates(USD_GBP_RATES);
+    let result = calculator.calculate_internal("100 USD in GBP at Feb 1, 2021");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "80 GBP");
+    assert_eq!(
+        result.warnings,
+        ["The nearest known USD/GBP rate is from 2021-01-11, 21 days before 2021-02-01"]
+    );
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Warning: The nearest known USD/GBP rate")));
+
+    calculator.set_stale_rate_days(30);
+    let result = calculator.calculate_internal("100 USD in GBP at Feb 1, 2021");
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_nearest_and_interpolated_rates() {
+    let mut calculator = calculator_with_lino_rates(USD_GBP_RATES);
+    assert!(calculator.set_historical_rate_mode("nearest"));
+    let result = calculator.calculate_internal("USD/GBP rate at Jan 9, 2021");
+    assert_eq!(result.result, "0.8");
+
+    assert!(calculator.set_historical_rate_mode("interpolate"));
+    let result = calculator.calculate_internal("100 USD in GBP at Jan 9, 2021");
+    assert_eq!(result.result, "78 GBP");
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.contains("interpolated between 2021-01-01 and 2021-01-11")));
+
+    assert!(!calculator.set_historical_rate_mode("latest"));
+}
@@ -0,0 +1,154 @@
+//! Tests for the result history: `ans`, `#n` references and history serialization.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::Calculator;
+
+#[test]
+fn test_ans_refers_to_the_latest_result() {
+    let calculator = Calculator::new();
+    calculate_with(&calculator, "2 + 3");
+    assert_eq!(calculate_with(&calculator, "ans * 2"), "10");
+    assert_eq!(calculate_with(&calculator, "ans + 1"), "11");
+}
+
+#[test]
+fn test_numbered_references_pick_earlier_results() {
+    let calculator = Calculator::new();
+    for input in ["1", "2", "3", "4", "5"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(calculate_with(&calculator, "#3 + #5"), "8");
+
+    let result = calculator.calculate_internal("#3 + #5");
+    assert_eq!(result.lino_interpretation, "((#3) + (#5))");
+}
+
+#[test]
+fn test_references_keep_units() {
+    let calculator = Calculator::new();
+    calculate_with(&calculator, "3 kg");
+    assert_eq!(calculate_with(&calculator, "ans * 2"), "6 kg");
+}
+
+#[test]
+fn test_failed_calculations_are_not_recorded() {
+    let calculator = Calculator::new();
+    calculate_with(&calculator, "7");
+    assert!(!calculator.calculate_internal("1 / 0").success);
+    assert_eq!(calculator.history().len(), 1);
+    assert_eq!(calculate_with(&calculator, "ans"), "7");
+}
+
+#[test]
+fn test_missing_references_are_errors() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("ans");
+    assert!(!result.success);
+
+    calculate_with(&calculator, "1");
+    let result = calculator.calculate_internal("#2");
+    assert!(!result.success);
+    assert!(result.error.unwrap_or_default().contains("#2"));
+}
+
+#[test]
+fn test_history_can_be_listed_cleared_and_restored() {
+    let mut calculator = Calculator::new();
+    calculate_with(&calculator, "6 * 7");
+    let json = calculator.history_json();
+    assert!(json.contains(r#""input":"6 * 7""#), "got: {json}");
+    assert!(json.contains(r#""result":"42""#), "got: {json}");
+
+    calculator.clear_history();
+    assert!(calculator.history().is_empty());
+    assert!(!calculator.calculate_internal("ans").success);
+
+    let mut restored = Calculator::new();
+    assert!(restored.load_history_json(&json));
+    assert_eq!(calculate_with(&restored, "#1 / 2"), "21");
+    assert!(!restored.load_history_json("not json"));
+    assert_eq!(restored.history().len(), 2);
+}
+
+#[test]
+fn test_ranges_of_results_feed_statistics() {
+    let calculator = Calculator::new();
+    for input in ["10", "20", "30", "40", "50"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(calculate_with(&calculator, "sum(#1:#5)"), "150");
+    assert_eq!(calculate_with(&calculator, "average(#2:#4)"), "30");
+    assert_eq!(calculate_with(&calculator, "max(#1:#3, 35)"), "35");
+
+    let result = calculator.calculate_internal("sum(#1:#5)");
+    assert_eq!(result.lino_interpretation, "(sum ((#1:#5)))");
+}
+
+#[test]
+fn test_ranges_convert_compatible_units() {
+    let calculator = Calculator::new();
+    for input in ["1 kg", "500 g", "250 g"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(calculate_with(&calculator, "sum(#1:#3)"), "1.75 kg");
+    assert_eq!(calculate_with(&calculator, "sum(#2:#3)"), "750 g");
+
+    calculate_with(&calculator, "3 USD");
+    let result = calculator.calculate_internal("sum(#5:#6)");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_ranges_of_durations_are_totalled() {
+    let calculator = Calculator::new();
+    for input in ["2 hours", "30 minutes", "15 minutes"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(
+        calculate_with(&calculator, "sum(#1:#3)"),
+        "2 hours, 45 minutes"
+    );
+
+    let calculator = Calculator::new();
+    for input in ["10:30 - 9:00", "17:45 - 13:15", "1 hour"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(calculate_with(&calculator, "sum(#1:#3)"), "7 hours");
+    assert_eq!(
+        calculate_with(&calculator, "max(#1:#3)"),
+        "4 hours, 30 minutes"
+    );
+}
+
+#[test]
+fn test_durations_in_mixed_units_aggregate_to_a_duration() {
+    let calculator = Calculator::new();
+    for input in ["1 hour", "30 minutes"] {
+        calculate_with(&calculator, input);
+    }
+    assert_eq!(
+        calculate_with(&calculator, "sum(#1:#2)"),
+        "1 hour, 30 minutes"
+    );
+    assert_eq!(calculate_with(&calculator, "average(#1:#2)"), "45 minutes");
+    assert_eq!(
+        calculate_with(&calculator, "average(2 hours, 1 hour)"),
+        calculate_with(&calculator, "(2 hours + 1 hour) / 2")
+    );
+    assert_eq!(
+        calculate_with(&calculator, "sum(2 hours, 1 hour)"),
+        "3 hours"
+    );
+}
+
+#[test]
+fn test_ranges_must_be_in_history_and_in_order() {
+    let calculator = Calculator::new();
+    calculate_with(&calculator, "1");
+    calculate_with(&calculator, "2");
+    let result = calculator.calculate_internal("sum(#1:#3)");
+    assert!(result.error.unwrap_or_default().contains("#3"));
+    assert!(!calculator.calculate_internal("sum(#2:#1)").success);
+}
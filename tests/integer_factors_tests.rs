@@ -0,0 +1,54 @@
+//! Tests for `with factors`, which explains an integer result by its prime
+//! factorization, divisor count and divisibility.
+
+mod common;
+
+use common::calculate_with_steps;
+use link_calculator::Calculator;
+
+fn has(steps: &[String], step: &str) -> bool {
+    steps.iter().any(|s| s == step)
+}
+
+#[test]
+fn test_factors_of_an_integer_result() {
+    let (result, steps) = calculate_with_steps("12 * 30 with factors");
+    assert_eq!(result, "360");
+    assert!(has(&steps, "Prime factorization: 360 = 2^3 * 3^2 * 5"));
+    assert!(has(&steps, "Divisors: (3 + 1) * (2 + 1) * (1 + 1) = 24"));
+}
+
+#[test]
+fn test_divisibility_facts_give_their_rule() {
+    let (_, steps) = calculate_with_steps("1001 with factors");
+    assert!(has(&steps, "Prime factorization: 1001 = 7 * 11 * 13"));
+    assert!(has(
+        &steps,
+        "Not divisible by 2: the last digit 1 is not even"
+    ));
+    assert!(has(
+        &steps,
+        "Divisible by 11: the alternating digit sum 0 is divisible by 11"
+    ));
+}
+
+#[test]
+fn test_large_integers_still_get_divisibility() {
+    let (_, steps) = calculate_with_steps("10^15 with factors");
+    assert!(steps.iter().any(|s| s.contains("too large to factor")));
+    assert!(has(&steps, "Divisible by 10: the last digit 0 is 0"));
+}
+
+#[test]
+fn test_negative_integers_are_factored_by_magnitude() {
+    let (result, steps) = calculate_with_steps("-98 with factors");
+    assert_eq!(result, "-98");
+    assert!(has(&steps, "Prime factorization: 98 = 2 * 7^2"));
+}
+
+#[test]
+fn test_non_integer_results_are_rejected() {
+    let result = Calculator::new().calculate_internal("7 / 2 with factors");
+    assert!(!result.success);
+    assert!(result.error.unwrap().contains("integer"));
+}
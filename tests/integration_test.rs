@@ -15,7 +15,7 @@ mod calculator_tests {
 
     #[test]
     fn test_simple_addition() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("2 + 3");
         assert!(result.success);
         assert_eq!(result.result, "5");
@@ -23,7 +23,7 @@ mod calculator_tests {
 
     #[test]
     fn test_simple_subtraction() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("10 - 4");
         assert!(result.success);
         assert_eq!(result.result, "6");
@@ -31,7 +31,7 @@ mod calculator_tests {
 
     #[test]
     fn test_simple_multiplication() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("3 * 4");
         assert!(result.success);
         assert_eq!(result.result, "12");
@@ -39,7 +39,7 @@ mod calculator_tests {
 
     #[test]
     fn test_simple_division() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("15 / 3");
         assert!(result.success);
         assert_eq!(result.result, "5");
@@ -47,7 +47,7 @@ mod calculator_tests {
 
     #[test]
     fn test_division_by_zero() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("10 / 0");
         assert!(!result.success);
         assert!(result.error.is_some());
@@ -56,7 +56,7 @@ mod calculator_tests {
 
     #[test]
     fn test_decimal_numbers() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("3.14 + 2.86");
         assert!(result.success);
         assert_eq!(result.result, "6");
@@ -64,7 +64,7 @@ mod calculator_tests {
 
     #[test]
     fn test_negative_numbers() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("-5 + 3");
         assert!(result.success);
         assert_eq!(result.result, "-2");
@@ -72,7 +72,7 @@ mod calculator_tests {
 
     #[test]
     fn test_parentheses() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("(2 + 3) * 4");
         assert!(result.success);
         assert_eq!(result.result, "20");
@@ -80,7 +80,7 @@ mod calculator_tests {
 
     #[test]
     fn test_operator_precedence() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("2 + 3 * 4");
         assert!(result.success);
         assert_eq!(result.result, "14"); // 2 + (3 * 4) = 14
@@ -92,7 +92,7 @@ mod currency_tests {
 
     #[test]
     fn test_currency_value() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("100 USD");
         assert!(result.success);
         assert!(result.result.contains("100"));
@@ -101,7 +101,7 @@ mod currency_tests {
 
     #[test]
     fn test_same_currency_addition() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("100 USD + 50 USD");
         assert!(result.success);
         assert!(result.result.contains("150"));
@@ -109,7 +109,7 @@ mod currency_tests {
 
     #[test]
     fn test_currency_conversion() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("84 USD - 34 EUR");
         assert!(result.success);
         // Should convert EUR to USD and perform subtraction
@@ -118,7 +118,7 @@ mod currency_tests {
 
     #[test]
     fn test_currency_conversion_steps_show_rate_info() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("0 RUB + 1 USD");
         assert!(result.success);
         // Steps should include exchange rate information
@@ -142,7 +142,7 @@ mod currency_tests {
 
     #[test]
     fn test_same_currency_no_rate_info() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("100 USD + 50 USD");
         assert!(result.success);
         // Same currency addition should not show exchange rate info
@@ -160,7 +160,7 @@ mod datetime_tests {
 
     #[test]
     fn test_datetime_subtraction() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("(Jan 27, 8:59am UTC) - (Jan 25, 12:51pm UTC)");
         assert!(result.success);
         // Should be approximately 1 day, 20 hours
@@ -171,7 +171,7 @@ mod datetime_tests {
     /// not 3. Input: (datetime) - (datetime) should produce ((datetime) - (datetime))
     #[test]
     fn test_datetime_subtraction_lino_notation_issue_30() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("(Jan 27, 8:59am UTC) - (Jan 26, 10:20am UTC)");
         assert!(result.success);
 
@@ -209,7 +209,7 @@ mod lino_tests {
 
     #[test]
     fn test_lino_representation_simple() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("2 + 3");
         assert!(result.success);
         assert!(!result.lino_interpretation.is_empty());
@@ -218,7 +218,7 @@ mod lino_tests {
 
     #[test]
     fn test_lino_representation_currency() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("84 USD - 34 EUR");
         assert!(result.success);
         assert!(result.lino_interpretation.contains("USD"));
@@ -231,7 +231,7 @@ mod error_handling_tests {
 
     #[test]
     fn test_empty_input() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("");
         assert!(!result.success);
         assert!(result.error.is_some());
@@ -239,7 +239,7 @@ mod error_handling_tests {
 
     #[test]
     fn test_invalid_input_generates_issue_link() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("???invalid???");
         assert!(!result.success);
         assert!(result.issue_link.is_some());
@@ -254,7 +254,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_sin_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sin(0)");
         assert!(result.success, "sin(0) should succeed");
         assert_eq!(result.result, "0");
@@ -262,7 +262,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_cos_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("cos(0)");
         assert!(result.success, "cos(0) should succeed");
         assert_eq!(result.result, "1");
@@ -270,7 +270,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_sqrt_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sqrt(16)");
         assert!(result.success, "sqrt(16) should succeed");
         assert_eq!(result.result, "4");
@@ -278,7 +278,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_sqrt_function_decimal() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sqrt(2)");
         assert!(result.success, "sqrt(2) should succeed");
         // sqrt(2) ≈ 1.414...
@@ -290,7 +290,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_pow_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("pow(2, 3)");
         assert!(result.success, "pow(2, 3) should succeed");
         assert_eq!(result.result, "8");
@@ -298,7 +298,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_power_operator() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("2^3");
         assert!(result.success, "2^3 should succeed");
         assert_eq!(result.result, "8");
@@ -306,7 +306,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_exp_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("exp(0)");
         assert!(result.success, "exp(0) should succeed");
         assert_eq!(result.result, "1");
@@ -314,7 +314,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_ln_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("ln(1)");
         assert!(result.success, "ln(1) should succeed");
         assert_eq!(result.result, "0");
@@ -322,7 +322,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_log_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("log(100)");
         assert!(result.success, "log(100) should succeed");
         assert_eq!(result.result, "2");
@@ -330,7 +330,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_abs_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("abs(-5)");
         assert!(result.success, "abs(-5) should succeed");
         assert_eq!(result.result, "5");
@@ -338,7 +338,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_floor_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("floor(3.7)");
         assert!(result.success, "floor(3.7) should succeed");
         assert_eq!(result.result, "3");
@@ -346,7 +346,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_ceil_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("ceil(3.2)");
         assert!(result.success, "ceil(3.2) should succeed");
         assert_eq!(result.result, "4");
@@ -354,29 +354,31 @@ mod advanced_math_tests {
 
     #[test]
     fn test_pi_constant() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("pi()");
         assert!(result.success, "pi() should succeed");
+        assert_eq!(result.result, "π");
         assert!(
-            result.result.starts_with("3.14"),
+            result.alternatives[0].value.starts_with("3.14"),
             "pi should start with 3.14"
         );
     }
 
     #[test]
     fn test_e_constant() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("e()");
         assert!(result.success, "e() should succeed");
+        assert_eq!(result.result, "e");
         assert!(
-            result.result.starts_with("2.71"),
+            result.alternatives[0].value.starts_with("2.71"),
             "e should start with 2.71"
         );
     }
 
     #[test]
     fn test_nested_functions() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sqrt(abs(-16))");
         assert!(result.success, "sqrt(abs(-16)) should succeed");
         assert_eq!(result.result, "4");
@@ -384,7 +386,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_function_with_expression() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sqrt(9 + 7)");
         assert!(result.success, "sqrt(9 + 7) should succeed");
         assert_eq!(result.result, "4");
@@ -392,7 +394,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_min_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("min(5, 3)");
         assert!(result.success, "min(5, 3) should succeed");
         assert_eq!(result.result, "3");
@@ -400,7 +402,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_max_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("max(5, 3)");
         assert!(result.success, "max(5, 3) should succeed");
         assert_eq!(result.result, "5");
@@ -408,7 +410,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_factorial() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("factorial(5)");
         assert!(result.success, "factorial(5) should succeed");
         assert_eq!(result.result, "120");
@@ -416,7 +418,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_deg_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         // pi radians = 180 degrees
         let result = calculator.calculate_internal("deg(3.14159265358979)");
         assert!(result.success, "deg(pi) should succeed");
@@ -430,7 +432,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_rad_function() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         // 180 degrees = pi radians
         let result = calculator.calculate_internal("rad(180)");
         assert!(result.success, "rad(180) should succeed");
@@ -444,7 +446,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_integrate_x_squared() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         // integrate(x^2, x, 0, 3) = [x^3/3] from 0 to 3 = 9
         let result = calculator.calculate_internal("integrate(x^2, x, 0, 3)");
         assert!(result.success, "integrate(x^2, x, 0, 3) should succeed");
@@ -457,7 +459,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_integrate_sin() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         // integrate(sin(x), x, 0, pi) = 2
         let result = calculator.calculate_internal("integrate(sin(x), x, 0, 3.14159265358979)");
         assert!(result.success, "integrate(sin(x), x, 0, pi) should succeed");
@@ -470,7 +472,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_sqrt_negative_error() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("sqrt(-1)");
         assert!(!result.success, "sqrt(-1) should fail");
         assert!(result.error.is_some());
@@ -479,7 +481,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_ln_negative_error() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("ln(-1)");
         assert!(!result.success, "ln(-1) should fail");
         assert!(result.error.is_some());
@@ -488,7 +490,7 @@ mod advanced_math_tests {
 
     #[test]
     fn test_unknown_function_error() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("foobar(5)");
         assert!(!result.success, "foobar(5) should fail");
         assert!(result.error.is_some());
@@ -517,7 +519,7 @@ mod indefinite_integral_tests {
     #[test]
     fn test_natural_integral_notation_sin_x_over_x() {
         // This is the exact test case from issue #3: "integrate sin(x)/x dx"
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate sin(x)/x dx");
 
         // The result should be a symbolic result with Si(x) + C
@@ -545,7 +547,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_x_squared() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate x^2 dx");
 
         assert!(result.success, "integrate x^2 dx should succeed");
@@ -563,7 +565,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_sin() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate sin(x) dx");
 
         assert!(result.success, "integrate sin(x) dx should succeed");
@@ -581,7 +583,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_cos() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate cos(x) dx");
 
         assert!(result.success, "integrate cos(x) dx should succeed");
@@ -599,7 +601,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_x() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate x dx");
 
         assert!(result.success, "integrate x dx should succeed");
@@ -617,7 +619,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_constant() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate 5 dx");
 
         assert!(result.success, "integrate 5 dx should succeed");
@@ -637,7 +639,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_exp() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate exp(x) dx");
 
         assert!(result.success, "integrate exp(x) dx should succeed");
@@ -655,7 +657,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_natural_integral_notation_different_variable() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate sin(t) dt");
 
         assert!(result.success, "integrate sin(t) dt should succeed");
@@ -672,7 +674,7 @@ mod indefinite_integral_tests {
 
     #[test]
     fn test_latex_rendering_for_integral() {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate sin(x)/x dx");
 
         assert!(result.success, "Should succeed");
@@ -686,7 +688,7 @@ mod indefinite_integral_tests {
     #[test]
     fn test_definite_integral_still_works() {
         // Ensure the traditional definite integral syntax still works
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
         let result = calculator.calculate_internal("integrate(sin(x), x, 0, 3.14159)");
 
         assert!(result.success, "Definite integral should still work");
@@ -708,7 +710,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_update_rates_from_api_returns_count() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // Create a JSON string simulating API response
         let rates_json = r#"{"eur": 0.92, "gbp": 0.79, "rub": 75.5}"#;
@@ -721,7 +723,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_update_rates_from_api_invalid_json() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // Invalid JSON should return 0
         let count = calc.update_rates_from_api("USD", "2026-01-26", "invalid json");
@@ -730,7 +732,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_update_rates_from_api_empty() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // Empty rates object should return 0
         let count = calc.update_rates_from_api("USD", "2026-01-26", "{}");
@@ -739,7 +741,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_update_rates_from_api_skips_same_currency() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // USD to USD should be skipped
         let rates_json = r#"{"usd": 1.0, "eur": 0.92}"#;
@@ -751,7 +753,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_calculation_uses_updated_api_rate() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // Update with a specific rate for testing (100 RUB per USD)
         let rates_json = r#"{"rub": 100.0}"#;
@@ -765,7 +767,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_calculation_steps_show_api_rate_source() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // Update with a specific rate for testing
         let rates_json = r#"{"rub": 100.0}"#;
@@ -789,7 +791,7 @@ mod api_rate_update_tests {
 
     #[test]
     fn test_api_rate_overrides_hardcoded_rate() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         // First calculation uses hardcoded rate (89.5)
         let result1 = calc.calculate_internal("0 RUB + 1 USD");
@@ -13,7 +13,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_time_with_gmt() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT");
         assert!(
             result.success,
@@ -34,7 +34,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_time_with_est() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM EST");
         assert!(
             result.success,
@@ -55,7 +55,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_time_with_msk() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM MSK");
         assert!(
             result.success,
@@ -76,7 +76,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_time_with_jst() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("3 AM JST");
         assert!(
             result.success,
@@ -97,7 +97,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_gtm_typo_treated_as_gmt() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GTM");
         assert!(
             result.success,
@@ -118,7 +118,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_time_without_timezone() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6:00 PM");
         assert!(result.success, "6:00 PM should succeed: {:?}", result.error);
         assert!(
@@ -130,7 +130,7 @@ mod timezone_parsing {
 
     #[test]
     fn test_am_time_with_timezone() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("9 AM PST");
         assert!(
             result.success,
@@ -155,7 +155,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_gmt_to_msk_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT as MSK");
         assert!(
             result.success,
@@ -177,7 +177,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_gtm_to_msk_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GTM as MSK");
         assert!(
             result.success,
@@ -194,7 +194,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_est_to_pst_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("3 PM EST as PST");
         assert!(
             result.success,
@@ -216,7 +216,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_msk_to_jst_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("12 PM MSK as JST");
         assert!(
             result.success,
@@ -238,7 +238,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_utc_to_est_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("10 AM UTC as EST");
         assert!(
             result.success,
@@ -255,7 +255,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_timezone_conversion_with_in_keyword() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT in MSK");
         assert!(
             result.success,
@@ -271,7 +271,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_timezone_conversion_with_to_keyword() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT to MSK");
         assert!(
             result.success,
@@ -287,7 +287,7 @@ mod timezone_conversion {
 
     #[test]
     fn test_conversion_across_midnight() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("11 PM EST as GMT");
         assert!(
             result.success,
@@ -308,7 +308,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_european_timezones() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         for tz in ["CET", "CEST", "EET", "EEST", "WET", "BST"] {
             let expr = format!("12 PM {tz}");
             let result = calc.calculate_internal(&expr);
@@ -327,7 +327,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_asian_timezones() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         for tz in ["JST", "KST", "SGT", "HKT", "ICT"] {
             let expr = format!("3 PM {tz}");
             let result = calc.calculate_internal(&expr);
@@ -346,7 +346,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_australian_timezones() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         for tz in ["AEST", "AEDT", "AWST"] {
             let expr = format!("8 AM {tz}");
             let result = calc.calculate_internal(&expr);
@@ -365,7 +365,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_south_american_timezones() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         for tz in ["ART", "BRT"] {
             let expr = format!("2 PM {tz}");
             let result = calc.calculate_internal(&expr);
@@ -379,7 +379,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_african_timezones() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         for tz in ["WAT", "CAT", "EAT", "SAST"] {
             let expr = format!("10 AM {tz}");
             let result = calc.calculate_internal(&expr);
@@ -393,7 +393,7 @@ mod timezone_abbreviations {
 
     #[test]
     fn test_half_hour_offset_ist() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // IST is UTC+5:30
         let result = calc.calculate_internal("12 PM IST as UTC");
         assert!(
@@ -415,7 +415,7 @@ mod lino_notation {
 
     #[test]
     fn test_timezone_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT");
         assert!(result.success);
         assert!(
@@ -427,7 +427,7 @@ mod lino_notation {
 
     #[test]
     fn test_timezone_conversion_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("6 PM GMT as MSK");
         assert!(result.success);
         assert!(
@@ -24,7 +24,7 @@ use link_calculator::Calculator;
 /// The exact expression from the issue report should now work.
 #[test]
 fn test_issue_123_tenge_in_dollars_russian() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5550000.00 тенге в долларах");
     assert!(
         result.success,
@@ -41,7 +41,7 @@ fn test_issue_123_tenge_in_dollars_russian() {
 /// The converted value should be reasonable (~11,809 USD at ~470 KZT/USD).
 #[test]
 fn test_issue_123_tenge_to_usd_value_is_reasonable() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5550000.00 тенге в долларах");
     assert!(
         result.success,
@@ -68,7 +68,7 @@ fn test_issue_123_tenge_to_usd_value_is_reasonable() {
 
 #[test]
 fn test_issue_123_kzt_iso_code_recognized() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 KZT");
     assert!(
         result.success,
@@ -84,7 +84,7 @@ fn test_issue_123_kzt_iso_code_recognized() {
 
 #[test]
 fn test_issue_123_kzt_to_usd_converts() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("470 KZT in USD");
     assert!(
         result.success,
@@ -100,7 +100,7 @@ fn test_issue_123_kzt_to_usd_converts() {
 
 #[test]
 fn test_issue_123_usd_to_kzt_converts() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 USD in KZT");
     assert!(
         result.success,
@@ -118,7 +118,7 @@ fn test_issue_123_usd_to_kzt_converts() {
 
 #[test]
 fn test_issue_123_russian_tenge_nominative_singular() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 тенге");
     assert!(
         result.success,
@@ -134,7 +134,7 @@ fn test_issue_123_russian_tenge_nominative_singular() {
 
 #[test]
 fn test_issue_123_russian_tenge_recognized_as_kzt() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 тенге");
     assert!(
         result.success,
@@ -150,7 +150,7 @@ fn test_issue_123_russian_tenge_recognized_as_kzt() {
 
 #[test]
 fn test_issue_123_tenge_to_rub_converts() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 тенге в рублях");
     assert!(
         result.success,
@@ -166,7 +166,7 @@ fn test_issue_123_tenge_to_rub_converts() {
 
 #[test]
 fn test_issue_123_rub_to_tenge_converts() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в тенге");
     assert!(
         result.success,
@@ -184,7 +184,7 @@ fn test_issue_123_rub_to_tenge_converts() {
 
 #[test]
 fn test_issue_123_kzt_addition() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 KZT + 2000 KZT");
     assert!(
         result.success,
@@ -207,7 +207,7 @@ fn test_issue_123_kzt_addition() {
 
 #[test]
 fn test_issue_123_kzt_to_eur_via_usd_converts() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10000 KZT in EUR");
     assert!(
         result.success,
@@ -24,7 +24,7 @@ use link_calculator::Calculator;
 /// German: "17. Februar 2027 - 6 Monate" → 2026-08-17
 #[test]
 fn test_german_date_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 Februar 2027 - 6 Monate");
     assert!(
         result.success,
@@ -41,7 +41,7 @@ fn test_german_date_minus_months() {
 /// German: "17 Februar 2027 + 3 Monate" → 2027-05-17
 #[test]
 fn test_german_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 Februar 2027 + 3 Monate");
     assert!(
         result.success,
@@ -58,7 +58,7 @@ fn test_german_date_plus_months() {
 /// German: "März" is recognized as March
 #[test]
 fn test_german_maerz_month() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("15 März 2027 - 1 Jahr");
     assert!(
         result.success,
@@ -75,7 +75,7 @@ fn test_german_maerz_month() {
 /// German: duration units — Wochen (weeks), Tage (days)
 #[test]
 fn test_german_duration_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("1 Januar 2027 + 2 Wochen");
     assert!(
@@ -109,7 +109,7 @@ fn test_german_duration_units() {
 /// French: "17 février 2027 - 6 mois" → 2026-08-17
 #[test]
 fn test_french_date_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 février 2027 - 6 mois");
     assert!(
         result.success,
@@ -126,7 +126,7 @@ fn test_french_date_minus_months() {
 /// French: accent-free variant "fevrier" also works
 #[test]
 fn test_french_fevrier_no_accent() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 fevrier 2027 - 6 mois");
     assert!(
         result.success,
@@ -143,7 +143,7 @@ fn test_french_fevrier_no_accent() {
 /// French: "17 février 2027 + 3 mois" → 2027-05-17
 #[test]
 fn test_french_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 février 2027 + 3 mois");
     assert!(
         result.success,
@@ -160,7 +160,7 @@ fn test_french_date_plus_months() {
 /// French: duration units — semaines (weeks), jours (days), ans (years)
 #[test]
 fn test_french_duration_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("1 janvier 2027 + 2 semaines");
     assert!(
@@ -195,7 +195,7 @@ fn test_french_duration_units() {
 /// but month words like 二月 (February) should be recognized.
 #[test]
 fn test_chinese_month_name_recognized() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Test that 二月 (February) is recognized as a datetime component
     let result = calc.calculate_internal("17 二月 2027 - 6 个月");
     assert!(
@@ -213,7 +213,7 @@ fn test_chinese_month_name_recognized() {
 /// Chinese: "17 一月 2027 + 3 月" → 2027-04-17
 #[test]
 fn test_chinese_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 一月 2027 + 3 月");
     assert!(
         result.success,
@@ -230,7 +230,7 @@ fn test_chinese_date_plus_months() {
 /// Chinese: duration unit 天 (days)
 #[test]
 fn test_chinese_duration_days() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 一月 2027 + 7 天");
     assert!(
         result.success,
@@ -247,7 +247,7 @@ fn test_chinese_duration_days() {
 /// Chinese: duration unit 周 (weeks)
 #[test]
 fn test_chinese_duration_weeks() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 一月 2027 + 2 周");
     assert!(
         result.success,
@@ -268,7 +268,7 @@ fn test_chinese_duration_weeks() {
 /// Hindi: "17 फरवरी 2027 - 6 महीने" → 2026-08-17
 #[test]
 fn test_hindi_date_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 फरवरी 2027 - 6 महीने");
     assert!(
         result.success,
@@ -285,7 +285,7 @@ fn test_hindi_date_minus_months() {
 /// Hindi: "17 फरवरी 2027 + 3 महीने" → 2027-05-17
 #[test]
 fn test_hindi_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 फरवरी 2027 + 3 महीने");
     assert!(
         result.success,
@@ -302,7 +302,7 @@ fn test_hindi_date_plus_months() {
 /// Hindi: duration units — दिन (days), सप्ताह (weeks)
 #[test]
 fn test_hindi_duration_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("1 जनवरी 2027 + 7 दिन");
     assert!(
@@ -336,7 +336,7 @@ fn test_hindi_duration_units() {
 /// Arabic: "17 فبراير 2027 - 6 أشهر" → 2026-08-17
 #[test]
 fn test_arabic_date_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 فبراير 2027 - 6 أشهر");
     assert!(
         result.success,
@@ -353,7 +353,7 @@ fn test_arabic_date_minus_months() {
 /// Arabic: "17 فبراير 2027 + 3 أشهر" → 2027-05-17
 #[test]
 fn test_arabic_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 فبراير 2027 + 3 أشهر");
     assert!(
         result.success,
@@ -370,7 +370,7 @@ fn test_arabic_date_plus_months() {
 /// Arabic: duration units — أيام (days), أسابيع (weeks)
 #[test]
 fn test_arabic_duration_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("1 يناير 2027 + 7 أيام");
     assert!(
@@ -32,7 +32,7 @@ use link_calculator::Calculator;
 /// The exact expression from the issue report should now work.
 #[test]
 fn test_issue_125_russian_date_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027 - 6 месяцев");
     assert!(
         result.success,
@@ -50,7 +50,7 @@ fn test_issue_125_russian_date_minus_months() {
 /// The Links notation should show the date as a structured expression.
 #[test]
 fn test_issue_125_lino_notation() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027 - 6 месяцев");
     assert!(
         result.success,
@@ -76,7 +76,7 @@ fn test_issue_125_lino_notation() {
 /// Standalone Russian date (genitive form: февраля = of February).
 #[test]
 fn test_issue_125_russian_date_standalone() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027");
     assert!(
         result.success,
@@ -93,7 +93,7 @@ fn test_issue_125_russian_date_standalone() {
 /// Russian date with January (nominative: январь, genitive: января).
 #[test]
 fn test_issue_125_russian_date_january() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("15 января 2027");
     assert!(
         result.success,
@@ -110,7 +110,7 @@ fn test_issue_125_russian_date_january() {
 /// Russian date with December (genitive: декабря).
 #[test]
 fn test_issue_125_russian_date_december() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("31 декабря 2026");
     assert!(
         result.success,
@@ -129,7 +129,7 @@ fn test_issue_125_russian_date_december() {
 /// "месяцев" (genitive plural of месяц = month) should be recognized as months.
 #[test]
 fn test_issue_125_russian_months_unit() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Use an already-recognized date format to isolate duration unit parsing
     let result = calc.calculate_internal("17 февраля 2027 - 6 месяцев");
     assert!(
@@ -147,7 +147,7 @@ fn test_issue_125_russian_months_unit() {
 /// "месяца" (genitive singular of месяц = month) should be recognized.
 #[test]
 fn test_issue_125_russian_month_singular() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 - 1 месяца");
     assert!(
         result.success,
@@ -159,7 +159,7 @@ fn test_issue_125_russian_month_singular() {
 /// "месяц" (nominative singular) should be recognized.
 #[test]
 fn test_issue_125_russian_month_nominative() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 - 1 месяц");
     assert!(
         result.success,
@@ -171,7 +171,7 @@ fn test_issue_125_russian_month_nominative() {
 /// "дней" (genitive plural of день = day) should be recognized as days.
 #[test]
 fn test_issue_125_russian_days_unit() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 - 30 дней");
     assert!(
         result.success,
@@ -183,7 +183,7 @@ fn test_issue_125_russian_days_unit() {
 /// "недель" (genitive plural of неделя = week) should be recognized as weeks.
 #[test]
 fn test_issue_125_russian_weeks_unit() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 + 2 недели");
     assert!(
         result.success,
@@ -195,7 +195,7 @@ fn test_issue_125_russian_weeks_unit() {
 /// "лет" (genitive plural of год = year) should be recognized as years.
 #[test]
 fn test_issue_125_russian_years_unit() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 - 2 лет");
     assert!(
         result.success,
@@ -207,7 +207,7 @@ fn test_issue_125_russian_years_unit() {
 /// "часов" (genitive plural of час = hour) should be recognized as hours.
 #[test]
 fn test_issue_125_russian_hours_unit() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2027-02-17 + 5 часов");
     assert!(
         result.success,
@@ -221,7 +221,7 @@ fn test_issue_125_russian_hours_unit() {
 /// "Feb 17 2027" (English, without comma) should parse correctly.
 #[test]
 fn test_issue_125_english_date_without_comma_mon_dd_yyyy() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("Feb 17 2027 - 6 months");
     assert!(
         result.success,
@@ -238,7 +238,7 @@ fn test_issue_125_english_date_without_comma_mon_dd_yyyy() {
 /// "17 February 2027" (English, without comma) should parse correctly.
 #[test]
 fn test_issue_125_english_date_without_comma_dd_month_yyyy() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 February 2027 - 6 months");
     assert!(
         result.success,
@@ -255,7 +255,7 @@ fn test_issue_125_english_date_without_comma_dd_month_yyyy() {
 /// "January 15 2027" (English, without comma) should parse correctly.
 #[test]
 fn test_issue_125_english_date_january_without_comma() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("January 15 2027 - 1 month");
     assert!(
         result.success,
@@ -274,7 +274,7 @@ fn test_issue_125_english_date_january_without_comma() {
 /// Adding months to a Russian date expression.
 #[test]
 fn test_issue_125_russian_date_plus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027 + 3 месяца");
     assert!(
         result.success,
@@ -293,7 +293,7 @@ fn test_issue_125_russian_date_plus_months() {
 /// "1 января 2026 - 6 месяцев" (January 1 2026 - 6 months = July 2025).
 #[test]
 fn test_issue_125_russian_january_minus_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 января 2026 - 6 месяцев");
     assert!(
         result.success,
@@ -25,7 +25,7 @@ use link_calculator::Calculator;
 /// The original issue: Feb 17, 2027 - 6 months must be 2026-08-17, not 2026-08-21.
 #[test]
 fn test_issue_128_exact_day_preserved_russian() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027 - 6 месяцев");
     assert!(
         result.success,
@@ -42,7 +42,7 @@ fn test_issue_128_exact_day_preserved_russian() {
 /// Same using English month name format.
 #[test]
 fn test_issue_128_exact_day_preserved_english() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 February 2027 - 6 months");
     assert!(
         result.success,
@@ -59,7 +59,7 @@ fn test_issue_128_exact_day_preserved_english() {
 /// Adding months should also preserve day.
 #[test]
 fn test_issue_128_exact_day_preserved_add_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 August 2026 + 6 months");
     assert!(
         result.success,
@@ -76,7 +76,7 @@ fn test_issue_128_exact_day_preserved_add_months() {
 /// Month-end clamping: Jan 31, 2027 + 1 month should give 2027-02-28 (not overflow).
 #[test]
 fn test_issue_128_month_end_clamping() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("31 January 2027 + 1 month");
     assert!(
         result.success,
@@ -94,7 +94,7 @@ fn test_issue_128_month_end_clamping() {
 /// Year arithmetic should also preserve day.
 #[test]
 fn test_issue_128_year_arithmetic_preserves_day() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2024 + 1 год");
     assert!(
         result.success,
@@ -111,7 +111,7 @@ fn test_issue_128_year_arithmetic_preserves_day() {
 /// Subtracting years should preserve day.
 #[test]
 fn test_issue_128_subtract_year_preserves_day() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("15 марта 2025 - 2 года");
     assert!(
         result.success,
@@ -130,7 +130,7 @@ fn test_issue_128_subtract_year_preserves_day() {
 /// Steps should say "6 months" not "6 mo".
 #[test]
 fn test_issue_128_steps_show_full_word_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 February 2027 - 6 months");
     assert!(
         result.success,
@@ -152,7 +152,7 @@ fn test_issue_128_steps_show_full_word_months() {
 /// Russian input: steps should also show full English word.
 #[test]
 fn test_issue_128_russian_steps_show_full_word_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("17 февраля 2027 - 6 месяцев");
     assert!(
         result.success,
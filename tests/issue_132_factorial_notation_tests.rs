@@ -15,7 +15,7 @@ use link_calculator::Calculator;
 /// The original issue: 63! should be parsed and evaluated.
 #[test]
 fn test_issue_132_63_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("63!");
     assert!(
         result.success,
@@ -34,7 +34,7 @@ fn test_issue_132_63_factorial() {
 /// 5! = 120
 #[test]
 fn test_issue_132_5_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5!");
     assert!(
         result.success,
@@ -51,7 +51,7 @@ fn test_issue_132_5_factorial() {
 /// 0! = 1 (by convention)
 #[test]
 fn test_issue_132_0_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("0!");
     assert!(
         result.success,
@@ -68,7 +68,7 @@ fn test_issue_132_0_factorial() {
 /// 1! = 1
 #[test]
 fn test_issue_132_1_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1!");
     assert!(
         result.success,
@@ -85,7 +85,7 @@ fn test_issue_132_1_factorial() {
 /// 10! = 3628800
 #[test]
 fn test_issue_132_10_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10!");
     assert!(
         result.success,
@@ -104,7 +104,7 @@ fn test_issue_132_10_factorial() {
 /// (3+2)! = 5! = 120
 #[test]
 fn test_issue_132_expression_factorial() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(3+2)!");
     assert!(
         result.success,
@@ -121,7 +121,7 @@ fn test_issue_132_expression_factorial() {
 /// 5! + 3! = 120 + 6 = 126
 #[test]
 fn test_issue_132_sum_of_factorials() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5! + 3!");
     assert!(
         result.success,
@@ -138,7 +138,7 @@ fn test_issue_132_sum_of_factorials() {
 /// 5! * 2 = 240
 #[test]
 fn test_issue_132_factorial_times_number() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5! * 2");
     assert!(
         result.success,
@@ -155,7 +155,7 @@ fn test_issue_132_factorial_times_number() {
 /// 6! / 4! = 720 / 24 = 30
 #[test]
 fn test_issue_132_factorial_division() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("6! / 4!");
     assert!(
         result.success,
@@ -174,7 +174,7 @@ fn test_issue_132_factorial_division() {
 /// The existing `factorial(5)` notation must still work.
 #[test]
 fn test_issue_132_factorial_function_notation_unchanged() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("factorial(5)");
     assert!(
         result.success,
@@ -193,7 +193,7 @@ fn test_issue_132_factorial_function_notation_unchanged() {
 /// (-1)! should return a domain error (factorial not defined for negatives).
 #[test]
 fn test_issue_132_negative_factorial_error() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(-1)!");
     assert!(
         !result.success,
@@ -205,7 +205,7 @@ fn test_issue_132_negative_factorial_error() {
 /// 3.5! should return a domain error (factorial requires non-negative integer).
 #[test]
 fn test_issue_132_non_integer_factorial_error() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("3.5!");
     assert!(
         !result.success,
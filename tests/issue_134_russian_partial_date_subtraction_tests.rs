@@ -4,7 +4,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn test_issue_134_russian_partial_dates_subtract_as_dates() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("18 апреля - 28 марта");
 
@@ -21,7 +21,7 @@ fn test_issue_134_russian_partial_dates_subtract_as_dates() {
 
 #[test]
 fn test_issue_134_partial_russian_date_uses_current_year() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("18 апреля");
 
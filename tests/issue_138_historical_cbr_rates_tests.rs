@@ -18,7 +18,7 @@ fn parse_inr_result(result: &str) -> f64 {
 
 #[test]
 fn current_cbr_rate_is_not_used_for_a_different_historical_date() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     calc.update_cbr_rates_from_api("2026-04-24", r#"{"inr": 0.7954370000000001}"#);
 
     let result = calc.calculate_internal("1 RUB as INR at Apr 11, 2026");
@@ -37,7 +37,7 @@ fn current_cbr_rate_is_not_used_for_a_different_historical_date() {
 
 #[test]
 fn current_cbr_rate_is_available_for_its_own_effective_date() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     calc.update_cbr_rates_from_api("2026-04-24", r#"{"inr": 0.7954370000000001}"#);
 
     let result = calc.calculate_internal("1 RUB as INR at Apr 24, 2026");
@@ -56,7 +56,7 @@ fn current_cbr_rate_is_available_for_its_own_effective_date() {
 
 #[test]
 fn original_issue_expression_uses_april_11_cbr_lino_rate() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     calc.update_cbr_rates_from_api("2026-04-24", r#"{"inr": 0.7954370000000001}"#);
     let loaded =
         calc.load_rates_from_consolidated_lino(include_str!("../data/currency/inr-rub.lino"));
@@ -80,7 +80,7 @@ fn original_issue_expression_uses_april_11_cbr_lino_rate() {
         "Steps should not show the latest CBR rate date. Steps:\n{steps_text}"
     );
 
-    let actual = parse_inr_result(&result.result);
+    let actual = parse_inr_result(result.raw_result.as_deref().unwrap_or(&result.result));
     let expected = 22_822.0 * 1.203_667_816_570_654;
     assert!(
         (actual - expected).abs() < 0.000_001,
@@ -90,7 +90,7 @@ fn original_issue_expression_uses_april_11_cbr_lino_rate() {
 
 #[test]
 fn missing_weekend_rate_uses_previous_available_business_day_rate() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let loaded = calc.load_rates_from_consolidated_lino(
         "conversion:
   from RUB
@@ -123,7 +123,7 @@ fn missing_weekend_rate_uses_previous_available_business_day_rate() {
 
 #[test]
 fn missing_historical_rate_before_first_known_date_still_fails() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let loaded = calc.load_rates_from_consolidated_lino(
         "conversion:
   from RUB
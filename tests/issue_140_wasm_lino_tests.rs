@@ -17,7 +17,7 @@ use link_calculator::Calculator;
 /// the web worker (returns usize, not Result).
 #[test]
 fn load_rates_returns_count_not_result() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let content = "conversion:
   from INR
   to RUB
@@ -33,7 +33,7 @@ fn load_rates_returns_count_not_result() {
 /// Verify that after loading via the WASM-compatible method, historical conversions work.
 #[test]
 fn issue_140_rub_to_inr_on_april_11_2026_succeeds() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // Simulate what the web worker does: call the WASM method with inr-rub.lino content
     let inr_rub_content = include_str!("../data/currency/inr-rub.lino");
@@ -52,7 +52,7 @@ fn issue_140_rub_to_inr_on_april_11_2026_succeeds() {
 /// Verify that zero is returned for invalid content (previously would panic or return Err).
 #[test]
 fn load_rates_returns_zero_for_empty_content() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let count = calc.load_rates_from_consolidated_lino("");
     assert_eq!(count, 0, "Empty content should return 0");
 }
@@ -17,7 +17,7 @@ use link_calculator::Calculator;
 /// The original issue: 8% of $50 should equal 4 USD.
 #[test]
 fn test_issue_145_8_percent_of_50_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("8% of $50");
     assert!(
         result.success,
@@ -36,7 +36,7 @@ fn test_issue_145_8_percent_of_50_usd() {
 /// 8% * $50 — already working; must stay 4 USD.
 #[test]
 fn test_issue_145_8_percent_times_50_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("8% * $50");
     assert!(
         result.success,
@@ -55,7 +55,7 @@ fn test_issue_145_8_percent_times_50_usd() {
 /// 10% of 200 = 20
 #[test]
 fn test_issue_145_10_percent_of_200() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10% of 200");
     assert!(
         result.success,
@@ -72,7 +72,7 @@ fn test_issue_145_10_percent_of_200() {
 /// 50% of 80 = 40
 #[test]
 fn test_issue_145_50_percent_of_80() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("50% of 80");
     assert!(
         result.success,
@@ -89,7 +89,7 @@ fn test_issue_145_50_percent_of_80() {
 /// 100% of 42 = 42
 #[test]
 fn test_issue_145_100_percent_of_42() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100% of 42");
     assert!(
         result.success,
@@ -108,7 +108,7 @@ fn test_issue_145_100_percent_of_42() {
 /// 50% standalone should still evaluate to 0.5.
 #[test]
 fn test_issue_145_percent_standalone_unchanged() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("50%");
     assert!(
         result.success,
@@ -125,7 +125,7 @@ fn test_issue_145_percent_standalone_unchanged() {
 /// 3% * 50 should still equal 1.5.
 #[test]
 fn test_issue_145_percent_times_number_unchanged() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("3% * 50");
     assert!(
         result.success,
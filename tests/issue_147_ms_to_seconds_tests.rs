@@ -16,7 +16,7 @@ use link_calculator::Calculator;
 /// The exact expression from the issue report must succeed and return 300 seconds.
 #[test]
 fn test_300000_ms_in_seconds() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("300000 ms in seconds");
     assert!(
         result.success,
@@ -33,7 +33,7 @@ fn test_300000_ms_in_seconds() {
 /// "in" keyword variant using short unit names.
 #[test]
 fn test_ms_to_s_short() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 ms in s");
     assert!(
         result.success,
@@ -50,7 +50,7 @@ fn test_ms_to_s_short() {
 /// "as" keyword variant.
 #[test]
 fn test_ms_as_seconds() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5000 ms as seconds");
     assert!(
         result.success,
@@ -67,7 +67,7 @@ fn test_ms_as_seconds() {
 /// "to" keyword variant.
 #[test]
 fn test_ms_to_seconds_keyword() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2000 ms to seconds");
     assert!(
         result.success,
@@ -84,7 +84,7 @@ fn test_ms_to_seconds_keyword() {
 /// Minutes to seconds conversion.
 #[test]
 fn test_minutes_to_seconds() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5 minutes in seconds");
     assert!(
         result.success,
@@ -101,7 +101,7 @@ fn test_minutes_to_seconds() {
 /// Hours to minutes conversion.
 #[test]
 fn test_hours_to_minutes() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2 hours in minutes");
     assert!(
         result.success,
@@ -118,7 +118,7 @@ fn test_hours_to_minutes() {
 /// Seconds to milliseconds (reverse direction).
 #[test]
 fn test_seconds_to_ms() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 second in ms");
     assert!(
         result.success,
@@ -135,7 +135,7 @@ fn test_seconds_to_ms() {
 /// Hours to seconds conversion.
 #[test]
 fn test_hours_to_seconds() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 hour in seconds");
     assert!(
         result.success,
@@ -4,7 +4,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn parses_hour_by_moscow_time() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("11 по мск");
 
@@ -3,7 +3,7 @@
 use link_calculator::Calculator;
 
 fn assert_parses_as_msk_time(input: &str, expected_time: &str) {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal(input);
 
@@ -4,7 +4,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn msk_time_shows_utc_equivalent_in_steps() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("12:30 по МСК");
 
@@ -32,7 +32,7 @@ fn version_constant_is_reachable() {
 
 #[test]
 fn calculator_calculate_with_value_returns_every_artefact() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let (expression, value, steps, lino) = calculator
         .calculate_with_value("(2 + 3) * 4")
         .expect("expression must parse and evaluate");
@@ -57,14 +57,14 @@ fn calculator_calculate_with_value_returns_every_artefact() {
 
 #[test]
 fn calculate_with_value_propagates_empty_input_error() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_with_value("   ");
     assert!(result.is_err());
 }
 
 #[test]
 fn calculate_with_value_propagates_parse_error() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_with_value("???invalid???");
     assert!(result.is_err());
 }
@@ -143,7 +143,7 @@ fn expression_parser_evaluate_expr_with_var_keeps_value_kind() {
     let mut parser = ExpressionParser::new();
     let expr = parser.parse("x + 1").expect("parses");
     let value = parser
-        .evaluate_expr_with_var(&expr, "x", Decimal::from_f64(4.0))
+        .evaluate_expr_with_var(&expr, "x", Decimal::from_f64(4.0).unwrap())
         .expect("evaluates with var");
     assert!((value.as_decimal().unwrap().to_f64() - 5.0).abs() < 1e-9);
 }
@@ -189,7 +189,7 @@ fn calculator_plan_returns_typed_calculation_plan() {
 
 #[test]
 fn calculate_internal_returns_calculation_result_with_steps_and_lino() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result: CalculationResult = calculator.calculate_internal("2 + 3");
     assert!(result.success);
     assert_eq!(result.result, "5");
@@ -206,7 +206,7 @@ fn high_level_and_low_level_paths_produce_the_same_value() {
     let input = "5 + 6";
 
     // High-level path
-    let mut hi = Calculator::new();
+    let hi = Calculator::new();
     let (_, hi_value, hi_steps, hi_lino) = hi
         .calculate_with_value(input)
         .expect("high-level evaluation");
@@ -224,7 +224,7 @@ fn high_level_and_low_level_paths_produce_the_same_value() {
 
 #[test]
 fn structured_value_kind_is_reachable_through_public_value_kind() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let (_, value, _, _) = calculator
         .calculate_with_value("100 USD")
         .expect("evaluates");
@@ -14,7 +14,7 @@ fn solves_issue_160_examples() {
         ("2 * (x + 3) = 10", "x = 2"),
         ("2 * x + 3 = x + 11", "x = 8"),
     ] {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal(input);
 
         assert!(
@@ -33,7 +33,7 @@ fn solves_issue_160_examples() {
 
 #[test]
 fn calculate_with_value_returns_structured_equation_solution() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc.calculate_with_value("2 * x + 3 = 11").unwrap();
 
     assert_eq!(value.to_display_string(), "x = 4");
@@ -53,7 +53,7 @@ fn calculate_with_value_returns_structured_equation_solution() {
 
 #[test]
 fn preserves_numeric_equality_checks() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 + 1 = 2");
 
     assert!(result.success);
@@ -5,7 +5,7 @@
 //! so the expression is equivalent to `19000 рублей в долларах`.
 
 use link_calculator::grammar::ExpressionParser;
-use link_calculator::types::{Decimal, Expression, Unit};
+use link_calculator::types::{Expression, Rational, Unit};
 use link_calculator::Calculator;
 
 fn assert_currency_conversion(input: &str, expected_value: &str, source: &str, target: &str) {
@@ -22,7 +22,7 @@ fn assert_currency_conversion(input: &str, expected_value: &str, source: &str, t
     let Expression::Number { value, unit, .. } = *value else {
         panic!("{input:?} source should be a number with a unit");
     };
-    assert_eq!(value, expected_value.parse::<Decimal>().unwrap());
+    assert_eq!(value, expected_value.parse::<Rational>().unwrap());
     assert_eq!(unit, Unit::currency(source));
 }
 
@@ -33,7 +33,7 @@ fn issue_162_cyrillic_k_scales_before_russian_currency_unit() {
 
 #[test]
 fn issue_162_exact_input_evaluates_as_19000_rub_to_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("19к рублей в долларах");
 
     assert!(
@@ -88,7 +88,7 @@ fn issue_162_si_suffixes_follow_case_sensitive_meanings() {
 
 #[test]
 fn issue_162_adjacent_duration_abbreviations_still_parse_as_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let hours = calc.calculate_internal("2h in minutes");
     assert!(
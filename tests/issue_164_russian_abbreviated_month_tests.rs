@@ -15,7 +15,7 @@ fn date_parser_accepts_reported_russian_abbreviation() {
 
 #[test]
 fn calculator_accepts_reported_russian_abbreviated_date() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("30 июл 2026");
 
     assert!(
@@ -35,7 +35,7 @@ fn calculator_accepts_neighboring_russian_month_forms() {
         ("1 сент 2026", "2026-09-01"),
     ];
 
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     for (input, expected) in cases {
         let result = calc.calculate_internal(input);
         assert!(
@@ -8,7 +8,7 @@ use link_calculator::Calculator;
 
 /// Helper: evaluate an expression and return the rendered result, asserting success.
 fn eval(input: &str) -> String {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal(input);
     assert!(
         result.success,
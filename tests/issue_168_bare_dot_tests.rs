@@ -24,7 +24,7 @@ fn issue_168_lexer_rejects_standalone_dot_without_loop() {
 #[test]
 fn issue_168_calculate_with_value_rejects_bare_or_dangling_dot_inputs() {
     for input in ["2. 3", "2+2. 3+3", "2.", ". 3"] {
-        let mut calculator = Calculator::new();
+        let calculator = Calculator::new();
 
         let error = calculator
             .calculate_with_value(input)
@@ -39,7 +39,7 @@ fn issue_168_calculate_with_value_rejects_bare_or_dangling_dot_inputs() {
 
 #[test]
 fn issue_168_valid_decimals_still_evaluate() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     let (_expression, value, _steps, lino) = calculator
         .calculate_with_value("3.14 + 2.5")
@@ -5,7 +5,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn issue_170_exact_russian_percent_of_input_evaluates() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("38% от 100к");
 
     assert!(
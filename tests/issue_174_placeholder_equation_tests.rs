@@ -273,7 +273,7 @@ fn solves_more_than_1000_power_equation_edge_cases_with_explicit_steps() {
 
 #[test]
 fn calculate_with_value_returns_structured_placeholder_solution_and_trace() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value("2 * ? + 3 = 11")
         .expect("placeholder equation should solve");
@@ -303,7 +303,7 @@ fn calculate_with_value_returns_structured_placeholder_solution_and_trace() {
 
 #[test]
 fn calculate_with_value_returns_structured_star_placeholder_solution_and_trace() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value("2 * * + 3 = 11")
         .expect("star placeholder equation should solve");
@@ -337,7 +337,7 @@ fn calculate_with_value_returns_structured_repeated_placeholder_solution() {
         ("? + ? = 10", "?", Rational::from_integer(5)),
         ("* + * = 10", "*", Rational::from_integer(5)),
     ] {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let (_expr, value, steps, _lino) = calc
             .calculate_with_value(input)
             .unwrap_or_else(|err| panic!("expected success for {input:?}, got {err:?}"));
@@ -356,7 +356,7 @@ fn calculate_with_value_returns_structured_repeated_placeholder_solution() {
 
 #[test]
 fn calculate_with_value_returns_structured_symbolic_solution_and_detailed_trace() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value("2 * x + 3 * y = 12")
         .expect("multi-variable equation should solve symbolically");
@@ -375,7 +375,7 @@ fn calculate_with_value_returns_structured_symbolic_solution_and_detailed_trace(
 
 #[test]
 fn calculate_with_value_returns_structured_polynomial_solutions_and_detailed_trace() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value("x^2 - 5 * x + 6 = 0")
         .expect("quadratic equation should solve");
@@ -395,7 +395,7 @@ fn calculate_with_value_returns_structured_polynomial_solutions_and_detailed_tra
 #[test]
 fn rejects_unsupported_placeholder_equations() {
     for input in ["? + = 4", "2 * = 8", "** + 2 = 4"] {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal(input);
 
         assert!(
@@ -409,7 +409,7 @@ fn rejects_unsupported_placeholder_equations() {
 #[test]
 fn rejects_unsupported_polynomial_equations() {
     for input in ["x^2 + y = 4", "x / x = 1", "x^2 = 2"] {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal(input);
 
         assert!(
@@ -427,7 +427,7 @@ fn assert_equation_solutions(cases: &[(&str, &str)]) {
 }
 
 fn assert_equation_solution(input: &str, expected: &str) {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal(input);
 
     assert!(
@@ -444,7 +444,7 @@ fn assert_equation_solution(input: &str, expected: &str) {
 }
 
 fn assert_equation_solution_with_required_steps(input: &str, expected: &str) {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value(input)
         .unwrap_or_else(|err| panic!("expected success for {input:?}, got {err:?}"));
@@ -462,7 +462,7 @@ fn assert_equation_solution_with_required_steps(input: &str, expected: &str) {
 }
 
 fn assert_polynomial_solution_with_required_steps(input: &str, expected: &str) {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let (_expr, value, steps, lino) = calc
         .calculate_with_value(input)
         .unwrap_or_else(|err| panic!("expected success for {input:?}, got {err:?}"));
@@ -79,7 +79,7 @@ fn now_uses_local_timezone_when_set() {
 
 #[test]
 fn now_defaults_to_utc_without_offset() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("now");
     assert!(result.success);
     assert!(
@@ -133,7 +133,7 @@ fn unspaced_time_difference_uses_wall_clock() {
 fn negative_time_difference_is_not_collapsed_to_zero() {
     // Reproduces the core of the reported "0 seconds" bug without timezones:
     // an earlier-minus-later subtraction must yield a negative duration.
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("12:00-14:00");
     assert!(
         result.success,
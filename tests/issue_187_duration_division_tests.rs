@@ -9,7 +9,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn issue_187_russian_hours_divided_by_minutes_is_unitless_ratio() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("8 часов / 30 минут");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
@@ -19,7 +19,7 @@ fn issue_187_russian_hours_divided_by_minutes_is_unitless_ratio() {
 
 #[test]
 fn issue_187_english_hours_divided_by_minutes_is_unitless_ratio() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("8 hours / 30 minutes");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
@@ -28,7 +28,7 @@ fn issue_187_english_hours_divided_by_minutes_is_unitless_ratio() {
 
 #[test]
 fn issue_187_reverse_duration_division_converts_units() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("30 minutes / 8 hours");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
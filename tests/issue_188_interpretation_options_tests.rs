@@ -7,7 +7,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn division_then_multiplication_exposes_denominator_grouping_alternative() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("253 / 16 * 3");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
@@ -9,27 +9,27 @@ use link_calculator::Calculator;
 
 #[test]
 fn issue_193_date_difference_divided_by_inr_product_uses_day_count() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(((2026-08-08) - (2026-06-17)) / (30 * (3500 INR)))");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
-    assert_eq!(result.result, "0.000495238095238095");
+    assert_eq!(result.result, "0.0004952380952380952");
     assert_eq!(result.fraction.as_deref(), Some("13/26250"));
 }
 
 #[test]
 fn issue_193_localized_currency_name_uses_same_duration_day_count_rule() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(((2026-08-08) - (2026-06-17)) / (30 * (3500 рупий)))");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
-    assert_eq!(result.result, "0.000495238095238095");
+    assert_eq!(result.result, "0.0004952380952380952");
     assert_eq!(result.fraction.as_deref(), Some("13/26250"));
 }
 
 #[test]
 fn issue_193_explicit_duration_unit_divided_by_currency_becomes_number() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result =
         calc.calculate_internal("(((2026-08-08) - (2026-06-17)) as hours) / (30 * (3500 INR))");
 
@@ -8,7 +8,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn decimal_comma_expression_calculates() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("82,6172 / 100");
 
     assert!(result.success, "calculation failed: {:?}", result.error);
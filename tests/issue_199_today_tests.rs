@@ -10,7 +10,7 @@ use link_calculator::Calculator;
 fn today_minus_fixed_date_returns_elapsed_days() {
     let before = Utc::now().date_naive();
 
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("today - 17.01.2023");
 
     let after = Utc::now().date_naive();
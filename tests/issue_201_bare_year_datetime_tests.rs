@@ -4,7 +4,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn reported_now_minus_bare_year_converts_to_months() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("(now - 2023) in months");
 
     assert!(
@@ -17,7 +17,7 @@ fn reported_now_minus_bare_year_converts_to_months() {
 
 #[test]
 fn bare_year_is_january_first_in_datetime_subtraction() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let forward = calc.calculate_internal("2024-07-01 - 2023 in months");
     assert!(forward.success, "forward subtraction: {:?}", forward.error);
@@ -32,7 +32,7 @@ fn bare_year_is_january_first_in_datetime_subtraction() {
 
 #[test]
 fn bare_year_coercion_is_limited_to_datetime_subtraction() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let arithmetic = calc.calculate_internal("2023 - 10");
     assert!(
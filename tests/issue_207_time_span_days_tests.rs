@@ -4,7 +4,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn reported_time_span_expressions_are_supported() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     for expression in [
         "days between 8th august and now",
@@ -23,7 +23,7 @@ fn reported_time_span_expressions_are_supported() {
 
 #[test]
 fn days_between_returns_the_first_date_minus_the_second_in_days() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result =
         calculator.calculate_internal("days between 8th august 2026 and 24th of july 2026");
 
@@ -33,7 +33,7 @@ fn days_between_returns_the_first_date_minus_the_second_in_days() {
 
 #[test]
 fn days_to_matches_target_date_minus_now_in_days() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let natural = calculator.calculate_internal("days to 8th of august");
     let explicit = calculator.calculate_internal("(8 august - now) as days");
 
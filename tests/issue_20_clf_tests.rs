@@ -14,7 +14,7 @@ use link_calculator::Calculator;
 /// Issue #20: `2 UF + 1 USD` should succeed (main issue expression).
 #[test]
 fn test_issue_20_uf_plus_usd() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("2 UF + 1 USD");
     assert!(
         result.success,
@@ -28,7 +28,7 @@ fn test_issue_20_uf_plus_usd() {
 /// Issue #20: `1 USD + 2 UF` should succeed (reversed operands).
 #[test]
 fn test_issue_20_usd_plus_uf() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("1 USD + 2 UF");
     assert!(
         result.success,
@@ -42,7 +42,7 @@ fn test_issue_20_usd_plus_uf() {
 /// UF alone should parse as CLF currency.
 #[test]
 fn test_issue_20_uf_alone() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("5 UF");
     assert!(
         result.success,
@@ -56,7 +56,7 @@ fn test_issue_20_uf_alone() {
 /// CLF ISO code should be recognized directly.
 #[test]
 fn test_issue_20_clf_iso_code() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("3 CLF");
     assert!(
         result.success,
@@ -70,7 +70,7 @@ fn test_issue_20_clf_iso_code() {
 /// CLF to USD conversion should work.
 #[test]
 fn test_issue_20_clf_to_usd_conversion() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("0 USD + 1 CLF");
     assert!(
         result.success,
@@ -83,7 +83,7 @@ fn test_issue_20_clf_to_usd_conversion() {
 /// UF to USD conversion via exchange rate should produce a positive result.
 #[test]
 fn test_issue_20_uf_to_usd_value() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     // 1 CLF ≈ 45 USD (default rate: 1 USD = 0.022 CLF, so 1 CLF ≈ 45.45 USD)
     let result = calculator.calculate_internal("0 USD + 1 UF");
     assert!(
@@ -102,7 +102,7 @@ fn test_issue_20_uf_to_usd_value() {
 /// UF mixed with EUR should also work via USD triangulation.
 #[test]
 fn test_issue_20_uf_plus_eur() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
     let result = calculator.calculate_internal("1 UF + 1 EUR");
     assert!(
         result.success,
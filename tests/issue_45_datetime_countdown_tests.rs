@@ -9,7 +9,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn standalone_datetime_has_live_time() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("Jan 27, 9:33am UTC");
     assert!(result.success);
     assert_eq!(
@@ -21,7 +21,7 @@ fn standalone_datetime_has_live_time() {
 
 #[test]
 fn standalone_date_has_live_time() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("Jan 27, 2026");
     assert!(result.success);
     assert_eq!(
@@ -33,7 +33,7 @@ fn standalone_date_has_live_time() {
 
 #[test]
 fn datetime_steps_contain_time_since_or_until() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Use a past date to get "Time since"
     let result = calc.calculate_internal("Jan 1, 2020");
     assert!(result.success);
@@ -50,7 +50,7 @@ fn datetime_steps_contain_time_since_or_until() {
 
 #[test]
 fn future_datetime_steps_contain_time_until() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Use a far future date
     let result = calc.calculate_internal("Dec 31, 2099");
     assert!(result.success);
@@ -64,7 +64,7 @@ fn future_datetime_steps_contain_time_until() {
 
 #[test]
 fn datetime_lino_has_parentheses() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("Jan 27, 9:33am UTC");
     assert!(result.success);
     assert!(
@@ -98,7 +98,7 @@ fn plan_standalone_date_is_live_time() {
 
 #[test]
 fn datetime_subtraction_not_live_time() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // DateTime subtraction produces a Duration, not a DateTime
     let result = calc.calculate_internal("(Jan 27, 9:33am UTC) - (Jan 25, 12:51pm UTC)");
     assert!(result.success);
@@ -113,7 +113,7 @@ fn datetime_subtraction_not_live_time() {
 
 #[test]
 fn now_expression_still_live() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("now");
     assert!(result.success);
     assert_eq!(
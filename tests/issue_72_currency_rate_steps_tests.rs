@@ -16,7 +16,7 @@ use link_calculator::Calculator;
 /// Helper: inject a mock ETH/USD rate and a USD/EUR rate so that
 /// `1 ETH in EUR` exercises a cross-rate lookup (ETH→USD→EUR).
 fn calc_with_eth_and_eur_rates() -> Calculator {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // 1 ETH = 1625.0 USD (mock crypto rate)
     let crypto_json = r#"{"ETH": 1625.0}"#;
     calc.update_crypto_rates_from_api("USD", "2026-02-25", crypto_json);
@@ -31,7 +31,7 @@ fn calc_with_eth_and_eur_rates() -> Calculator {
 /// `100 USD as EUR` — fiat-to-fiat unit conversion shows rate info in steps.
 #[test]
 fn test_fiat_unit_conversion_steps_show_rate_info() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let rates_json = r#"{"eur": 0.92}"#;
     calc.update_rates_from_api("USD", "2026-02-25", rates_json);
 
@@ -61,7 +61,7 @@ fn test_fiat_unit_conversion_steps_show_rate_info() {
 /// `100 USD in EUR` — using `in` keyword shows the same rate info.
 #[test]
 fn test_fiat_unit_conversion_in_keyword_steps_show_rate_info() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let rates_json = r#"{"eur": 0.92}"#;
     calc.update_rates_from_api("USD", "2026-02-25", rates_json);
 
@@ -87,7 +87,7 @@ fn test_fiat_unit_conversion_in_keyword_steps_show_rate_info() {
 /// `1 EUR as USD` — reverse fiat direction also shows rate info.
 #[test]
 fn test_reverse_fiat_unit_conversion_steps_show_rate_info() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let rates_json = r#"{"eur": 0.92}"#;
     calc.update_rates_from_api("USD", "2026-02-25", rates_json);
 
@@ -111,7 +111,7 @@ fn test_reverse_fiat_unit_conversion_steps_show_rate_info() {
 /// `1 ETH in USD` — direct crypto-to-fiat unit conversion shows rate info.
 #[test]
 fn test_crypto_direct_unit_conversion_steps_show_rate_info() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let crypto_json = r#"{"ETH": 1625.0}"#;
     calc.update_crypto_rates_from_api("USD", "2026-02-25", crypto_json);
 
@@ -143,7 +143,7 @@ fn test_crypto_direct_unit_conversion_steps_show_rate_info() {
 /// For a cross-rate (ETH→USD→EUR), both individual hops must appear as separate steps.
 #[test]
 fn test_issue_72_eth_in_eur_steps_show_rate_info() {
-    let mut calc = calc_with_eth_and_eur_rates();
+    let calc = calc_with_eth_and_eur_rates();
 
     let result = calc.calculate_internal("1 ETH in EUR");
     assert!(
@@ -201,7 +201,7 @@ fn test_issue_72_eth_in_eur_steps_show_rate_info() {
 /// `1 ETH in EUR` cross-rate shows the ETH→USD rate value explicitly.
 #[test]
 fn test_issue_72_eth_in_eur_shows_eth_usd_rate() {
-    let mut calc = calc_with_eth_and_eur_rates();
+    let calc = calc_with_eth_and_eur_rates();
 
     let result = calc.calculate_internal("1 ETH in EUR");
     assert!(
@@ -227,7 +227,7 @@ fn test_issue_72_eth_in_eur_shows_eth_usd_rate() {
 /// Rate step for fiat conversion shows the exact rate value.
 #[test]
 fn test_fiat_unit_conversion_steps_show_exact_rate_value() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let rates_json = r#"{"eur": 0.92}"#;
     calc.update_rates_from_api("USD", "2026-02-25", rates_json);
 
@@ -251,7 +251,7 @@ fn test_fiat_unit_conversion_steps_show_exact_rate_value() {
 /// `100 USD as USD` — same-currency conversion should NOT show an exchange rate step.
 #[test]
 fn test_same_currency_unit_conversion_no_rate_step() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("100 USD as USD");
     assert!(
@@ -273,7 +273,7 @@ fn test_same_currency_unit_conversion_no_rate_step() {
 /// Without loading API rates, the default hardcoded rate should still appear in steps.
 #[test]
 fn test_default_rate_shown_in_unit_conversion_steps() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // No API rates loaded — falls back to default (hardcoded) rate
 
     let result = calc.calculate_internal("1 USD as EUR");
@@ -30,7 +30,7 @@ use link_calculator::Calculator;
 /// Old hardcoded result was: 916.38 RUB (using 1 USD = 89.5 RUB, 1 INR ≈ 1.034 RUB)
 #[test]
 fn test_issue_73_expression_with_cbr_rates() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // Load CBR rates as the web worker would after a successful CBR fetch or .lino fallback
     // These approximate real CBR rates as of 2026-02-26
@@ -60,7 +60,7 @@ fn test_issue_73_expression_with_cbr_rates() {
 /// Verify that steps reference CBR as the source for the issue #73 expression.
 #[test]
 fn test_issue_73_steps_show_cbr_source() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let cbr_rates = r#"{"usd": 76.47, "inr": 0.8408}"#;
     calc.update_cbr_rates_from_api("2026-02-26", cbr_rates);
@@ -81,7 +81,7 @@ fn test_issue_73_steps_show_cbr_source() {
 /// after CBR rates are loaded with both USD and INR.
 #[test]
 fn test_issue_73_inr_rub_no_triangulation_after_cbr() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // Load both USD and INR directly from CBR
     let cbr_rates = r#"{"usd": 76.47, "inr": 0.8408}"#;
@@ -102,7 +102,7 @@ fn test_issue_73_inr_rub_no_triangulation_after_cbr() {
 /// Verify USD→RUB rate uses CBR value (not hardcoded 89.5) for issue #73 expression.
 #[test]
 fn test_issue_73_usd_rub_rate_is_cbr_not_hardcoded() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // CBR rate: 1 USD = 76.47 RUB
     // Hardcoded rate: 1 USD = 89.5 RUB
@@ -130,7 +130,7 @@ fn test_issue_73_usd_rub_rate_is_cbr_not_hardcoded() {
 #[test]
 fn test_issue_73_hardcoded_default_gives_wrong_result() {
     // Without CBR rates loaded, Calculator uses hardcoded defaults
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     let result = calc.calculate_internal("10 RUB + 10 USD + 11 INR");
     assert!(
@@ -158,7 +158,7 @@ fn test_issue_73_hardcoded_default_gives_wrong_result() {
 /// The download script divides by nominal (100 for INR): 84.0766 / 100 = 0.840766 RUB/INR.
 #[test]
 fn test_issue_73_inr_lino_rate_format() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
 
     // This simulates what the fallback code in the web worker does after reading
     // the inr-rub.lino file (latest rate: 1 INR = 0.840766 RUB)
@@ -25,7 +25,7 @@ use link_calculator::Calculator;
 /// German: "1000 Dollar in Euro" (USD to EUR using German currency names)
 #[test]
 fn test_german_dollar_in_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 Dollar in Euro");
     assert!(
         result.success,
@@ -42,7 +42,7 @@ fn test_german_dollar_in_euro() {
 /// German: "Rubel" should map to RUB
 #[test]
 fn test_german_rubel_to_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 Rubel in Euro");
     assert!(
         result.success,
@@ -59,7 +59,7 @@ fn test_german_rubel_to_euro() {
 /// German: "Pfund" should map to GBP
 #[test]
 fn test_german_pfund_in_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 Pfund in Dollar");
     assert!(
         result.success,
@@ -76,7 +76,7 @@ fn test_german_pfund_in_dollar() {
 /// German: "Franken" should map to CHF (German spelling, not "Franc")
 #[test]
 fn test_german_franken_in_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 Franken in Euro");
     assert!(
         result.success,
@@ -93,7 +93,7 @@ fn test_german_franken_in_euro() {
 /// German: "Rupie" should map to INR
 #[test]
 fn test_german_rupie_in_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 Rupie in Dollar");
     assert!(
         result.success,
@@ -110,7 +110,7 @@ fn test_german_rupie_in_dollar() {
 /// German: "Rupien" (plural) should map to INR
 #[test]
 fn test_german_rupien_plural_in_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 Rupien in Euro");
     assert!(
         result.success,
@@ -131,7 +131,7 @@ fn test_german_rupien_plural_in_euro() {
 /// French: "1000 dollars en euros" (USD to EUR, the canonical French form)
 #[test]
 fn test_french_dollars_en_euros() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 dollars en euros");
     assert!(
         result.success,
@@ -148,7 +148,7 @@ fn test_french_dollars_en_euros() {
 /// French preposition "en" should work as the conversion keyword with ISO codes.
 #[test]
 fn test_french_en_keyword_with_iso_codes() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result_french = calc.calculate_internal("1000 USD en EUR");
     let result_english = calc.calculate_internal("1000 USD in EUR");
     assert!(
@@ -167,7 +167,7 @@ fn test_french_en_keyword_with_iso_codes() {
 /// The single-token "livre" / "livres" forms work.
 #[test]
 fn test_french_livre_en_euros() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 livres en euros");
     assert!(
         result.success,
@@ -184,7 +184,7 @@ fn test_french_livre_en_euros() {
 /// French: "roupie" (French spelling of rupee) should map to INR
 #[test]
 fn test_french_roupie_en_euros() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 roupies en euros");
     assert!(
         result.success,
@@ -203,7 +203,7 @@ fn test_french_roupie_en_euros() {
 /// The single-token "rouble" / "roubles" forms work.
 #[test]
 fn test_french_rouble_en_dollars() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 roubles en dollars");
     assert!(
         result.success,
@@ -222,7 +222,7 @@ fn test_french_rouble_en_dollars() {
 /// The single-token "franc" / "francs" forms work.
 #[test]
 fn test_french_franc_en_euros() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 francs en euros");
     assert!(
         result.success,
@@ -239,7 +239,7 @@ fn test_french_franc_en_euros() {
 /// French: "yuans" (plural) should map to CNY
 #[test]
 fn test_french_yuans_en_euros() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 yuans en euros");
     assert!(
         result.success,
@@ -262,7 +262,7 @@ fn test_french_yuans_en_euros() {
 /// word boundary markers. The conversion keyword "换成" must be space-separated.
 #[test]
 fn test_chinese_usd_to_eur_huan_cheng() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 美元 换成 欧元");
     assert!(
         result.success,
@@ -279,7 +279,7 @@ fn test_chinese_usd_to_eur_huan_cheng() {
 /// Chinese: "转换为" (zhuǎn huàn wèi) conversion keyword should work
 #[test]
 fn test_chinese_usd_to_eur_zhuan_huan_wei() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 美元 转换为 欧元");
     assert!(
         result.success,
@@ -296,7 +296,7 @@ fn test_chinese_usd_to_eur_zhuan_huan_wei() {
 /// Chinese: "人民币" (rén mín bì = Renminbi/CNY) should be recognized
 #[test]
 fn test_chinese_renminbi_to_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 人民币 换成 美元");
     assert!(
         result.success,
@@ -313,7 +313,7 @@ fn test_chinese_renminbi_to_usd() {
 /// Chinese: "美金" (měi jīn, colloquial for USD) should be recognized
 #[test]
 fn test_chinese_mei_jin_to_eur() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 美金 换成 欧元");
     assert!(
         result.success,
@@ -330,7 +330,7 @@ fn test_chinese_mei_jin_to_eur() {
 /// Chinese: "英镑" (yīng bàng = GBP) should be recognized
 #[test]
 fn test_chinese_gbp_to_cny() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 英镑 换成 人民币");
     assert!(
         result.success,
@@ -347,7 +347,7 @@ fn test_chinese_gbp_to_cny() {
 /// Chinese: "日元" (rì yuán = JPY) should be recognized
 #[test]
 fn test_chinese_jpy_to_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10000 日元 换成 美元");
     assert!(
         result.success,
@@ -364,7 +364,7 @@ fn test_chinese_jpy_to_usd() {
 /// Chinese: "卢布" (lú bù = RUB) should be recognized
 #[test]
 fn test_chinese_rub_to_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 卢布 换成 美元");
     assert!(
         result.success,
@@ -382,7 +382,7 @@ fn test_chinese_rub_to_usd() {
 /// Note: "印度卢比" (4 chars) arrives as a single identifier token since no spaces.
 #[test]
 fn test_chinese_inr_to_usd() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 印度卢比 换成 美元");
     assert!(
         result.success,
@@ -399,7 +399,7 @@ fn test_chinese_inr_to_usd() {
 /// Chinese: "兑换成" (duì huàn chéng = exchange into) keyword should work
 #[test]
 fn test_chinese_dui_huan_cheng_keyword() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result_dui_huan = calc.calculate_internal("1000 美元 兑换成 欧元");
     let result_huan_cheng = calc.calculate_internal("1000 美元 换成 欧元");
     assert!(
@@ -420,7 +420,7 @@ fn test_chinese_dui_huan_cheng_keyword() {
 /// Hindi: "1000 डॉलर में यूरो" (1000 dollars in euros)
 #[test]
 fn test_hindi_dollar_mein_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 डॉलर में यूरो");
     assert!(
         result.success,
@@ -437,7 +437,7 @@ fn test_hindi_dollar_mein_euro() {
 /// Hindi: "में" postposition should work with ISO codes
 #[test]
 fn test_hindi_mein_keyword_with_iso_codes() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result_hindi = calc.calculate_internal("1000 USD में EUR");
     let result_english = calc.calculate_internal("1000 USD in EUR");
     assert!(
@@ -454,7 +454,7 @@ fn test_hindi_mein_keyword_with_iso_codes() {
 /// Hindi: "रुपये" (plural direct of rupee) should map to INR
 #[test]
 fn test_hindi_rupaye_mein_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 रुपये में डॉलर");
     assert!(
         result.success,
@@ -471,7 +471,7 @@ fn test_hindi_rupaye_mein_dollar() {
 /// Hindi: "पाउंड" (pound = GBP) should be recognized
 #[test]
 fn test_hindi_pound_mein_rupaye() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 पाउंड में रुपये");
     assert!(
         result.success,
@@ -488,7 +488,7 @@ fn test_hindi_pound_mein_rupaye() {
 /// Hindi: "येन" (yen = JPY) should be recognized
 #[test]
 fn test_hindi_yen_mein_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10000 येन में डॉलर");
     assert!(
         result.success,
@@ -505,7 +505,7 @@ fn test_hindi_yen_mein_dollar() {
 /// Hindi: "रूबल" (rubal = RUB) should be recognized
 #[test]
 fn test_hindi_rubal_mein_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 रूबल में डॉलर");
     assert!(
         result.success,
@@ -526,7 +526,7 @@ fn test_hindi_rubal_mein_dollar() {
 /// Arabic: "1000 دولار إلى يورو" (1000 dollars to euros)
 #[test]
 fn test_arabic_dollar_ila_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 دولار إلى يورو");
     assert!(
         result.success,
@@ -543,7 +543,7 @@ fn test_arabic_dollar_ila_euro() {
 /// Arabic: "إلى" keyword should work with ISO codes
 #[test]
 fn test_arabic_ila_keyword_with_iso_codes() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result_arabic = calc.calculate_internal("1000 USD إلى EUR");
     let result_english = calc.calculate_internal("1000 USD to EUR");
     assert!(
@@ -560,7 +560,7 @@ fn test_arabic_ila_keyword_with_iso_codes() {
 /// Arabic: "روبل" (rubl = RUB) should be recognized
 #[test]
 fn test_arabic_rubl_ila_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 روبل إلى دولار");
     assert!(
         result.success,
@@ -577,7 +577,7 @@ fn test_arabic_rubl_ila_dollar() {
 /// Arabic: "جنيه" (junayh = GBP) should be recognized
 #[test]
 fn test_arabic_junayh_ila_euro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 جنيه إلى يورو");
     assert!(
         result.success,
@@ -594,7 +594,7 @@ fn test_arabic_junayh_ila_euro() {
 /// Arabic: "روبية" (rūbiyya = INR) should be recognized
 #[test]
 fn test_arabic_rupiya_ila_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 روبية إلى دولار");
     assert!(
         result.success,
@@ -611,7 +611,7 @@ fn test_arabic_rupiya_ila_dollar() {
 /// Arabic: "فرنك" (frank = CHF) should be recognized
 #[test]
 fn test_arabic_frank_ila_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 فرنك إلى دولار");
     assert!(
         result.success,
@@ -628,7 +628,7 @@ fn test_arabic_frank_ila_dollar() {
 /// Arabic: "يوان" (yuan = CNY) should be recognized
 #[test]
 fn test_arabic_yuan_ila_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 يوان إلى دولار");
     assert!(
         result.success,
@@ -645,7 +645,7 @@ fn test_arabic_yuan_ila_dollar() {
 /// Arabic: "ين" (yen = JPY) should be recognized
 #[test]
 fn test_arabic_yen_ila_dollar() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("10000 ين إلى دولار");
     assert!(
         result.success,
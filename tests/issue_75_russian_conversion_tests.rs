@@ -22,7 +22,7 @@ use link_calculator::Calculator;
 /// The exact expression from the issue report should now work.
 #[test]
 fn test_issue_75_rub_in_dollars_russian() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в долларах");
     assert!(
         result.success,
@@ -39,7 +39,7 @@ fn test_issue_75_rub_in_dollars_russian() {
 /// The converted value should be reasonable (around 11 USD at ~89.5 RUB/USD).
 #[test]
 fn test_issue_75_rub_to_usd_value_is_reasonable() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в долларах");
     assert!(
         result.success,
@@ -59,7 +59,7 @@ fn test_issue_75_rub_to_usd_value_is_reasonable() {
 /// Russian "в" should work as the unit conversion keyword (like "in").
 #[test]
 fn test_issue_75_russian_v_as_in_keyword() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // "1000 RUB в USD" should be identical to "1000 RUB in USD"
     let result_russian = calc.calculate_internal("1000 RUB в USD");
     let result_english = calc.calculate_internal("1000 RUB in USD");
@@ -85,7 +85,7 @@ fn test_issue_75_russian_v_as_in_keyword() {
 /// Russian nominative singular "доллар" should be recognized as USD.
 #[test]
 fn test_issue_75_russian_dollar_nominative() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 RUB в доллар");
     assert!(
         result.success,
@@ -98,7 +98,7 @@ fn test_issue_75_russian_dollar_nominative() {
 /// Russian genitive plural "долларов" (as in "конвертировать в долларов").
 #[test]
 fn test_issue_75_russian_dollar_genitive_plural() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в долларов");
     assert!(
         result.success,
@@ -111,7 +111,7 @@ fn test_issue_75_russian_dollar_genitive_plural() {
 /// Russian prepositional plural "долларах" (the most natural form in "в долларах").
 #[test]
 fn test_issue_75_russian_dollar_prepositional_plural() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("500 RUB в долларах");
     assert!(
         result.success,
@@ -126,7 +126,7 @@ fn test_issue_75_russian_dollar_prepositional_plural() {
 /// Russian "евро" (indeclinable word) should be recognized as EUR.
 #[test]
 fn test_issue_75_russian_evro() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в евро");
     assert!(
         result.success,
@@ -139,7 +139,7 @@ fn test_issue_75_russian_evro() {
 /// Reverse: EUR to RUB using Russian.
 #[test]
 fn test_issue_75_russian_evro_to_rub() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("100 евро в рублях");
     assert!(
         result.success,
@@ -154,7 +154,7 @@ fn test_issue_75_russian_evro_to_rub() {
 /// Russian "фунт" should be recognized as GBP.
 #[test]
 fn test_issue_75_russian_funt() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в фунтах");
     assert!(
         result.success,
@@ -169,7 +169,7 @@ fn test_issue_75_russian_funt() {
 /// Russian "юань" should be recognized as CNY.
 #[test]
 fn test_issue_75_russian_yuan() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в юанях");
     assert!(
         result.success,
@@ -184,7 +184,7 @@ fn test_issue_75_russian_yuan() {
 /// Russian "иена" should be recognized as JPY.
 #[test]
 fn test_issue_75_russian_yen() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в иенах");
     assert!(
         result.success,
@@ -199,7 +199,7 @@ fn test_issue_75_russian_yen() {
 /// Mix of Russian currency name with English conversion keyword should work.
 #[test]
 fn test_issue_75_russian_currency_with_english_in() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей in USD");
     assert!(
         result.success,
@@ -212,7 +212,7 @@ fn test_issue_75_russian_currency_with_english_in() {
 /// Russian currency name with "as" conversion keyword should work.
 #[test]
 fn test_issue_75_russian_currency_with_as() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей as USD");
     assert!(
         result.success,
@@ -225,7 +225,7 @@ fn test_issue_75_russian_currency_with_as() {
 /// Russian ruble amount with Russian "в" and ISO code target should work.
 #[test]
 fn test_issue_75_rub_v_usd_iso_code() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 рублей в USD");
     assert!(
         result.success,
@@ -9,7 +9,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_days() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 10 days");
         assert!(
             result.success,
@@ -26,7 +26,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_plus_days() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 10 days");
         assert!(
             result.success,
@@ -42,7 +42,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_hours() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 2 hours");
         assert!(
             result.success,
@@ -58,7 +58,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_plus_hours() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 2 hours");
         assert!(
             result.success,
@@ -69,7 +69,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_minutes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 30 minutes");
         assert!(
             result.success,
@@ -80,7 +80,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_plus_minutes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 30 minutes");
         assert!(
             result.success,
@@ -91,7 +91,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_weeks() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 2 weeks");
         assert!(
             result.success,
@@ -102,7 +102,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_plus_weeks() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 2 weeks");
         assert!(
             result.success,
@@ -113,7 +113,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_months() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 3 months");
         assert!(
             result.success,
@@ -124,7 +124,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_years() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 1 year");
         assert!(
             result.success,
@@ -135,7 +135,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_now_minus_seconds() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 60 seconds");
         assert!(
             result.success,
@@ -146,7 +146,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_fixed_date_minus_days() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         // Use parentheses to ensure the date is parsed as a datetime, not arithmetic
         let result = calc.calculate_internal("(Jan 27, 8:00am UTC) - 10 days");
         assert!(
@@ -164,7 +164,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_fixed_date_plus_days() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("(Jan 1, 12:00am UTC) + 30 days");
         assert!(
             result.success,
@@ -181,7 +181,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_duration_unit_abbreviation_day() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 1 day");
         assert!(
             result.success,
@@ -192,7 +192,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_duration_unit_abbreviation_hour() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 1 hour");
         assert!(
             result.success,
@@ -203,7 +203,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_duration_unit_abbreviation_minute() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 1 minute");
         assert!(
             result.success,
@@ -214,7 +214,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_duration_unit_abbreviation_second() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 1 second");
         assert!(
             result.success,
@@ -225,7 +225,7 @@ mod issue_83_now_minus_duration {
 
     #[test]
     fn test_duration_unit_abbreviation_week() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now + 1 week");
         assert!(
             result.success,
@@ -237,7 +237,7 @@ mod issue_83_now_minus_duration {
     #[test]
     fn test_now_minus_10_days_exact_issue() {
         // This is the exact failing input from the issue
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now - 10 days");
         assert!(
             result.success,
@@ -8,7 +8,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_integrate_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("integrate(x^2, x, 0, 3)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(integrate ((x ^ 2) x 0 3))");
@@ -16,7 +16,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_sin_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("sin(0)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(sin (0))");
@@ -24,7 +24,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_cos_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("cos(0)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(cos (0))");
@@ -32,7 +32,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_sqrt_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("sqrt(16)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(sqrt (16))");
@@ -40,7 +40,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_pow_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("pow(2, 3)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(pow (2 3))");
@@ -48,7 +48,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_abs_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("abs(-5)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(abs ((-5)))");
@@ -56,7 +56,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_nested_functions_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("sqrt(abs(-16))");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(sqrt ((abs ((-16)))))");
@@ -64,7 +64,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_function_with_expression_arg_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("sqrt(9 + 7)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(sqrt ((9 + 7)))");
@@ -72,7 +72,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_min_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("min(5, 3)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(min (5 3))");
@@ -80,7 +80,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_max_function_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("max(5, 3)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(max (5 3))");
@@ -88,7 +88,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_power_operator_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2^3");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(2 ^ 3)");
@@ -96,7 +96,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_pi_constant_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("pi()");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(pi)");
@@ -104,7 +104,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_e_constant_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("e()");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(e)");
@@ -112,7 +112,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_binary_expression_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2 + 3");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(2 + 3)");
@@ -120,7 +120,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_indefinite_integral_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("integrate x^2 dx");
         assert!(result.success);
         assert_eq!(
@@ -131,7 +131,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_indefinite_integral_sin_over_x_lino() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("integrate sin(x)/x dx");
         assert!(result.success);
         assert_eq!(
@@ -143,7 +143,7 @@ mod lino_notation_tests {
     #[test]
     fn test_indefinite_integral_cos_x_lino() {
         // Issue #89: integrate cos(x) dx should produce explicit lino notation
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("integrate cos(x) dx");
         assert!(result.success);
         assert_eq!(
@@ -154,7 +154,7 @@ mod lino_notation_tests {
 
     #[test]
     fn test_all_expressions_have_outer_parens() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
 
         let expressions = vec![
             "sin(0)",
@@ -185,7 +185,7 @@ mod alternative_interpretation_tests {
 
     #[test]
     fn test_simple_expression_no_alternatives() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2 + 3");
         assert!(result.success);
         // Simple addition has no alternative interpretations
@@ -194,7 +194,7 @@ mod alternative_interpretation_tests {
 
     #[test]
     fn test_mixed_precedence_has_alternatives() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2 + 3 * 4");
         assert!(result.success);
         assert_eq!(result.result, "14");
@@ -212,7 +212,7 @@ mod alternative_interpretation_tests {
     fn test_function_call_no_alternatives() {
         // Simple function calls with unambiguous arguments should not produce
         // spurious "expression" alternatives (see issue #121).
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("sin(0)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(sin (0))");
@@ -226,7 +226,7 @@ mod alternative_interpretation_tests {
     #[test]
     fn test_integrate_function_no_alternatives() {
         // Integration with unambiguous arguments should not produce alternatives.
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("integrate(x^2, x, 0, 3)");
         assert!(result.success);
         assert_eq!(result.lino_interpretation, "(integrate ((x ^ 2) x 0 3))");
@@ -238,7 +238,7 @@ mod alternative_interpretation_tests {
 
     #[test]
     fn test_power_expression_no_alternatives() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2^3");
         assert!(result.success);
         // Simple power has no alternative interpretations
@@ -247,7 +247,7 @@ mod alternative_interpretation_tests {
 
     #[test]
     fn test_reverse_mixed_precedence() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("2 * 3 + 4");
         assert!(result.success);
         assert_eq!(result.result, "10");
@@ -7,7 +7,7 @@ mod issue_91_format {
 
     #[test]
     fn test_utc_time_display_format() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(
             result.success,
@@ -24,7 +24,7 @@ mod issue_91_format {
 
     #[test]
     fn test_current_utc_time_display_format() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("current UTC time");
         assert!(
             result.success,
@@ -40,7 +40,7 @@ mod issue_91_format {
 
     #[test]
     fn test_utc_time_contains_offset() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(result.success);
         // Should contain (+00:00) for UTC
@@ -53,7 +53,7 @@ mod issue_91_format {
 
     #[test]
     fn test_utc_time_is_live_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(result.success);
         assert_eq!(
@@ -66,7 +66,7 @@ mod issue_91_format {
 
     #[test]
     fn test_current_time_is_live_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("current time");
         assert!(result.success);
         assert_eq!(
@@ -78,7 +78,7 @@ mod issue_91_format {
 
     #[test]
     fn test_now_is_live_time() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now");
         assert!(result.success);
         assert_eq!(
@@ -94,7 +94,7 @@ mod issue_91_format {
         // Static datetime expressions should have is_live_time=true
         // so the frontend auto-refreshes the "Time since/until" countdown display.
         // See issue #45.
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("Jan 1, 12:00am UTC");
         assert!(result.success);
         assert_eq!(
@@ -107,7 +107,7 @@ mod issue_91_format {
 
     #[test]
     fn test_est_time_phrase() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("EST time");
         assert!(
             result.success,
@@ -123,7 +123,7 @@ mod issue_91_format {
 
     #[test]
     fn test_est_time_contains_offset() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("EST time");
         assert!(result.success);
         // EST is -05:00
@@ -136,7 +136,7 @@ mod issue_91_format {
 
     #[test]
     fn test_utc_time_format_has_outer_parentheses() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(result.success);
         // Result should be wrapped in outer parentheses: ('current UTC time': ... UTC (+00:00))
@@ -149,7 +149,7 @@ mod issue_91_format {
 
     #[test]
     fn test_utc_time_contains_tz_name_in_value() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("UTC time");
         assert!(result.success);
         // The timezone name "UTC" should appear in the value portion (after the datetime)
@@ -163,7 +163,7 @@ mod issue_91_format {
 
     #[test]
     fn test_est_time_contains_tz_name_in_value() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("EST time");
         assert!(result.success);
         // The timezone name "EST" should appear in the value portion
@@ -177,7 +177,7 @@ mod issue_91_format {
 
     #[test]
     fn test_now_display_format() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("now");
         assert!(result.success);
         // "now" should use the labeled format with UTC
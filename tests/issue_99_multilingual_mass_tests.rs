@@ -17,7 +17,7 @@ use link_calculator::Calculator;
 /// The exact input from the issue report: `19 ton в кг`
 #[test]
 fn test_issue_99_exact_input() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("19 ton в кг");
     assert!(
         result.success,
@@ -38,7 +38,7 @@ fn test_issue_99_exact_input() {
 /// Russian: "кг" should be recognized as kilogram.
 #[test]
 fn test_russian_kg_alias() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 г в кг");
     assert!(
         result.success,
@@ -51,7 +51,7 @@ fn test_russian_kg_alias() {
 /// Russian: "г" should be recognized as gram.
 #[test]
 fn test_russian_gram_alias() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 кг в г");
     assert!(
         result.success,
@@ -64,7 +64,7 @@ fn test_russian_gram_alias() {
 /// Russian: "мг" should be recognized as milligram.
 #[test]
 fn test_russian_mg_alias() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 г в мг");
     assert!(
         result.success,
@@ -77,7 +77,7 @@ fn test_russian_mg_alias() {
 /// Russian: full word "килограмм" should be recognized.
 #[test]
 fn test_russian_kilogramm_full_word() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 тонна в килограммах");
     assert!(
         result.success,
@@ -90,7 +90,7 @@ fn test_russian_kilogramm_full_word() {
 /// Russian: "тонна" should be recognized as metric ton.
 #[test]
 fn test_russian_tonna() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 тонна в кг");
     assert!(
         result.success,
@@ -103,7 +103,7 @@ fn test_russian_tonna() {
 /// Russian: genitive case "тонн" should be recognized.
 #[test]
 fn test_russian_tonn_genitive() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("5 тонн в кг");
     assert!(
         result.success,
@@ -116,7 +116,7 @@ fn test_russian_tonn_genitive() {
 /// Russian: "унция" (ounce) should be recognized.
 #[test]
 fn test_russian_untsia_ounce() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 кг в унциях");
     assert!(
         result.success,
@@ -133,7 +133,7 @@ fn test_russian_untsia_ounce() {
 /// Russian: "граммов" (genitive plural) should be recognized.
 #[test]
 fn test_russian_grammov_genitive() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 кг в граммов");
     assert!(
         result.success,
@@ -150,7 +150,7 @@ fn test_russian_grammov_genitive() {
 /// Chinese: "公斤" (gōng jīn, common for kg) should be recognized.
 #[test]
 fn test_chinese_gongjin_kg() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 克 换成 公斤");
     assert!(
         result.success,
@@ -163,7 +163,7 @@ fn test_chinese_gongjin_kg() {
 /// Chinese: "千克" (qiān kè, formal for kg) should be recognized.
 #[test]
 fn test_chinese_qianke_kg() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 千克 换成 克");
     assert!(
         result.success,
@@ -176,7 +176,7 @@ fn test_chinese_qianke_kg() {
 /// Chinese: "克" (kè, gram) should be recognized.
 #[test]
 fn test_chinese_ke_gram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 公斤 换成 克");
     assert!(
         result.success,
@@ -189,7 +189,7 @@ fn test_chinese_ke_gram() {
 /// Chinese: "吨" (dūn, metric ton) should be recognized.
 #[test]
 fn test_chinese_dun_ton() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 吨 换成 公斤");
     assert!(
         result.success,
@@ -202,7 +202,7 @@ fn test_chinese_dun_ton() {
 /// Chinese: "磅" (bàng, pound) should be recognized.
 #[test]
 fn test_chinese_bang_pound() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 磅 换成 克");
     assert!(
         result.success,
@@ -219,7 +219,7 @@ fn test_chinese_bang_pound() {
 /// Chinese: "盎司" (àng sī, ounce) should be recognized.
 #[test]
 fn test_chinese_angsi_ounce() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 公斤 换成 盎司");
     assert!(
         result.success,
@@ -236,7 +236,7 @@ fn test_chinese_angsi_ounce() {
 /// Chinese: "毫克" (háo kè, milligram) should be recognized.
 #[test]
 fn test_chinese_haoke_milligram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 克 换成 毫克");
     assert!(
         result.success,
@@ -253,7 +253,7 @@ fn test_chinese_haoke_milligram() {
 /// Hindi: "किलोग्राम" (kilōgrām) should be recognized as kilogram.
 #[test]
 fn test_hindi_kilogram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 ग्राम में किलोग्राम");
     assert!(
         result.success,
@@ -266,7 +266,7 @@ fn test_hindi_kilogram() {
 /// Hindi: "ग्राम" (grām) should be recognized as gram.
 #[test]
 fn test_hindi_gram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 किलोग्राम में ग्राम");
     assert!(
         result.success,
@@ -279,7 +279,7 @@ fn test_hindi_gram() {
 /// Hindi: "टन" (ṭan) should be recognized as metric ton.
 #[test]
 fn test_hindi_ton() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 टन में किलोग्राम");
     assert!(
         result.success,
@@ -292,7 +292,7 @@ fn test_hindi_ton() {
 /// Hindi: "किलो" (kilo, short form) should be recognized as kilogram.
 #[test]
 fn test_hindi_kilo_short() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("2 किलो में ग्राम");
     assert!(
         result.success,
@@ -309,7 +309,7 @@ fn test_hindi_kilo_short() {
 /// Arabic: "كيلوغرام" (kīlūġrām) should be recognized as kilogram.
 #[test]
 fn test_arabic_kilogram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 غرام إلى كيلوغرام");
     assert!(
         result.success,
@@ -322,7 +322,7 @@ fn test_arabic_kilogram() {
 /// Arabic: "غرام" (ġrām) should be recognized as gram.
 #[test]
 fn test_arabic_gram() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 كيلوغرام إلى غرام");
     assert!(
         result.success,
@@ -335,7 +335,7 @@ fn test_arabic_gram() {
 /// Arabic: "طن" (ṭun) should be recognized as metric ton.
 #[test]
 fn test_arabic_ton() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 طن إلى كيلوغرام");
     assert!(
         result.success,
@@ -348,7 +348,7 @@ fn test_arabic_ton() {
 /// Arabic: "رطل" (raṭl, pound) should be recognized — distinct from "جنيه" (GBP).
 #[test]
 fn test_arabic_ratl_pound() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 رطل إلى غرام");
     assert!(
         result.success,
@@ -365,7 +365,7 @@ fn test_arabic_ratl_pound() {
 /// Arabic: "كغ" (abbreviation for kg) should be recognized.
 #[test]
 fn test_arabic_kg_abbreviation() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 غرام إلى كغ");
     assert!(
         result.success,
@@ -382,7 +382,7 @@ fn test_arabic_kg_abbreviation() {
 /// German: "Kilogramm" should be recognized.
 #[test]
 fn test_german_kilogramm() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 Gramm in Kilogramm");
     assert!(
         result.success,
@@ -395,7 +395,7 @@ fn test_german_kilogramm() {
 /// German: "Gramm" should be recognized.
 #[test]
 fn test_german_gramm() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 Kilogramm in Gramm");
     assert!(
         result.success,
@@ -408,7 +408,7 @@ fn test_german_gramm() {
 /// German: "Unze" should be recognized as ounce.
 #[test]
 fn test_german_unze() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 kg in Unzen");
     assert!(
         result.success,
@@ -429,7 +429,7 @@ fn test_german_unze() {
 /// French: "kilogramme" should be recognized.
 #[test]
 fn test_french_kilogramme() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1000 grammes en kilogrammes");
     assert!(
         result.success,
@@ -442,7 +442,7 @@ fn test_french_kilogramme() {
 /// French: "gramme" should be recognized.
 #[test]
 fn test_french_gramme() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 kilogramme en grammes");
     assert!(
         result.success,
@@ -455,7 +455,7 @@ fn test_french_gramme() {
 /// French: "once" (ounce) should be recognized.
 #[test]
 fn test_french_once_ounce() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let result = calc.calculate_internal("1 kg en onces");
     assert!(
         result.success,
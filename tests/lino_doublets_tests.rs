@@ -0,0 +1,51 @@
+//! Tests for the doublets (source/target links) export of calculation results.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_results_include_doublets_with_the_root_last() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("84 USD - 34 EUR");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(
+        result.lino_doublets.as_deref(),
+        Some("(l1: 84 USD)\n(l2: 34 EUR)\n(l3: l1 -)\n(l4: l3 l2)")
+    );
+}
+
+#[test]
+fn test_every_doublet_has_a_source_and_a_target() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("sqrt(16) + 2 * 3");
+    let doublets = result
+        .lino_doublets
+        .expect("doublets for a compound expression");
+    for line in doublets.lines() {
+        let (id, refs) = line
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split_once(": ")
+            .expect("doublets carry an id");
+        assert!(id.starts_with('l'), "unexpected id in {line}");
+        assert_eq!(refs.split(' ').count(), 2, "not a doublet: {line}");
+    }
+}
+
+#[test]
+fn test_doublet_ids_are_stable() {
+    let calculator = Calculator::new();
+    let first = calculator.calculate_internal("(2 + 3) * (2 + 3)");
+    let second = calculator.calculate_internal("(2 + 3) * (2 + 3)");
+    assert_eq!(first.lino_doublets, second.lino_doublets);
+    assert_eq!(
+        first.lino_doublets.as_deref(),
+        Some("(l1: 2 +)\n(l2: l1 3)\n(l3: l2 *)\n(l4: l3 l2)")
+    );
+}
+
+#[test]
+fn test_single_values_and_failures_have_no_doublets() {
+    let calculator = Calculator::new();
+    assert_eq!(calculator.calculate_internal("42").lino_doublets, None);
+    assert_eq!(calculator.calculate_internal("1 +").lino_doublets, None);
+}
@@ -0,0 +1,80 @@
+//! Tests for parsing links notation back into expressions (`Expression::from_lino`,
+//! `Calculator::calculate_lino`).
+
+use link_calculator::types::{BinaryOp, Expression, Unit};
+use link_calculator::Calculator;
+
+#[test]
+fn test_from_lino_builds_the_expression_tree() {
+    let expr = Expression::from_lino("((84 USD) - (34 EUR))").unwrap();
+    let Expression::Binary { left, op, right } = expr else {
+        panic!("expected a binary expression, got {expr:?}");
+    };
+    assert_eq!(op, BinaryOp::Subtract);
+    assert!(matches!(*left, Expression::Number { ref unit, .. } if *unit == Unit::currency("USD")));
+    assert!(
+        matches!(*right, Expression::Number { ref unit, .. } if *unit == Unit::currency("EUR"))
+    );
+}
+
+#[test]
+fn test_calculate_lino_evaluates_currency_expressions() {
+    let calculator = Calculator::new();
+    let text = calculator.calculate_internal("84 USD - 34 EUR");
+    let lino = calculator.calculate_lino("((84 USD) - (34 EUR))");
+    assert!(lino.success, "calculation failed: {:?}", lino.error);
+    assert_eq!(lino.result, text.result);
+    assert_eq!(lino.lino_interpretation, "((84 USD) - (34 EUR))");
+}
+
+#[test]
+fn test_calculate_lino_adds_to_the_history() {
+    let calculator = Calculator::new();
+    let lino = calculator.calculate_lino("(2 + 3)");
+    assert!(lino.success, "calculation failed: {:?}", lino.error);
+    assert_eq!(calculator.calculate_internal("ans * 2").result, "10");
+}
+
+#[test]
+fn test_lino_interpretations_round_trip() {
+    let calculator = Calculator::new();
+    for input in [
+        "2 + 3 * 4",
+        "10 - (2 - 3)",
+        "-(2 + 3)",
+        "2^3^2",
+        "sqrt(16) + 5!",
+        "pi",
+        "[1, 2, 3] * 2",
+        "[10, 20, 30][1]",
+        "3 days in hours",
+        "255 in hex",
+        "(Jan 30, 12:00) - (Jan 20)",
+        "age(15 June 1990, 1 Jan 2026)",
+        "2 + 3 = 5",
+        "1 < 2",
+        "compare 1 and 2",
+        "x + 2 = 5",
+        "integrate x^2 dx",
+        "12:30 UTC",
+    ] {
+        let text = calculator.calculate_internal(input);
+        assert!(text.success, "{input:?} failed: {:?}", text.error);
+        let lino = calculator.calculate_lino(&text.lino_interpretation);
+        assert!(
+            lino.success,
+            "{:?} failed: {:?}",
+            text.lino_interpretation, lino.error
+        );
+        assert_eq!(lino.result, text.result, "round trip of {input:?}");
+    }
+}
+
+#[test]
+fn test_invalid_lino_is_an_error() {
+    let calculator = Calculator::new();
+    for lino in ["((2 + 3)", "(2 +)", "(1) (2)", ""] {
+        let result = calculator.calculate_lino(lino);
+        assert!(!result.success, "{lino:?} should fail");
+    }
+}
@@ -16,7 +16,7 @@ fn test_calculator_creation() {
 
 #[test]
 fn test_load_rate_from_lino() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let content = "rate:
   from USD
   to EUR
@@ -34,7 +34,7 @@ fn test_load_rate_from_lino() {
 
 #[test]
 fn test_load_rates_batch() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let content1 = "rate:
   from USD
   to EUR
@@ -56,7 +56,7 @@ fn test_load_rates_batch() {
 
 #[test]
 fn test_load_rates_from_consolidated_lino_legacy_format() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // Legacy format: rates: as root, data: for rates
     let content = "rates:
   from USD
@@ -73,7 +73,7 @@ fn test_load_rates_from_consolidated_lino_legacy_format() {
 
 #[test]
 fn test_load_rates_from_consolidated_lino_new_format() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     // New format: conversion: as root, rates: for rates
     let content = "conversion:
   from USD
@@ -90,7 +90,7 @@ fn test_load_rates_from_consolidated_lino_new_format() {
 
 #[test]
 fn test_load_rates_from_consolidated_lino_empty() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let content = "conversion:
   from USD
   to EUR
@@ -11,7 +11,7 @@ use link_calculator::Calculator;
 /// Uses the "Feb 8, 2021" date format which is parsed as a `DateTime` correctly.
 #[test]
 fn test_load_lino_rates_and_use_in_conversion() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // Load USD to RUB rates in the new .lino format
     let lino_content = "conversion:
@@ -42,7 +42,12 @@ fn test_load_lino_rates_and_use_in_conversion() {
     );
 
     // Parse the result to get the numeric value
-    let result_str = calc_result.result.replace(" RUB", "").replace(',', "");
+    let result_str = calc_result
+        .raw_result
+        .as_ref()
+        .unwrap_or(&calc_result.result)
+        .replace(" RUB", "")
+        .replace(',', "");
     let result_value: f64 = result_str
         .trim()
         .parse()
@@ -60,7 +65,7 @@ fn test_load_lino_rates_and_use_in_conversion() {
 /// (YYYY-MM-DD) is currently tokenized as arithmetic, not as a date.
 #[test]
 fn test_different_dates_use_different_rates() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // Load multiple dates of USD to EUR rates
     let lino_content = "conversion:
@@ -78,7 +83,12 @@ fn test_different_dates_use_different_rates() {
     // Test first date - using month name format
     let result1 = calculator.calculate_internal("(0 EUR + 1 USD) at Jan 25, 2021");
     assert!(result1.success);
-    let val1_str = result1.result.replace(" EUR", "").replace(',', "");
+    let val1_str = result1
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result1.result)
+        .replace(" EUR", "")
+        .replace(',', "");
     let val1: f64 = val1_str.trim().parse().expect("Should parse");
     assert!(
         (val1 - 0.8234).abs() < 0.001,
@@ -88,7 +98,12 @@ fn test_different_dates_use_different_rates() {
     // Test second date - using month name format
     let result2 = calculator.calculate_internal("(0 EUR + 1 USD) at Feb 8, 2021");
     assert!(result2.success);
-    let val2_str = result2.result.replace(" EUR", "").replace(',', "");
+    let val2_str = result2
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result2.result)
+        .replace(" EUR", "")
+        .replace(',', "");
     let val2: f64 = val2_str.trim().parse().expect("Should parse");
     assert!(
         (val2 - 0.8402).abs() < 0.001,
@@ -105,7 +120,7 @@ fn test_different_dates_use_different_rates() {
 /// Test loading rates for multiple currency pairs.
 #[test]
 fn test_multiple_currency_pairs() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // Load EUR to GBP rates
     let eur_gbp_content = "conversion:
@@ -131,7 +146,12 @@ fn test_multiple_currency_pairs() {
     // Test EUR to GBP conversion - using month name format
     let result1 = calculator.calculate_internal("(0 GBP + 1 EUR) at Feb 8, 2021");
     assert!(result1.success);
-    let val1_str = result1.result.replace(" GBP", "").replace(',', "");
+    let val1_str = result1
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result1.result)
+        .replace(" GBP", "")
+        .replace(',', "");
     let val1: f64 = val1_str.trim().parse().expect("Should parse");
     assert!(
         (val1 - 0.8765).abs() < 0.001,
@@ -141,7 +161,12 @@ fn test_multiple_currency_pairs() {
     // Test USD to JPY conversion - using month name format
     let result2 = calculator.calculate_internal("(0 JPY + 1 USD) at Feb 8, 2021");
     assert!(result2.success);
-    let val2_str = result2.result.replace(" JPY", "").replace(',', "");
+    let val2_str = result2
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result2.result)
+        .replace(" JPY", "")
+        .replace(',', "");
     let val2: f64 = val2_str.trim().parse().expect("Should parse");
     assert!(
         (val2 - 105.25).abs() < 0.1,
@@ -152,7 +177,7 @@ fn test_multiple_currency_pairs() {
 /// Test that the inverse rate is also available after loading.
 #[test]
 fn test_inverse_rate_available() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // Load USD to RUB rate
     let lino_content = "conversion:
@@ -172,7 +197,12 @@ fn test_inverse_rate_available() {
     // Test inverse conversion: RUB -> USD (using month name format)
     let result2 = calculator.calculate_internal("(0 USD + 100 RUB) at Feb 8, 2021");
     assert!(result2.success);
-    let val_str = result2.result.replace(" USD", "").replace(',', "");
+    let val_str = result2
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result2.result)
+        .replace(" USD", "")
+        .replace(',', "");
     let val: f64 = val_str.trim().parse().expect("Should parse");
 
     // 100 RUB at 74.2602 rate = 100 / 74.2602 ≈ 1.347 USD
@@ -186,7 +216,7 @@ fn test_inverse_rate_available() {
 /// Test that rate source and date are shown in calculation steps.
 #[test]
 fn test_rate_info_shown_in_steps() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     let lino_content = "conversion:
   from USD
@@ -220,7 +250,7 @@ fn test_rate_info_shown_in_steps() {
 /// Test loading the legacy .lino format (rates/data) still works.
 #[test]
 fn test_legacy_lino_format() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // Legacy format: rates: as root, data: for rates
     let lino_content = "rates:
@@ -237,7 +267,12 @@ fn test_legacy_lino_format() {
     // Verify the rate is used - using month name format for the date
     let calc_result = calculator.calculate_internal("(0 EUR + 1 USD) at Jan 25, 2021");
     assert!(calc_result.success);
-    let val_str = calc_result.result.replace(" EUR", "").replace(',', "");
+    let val_str = calc_result
+        .raw_result
+        .as_ref()
+        .unwrap_or(&calc_result.result)
+        .replace(" EUR", "")
+        .replace(',', "");
     let val: f64 = val_str.trim().parse().expect("Should parse");
     assert!(
         (val - 0.8234).abs() < 0.001,
@@ -248,7 +283,7 @@ fn test_legacy_lino_format() {
 /// Test arithmetic with currency conversion uses correct rates.
 #[test]
 fn test_currency_arithmetic_with_loaded_rates() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     let lino_content = "conversion:
   from USD
@@ -266,7 +301,12 @@ fn test_currency_arithmetic_with_loaded_rates() {
     // Total = 100 + 58.82 = 158.82 USD
     let result = calculator.calculate_internal("(100 USD + 50 EUR) at Feb 8, 2021");
     assert!(result.success, "Arithmetic with conversion should succeed");
-    let val_str = result.result.replace(" USD", "").replace(',', "");
+    let val_str = result
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result.result)
+        .replace(" USD", "")
+        .replace(',', "");
     let val: f64 = val_str.trim().parse().expect("Should parse");
 
     let expected = 100.0 + (50.0 / 0.85);
@@ -279,7 +319,7 @@ fn test_currency_arithmetic_with_loaded_rates() {
 /// Test subtraction with currency conversion.
 #[test]
 fn test_currency_subtraction_with_loaded_rates() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     let lino_content = "conversion:
   from USD
@@ -300,7 +340,12 @@ fn test_currency_subtraction_with_loaded_rates() {
         "Subtraction with conversion should succeed: {:?}",
         result.error
     );
-    let val_str = result.result.replace(" USD", "").replace(',', "");
+    let val_str = result
+        .raw_result
+        .as_ref()
+        .unwrap_or(&result.result)
+        .replace(" USD", "")
+        .replace(',', "");
     let val: f64 = val_str.trim().parse().expect("Should parse");
 
     let expected = 100.0 - (34.0 / 0.85);
@@ -316,7 +361,7 @@ fn test_currency_subtraction_with_loaded_rates() {
 /// literal, so the temporal context is honored.
 #[test]
 fn test_iso_date_format_in_at_clause() {
-    let mut calculator = Calculator::new();
+    let calculator = Calculator::new();
 
     // With no rate loaded for that date the calculation fails, but the error now
     // proves the date was parsed (it references the date), rather than the old
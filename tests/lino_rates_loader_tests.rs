@@ -10,7 +10,7 @@ use link_calculator::Calculator;
 
 #[test]
 fn lino_rates_used_in_historical_conversion() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let content = "conversion:
   from USD
   to RUB
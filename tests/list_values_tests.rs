@@ -0,0 +1,58 @@
+//! Tests for list values: literals, element-wise arithmetic, indexing and aggregation.
+
+mod common;
+
+use common::{calculate, error};
+use link_calculator::Calculator;
+
+#[test]
+fn test_list_literal() {
+    assert_eq!(calculate("[1, 2, 3]"), "[1, 2, 3]");
+    assert_eq!(calculate("[]"), "[]");
+    assert_eq!(calculate("[1 + 1, 2 * 3]"), "[2, 6]");
+}
+
+#[test]
+fn test_list_lino() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("[1, 2] * 2");
+    assert_eq!(result.lino_interpretation, "((list (1 2)) * 2)");
+}
+
+#[test]
+fn test_element_wise_with_scalar() {
+    assert_eq!(calculate("[1, 2, 3] * 2"), "[2, 4, 6]");
+    assert_eq!(calculate("10 - [1, 2, 3]"), "[9, 8, 7]");
+    assert_eq!(calculate("[1, 2, 3]^2"), "[1, 4, 9]");
+    assert_eq!(calculate("[1 kg, 2 kg] * 3"), "[3 kg, 6 kg]");
+}
+
+#[test]
+fn test_element_wise_with_list() {
+    assert_eq!(calculate("[1, 2, 3] + [10, 20, 30]"), "[11, 22, 33]");
+    assert!(error("[1, 2] + [1, 2, 3]").contains("different lengths"));
+}
+
+#[test]
+fn test_indexing() {
+    assert_eq!(calculate("[10, 20, 30][0]"), "10");
+    assert_eq!(calculate("[10, 20, 30][-1]"), "30");
+    assert_eq!(calculate("[[1, 2], [3, 4]][1][0]"), "3");
+    assert_eq!(calculate("([1, 2, 3] * 10)[2]"), "30");
+    assert!(error("[10, 20, 30][3]").contains("out of range"));
+    assert!(error("[10, 20][0.5]").contains("whole number"));
+}
+
+#[test]
+fn test_aggregation() {
+    assert_eq!(calculate("sum([1, 2, 3])"), "6");
+    assert_eq!(calculate("mean([1, 2, 3, 4])"), "2.5");
+    assert_eq!(calculate("count([4, 5, 6])"), "3");
+    assert_eq!(calculate("sum([1, 2], 3)"), "6");
+    assert_eq!(calculate("sum([1, 2, 3] * 2)"), "12");
+}
+
+#[test]
+fn test_list_conversion() {
+    assert_eq!(calculate("[1, 2, 255] in hex"), "[0x1, 0x2, 0xFF]");
+}
@@ -0,0 +1,78 @@
+//! Tests for internationalized number input: decimal commas, spaces as
+//! thousands separators, and a number locale hint.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::{CalculationOptions, Calculator};
+
+#[test]
+fn test_space_grouping_is_detected_without_a_hint() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "1 234,56 + 2,5"), "1237.06");
+    assert_eq!(
+        calculate_with(&calculator, "1 234 567 / 7"),
+        "176366.7142857143"
+    );
+    assert_eq!(calculate_with(&calculator, "1\u{a0}234,5 * 2"), "2469");
+}
+
+#[test]
+fn test_russian_locale_reads_comma_decimals() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_number_locale("ru-RU"));
+    let result = calculator.calculate_internal("1 234,56 + 2,5");
+    assert_eq!(result.result, "1237.06");
+    assert_eq!(result.lino_interpretation, "(1234.56 + 2.5)");
+}
+
+#[test]
+fn test_comma_followed_by_space_separates_arguments() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_number_locale("ru"));
+    assert_eq!(calculate_with(&calculator, "max(1,5, 2)"), "2");
+    assert_eq!(calculate_with(&calculator, "max(1, 5)"), "5");
+}
+
+#[test]
+fn test_german_locale_reads_dot_grouping() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_number_locale("de"));
+    assert_eq!(calculate_with(&calculator, "1.234 * 2"), "2468");
+    assert_eq!(calculate_with(&calculator, "1.234,5 + 0,5"), "1235");
+    calculator.clear_number_locale();
+    assert_eq!(calculate_with(&calculator, "1.234 * 2"), "2.468");
+}
+
+#[test]
+fn test_english_locale_reads_comma_grouping() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_number_locale("en"));
+    assert_eq!(calculate_with(&calculator, "1,234 / 100"), "12.34");
+}
+
+#[test]
+fn test_unknown_locale_is_rejected() {
+    let mut calculator = Calculator::new();
+    assert!(!calculator.set_number_locale("xx"));
+    assert_eq!(calculate_with(&calculator, "1,234 / 100"), "0.01234");
+}
+
+#[test]
+fn test_number_locale_option_applies_to_one_calculation() {
+    let calculator = Calculator::new();
+    let options = CalculationOptions {
+        number_locale: Some("de".to_string()),
+        ..CalculationOptions::default()
+    };
+    let result = calculator.calculate_with_options("1.234 * 2", &options);
+    assert_eq!(result.result, "2468");
+    assert_eq!(calculate_with(&calculator, "1.234 * 2"), "2.468");
+
+    let options = CalculationOptions {
+        number_locale: Some("xx".to_string()),
+        ..CalculationOptions::default()
+    };
+    let result = calculator.calculate_with_options("1 + 1", &options);
+    assert!(!result.success);
+}
@@ -0,0 +1,70 @@
+//! Tests for `Calculator::set_locale` and `get_supported_locales`.
+
+use link_calculator::{supported_locales, Calculator};
+
+fn calculate_in(locale: &str, input: &str) -> String {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_locale(locale), "{locale}");
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{locale} {input}: {:?}", result.error);
+    result.result
+}
+
+#[test]
+fn test_numbers_are_read_and_written_in_the_locale() {
+    assert_eq!(calculate_in("ru", "1 234,5 * 2"), "2\u{a0}469");
+    assert_eq!(calculate_in("de-DE", "1.234,5 + 1"), "1.235,5");
+    assert_eq!(calculate_in("en", "1,234.5 + 1"), "1,235.5");
+}
+
+#[test]
+fn test_slash_dates_are_day_first_outside_the_us() {
+    assert_eq!(calculate_in("ru", "01/02/2026"), "2026-02-01");
+    assert_eq!(calculate_in("en-GB", "01/02/2026 + 1 day"), "2026-02-02");
+    assert_eq!(calculate_in("fr", "01/02/2026 - 01/01/2026"), "31 days");
+    assert_eq!(calculate_in("en", "01/02/2026"), "2026-01-02");
+    assert_eq!(calculate_in("ru", "12/31/2026"), "2026-12-31");
+}
+
+#[test]
+fn test_the_yen_sign_is_yuan_in_chinese() {
+    assert_eq!(calculate_in("zh", "¥100 + ¥5"), "105 CNY");
+    assert_eq!(calculate_in("en", "¥100"), "100 JPY");
+}
+
+#[test]
+fn test_clear_locale_restores_the_defaults() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_rounding_mode("half_even"));
+    assert!(calculator.set_locale("de"));
+    calculator.clear_locale();
+    assert_eq!(calculator.calculate_internal("1234.5 * 2").result, "2469");
+    assert_eq!(
+        calculator.calculate_internal("01/02/2026").result,
+        "2026-01-02"
+    );
+    assert_eq!(
+        calculator.format_options().rounding_mode,
+        link_calculator::types::RoundingMode::HalfEven
+    );
+}
+
+#[test]
+fn test_unsupported_locales_are_rejected() {
+    let mut calculator = Calculator::new();
+    assert!(!calculator.set_locale("xx"));
+    assert_eq!(calculator.calculate_internal("1234.5").result, "1234.5");
+}
+
+#[test]
+fn test_supported_locales_describe_each_locale() {
+    let codes: Vec<_> = supported_locales().iter().map(|l| l.code).collect();
+    assert_eq!(codes, ["en", "ru", "zh", "hi", "ar", "de", "fr"]);
+    let json = Calculator::get_supported_locales();
+    let locales: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let german = &locales[5];
+    assert_eq!(german["native_name"], "Deutsch");
+    assert_eq!(german["decimal_separator"], ",");
+    assert_eq!(german["day_first_dates"], true);
+    assert_eq!(locales[2]["yen_sign"], "CNY");
+}
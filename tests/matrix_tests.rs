@@ -0,0 +1,62 @@
+//! Tests for matrix literals and matrix operations.
+
+mod common;
+
+use common::{calculate, error};
+use link_calculator::Calculator;
+
+#[test]
+fn test_matrix_arithmetic() {
+    assert_eq!(
+        calculate("[[1,2],[3,4]] * [[5,6],[7,8]]"),
+        "[[19, 22], [43, 50]]"
+    );
+    assert_eq!(calculate("2 * [[1,2],[3,4]]"), "[[2, 4], [6, 8]]");
+    assert_eq!(
+        calculate("[[1,2],[3,4]] - [[1,1],[1,1]]"),
+        "[[0, 1], [2, 3]]"
+    );
+    assert_eq!(calculate("[[1,1],[0,1]]^3"), "[[1, 3], [0, 1]]");
+}
+
+#[test]
+fn test_matrix_functions() {
+    assert_eq!(
+        calculate("transpose([[1,2,3],[4,5,6]])"),
+        "[[1, 4], [2, 5], [3, 6]]"
+    );
+    assert_eq!(calculate("det([[1,2],[3,4]])"), "-2");
+    assert_eq!(calculate("det([[2,0,0],[0,3,0],[0,0,4]])"), "24");
+    assert_eq!(
+        calculate("inverse([[1,2],[3,4]])"),
+        "[[-2, 1], [1.5, -0.5]]"
+    );
+    assert_eq!(calculate("[[2,0],[0,4]]^-1"), "[[0.5, 0], [0, 0.25]]");
+}
+
+#[test]
+fn test_matrix_errors() {
+    assert!(error("inverse([[1,2],[2,4]])").contains("singular"));
+    assert!(error("[[1,2]] * [[1,2]]").contains("1×2"));
+    assert!(error("det([[1,2,3],[4,5,6]])").contains("square"));
+}
+
+#[test]
+fn test_matrix_indexing_and_lists() {
+    assert_eq!(calculate("[[1, 2], [3, 4]][1][0]"), "3");
+    assert_eq!(calculate("[[1, 2], [3]]"), "[[1, 2], [3]]");
+}
+
+#[test]
+fn test_matrix_latex() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("inverse([[1,2],[3,4]])");
+    assert_eq!(
+        result.latex_input.as_deref(),
+        Some("{\\begin{bmatrix} 1 & 2 \\\\ 3 & 4 \\end{bmatrix}}^{-1}")
+    );
+    assert_eq!(
+        result.latex_result.as_deref(),
+        Some("\\begin{bmatrix} -2 & 1 \\\\ \\frac{3}{2} & -\\frac{1}{2} \\end{bmatrix}")
+    );
+}
@@ -0,0 +1,132 @@
+//! Tests for rounding amounts of money to the decimals of their currency.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_money_rounding_can_be_turned_off() {
+    let mut calculator = Calculator::new();
+    calculator.set_round_money(false);
+    let result = calculator.calculate_internal("10 USD / 3");
+    assert_eq!(result.result, "3.333333333333333 USD");
+    assert_eq!(result.raw_result, None);
+}
+
+#[test]
+fn test_money_is_rounded_to_the_decimals_of_its_currency() {
+    let calculator = Calculator::new();
+
+    let result = calculator.calculate_internal("10 USD / 3");
+    assert_eq!(result.result, "3.33 USD");
+    assert_eq!(result.raw_result.as_deref(), Some("3.333333333333333 USD"));
+
+    let result = calculator.calculate_internal("100.5 USD in JPY");
+    assert_eq!(result.result, "14924 JPY");
+    assert_eq!(result.raw_result.as_deref(), Some("14924.25 JPY"));
+
+    assert_eq!(
+        calculator.calculate_internal("100 JPY + 0.4 JPY").result,
+        "100 JPY"
+    );
+    assert_eq!(
+        calculator
+            .calculate_internal("84 USD - 34 EUR in EUR")
+            .result,
+        "43.28 EUR"
+    );
+}
+
+#[test]
+fn test_each_currency_keeps_its_own_decimals() {
+    let calculator = Calculator::new();
+    for (input, expected) in [
+        ("0.001 BTC", "0.001 BTC"),
+        ("0.12345 BTC", "0.12345 BTC"),
+        ("0.123456789 BTC", "0.12345679 BTC"),
+        ("1.2345 KWD", "1.235 KWD"),
+        ("1.23456 BHD", "1.235 BHD"),
+        ("1234.5 KRW", "1235 KRW"),
+    ] {
+        assert_eq!(calculator.calculate_internal(input).result, expected);
+    }
+}
+
+#[test]
+fn test_currencies_of_unknown_precision_are_not_rounded() {
+    let result = Calculator::new().calculate_internal("1.2345 XYZ");
+    assert_eq!(result.result, "1.2345 XYZ");
+    assert_eq!(result.raw_result, None);
+}
+
+#[test]
+fn test_tiny_amounts_round_instead_of_going_scientific() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("0.0000001 USD").result,
+        "0 USD"
+    );
+    assert_eq!(
+        calculator.calculate_internal("0.00000001 BTC").result,
+        "0.00000001 BTC"
+    );
+}
+
+#[test]
+fn test_lino_input_is_rounded_too() {
+    let result = Calculator::new().calculate_lino("(10 USD / 3)");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "3.33 USD");
+}
+
+#[test]
+fn test_unchanged_results_have_no_raw_result() {
+    let result = Calculator::new().calculate_internal("12.5 USD + 1 USD");
+    assert_eq!(result.result, "13.5 USD");
+    assert_eq!(result.raw_result, None);
+}
+
+#[test]
+fn test_bankers_rounding_rounds_halves_to_even() {
+    let mut calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("0.125 USD").result,
+        "0.13 USD"
+    );
+    assert!(calculator.set_rounding_mode("half_even"));
+    assert_eq!(
+        calculator.calculate_internal("0.125 USD").result,
+        "0.12 USD"
+    );
+    assert_eq!(
+        calculator.calculate_internal("0.135 USD").result,
+        "0.14 USD"
+    );
+    assert!(!calculator.set_rounding_mode("sideways"));
+}
+
+#[test]
+fn test_a_requested_precision_wins_over_money_rounding() {
+    let result = Calculator::new().calculate_internal("10 USD / 3 to 4 dp");
+    assert_eq!(result.result, "3.3333 USD");
+}
+
+#[test]
+fn test_money_rounding_is_set_with_format_options() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_format_options(r#"{"round_money": false}"#));
+    assert_eq!(
+        calculator.calculate_internal("2.665 EUR").result,
+        "2.665 EUR"
+    );
+    assert!(calculator.set_format_options(r#"{"round_money": true, "rounding_mode": "half_even"}"#));
+    assert_eq!(
+        calculator.calculate_internal("2.665 EUR").result,
+        "2.66 EUR"
+    );
+}
+
+#[test]
+fn test_numbers_without_currency_are_not_rounded() {
+    let result = Calculator::new().calculate_internal("10 / 3");
+    assert_eq!(result.raw_result, None);
+    assert!(result.result.starts_with("3.333"));
+}
@@ -0,0 +1,148 @@
+//! Tests for per-calculator number formatting preferences.
+
+use link_calculator::types::FormatOptions;
+use link_calculator::Calculator;
+
+#[test]
+fn test_default_format_is_unchanged() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("1234567.5 + 1");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "1234568.5");
+}
+
+#[test]
+fn test_locale_grouping_and_decimal_comma() {
+    let mut calc = Calculator::new();
+    calc.set_format_locale("de-DE");
+    let result = calc.calculate_internal("1234567.5 + 1");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "1.234.568,5");
+}
+
+#[test]
+fn test_grouping_keeps_unit_suffix() {
+    let mut calc = Calculator::new();
+    calc.set_format_locale("en");
+    let result = calc.calculate_internal("1500 USD * 2");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "3,000 USD");
+}
+
+#[test]
+fn test_max_fraction_digits_from_json() {
+    let mut calc = Calculator::new();
+    assert!(calc.set_format_options(r#"{"max_fraction_digits": 3}"#));
+    let result = calc.calculate_internal("1 / 3");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "0.333");
+    // The exact fraction is still reported alongside the rounded display.
+    assert_eq!(result.fraction.as_deref(), Some("1/3"));
+}
+
+#[test]
+fn test_invalid_json_keeps_previous_options() {
+    let mut calc = Calculator::new();
+    calc.set_format_locale("en");
+    assert!(!calc.set_format_options("not json"));
+    assert_eq!(calc.format_options(), &FormatOptions::for_locale("en"));
+}
+
+#[test]
+fn test_significant_figures_via_rust_api() {
+    let mut calc = Calculator::new();
+    calc.format_options_mut().significant_figures = Some(2);
+    let result = calc.calculate_internal("2 / 3");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "0.67");
+
+    calc.clear_format_options();
+    let result = calc.calculate_internal("1000 * 1000");
+    assert_eq!(result.result, "1000000");
+}
+
+#[test]
+fn test_scientific_notation_past_the_thresholds() {
+    let mut calc = Calculator::new();
+    calc.set_scientific_thresholds(Some(15), Some(-6));
+
+    let result = calc.calculate_internal("25!");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "1.551121004333099e25");
+    assert_eq!(result.alternatives[0].value, "15511210043330985984000000");
+
+    let small = calc.calculate_internal("1 / 30000000");
+    assert_eq!(small.result, "3.333333333333333e-8");
+    assert_eq!(calc.calculate_internal("12345 km").result, "12345 km");
+    assert_eq!(calc.calculate_internal("0.000001").result, "0.000001");
+    assert_eq!(
+        calc.calculate_internal("2000000000000000 m").result,
+        "2e15 m"
+    );
+}
+
+#[test]
+fn test_scientific_thresholds_from_json_with_precision() {
+    let mut calc = Calculator::new();
+    assert!(calc.set_format_options(r#"{"scientific_above": 9, "max_fraction_digits": 2}"#));
+    let result = calc.calculate_internal("2^40");
+    assert_eq!(result.result, "1.1e12");
+}
+
+#[test]
+fn test_a_mantissa_rounded_up_to_ten_carries_into_the_exponent() {
+    let calc = Calculator::new();
+    for (input, expected) in [
+        ("99999999999999999", "1e17"),
+        ("9999999999999999999999999999", "1e28"),
+        ("0.000000999999999999999999", "1e-6"),
+        ("-99999999999999999", "-1e17"),
+        ("99999999999999", "99999999999999"),
+    ] {
+        assert_eq!(calc.calculate_internal(input).result, expected, "{input}");
+    }
+}
+
+#[test]
+fn test_scientific_results_read_back_as_input() {
+    let calc = Calculator::new();
+    assert_eq!(calc.calculate_internal("2.5e-3").result, "0.0025");
+    assert_eq!(calc.calculate_internal("1.5E3").result, "1500");
+    assert_eq!(calc.calculate_internal("2e+5").result, "200000");
+    for input in ["1e-7", "1e20", "1 / 30000000", "pi * 10^20", "25!"] {
+        let shown = calc.calculate_internal(input).result;
+        assert!(shown.contains('e'), "{input}: {shown}");
+        let read_back = calc.calculate_internal(&shown);
+        assert!(read_back.success, "{shown}: {:?}", read_back.error);
+        assert_eq!(read_back.result, shown, "{input}");
+    }
+    assert_eq!(
+        calc.calculate_internal("pi * 10^20").result,
+        "3.141592653589793e20"
+    );
+}
+
+#[test]
+fn test_large_and_small_scientific_results_read_back_as_input() {
+    let calc = Calculator::new();
+    for input in [
+        "factorial(100)",
+        "10^400",
+        "10^-400",
+        "2^-2000",
+        "1e400",
+        "1e-400",
+    ] {
+        let shown = calc.calculate_internal(input).result;
+        assert!(shown.contains('e'), "{input}: {shown}");
+        let read_back = calc.calculate_internal(&shown);
+        assert!(read_back.success, "{shown}: {:?}", read_back.error);
+        assert_eq!(read_back.result, shown, "{input}");
+    }
+    assert_eq!(calc.calculate_internal("1e400 / 1e398").result, "100");
+}
@@ -0,0 +1,143 @@
+//! Tests for numeric differentiation (`nderiv`) and root finding (`root`).
+
+mod common;
+
+use common::{calculate, error};
+use link_calculator::Calculator;
+
+#[test]
+fn test_nderiv() {
+    assert_eq!(calculate("nderiv(x^2, x, 3)"), "6");
+    assert_eq!(calculate("nderiv(sin(x), x, 0)"), "1");
+    assert_eq!(calculate("nderiv(ln(x), x, 2)"), "0.5");
+    let value: f64 = calculate("nderiv(exp(x), x, 1)").parse().unwrap();
+    assert!((value - std::f64::consts::E).abs() < 1e-10, "{value}");
+}
+
+#[test]
+fn test_implicit_multiplication() {
+    assert_eq!(calculate("nderiv(3x, x, 1)"), "3");
+    assert_eq!(calculate("nderiv(sin(2x), x, 0)"), "2");
+    assert_eq!(calculate("root(3x - 3, x, 0, 2)"), "1");
+    assert_eq!(calculate("nderiv(3x^2, x, 1)"), "6");
+    assert_eq!(calculate("root(2x^2 - 2, x, 0, 3)"), "1");
+}
+
+#[test]
+fn test_a_variable_named_like_a_unit() {
+    assert_eq!(calculate("nderiv(2y, y, 1)"), "2");
+    assert_eq!(calculate("nderiv(3m^2, m, 2)"), "12");
+    assert_eq!(calculate("root(2h - 4, h, 0, 5)"), "2");
+    // Outside the call the unit is read as before
+    assert_eq!(calculate("2y"), "2 years");
+}
+
+#[test]
+fn test_nderiv_near_a_domain_boundary() {
+    let value: f64 = calculate("nderiv(sqrt(x), x, 0.01)").parse().unwrap();
+    assert!((value - 5.0).abs() < 1e-6, "{value}");
+}
+
+#[test]
+fn test_nderiv_needs_a_differentiable_point() {
+    for input in [
+        "nderiv(1/x, x, 0)",
+        "nderiv(1/(x-2), x, 2)",
+        "nderiv(sign(x), x, 0)",
+        "nderiv(floor(x), x, 1)",
+        "nderiv(abs(x), x, 0)",
+        "nderiv(abs(x - 1), x, 1)",
+    ] {
+        let result = Calculator::new().calculate_internal(input);
+        assert!(!result.success, "{input}: {}", result.result);
+    }
+    let error = Calculator::new()
+        .calculate_internal("nderiv(abs(x), x, 0)")
+        .error
+        .unwrap();
+    assert!(error.contains("not differentiable at 0"), "{error}");
+    assert_eq!(calculate("nderiv(abs(x), x, 1)"), "1");
+}
+
+#[test]
+fn test_root() {
+    assert_eq!(calculate("root(x - 3, x, 0, 10)"), "3");
+    let value: f64 = calculate("root(x^2 - 2, x, 0, 2)").parse().unwrap();
+    assert!((value - std::f64::consts::SQRT_2).abs() < 1e-11, "{value}");
+    let value: f64 = calculate("root(sin(x), x, 3, 4)").parse().unwrap();
+    assert!((value - std::f64::consts::PI).abs() < 1e-11, "{value}");
+}
+
+#[test]
+fn test_root_must_be_bracketed() {
+    let result = Calculator::new().calculate_internal("root(x^2 + 1, x, -1, 1)");
+    assert!(!result.success);
+    let error = result.error.unwrap();
+    assert!(
+        error.contains("no root is bracketed between -1 and 1"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_root_is_not_a_pole() {
+    let result = Calculator::new().calculate_internal("root(tan(x), x, 1, 2)");
+    assert!(!result.success, "{}", result.result);
+    let error = result.error.unwrap();
+    assert!(error.contains("no root between 1 and 2"), "{error}");
+
+    let result = Calculator::new().calculate_internal("root(1/x, x, -1, 1)");
+    assert!(!result.success, "{}", result.result);
+    let error = result.error.unwrap();
+    assert!(error.contains("pole or discontinuity at 0"), "{error}");
+}
+
+#[test]
+fn test_root_tolerance_and_iterations_are_configurable() {
+    let mut calculator = Calculator::new();
+    calculator.set_root_tolerance(1e-3);
+    let value: f64 = calculator
+        .calculate_internal("root(cos(x) - x, x, 0, 1)")
+        .result
+        .parse()
+        .unwrap();
+    assert!((value - 0.739).abs() < 1e-3, "{value}");
+
+    calculator.set_root_tolerance(0.0);
+    calculator.set_max_root_iterations(2);
+    let result = calculator.calculate_internal("root(cos(x) - x, x, 0, 1)");
+    assert_eq!(result.error_info.unwrap().key, "errors.limitExceeded");
+}
+
+#[test]
+fn test_argument_errors() {
+    let calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("nderiv(x^3, x)").success);
+    assert!(!calculator.calculate_internal("root(x, 2, 0, 1)").success);
+    assert!(
+        !calculator
+            .calculate_internal("integrate(nderiv(x^2, x, 1), x, 0, 1)")
+            .success
+    );
+}
+
+#[test]
+fn test_points_past_a_decimal_are_out_of_range() {
+    let point = error("nderiv(x^2, x, 10^30)");
+    assert!(point.contains("point is too large"), "{point}");
+    let bound = error("root(x, x, -10^30, 1)");
+    assert!(bound.contains("lower bound is too large"), "{bound}");
+}
+
+#[test]
+fn test_steps_show_the_call() {
+    let result = Calculator::new().calculate_internal("root(x - 3, x, 0, 10)");
+    assert!(
+        result
+            .steps
+            .iter()
+            .any(|step| step == "Call function: root(x - 3, x, 0, 10)"),
+        "{:?}",
+        result.steps
+    );
+}
@@ -0,0 +1,59 @@
+//! Tests for the exchange rates embedded with the `offline-rates` feature.
+
+#![cfg(feature = "offline-rates")]
+
+use link_calculator::offline_rates::BUNDLED_RATES;
+use link_calculator::Calculator;
+
+fn offline_calculator() -> Calculator {
+    let calculator = Calculator::new();
+    assert!(calculator.load_bundled_rates() > 1000);
+    calculator
+}
+
+#[test]
+fn test_every_bundled_file_has_rates() {
+    for (from, to, content) in BUNDLED_RATES {
+        assert!(
+            Calculator::new().load_rates_from_consolidated_lino(content) > 0,
+            "{from}/{to}"
+        );
+    }
+}
+
+#[test]
+fn test_bundled_rates_answer_historical_conversions() {
+    let result = offline_calculator().calculate_internal("(100 USD in EUR) at 2 Jan 2025");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "96.89 EUR");
+}
+
+#[test]
+fn test_bundled_rates_replace_the_hard_coded_current_rates() {
+    let result = offline_calculator().calculate_internal("100 USD in EUR");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.rates_used.len(), 1);
+    assert_eq!(result.rates_used[0].source, "frankfurter.dev (ECB)");
+}
+
+#[test]
+fn test_bundled_rates_keep_rates_from_other_sources() {
+    let calculator = Calculator::new();
+    calculator.update_rates_from_api("USD", "2026-10-01", r#"{"EUR": 0.5}"#);
+    calculator.load_bundled_rates();
+    assert_eq!(
+        calculator.calculate_internal("100 USD in EUR").result,
+        "50 EUR"
+    );
+}
+
+#[test]
+fn test_bundled_rates_load_into_a_shared_store() {
+    let calculator = Calculator::new();
+    let other = calculator.share_rates();
+    other.update_rates_from_api("USD", "2026-10-01", r#"{"EUR": 0.5}"#);
+    assert!(calculator.load_bundled_rates() > 1000);
+    assert_eq!(other.calculate_internal("100 USD in EUR").result, "50 EUR");
+    let result = other.calculate_internal("100 GBP in USD");
+    assert_eq!(result.rates_used[0].source, "frankfurter.dev (ECB)");
+}
@@ -0,0 +1,90 @@
+//! Tests for operator precedence and associativity, in full expressions and
+//! in integrands.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+fn interpretation(input: &str) -> String {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.lino_interpretation
+}
+
+#[test]
+fn test_operators_bind_by_precedence() {
+    assert_eq!(interpretation("1 + 2 * 3"), "(1 + (2 * 3))");
+    assert_eq!(
+        interpretation("2 * 3 + 4 * 5 ^ 2"),
+        "((2 * 3) + (4 * (5 ^ 2)))"
+    );
+    assert_eq!(interpretation("7 % 2 ^ 2"), "(7 % (2 ^ 2))");
+    assert_eq!(interpretation("5 plus 3 times 2"), "(5 + (3 * 2))");
+}
+
+#[test]
+fn test_powers_are_right_associative_and_the_rest_left() {
+    assert_eq!(interpretation("2^3^2"), "(2 ^ (3 ^ 2))");
+    assert_eq!(interpretation("1 - 2 - 3"), "((1 - 2) - 3)");
+    assert_eq!(interpretation("8 / 4 / 2"), "((8 / 4) / 2)");
+    assert_eq!(interpretation("10 divided by 2 minus 1"), "((10 / 2) - 1)");
+}
+
+#[test]
+fn test_prefix_and_postfix_operators_bind_to_their_operand() {
+    assert_eq!(interpretation("-2^2"), "(-(2 ^ 2))");
+    assert_eq!(interpretation("(-2)^2"), "((-2) ^ 2)");
+    assert_eq!(interpretation("2^-3"), "(2 ^ (-3))");
+    assert_eq!(interpretation("-3!"), "(-(factorial (3)))");
+    assert_eq!(interpretation("2^3!"), "(2 ^ (factorial (3)))");
+    assert_eq!(interpretation("50% of 200"), "((50 / 100) * 200)");
+}
+
+#[test]
+fn test_integrands_use_the_same_operators_with_implicit_multiplication() {
+    assert_eq!(
+        interpretation("integrate 2x + (x-1)(x+1) dx"),
+        "(integrate (((2 * x) + ((x - 1) * (x + 1))) * (differential of (x))))"
+    );
+    assert_eq!(
+        interpretation("integrate x plus 1 dx"),
+        "(integrate ((x + 1) * (differential of (x))))"
+    );
+}
+
+#[test]
+fn test_an_integrand_cannot_end_with_an_operator() {
+    for input in ["integrate x + dx", "integrate x^ dx"] {
+        let result = Calculator::new().calculate_internal(input);
+        assert!(!result.success, "{input}");
+    }
+}
+
+#[test]
+fn test_a_leading_minus_applies_after_the_power() {
+    assert_eq!(calculate("-2^2"), "-4");
+    assert_eq!(calculate("(-2)^2"), "4");
+    assert_eq!(calculate("2 - 3^2"), "-7");
+    let integral: f64 = calculate("integrate(exp(-x^2), x, -3, 3)").parse().unwrap();
+    assert!((integral - 1.772_414_696_519_04).abs() < 1e-9, "{integral}");
+}
+
+#[test]
+fn test_implicit_multiplication_binds_like_a_product_everywhere() {
+    for input in [
+        "integrate(3x^2, x, 0, 1)",
+        "integrate 3x^2 dx",
+        "nderiv(3x^2, x, 1)",
+        "plot 3x^2 from 0 to 1",
+        "is 3x^2 equivalent to 3*x^2",
+    ] {
+        assert!(
+            interpretation(input).contains("(3 * (x ^ 2))"),
+            "{input}: {}",
+            interpretation(input)
+        );
+    }
+    assert_eq!(calculate("integrate(3x^2, x, 0, 1)"), "1");
+    assert_eq!(calculate("integrate(2x + 1, x, 0, 1)"), "2");
+}
@@ -0,0 +1,74 @@
+//! Tests for choosing the currency of mixed-currency results, with
+//! `... in EUR` or a default output currency on the calculator.
+
+mod common;
+
+use common::calculator_with_api_rates;
+
+const USD_EUR_RESPONSES: &[(&str, &str)] = &[("USD", r#"{"EUR": 0.5}"#), ("EUR", r#"{"USD": 2}"#)];
+
+#[test]
+fn test_mixed_currencies_use_the_left_currency_by_default() {
+    let result = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES)
+        .calculate_internal("84 USD - 34 EUR");
+    assert_eq!(result.result, "16 USD");
+}
+
+#[test]
+fn test_a_conversion_converts_each_operand_to_its_currency() {
+    let result = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES)
+        .calculate_internal("(84 USD - 34 EUR) in EUR");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "8 EUR");
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Exchange rate: 1 USD = 0.5 EUR")));
+}
+
+#[test]
+fn test_output_currency_sets_the_currency_of_results() {
+    let mut calculator = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES);
+    assert!(calculator.set_output_currency("euros"));
+
+    let result = calculator.calculate_internal("84 USD - 34 EUR");
+    assert_eq!(result.result, "8 EUR");
+    let result = calculator.calculate_internal("10 USD");
+    assert_eq!(result.result, "5 EUR");
+    assert!(
+        result
+            .steps
+            .iter()
+            .any(|step| step == "Convert: 10 USD to EUR"),
+        "{:?}",
+        result.steps
+    );
+}
+
+#[test]
+fn test_an_explicit_conversion_wins_over_the_output_currency() {
+    let mut calculator = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES);
+    assert!(calculator.set_output_currency("EUR"));
+    assert_eq!(
+        calculator
+            .calculate_internal("84 USD - 34 EUR in USD")
+            .result,
+        "16 USD"
+    );
+}
+
+#[test]
+fn test_output_currency_leaves_other_results_alone() {
+    let mut calculator = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES);
+    assert!(calculator.set_output_currency("EUR"));
+    assert_eq!(calculator.calculate_internal("2 + 3").result, "5");
+}
+
+#[test]
+fn test_output_currency_can_be_cleared() {
+    let mut calculator = calculator_with_api_rates("2026-01-01", USD_EUR_RESPONSES);
+    assert!(!calculator.set_output_currency("not money"));
+    assert!(calculator.set_output_currency("EUR"));
+    calculator.clear_output_currency();
+    assert_eq!(calculator.calculate_internal("10 USD").result, "10 USD");
+}
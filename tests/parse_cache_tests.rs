@@ -0,0 +1,57 @@
+//! Tests for the cache of parsed inputs behind live re-evaluation.
+
+use link_calculator::Calculator;
+use serde_json::Value;
+
+fn stats(calculator: &Calculator) -> Value {
+    serde_json::from_str(&calculator.parse_cache_stats()).unwrap()
+}
+
+#[test]
+fn test_repeated_calculations_reuse_the_parse() {
+    let calculator = Calculator::new();
+    let first = calculator.calculate_internal("2 * (3 + 4)");
+    let misses = stats(&calculator)["misses"].clone();
+    let second = calculator.calculate_internal("2 * (3 + 4)");
+    assert_eq!(first.result, second.result);
+    assert_eq!(stats(&calculator)["misses"], misses);
+    assert!(stats(&calculator)["hits"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn test_cached_parses_follow_the_number_locale() {
+    let mut calculator = Calculator::new();
+    assert_eq!(calculator.calculate_internal("1.234 * 2").result, "2.468");
+    calculator.set_number_locale("de");
+    assert_eq!(calculator.calculate_internal("1.234 * 2").result, "2468");
+    calculator.clear_number_locale();
+    assert_eq!(calculator.calculate_internal("1.234 * 2").result, "2.468");
+}
+
+#[test]
+fn test_cached_parses_follow_the_depth_limit() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.calculate_internal("((((1))))").success);
+    calculator.set_max_depth(2);
+    assert!(!calculator.calculate_internal("((((1))))").success);
+}
+
+#[test]
+fn test_history_references_are_not_frozen() {
+    let calculator = Calculator::new();
+    calculator.calculate_internal("2");
+    assert_eq!(calculator.calculate_internal("ans + 1").result, "3");
+    assert_eq!(calculator.calculate_internal("ans + 1").result, "4");
+}
+
+#[test]
+fn test_clearing_resets_statistics() {
+    let mut calculator = Calculator::new();
+    calculator.calculate_internal("1 + 1");
+    calculator.set_parse_cache_capacity(10);
+    calculator.clear_parse_cache();
+    let stats = stats(&calculator);
+    assert_eq!(stats["entries"], 0);
+    assert_eq!(stats["misses"], 0);
+    assert_eq!(stats["capacity"], 10);
+}
@@ -0,0 +1,86 @@
+//! Property tests for the parser: untrusted input never panics, generated
+//! expressions read back as themselves, and ISO dates are never split into
+//! subtractions.
+
+use link_calculator::error::CalculatorError;
+use link_calculator::grammar::ExpressionParser;
+use link_calculator::testing;
+use link_calculator::types::{BinaryOp, Expression};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_arbitrary_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = ExpressionParser::new().parse_untrusted(&bytes);
+    }
+
+    #[test]
+    fn test_calculator_like_input_never_panics(input in testing::input()) {
+        let _ = ExpressionParser::new().parse_untrusted(input.as_bytes());
+    }
+
+    #[test]
+    fn test_printed_expressions_parse_back(expr in testing::expression()) {
+        let printed = expr.to_string();
+        let parsed = ExpressionParser::new().parse(&printed);
+        prop_assert!(parsed.is_ok(), "{printed}: {parsed:?}");
+        prop_assert_eq!(parsed.unwrap().to_lino(), expr.to_lino(), "{}", printed);
+    }
+
+    #[test]
+    fn test_iso_dates_are_single_literals(left in testing::iso_date(), right in testing::iso_date()) {
+        let parser = ExpressionParser::new();
+        let date = parser.parse(&left.to_string()).unwrap();
+        prop_assert!(matches!(date, Expression::DateTime(_)), "{left}: {date:?}");
+
+        let difference = parser.parse(&format!("{left} - {right}")).unwrap();
+        let is_date_difference = matches!(
+            &difference,
+            Expression::Binary { left, op: BinaryOp::Subtract, right }
+                if matches!(**left, Expression::DateTime(_))
+                    && matches!(**right, Expression::DateTime(_))
+        );
+        prop_assert!(is_date_difference, "{left} - {right}: {difference:?}");
+    }
+}
+
+#[test]
+fn test_untrusted_input_limits_are_structured_errors() {
+    let parser = ExpressionParser::new();
+    assert!(matches!(
+        parser.parse_untrusted(&[0xff, 0xfe]),
+        Err(CalculatorError::ParseError(_))
+    ));
+    assert!(matches!(
+        parser.parse_untrusted(b"   "),
+        Err(CalculatorError::EmptyInput)
+    ));
+    let long = "1+".repeat(10_000);
+    assert!(matches!(
+        parser.parse_untrusted(long.as_bytes()),
+        Err(CalculatorError::LimitExceeded(_))
+    ));
+    assert!(parser.parse_untrusted(b"2 * (3 + 4)").is_ok());
+}
+
+#[test]
+fn test_deep_nesting_is_a_structured_error() {
+    // The depth limit is sized for the main thread's stack in debug builds
+    let worker = std::thread::Builder::new().stack_size(8 << 20);
+    let worker = worker.spawn(|| {
+        let parser = ExpressionParser::new();
+        let chains = [
+            "(".repeat(5_000) + "1",
+            "-".repeat(5_000) + "1",
+            "2".to_string() + &"^2".repeat(3_000),
+            "until ".repeat(1_500) + "now",
+        ];
+        chains.iter().all(|chain| {
+            matches!(
+                parser.parse_untrusted(chain.as_bytes()),
+                Err(CalculatorError::LimitExceeded(_))
+            )
+        })
+    });
+    assert!(worker.unwrap().join().unwrap());
+}
@@ -0,0 +1,72 @@
+//! Tests for best-effort partial results when part of an expression fails.
+
+use link_calculator::Calculator;
+
+fn unresolved(result: &link_calculator::CalculationResult) -> Vec<&str> {
+    result
+        .unresolved
+        .as_ref()
+        .expect("unresolved subexpressions should be listed")
+        .iter()
+        .map(|item| item.expression.as_str())
+        .collect()
+}
+
+#[test]
+fn test_missing_rate_keeps_the_rest() {
+    let result = Calculator::new().calculate_internal("(84 USD + 10 ABC) * 2 + (3 + 4)");
+    assert!(!result.success);
+    assert_eq!(
+        result.partial_result.as_deref(),
+        Some("(84 USD + 10 ABC) * 2 + 7")
+    );
+    assert_eq!(unresolved(&result), ["84 USD + 10 ABC"]);
+    let item = &result.unresolved.as_ref().unwrap()[0];
+    assert_eq!(item.error_info.key, "errors.currencyConversion");
+}
+
+#[test]
+fn test_smallest_failing_subexpression_is_reported() {
+    let result = Calculator::new().calculate_internal("(2 + 3) * (1 / 0)");
+    assert_eq!(result.partial_result.as_deref(), Some("5 * (1 / 0)"));
+    assert_eq!(unresolved(&result), ["1 / 0"]);
+}
+
+#[test]
+fn test_every_failing_item_is_listed() {
+    let result = Calculator::new().calculate_internal("[1 + 1, 1/0, sqrt(-4)]");
+    assert_eq!(
+        result.partial_result.as_deref(),
+        Some("[2, 1 / 0, sqrt(-4)]")
+    );
+    assert_eq!(unresolved(&result), ["1 / 0", "sqrt(-4)"]);
+}
+
+#[test]
+fn test_integrands_are_not_split() {
+    let result = Calculator::new().calculate_internal("integrate(x/0, x, 0, 1) + 2*3");
+    assert_eq!(
+        result.partial_result.as_deref(),
+        Some("integrate(x / 0, x, 0, 1) + 6")
+    );
+    assert_eq!(unresolved(&result), ["integrate(x / 0, x, 0, 1)"]);
+}
+
+#[test]
+fn test_no_partial_result_when_whole_expression_fails() {
+    for input in ["1 / 0", "84 USD + 10 ABC"] {
+        let result = Calculator::new().calculate_internal(input);
+        assert!(!result.success);
+        assert!(result.partial_result.is_none(), "{input}");
+        assert!(result.unresolved.is_none(), "{input}");
+    }
+}
+
+#[test]
+fn test_no_partial_result_on_success() {
+    let result = Calculator::new().calculate_internal("(2 + 3) * 4");
+    assert!(result.success);
+    assert!(result.partial_result.is_none());
+    let json = serde_json::to_value(&result).unwrap();
+    assert!(json.get("unresolved").is_none());
+}
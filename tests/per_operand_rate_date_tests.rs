@@ -0,0 +1,57 @@
+//! Tests for amounts of money that keep the date of their own `at` clause,
+//! so each operand of a sum is converted at its own date's rate.
+
+mod common;
+
+use common::{calculate_with, calculator_with_lino_rates};
+
+const USD_EUR_RATES: &str = "conversion:
+  from USD
+  to EUR
+  source 'test'
+  rates:
+    2025-01-01 0.90
+    2026-01-01 0.80";
+
+fn result_of(input: &str) -> String {
+    calculate_with(&calculator_with_lino_rates(USD_EUR_RATES), input)
+}
+
+#[test]
+fn test_each_operand_is_converted_at_its_own_date() {
+    assert_eq!(
+        result_of("(100 USD at 1 Jan 2025) + (100 USD at 1 Jan 2026) in EUR"),
+        "170 EUR"
+    );
+    assert_eq!(
+        result_of("(100 USD at 1 Jan 2025) - (100 USD at 1 Jan 2026) in EUR"),
+        "10 EUR"
+    );
+}
+
+#[test]
+fn test_a_pinned_amount_keeps_its_date_through_conversion() {
+    assert_eq!(result_of("(100 USD at 1 Jan 2025) in EUR"), "90 EUR");
+    assert_eq!(result_of("(100 USD at 1 Jan 2025) * 2 in EUR"), "180 EUR");
+}
+
+#[test]
+fn test_a_pinned_operand_is_converted_at_its_date_without_a_target() {
+    assert_eq!(result_of("100 EUR + (100 USD at 1 Jan 2026)"), "180 EUR");
+}
+
+#[test]
+fn test_operands_sharing_a_date_are_converted_once() {
+    assert_eq!(
+        result_of("((100 USD at 1 Jan 2025) + (50 USD at 1 Jan 2025)) in EUR"),
+        "135 EUR"
+    );
+}
+
+#[test]
+fn test_pinned_dates_win_over_an_outer_at_clause() {
+    assert_eq!(
+        result_of("((100 USD at 1 Jan 2025) + 100 USD in EUR) at 1 Jan 2026"),
+        "170 EUR"
+    );
+}
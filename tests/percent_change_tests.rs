@@ -0,0 +1,60 @@
+//! Tests for proportions written in words: `percent change from 80 to 100`,
+//! `100 is what percent of 250` and `ratio 3:4 of 140`.
+
+mod common;
+
+use common::{calculate, calculate_result};
+use link_calculator::Calculator;
+
+#[test]
+fn test_percent_change_explains_the_formula() {
+    let result = calculate_result("percent change from 80 to 100");
+    assert_eq!(result.result, "25%");
+    assert!(result
+        .steps
+        .contains(&"Percent change: (new - old) / old * 100".to_string()));
+    assert!(result
+        .steps
+        .contains(&"= (100 - 80) / 80 * 100".to_string()));
+}
+
+#[test]
+fn test_percent_change_can_fall_and_keeps_units() {
+    assert_eq!(calculate("percent change from 200 to 150"), "-25%");
+    assert_eq!(calculate("percentage change from 40 USD to 50 USD"), "25%");
+}
+
+#[test]
+fn test_percent_change_from_zero_is_an_error() {
+    let result = Calculator::new().calculate_internal("percent change from 0 to 5");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_what_percent_of_a_total() {
+    let result = calculate_result("100 is what percent of 250");
+    assert_eq!(result.result, "40%");
+    assert!(result
+        .steps
+        .contains(&"Percent of total: part / whole * 100".to_string()));
+    assert!(result.steps.contains(&"= 100 / 250 * 100".to_string()));
+    assert_eq!(calculate("30 EUR is what percent of 120 EUR"), "25%");
+}
+
+#[test]
+fn test_ratio_splits_the_total_into_shares() {
+    let result = calculate_result("ratio 3:4 of 140");
+    assert_eq!(result.result, "[60, 80]");
+    assert!(result
+        .steps
+        .contains(&"= [140 * 3 / 7, 140 * 4 / 7]".to_string()));
+    assert_eq!(
+        calculate("ratio 1:2:2 of 50 USD"),
+        "[10 USD, 20 USD, 20 USD]"
+    );
+}
+
+#[test]
+fn test_percent_of_still_multiplies() {
+    assert_eq!(calculate("8% of 50"), "4");
+}
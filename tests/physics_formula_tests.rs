@@ -0,0 +1,114 @@
+//! Tests for physics formulas solved for an unknown quantity:
+//! `voltage with current 2 A and resistance 10 ohm`.
+
+mod common;
+
+use common::{calculate_with_steps, error};
+
+#[test]
+fn test_ohms_law_solves_for_each_quantity() {
+    let (result, steps) = calculate_with_steps("voltage with current 2 A and resistance 10 ohm");
+    assert_eq!(result, "20 V");
+    assert!(steps.iter().any(|s| s == "Ohm's law: V = I * R"));
+    assert!(steps.iter().any(|s| s == "= 2 A * 10 Ω"));
+
+    let (result, steps) = calculate_with_steps("resistance with voltage 5 V and current 20 mA");
+    assert_eq!(result, "250 Ω");
+    assert!(steps.iter().any(|s| s == "Solve for R: R = V / I"));
+    assert!(steps.iter().any(|s| s == "= 5 V / 0.02 A"));
+
+    let (result, _) = calculate_with_steps("current with voltage 12 V and resistance 4 kohm");
+    assert_eq!(result, "3 mA");
+}
+
+#[test]
+fn test_power_and_newtons_second_law() {
+    assert_eq!(
+        calculate_with_steps("power with voltage 230 V, current 10 A").0,
+        "2.3 kW"
+    );
+    assert_eq!(
+        calculate_with_steps("force with mass 1000 kg and acceleration 9.8").0,
+        "9.8 kN"
+    );
+    assert_eq!(
+        calculate_with_steps("mass with force 10 N and acceleration 4").0,
+        "2.5 kg"
+    );
+    assert_eq!(
+        calculate_with_steps("acceleration with force 10 N and mass 500 g").0,
+        "20 m/s²"
+    );
+}
+
+#[test]
+fn test_mass_energy_uses_the_speed_of_light() {
+    let (result, steps) = calculate_with_steps("energy with mass 1 g");
+    assert_eq!(result, "89.875517873681764 TJ");
+    assert!(steps.iter().any(|s| s == "= 0.001 kg * (299792458 m/s)^2"));
+}
+
+#[test]
+fn test_function_form_matches_the_words() {
+    assert_eq!(
+        calculate_with_steps("physics(voltage, current, 2 A, resistance, 10 ohm)").0,
+        "20 V"
+    );
+}
+
+#[test]
+fn test_units_of_another_dimension_are_rejected() {
+    assert!(error("current with voltage 2 kg and resistance 5 ohm")
+        .contains("the voltage must be in V, not 2 kg"));
+    assert!(error("force with mass 2 A and acceleration 3").contains("mass"));
+}
+
+#[test]
+fn test_unrelated_or_incomplete_quantities_are_rejected() {
+    assert!(error("voltage with mass 2 kg").contains("no formula gives the voltage"));
+    assert!(error("resistance with voltage 5 V and current 0 A").contains("must not be zero"));
+    assert!(error("voltage with voltage 5 V").contains("given twice"));
+}
+
+#[test]
+fn test_prefixed_units_keep_their_case() {
+    assert_eq!(calculate_with_steps("20 mA").0, "20 mA");
+    assert_eq!(calculate_with_steps("5 kV").0, "5 kV");
+    assert_eq!(calculate_with_steps("10 ohm").0, "10 Ω");
+}
+
+#[test]
+fn test_multiplying_and_dividing_units_derives_the_third_quantity() {
+    assert_eq!(calculate_with_steps("2 A * 10 ohm").0, "20 V");
+    assert_eq!(calculate_with_steps("10 ohm * 2 A").0, "20 V");
+    assert_eq!(calculate_with_steps("10 V / 2 ohm").0, "5 A");
+    assert_eq!(calculate_with_steps("20 V / 2 A").0, "10 Ω");
+    assert_eq!(calculate_with_steps("2 mA * 3 kohm").0, "6 V");
+    assert_eq!(calculate_with_steps("230 V * 10 A").0, "2.3 kW");
+    assert_eq!(calculate_with_steps("2 kg * 3 m/s²").0, "6 N");
+    assert_eq!(calculate_with_steps("6 N / 2 kg").0, "3 m/s²");
+    assert!(error("10 V / 0 ohm").contains("Division by zero"));
+    assert_eq!(calculate_with_steps("2 A * 3 A").0, "6 A²");
+    assert!(error("2 kg * 3 ohm").contains("Unit mismatch"));
+}
+
+#[test]
+fn test_derived_units_follow_from_what_each_unit_is_made_of() {
+    assert_eq!(calculate_with_steps("10 J / 2 s").0, "5 W");
+    assert_eq!(calculate_with_steps("2 N * 3 m").0, "6 J");
+    assert_eq!(calculate_with_steps("10 W * 2 s").0, "20 J");
+    assert_eq!(calculate_with_steps("10 J / 2 W").0, "5 seconds");
+    assert_eq!(calculate_with_steps("6 J / 2 m").0, "3 N");
+    assert_eq!(calculate_with_steps("10 V / 5 V").0, "2");
+}
+
+#[test]
+fn test_powers_keep_the_unit() {
+    assert_eq!(calculate_with_steps("(3 V)^2").0, "9 V²");
+    assert_eq!(calculate_with_steps("(3 m)^2").0, "9 m²");
+    assert_eq!(calculate_with_steps("(3 V)^2 / 9 ohm").0, "1 W");
+    assert_eq!(calculate_with_steps("(2 A)^2 * 3 ohm").0, "12 W");
+    assert_eq!(calculate_with_steps("(9 m^2)^0.5").0, "3 m");
+    assert!(error("(5 USD)^2").contains("cannot raise USD to a power"));
+    assert!(error("(2 m)^0.5").contains("cannot raise m"));
+}
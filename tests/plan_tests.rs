@@ -199,7 +199,7 @@ fn plan_generates_alternatives() {
 
 #[test]
 fn plan_execute_consistency() {
-    let mut calc = Calculator::new();
+    let calc = Calculator::new();
     let plan = calc.plan_internal("2 + 3");
     assert!(plan.success);
 
@@ -0,0 +1,83 @@
+//! Tests for the `plot <expr> from <a> to <b>` command.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_plot_single_function() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot sin(x) from -10 to 10");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "sin(x) from -10 to 10");
+
+    let plots = result.plots.expect("plot command should return plots");
+    assert_eq!(plots.len(), 1);
+    let plot = &plots[0];
+    assert_eq!(plot.label, "sin(x)");
+    assert_eq!(plot.x_values.len(), 200);
+    assert_eq!(plot.x_values.first().copied(), Some(-10.0));
+    assert_eq!(plot.x_values.last().copied(), Some(10.0));
+
+    // The first series is also exposed as plot_data for single-plot consumers.
+    let plot_data = result.plot_data.expect("plot_data should be set");
+    assert_eq!(plot_data.label, "sin(x)");
+}
+
+#[test]
+fn test_plot_multiple_functions() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot sin(x), cos(x), x^2 from 0 to 2*pi");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+
+    let plots = result.plots.expect("plot command should return plots");
+    let labels: Vec<&str> = plots.iter().map(|p| p.label.as_str()).collect();
+    assert_eq!(labels, ["sin(x)", "cos(x)", "x^2"]);
+    assert!((plots[1].y_values[0] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_plot_sample_count() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot x^2 from -2 to 2 with 5 points");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+
+    let plot = &result.plots.expect("plot command should return plots")[0];
+    assert_eq!(plot.x_values, [-2.0, -1.0, 0.0, 1.0, 2.0]);
+    assert_eq!(plot.y_values, [4.0, 1.0, 0.0, 1.0, 4.0]);
+
+    let result = calc.calculate_internal("plot x from 0 to 1 with 11 samples");
+    assert_eq!(result.plots.map(|p| p[0].x_values.len()), Some(11));
+}
+
+#[test]
+fn test_plot_uses_the_functions_variable() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot t^3 from 0 to 1 with 3 points");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    let plot = &result.plots.expect("plot command should return plots")[0];
+    assert_eq!(plot.x_label, "t");
+    assert_eq!(plot.y_values, [0.0, 0.125, 1.0]);
+}
+
+#[test]
+fn test_plot_breaks_at_undefined_points() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot 1/x from -1 to 1 with 3 points");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    let plot = &result.plots.expect("plot command should return plots")[0];
+    assert_eq!(plot.x_values, [-1.0, 0.0, 1.0]);
+    assert!(plot.y_values[1].is_nan());
+}
+
+#[test]
+fn test_plot_errors() {
+    let calc = Calculator::new();
+    for input in [
+        "plot sin(x) from 0",
+        "plot sin(x) from 10 to 1",
+        "plot sin(x) from 0 to 1 with 1 points",
+        "plot sin(x) from 0 to 1 with 2.5 points",
+    ] {
+        let result = calc.calculate_internal(input);
+        assert!(!result.success, "{input} should fail");
+    }
+}
@@ -0,0 +1,112 @@
+//! Tests for automatic plot ranges and breaks in the curve at poles.
+
+use link_calculator::{Calculator, PlotData};
+
+fn indefinite_plot(input: &str) -> PlotData {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal(input);
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    result
+        .plot_data
+        .expect("indefinite integral should have plot data")
+}
+
+fn range(plot: &PlotData) -> (f64, f64) {
+    (plot.x_values[0], *plot.x_values.last().unwrap())
+}
+
+#[test]
+fn test_range_defaults_without_features() {
+    assert_eq!(
+        range(&indefinite_plot("integrate exp(x) dx")),
+        (-10.0, 10.0)
+    );
+}
+
+#[test]
+fn test_range_covers_distant_pole() {
+    let plot = indefinite_plot("integrate 1/(x - 50) dx");
+    let (lo, hi) = range(&plot);
+    assert!(lo < 0.0 && hi > 50.0, "range {lo}..{hi}");
+}
+
+#[test]
+fn test_range_covers_domain_edge() {
+    let plot = indefinite_plot("integrate ln(x) dx");
+    let (lo, hi) = range(&plot);
+    assert!(lo < 0.0 && hi > 1.0, "range {lo}..{hi}");
+    assert!(plot
+        .x_values
+        .iter()
+        .zip(&plot.y_values)
+        .all(|(x, y)| *x > 0.0 || y.is_nan()));
+}
+
+#[test]
+fn test_samples_land_on_poles() {
+    let plot = indefinite_plot("integrate 1/(x^2 - 4) dx");
+    for pole in [-2.0, 2.0] {
+        let index = plot
+            .x_values
+            .iter()
+            .position(|x| (x - pole).abs() < 1e-12)
+            .unwrap();
+        assert!(plot.y_values[index].is_nan());
+    }
+}
+
+#[test]
+fn test_curve_breaks_between_samples_around_pole() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot tan(x) from -3 to 3");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    let plot = &result.plots.expect("plot command should return plots")[0];
+    let breaks: Vec<f64> = plot
+        .x_values
+        .iter()
+        .zip(&plot.y_values)
+        .filter(|(_, y)| y.is_nan())
+        .map(|(x, _)| *x)
+        .collect();
+    assert_eq!(breaks.len(), 2, "breaks at {breaks:?}");
+    for (x, pole) in breaks.iter().zip([-1.0, 1.0]) {
+        assert!((x - pole * std::f64::consts::FRAC_PI_2).abs() < 0.02);
+    }
+}
+
+#[test]
+fn test_steep_functions_are_not_broken() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot exp(x), 1000*x from -10 to 10");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    for plot in result.plots.expect("plot command should return plots") {
+        assert!(plot.y_values.iter().all(|y| !y.is_nan()), "{}", plot.label);
+    }
+}
+
+#[test]
+fn test_breaks_serialize_as_null() {
+    let plot = indefinite_plot("integrate 1/x dx");
+    let json = serde_json::to_string(&plot).unwrap();
+    assert!(json.contains("null"));
+    let parsed: PlotData = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.y_values.len(), plot.y_values.len());
+    assert!(parsed.y_values.iter().any(|y| y.is_nan()));
+}
+
+#[test]
+fn test_plot_without_range_picks_one() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("plot 1/x");
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "1 / x from -5 to 5");
+    let plots = result.plots.expect("plot command should return plots");
+    assert_eq!(range(&plots[0]), (-5.0, 5.0));
+    assert!(plots[0].y_values.iter().any(|y| y.is_nan()));
+
+    let result = calc.calculate_internal("plot sin(x), 1/(x - 20) with 50 points");
+    let plots = result.plots.expect("plot command should return plots");
+    let (lo, hi) = range(&plots[1]);
+    assert!(lo < 0.0 && hi > 20.0, "range {lo}..{hi}");
+    assert_eq!(range(&plots[0]), (lo, hi));
+}
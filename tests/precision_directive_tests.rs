@@ -0,0 +1,108 @@
+//! Tests for precision directives (`to 2 dp`, `round to 3 sf`) and
+//! `round(value, places)`.
+
+use link_calculator::Calculator;
+
+fn result(calculator: &Calculator, input: &str) -> String {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{input} failed: {:?}", result.error);
+    result.result
+}
+
+#[test]
+fn test_decimal_places_directive() {
+    let calculator = Calculator::new();
+    assert_eq!(result(&calculator, "1/3 to 2 dp"), "0.33");
+    assert_eq!(result(&calculator, "1/3 = to 2 dp"), "0.33");
+    assert_eq!(
+        result(&calculator, "pi rounded to 4 decimal places"),
+        "3.1416"
+    );
+}
+
+#[test]
+fn test_precision_beyond_sixteen_digits_is_exact() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        result(&calculator, "1/3 to 20 dp"),
+        "0.33333333333333333333"
+    );
+    assert_eq!(
+        result(&calculator, "1/7 to 30 dp"),
+        "0.142857142857142857142857142857"
+    );
+    assert_eq!(
+        result(&calculator, "2/3 to 20 sf"),
+        "0.66666666666666666667"
+    );
+}
+
+#[test]
+fn test_directive_keeps_trailing_zeros() {
+    let calculator = Calculator::new();
+    assert_eq!(result(&calculator, "1/2 to 2 dp"), "0.50");
+    assert_eq!(result(&calculator, "5 kg to 1 dp"), "5.0 kg");
+}
+
+#[test]
+fn test_significant_figures_directive() {
+    let calculator = Calculator::new();
+    assert_eq!(result(&calculator, "2/3 round to 3 sf"), "0.667");
+    assert_eq!(result(&calculator, "1/2 to 3 significant figures"), "0.500");
+    assert_eq!(result(&calculator, "12345.678 to 2 sf"), "12000");
+}
+
+#[test]
+fn test_directive_after_currency_conversion() {
+    let calculator = Calculator::new();
+    let converted = result(&calculator, "100 USD in EUR to 2 dp");
+    let amount = converted.strip_suffix(" EUR").unwrap();
+    assert_eq!(
+        amount.split('.').nth(1).map(str::len),
+        Some(2),
+        "{converted}"
+    );
+}
+
+#[test]
+fn test_directive_keeps_the_exact_value() {
+    let calculator = Calculator::new();
+    let first = calculator.calculate_internal("1/3 to 2 dp");
+    assert_eq!(first.result, "0.33");
+    assert_eq!(first.lino_interpretation, "((1 / 3) to 2 dp)");
+    assert_eq!(result(&calculator, "ans * 3"), "1");
+}
+
+#[test]
+fn test_round_to_places() {
+    let calculator = Calculator::new();
+    assert_eq!(result(&calculator, "round(3.14159, 2)"), "3.14");
+    assert_eq!(result(&calculator, "round(2.675, 2)"), "2.68");
+    assert_eq!(result(&calculator, "round(1234.5, -2)"), "1200");
+    assert_eq!(result(&calculator, "round(2.5)"), "3");
+    assert!(!calculator.calculate_internal("round(1, 0.5)").success);
+}
+
+#[test]
+fn test_out_of_range_directive_is_an_error() {
+    let calculator = Calculator::new();
+    for input in [
+        "1/3 to 999999 dp",
+        "1/3 to 101 dp",
+        "1/3 to 0 sf",
+        "1/3 to 500 sf",
+    ] {
+        let result = calculator.calculate_internal(input);
+        assert!(!result.success, "{input} gave {}", result.result);
+        assert!(
+            result
+                .error
+                .as_deref()
+                .unwrap_or_default()
+                .contains("Precision"),
+            "{input}: {:?}",
+            result.error
+        );
+    }
+    assert_eq!(result(&calculator, "1/3 to 0 dp"), "0");
+}
@@ -0,0 +1,64 @@
+//! Tests for progress callbacks on long-running calculations.
+
+use std::sync::{Arc, Mutex};
+
+use link_calculator::types::Progress;
+use link_calculator::Calculator;
+
+fn record(calculator: &mut Calculator, input: &str) -> (bool, Vec<Progress>) {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reports);
+    let result = calculator.calculate_with_progress_callback(input, move |progress| {
+        sink.lock().unwrap().push(progress.clone());
+        true
+    });
+    let reports = reports.lock().unwrap().clone();
+    (result.success, reports)
+}
+
+#[test]
+fn test_integration_reports_progress() {
+    let (success, reports) = record(&mut Calculator::new(), "integrate(x^2, x, 0, 3)");
+    assert!(success);
+    let integration: Vec<_> = reports
+        .iter()
+        .filter(|p| p.stage == "integration")
+        .collect();
+    assert!(integration.len() <= 101, "{} reports", integration.len());
+    assert!(integration.windows(2).all(|w| w[0].done < w[1].done));
+    let last = integration.last().unwrap();
+    assert_eq!(last.done, last.total);
+}
+
+#[test]
+fn test_plot_reports_progress() {
+    let (success, reports) = record(&mut Calculator::new(), "plot(x^2, 0, 1, 5)");
+    assert!(success);
+    let done: Vec<_> = reports.iter().map(|p| p.done).collect();
+    assert_eq!(done, vec![1, 2, 3, 4, 5]);
+    assert!(reports.iter().all(|p| p.stage == "plot" && p.total == 5));
+}
+
+#[test]
+fn test_no_progress_without_long_running_work() {
+    let (success, reports) = record(&mut Calculator::new(), "2 + 3");
+    assert!(success);
+    assert!(reports.is_empty());
+}
+
+#[test]
+fn test_returning_false_cancels() {
+    let mut calculator = Calculator::new();
+    let result = calculator
+        .calculate_with_progress_callback("integrate(sin(x), x, 0, 3)", |p| p.fraction() < 0.5);
+    assert!(!result.success);
+    assert_eq!(result.error.as_deref(), Some("Calculation cancelled"));
+    assert_eq!(result.error_info.unwrap().key, "errors.cancelled");
+
+    // The callback only applies to that calculation
+    assert!(
+        calculator
+            .calculate_internal("integrate(sin(x), x, 0, 3)")
+            .success
+    );
+}
@@ -0,0 +1,83 @@
+//! Tests for hexadecimal, binary and octal literals and base conversions.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_hex_plus_binary_literal() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("0xFF + 0b1010");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "265");
+}
+
+#[test]
+fn test_octal_literal() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("0o17 * 2");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "30");
+}
+
+#[test]
+fn test_decimal_in_hex() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("255 in hex");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "0xFF");
+    assert_eq!(result.lino_interpretation, "(255 as hex)");
+}
+
+#[test]
+fn test_decimal_in_binary_and_octal() {
+    let calc = Calculator::new();
+    assert_eq!(calc.calculate_internal("10 in binary").result, "0b1010");
+    assert_eq!(calc.calculate_internal("8 as octal").result, "0o10");
+}
+
+#[test]
+fn test_hex_in_decimal() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("0x1F in decimal");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.result, "31");
+}
+
+#[test]
+fn test_integer_result_lists_other_bases() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("0x1F");
+
+    let bases = result.bases.expect("integer result should list bases");
+    assert_eq!(bases.binary, "0b11111");
+    assert_eq!(bases.octal, "0o37");
+    assert_eq!(bases.decimal, "31");
+    assert_eq!(bases.hexadecimal, "0x1F");
+}
+
+#[test]
+fn test_non_integer_results_have_no_bases() {
+    let calc = Calculator::new();
+    assert!(calc.calculate_internal("1 / 3").bases.is_none());
+    assert!(calc.calculate_internal("5 USD").bases.is_none());
+}
+
+#[test]
+fn test_fraction_in_hex_is_an_error() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("1.5 in hex");
+
+    assert!(!result.success);
+}
+
+#[test]
+fn test_zero_bits_still_parses_as_data_size() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("0b");
+
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert!(result.bases.is_none());
+}
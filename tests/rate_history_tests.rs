@@ -0,0 +1,94 @@
+//! Tests for exchange rates over a range of dates, such as
+//! `USD/GBP from 1 Jan 2026 to 31 Jan 2026`.
+
+mod common;
+
+use common::calculator_with_lino_rates;
+use link_calculator::RepresentationKind;
+
+const USD_GBP_RATES: &str = "conversion:
+  from USD
+  to GBP
+  source 'test'
+  rates:
+    2025-12-31 0.70
+    2026-01-02 0.80
+    2026-01-15 0.90
+    2026-01-30 0.85
+    2026-02-02 0.95";
+
+#[test]
+fn test_rate_range_returns_the_average_with_min_and_max_steps() {
+    let result = calculator_with_lino_rates(USD_GBP_RATES)
+        .calculate_internal("USD/GBP from 1 Jan 2026 to 31 Jan 2026");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "0.85");
+    assert!(result
+        .steps
+        .contains(&"USD/GBP rates: 3 from 2026-01-02 to 2026-01-30".to_string()));
+    assert!(result
+        .steps
+        .contains(&"Minimum: 0.8 (2026-01-02)".to_string()));
+    assert!(result
+        .steps
+        .contains(&"Maximum: 0.9 (2026-01-15)".to_string()));
+    assert!(result.steps.contains(&"Average: 0.85".to_string()));
+}
+
+#[test]
+fn test_rate_range_includes_a_time_series_plot() {
+    let result = calculator_with_lino_rates(USD_GBP_RATES)
+        .calculate_internal("USD/GBP rate from 1 Jan 2026 to 31 Jan 2026");
+    let plot = result.plot_data.expect("plot data");
+    assert_eq!(plot.label, "USD/GBP");
+    assert_eq!(plot.y_values, vec![0.8, 0.9, 0.85]);
+    // 2 Jan 2026, 00:00 UTC
+    assert!((plot.x_values[0] - 1_767_312_000.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_rate_range_without_known_rates_fails() {
+    let result = calculator_with_lino_rates(USD_GBP_RATES)
+        .calculate_internal("USD/GBP from 1 Mar 2026 to 31 Mar 2026");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_rate_range_must_not_end_before_it_starts() {
+    let result = calculator_with_lino_rates(USD_GBP_RATES)
+        .calculate_internal("USD/GBP from 31 Jan 2026 to 1 Jan 2026");
+    assert!(!result.success);
+}
+
+#[test]
+fn test_rate_lookups_still_parse() {
+    let result =
+        calculator_with_lino_rates(USD_GBP_RATES).calculate_internal("USD/GBP rate at 15 Jan 2026");
+    assert_eq!(result.result, "0.9");
+}
+
+#[test]
+fn test_rate_range_average_is_exact() {
+    let calculator = calculator_with_lino_rates(
+        "conversion:
+  from USD
+  to EUR
+  source 'test'
+  rates:
+    2026-03-02 0.92
+    2026-03-03 0.924
+    2026-03-04 0.92",
+    );
+    let result = calculator.calculate_internal("USD/EUR from 1 Mar 2026 to 31 Mar 2026");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "0.9213333333333333");
+    assert!(result
+        .steps
+        .contains(&"Average: 0.9213333333333333".to_string()));
+    let fraction = result
+        .alternatives
+        .iter()
+        .find(|representation| representation.kind == RepresentationKind::Fraction)
+        .map(|representation| representation.value.as_str());
+    assert_eq!(fraction, Some("691/750"));
+}
@@ -0,0 +1,74 @@
+//! Tests for standalone exchange rate lookups (`USD/EUR rate`, `EUR to USD rate`).
+
+use link_calculator::types::Expression;
+use link_calculator::Calculator;
+
+fn rate(calculator: &Calculator, input: &str) -> f64 {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{input} failed: {:?}", result.error);
+    result
+        .result
+        .parse()
+        .expect("rate should be a plain number")
+}
+
+#[test]
+fn test_slash_and_to_forms() {
+    let calculator = Calculator::new();
+    let usd_eur = rate(&calculator, "USD/EUR rate");
+    let eur_usd = rate(&calculator, "EUR to USD rate");
+    assert!(usd_eur.mul_add(eur_usd, -1.0).abs() < 1e-9);
+    assert!((rate(&calculator, "EUR in USD exchange rate") - eur_usd).abs() < 1e-12);
+}
+
+#[test]
+fn test_rate_at_date_reports_source_and_date() {
+    let calculator = Calculator::new();
+    let lino_content = "conversion:
+  from USD
+  to EUR
+  source 'frankfurter.dev (ECB)'
+  rates:
+    2021-01-25 0.8234
+    2021-02-08 0.8402";
+    assert!(calculator.load_rates_from_consolidated_lino(lino_content) > 0);
+
+    let result = calculator.calculate_internal("USD/EUR rate at Jan 25, 2021");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "0.8234");
+    assert_eq!(
+        result.lino_interpretation,
+        "((USD/EUR rate) at (2021-01-25))"
+    );
+    let rate_step = result
+        .steps
+        .iter()
+        .find(|step| step.starts_with("Exchange rate:"))
+        .expect("steps should show the rate used");
+    assert!(rate_step.contains("frankfurter.dev"), "{rate_step}");
+    assert!(rate_step.contains("2021-01-25"), "{rate_step}");
+
+    let later = calculator.calculate_internal("USD/EUR rate at 8 Feb 2021");
+    assert_eq!(later.result, "0.8402");
+}
+
+#[test]
+fn test_rate_in_arithmetic() {
+    let calculator = Calculator::new();
+    let usd_eur = rate(&calculator, "USD/EUR rate");
+    let scaled = rate(&calculator, "USD/EUR rate * 100");
+    assert!(usd_eur.mul_add(-100.0, scaled).abs() < 1e-9);
+}
+
+#[test]
+fn test_unknown_rate_fails() {
+    let calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("USD/ABC rate").success);
+}
+
+#[test]
+fn test_rate_lookup_lino_round_trip() {
+    let expr = Expression::from_lino("(USD/EUR rate)").unwrap();
+    assert_eq!(expr, Expression::rate_lookup("USD", "EUR"));
+    assert_eq!(expr.collect_currencies().len(), 2);
+}
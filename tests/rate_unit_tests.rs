@@ -0,0 +1,73 @@
+//! Tests for lengths, volumes and compound rate units such as `km/h`.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_speed_from_distance_and_time() {
+    assert_eq!(calculate("120 km / 2 hours"), "60 km/h");
+    assert_eq!(calculate("100 MB / 10 s"), "10 MB/s");
+}
+
+#[test]
+fn test_pace() {
+    assert_eq!(calculate("5 km in 25 minutes"), "5 min/km");
+    assert_eq!(calculate("25 minutes / 5 km"), "5 min/km");
+}
+
+#[test]
+fn test_speed_conversion() {
+    assert_eq!(calculate("60 mph in km/h"), "96.56064 km/h");
+    assert_eq!(calculate("36 km/h as m/s"), "10 m/s");
+}
+
+#[test]
+fn test_fuel_economy() {
+    assert_eq!(calculate("300 mi / 10 gal"), "30 mi/gal");
+    assert_eq!(calculate("300 mi / 10 gal"), calculate("30 mpg"));
+}
+
+#[test]
+fn test_rate_times_duration() {
+    assert_eq!(calculate("60 km/h * 30 minutes"), "30 km");
+    assert_eq!(calculate("120 km / 60 km/h"), "2 hours");
+}
+
+#[test]
+fn test_mixed_lengths() {
+    assert_eq!(calculate("1 km + 500 m"), "1.5 km");
+    assert_eq!(calculate("5 km / 500 m"), "10");
+    assert_eq!(calculate("3 ft in inch"), "36 inch");
+}
+
+#[test]
+fn test_price_per_unit() {
+    assert_eq!(calculate("10 USD / 2 kg"), "5 USD/kg");
+}
+
+#[test]
+fn test_incompatible_conversion() {
+    let calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("60 km/h in kg").success);
+}
+
+#[test]
+fn test_product_of_unrelated_units_is_an_error() {
+    let calculator = Calculator::new();
+    for input in ["2 kg * 3 m", "3 m * 2 kg", "10 USD * 2 kg", "10 kg / 2 USD"] {
+        let result = calculator.calculate_internal(input);
+        assert!(!result.success, "{input} gave {}", result.result);
+        assert!(
+            result
+                .error
+                .as_deref()
+                .unwrap_or_default()
+                .contains("Unit mismatch"),
+            "{input}: {:?}",
+            result.error
+        );
+    }
+    assert_eq!(calculate("5 USD/kg * 2 kg"), "10 USD");
+}
@@ -0,0 +1,79 @@
+//! Tests for the report of every exchange rate a calculation used.
+
+mod common;
+
+use common::calculator_with_lino_rates;
+use link_calculator::Calculator;
+
+const USD_EUR_RATES: &str = "conversion:
+  from USD
+  to EUR
+  source 'test'
+  rates:
+    2025-01-01 0.90
+    2026-01-01 0.80";
+
+#[test]
+fn test_every_conversion_is_reported() {
+    let result = calculator_with_lino_rates(USD_EUR_RATES)
+        .calculate_internal("(100 USD at 1 Jan 2025) + (100 USD at 1 Jan 2026) in EUR");
+    assert!(result.success, "{:?}", result.error);
+
+    let rates = &result.rates_used;
+    assert_eq!(rates.len(), 2, "{rates:?}");
+    assert_eq!(
+        (rates[0].from.as_str(), rates[0].to.as_str()),
+        ("USD", "EUR")
+    );
+    assert!((rates[0].rate - 0.90).abs() < f64::EPSILON);
+    assert_eq!(rates[0].date_requested.as_deref(), Some("2025-01-01"));
+    assert_eq!(rates[0].date_used, "2025-01-01");
+    assert_eq!(rates[0].source, "test");
+    assert!((rates[1].rate - 0.80).abs() < f64::EPSILON);
+    assert_eq!(rates[1].date_requested.as_deref(), Some("2026-01-01"));
+}
+
+#[test]
+fn test_the_date_used_can_differ_from_the_date_requested() {
+    let result = calculator_with_lino_rates(USD_EUR_RATES)
+        .calculate_internal("(100 USD in EUR) at 15 Mar 2025");
+    let rates = &result.rates_used;
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].date_requested.as_deref(), Some("2025-03-15"));
+    assert_eq!(rates[0].date_used, "2025-01-01");
+}
+
+#[test]
+fn test_current_rates_have_no_requested_date() {
+    let result = Calculator::new().calculate_internal("10 USD + 5 EUR");
+    assert_eq!(result.rates_used.len(), 1);
+    assert_eq!(result.rates_used[0].date_requested, None);
+}
+
+#[test]
+fn test_calculations_without_money_report_no_rates() {
+    let calculator = calculator_with_lino_rates(USD_EUR_RATES);
+    calculator.calculate_internal("(100 USD in EUR) at 15 Mar 2025");
+    let result = calculator.calculate_internal("2 + 2");
+    assert!(result.rates_used.is_empty());
+    let json = calculator.execute("2 + 2");
+    assert!(!json.contains("rates_used"));
+}
+
+#[test]
+fn test_built_in_rates_have_no_date_and_are_warned_about() {
+    let result = Calculator::new().calculate_internal("(100 USD + 50 EUR) at 22 Jan 2026");
+    assert!(result.success, "{:?}", result.error);
+    let rates = &result.rates_used;
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].date_requested.as_deref(), Some("2026-01-22"));
+    assert_eq!(rates[0].date_used, "unknown");
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("No historical EUR/USD rate was available")),
+        "{:?}",
+        result.warnings
+    );
+}
@@ -0,0 +1,181 @@
+//! Tests for the `alternatives` of a numeric result: the same number in
+//! decimal, fraction, scientific, engineering, percentage and prime factor
+//! notation, and the nearest fractions and continued fraction of an
+//! irrational one.
+
+mod common;
+
+use common::calculate_result;
+use link_calculator::{CalculationResult, RepresentationKind};
+
+fn alternative(result: &CalculationResult, kind: RepresentationKind) -> Option<&str> {
+    result
+        .alternatives
+        .iter()
+        .find(|representation| representation.kind == kind)
+        .map(|representation| representation.value.as_str())
+}
+
+#[test]
+fn test_fraction_result_has_every_notation_but_prime_factors() {
+    let result = calculate_result("3/8");
+    assert_eq!(
+        alternative(&result, RepresentationKind::Decimal),
+        Some("0.375")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Fraction),
+        Some("3/8")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Scientific),
+        Some("3.75e-1")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Engineering),
+        Some("375e-3")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Percentage),
+        Some("37.5%")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::PrimeFactorization),
+        None
+    );
+}
+
+#[test]
+fn test_integer_result_lists_its_prime_factors() {
+    let result = calculate_result("100 + 20");
+    assert_eq!(
+        alternative(&result, RepresentationKind::PrimeFactorization),
+        Some("2^3 * 3 * 5")
+    );
+    assert_eq!(alternative(&result, RepresentationKind::Fraction), None);
+    assert_eq!(
+        alternative(&result, RepresentationKind::Scientific),
+        Some("1.2e2")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Engineering),
+        Some("120e0")
+    );
+    assert_eq!(
+        alternative(&calculate_result("12345"), RepresentationKind::Engineering),
+        Some("12.345e3")
+    );
+    assert_eq!(
+        alternative(
+            &calculate_result("-12"),
+            RepresentationKind::PrimeFactorization
+        ),
+        Some("-1 * 2^2 * 3")
+    );
+}
+
+#[test]
+fn test_irrational_result_lists_nearest_fractions_and_continued_fraction() {
+    let result = calculate_result("pi");
+    assert_eq!(
+        alternative(&result, RepresentationKind::Approximations),
+        Some("22/7, 333/106, 355/113, 103993/33102")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::ContinuedFraction),
+        Some("[3; 7, 15, 1, 292, 1, 1, 1, 2, 1, ...]")
+    );
+    assert_eq!(
+        alternative(
+            &calculate_result("-sqrt(2)"),
+            RepresentationKind::Approximations
+        ),
+        Some("-3/2, -7/5, -17/12, -41/29")
+    );
+    // Exact fractions need no approximating
+    assert_eq!(
+        alternative(
+            &calculate_result("3/8"),
+            RepresentationKind::ContinuedFraction
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_results_with_units_have_no_alternatives() {
+    assert!(calculate_result("5 km").alternatives.is_empty());
+    assert!(calculate_result("2026-01-22").alternatives.is_empty());
+}
+
+#[test]
+fn test_alternatives_are_serialized_with_snake_case_kinds() {
+    let json = serde_json::to_string(&calculate_result("1/4")).unwrap();
+    assert!(
+        json.contains(r#"{"kind":"fraction","value":"1/4"}"#),
+        "{json}"
+    );
+    assert!(
+        json.contains(r#""kind":"percentage","value":"25%""#),
+        "{json}"
+    );
+}
+
+#[test]
+fn test_only_exact_results_are_written_as_fractions() {
+    for input in [
+        "sqrt(2)",
+        "sqrt(2) + 1",
+        "sqrt(8) / 2",
+        "2 * sin(1)",
+        "sqrt(2)^2",
+    ] {
+        let result = calculate_result(input);
+        assert_eq!(
+            alternative(&result, RepresentationKind::Fraction),
+            None,
+            "{input}"
+        );
+        assert_eq!(result.fraction, None, "{input}");
+    }
+    assert_eq!(
+        alternative(
+            &calculate_result("sqrt(2) + 1"),
+            RepresentationKind::Approximations
+        ),
+        Some("5/2, 12/5, 29/12, 70/29")
+    );
+    assert_eq!(calculate_result("1.5^2").fraction.as_deref(), Some("9/4"));
+}
+
+#[test]
+fn test_scientific_and_engineering_keep_the_same_significant_digits() {
+    let result = calculate_result("1/3");
+    assert_eq!(
+        alternative(&result, RepresentationKind::Scientific),
+        Some("3.333333333333333e-1")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Engineering),
+        Some("333.3333333333333e-3")
+    );
+    assert_eq!(
+        alternative(&calculate_result("2000/3"), RepresentationKind::Engineering),
+        Some("666.6666666666667e0")
+    );
+}
+
+#[test]
+fn test_float_results_keep_every_significant_digit_in_each_notation() {
+    let result = calculate_result("erf(1)");
+    assert_eq!(result.result, "0.8427007929497148");
+    assert_eq!(
+        alternative(&result, RepresentationKind::Scientific),
+        Some("8.427007929497148e-1")
+    );
+    assert_eq!(
+        alternative(&result, RepresentationKind::Engineering),
+        Some("842.7007929497148e-3")
+    );
+    assert_eq!(calculate_result("0.1 + 0.2").result, "0.3");
+}
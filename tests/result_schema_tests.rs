@@ -0,0 +1,64 @@
+//! Tests for the schema version and deterministic JSON of `CalculationResult`.
+
+use link_calculator::{result_from_json, Calculator, RESULT_SCHEMA_VERSION};
+
+#[test]
+fn test_results_carry_the_schema_version_first() {
+    let json = Calculator::new().execute("2 + 3");
+    assert!(
+        json.starts_with(&format!(
+            r#"{{"schema_version":{RESULT_SCHEMA_VERSION},"result":"5""#
+        )),
+        "{json}"
+    );
+}
+
+#[test]
+fn test_results_round_trip_through_json() {
+    for input in [
+        "1/3",
+        "10 km in miles",
+        "2026-03-15 + 10 days",
+        "1 +",
+        "5 m + 2 kg",
+    ] {
+        let json = Calculator::new().execute(input);
+        let result = result_from_json(&json).unwrap();
+        assert_eq!(serde_json::to_string(&result).unwrap(), json, "{input}");
+    }
+}
+
+#[test]
+fn test_error_parameters_are_written_in_key_order() {
+    let json = Calculator::new().execute("5 m + 2 kg");
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let params = value["error_info"]["params"].as_object().unwrap();
+    let keys: Vec<_> = params.keys().cloned().collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert!(keys.len() > 1);
+    assert_eq!(keys, sorted);
+    for _ in 0..10 {
+        assert_eq!(Calculator::new().execute("5 m + 2 kg"), json);
+    }
+}
+
+#[test]
+fn test_results_saved_without_a_version_are_version_one() {
+    let result = result_from_json(
+        r#"{"result":"5","lino_interpretation":"(2 + 3)","steps":[],"success":true,"error":null,"issue_link":null}"#,
+    )
+    .unwrap();
+    assert_eq!(result.schema_version, 1);
+    assert_eq!(result.result, "5");
+}
+
+#[test]
+fn test_newer_or_invalid_results_are_rejected() {
+    let newer = format!(
+        r#"{{"schema_version":{},"result":"5","lino_interpretation":"","steps":[],"success":true,"error":null,"issue_link":null}}"#,
+        RESULT_SCHEMA_VERSION + 1
+    );
+    assert!(result_from_json(&newer).unwrap_err().contains("newer"));
+    assert!(result_from_json("[1, 2]").is_err());
+}
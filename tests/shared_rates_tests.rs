@@ -0,0 +1,127 @@
+//! Tests for calculators sharing one currency rate store.
+
+use link_calculator::types::CurrencyDatabase;
+use link_calculator::Calculator;
+use std::thread;
+
+fn convert(calculator: &Calculator, input: &str) -> String {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    result.result
+}
+
+#[test]
+fn test_shared_calculators_see_each_others_rates() {
+    let first = Calculator::new();
+    let second = first.share_rates();
+    assert!(second
+        .parser()
+        .currency_db()
+        .shares_rates_with(first.parser().currency_db()));
+
+    second.update_rates_from_api("USD", "2026-01-25", r#"{"eur": 0.5}"#);
+    assert_eq!(convert(&first, "100 USD as EUR"), "50 EUR");
+
+    // Independent calculators keep their own rates
+    let other = Calculator::new();
+    assert_ne!(convert(&other, "100 USD as EUR"), "50 EUR");
+}
+
+#[test]
+fn test_cloned_databases_do_not_share() {
+    let original = CurrencyDatabase::new();
+    let copy = original.clone();
+    assert!(!copy.shares_rates_with(&original));
+    original.set_rate("USD", "EUR", 0.5);
+    assert_ne!(copy.get_rate("USD", "EUR"), Some(0.5));
+}
+
+#[test]
+fn test_each_calculator_reports_its_own_rates() {
+    let first = Calculator::new();
+    let second = first.share_rates();
+    first.calculate_internal("100 USD as EUR");
+    let result = second.calculate_internal("100 GBP as USD");
+    let steps = result.steps.join("\n");
+    assert!(steps.contains("1 GBP"), "{steps}");
+    assert!(!steps.contains("1 USD = "), "{steps}");
+}
+
+#[test]
+fn test_background_updates_reach_calculators_on_other_threads() {
+    let updater = Calculator::new();
+    let rates = updater.parser().currency_db().shared();
+
+    updater.update_rates_from_api("USD", "2026-01-25", r#"{"eur": 0.5}"#);
+    let worker = thread::spawn(move || {
+        let calculator = Calculator::with_currency_db(rates);
+        convert(&calculator, "100 USD as EUR")
+    });
+    assert_eq!(worker.join().unwrap(), "50 EUR");
+}
+
+#[test]
+fn test_concurrent_updates_and_conversions() {
+    let updater = Calculator::new();
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let rates = updater.parser().currency_db().shared();
+            thread::spawn(move || {
+                let calculator = Calculator::with_currency_db(rates);
+                for _ in 0..50 {
+                    convert(&calculator, "100 USD as EUR");
+                }
+            })
+        })
+        .collect();
+    for i in 1..=50 {
+        let json = format!(r#"{{"eur": {}}}"#, 0.5 + f64::from(i) / 100.0);
+        updater.update_rates_from_api("USD", "2026-01-25", &json);
+    }
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
+
+#[test]
+fn test_one_calculator_updates_and_converts_on_several_threads() {
+    let calculator = Calculator::new();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            for i in 1..=50 {
+                let json = format!(r#"{{"eur": {}}}"#, 0.5 + f64::from(i) / 100.0);
+                calculator.update_rates_from_api("USD", "2026-01-25", &json);
+            }
+        });
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..50 {
+                    convert(&calculator, "100 USD as EUR");
+                }
+            });
+        }
+    });
+    assert_eq!(convert(&calculator, "100 USD as EUR"), "100 EUR");
+}
+
+#[test]
+fn test_rate_files_and_values_through_a_shared_calculator() {
+    let calculator = Calculator::new();
+    let other = calculator.share_rates();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            let rate = "rate:
+  from USD
+  to EUR
+  value 0.5
+  date 2021-01-04
+  source 'test'";
+            assert_eq!(calculator.load_rates_batch(&[rate]), Ok(1));
+        });
+        scope.spawn(|| {
+            let (_, value, _, _) = calculator.calculate_with_value("2 + 3").unwrap();
+            assert_eq!(value.to_display_string(), "5");
+        });
+    });
+    assert_eq!(convert(&other, "(100 USD in EUR) at 4 Jan 2021"), "50 EUR");
+}
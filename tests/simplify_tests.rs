@@ -0,0 +1,57 @@
+//! Tests for the `simplify` command.
+
+use link_calculator::Calculator;
+
+fn simplify(input: &str) -> String {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal(&format!("simplify {input}"));
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.is_symbolic, Some(true), "not symbolic: {input}");
+    result.result
+}
+
+#[test]
+fn test_cancel_common_factors() {
+    assert_eq!(simplify("(x^2 + 2x + 1)/(x+1)"), "x + 1");
+    assert_eq!(simplify("(x^2 - 1)/(x - 1)"), "x + 1");
+    assert_eq!(simplify("(x^3 - x)/(x^2 + x)"), "x - 1");
+    assert_eq!(simplify("(2x + 2)/(x + 1)"), "2");
+    assert_eq!(simplify("(x + 1)/(x + 2)"), "(x + 1) / (x + 2)");
+}
+
+#[test]
+fn test_combine_like_terms() {
+    assert_eq!(simplify("x + 2x - 1 + 1"), "3 * x");
+    assert_eq!(simplify("x*y + y*x"), "2 * x * y");
+    assert_eq!(simplify("1/x + 1/x"), "2 / x");
+    assert_eq!(simplify("sin(x) + sin(x)"), "2 * sin(x)");
+}
+
+#[test]
+fn test_constant_folding() {
+    assert_eq!(simplify("2 + 3"), "5");
+    assert_eq!(simplify("2^10 * x + 0"), "1024 * x");
+}
+
+#[test]
+fn test_simplify_latex() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("simplify (x^2 - 1)/(x - 1)");
+    let latex_input = result.latex_input.unwrap();
+    assert!(
+        latex_input.starts_with("\\operatorname{simplify}"),
+        "{latex_input}"
+    );
+    assert_eq!(result.latex_result.as_deref(), Some("x + 1"));
+}
+
+#[test]
+fn test_division_by_zero_is_reported() {
+    let calc = Calculator::new();
+    for input in ["simplify 0/0", "simplify 0/(x-x)", "simplify x/(x - x)"] {
+        let result = calc.calculate_internal(input);
+        assert!(!result.success, "{input} gave {}", result.result);
+        assert_eq!(result.error.as_deref(), Some("Division by zero"), "{input}");
+    }
+    assert_eq!(simplify("0/x"), "0");
+}
@@ -0,0 +1,82 @@
+//! Tests for the special functions: gamma, beta, erf, erfc and the sine,
+//! cosine and exponential integrals.
+
+use link_calculator::Calculator;
+
+fn number(input: &str) -> f64 {
+    let result = Calculator::new().calculate_internal(input);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.result.parse().unwrap()
+}
+
+fn assert_close(input: &str, expected: f64) {
+    let actual = number(input);
+    assert!(
+        (actual - expected).abs() < 1e-12 * expected.abs().max(1.0),
+        "{input} = {actual}, expected {expected}"
+    );
+}
+
+#[test]
+fn test_si_matches_the_integral_of_sinc() {
+    assert_close("Si(1.5)", 1.324_683_531_172_12);
+    let integral = number("integrate(sin(x)/x, x, 0, 1.5)");
+    assert!((number("Si(1.5)") - integral).abs() < 1e-9);
+    assert_close("Si(0)", 0.0);
+    assert_close("Si(-1.5)", -1.324_683_531_172_12);
+}
+
+#[test]
+fn test_cosine_and_exponential_integrals() {
+    assert_close("Ci(1)", 0.337_403_922_900_968_2);
+    assert_close("Ei(1)", 1.895_117_816_355_937);
+    assert_close("ei(-1)", -0.219_383_934_395_520_3);
+}
+
+#[test]
+fn test_gamma_and_beta_are_exact_for_whole_numbers() {
+    let calculator = Calculator::new();
+    assert_eq!(calculator.calculate_internal("gamma(5)").result, "24");
+    assert_eq!(
+        calculator.calculate_internal("beta(2, 3)").result,
+        "0.08333333333333333"
+    );
+    assert_eq!(
+        calculator.calculate_internal("beta(1, 3)").result,
+        "0.3333333333333333"
+    );
+    assert_eq!(calculator.calculate_internal("beta(1, 3) * 3").result, "1");
+    assert_close("gamma(0.5)", std::f64::consts::PI.sqrt());
+    assert_close("beta(0.5, 0.5)", std::f64::consts::PI);
+}
+
+#[test]
+fn test_gamma_keeps_going_past_a_decimal() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("gamma(29)").result,
+        calculator.calculate_internal("factorial(28)").result
+    );
+    assert_eq!(calculator.calculate_internal("gamma(29) - 28!").result, "0");
+    assert_close("gamma(30.5) / 10^31", 4.822_696_933_490_909);
+    assert_close("gamma(171) / 10^306", 7.257_415_615_307_999);
+    assert_eq!(
+        calculator.calculate_internal("gamma(200.5)").result,
+        "5.573168944800758e373"
+    );
+}
+
+#[test]
+fn test_error_functions() {
+    assert_close("erf(0.5)", 0.520_499_877_813_047);
+    assert_close("erf(0.5) + erfc(0.5)", 1.0);
+    assert_close("erfc(3)", 2.209_049_699_858_544e-5);
+}
+
+#[test]
+fn test_poles_are_domain_errors() {
+    let calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("gamma(-2)").success);
+    assert!(!calculator.calculate_internal("Ci(0)").success);
+    assert!(!calculator.calculate_internal("Ei(0)").success);
+}
@@ -0,0 +1,87 @@
+//! Tests for variadic statistics functions (mean, median, mode, stddev, ...).
+
+mod common;
+
+use common::{calculate, calculate_result, calculator_with_api_rates};
+use link_calculator::Calculator;
+
+#[test]
+fn test_mean() {
+    assert_eq!(calculate("mean(1, 2, 3, 4)"), "2.5");
+}
+
+#[test]
+fn test_mean_of_rationals_is_exact() {
+    let result = calculate_result("mean(1, 2, 2)");
+    assert_eq!(result.fraction.as_deref(), Some("5/3"));
+    assert_eq!(calculate("sum(1/3, 1/3, 1/3)"), "1");
+}
+
+#[test]
+fn test_median_and_mode() {
+    assert_eq!(calculate("median(5, 1, 3)"), "3");
+    assert_eq!(calculate("median(4, 1, 3, 2)"), "2.5");
+    assert_eq!(calculate("mode(1, 2, 2, 3)"), "2");
+}
+
+#[test]
+fn test_variance_and_stddev() {
+    assert_eq!(
+        calculate_result("variance(1, 2, 3, 4)").fraction.as_deref(),
+        Some("5/3")
+    );
+    assert_eq!(calculate("stddev(1, 3, 5)"), "2");
+}
+
+#[test]
+fn test_stddev_past_a_decimal() {
+    assert_eq!(calculate("stddev(10^40, 1)"), "7.071067811865475e39");
+}
+
+#[test]
+fn test_sum_and_count() {
+    assert_eq!(calculate("sum(1, 2, 3)"), "6");
+    assert_eq!(calculate("count(4, 5, 6)"), "3");
+}
+
+#[test]
+fn test_units_are_kept() {
+    assert_eq!(calculate("mean(10 kg, 20 kg)"), "15 kg");
+    assert_eq!(calculate("sum(1 USD, 2 USD)"), "3 USD");
+}
+
+#[test]
+fn test_units_convert_exactly() {
+    assert_eq!(
+        calculate_result("sum(1 kg, 1 lb)").fraction.as_deref(),
+        Some("145359237/100000000")
+    );
+}
+
+#[test]
+fn test_currencies_convert_at_exchange_rates() {
+    let calculator = calculator_with_api_rates(
+        "2026-01-01",
+        &[("USD", r#"{"EUR": 0.5}"#), ("EUR", r#"{"USD": 2}"#)],
+    );
+    assert_eq!(
+        calculator.calculate_internal("sum(10 USD, 5 EUR)").result,
+        "20 USD"
+    );
+    assert_eq!(
+        calculator.calculate_internal("mean(10 EUR, 10 USD)").result,
+        "7.5 EUR"
+    );
+}
+
+#[test]
+fn test_statistics_in_expressions() {
+    assert_eq!(calculate("mean(1, 2) * 2"), "3");
+}
+
+#[test]
+fn test_errors() {
+    let calc = Calculator::new();
+    assert!(!calc.calculate_internal("mean(1 kg, 2 m)").success);
+    assert!(!calc.calculate_internal("variance(1)").success);
+}
@@ -0,0 +1,78 @@
+//! Tests for `Calculator::set_language`: the `steps` strings come out in the
+//! user's language.
+
+use link_calculator::Calculator;
+
+fn steps(calculator: &Calculator, input: &str) -> Vec<String> {
+    let result = calculator.calculate_internal(input);
+    assert!(result.success, "{input} failed: {:?}", result.error);
+    result.steps
+}
+
+#[test]
+fn test_steps_are_english_by_default() {
+    let calculator = Calculator::new();
+    let steps = steps(&calculator, "2 + 3");
+    assert_eq!(steps.first().unwrap(), "Input expression: 2 + 3");
+    assert_eq!(steps.last().unwrap(), "Final result: 5");
+}
+
+#[test]
+fn test_russian_steps() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_language("ru-RU"));
+    let steps = steps(&calculator, "2 + 3 * 4");
+    assert_eq!(
+        steps,
+        vec![
+            "Входное выражение: 2 + 3 * 4",
+            "Литеральное значение: 2",
+            "Литеральное значение: 3",
+            "Литеральное значение: 4",
+            "Вычислить: 3 * 4",
+            "= 12",
+            "Вычислить: 2 + 12",
+            "= 14",
+            "Итоговый результат: 14",
+        ]
+    );
+}
+
+#[test]
+fn test_german_conversion_steps() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_language("de"));
+    let steps = steps(&calculator, "5 km to m");
+    assert!(steps
+        .iter()
+        .any(|step| step.starts_with("Eingabeausdruck: ")));
+    assert!(
+        steps.iter().any(|step| step.starts_with("Endergebnis: ")),
+        "{steps:?}"
+    );
+}
+
+#[test]
+fn test_steps_without_a_template_stay_english() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_language("ru"));
+    let steps = steps(&calculator, "x + 1 = 3");
+    assert!(steps.iter().any(|step| step == "Решение: x = 2"));
+    assert!(steps.iter().any(|step| step == "Solve for x: x = 2"));
+}
+
+#[test]
+fn test_unknown_language_keeps_the_current_one() {
+    let mut calculator = Calculator::new();
+    assert!(calculator.set_language("fr"));
+    assert!(!calculator.set_language("xx"));
+    assert_eq!(
+        steps(&calculator, "1 + 1").last().unwrap(),
+        "Résultat final : 2"
+    );
+    assert!(calculator.set_language("en"));
+    assert_eq!(
+        steps(&calculator, "1 + 1").last().unwrap(),
+        "Final result: 2"
+    );
+}
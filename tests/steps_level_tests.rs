@@ -0,0 +1,78 @@
+//! Tests for the detail level of calculation steps.
+
+use link_calculator::types::StepsLevel;
+use link_calculator::{CalculationOptions, CalculationResult, Calculator};
+
+fn calculate(input: &str, steps_level: StepsLevel) -> CalculationResult {
+    let calculator = Calculator::new();
+    let options = CalculationOptions {
+        steps_level,
+        ..CalculationOptions::default()
+    };
+    calculator.calculate_with_options(input, &options)
+}
+
+#[test]
+fn test_minimal_steps() {
+    let result = calculate("(2 + 3) * 4", StepsLevel::Minimal);
+    assert_eq!(
+        result.steps,
+        vec!["Input expression: (2 + 3) * 4", "Final result: 20"]
+    );
+}
+
+#[test]
+fn test_normal_steps_are_the_default() {
+    let normal = calculate("(2 + 3) * 4", StepsLevel::Normal);
+    let default = Calculator::new().calculate_internal("(2 + 3) * 4");
+    assert_eq!(normal.steps, default.steps);
+    assert!(normal.steps.len() > 2);
+}
+
+#[test]
+fn test_verbose_steps_normalize_units() {
+    let result = calculate("2 km + 500 m", StepsLevel::Verbose);
+    assert!(
+        result
+            .steps
+            .contains(&"Normalize to base units: 2 km = 2000 m".to_string()),
+        "{:?}",
+        result.steps
+    );
+}
+
+#[test]
+fn test_debug_steps_show_parser_decisions() {
+    let result = calculate("2 + 3", StepsLevel::Debug);
+    assert!(
+        result.steps[0].starts_with("Tokens: Number"),
+        "{:?}",
+        result.steps
+    );
+    assert_eq!(result.steps[1], "Grammar branch: binary operation (+)");
+    assert_eq!(result.steps[2], "Links notation: (2 + 3)");
+    assert_eq!(result.steps.last().unwrap(), "Final result: 5");
+}
+
+#[test]
+fn test_debug_steps_explain_parse_errors() {
+    let result = calculate("2 + * 3", StepsLevel::Debug);
+    assert!(!result.success);
+    assert!(result.steps[0].starts_with("Tokens:"), "{:?}", result.steps);
+    assert!(
+        result.steps[1].starts_with("Parse error:"),
+        "{:?}",
+        result.steps
+    );
+}
+
+#[test]
+fn test_steps_level_json() {
+    assert_eq!(StepsLevel::parse("Verbose"), Some(StepsLevel::Verbose));
+    let calculator = Calculator::new();
+    let json = calculator.calculate("1 + 1", Some(r#"{"steps_level": "minimal"}"#.into()));
+    assert!(
+        json.contains(r#""steps":["Input expression: 1 + 1","Final result: 2"]"#),
+        "{json}"
+    );
+}
@@ -0,0 +1,72 @@
+//! Tests for the machine-readable computation graph of a calculation.
+
+use link_calculator::Calculator;
+
+#[test]
+fn test_operations_refer_to_operand_ids() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("(2 + 3) * 4");
+    let steps = result.structured_steps.expect("structured steps");
+    let summary: Vec<_> = steps
+        .iter()
+        .map(|step| {
+            (
+                step.id,
+                step.operation.as_str(),
+                step.inputs.clone(),
+                step.output.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (0, "literal", vec![], "2"),
+            (1, "literal", vec![], "3"),
+            (2, "add", vec![0, 1], "5"),
+            (3, "literal", vec![], "4"),
+            (4, "multiply", vec![2, 3], "20"),
+        ]
+    );
+}
+
+#[test]
+fn test_function_and_unit_metadata() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("sqrt(16) * 2 km");
+    let steps = result.structured_steps.expect("structured steps");
+    let function = steps
+        .iter()
+        .find(|step| step.operation == "function")
+        .unwrap();
+    assert_eq!(function.detail.as_deref(), Some("sqrt"));
+    let last = steps.last().unwrap();
+    assert_eq!(last.operation, "multiply");
+    assert_eq!(last.unit.as_deref(), Some("km"));
+}
+
+#[test]
+fn test_currency_steps_record_rates() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("10 USD + 5 EUR");
+    assert!(result.success, "{:?}", result.error);
+    let steps = result.structured_steps.expect("structured steps");
+    let add = steps.last().unwrap();
+    assert_eq!(add.operation, "add");
+    assert_eq!(add.rates.len(), 1, "{add:?}");
+    assert_eq!(add.rates[0].from, "EUR");
+    assert_eq!(add.rates[0].to, "USD");
+    assert!(steps[0].rates.is_empty());
+}
+
+#[test]
+fn test_structured_steps_serialize() {
+    let calculator = Calculator::new();
+    let json = calculator.execute("1 + 2");
+    assert!(
+        json.contains(
+            r#""structured_steps":[{"id":0,"operation":"literal","inputs":[],"output":"1"}"#
+        ),
+        "{json}"
+    );
+}
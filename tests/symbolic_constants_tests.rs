@@ -0,0 +1,56 @@
+//! Tests for keeping π and e exact through arithmetic.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::Calculator;
+
+#[test]
+fn test_multiples_of_pi_stay_symbolic() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "2 * pi"), "2π");
+    assert_eq!(calculate_with(&calculator, "pi / 2"), "π/2");
+    assert_eq!(calculate_with(&calculator, "3 * pi / 4"), "3π/4");
+    assert_eq!(calculate_with(&calculator, "-pi"), "-π");
+    assert_eq!(calculate_with(&calculator, "pi * pi"), "π²");
+    assert_eq!(calculate_with(&calculator, "pi^2 / pi"), "π");
+    assert_eq!(calculate_with(&calculator, "1 / (2 * pi)"), "1/(2π)");
+    assert_eq!(calculate_with(&calculator, "pi + pi"), "2π");
+    assert_eq!(calculate_with(&calculator, "pi - pi"), "0");
+    assert_eq!(calculate_with(&calculator, "e^2"), "e²");
+}
+
+#[test]
+fn test_the_value_is_among_the_alternatives() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("2 * pi");
+    assert_eq!(result.result, "2π");
+    assert_eq!(result.alternatives[0].value, "6.283185307179586");
+    assert!(result.fraction.is_none());
+    assert_eq!(
+        calculate_with(&calculator, "(2 * pi) to 5 decimals"),
+        "6.28319"
+    );
+}
+
+#[test]
+fn test_inexact_arithmetic_uses_the_value() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "pi + 1"), "4.141592653589793");
+    assert_eq!(calculate_with(&calculator, "sqrt(pi)"), "1.772453850905516");
+    assert_eq!(calculate_with(&calculator, "pi > 3"), "true");
+}
+
+#[test]
+fn test_functions_of_multiples_of_pi_are_exact() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "sin(pi)"), "0");
+    assert_eq!(calculate_with(&calculator, "sin(2 * pi)"), "0");
+    assert_eq!(calculate_with(&calculator, "cos(pi)"), "-1");
+    assert_eq!(calculate_with(&calculator, "sin(pi / 6)"), "0.5");
+    assert_eq!(calculate_with(&calculator, "cos(pi / 3)"), "0.5");
+    assert_eq!(calculate_with(&calculator, "tan(-pi / 4)"), "-1");
+    assert_eq!(calculate_with(&calculator, "ln(e^3)"), "3");
+    let result = calculator.calculate_internal("tan(pi / 2)");
+    assert!(!result.success);
+}
@@ -0,0 +1,114 @@
+//! Tests for the rule-based symbolic integration engine.
+
+use link_calculator::Calculator;
+
+fn integrate(input: &str) -> String {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal(input);
+    assert!(result.success, "calculation failed: {:?}", result.error);
+    assert_eq!(result.is_symbolic, Some(true), "not symbolic: {input}");
+    result.result
+}
+
+#[test]
+fn test_integration_by_parts() {
+    assert_eq!(
+        integrate("integrate x*exp(x) dx"),
+        "x * exp(x) - exp(x) + C"
+    );
+    assert_eq!(
+        integrate("integrate x*sin(x) dx"),
+        "-x * cos(x) + sin(x) + C"
+    );
+    assert_eq!(integrate("integrate ln(x) dx"), "x * ln(x) - x + C");
+}
+
+#[test]
+fn test_inverse_trig_forms() {
+    assert_eq!(integrate("integrate 1/(1+x^2) dx"), "atan(x) + C");
+    assert_eq!(integrate("integrate 1/sqrt(4-x^2) dx"), "asin(x / 2) + C");
+}
+
+#[test]
+fn test_u_substitution() {
+    assert_eq!(integrate("integrate 2*x*cos(x^2) dx"), "sin(x^2) + C");
+    assert_eq!(integrate("integrate x/sqrt(x^2+1) dx"), "sqrt(x^2 + 1) + C");
+}
+
+#[test]
+fn test_logarithmic_derivative() {
+    assert_eq!(
+        integrate("integrate x/(x^2+1) dx"),
+        "ln(abs(x^2 + 1)) / 2 + C"
+    );
+}
+
+#[test]
+fn test_polynomials() {
+    assert_eq!(integrate("integrate x^2 dx"), "x^3 / 3 + C");
+    assert_eq!(integrate("integrate 3*x^2+2*x+1 dx"), "x^3 + x^2 + x + C");
+    assert_eq!(integrate("integrate x^(-1) dx"), "ln(abs(x)) + C");
+    assert_eq!(integrate("integrate x^-1 dx"), "ln(abs(x)) + C");
+}
+
+#[test]
+fn test_implicit_multiplication() {
+    assert_eq!(integrate("integrate sin(3*x) dx"), "-cos(3 * x) / 3 + C");
+    assert_eq!(integrate("integrate sin(3x) dx"), "-cos(3 * x) / 3 + C");
+    assert_eq!(
+        integrate("integrate 1/(2*x+1) dx"),
+        "ln(abs(2 * x + 1)) / 2 + C"
+    );
+    assert_eq!(
+        integrate("integrate 1/(2x+1) dx"),
+        "ln(abs(2 * x + 1)) / 2 + C"
+    );
+}
+
+#[test]
+fn test_non_elementary_integral_suggests_definite_integral() {
+    let calc = Calculator::new();
+    let result = calc.calculate_internal("integrate exp(x^2) dx");
+    assert!(result.result.contains("Cannot compute symbolic result"));
+}
+
+#[test]
+fn test_parenthesized_integrands() {
+    assert_eq!(integrate("integrate (x+1)^2 dx"), "(x + 1)^3 / 3 + C");
+    assert_eq!(integrate("integrate (2*x+1)^3 dx"), "(2 * x + 1)^4 / 8 + C");
+    assert_eq!(integrate("integrate (x+1)*x dx"), "x^3 / 3 + x^2 / 2 + C");
+}
+
+#[test]
+fn test_trigonometric_squares_and_exp_times_trig() {
+    assert_eq!(
+        integrate("integrate sin(x)^2 dx"),
+        "x / 2 - sin(2 * x) / 4 + C"
+    );
+    assert_eq!(
+        integrate("integrate cos(x)^2 dx"),
+        "x / 2 + sin(2 * x) / 4 + C"
+    );
+    assert_eq!(
+        integrate("integrate exp(x)*sin(x) dx"),
+        "exp(x) * (sin(x) - cos(x)) / 2 + C"
+    );
+    assert_eq!(
+        integrate("integrate exp(x)*cos(x) dx"),
+        "exp(x) * (cos(x) + sin(x)) / 2 + C"
+    );
+}
+
+#[test]
+fn test_logarithmic_derivative_with_a_cofactor() {
+    assert_eq!(integrate("integrate 1/(x*ln(x)) dx"), "ln(abs(ln(x))) + C");
+}
+
+#[test]
+fn test_euler_powers_are_written_without_call_parentheses() {
+    assert_eq!(integrate("integrate e^x dx"), "e^x + C");
+
+    let result = Calculator::new().calculate_internal("integrate e^x dx");
+    assert_eq!(result.latex_input.as_deref(), Some("\\int e^{x} \\, dx"));
+    assert_eq!(result.latex_result.as_deref(), Some("e^{x} + C"));
+}
@@ -0,0 +1,68 @@
+//! Tests for teaching steps, which show the written working of basic
+//! arithmetic when `teaching_steps` is set in [`CalculationOptions`].
+
+use link_calculator::{CalculationOptions, Calculator};
+
+fn steps(input: &str, teaching_steps: bool) -> Vec<String> {
+    let options = CalculationOptions {
+        teaching_steps,
+        ..CalculationOptions::default()
+    };
+    let result = Calculator::new().calculate_with_options(input, &options);
+    assert!(result.success, "{input}: {:?}", result.error);
+    result.steps
+}
+
+fn has(steps: &[String], step: &str) -> bool {
+    steps.iter().any(|s| s == step)
+}
+
+#[test]
+fn test_long_multiplication_shows_partial_products() {
+    let steps = steps("123 * 45", true);
+    assert!(has(&steps, "Long multiplication: 123 × 45"));
+    assert!(has(&steps, "123 × 4 = 492, shifted 1 place: 4920"));
+    assert!(has(&steps, "Add the partial products: 615 + 4920 = 5535"));
+}
+
+#[test]
+fn test_long_division_brings_down_each_digit() {
+    let steps = steps("1234 / 7", true);
+    assert!(has(&steps, "Long division: 1234 ÷ 7"));
+    assert!(has(&steps, "Bring down 3: 53 ÷ 7 = 7, remainder 4"));
+    assert!(has(&steps, "Quotient 176, remainder 2"));
+}
+
+#[test]
+fn test_fractions_are_added_over_a_common_denominator() {
+    let steps = steps("(1/4) + (1/6)", true);
+    assert!(has(&steps, "Common denominator: lcm(4, 6) = 12"));
+    assert!(has(&steps, "1/4 = 3/12, 1/6 = 2/12"));
+    assert!(has(&steps, "3/12 + 2/12 = 5/12"));
+}
+
+#[test]
+fn test_working_is_off_by_default_and_reset_after_a_calculation() {
+    assert!(!steps("123 * 45", false)
+        .iter()
+        .any(|step| step.starts_with("Long multiplication")));
+
+    let calculator = Calculator::new();
+    let options = CalculationOptions {
+        teaching_steps: true,
+        ..CalculationOptions::default()
+    };
+    calculator.calculate_with_options("2 * 3", &options);
+    let result = calculator.calculate_internal("123 * 45");
+    assert!(!result
+        .steps
+        .iter()
+        .any(|step| step.starts_with("Long multiplication")));
+}
+
+#[test]
+fn test_options_are_read_from_json() {
+    let json =
+        Calculator::new().calculate("12 * 12", Some(r#"{"teaching_steps": true}"#.to_string()));
+    assert!(json.contains("Long multiplication: 12 × 12"), "{json}");
+}
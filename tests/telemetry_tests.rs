@@ -0,0 +1,63 @@
+//! Tests for the telemetry hook on `Calculator`.
+
+use std::sync::{Arc, Mutex};
+
+use link_calculator::types::{EvaluationEvent, FixedClock};
+use link_calculator::Calculator;
+
+fn recording_calculator() -> (Calculator, Arc<Mutex<Vec<EvaluationEvent>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut calculator = Calculator::new();
+    calculator.set_clock(FixedClock::from_rfc3339("2026-03-15T12:30:00Z").unwrap());
+    let recorded = Arc::clone(&events);
+    calculator.set_telemetry(move |event: &EvaluationEvent| {
+        recorded.lock().unwrap().push(event.clone());
+    });
+    (calculator, events)
+}
+
+#[test]
+fn test_successful_calculations_are_recorded_without_the_input() {
+    let (calculator, events) = recording_calculator();
+    assert!(calculator.calculate_internal("  5 km in m ").success);
+    assert_eq!(
+        events.lock().unwrap().as_slice(),
+        [EvaluationEvent {
+            input_length: 9,
+            expression_kind: Some("unit conversion".to_string()),
+            duration_ms: 0.0,
+            success: true,
+            error_code: None,
+        }]
+    );
+}
+
+#[test]
+fn test_failures_are_recorded_with_their_error_code() {
+    let (calculator, events) = recording_calculator();
+    calculator.calculate_internal("1 / 0");
+    calculator.calculate_internal("2 +");
+    let events = events.lock().unwrap().clone();
+    assert_eq!(
+        events[0].expression_kind.as_deref(),
+        Some("binary operation")
+    );
+    assert_eq!(
+        events[0].error_code.as_deref(),
+        Some("errors.divisionByZero")
+    );
+    assert!(!events[1].success);
+    assert_eq!(events[1].expression_kind, None);
+    assert_eq!(events[1].error_code.as_deref(), Some("errors.parseError"));
+}
+
+#[test]
+fn test_clear_telemetry_stops_recording() {
+    let (mut calculator, events) = recording_calculator();
+    calculator.calculate_internal("sin(0)");
+    calculator.clear_telemetry();
+    calculator.calculate_internal("1 + 1");
+    let events = events.lock().unwrap().clone();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].expression_kind.as_deref(), Some("function call"));
+}
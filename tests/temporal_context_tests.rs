@@ -0,0 +1,61 @@
+//! Tests for how the date of an `at` clause reaches every currency
+//! conversion inside it.
+
+mod common;
+
+use common::{calculate_with, calculator_with_lino_rates};
+
+const USD_GBP_RATES: &str = "conversion:
+  from USD
+  to GBP
+  source 'test'
+  rates:
+    2021-01-01 0.70
+    2021-01-11 0.80";
+
+fn result_of(input: &str) -> String {
+    calculate_with(&calculator_with_lino_rates(USD_GBP_RATES), input)
+}
+
+#[test]
+fn test_at_date_reaches_grouped_sums() {
+    assert_eq!(result_of("(100 GBP + 50 USD) at Jan 5, 2021"), "135 GBP");
+    assert_eq!(result_of("(100 GBP + 50 USD) at (Jan 12, 2021)"), "140 GBP");
+}
+
+#[test]
+fn test_at_date_reaches_chained_and_nested_operations() {
+    assert_eq!(
+        result_of("((100 GBP + 50 USD) * 2) at Jan 5, 2021"),
+        "270 GBP"
+    );
+    assert_eq!(
+        result_of("(100 GBP + (50 USD in GBP)) at Jan 5, 2021"),
+        "135 GBP"
+    );
+    assert_eq!(
+        result_of("(100 GBP + 50 USD) at (Jan 1, 2021 + 10 days)"),
+        "140 GBP"
+    );
+}
+
+#[test]
+fn test_at_date_reaches_comparisons() {
+    assert_eq!(
+        result_of("(100 USD as GBP > 75 GBP) at Jan 5, 2021"),
+        "false"
+    );
+}
+
+#[test]
+fn test_at_bare_year_means_its_first_day() {
+    assert_eq!(result_of("(100 USD in GBP) at 2021"), "70 GBP");
+}
+
+#[test]
+fn test_inner_at_clause_overrides_the_outer_one() {
+    assert_eq!(
+        result_of("((50 USD in GBP at Jan 12, 2021) + 50 USD) at Jan 5, 2021"),
+        "75 GBP"
+    );
+}
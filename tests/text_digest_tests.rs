@@ -0,0 +1,52 @@
+//! Tests for the `md5`, `sha256` and `crc32` digests of quoted text, which
+//! need the `text-tools` feature.
+#![cfg(feature = "text-tools")]
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_md5_of_text() {
+    assert_eq!(
+        calculate("md5(\"hello\")"),
+        "5d41402abc4b2a76b9719d911017c592"
+    );
+    assert_eq!(calculate("md5(\"\")"), "d41d8cd98f00b204e9800998ecf8427e");
+}
+
+#[test]
+fn test_sha256_of_text() {
+    assert_eq!(
+        calculate("sha256(\"hello\")"),
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+}
+
+#[test]
+fn test_crc32_of_text() {
+    assert_eq!(calculate("crc32(\"hello\")"), "3610a686");
+    assert_eq!(
+        calculate("crc32(\"The quick brown fox jumps over the lazy dog\")"),
+        "414fa339"
+    );
+}
+
+#[test]
+fn test_digests_hash_utf8_bytes() {
+    assert_eq!(
+        calculate("md5(\"привет\")"),
+        "608333adc72f545078ede3aad71bfe74"
+    );
+}
+
+#[test]
+fn test_digests_need_quoted_text() {
+    let result = Calculator::new().calculate_internal("sha256(42)");
+    assert!(!result.success);
+    assert!(result
+        .error
+        .unwrap_or_default()
+        .contains("expected quoted text"));
+}
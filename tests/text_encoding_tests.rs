@@ -0,0 +1,36 @@
+//! Tests for the base64 and URL encodings of quoted text.
+
+mod common;
+
+use common::{calculate, error};
+
+#[test]
+fn test_base64_encodes_utf8_text() {
+    assert_eq!(calculate("base64(\"hello\")"), "aGVsbG8=");
+    assert_eq!(calculate("base64(\"привет\")"), "0L/RgNC40LLQtdGC");
+    assert_eq!(calculate("base64(\"\")"), "");
+}
+
+#[test]
+fn test_base64decode_reverses_base64() {
+    assert_eq!(calculate("base64decode(\"aGVsbG8=\")"), "hello");
+    assert_eq!(calculate("base64decode(\"aGVsbG8\")"), "hello");
+    assert_eq!(
+        calculate("base64decode(base64(\"the quick brown fox\"))"),
+        "the quick brown fox"
+    );
+}
+
+#[test]
+fn test_urlencode_percent_encodes_reserved_characters() {
+    assert_eq!(calculate("urlencode(\"a b&c\")"), "a%20b%26c");
+    assert_eq!(calculate("urlencode(\"q=1/2?\")"), "q%3D1%2F2%3F");
+    assert_eq!(calculate("urlencode(\"цена\")"), "%D1%86%D0%B5%D0%BD%D0%B0");
+}
+
+#[test]
+fn test_invalid_encodings_are_rejected() {
+    assert!(error("base64decode(\"a*b=\")").contains("not base64"));
+    assert!(error("base64decode(\"/w==\")").contains("not UTF-8"));
+    assert!(error("base64(42)").contains("expected quoted text"));
+}
@@ -0,0 +1,55 @@
+//! Tests for quoted text and its length in characters, UTF-8 bytes and
+//! words.
+
+mod common;
+
+use common::{calculate, error};
+use link_calculator::Calculator;
+
+#[test]
+fn test_length_counts_characters() {
+    assert_eq!(calculate("length(\"hello\")"), "5");
+    assert_eq!(calculate("length of \"привет\""), "6");
+    assert_eq!(calculate("characters in \"naïve\""), "5");
+    assert_eq!(calculate("length(\"\")"), "0");
+}
+
+#[test]
+fn test_utf8_bytes_counts_encoded_size() {
+    assert_eq!(calculate("utf8 bytes of \"привет\""), "12 B");
+    assert_eq!(calculate("utf8_bytes(\"hello\")"), "5 B");
+    assert_eq!(calculate("bytes in \"€\""), "3 B");
+}
+
+#[test]
+fn test_words_are_separated_by_whitespace() {
+    assert_eq!(calculate("words in \"the quick brown fox\""), "4");
+    assert_eq!(calculate("words(\"  spaced   out  \")"), "2");
+}
+
+#[test]
+fn test_text_literals_accept_curly_quotes_and_escapes() {
+    assert_eq!(calculate("length(“hello”)"), "5");
+    assert_eq!(calculate(r#"length("say \"hi\"")"#), "8");
+    assert_eq!(calculate("\"hello\""), "hello");
+}
+
+#[test]
+fn test_lengths_combine_with_arithmetic() {
+    assert_eq!(calculate("length(\"hello\") * 2 + 1"), "11");
+}
+
+#[test]
+fn test_text_interpretation_keeps_quotes() {
+    let result = Calculator::new().calculate_internal("words in \"the quick brown fox\"");
+    assert_eq!(
+        result.lino_interpretation,
+        "(words (\"the quick brown fox\"))"
+    );
+}
+
+#[test]
+fn test_invalid_text_arguments_are_rejected() {
+    assert!(error("length(5)").contains("quoted text"));
+    assert!(error("\"unterminated").contains("Unterminated text"));
+}
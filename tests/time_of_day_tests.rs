@@ -0,0 +1,60 @@
+//! Tests for arithmetic on clock times without dates.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::Calculator;
+
+#[test]
+fn test_adding_clock_times_gives_a_time_of_day() {
+    let calculator = Calculator::new();
+    assert_eq!(calculate_with(&calculator, "14:30 + 2:45"), "17:15");
+    assert_eq!(
+        calculate_with(&calculator, "(14:30 + 2:45) + 30 minutes"),
+        "17:45"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "(8:00 + 0:30) - 9:00"),
+        "23:30 (-1 day)"
+    );
+}
+
+#[test]
+fn test_times_past_midnight_run_into_the_next_day() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "22:00 + 3:30"),
+        "01:30 (+1 day)"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "(20:00 + 20:00) + 24 hours"),
+        "16:00 (+2 days)"
+    );
+}
+
+#[test]
+fn test_a_shift_is_the_time_between_two_clock_times() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "9:00 to 17:30 minus 45 minutes break"),
+        "7 hours, 45 minutes"
+    );
+    assert_eq!(calculate_with(&calculator, "22:00 to 6:00"), "8 hours");
+    assert_eq!(
+        calculate_with(&calculator, "9am to 5pm - 1 hour lunch"),
+        "7 hours"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "9:00 to 17:30 in hours"),
+        "8.5 hours"
+    );
+}
+
+#[test]
+fn test_subtracting_clock_times_still_gives_a_duration() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "17:30 - 9:00"),
+        "8 hours, 30 minutes"
+    );
+}
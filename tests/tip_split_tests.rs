@@ -0,0 +1,66 @@
+//! Tests for tips and bill splitting (`86.40 USD + 18% tip split 4 ways`).
+
+mod common;
+
+use common::breakdown;
+use link_calculator::Calculator;
+
+#[test]
+fn test_tip_split_per_person() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("86.40 USD + 18% tip split 4 ways");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "25.49 USD");
+    assert_eq!(
+        breakdown(&result),
+        [
+            ("Subtotal".to_string(), "86.4 USD".to_string()),
+            ("Tip 18%".to_string(), "15.55 USD".to_string()),
+            ("Total".to_string(), "101.95 USD".to_string()),
+            ("Per person (4 ways)".to_string(), "25.49 USD".to_string()),
+        ]
+    );
+    assert!(result
+        .steps
+        .iter()
+        .any(|step| step == "Tip 18%: 15.552 USD"));
+}
+
+#[test]
+fn test_tip_without_split() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("50 + 20% tip");
+    assert_eq!(result.result, "60");
+    assert_eq!(breakdown(&result).len(), 3);
+}
+
+#[test]
+fn test_split_without_tip() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("120 EUR split between 3 people");
+    assert_eq!(result.result, "40 EUR");
+    assert_eq!(
+        breakdown(&result),
+        [
+            ("Total".to_string(), "120 EUR".to_string()),
+            ("Per person (3 ways)".to_string(), "40 EUR".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_split_after_vat() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("150 EUR + VAT 19% split 2 ways");
+    assert_eq!(result.result, "89.25 EUR");
+    assert_eq!(breakdown(&result)[3].0, "Per person (2 ways)");
+}
+
+#[test]
+fn test_invalid_split() {
+    let calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("100 split 0 ways").success);
+    assert!(!calculator.calculate_internal("100 split 2.5 ways").success);
+    // Modulo is unaffected
+    assert_eq!(calculator.calculate_internal("17 % 5").result, "2");
+}
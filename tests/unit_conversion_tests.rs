@@ -30,7 +30,7 @@ mod data_size_tests {
     /// `741 KB as MB` → 0.741 MB (exact within SI system)
     #[test]
     fn test_741_kb_as_mb_issue_55() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("741 KB as MB");
         assert!(result.success, "Failed: {:?}", result.error);
         // 741 KB = 741 * 1000 bytes = 741000 bytes; 741000 / 1000000 = 0.741
@@ -40,7 +40,7 @@ mod data_size_tests {
     /// `741 KB as mebibytes` → ~0.706863... MiB (cross-standard conversion)
     #[test]
     fn test_741_kb_as_mebibytes_issue_55() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("741 KB as mebibytes");
         assert!(result.success, "Failed: {:?}", result.error);
         // 741000 bytes / 1048576 bytes per MiB ≈ 0.706863
@@ -50,7 +50,7 @@ mod data_size_tests {
     /// `741 KiB as MiB` → 741/1024 ≈ 0.72363... MiB (within IEC system)
     #[test]
     fn test_741_kib_as_mib_issue_55() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("741 KiB as MiB");
         assert!(result.success, "Failed: {:?}", result.error);
         // 741 KiB = 741 * 1024 bytes = 758784 bytes; 758784 / 1048576 = 741/1024
@@ -62,7 +62,7 @@ mod data_size_tests {
     /// Basic byte to kilobyte conversion.
     #[test]
     fn test_1000_bytes_as_kb() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1000 B as KB");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 KB");
@@ -71,7 +71,7 @@ mod data_size_tests {
     /// Basic kibibyte to byte conversion.
     #[test]
     fn test_1_kib_as_bytes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 KiB as B");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1024 B");
@@ -80,7 +80,7 @@ mod data_size_tests {
     /// Gigabyte to megabyte conversion (SI).
     #[test]
     fn test_1_gb_as_mb() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 GB as MB");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1000 MB");
@@ -89,7 +89,7 @@ mod data_size_tests {
     /// Gibibyte to mebibyte conversion (IEC).
     #[test]
     fn test_1_gib_as_mib() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 GiB as MiB");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1024 MiB");
@@ -98,7 +98,7 @@ mod data_size_tests {
     /// Full-name unit support.
     #[test]
     fn test_full_name_kilobytes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kilobytes as megabytes");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "0.001 MB");
@@ -107,7 +107,7 @@ mod data_size_tests {
     /// Full-name unit support for binary prefixes.
     #[test]
     fn test_full_name_kibibytes_to_mebibytes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1024 kibibytes as mebibytes");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 MiB");
@@ -116,7 +116,7 @@ mod data_size_tests {
     /// Cross-standard: GiB to GB.
     #[test]
     fn test_1_gib_as_gb() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 GiB as GB");
         assert!(result.success, "Failed: {:?}", result.error);
         // 1 GiB = 1073741824 bytes; 1073741824 / 1000000000 GB ≈ 1.073741824
@@ -131,7 +131,7 @@ mod data_size_tests {
     /// Bit conversions.
     #[test]
     fn test_8_bits_as_byte() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("8 b as B");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 B");
@@ -140,7 +140,7 @@ mod data_size_tests {
     /// Links notation includes the target unit.
     #[test]
     fn test_lino_for_unit_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("741 KB as MB");
         assert!(result.success, "Failed: {:?}", result.error);
         // The lino should contain "as MB"
@@ -154,7 +154,7 @@ mod data_size_tests {
     /// Arithmetic with data size units.
     #[test]
     fn test_data_size_arithmetic_then_convert() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("(500 KB + 241 KB) as MB");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "0.741 MB");
@@ -185,7 +185,7 @@ mod mass_unit_tests {
     /// `10 tons to kg` → 10000 kg (metric ton disambiguation).
     #[test]
     fn test_tons_to_kg_issue_57() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("10 tons to kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "10000 kg");
@@ -194,7 +194,7 @@ mod mass_unit_tests {
     /// `1 tonne to kg` → 1000 kg.
     #[test]
     fn test_tonne_to_kg() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 tonne to kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1000 kg");
@@ -203,7 +203,7 @@ mod mass_unit_tests {
     /// `1000 g as kg` → 1 kg.
     #[test]
     fn test_grams_to_kg() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1000 g as kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 kg");
@@ -212,7 +212,7 @@ mod mass_unit_tests {
     /// `1 kg as grams` → 1000 g.
     #[test]
     fn test_kg_to_grams() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kg as grams");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1000 g");
@@ -221,7 +221,7 @@ mod mass_unit_tests {
     /// `1 kg as pounds` → approximately 2.2046 lb.
     #[test]
     fn test_kg_to_pounds() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kg as pounds");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_approx(&result.result, "lb", 2.204_622_6, 1e-3);
@@ -230,7 +230,7 @@ mod mass_unit_tests {
     /// `1 lb as kg` → approximately 0.4536 kg.
     #[test]
     fn test_pounds_to_kg() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 lb as kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_approx(&result.result, "kg", 0.453_592_4, 1e-3);
@@ -239,7 +239,7 @@ mod mass_unit_tests {
     /// `1 kg as oz` → approximately 35.274 oz.
     #[test]
     fn test_kg_to_oz() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kg as oz");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_approx(&result.result, "oz", 35.274, 0.01);
@@ -248,7 +248,7 @@ mod mass_unit_tests {
     /// `1000 kg as tonnes` → 1 t.
     #[test]
     fn test_kg_to_tonnes() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1000 kg as tonnes");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_approx(&result.result, "t", 1.0, 1e-9);
@@ -257,7 +257,7 @@ mod mass_unit_tests {
     /// `1 kg in kilograms` — same unit, should stay 1 kg.
     #[test]
     fn test_kg_in_kg_no_change() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kg in kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 kg");
@@ -266,7 +266,7 @@ mod mass_unit_tests {
     /// `500 g + 500 g in kg` → 1 kg (arithmetic then conversion).
     #[test]
     fn test_mass_arithmetic_then_convert() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("(500 g + 500 g) in kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(result.result, "1 kg");
@@ -275,7 +275,7 @@ mod mass_unit_tests {
     /// Natural language: `kilograms in pounds`.
     #[test]
     fn test_kilograms_word_conversion() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("1 kilograms in pounds");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_approx(&result.result, "lb", 2.204_622_6, 1e-3);
@@ -291,7 +291,7 @@ mod crypto_unit_tests {
 
     /// Helper: sets up calculator with a mock TON/USD rate.
     fn calc_with_ton_rate() -> Calculator {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let rates_json = r#"{"TON": 5.42, "BTC": 95000.0, "ETH": 3500.0}"#;
         calc.update_crypto_rates_from_api("USD", "2026-01-26", rates_json);
         calc
@@ -300,7 +300,7 @@ mod crypto_unit_tests {
     /// `update_crypto_rates_from_api` returns correct count.
     #[test]
     fn test_update_crypto_rates_returns_count() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let rates_json = r#"{"TON": 5.42, "BTC": 95000.0, "ETH": 3500.0}"#;
         let count = calc.update_crypto_rates_from_api("USD", "2026-01-26", rates_json);
         assert_eq!(count, 3);
@@ -309,7 +309,7 @@ mod crypto_unit_tests {
     /// `update_crypto_rates_from_api` with invalid JSON returns 0.
     #[test]
     fn test_update_crypto_rates_invalid_json() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let count = calc.update_crypto_rates_from_api("USD", "2026-01-26", "not json");
         assert_eq!(count, 0);
     }
@@ -317,7 +317,7 @@ mod crypto_unit_tests {
     /// `19 TON as USD` — ticker-based crypto conversion.
     #[test]
     fn test_ton_as_usd_ticker() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("19 TON as USD");
         assert!(result.success, "Failed: {:?}", result.error);
         // 19 * 5.42 = 102.98
@@ -341,7 +341,7 @@ mod crypto_unit_tests {
     /// `19 ton in usd` — the exact use case from issue #57 (lowercase, `in` keyword).
     #[test]
     fn test_ton_in_usd_lowercase_issue_57() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("19 ton in usd");
         assert!(result.success, "Failed: {:?}", result.error);
         let value: f64 = result
@@ -359,7 +359,7 @@ mod crypto_unit_tests {
     /// `19 ton to usd` — `to` keyword variant.
     #[test]
     fn test_ton_to_usd_issue_57() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("19 ton to usd");
         assert!(result.success, "Failed: {:?}", result.error);
         let value: f64 = result
@@ -377,7 +377,7 @@ mod crypto_unit_tests {
     /// `19 ton in dollars` — natural language alias for USD.
     #[test]
     fn test_ton_in_dollars_issue_57() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("19 ton in dollars");
         assert!(result.success, "Failed: {:?}", result.error);
         let value: f64 = result
@@ -395,7 +395,7 @@ mod crypto_unit_tests {
     /// `19 toncoin in usd` — natural language crypto name.
     #[test]
     fn test_toncoin_in_usd_issue_57() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("19 toncoin in usd");
         assert!(result.success, "Failed: {:?}", result.error);
         let value: f64 = result
@@ -413,7 +413,7 @@ mod crypto_unit_tests {
     /// `10 tons to kg` should NOT use crypto rates — it's a mass conversion.
     #[test]
     fn test_tons_mass_not_crypto_issue_57() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         // Even with crypto rates loaded, plural `tons` → mass unit
         let result = calc.calculate_internal("10 tons to kg");
         assert!(result.success, "Failed: {:?}", result.error);
@@ -428,7 +428,7 @@ mod crypto_unit_tests {
     /// `1 BTC as USD` — bitcoin conversion.
     #[test]
     fn test_btc_as_usd() {
-        let mut calc = calc_with_ton_rate();
+        let calc = calc_with_ton_rate();
         let result = calc.calculate_internal("1 BTC as USD");
         assert!(result.success, "Failed: {:?}", result.error);
         let value: f64 = result
@@ -521,7 +521,7 @@ mod unit_ambiguity_tests {
     /// `19 ton in kg` should compute correct mass conversion.
     #[test]
     fn test_ton_in_kg_computes_correctly_issue_104() {
-        let mut calc = Calculator::new();
+        let calc = Calculator::new();
         let result = calc.calculate_internal("19 ton in kg");
         assert!(result.success, "Failed: {:?}", result.error);
         assert_eq!(
@@ -0,0 +1,91 @@
+//! Tests for units raised to whole powers: areas and volumes built by
+//! multiplying lengths, written with `^` or superscripts, and converted.
+
+mod common;
+
+use common::calculate;
+use link_calculator::Calculator;
+
+#[test]
+fn test_powers_of_a_unit_add_up() {
+    assert_eq!(calculate("5 m^2 + 3 m^2"), "8 m²");
+    assert_eq!(calculate("1 m^2 + 5000 cm^2"), "1.5 m²");
+}
+
+#[test]
+fn test_multiplying_lengths_gives_an_area() {
+    assert_eq!(calculate("2 m * 3 m"), "6 m²");
+    assert_eq!(calculate("2 km * 3 m"), "0.006 km²");
+    assert_eq!(calculate("5 kg * 2 kg"), "10 kg²");
+}
+
+#[test]
+fn test_dividing_lowers_the_exponent() {
+    assert_eq!(calculate("8 m^3 / 2 m"), "4 m²");
+    assert_eq!(calculate("6 m² / 3 m²"), "2");
+    assert_eq!(calculate("2 m / 4 m^2"), "0.5 m⁻¹");
+}
+
+#[test]
+fn test_negative_powers_belong_to_the_unit() {
+    assert_eq!(calculate("3 m^-1"), "3 m⁻¹");
+    assert_eq!(calculate("3 m^(-1)"), "3 m⁻¹");
+    assert_eq!(calculate("2 m^2 * 3 m^-1"), "6 m");
+}
+
+#[test]
+fn test_dividing_a_number_by_an_amount_inverts_its_unit() {
+    assert_eq!(calculate("1 / (2 m)"), "0.5 m⁻¹");
+    assert_eq!(calculate("(2 m)^-1"), "0.5 m⁻¹");
+    assert_eq!(calculate("6 / (2 m/s)"), "3 s/m");
+}
+
+#[test]
+fn test_roots_of_an_amount_take_the_root_of_its_unit() {
+    assert_eq!(calculate("sqrt(9 m^2)"), "3 m");
+    assert_eq!(calculate("(9 m^2)^0.5"), "3 m");
+    assert_eq!(calculate("cbrt(8 m^3)"), "2 m");
+    assert_eq!(calculate("sqrt(16 m^2/s^2)"), "4 m/s");
+    assert!(!Calculator::new().calculate_internal("sqrt(2 m)").success);
+}
+
+#[test]
+fn test_raising_an_amount_raises_its_unit() {
+    assert_eq!(calculate("(3 m)^2"), "9 m²");
+    assert_eq!(calculate("(2 m)^3"), "8 m³");
+}
+
+#[test]
+fn test_powers_convert_between_units_and_to_volumes() {
+    assert_eq!(calculate("10 m^2 as cm^2"), "100000 cm²");
+    assert_eq!(calculate("1 m^3 as L"), "1000 L");
+    assert_eq!(calculate("2 L as cm^3"), "2000 cm³");
+}
+
+#[test]
+fn test_rates_per_power_cancel_against_amounts() {
+    assert_eq!(calculate("9.8 m/s^2 * 2 s"), "19.6 m/s");
+    assert_eq!(calculate("3 USD/m^2 * 10 m^2"), "30 USD");
+    assert_eq!(calculate("60 km/h * 2 h"), "120 km");
+}
+
+#[test]
+fn test_mixed_dimension_products() {
+    assert_eq!(calculate("2 m^2 * 3 m/s"), "6 m³/s");
+    assert_eq!(calculate("3 m/s * 200 cm"), "6 m²/s");
+    assert_eq!(calculate("2 m^-1 * 3 m/s"), "6 s⁻¹");
+    assert_eq!(calculate("10 USD/m^2 * 2 ft * 3 ft"), "5.57 USD");
+    assert_eq!(calculate("5 USD/kg * 500 g"), "2.5 USD");
+
+    // Products with no unit to name them are errors, not a dropped unit
+    let calculator = Calculator::new();
+    for input in [
+        "2 m * 3 s",
+        "2 kg * 3 m/s",
+        "3 USD/m * 2 m^2",
+        "5 USD/kg * 2 m",
+    ] {
+        let result = calculator.calculate_internal(input);
+        assert!(!result.success, "{input} gave {}", result.result);
+    }
+}
@@ -0,0 +1,60 @@
+//! Tests for unit prices and comparison shopping
+//! (`compare 5 USD for 300 g vs 8 USD for 550 g`).
+
+mod common;
+
+use common::breakdown;
+use link_calculator::Calculator;
+
+#[test]
+fn test_unit_price_per_word() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("3.49 USD / 750 ml in USD per liter");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "4.653333333333333 USD/L");
+    let result = calculator.calculate_internal("60 km/h in m per s");
+    assert_eq!(result.result, "16.66666666666667 m/s");
+}
+
+#[test]
+fn test_compare_offers_names_the_better_deal() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("compare 5 USD for 300 g vs 8 USD for 550 g");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(
+        result.result,
+        "8 USD for 550 g (14.54545454545455 USD/kg) is a better deal than \
+         5 USD for 300 g (16.66666666666667 USD/kg)"
+    );
+    assert_eq!(
+        breakdown(&result),
+        [
+            (
+                "5 USD for 300 g".to_string(),
+                "16.66666666666667 USD/kg".to_string()
+            ),
+            (
+                "8 USD for 550 g".to_string(),
+                "14.54545454545455 USD/kg".to_string()
+            ),
+        ]
+    );
+
+    let result = calculator.calculate_internal("2 USD for 1 L vs 1 USD for 500 ml");
+    assert_eq!(
+        result.result,
+        "2 USD for 1 L (2 USD/L) costs the same as 1 USD for 500 ml (2 USD/L)"
+    );
+}
+
+#[test]
+fn test_compare_offers_needs_comparable_quantities() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("compare 5 USD for 300 g vs 8 USD for 550 ml");
+    assert!(!result.success);
+    let result = calculator.calculate_internal("compare 5 USD for 300 g vs 8 USD");
+    assert!(!result.success);
+    // Plain values still compare as before
+    let result = calculator.calculate_internal("compare 1 m vs 90 cm");
+    assert_eq!(result.result, "1 m > 90 cm");
+}
@@ -0,0 +1,70 @@
+//! Tests for parse-only validation with `Calculator::validate`.
+
+use link_calculator::error::ErrorSpan;
+use link_calculator::Calculator;
+
+#[test]
+fn test_valid_input() {
+    let result = Calculator::new().validate_internal("2 + 3 * 4");
+    assert!(result.valid);
+    assert!(result.diagnostics.is_empty());
+}
+
+#[test]
+fn test_invalid_input_reports_span() {
+    let result = Calculator::new().validate_internal("  2 @ 3");
+    assert!(!result.valid);
+    let diagnostic = &result.diagnostics[0];
+    assert_eq!(diagnostic.error_info.key, "errors.parseError");
+    assert_eq!(diagnostic.span, Some(ErrorSpan::new(4, 5)));
+}
+
+#[test]
+fn test_matches_calculate_errors() {
+    let calculator = Calculator::new();
+    for input in ["(2 + 3", "10 kg in flibbles", "2 + 3)"] {
+        let validation = calculator.validate_internal(input);
+        let result = calculator.calculate_internal(input);
+        assert_eq!(
+            validation.diagnostics[0].message,
+            result.error.unwrap(),
+            "{input}"
+        );
+        assert_eq!(validation.diagnostics[0].span, result.error_span, "{input}");
+    }
+}
+
+#[test]
+fn test_does_not_evaluate() {
+    let calculator = Calculator::new();
+    // Evaluation errors and missing rates are not parse problems
+    assert!(calculator.validate_internal("1 / 0").valid);
+    assert!(calculator.validate_internal("84 USD + 10 ABC").valid);
+    assert!(calculator.validate_internal("#5 + 1").valid);
+    assert!(calculator.history().is_empty());
+}
+
+#[test]
+fn test_datetime_subtraction_is_valid() {
+    let calculator = Calculator::new();
+    assert!(
+        calculator
+            .validate_internal("(Jan 30, 2026) - (Jan 20, 2026)")
+            .valid
+    );
+}
+
+#[test]
+fn test_empty_input() {
+    let result = Calculator::new().validate_internal("   ");
+    assert!(!result.valid);
+    assert_eq!(result.diagnostics[0].error_info.key, "errors.emptyInput");
+}
+
+#[test]
+fn test_validate_json() {
+    let json: serde_json::Value =
+        serde_json::from_str(&Calculator::new().validate("2 @ 3")).unwrap();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["diagnostics"][0]["span"]["start"], 2);
+}
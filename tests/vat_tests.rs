@@ -0,0 +1,72 @@
+//! Tests for VAT helpers (`150 EUR + VAT 19%`, `gross 119 EUR at VAT 19%`).
+
+mod common;
+
+use common::breakdown;
+use link_calculator::Calculator;
+
+#[test]
+fn test_add_vat() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("150 EUR + VAT 19%");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "178.5 EUR");
+    assert_eq!(
+        breakdown(&result),
+        [
+            ("Net".to_string(), "150 EUR".to_string()),
+            ("VAT 19%".to_string(), "28.5 EUR".to_string()),
+            ("Gross".to_string(), "178.5 EUR".to_string()),
+        ]
+    );
+    assert!(result.steps.iter().any(|step| step == "VAT 19%: 28.5 EUR"));
+}
+
+#[test]
+fn test_remove_vat_from_gross() {
+    let calculator = Calculator::new();
+    let result = calculator.calculate_internal("gross 119 EUR at VAT 19%");
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.result, "100 EUR");
+    assert_eq!(breakdown(&result)[1].1, "19 EUR");
+}
+
+#[test]
+fn test_default_vat_rate() {
+    let mut calculator = Calculator::new();
+    assert!(!calculator.calculate_internal("150 EUR + VAT").success);
+
+    calculator.set_default_vat_rate(20.0);
+    assert_eq!(
+        calculator.calculate_internal("150 EUR + VAT").result,
+        "180 EUR"
+    );
+    assert_eq!(
+        calculator.calculate_internal("gross 120 EUR").result,
+        "100 EUR"
+    );
+    // An explicit rate still wins
+    assert_eq!(
+        calculator.calculate_internal("100 EUR + VAT 7%").result,
+        "107 EUR"
+    );
+
+    calculator.clear_default_vat_rate();
+    assert!(!calculator.calculate_internal("gross 120 EUR").success);
+}
+
+#[test]
+fn test_vat_in_larger_expressions() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculator.calculate_internal("100 + VAT 20% + 5").result,
+        "125"
+    );
+    assert_eq!(
+        calculator
+            .calculate_internal("150 EUR + VAT 19% to 2 dp")
+            .result,
+        "178.50 EUR"
+    );
+    assert!(calculator.calculate_internal("2 + 3").breakdown.is_none());
+}
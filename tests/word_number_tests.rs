@@ -0,0 +1,34 @@
+//! Tests for numbers and operators written in words.
+
+mod common;
+
+use common::calculate;
+
+#[test]
+fn test_english_word_numbers() {
+    assert_eq!(calculate("two hundred plus thirty five"), "235");
+    assert_eq!(calculate("one hundred and five divided by five"), "21");
+    assert_eq!(calculate("twenty-one times two"), "42");
+    assert_eq!(calculate("three thousand four hundred minus 1"), "3399");
+}
+
+#[test]
+fn test_fractions_and_dozens() {
+    assert_eq!(calculate("half of 90"), "45");
+    assert_eq!(calculate("two thirds of 90"), "60");
+    assert_eq!(calculate("a dozen * 3"), "36");
+    assert_eq!(calculate("two dozen"), "24");
+}
+
+#[test]
+fn test_russian_word_numbers() {
+    assert_eq!(calculate("двести плюс тридцать пять"), "235");
+    assert_eq!(calculate("сто разделить на четыре"), "25");
+    assert_eq!(calculate("две тысячи двадцать пять"), "2025");
+}
+
+#[test]
+fn test_word_numbers_with_units() {
+    assert_eq!(calculate("twenty dollars + 5 USD"), "25 USD");
+    assert_eq!(calculate("5 plus 3"), "8");
+}
@@ -0,0 +1,54 @@
+//! Tests for world-clock expressions with cities in place of time zones.
+
+mod common;
+
+use common::calculate_with;
+use link_calculator::Calculator;
+
+#[test]
+fn test_a_time_in_one_city_is_shown_in_another() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "time in Tokyo when 9am in Mumbai"),
+        "12:30:00 JST"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "time in Dubai when it is 17:00 in Singapore"),
+        "13:00:00 GST"
+    );
+    // New York is 13 or 14 hours behind Tokyo, by daylight saving time
+    let tokyo = calculate_with(&calculator, "time in Tokyo when 9am in New York");
+    assert!(
+        tokyo == "22:00:00 JST" || tokyo == "23:00:00 JST",
+        "{tokyo}"
+    );
+}
+
+#[test]
+fn test_cities_follow_daylight_saving_time() {
+    let calculator = Calculator::new();
+    assert_eq!(
+        calculate_with(&calculator, "2026-01-15 12:00 UTC in New York"),
+        "2026-01-15 07:00:00 EST"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "2026-07-15 12:00 UTC in New York"),
+        "2026-07-15 08:00:00 EDT"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "2026-07-15 12:00 UTC in Sydney"),
+        "2026-07-15 22:00:00 AEST"
+    );
+    assert_eq!(
+        calculate_with(&calculator, "2026-01-15 12:00 UTC as Rio de Janeiro"),
+        "2026-01-15 09:00:00 BRT"
+    );
+}
+
+#[test]
+fn test_the_time_in_a_city_now() {
+    let calculator = Calculator::new();
+    let result = calculate_with(&calculator, "time in Tokyo");
+    assert!(result.ends_with(" JST"), "{result}");
+    assert!(!calculator.calculate_internal("time in Atlantis").success);
+}
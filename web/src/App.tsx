@@ -8,7 +8,7 @@ import { generateIssueUrl, type PageState } from './utils/reportIssue';
 import { formatLocalDateTime, formatUtcDateTime } from './utils/datetimeDisplay';
 import { AutoResizeTextarea, ColorCodedLino, RepeatingDecimalNotations, UniversalKeyboard, type AutoResizeTextareaRef } from './components';
 import { getExamplesForDisplay } from './examples';
-import type { CalculationPlan, CalculationResult, ErrorInfo } from './types';
+import type { CalculationPlan, CalculationResult, ErrorInfo, ErrorSpan } from './types';
 
 // SVG Logo component for Link.Calculator branding
 const LinkCalculatorLogo = ({ size = 24 }: { size?: number }) => (
@@ -93,6 +93,27 @@ function translateError(
   return translated;
 }
 
+/**
+ * Shows the input with the part an error points at underlined.
+ * Spans count code points, matching the Rust engine's character indices.
+ */
+function ErrorSpanHighlight({ input, span }: { input: string; span: ErrorSpan }) {
+  const chars = Array.from(input);
+  if (span.start > chars.length || span.end > chars.length) {
+    return null;
+  }
+  const before = chars.slice(0, span.start).join('');
+  const marked = chars.slice(span.start, span.end).join('');
+  const after = chars.slice(span.end).join('');
+  return (
+    <div className="error-span" data-testid="error-span">
+      {before}
+      {marked ? <mark>{marked}</mark> : <span className="error-caret" />}
+      {after}
+    </div>
+  );
+}
+
 /**
  * Detect user's preferred currency from browser locale.
  */
@@ -293,6 +314,14 @@ function App() {
     workerRef.current.postMessage({ type: 'calculate', expression: expr });
   }, [wasmReady, input]);
 
+  // Parse and format in the UI language, so switching it changes how
+  // `1 234,56`, `01/02/2026` and `¥` are read, not just the UI strings.
+  useEffect(() => {
+    if (wasmReady) {
+      workerRef.current?.postMessage({ type: 'setLocale', locale: i18n.language });
+    }
+  }, [wasmReady, i18n.language]);
+
   // Auto-calculate when expression is loaded from URL.
   // If a cached result exists for the current app version, display it immediately
   // while the fresh calculation runs in the background.
@@ -710,6 +739,13 @@ function App() {
                         )}
                       </div>
                     )}
+                    {result.warnings && result.warnings.length > 0 && (
+                      <ul className="result-warnings">
+                        {result.warnings.map((warning, i) => (
+                          <li key={i}>{warning}</li>
+                        ))}
+                      </ul>
+                    )}
 
                     {/* Section 3: Repeating decimal notations (optional) */}
                     {result.repeating_decimal && (
@@ -719,12 +755,56 @@ function App() {
                       </div>
                     )}
 
+                    {result.breakdown && result.breakdown.length > 0 && (
+                      <div className="table-section">
+                        <h3>{t('result.table', 'Breakdown')}</h3>
+                        <table className="result-table result-breakdown">
+                          <tbody>
+                            {result.breakdown.map((item) => (
+                              <tr key={item.label}>
+                                <th scope="row">{item.label}</th>
+                                <td>{item.value}</td>
+                              </tr>
+                            ))}
+                          </tbody>
+                        </table>
+                      </div>
+                    )}
+
+                    {result.table && (
+                      <div className="table-section">
+                        <h3>{t('result.table', 'Breakdown')}</h3>
+                        <div className="result-table-scroll">
+                          <table className="result-table">
+                            <thead>
+                              <tr>
+                                {result.table.columns.map((column) => (
+                                  <th key={column}>{column}</th>
+                                ))}
+                              </tr>
+                            </thead>
+                            <tbody>
+                              {result.table.rows.map((row, i) => (
+                                <tr key={i}>
+                                  {row.map((cell, j) => (
+                                    <td key={j}>{cell}</td>
+                                  ))}
+                                </tr>
+                              ))}
+                            </tbody>
+                          </table>
+                        </div>
+                      </div>
+                    )}
+
                     {/* Section 4: Plot (optional) */}
                     {result.plot_data && (
                       <div className="plot-section">
                         <h3>{t('result.plot', 'Function Plot')}</h3>
                         <Suspense fallback={<div className="loading"><div className="spinner" /></div>}>
-                          <FunctionPlot data={result.plot_data} width={360} height={220} />
+                          {(result.plots ?? [result.plot_data]).map((plot, index) => (
+                            <FunctionPlot key={index} data={plot} width={360} height={220} />
+                          ))}
                         </Suspense>
                       </div>
                     )}
@@ -746,6 +826,23 @@ function App() {
                     <div className="result-value error">
                       {translateError(t, result.error_info, result.error)}
                     </div>
+                    {result.error_span && (
+                      <ErrorSpanHighlight input={input} span={result.error_span} />
+                    )}
+                    {result.partial_result && (
+                      <div className="partial-result">
+                        <h3>{t('errors.partialResult')}</h3>
+                        <code>{result.partial_result}</code>
+                        <ul>
+                          {result.unresolved?.map((item, i) => (
+                            <li key={i}>
+                              <code>{item.expression}</code>:{' '}
+                              {translateError(t, item.error_info, item.error)}
+                            </li>
+                          ))}
+                        </ul>
+                      </div>
+                    )}
                     {result.issue_link && (
                       <div className="issue-link">
                         <a href={result.issue_link} target="_blank" rel="noopener noreferrer">
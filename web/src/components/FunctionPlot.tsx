@@ -34,8 +34,10 @@ export const FunctionPlot: React.FC<FunctionPlotProps> = ({
     // Calculate bounds
     const xMin = Math.min(...data.x_values);
     const xMax = Math.max(...data.x_values);
-    const yMin = Math.min(...data.y_values.filter(y => isFinite(y)));
-    const yMax = Math.max(...data.y_values.filter(y => isFinite(y)));
+    const finiteY = data.y_values.filter((y): y is number => Number.isFinite(y));
+    if (!finiteY.length) return;
+    const yMin = Math.min(...finiteY);
+    const yMax = Math.max(...finiteY);
 
     // Add padding
     const padding = 40;
@@ -92,6 +94,34 @@ export const FunctionPlot: React.FC<FunctionPlotProps> = ({
       ctx.stroke();
     }
 
+    // Shade the area between the curve and the x-axis over the bounds
+    if (data.shade_from !== undefined && data.shade_to !== undefined) {
+      const from = Math.min(data.shade_from, data.shade_to);
+      const to = Math.max(data.shade_from, data.shade_to);
+      const baseline = scaleY(Math.min(Math.max(0, yMin), yMax));
+
+      ctx.fillStyle = 'rgba(79, 195, 247, 0.25)';
+      ctx.beginPath();
+      let shading = false;
+      for (let i = 0; i < data.x_values.length; i++) {
+        const x = data.x_values[i];
+        const y = data.y_values[i];
+        if (x < from || x > to || y === null || !Number.isFinite(y)) continue;
+
+        if (!shading) {
+          ctx.moveTo(scaleX(x), baseline);
+          shading = true;
+        }
+        ctx.lineTo(scaleX(x), scaleY(y));
+      }
+      if (shading) {
+        const last = data.x_values.filter(x => x >= from && x <= to).pop() ?? to;
+        ctx.lineTo(scaleX(last), baseline);
+        ctx.closePath();
+        ctx.fill();
+      }
+    }
+
     // Draw the function
     ctx.strokeStyle = '#4fc3f7';
     ctx.lineWidth = 2;
@@ -102,7 +132,8 @@ export const FunctionPlot: React.FC<FunctionPlotProps> = ({
       const x = data.x_values[i];
       const y = data.y_values[i];
 
-      if (!isFinite(y)) {
+      // A null or non-finite value breaks the curve, e.g. at a pole
+      if (y === null || !Number.isFinite(y)) {
         started = false;
         continue;
       }
@@ -1,9 +1,51 @@
 export interface PlotData {
   x_values: number[];
-  y_values: number[];
+  /** `null` where the function is undefined, breaking the curve (e.g. at poles). */
+  y_values: (number | null)[];
   label: string;
   x_label: string;
   y_label: string;
+  /** Start of the region to shade under the curve (definite integral lower bound). */
+  shade_from?: number;
+  /** End of the region to shade under the curve (definite integral upper bound). */
+  shade_to?: number;
+}
+
+/**
+ * A table of rows under named columns, such as an amortization schedule.
+ */
+export interface ResultTable {
+  columns: string[];
+  rows: string[][];
+}
+
+/**
+ * One labelled part of a result, such as the VAT in `150 EUR + VAT 19%`.
+ */
+export interface BreakdownItem {
+  label: string;
+  value: string;
+}
+
+/** An exchange rate used by a structured step. */
+export interface StepRate {
+  from: string;
+  to: string;
+  rate: number;
+  source: string;
+  date: string;
+}
+
+/** One operation of a calculation, as a node of its computation graph. */
+export interface StructuredStep {
+  id: number;
+  operation: string;
+  detail?: string;
+  /** Ids of the steps whose values this step consumes. */
+  inputs: number[];
+  output: string;
+  unit?: string;
+  rates?: StepRate[];
 }
 
 /**
@@ -33,6 +75,26 @@ export interface ErrorInfo {
   params?: Record<string, string>;
 }
 
+/**
+ * A range of the input an error points at, counted in Unicode code points.
+ * `end` is exclusive; an empty range marks a position (e.g. end of input).
+ */
+export interface ErrorSpan {
+  start: number;
+  end: number;
+}
+
+/**
+ * A subexpression that kept a partial result from being evaluated.
+ */
+export interface UnresolvedExpression {
+  expression: string;
+  /** Error message (raw text). */
+  error: string;
+  /** Error information for i18n support. */
+  error_info: ErrorInfo;
+}
+
 /**
  * A single calculation step with i18n support.
  */
@@ -92,6 +154,10 @@ export interface CalculationResult {
   lino_interpretation: string;
   /** Alternative links notation interpretations the user can switch between. */
   alternative_lino?: string[];
+  /** The interpretation as doublet links, one per line with the root last. */
+  lino_doublets?: string;
+  /** The parsed expression tree, when enabled with `set_include_ast(true)`. */
+  ast?: Record<string, unknown>;
   /** Step-by-step explanation (raw text for backwards compatibility). */
   steps: string[];
   /** Step-by-step explanation with i18n support. */
@@ -101,11 +167,27 @@ export interface CalculationResult {
   error?: string;
   /** Error information for i18n support. */
   error_info?: ErrorInfo;
+  /** The part of the input a parse error points at. */
+  error_span?: ErrorSpan;
+  /** The input with every part that evaluated replaced by its value. */
+  partial_result?: string;
+  /** The subexpressions that could not be evaluated in `partial_result`. */
+  unresolved?: UnresolvedExpression[];
+  /** Caveats about a successful result, such as a stale exchange rate. */
+  warnings?: string[];
+  /** A breakdown of the result, such as the periods of `amortize(...)`. */
+  table?: ResultTable;
+  /** The labelled parts of the result, such as net, VAT and gross. */
+  breakdown?: BreakdownItem[];
+  /** The computation graph of the result, one step per operation. */
+  structured_steps?: StructuredStep[];
   issue_link?: string;
   latex_input?: string;
   latex_result?: string;
   is_symbolic?: boolean;
   plot_data?: PlotData;
+  /** All plotted series, one per function (plot command). */
+  plots?: PlotData[];
   /** Repeating decimal notations (if result is a repeating decimal). */
   repeating_decimal?: RepeatingDecimalFormats;
   /** Fraction representation (if applicable). */
@@ -159,6 +159,13 @@ function parseRawCalculationStep(step: string): ParsedCalculationStep | null {
         params: { name: match[1] },
       }),
     },
+    {
+      regex: /^Error estimate: ±(.+) \(subintervals: (\d+)\)$/,
+      build: match => ({
+        key: 'steps.integrationErrorEstimate',
+        params: { error: match[1], intervals: match[2] },
+      }),
+    },
     {
       regex: /^Parse first datetime: (.+)$/,
       build: match => ({
@@ -15,7 +15,7 @@ declare module '*.wasm' {
 
 declare module '@wasm/link_calculator' {
   export interface CalculatorInstance {
-    calculate(input: string): string;
+    calculate(input: string, options?: string): string;
     free(): void;
   }
 
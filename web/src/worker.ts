@@ -19,13 +19,23 @@ import { loadCbrRatesFromLinoFiles } from './worker-cbr-lino-loader';
 interface CalculatorInstance {
   plan(input: string): string;
   execute(input: string): string;
-  calculate(input: string): string;
+  format(input: string): string;
+  calculate_with_progress(input: string, on_progress: (progress: { stage: string; done: number; total: number }) => boolean | void): string;
+  calculate(input: string, options?: string): string;
   update_rates_from_api(base: string, date: string, rates_json: string): number;
   update_crypto_rates_from_api(base: string, date: string, rates_json: string): number;
   update_cbr_rates_from_api(date: string, rates_json: string): number;
   load_rates_from_consolidated_lino(content: string): number;
   set_timezone_offset(offset_minutes: number): void;
   clear_timezone_offset(): void;
+  set_number_locale(language: string): boolean;
+  clear_number_locale(): void;
+  set_language(language: string): boolean;
+  set_format_options(options_json: string): boolean;
+  set_format_locale(locale: string): void;
+  clear_format_options(): void;
+  set_locale(locale: string): boolean;
+  clear_locale(): void;
 }
 
 interface CalculatorStatic {
@@ -372,10 +382,15 @@ async function executeCalculation(expression: string): Promise<void> {
 }
 
 self.onmessage = async (e: MessageEvent) => {
-  const { type, expression, baseCurrency } = e.data;
+  const { type, expression, baseCurrency, locale } = e.data;
 
   if (type === 'calculate') {
     await executeCalculation(expression);
+  } else if (type === 'setLocale') {
+    // Read and write numbers, dates and currency signs in the UI language
+    if (!calculator?.set_locale(locale)) {
+      calculator?.clear_locale();
+    }
   } else if (type === 'refreshRates') {
     // Manual refresh: re-fetch all sources that were previously loaded
     fetchCbrRates();